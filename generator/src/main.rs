@@ -37,6 +37,15 @@ fn main() {
     let mut hl_out = File::create(manifest_dir.join("../openxr/src/generated.rs")).unwrap();
     write!(sys_out, "{}", parser.generate_sys()).unwrap();
     write!(hl_out, "{}", parser.generate_hl()).unwrap();
+    let mut layer_out =
+        File::create(manifest_dir.join("../openxr/src/layer/generated.rs")).unwrap();
+    write!(layer_out, "{}", parser.generate_layer()).unwrap();
+    let mut runtime_out =
+        File::create(manifest_dir.join("../openxr/src/runtime/generated.rs")).unwrap();
+    write!(runtime_out, "{}", parser.generate_runtime()).unwrap();
+    let mut trace_out =
+        File::create(manifest_dir.join("../openxr/src/trace/generated.rs")).unwrap();
+    write!(trace_out, "{}", parser.generate_trace()).unwrap();
 }
 
 struct Parser {
@@ -55,6 +64,8 @@ struct Parser {
     extensions: IndexMap<String, Tag>,
     disabled_exts: HashSet<Rc<str>>,
     api_version: Option<(u16, u16, u32)>,
+    /// Major and minor versions of each `XR_API_VERSION_*` define
+    api_versions: Vec<(u16, u16)>,
     base_headers: IndexMap<String, Vec<String>>,
 }
 
@@ -86,6 +97,7 @@ impl Parser {
             .map(Into::into)
             .collect(),
             api_version: None,
+            api_versions: Vec::new(),
             base_headers: IndexMap::new(),
         }
     }
@@ -112,6 +124,9 @@ impl Parser {
                     "extensions" => {
                         self.parse_extensions();
                     }
+                    "feature" => {
+                        self.parse_feature(&attributes);
+                    }
                     _ => {
                         eprintln!("unimplemented root element: {}", name.local_name);
                         self.finish_element();
@@ -169,18 +184,7 @@ impl Parser {
                     name, attributes, ..
                 } => match &name.local_name[..] {
                     "extension" => {
-                        if let StartElement { name, .. } =
-                            self.reader.next().expect("failed to parse XML")
-                        {
-                            if name.local_name == "require" {
-                                self.parse_extension_required(&attributes);
-                            } else {
-                                eprintln!("unexpected extension element: {}", name.local_name);
-                            }
-                        } else {
-                            eprintln!("unexpected extension event");
-                        }
-                        self.finish_element();
+                        self.parse_extension(&attributes);
                     }
                     _ => {
                         eprintln!("unimplemented extensions element: {}", name.local_name);
@@ -201,7 +205,7 @@ impl Parser {
         }
     }
 
-    fn parse_extension_required(&mut self, attrs: &[OwnedAttribute]) {
+    fn parse_extension(&mut self, attrs: &[OwnedAttribute]) {
         let ext_name = Rc::<str>::from(attr(attrs, "name").unwrap());
         let ext_number = attr(attrs, "number").unwrap().parse::<i32>().unwrap();
         let mut ext_version = None;
@@ -213,6 +217,8 @@ impl Parser {
                     name, attributes, ..
                 } => {
                     match &name.local_name[..] {
+                        // Requirements may be split across several blocks, e.g. by dependency
+                        "require" => continue,
                         "command" => {
                             let cmd = attr(&attributes, "name").unwrap();
                             if let Some(command) = self.commands.get_mut(cmd) {
@@ -222,55 +228,8 @@ impl Parser {
                         }
                         "enum" => {
                             let name = attr(&attributes, "name").unwrap();
-                            if let Some(extends) = attr(&attributes, "extends") {
-                                const EXT_BASE: i32 = 1_000_000_000;
-                                const EXT_BLOCK_SIZE: i32 = 1000;
-
-                                let value = if let Some(offset) = attr(&attributes, "offset") {
-                                    let offset = offset.parse::<i32>().unwrap();
-                                    let sign =
-                                        if attr(&attributes, "dir").map_or(false, |x| x == "-") {
-                                            -1
-                                        } else {
-                                            1
-                                        };
-                                    ConstantValue::Literal(
-                                        sign * (EXT_BASE
-                                            + (ext_number - 1) * EXT_BLOCK_SIZE
-                                            + offset),
-                                    )
-                                } else if let Some(bitpos) = attr(&attributes, "bitpos") {
-                                    ConstantValue::Literal(bitpos.parse::<i32>().unwrap())
-                                } else {
-                                    ConstantValue::Alias(attr(&attributes, "alias").unwrap().into())
-                                };
-                                let bitmasks = &mut self.bitmasks;
-                                if let Some(e) = self.enums.get_mut(extends) {
-                                    e.values.push(Constant {
-                                        name: name.into(),
-                                        value,
-                                        comment: attr(&attributes, "comment")
-                                            .and_then(tidy_comment),
-                                    });
-                                } else if let Some(e) = self
-                                    .bitvalues
-                                    .get(extends)
-                                    .and_then(|x| bitmasks.get_mut(x))
-                                {
-                                    e.values.push(Constant {
-                                        name: name.into(),
-                                        value: match value {
-                                            ConstantValue::Literal(x) => {
-                                                ConstantValue::Literal(x as u64)
-                                            }
-                                            ConstantValue::Alias(x) => ConstantValue::Alias(x),
-                                        },
-                                        comment: attr(&attributes, "comment")
-                                            .and_then(tidy_comment),
-                                    });
-                                } else {
-                                    eprintln!("extension to unrecognized type {}", extends);
-                                }
+                            if attr(&attributes, "extends").is_some() {
+                                self.parse_enum_extension(&attributes, Some(ext_number));
                             } else if let Some(alias) = attr(&attributes, "alias") {
                                 self.api_aliases.push((name.into(), alias.into()));
                             } else if name.ends_with("SPEC_VERSION") {
@@ -298,10 +257,12 @@ impl Parser {
                     self.finish_element();
                 }
                 EndElement { name } => {
-                    if name.local_name == "require" {
+                    if name.local_name == "extension" {
                         break;
                     }
-                    eprintln!("unexpected end element: {}", name);
+                    if name.local_name != "require" {
+                        eprintln!("unexpected end element: {}", name);
+                    }
                 }
                 EndDocument => {
                     panic!("unexpected end of document");
@@ -309,7 +270,7 @@ impl Parser {
                 _ => {}
             }
         }
-        if attr(attrs, "supported").map_or(false, |x| x == "disabled") {
+        if attr(attrs, "supported") == Some("disabled") {
             self.disabled_exts.insert(ext_name);
         } else {
             let (tag, _) = split_ext_tag(&ext_name);
@@ -325,6 +286,103 @@ impl Parser {
         }
     }
 
+    /// Parse a core API version, e.g. `XR_VERSION_1_1`
+    fn parse_feature(&mut self, attrs: &[OwnedAttribute]) {
+        let number = attr(attrs, "number").unwrap();
+        let (major, minor) = number.split_once('.').unwrap();
+        let version = (major.parse::<u16>().unwrap(), minor.parse::<u16>().unwrap());
+        loop {
+            use XmlEvent::*;
+            match self.reader.next().expect("failed to parse XML") {
+                StartElement {
+                    name, attributes, ..
+                } => {
+                    match &name.local_name[..] {
+                        "require" => continue,
+                        "command" => {
+                            let cmd = attr(&attributes, "name").unwrap();
+                            self.commands.get_mut(cmd).unwrap().version = Some(version);
+                        }
+                        "enum" => {
+                            // Values promoted from extensions keep their original extension number
+                            if attr(&attributes, "extends").is_some() {
+                                let ext_number = attr(&attributes, "extnumber")
+                                    .map(|x| x.parse::<i32>().unwrap());
+                                self.parse_enum_extension(&attributes, ext_number);
+                            }
+                        }
+                        "type" => {}
+                        _ => {
+                            eprintln!("unimplemented feature element: {}", name.local_name);
+                        }
+                    }
+                    self.finish_element();
+                }
+                EndElement { name } => {
+                    if name.local_name == "feature" {
+                        break;
+                    }
+                    if name.local_name != "require" {
+                        eprintln!("unexpected end element: {}", name);
+                    }
+                }
+                EndDocument => {
+                    panic!("unexpected end of document");
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Parse an `<enum>` adding a value to an existing enumeration or bitmask
+    fn parse_enum_extension(&mut self, attributes: &[OwnedAttribute], ext_number: Option<i32>) {
+        const EXT_BASE: i32 = 1_000_000_000;
+        const EXT_BLOCK_SIZE: i32 = 1000;
+
+        let name = attr(attributes, "name").unwrap();
+        let extends = attr(attributes, "extends").unwrap();
+        let value = if let Some(offset) = attr(attributes, "offset") {
+            let offset = offset.parse::<i32>().unwrap();
+            let sign = if attr(attributes, "dir") == Some("-") {
+                -1
+            } else {
+                1
+            };
+            let ext_number = ext_number.unwrap();
+            ConstantValue::Literal(sign * (EXT_BASE + (ext_number - 1) * EXT_BLOCK_SIZE + offset))
+        } else if let Some(bitpos) = attr(attributes, "bitpos") {
+            ConstantValue::Literal(bitpos.parse::<i32>().unwrap())
+        } else if let Some(value) = attr(attributes, "value") {
+            ConstantValue::Literal(value.parse::<i32>().unwrap())
+        } else {
+            ConstantValue::Alias(attr(attributes, "alias").unwrap().into())
+        };
+        let comment = attr(attributes, "comment").and_then(tidy_comment);
+        let bitmasks = &mut self.bitmasks;
+        if let Some(e) = self.enums.get_mut(extends) {
+            e.values.push(Constant {
+                name: name.into(),
+                value,
+                comment,
+            });
+        } else if let Some(e) = self
+            .bitvalues
+            .get(extends)
+            .and_then(|x| bitmasks.get_mut(x))
+        {
+            e.values.push(Constant {
+                name: name.into(),
+                value: match value {
+                    ConstantValue::Literal(x) => ConstantValue::Literal(x as u64),
+                    ConstantValue::Alias(x) => ConstantValue::Alias(x),
+                },
+                comment,
+            });
+        } else {
+            eprintln!("extension to unrecognized type {}", extends);
+        }
+    }
+
     fn parse_commands(&mut self) {
         loop {
             use XmlEvent::*;
@@ -418,6 +476,7 @@ impl Parser {
                 Command {
                     params,
                     extension: None,
+                    version: None,
                 },
             );
         } else {
@@ -512,10 +571,8 @@ impl Parser {
                     }
                     self.finish_element();
                 }
-                Characters(ch) => {
-                    if define_ty.is_some() || define_name.is_some() {
-                        define_val = Some(ch);
-                    }
+                Characters(ch) if define_ty.is_some() || define_name.is_some() => {
+                    define_val = Some(ch);
                 }
                 EndElement { name } => {
                     if name.local_name == "type" {
@@ -531,6 +588,14 @@ impl Parser {
                 self.api_constants.push((name.into(), val))
             }
         }
+        if let Some(version) = define_name
+            .as_ref()
+            .and_then(|x| x.strip_prefix("XR_API_VERSION_"))
+        {
+            let (major, minor) = version.split_once('_').unwrap();
+            self.api_versions
+                .push((major.parse().unwrap(), minor.parse().unwrap()));
+        }
         if define_name.as_ref().map(|x| &x[..]) == Some("XR_CURRENT_API_VERSION") {
             let version = define_val.unwrap();
             assert!(version.starts_with('('));
@@ -583,9 +648,12 @@ impl Parser {
         if let Some(ref parent) = parent {
             self.base_headers
                 .entry(parent.clone())
-                .or_insert_with(Vec::new)
+                .or_default()
                 .push(struct_name.into());
         }
+        let extends = attr(attrs, "structextends")
+            .map(|x| x.split(',').map(|x| x.into()).collect())
+            .unwrap_or_default();
         if let Some(target) = attr(attrs, "alias") {
             self.struct_aliases
                 .push((struct_name.into(), target.into()));
@@ -597,6 +665,7 @@ impl Parser {
                     ty,
                     extension: None,
                     mut_next,
+                    extends,
                 },
             );
         }
@@ -876,6 +945,23 @@ impl Parser {
         format!("[{}]({})", name, self.spec_link(&name))
     }
 
+    /// Documentation for a command, or `None` if it belongs to a disabled extension
+    fn command_doc(&self, name: &str, command: &Command) -> Option<String> {
+        Some(if let Some(ref ext) = command.extension {
+            if self.disabled_exts.contains(ext) {
+                return None;
+            }
+            format!(
+                "See {} - defined by [{}]({})",
+                self.doc_link(name),
+                ext,
+                self.spec_link(ext)
+            )
+        } else {
+            format!("See {}", self.doc_link(name))
+        })
+    }
+
     fn generate_sys(&self) -> TokenStream {
         let consts = self.api_constants.iter().map(|(name, value)| {
             let ident = Ident::new(&name[3..], Span::call_site());
@@ -883,6 +969,14 @@ impl Parser {
                 pub const #ident: usize = #value;
             }
         });
+        let const_aliases = self.api_aliases.iter().filter_map(|(alias, target)| {
+            self.api_constants.iter().find(|(name, _)| name == target)?;
+            let alias = Ident::new(&alias[3..], Span::call_site());
+            let target = Ident::new(&target[3..], Span::call_site());
+            Some(quote! {
+                pub const #alias: usize = #target;
+            })
+        });
 
         let enums = self.enums.iter().map(|(name, e)| {
             let ident = xr_ty_name(name);
@@ -922,7 +1016,10 @@ impl Parser {
                 })
             });
             let result_extras = if name == "XrResult" {
-                let cases = e.values.iter().map(|v| {
+                let cases = e.values.iter().filter_map(|v| {
+                    if matches!(v.value, ConstantValue::Alias(_)) {
+                        return None;
+                    }
                     let ident = xr_enum_value_name(name, &v.name);
                     let reason = v.comment.as_ref().map_or_else(
                         || ident.to_string(),
@@ -933,9 +1030,9 @@ impl Parser {
                             reason
                         },
                     );
-                    quote! {
+                    Some(quote! {
                         Self::#ident => Some(#reason)
-                    }
+                    })
                 });
                 quote! {
                     impl fmt::Display for #ident {
@@ -1119,7 +1216,7 @@ impl Parser {
             let alias = xr_ty_name(alias);
             let source = xr_ty_name(source);
             quote! {
-                type #alias = #source;
+                pub type #alias = #source;
             }
         });
 
@@ -1139,18 +1236,9 @@ impl Parser {
                         #ident: #ty
                     }
                 });
-                let doc = if let Some(ref ext) = command.extension {
-                    if self.disabled_exts.contains(ext) {
-                        return (quote! {}, quote! {});
-                    }
-                    format!(
-                        "See {} - defined by [{}]({})",
-                        self.doc_link(name),
-                        ext,
-                        self.spec_link(ext)
-                    )
-                } else {
-                    format!("See {}", self.doc_link(name))
+                let doc = match self.command_doc(name, command) {
+                    Some(x) => x,
+                    None => return (quote! {}, quote! {}),
                 };
                 let conditions = conditions(name, command.extension.as_ref().map(|x| &x[..]));
                 let conditions2 = conditions.clone();
@@ -1160,7 +1248,7 @@ impl Parser {
                     #[doc = #doc]
                     pub type #ident = unsafe extern "system" fn(#(#params),*) -> Result;
                 };
-                let proto = if command.extension.is_some() {
+                let proto = if command.extension.is_some() || !self.commands.contains_key(name) {
                     quote! {}
                 } else {
                     let fn_ident =
@@ -1199,6 +1287,15 @@ impl Parser {
         });
 
         let (major, minor, patch) = self.api_version.unwrap();
+        let api_versions = self.api_versions.iter().map(|&(major, minor)| {
+            let ident = Ident::new(
+                &format!("API_VERSION_{}_{}", major, minor),
+                Span::call_site(),
+            );
+            quote! {
+                pub const #ident: Version = Version::new(#major, #minor, #patch);
+            }
+        });
 
         quote! {
             //! Automatically generated code; do not edit!
@@ -1214,8 +1311,10 @@ impl Parser {
             use crate::*;
 
             pub const CURRENT_API_VERSION: Version = Version::new(#major, #minor, #patch);
+            #(#api_versions)*
 
             #(#consts)*
+            #(#const_aliases)*
             #(#enums)*
             #(#bitmasks)*
             #(#handles)*
@@ -1249,9 +1348,12 @@ impl Parser {
     /// Generate high-level code
     #[allow(clippy::cognitive_complexity)] // TODO
     fn generate_hl(&self) -> TokenStream {
-        let (instance_pfn_fields, instance_pfn_inits) = self.commands.iter().map(|(name, command)| {
+        // Commands of later core versions can only be loaded if that version was requested, so
+        // each gets its own table
+        let mut core_pfns = IndexMap::<(u16, u16), (Vec<TokenStream>, Vec<TokenStream>)>::new();
+        for (name, command) in &self.commands {
             if command.extension.is_some() {
-                return (quote! {}, quote! {});
+                continue;
             }
             let pfn_ident = xr_command_name(name);
             let field_ident = Ident::new(&pfn_ident.to_string().to_snake_case(), Span::call_site());
@@ -1262,8 +1364,38 @@ impl Parser {
             let init = quote! {
                 #field_ident: mem::transmute(entry.get_instance_proc_addr(instance, CStr::from_bytes_with_nul_unchecked(#c_name))?),
             };
-            (field, init)
-        }).unzip::<_, _, Vec<_>, Vec<_>>();
+            let (fields, inits) = core_pfns
+                .entry(command.version.unwrap_or((1, 0)))
+                .or_default();
+            fields.push(field);
+            inits.push(init);
+        }
+        let (instance_pfn_fields, instance_pfn_inits) = core_pfns.shift_remove(&(1, 0)).unwrap();
+        let later_instances = core_pfns.iter().map(|(&(major, minor), (fields, inits))| {
+            let ident = Ident::new(&format!("Instance{}_{}", major, minor), Span::call_site());
+            let doc = format!("Commands introduced in OpenXR {}.{}", major, minor);
+            quote! {
+                #[doc = #doc]
+                #[derive(Copy, Clone)]
+                pub struct #ident {
+                    #(#fields)*
+                }
+
+                impl #ident {
+                    /// Load the function pointer table
+                    ///
+                    /// # Safety
+                    ///
+                    /// `instance` must be a valid instance handle created with this API version or
+                    /// later.
+                    pub unsafe fn load(entry: &Entry, instance: sys::Instance) -> Result<Self> {
+                        Ok(Self {
+                            #(#inits)*
+                        })
+                    }
+                }
+            }
+        });
 
         let mut exts = Vec::new();
         let mut ext_fields = Vec::new();
@@ -1379,7 +1511,7 @@ impl Parser {
             .filter_map(|(name, s)| {
                 if s.extension
                     .as_ref()
-                    .map_or(false, |ext| self.disabled_exts.contains(ext))
+                    .is_some_and(|ext| self.disabled_exts.contains(ext))
                 {
                     return None;
                 }
@@ -1405,6 +1537,12 @@ impl Parser {
                     .map(|x| &x[..]),
             )
             .chain(self.bitmasks.keys().map(|x| &x[..]))
+            .chain(
+                self.struct_aliases
+                    .iter()
+                    .filter(|(_, target)| simple_structs.contains(&target[..]))
+                    .map(|(alias, _)| &alias[..]),
+            )
             .map(xr_ty_name);
 
         let mut event_cases = Vec::new();
@@ -1450,15 +1588,15 @@ impl Parser {
             struct_meta.insert(name, self.compute_meta(name, s));
         }
 
-        let polymorphic_builders = self.base_headers.iter().filter_map(|(name, children)| {
-            if name == "XrSwapchainImageBaseHeader"
-                || name == "XrEventDataBaseHeader"
-                || name == "XrLoaderInitInfoBaseHeaderKHR"
-            {
-                return None;
-            }
-            Some(self.generate_polymorphic_builders(&struct_meta, &simple_structs, name, children))
-        });
+        let polymorphic_bases = self
+            .base_headers
+            .iter()
+            .filter(|(name, _)| {
+                *name != "XrSwapchainImageBaseHeader"
+                    && *name != "XrEventDataBaseHeader"
+                    && *name != "XrLoaderInitInfoBaseHeaderKHR"
+            })
+            .collect::<Vec<_>>();
 
         let whitelist = [
             "XrCompositionLayerProjectionView",
@@ -1469,14 +1607,72 @@ impl Parser {
         .iter()
         .cloned()
         .collect::<HashSet<&str>>();
+
+        // Structures whose `next` chain can be extended: those with builders, and those whose
+        // hand-written counterparts accept a `NextChain`
+        let mut chain_roots = whitelist.clone();
+        chain_roots.extend(
+            polymorphic_bases
+                .iter()
+                .flat_map(|(_, children)| children.iter().map(|x| &x[..])),
+        );
+        chain_roots.insert("XrSwapchainCreateInfo");
+        let chain_exts = self
+            .structs
+            .iter()
+            .filter_map(|(name, s)| {
+                let roots = s
+                    .extends
+                    .iter()
+                    .flat_map(|x| match self.base_headers.get(x) {
+                        Some(children) => children.iter().map(|x| &x[..]).collect(),
+                        None => vec![&x[..]],
+                    })
+                    .filter(|x| chain_roots.contains(x))
+                    .collect::<Vec<&str>>();
+                if roots.is_empty() {
+                    None
+                } else {
+                    Some((&name[..], s, roots))
+                }
+            })
+            .collect::<Vec<_>>();
+        let extended = chain_exts
+            .iter()
+            .flat_map(|(_, _, roots)| roots.iter().cloned())
+            .collect::<HashSet<&str>>();
+
+        let polymorphic_builders = polymorphic_bases.iter().map(|(name, children)| {
+            self.generate_polymorphic_builders(
+                &struct_meta,
+                &simple_structs,
+                &extended,
+                name,
+                children,
+            )
+        });
+
         let builders = self.structs.iter().filter_map(|(name, s)| {
             if whitelist.contains(&name[..]) {
-                Some(self.generate_builder(&struct_meta, &simple_structs, name, s))
+                Some(self.generate_builder(&struct_meta, &simple_structs, &extended, name, s))
             } else {
                 None
             }
         });
 
+        let ext_builders = chain_exts.iter().map(|(name, s, roots)| {
+            let builder = if whitelist.contains(name) {
+                quote! {}
+            } else {
+                self.generate_builder(&struct_meta, &simple_structs, &extended, name, s)
+            };
+            let impls = self.generate_extends(&struct_meta, name, s, roots);
+            quote! {
+                #builder
+                #impls
+            }
+        });
+
         quote! {
             //! Automatically generated code; do not edit!
 
@@ -1484,7 +1680,7 @@ impl Parser {
             use std::borrow::Cow;
             use std::mem::MaybeUninit;
             pub use sys::{#(#reexports),*};
-            pub use sys::platform::{EGLenum, VkFilter, VkSamplerMipmapMode, VkSamplerAddressMode, VkComponentSwizzle};
+            pub use sys::platform::{EGLenum, VkFilter, VkFormat, VkSamplerMipmapMode, VkSamplerAddressMode, VkComponentSwizzle, VkImageCreateFlags, VkImageUsageFlags};
 
             use crate::*;
 
@@ -1594,6 +1790,8 @@ impl Parser {
                     }
                 }
 
+                #(#later_instances)*
+
                 #(#exts)*
             }
 
@@ -1605,6 +1803,550 @@ impl Parser {
 
                 #(#builders)*
                 #(#polymorphic_builders)*
+                #(#ext_builders)*
+            }
+        }
+    }
+
+    /// Generate the API layer dispatch table, trait, and entry points
+    fn generate_layer(&self) -> TokenStream {
+        // Commands the loader handles itself, or that every layer must implement specially, are
+        // excluded
+        let commands = self
+            .commands
+            .iter()
+            .filter_map(|(name, command)| {
+                let doc = self.command_doc(name, command)?;
+                let first = command.params.first()?;
+                if name == "xrGetInstanceProcAddr"
+                    || first.ptr_depth != 0
+                    || !self.handles.contains(&first.ty)
+                {
+                    return None;
+                }
+                Some((name, command, doc))
+            })
+            .collect::<Vec<_>>();
+
+        let mut fields = Vec::new();
+        let mut inits = Vec::new();
+        let mut methods = Vec::new();
+        let mut thunks = Vec::new();
+        let mut arms = Vec::new();
+        for &(name, command, ref doc) in &commands {
+            let ident = xr_command_name(name);
+            let snake = Ident::new(&ident.to_string().to_snake_case(), Span::call_site());
+            let conds = conditions(name, command.extension.as_ref().map(|x| &x[..]));
+            let c_name = c_name(name);
+            let params = command
+                .params
+                .iter()
+                .map(|param| {
+                    let ident = xr_var_name(&param.name);
+                    // Refer to structs by their canonical names
+                    let mut param = param.clone();
+                    if let Some((_, target)) = self
+                        .struct_aliases
+                        .iter()
+                        .find(|(alias, _)| *alias == param.ty)
+                    {
+                        param.ty = target.clone();
+                    }
+                    let ty = xr_arg_ty(self.api_aliases.as_ref(), &param);
+                    quote! { #ident: #ty }
+                })
+                .collect::<Vec<_>>();
+            let args = command
+                .params
+                .iter()
+                .map(|param| xr_var_name(&param.name))
+                .collect::<Vec<_>>();
+            let first = &args[0];
+            let first_ty = xr_ty_name(&command.params[0].ty);
+            let created = command.params[1..]
+                .iter()
+                .filter(|param| {
+                    param.ptr_depth == 1 && !param.is_const && self.handles.contains(&param.ty)
+                })
+                .map(|param| {
+                    let ident = xr_var_name(&param.name);
+                    let ty = xr_ty_name(&param.ty);
+                    quote! {
+                        if result.into_raw() >= 0 {
+                            super::created::<#first_ty, #ty>(#first.into_raw(), (*#ident).into_raw());
+                        }
+                    }
+                })
+                .collect::<Vec<_>>();
+            let destroyed = if name.starts_with("xrDestroy") {
+                quote! {
+                    if result.into_raw() >= 0 {
+                        super::destroyed::<#first_ty>(#first.into_raw());
+                    }
+                }
+            } else {
+                quote! {}
+            };
+            let call = quote! {
+                super::call::<#first_ty>(#first.into_raw(), |this, next| {
+                    this.#snake(next, #(#args),*)
+                })
+            };
+            let body = if created.is_empty() && !name.starts_with("xrDestroy") {
+                call
+            } else {
+                quote! {
+                    let result = #call;
+                    #(#created)*
+                    #destroyed
+                    result
+                }
+            };
+
+            fields.push(quote! {
+                #conds
+                pub #snake: Option<pfn::#ident>,
+            });
+            // Promoted commands may only be provided under their extension's name
+            let alias_names = self
+                .cmd_aliases
+                .iter()
+                .filter(|(_, target)| target == name)
+                .map(|(alias, _)| self::c_name(alias));
+            inits.push(quote! {
+                #conds
+                #snake: super::load(get_instance_proc_addr, instance, #c_name)
+                    #(.or_else(|| super::load(get_instance_proc_addr, instance, #alias_names)))*
+                    .map(|f| mem::transmute::<pfn::VoidFunction, pfn::#ident>(f)),
+            });
+            methods.push(quote! {
+                #conds
+                #[doc = #doc]
+                unsafe fn #snake(&self, next: &Dispatch, #(#params),*) -> Result {
+                    match next.#snake {
+                        Some(f) => f(#(#args),*),
+                        None => Result::ERROR_FUNCTION_UNSUPPORTED,
+                    }
+                }
+            });
+            thunks.push(quote! {
+                #conds
+                unsafe extern "system" fn #snake(#(#params),*) -> Result {
+                    #body
+                }
+            });
+        }
+        let aliases = self
+            .cmd_aliases
+            .iter()
+            .map(|(name, target)| (name, target))
+            .chain(commands.iter().map(|&(name, _, _)| (name, name)));
+        for (name, target) in aliases {
+            let command = match commands.iter().find(|x| x.0 == target) {
+                Some(&(_, command, _)) => command,
+                None => continue,
+            };
+            let ident = xr_command_name(target);
+            let snake = Ident::new(&ident.to_string().to_snake_case(), Span::call_site());
+            let conds = conditions(target, command.extension.as_ref().map(|x| &x[..]));
+            let lit = LitByteStr::new(name.as_bytes(), Span::call_site());
+            arms.push(quote! {
+                #conds
+                #lit => mem::transmute::<pfn::#ident, pfn::VoidFunction>(#snake),
+            });
+        }
+
+        quote! {
+            //! Automatically generated code; do not edit!
+
+            #![allow(unused, clippy::too_many_arguments, clippy::missing_safety_doc)]
+            use std::mem;
+            use std::os::raw::{c_char, c_void};
+            use libc::{timespec, wchar_t};
+
+            use sys::platform::*;
+            use sys::*;
+
+            /// Function pointers for the next link in the call chain
+            ///
+            /// Commands the next link doesn't provide, e.g. because their extension isn't enabled,
+            /// are `None`.
+            #[derive(Copy, Clone)]
+            pub struct Dispatch {
+                pub get_instance_proc_addr: pfn::GetInstanceProcAddr,
+                #(#fields)*
+            }
+
+            impl Dispatch {
+                /// Load all commands for `instance` from `get_instance_proc_addr`
+                ///
+                /// # Safety
+                ///
+                /// `get_instance_proc_addr` must be a valid `xrGetInstanceProcAddr` implementation and
+                /// `instance` a handle it recognizes.
+                pub unsafe fn load(get_instance_proc_addr: pfn::GetInstanceProcAddr, instance: Instance) -> Self {
+                    Self {
+                        get_instance_proc_addr,
+                        #(#inits)*
+                    }
+                }
+            }
+
+            /// An OpenXR API layer
+            ///
+            /// Every method corresponds to an OpenXR command, and receives the function pointers of
+            /// the next link in the call chain alongside the command's arguments. The default
+            /// implementations pass the call through unchanged.
+            ///
+            /// # Safety
+            ///
+            /// Methods receive raw pointers straight from the application, with the same validity
+            /// guarantees the OpenXR specification makes to runtimes.
+            pub trait Layer: Send + Sync + 'static {
+                #(#methods)*
+            }
+
+            #(#thunks)*
+
+            /// Look up the entry point for the command named `name`
+            pub(super) fn thunk(name: &[u8]) -> Option<pfn::VoidFunction> {
+                unsafe {
+                    Some(match name {
+                        #(#arms)*
+                        _ => return None,
+                    })
+                }
+            }
+        }
+    }
+
+    /// Generate the runtime trait and entry points
+    fn generate_runtime(&self) -> TokenStream {
+        // Only core commands are dispatched statically; the loader implements the rest itself
+        let commands = self
+            .commands
+            .iter()
+            .filter(|(name, command)| {
+                command.extension.is_none()
+                    && *name != "xrGetInstanceProcAddr"
+                    && *name != "xrEnumerateApiLayerProperties"
+            })
+            .collect::<Vec<_>>();
+
+        let mut methods = Vec::new();
+        let mut thunks = Vec::new();
+        let mut arms = Vec::new();
+        for &(name, command) in &commands {
+            let ident = xr_command_name(name);
+            let snake = Ident::new(&ident.to_string().to_snake_case(), Span::call_site());
+            let doc = self.command_doc(name, command).unwrap();
+            let params = command
+                .params
+                .iter()
+                .map(|param| {
+                    let ident = xr_var_name(&param.name);
+                    let ty = xr_arg_ty(self.api_aliases.as_ref(), param);
+                    quote! { #ident: #ty }
+                })
+                .collect::<Vec<_>>();
+            let args = command
+                .params
+                .iter()
+                .map(|param| xr_var_name(&param.name))
+                .collect::<Vec<_>>();
+            let lit = LitByteStr::new(name.as_bytes(), Span::call_site());
+
+            methods.push(quote! {
+                #[doc = #doc]
+                unsafe fn #snake(&self, #(#params),*) -> Result {
+                    Result::ERROR_FUNCTION_UNSUPPORTED
+                }
+            });
+            thunks.push(quote! {
+                unsafe extern "system" fn #snake(#(#params),*) -> Result {
+                    super::call(|this| this.#snake(#(#args),*))
+                }
+            });
+            arms.push(quote! {
+                #lit => mem::transmute::<pfn::#ident, pfn::VoidFunction>(#snake),
+            });
+        }
+
+        let handles = self.handles.iter().map(|name| {
+            let ident = xr_ty_name(name);
+            quote! {
+                impl super::Handle for #ident {
+                    fn from_raw(raw: u64) -> Self {
+                        Self::from_raw(raw)
+                    }
+                    fn into_raw(self) -> u64 {
+                        self.into_raw()
+                    }
+                }
+            }
+        });
+
+        quote! {
+            //! Automatically generated code; do not edit!
+
+            #![allow(unused, clippy::too_many_arguments, clippy::missing_safety_doc)]
+            use std::ffi::CStr;
+            use std::mem;
+            use std::os::raw::{c_char, c_void};
+
+            use sys::*;
+
+            /// An OpenXR runtime
+            ///
+            /// Every method but the last corresponds to a core OpenXR command, and returns
+            /// `ERROR_FUNCTION_UNSUPPORTED` unless overridden.
+            ///
+            /// # Safety
+            ///
+            /// Methods receive raw pointers straight from the application, with the validity
+            /// guarantees the OpenXR specification makes to runtimes.
+            pub trait Runtime: Send + Sync + 'static {
+                #(#methods)*
+
+                /// Look up an extension command for `instance`
+                ///
+                /// Called by `xrGetInstanceProcAddr` for commands outside the core set. Returning
+                /// `None` reports the command as unsupported.
+                unsafe fn extension_proc_addr(&self, instance: Instance, name: &CStr) -> Option<pfn::VoidFunction> {
+                    None
+                }
+            }
+
+            #(#thunks)*
+
+            /// Look up the entry point for the core command named `name`
+            pub(super) fn thunk(name: &[u8]) -> Option<pfn::VoidFunction> {
+                unsafe {
+                    Some(match name {
+                        #(#arms)*
+                        _ => return None,
+                    })
+                }
+            }
+
+            #(#handles)*
+        }
+    }
+
+    /// Generate the interposers that record and replay calls
+    fn generate_trace(&self) -> TokenStream {
+        let commands = self
+            .commands
+            .iter()
+            .filter(|&(name, command)| {
+                name != "xrGetInstanceProcAddr" && self.command_doc(name, command).is_some()
+            })
+            .collect::<Vec<_>>();
+        // Refer to structs by their canonical names
+        let resolve = |ty: &str| -> String {
+            match self.struct_aliases.iter().find(|(alias, _)| alias == ty) {
+                Some((_, target)) => target.clone(),
+                None => ty.into(),
+            }
+        };
+        // Whether `ty` begins with `type` and `next`, so its extent can be found at run time
+        let is_chained = |ty: &str| {
+            self.structs
+                .get(ty)
+                .and_then(|s| s.members.first())
+                .is_some_and(|m| m.ty == "XrStructureType")
+        };
+
+        let mut fields = Vec::new();
+        let mut inits = Vec::new();
+        let mut thunks = Vec::new();
+        let mut arms = Vec::new();
+        for &(name, command) in &commands {
+            let ident = xr_command_name(name);
+            let snake = Ident::new(&ident.to_string().to_snake_case(), Span::call_site());
+            let conds = conditions(name, command.extension.as_ref().map(|x| &x[..]));
+            let c_name = c_name(name);
+            let lit = LitByteStr::new(name.as_bytes(), Span::call_site());
+            let params = command
+                .params
+                .iter()
+                .map(|param| {
+                    let ident = xr_var_name(&param.name);
+                    let mut param = param.clone();
+                    param.ty = resolve(&param.ty);
+                    let ty = xr_arg_ty(self.api_aliases.as_ref(), &param);
+                    quote! { #ident: #ty }
+                })
+                .collect::<Vec<_>>();
+            let args = command
+                .params
+                .iter()
+                .map(|param| xr_var_name(&param.name))
+                .collect::<Vec<_>>();
+            let handle = match command.params.first() {
+                Some(param) if param.ptr_depth == 0 && self.handles.contains(&param.ty) => {
+                    let ident = xr_var_name(&param.name);
+                    quote! { #ident.into_raw() }
+                }
+                _ => quote! { 0 },
+            };
+            // Array lengths reported by the runtime are recorded alongside their arrays
+            let counts = command
+                .params
+                .iter()
+                .filter_map(|param| param.len.as_ref()?.first())
+                .filter(|len| len.ends_with("CapacityInput"))
+                .map(|len| len.replace("CapacityInput", "CountOutput"))
+                .collect::<Vec<_>>();
+            let mut inputs = Vec::new();
+            let mut outputs = Vec::new();
+            for param in &command.params {
+                let ident = xr_var_name(&param.name);
+                let chained = is_chained(&resolve(&param.ty));
+                let capacity = param
+                    .len
+                    .as_ref()
+                    .and_then(|x| x.first())
+                    .filter(|x| x.ends_with("CapacityInput"));
+                if (param.ptr_depth == 0 && param.static_array_len.is_none())
+                    || param.ty == "void"
+                    || param.ty == "IUnknown"
+                {
+                    // Opaque pointers are recorded by address
+                    inputs.push(quote! { inputs.value(&#ident); });
+                } else if param.is_const {
+                    inputs.push(if param.ty == "char" {
+                        quote! { inputs.string(#ident); }
+                    } else if chained {
+                        quote! { inputs.chain(#ident); }
+                    } else {
+                        quote! { inputs.pointer(#ident); }
+                    });
+                } else if let Some(len) = param.static_array_len.as_ref() {
+                    let len = Ident::new(&len[3..], Span::call_site());
+                    outputs.push(quote! { outputs.fixed(#ident, #len); });
+                } else if let Some(capacity) = capacity {
+                    let count = xr_var_name(&capacity.replace("CapacityInput", "CountOutput"));
+                    let capacity = xr_var_name(capacity);
+                    outputs.push(if chained {
+                        quote! { outputs.chain_array(#capacity, #count, #ident); }
+                    } else {
+                        quote! { outputs.array(#capacity, #count, #ident); }
+                    });
+                } else if !counts.contains(&param.name) {
+                    outputs.push(if chained {
+                        quote! { outputs.chain(#ident); }
+                    } else {
+                        quote! { outputs.pointer(#ident); }
+                    });
+                }
+            }
+            let outputs = if outputs.is_empty() {
+                quote! { |_| {} }
+            } else {
+                quote! { |outputs| { #(#outputs)* } }
+            };
+
+            fields.push(quote! {
+                #conds
+                #snake: Option<pfn::#ident>,
+            });
+            // Promoted commands may only be provided under their extension's name
+            let alias_names = self
+                .cmd_aliases
+                .iter()
+                .filter(|(_, target)| target == name)
+                .map(|(alias, _)| self::c_name(alias));
+            inits.push(quote! {
+                #conds
+                #snake: super::load(get_instance_proc_addr, instance, #c_name)
+                    #(.or_else(|| super::load(get_instance_proc_addr, instance, #alias_names)))*
+                    .map(|f| mem::transmute::<pfn::VoidFunction, pfn::#ident>(f)),
+            });
+            thunks.push(quote! {
+                #conds
+                unsafe extern "system" fn #snake(#(#params),*) -> Result {
+                    super::call(
+                        #name,
+                        #handle,
+                        |inputs| { #(#inputs)* },
+                        |next| match next.#snake {
+                            Some(f) => f(#(#args),*),
+                            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+                        },
+                        #outputs,
+                    )
+                }
+            });
+            let alias_lits = self
+                .cmd_aliases
+                .iter()
+                .filter(|(_, target)| target == name)
+                .map(|(alias, _)| LitByteStr::new(alias.as_bytes(), Span::call_site()));
+            arms.push(quote! {
+                #conds
+                #lit #(| #alias_lits)* => mem::transmute::<pfn::#ident, pfn::VoidFunction>(#snake),
+            });
+        }
+
+        let sizes = self.structs.iter().filter_map(|(name, s)| {
+            let ty = s.ty.as_ref()?;
+            if let Some(ref ext) = s.extension {
+                if self.disabled_exts.contains(ext) {
+                    return None;
+                }
+            }
+            let conds = conditions(name, s.extension.as_ref().map(|x| &x[..]));
+            let ident = xr_ty_name(name);
+            let ty = xr_enum_value_name("XrStructureType", ty);
+            Some(quote! {
+                #conds
+                StructureType::#ty => mem::size_of::<#ident>(),
+            })
+        });
+
+        quote! {
+            //! Automatically generated code; do not edit!
+
+            #![allow(unused, clippy::too_many_arguments)]
+            use std::mem;
+            use std::os::raw::{c_char, c_void};
+            use libc::{timespec, wchar_t};
+
+            use sys::platform::*;
+            use sys::*;
+
+            /// Function pointers of the traced implementation
+            pub(super) struct Next {
+                #(#fields)*
+            }
+
+            impl Next {
+                pub(super) unsafe fn load(get_instance_proc_addr: pfn::GetInstanceProcAddr, instance: Instance) -> Self {
+                    Self {
+                        #(#inits)*
+                    }
+                }
+            }
+
+            #(#thunks)*
+
+            /// Look up the entry point for the command named `name`
+            pub(super) fn thunk(name: &[u8]) -> Option<pfn::VoidFunction> {
+                unsafe {
+                    Some(match name {
+                        #(#arms)*
+                        _ => return None,
+                    })
+                }
+            }
+
+            /// Size of the structure identified by `ty`
+            pub(super) fn struct_size(ty: StructureType) -> Option<usize> {
+                Some(match ty {
+                    #(#sizes)*
+                    _ => return None,
+                })
             }
         }
     }
@@ -1622,7 +2364,7 @@ impl Parser {
             out.has_graphics |= member.ty == "XrSession" || member.ty == "XrSwapchain";
             out.has_array |= member.static_array_len.is_some();
             if member.ty != name {
-                if let Some(x) = self.structs.get(&member.ty) {
+                if let Some(x) = self.get_struct(&member.ty) {
                     out |= self.compute_meta(&member.ty, x);
                 }
             }
@@ -1634,6 +2376,7 @@ impl Parser {
         &self,
         meta: &HashMap<&str, StructMeta>,
         simple: &IndexSet<&str>,
+        extended: &HashSet<&str>,
         base_name: &str,
         children: &[String],
     ) -> TokenStream {
@@ -1651,6 +2394,11 @@ impl Parser {
             let conds = conditions(name, s.extension.as_ref().map(|x| &x[..]));
             let inits = self.generate_builder_inits(s);
             let setters = self.generate_setters(meta, simple, s);
+            let push_next = if extended.contains(&name[..]) {
+                generate_push_next()
+            } else {
+                quote! {}
+            };
             quote! {
                 #conds
                 #[derive(Copy, Clone)]
@@ -1697,6 +2445,7 @@ impl Parser {
                     }
 
                     #setters
+                    #push_next
                 }
                 #conds
                 impl #type_params Deref for #ident #type_args {
@@ -1759,6 +2508,15 @@ impl Parser {
                     quote! { bool },
                     quote! { self.inner.#ident = value.into(); },
                 ),
+                // Swapchains are only referred to through a released image, so that layers can't
+                // submit a swapchain with nothing rendered to it
+                "XrSwapchain" => {
+                    assert!(m.len.is_none());
+                    (
+                        quote! { &ReleasedImage<'a, G> },
+                        quote! { self.inner.#ident = value.swapchain().as_raw(); },
+                    )
+                }
                 x if self.handles.contains(x) => {
                     assert!(m.len.is_none());
                     let ty = xr_var_ty(self.api_aliases.as_ref(), m);
@@ -1854,15 +2612,22 @@ impl Parser {
         &self,
         meta: &HashMap<&str, StructMeta>,
         simple: &IndexSet<&str>,
+        extended: &HashSet<&str>,
         name: &str,
         s: &Struct,
     ) -> TokenStream {
         let setters = self.generate_setters(meta, simple, s);
+        let push_next = if extended.contains(name) {
+            generate_push_next()
+        } else {
+            quote! {}
+        };
         let ident = xr_ty_name(name);
         let (type_params, type_args, marker, marker_init) = meta.get(name).unwrap().type_params();
         let inits = self.generate_builder_inits(s);
         let conds = conditions(name, s.extension.as_ref().map(|x| &x[..]));
         let conds2 = conds.clone();
+        let conds3 = conds.clone();
         quote! {
             #[derive(Copy, Clone)]
             #[repr(transparent)]
@@ -1909,8 +2674,10 @@ impl Parser {
                 }
 
                 #setters
+                #push_next
             }
 
+            #conds3
             impl #type_params Default for #ident #type_args {
                 fn default() -> Self {
                     Self::new()
@@ -1919,6 +2686,59 @@ impl Parser {
         }
     }
 
+    /// Mark the builder for `name` as valid in the `next` chain of each of `roots`
+    fn generate_extends(
+        &self,
+        meta: &HashMap<&str, StructMeta>,
+        name: &str,
+        s: &Struct,
+        roots: &[&str],
+    ) -> TokenStream {
+        let ident = xr_ty_name(name);
+        let ext_meta = meta.get(name).unwrap();
+        let conds = conditions(name, s.extension.as_ref().map(|x| &x[..]));
+        let impls = roots.iter().map(|&root| {
+            let root_ident = xr_ty_name(root);
+            // `SwapchainCreateInfo` is hand-written, and has no lifetime
+            let root_meta = match root {
+                "XrSwapchainCreateInfo" => StructMeta {
+                    has_graphics: true,
+                    ..StructMeta::default()
+                },
+                _ => *meta.get(root).unwrap(),
+            };
+            let mut params = Vec::new();
+            let mut ext_args = Vec::new();
+            let mut root_args = Vec::new();
+            if ext_meta.has_pointer {
+                params.push(quote! { 'a });
+                ext_args.push(quote! { 'a });
+            }
+            if root_meta.has_pointer {
+                params.push(quote! { 'b });
+                root_args.push(quote! { 'b });
+            }
+            if ext_meta.has_graphics || root_meta.has_graphics {
+                params.push(quote! { G: Graphics });
+            }
+            if ext_meta.has_graphics {
+                ext_args.push(quote! { G });
+            }
+            if root_meta.has_graphics {
+                root_args.push(quote! { G });
+            }
+            let root_conds = conditions(root, self.structs[root].extension.as_ref().map(|x| &x[..]));
+            quote! {
+                #conds
+                #root_conds
+                unsafe impl<#(#params),*> Extends<#root_ident<#(#root_args),*>> for #ident<#(#ext_args),*> {}
+            }
+        });
+        quote! {
+            #(#impls)*
+        }
+    }
+
     fn generate_reader(&self, ident: &Ident, raw_ident: &Ident, s: &Struct) -> TokenStream {
         let lens = s
             .members
@@ -1990,12 +2810,21 @@ impl Parser {
                 && x.static_array_len.is_none()
                 && x.ty != "XrBool32"
                 && self
-                    .structs
-                    .get(&x.ty)
+                    .get_struct(&x.ty)
                     .map_or(true, |x| self.is_simple_struct(x))
                 && !self.handles.contains(&x.ty)
         })
     }
+
+    /// Look up a struct by name or alias
+    fn get_struct(&self, name: &str) -> Option<&Struct> {
+        let name = self
+            .struct_aliases
+            .iter()
+            .find(|(alias, _)| alias == name)
+            .map_or(name, |(_, target)| target);
+        self.structs.get(name)
+    }
 }
 
 #[derive(Debug, Copy, Clone, Default)]
@@ -2060,6 +2889,8 @@ struct Tag {
 struct Command {
     params: Vec<Member>,
     extension: Option<Rc<str>>,
+    /// Core API version that introduced the command, e.g. `(1, 1)`
+    version: Option<(u16, u16)>,
 }
 
 #[derive(Debug)]
@@ -2095,6 +2926,8 @@ struct Struct {
     extension: Option<Rc<str>>,
     ty: Option<String>,
     mut_next: bool,
+    /// Structures whose `next` chain this may appear in
+    extends: Vec<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -2217,9 +3050,8 @@ fn xr_var_ty(api_aliases: &[(String, String)], member: &Member) -> TokenStream {
         quote! { #ident }
     };
     let mut ty = if let Some(ref len) = member.static_array_len {
-        // If the len is a constant, we see if it is an aliased constant. Since we do not emit
-        // aliased constant declarations, we must replace the alias with the original constant name
-        // that we do emit.
+        // If the len is a constant, we see if it is an aliased constant, and refer to the
+        // original constant instead.
         let len = match api_aliases.iter().find(|(alias, _)| alias == len) {
             Some((_, og)) => og,
             _ => len,
@@ -2249,6 +3081,20 @@ fn xr_command_name(raw: &str) -> Ident {
     Ident::new(&raw[start..], Span::call_site())
 }
 
+/// Builder method linking an extension structure into the `next` chain
+fn generate_push_next() -> TokenStream {
+    quote! {
+        /// Prepend `value` to the `next` chain, replacing any chain it was previously linked into
+        #[inline]
+        pub fn push_next<T: Extends<Self>>(mut self, value: &'a mut T) -> Self {
+            unsafe {
+                push_next(&mut self.inner.next as *mut _ as _, value as *mut T as _);
+            }
+            self
+        }
+    }
+}
+
 fn conditions(name: &str, ext: Option<&str>) -> TokenStream {
     let name = name.to_lowercase();
     let mut conditions = Vec::new();
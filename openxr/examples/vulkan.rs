@@ -68,6 +68,7 @@ pub fn main() {
                 application_version: 0,
                 engine_name: "openxrs example",
                 engine_version: 0,
+                api_version: None,
             },
             &enabled_extensions,
             &[],
@@ -122,7 +123,10 @@ pub fn main() {
             let vk_instance = xr_instance
                 .create_vulkan_instance(
                     system,
-                    std::mem::transmute(vk_entry.static_fn().get_instance_proc_addr),
+                    std::mem::transmute::<
+                        vk::PFN_vkGetInstanceProcAddr,
+                        xr::sys::platform::VkGetInstanceProcAddr,
+                    >(vk_entry.static_fn().get_instance_proc_addr),
                     &vk::InstanceCreateInfo::builder().application_info(&vk_app_info) as *const _
                         as *const _,
                 )
@@ -164,7 +168,10 @@ pub fn main() {
             let vk_device = xr_instance
                 .create_vulkan_device(
                     system,
-                    std::mem::transmute(vk_entry.static_fn().get_instance_proc_addr),
+                    std::mem::transmute::<
+                        vk::PFN_vkGetInstanceProcAddr,
+                        xr::sys::platform::VkGetInstanceProcAddr,
+                    >(vk_entry.static_fn().get_instance_proc_addr),
                     vk_physical_device.as_raw() as _,
                     &vk::DeviceCreateInfo::builder()
                         .queue_create_infos(&[vk::DeviceQueueCreateInfo::builder()
@@ -327,7 +334,7 @@ pub fn main() {
 
         // A session represents this application's desire to display things! This is where we hook
         // up our graphics API. This does not start the session; for that, you'll need a call to
-        // Session::begin, which we do in 'main_loop below.
+        // Session::begin, which the SessionDriver in the main loop below makes for us.
         let (session, mut frame_wait, mut frame_stream) = xr_instance
             .create_session::<xr::Vulkan>(
                 system,
@@ -427,76 +434,49 @@ pub fn main() {
 
         // Main loop
         let mut swapchain = None;
-        let mut event_storage = xr::EventDataBuffer::new();
-        let mut session_running = false;
+        // Begins and ends the session as the runtime's state changes dictate
+        let mut driver = xr::SessionDriver::new(session.clone(), VIEW_TYPE);
         // Index of the current frame, wrapped by PIPELINE_DEPTH. Not to be confused with the
         // swapchain image index.
         let mut frame = 0;
-        'main_loop: loop {
+        loop {
             if !running.load(Ordering::Relaxed) {
-                println!("requesting exit");
                 // The OpenXR runtime may want to perform a smooth transition between scenes, so we
                 // can't necessarily exit instantly. Instead, we must notify the runtime of our
                 // intent and wait for it to tell us when we're actually done.
-                match session.request_exit() {
-                    Ok(()) => {}
-                    Err(xr::sys::Result::ERROR_SESSION_NOT_RUNNING) => break,
-                    Err(e) => panic!("{}", e),
-                }
+                driver.request_exit().unwrap();
             }
 
-            while let Some(event) = xr_instance.poll_event(&mut event_storage).unwrap() {
-                use xr::Event::*;
-                match event {
-                    SessionStateChanged(e) => {
-                        // Session state change is where we can begin and end sessions, as well as
-                        // find quit messages!
+            let status = driver
+                .poll_with(|event| {
+                    if let xr::Event::SessionStateChanged(e) = event {
                         println!("entered state {:?}", e.state());
-                        match e.state() {
-                            xr::SessionState::READY => {
-                                session.begin(VIEW_TYPE).unwrap();
-                                session_running = true;
-                            }
-                            xr::SessionState::STOPPING => {
-                                session.end().unwrap();
-                                session_running = false;
-                            }
-                            xr::SessionState::EXITING | xr::SessionState::LOSS_PENDING => {
-                                break 'main_loop;
-                            }
-                            _ => {}
-                        }
-                    }
-                    InstanceLossPending(_) => {
-                        break 'main_loop;
-                    }
-                    EventsLost(e) => {
-                        println!("lost {} events", e.lost_event_count());
                     }
-                    _ => {}
+                })
+                .unwrap();
+            match status {
+                xr::FrameLoopStatus::Idle => {
+                    // Don't grind up the CPU
+                    std::thread::sleep(Duration::from_millis(100));
+                    continue;
+                }
+                xr::FrameLoopStatus::Running => {}
+                xr::FrameLoopStatus::Exit(reason) => {
+                    println!("exiting: {:?}", reason);
+                    break;
                 }
-            }
-
-            if !session_running {
-                // Don't grind up the CPU
-                std::thread::sleep(Duration::from_millis(100));
-                continue;
             }
 
             // Block until the previous frame is finished displaying, and is ready for another one.
             // Also returns a prediction of when the next frame will be displayed, for use with
             // predicting locations of controllers, viewpoints, etc.
-            let xr_frame_state = frame_wait.wait().unwrap();
+            let xr_frame = frame_wait.wait().unwrap();
             // Must be called before any rendering is done!
-            frame_stream.begin().unwrap();
+            let xr_frame = frame_stream.begin(xr_frame).unwrap();
 
-            if !xr_frame_state.should_render {
+            if !xr_frame.should_render {
                 frame_stream
-                    .end(
-                        xr_frame_state.predicted_display_time,
-                        environment_blend_mode,
-                        &[],
-                    )
+                    .end(xr_frame, environment_blend_mode, &[])
                     .unwrap();
                 continue;
             }
@@ -583,8 +563,10 @@ pub fn main() {
             });
 
             // We need to ask which swapchain image to use for rendering! Which one will we get?
-            // Who knows! It's up to the runtime to decide.
-            let image_index = swapchain.handle.acquire_image().unwrap();
+            // Who knows! It's up to the runtime to decide. Its index is only revealed once it's
+            // available to render to, since the compositor could still be reading from it.
+            let mut image = swapchain.handle.acquire_image().unwrap();
+            let image_index = image.wait(xr::Duration::INFINITE).unwrap().unwrap();
 
             // Ensure the last use of this frame's resources is 100% done
             vk_device
@@ -645,11 +627,11 @@ pub fn main() {
 
             // Find where our controllers are located in the Stage space
             let right_location = right_space
-                .locate(&stage, xr_frame_state.predicted_display_time)
+                .locate(&stage, xr_frame.predicted_display_time)
                 .unwrap();
 
             let left_location = left_space
-                .locate(&stage, xr_frame_state.predicted_display_time)
+                .locate(&stage, xr_frame.predicted_display_time)
                 .unwrap();
 
             let mut printed = false;
@@ -682,13 +664,9 @@ pub fn main() {
             // to the GPU just-in-time by writing them to per-frame host-visible memory which the
             // GPU will only read once the command buffer is submitted.
             let (_, views) = session
-                .locate_views(VIEW_TYPE, xr_frame_state.predicted_display_time, &stage)
+                .locate_views(VIEW_TYPE, xr_frame.predicted_display_time, &stage)
                 .unwrap();
 
-            // Wait until the image is available to render to before beginning work on the GPU. The
-            // compositor could still be reading from it.
-            swapchain.handle.wait_image(xr::Duration::INFINITE).unwrap();
-
             // Submit commands to the GPU, then tell OpenXR we're done with our part.
             vk_device
                 .queue_submit(
@@ -697,7 +675,7 @@ pub fn main() {
                     fences[frame],
                 )
                 .unwrap();
-            swapchain.handle.release_image().unwrap();
+            let image = image.release().unwrap();
 
             // Tell OpenXR what to present for this frame
             let rect = xr::Rect2Di {
@@ -709,28 +687,18 @@ pub fn main() {
             };
             frame_stream
                 .end(
-                    xr_frame_state.predicted_display_time,
+                    xr_frame,
                     environment_blend_mode,
                     &[
                         &xr::CompositionLayerProjection::new().space(&stage).views(&[
                             xr::CompositionLayerProjectionView::new()
                                 .pose(views[0].pose)
                                 .fov(views[0].fov)
-                                .sub_image(
-                                    xr::SwapchainSubImage::new()
-                                        .swapchain(&swapchain.handle)
-                                        .image_array_index(0)
-                                        .image_rect(rect),
-                                ),
+                                .sub_image(image.sub_image().image_array_index(0).image_rect(rect)),
                             xr::CompositionLayerProjectionView::new()
                                 .pose(views[1].pose)
                                 .fov(views[1].fov)
-                                .sub_image(
-                                    xr::SwapchainSubImage::new()
-                                        .swapchain(&swapchain.handle)
-                                        .image_array_index(1)
-                                        .image_rect(rect),
-                                ),
+                                .sub_image(image.sub_image().image_array_index(1).image_rect(rect)),
                         ]),
                     ],
                 )
@@ -741,6 +709,7 @@ pub fn main() {
         // OpenXR MUST be allowed to clean up before we destroy Vulkan resources it could touch, so
         // first we must drop all its handles.
         drop((
+            driver,
             session,
             frame_wait,
             frame_stream,
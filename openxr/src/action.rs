@@ -47,9 +47,18 @@ impl<T: ActionTy> Action<T> {
             next: ptr::null(),
             action: self.as_raw(),
         };
-        get_arr(|cap, count, buf| unsafe {
-            (self.fp().enumerate_bound_sources_for_action)(session.as_raw(), &info, cap, count, buf)
-        })
+        get_arr(
+            "xrEnumerateBoundSourcesForAction",
+            |cap, count, buf| unsafe {
+                (self.fp().enumerate_bound_sources_for_action)(
+                    session.as_raw(),
+                    &info,
+                    cap,
+                    count,
+                    buf,
+                )
+            },
+        )
     }
 
     // Private helper
@@ -92,11 +101,10 @@ impl Action<Posef> {
         };
         let mut out = sys::Space::NULL;
         unsafe {
-            cvt((self.fp().create_action_space)(
-                session.as_raw(),
-                &info,
-                &mut out,
-            ))?;
+            cvt(
+                "xrCreateActionSpace",
+                (self.fp().create_action_space)(session.as_raw(), &info, &mut out),
+            )?;
             Ok(Space::action_from_raw(self.clone(), session, out))
         }
     }
@@ -110,11 +118,10 @@ impl Action<Posef> {
         };
         let out = unsafe {
             let mut out = sys::ActionStatePose::out(ptr::null_mut());
-            cvt((self.fp().get_action_state_pose)(
-                session.as_raw(),
-                &info,
-                out.as_mut_ptr(),
-            ))?;
+            cvt(
+                "xrGetActionStatePose",
+                (self.fp().get_action_state_pose)(session.as_raw(), &info, out.as_mut_ptr()),
+            )?;
             out.assume_init()
         };
         Ok(out.is_active.into())
@@ -135,11 +142,10 @@ impl Action<Haptic> {
             subaction_path,
         };
         unsafe {
-            cvt((self.fp().apply_haptic_feedback)(
-                session.as_raw(),
-                &info,
-                event as *const _ as _,
-            ))?;
+            cvt(
+                "xrApplyHapticFeedback",
+                (self.fp().apply_haptic_feedback)(session.as_raw(), &info, event as *const _ as _),
+            )?;
         }
         Ok(())
     }
@@ -152,7 +158,10 @@ impl Action<Haptic> {
             subaction_path,
         };
         unsafe {
-            cvt((self.fp().stop_haptic_feedback)(session.as_raw(), &info))?;
+            cvt(
+                "xrStopHapticFeedback",
+                (self.fp().stop_haptic_feedback)(session.as_raw(), &info),
+            )?;
         }
         Ok(())
     }
@@ -198,11 +207,10 @@ impl ActionInput for bool {
         };
         unsafe {
             let mut out = sys::ActionStateBoolean::out(ptr::null_mut());
-            cvt((action.fp().get_action_state_boolean)(
-                session.as_raw(),
-                &info,
-                out.as_mut_ptr(),
-            ))?;
+            cvt(
+                "xrGetActionStateBoolean",
+                (action.fp().get_action_state_boolean)(session.as_raw(), &info, out.as_mut_ptr()),
+            )?;
             let out = out.assume_init();
             Ok(ActionState {
                 current_state: out.current_state.into(),
@@ -232,11 +240,10 @@ impl ActionInput for f32 {
         };
         unsafe {
             let mut out = sys::ActionStateFloat::out(ptr::null_mut());
-            cvt((action.fp().get_action_state_float)(
-                session.as_raw(),
-                &info,
-                out.as_mut_ptr(),
-            ))?;
+            cvt(
+                "xrGetActionStateFloat",
+                (action.fp().get_action_state_float)(session.as_raw(), &info, out.as_mut_ptr()),
+            )?;
             let out = out.assume_init();
             Ok(ActionState {
                 current_state: out.current_state,
@@ -266,11 +273,10 @@ impl ActionInput for Vector2f {
         };
         unsafe {
             let mut out = sys::ActionStateVector2f::out(ptr::null_mut());
-            cvt((action.fp().get_action_state_vector2f)(
-                session.as_raw(),
-                &info,
-                out.as_mut_ptr(),
-            ))?;
+            cvt(
+                "xrGetActionStateVector2f",
+                (action.fp().get_action_state_vector2f)(session.as_raw(), &info, out.as_mut_ptr()),
+            )?;
             let out = out.assume_init();
             Ok(ActionState {
                 current_state: out.current_state,
@@ -53,11 +53,10 @@ impl ActionSet {
             .action_type(T::TYPE);
         unsafe {
             let mut out = sys::Action::NULL;
-            cvt((self.fp().create_action)(
-                self.as_raw(),
-                info.as_raw(),
-                &mut out,
-            ))?;
+            cvt(
+                "xrCreateAction",
+                (self.fp().create_action)(self.as_raw(), info.as_raw(), &mut out),
+            )?;
             Ok(Action::from_raw(self.clone(), out))
         }
     }
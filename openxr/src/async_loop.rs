@@ -0,0 +1,282 @@
+use std::{
+    future::Future,
+    io,
+    pin::Pin,
+    sync::{mpsc, Arc, Condvar, Mutex, MutexGuard},
+    task::{Context, Poll, Waker},
+    thread,
+};
+
+use crate::*;
+
+/// Waits for frames on a dedicated thread, so frame pacing doesn't block an async executor
+///
+/// Each [`wait`](Self::wait) asks the thread for one call to [`FrameWaiter::wait`]. As with the
+/// blocking interface, the resulting [`FrameToken`] should be passed to [`FrameStream::begin`]
+/// before the next frame is waited for.
+///
+/// # Example
+///
+/// ```no_run
+/// # async fn dummy<G: openxr::RendersFrames>(
+/// #     frame_waiter: openxr::FrameWaiter,
+/// #     frame_stream: &mut openxr::FrameStream<G>,
+/// # ) -> Result<(), Box<dyn std::error::Error>> {
+/// let mut frame_waiter = openxr::AsyncFrameWaiter::new(frame_waiter)?;
+/// loop {
+///     let frame = frame_waiter.wait().await?;
+///     let frame = frame_stream.begin(frame)?;
+///     // render if frame.should_render...
+///     frame_stream.end(frame, openxr::EnvironmentBlendMode::OPAQUE, &[])?;
+/// }
+/// # }
+/// ```
+pub struct AsyncFrameWaiter {
+    requests: mpsc::Sender<()>,
+    slot: Arc<Mutex<WaitSlot>>,
+    /// Whether a wait was requested whose result hasn't been returned yet
+    pending: bool,
+}
+
+struct WaitSlot {
+    result: Option<Result<FrameToken>>,
+    waker: Option<Waker>,
+    /// Whether the thread is gone, e.g. because `FrameWaiter::wait` panicked
+    exited: bool,
+}
+
+/// Marks the [`WaitSlot`] as abandoned when the frame waiter thread exits, even by unwinding
+struct ExitGuard(Arc<Mutex<WaitSlot>>);
+
+impl Drop for ExitGuard {
+    fn drop(&mut self) {
+        let mut slot = lock(&self.0);
+        slot.exited = true;
+        if let Some(waker) = slot.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+impl AsyncFrameWaiter {
+    /// Move `waiter` to a new thread
+    ///
+    /// The thread exits once the `AsyncFrameWaiter` is dropped and any wait in progress returns.
+    /// Fails if the thread can't be spawned.
+    pub fn new(mut waiter: FrameWaiter) -> io::Result<Self> {
+        let (requests, recv) = mpsc::channel::<()>();
+        let slot = Arc::new(Mutex::new(WaitSlot {
+            result: None,
+            waker: None,
+            exited: false,
+        }));
+        let guard = ExitGuard(slot.clone());
+        thread::Builder::new()
+            .name("openxr frame waiter".into())
+            .spawn(move || {
+                while recv.recv().is_ok() {
+                    let result = waiter.wait();
+                    let mut slot = lock(&guard.0);
+                    slot.result = Some(result);
+                    if let Some(waker) = slot.waker.take() {
+                        waker.wake();
+                    }
+                }
+            })?;
+        Ok(Self {
+            requests,
+            slot,
+            pending: false,
+        })
+    }
+
+    /// Wait until rendering should begin, and return details to guide rendering
+    ///
+    /// If a previous `WaitFrame` was dropped before completing, its wait still takes effect, and
+    /// its result is returned by this one. Fails with `ERROR_RUNTIME_FAILURE` if the thread has
+    /// exited unexpectedly.
+    pub fn wait(&mut self) -> WaitFrame<'_> {
+        WaitFrame { waiter: self }
+    }
+}
+
+/// Future returned by [`AsyncFrameWaiter::wait`]
+#[must_use = "futures do nothing unless polled"]
+pub struct WaitFrame<'a> {
+    waiter: &'a mut AsyncFrameWaiter,
+}
+
+impl Future for WaitFrame<'_> {
+    type Output = Result<FrameToken>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let waiter = &mut *self.waiter;
+        let exited = || Error::new("xrWaitFrame", sys::Result::ERROR_RUNTIME_FAILURE);
+        if !waiter.pending {
+            if waiter.requests.send(()).is_err() {
+                return Poll::Ready(Err(exited()));
+            }
+            waiter.pending = true;
+        }
+        let mut slot = lock(&waiter.slot);
+        match slot.result.take() {
+            Some(result) => {
+                waiter.pending = false;
+                Poll::Ready(result)
+            }
+            None if slot.exited => {
+                waiter.pending = false;
+                Poll::Ready(Err(exited()))
+            }
+            None => {
+                slot.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+/// Asynchronous sequence of events from [`Instance::poll_event`]
+///
+/// `xrPollEvent` can't notify anyone of new events, so while no event is available, a helper
+/// thread wakes the task polling the stream at a fixed interval. With the `futures-core` feature,
+/// this also implements `futures_core::Stream`.
+///
+/// # Example
+///
+/// ```no_run
+/// # async fn dummy(instance: openxr::Instance) -> Result<(), Box<dyn std::error::Error>> {
+/// let mut events =
+///     openxr::EventStream::new(instance, std::time::Duration::from_millis(10))?;
+/// loop {
+///     let event = events.next_event().await?;
+///     if let openxr::Event::SessionStateChanged(e) = event.event() {
+///         println!("entered state {:?}", e.state());
+///     }
+/// }
+/// # }
+/// ```
+pub struct EventStream {
+    instance: Instance,
+    buffer: Box<EventDataBuffer>,
+    timer: Arc<Timer>,
+}
+
+struct Timer {
+    state: Mutex<TimerState>,
+    cond: Condvar,
+}
+
+struct TimerState {
+    waker: Option<Waker>,
+    closed: bool,
+}
+
+impl EventStream {
+    /// Deliver events from `instance`, checking for new ones every `interval` while idle
+    ///
+    /// Fails if the helper thread can't be spawned.
+    pub fn new(instance: Instance, interval: std::time::Duration) -> io::Result<Self> {
+        let timer = Arc::new(Timer {
+            state: Mutex::new(TimerState {
+                waker: None,
+                closed: false,
+            }),
+            cond: Condvar::new(),
+        });
+        let thread_timer = timer.clone();
+        thread::Builder::new()
+            .name("openxr event poller".into())
+            .spawn(move || loop {
+                let mut state = lock(&thread_timer.state);
+                while state.waker.is_none() && !state.closed {
+                    state = thread_timer
+                        .cond
+                        .wait(state)
+                        .unwrap_or_else(|e| e.into_inner());
+                }
+                if state.closed {
+                    return;
+                }
+                drop(state);
+                thread::sleep(interval);
+                let waker = lock(&thread_timer.state).waker.take();
+                if let Some(waker) = waker {
+                    waker.wake();
+                }
+            })?;
+        Ok(Self {
+            instance,
+            buffer: Box::default(),
+            timer,
+        })
+    }
+
+    /// Get the next event
+    pub fn next_event(&mut self) -> NextEvent<'_> {
+        NextEvent { stream: self }
+    }
+
+    /// Attempt to get the next event, registering the current task for wakeup if none is
+    /// available
+    pub fn poll_next_event(&mut self, cx: &mut Context<'_>) -> Poll<Result<PolledEvent>> {
+        match self.instance.poll_event(&mut self.buffer) {
+            Ok(Some(_)) => {
+                let buffer = std::mem::take(&mut self.buffer);
+                return Poll::Ready(Ok(PolledEvent { buffer }));
+            }
+            Ok(None) => {}
+            Err(e) => return Poll::Ready(Err(e)),
+        }
+        lock(&self.timer.state).waker = Some(cx.waker().clone());
+        self.timer.cond.notify_one();
+        Poll::Pending
+    }
+}
+
+impl Drop for EventStream {
+    fn drop(&mut self) {
+        lock(&self.timer.state).closed = true;
+        self.timer.cond.notify_one();
+    }
+}
+
+#[cfg(feature = "futures-core")]
+impl futures_core::Stream for EventStream {
+    type Item = Result<PolledEvent>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.poll_next_event(cx).map(Some)
+    }
+}
+
+/// Future returned by [`EventStream::next_event`]
+#[must_use = "futures do nothing unless polled"]
+pub struct NextEvent<'a> {
+    stream: &'a mut EventStream,
+}
+
+impl Future for NextEvent<'_> {
+    type Output = Result<PolledEvent>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.stream.poll_next_event(cx)
+    }
+}
+
+/// An event delivered by an [`EventStream`], owning its storage
+pub struct PolledEvent {
+    buffer: Box<EventDataBuffer>,
+}
+
+impl PolledEvent {
+    /// Decode the event
+    pub fn event(&self) -> Event<'_> {
+        // Only events that `Instance::poll_event` decoded successfully are stored
+        unsafe { Event::from_raw(&self.buffer.inner).unwrap() }
+    }
+}
+
+fn lock<T>(x: &Mutex<T>) -> MutexGuard<'_, T> {
+    x.lock().unwrap_or_else(|e| e.into_inner())
+}
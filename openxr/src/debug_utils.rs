@@ -0,0 +1,367 @@
+use std::{
+    ffi::{CStr, CString},
+    fmt,
+    os::raw::{c_char, c_void},
+    panic::{self, AssertUnwindSafe},
+    ptr,
+    sync::{Mutex, MutexGuard},
+};
+
+use crate::*;
+
+/// Receives diagnostics from the runtime and API layers through `XR_EXT_debug_utils`
+///
+/// Messages are delivered to the callback for as long as the messenger is alive, possibly from
+/// other threads. Messages about instance creation and destruction can only be received by
+/// passing a [`DebugUtilsMessengerCreateInfo`] to [`Entry::create_instance_with_debug_messenger`].
+pub struct DebugUtilsMessenger {
+    instance: Instance,
+    handle: sys::DebugUtilsMessengerEXT,
+    _callback: Box<Callback>,
+}
+
+impl DebugUtilsMessenger {
+    #[inline]
+    pub fn as_raw(&self) -> sys::DebugUtilsMessengerEXT {
+        self.handle
+    }
+
+    pub(crate) fn create(instance: &Instance, info: DebugUtilsMessengerCreateInfo) -> Result<Self> {
+        let fp = instance
+            .exts()
+            .ext_debug_utils
+            .as_ref()
+            .ok_or(sys::Result::ERROR_EXTENSION_NOT_PRESENT)?;
+        let raw = info.as_raw();
+        let mut handle = sys::DebugUtilsMessengerEXT::NULL;
+        unsafe {
+            cvt(
+                "xrCreateDebugUtilsMessengerEXT",
+                (fp.create_debug_utils_messenger)(instance.as_raw(), &raw, &mut handle),
+            )?;
+        }
+        Ok(Self {
+            instance: instance.clone(),
+            handle,
+            _callback: info.callback,
+        })
+    }
+}
+
+impl Drop for DebugUtilsMessenger {
+    fn drop(&mut self) {
+        unsafe {
+            (self
+                .instance
+                .exts()
+                .ext_debug_utils
+                .as_ref()
+                .unwrap()
+                .destroy_debug_utils_messenger)(self.handle);
+        }
+    }
+}
+
+/// A labeled region of a session's timeline, closed on drop
+///
+/// Created by [`Session::begin_label_region`].
+#[must_use = "the region closes as soon as the guard is dropped"]
+pub struct LabelRegion<'a> {
+    session: &'a session::SessionInner,
+    /// Number of regions open before this one, or `None` if `XR_EXT_debug_utils` isn't loaded
+    depth: Option<u32>,
+}
+
+impl<'a> LabelRegion<'a> {
+    pub(crate) fn begin(session: &'a session::SessionInner, name: &str) -> Result<Self> {
+        let fp = match session.instance.exts().ext_debug_utils.as_ref() {
+            Some(x) => x,
+            None => {
+                return Ok(Self {
+                    session,
+                    depth: None,
+                })
+            }
+        };
+        let name = CString::new(name).unwrap();
+        let label = label(&name);
+        let mut depth = lock(&session.label_depth);
+        unsafe {
+            cvt(
+                "xrSessionBeginDebugUtilsLabelRegionEXT",
+                (fp.session_begin_debug_utils_label_region)(session.handle, &label),
+            )?;
+        }
+        *depth += 1;
+        Ok(Self {
+            session,
+            depth: Some(*depth - 1),
+        })
+    }
+}
+
+impl Drop for LabelRegion<'_> {
+    fn drop(&mut self) {
+        let expected = match self.depth {
+            Some(x) => x,
+            None => return,
+        };
+        let mut depth = lock(&self.session.label_depth);
+        // The runtime closes the innermost region regardless of which guard is dropped
+        if *depth != expected + 1 {
+            if !std::thread::panicking() {
+                panic!("label regions must be closed in the reverse of the order they were opened");
+            }
+            return;
+        }
+        unsafe {
+            (self
+                .session
+                .instance
+                .exts()
+                .ext_debug_utils
+                .as_ref()
+                .unwrap()
+                .session_end_debug_utils_label_region)(self.session.handle);
+        }
+        *depth -= 1;
+    }
+}
+
+pub(crate) fn label(name: &CStr) -> sys::DebugUtilsLabelEXT {
+    sys::DebugUtilsLabelEXT {
+        ty: sys::DebugUtilsLabelEXT::TYPE,
+        next: ptr::null(),
+        label_name: name.as_ptr(),
+    }
+}
+
+fn lock<T>(x: &Mutex<T>) -> MutexGuard<'_, T> {
+    x.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+pub(crate) type Callback = Box<dyn Fn(&DebugUtilsMessage) + Send + Sync>;
+
+/// Which messages a [`DebugUtilsMessenger`] receives, and what to do with them
+pub struct DebugUtilsMessengerCreateInfo {
+    pub message_severities: DebugUtilsMessageSeverityFlagsEXT,
+    pub message_types: DebugUtilsMessageTypeFlagsEXT,
+    pub(crate) callback: Box<Callback>,
+}
+
+impl DebugUtilsMessengerCreateInfo {
+    /// Pass messages of every severity and type to `callback`
+    ///
+    /// The callback may be invoked from any thread that makes OpenXR calls. Panics are caught and
+    /// discarded, since they can't unwind into the runtime.
+    pub fn new(callback: impl Fn(&DebugUtilsMessage) + Send + Sync + 'static) -> Self {
+        Self {
+            message_severities: DebugUtilsMessageSeverityFlagsEXT::VERBOSE
+                | DebugUtilsMessageSeverityFlagsEXT::INFO
+                | DebugUtilsMessageSeverityFlagsEXT::WARNING
+                | DebugUtilsMessageSeverityFlagsEXT::ERROR,
+            message_types: DebugUtilsMessageTypeFlagsEXT::GENERAL
+                | DebugUtilsMessageTypeFlagsEXT::VALIDATION
+                | DebugUtilsMessageTypeFlagsEXT::PERFORMANCE
+                | DebugUtilsMessageTypeFlagsEXT::CONFORMANCE,
+            callback: Box::new(Box::new(callback)),
+        }
+    }
+
+    /// Forward messages to the `log` crate under the `openxr` target
+    ///
+    /// Errors, warnings and info map to the levels of the same names, and verbose messages to
+    /// `Debug`.
+    #[cfg(feature = "log")]
+    pub fn log() -> Self {
+        Self::new(|message| {
+            let level = match message.level() {
+                Level::Error => log::Level::Error,
+                Level::Warn => log::Level::Warn,
+                Level::Info => log::Level::Info,
+                Level::Debug => log::Level::Debug,
+            };
+            log::log!(target: "openxr", level, "{}", message);
+        })
+    }
+
+    /// Forward messages to the `tracing` crate as events under the `openxr` target
+    ///
+    /// Errors, warnings and info map to the levels of the same names, and verbose messages to
+    /// `DEBUG`. The message ID and function name are recorded as fields.
+    #[cfg(feature = "tracing")]
+    pub fn tracing() -> Self {
+        Self::new(|message| {
+            macro_rules! event {
+                ($level:expr) => {
+                    tracing::event!(
+                        target: "openxr",
+                        $level,
+                        message_id = message.message_id.as_deref(),
+                        function_name = message.function_name.as_deref(),
+                        "{}",
+                        message.message
+                    )
+                };
+            }
+            match message.level() {
+                Level::Error => event!(tracing::Level::ERROR),
+                Level::Warn => event!(tracing::Level::WARN),
+                Level::Info => event!(tracing::Level::INFO),
+                Level::Debug => event!(tracing::Level::DEBUG),
+            }
+        })
+    }
+
+    /// Only receive messages of the given severities
+    #[inline]
+    pub fn message_severities(mut self, value: DebugUtilsMessageSeverityFlagsEXT) -> Self {
+        self.message_severities = value;
+        self
+    }
+
+    /// Only receive messages of the given types
+    #[inline]
+    pub fn message_types(mut self, value: DebugUtilsMessageTypeFlagsEXT) -> Self {
+        self.message_types = value;
+        self
+    }
+
+    /// The raw create info, valid for as long as `self` is neither moved nor dropped
+    pub(crate) fn as_raw(&self) -> sys::DebugUtilsMessengerCreateInfoEXT {
+        sys::DebugUtilsMessengerCreateInfoEXT {
+            ty: sys::DebugUtilsMessengerCreateInfoEXT::TYPE,
+            next: ptr::null(),
+            message_severities: self.message_severities,
+            message_types: self.message_types,
+            user_callback: Some(callback),
+            user_data: &*self.callback as *const Callback as *mut c_void,
+        }
+    }
+}
+
+unsafe extern "system" fn callback(
+    message_severity: DebugUtilsMessageSeverityFlagsEXT,
+    message_types: DebugUtilsMessageTypeFlagsEXT,
+    callback_data: *const sys::DebugUtilsMessengerCallbackDataEXT,
+    user_data: *mut c_void,
+) -> sys::Bool32 {
+    let callback = &*(user_data as *const Callback);
+    let _ = panic::catch_unwind(AssertUnwindSafe(|| {
+        let message = DebugUtilsMessage::from_raw(message_severity, message_types, &*callback_data);
+        callback(&message);
+    }));
+    // Aborting the call that produced the message is reserved for validation layers
+    sys::FALSE
+}
+
+/// A message delivered to a [`DebugUtilsMessenger`]
+#[derive(Debug, Clone)]
+pub struct DebugUtilsMessage {
+    pub severity: DebugUtilsMessageSeverityFlagsEXT,
+    pub types: DebugUtilsMessageTypeFlagsEXT,
+    /// Identifies the condition being reported, e.g. a validation rule
+    pub message_id: Option<String>,
+    /// The command that triggered the message
+    pub function_name: Option<String>,
+    pub message: String,
+    /// Objects related to the message
+    pub objects: Vec<DebugUtilsObject>,
+    /// Session labels active when the message was generated
+    pub session_labels: Vec<String>,
+}
+
+impl DebugUtilsMessage {
+    unsafe fn from_raw(
+        severity: DebugUtilsMessageSeverityFlagsEXT,
+        types: DebugUtilsMessageTypeFlagsEXT,
+        data: &sys::DebugUtilsMessengerCallbackDataEXT,
+    ) -> Self {
+        Self {
+            severity,
+            types,
+            message_id: opt_str(data.message_id),
+            function_name: opt_str(data.function_name),
+            message: opt_str(data.message).unwrap_or_default(),
+            objects: slice(data.objects, data.object_count)
+                .iter()
+                .map(|x| DebugUtilsObject {
+                    object_type: x.object_type,
+                    object_handle: x.object_handle,
+                    object_name: opt_str(x.object_name),
+                })
+                .collect(),
+            session_labels: slice(data.session_labels, data.session_label_count)
+                .iter()
+                .filter_map(|x| opt_str(x.label_name))
+                .collect(),
+        }
+    }
+
+    #[cfg(any(feature = "log", feature = "tracing"))]
+    fn level(&self) -> Level {
+        if self
+            .severity
+            .contains(DebugUtilsMessageSeverityFlagsEXT::ERROR)
+        {
+            Level::Error
+        } else if self
+            .severity
+            .contains(DebugUtilsMessageSeverityFlagsEXT::WARNING)
+        {
+            Level::Warn
+        } else if self
+            .severity
+            .contains(DebugUtilsMessageSeverityFlagsEXT::INFO)
+        {
+            Level::Info
+        } else {
+            Level::Debug
+        }
+    }
+}
+
+impl fmt::Display for DebugUtilsMessage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(ref function_name) = self.function_name {
+            write!(f, "{}: ", function_name)?;
+        }
+        f.write_str(&self.message)?;
+        if let Some(ref message_id) = self.message_id {
+            write!(f, " [{}]", message_id)?;
+        }
+        Ok(())
+    }
+}
+
+/// An object referred to by a [`DebugUtilsMessage`]
+#[derive(Debug, Clone)]
+pub struct DebugUtilsObject {
+    pub object_type: ObjectType,
+    /// The raw handle, e.g. from `Session::as_raw().into_raw()`
+    pub object_handle: u64,
+    /// The name given with `set_name`, if any
+    pub object_name: Option<String>,
+}
+
+#[cfg(any(feature = "log", feature = "tracing"))]
+enum Level {
+    Error,
+    Warn,
+    Info,
+    Debug,
+}
+
+unsafe fn opt_str(p: *const c_char) -> Option<String> {
+    if p.is_null() {
+        return None;
+    }
+    Some(CStr::from_ptr(p).to_string_lossy().into_owned())
+}
+
+unsafe fn slice<'a, T>(p: *const T, len: u32) -> &'a [T] {
+    if p.is_null() || len == 0 {
+        return &[];
+    }
+    std::slice::from_raw_parts(p, len as usize)
+}
@@ -23,9 +23,12 @@ impl<G> Session<G> {
             .fb_display_refresh_rate
             .as_ref()
             .expect("XR_FB_display_refresh_rate not loaded");
-        get_arr(|cap, count, buf| unsafe {
-            (ext.enumerate_display_refresh_rates)(self.as_raw(), cap, count, buf)
-        })
+        get_arr(
+            "xrEnumerateDisplayRefreshRatesFB",
+            |cap, count, buf| unsafe {
+                (ext.enumerate_display_refresh_rates)(self.as_raw(), cap, count, buf)
+            },
+        )
     }
 
     /// Retrieves the [current display refresh rate].
@@ -43,10 +46,10 @@ impl<G> Session<G> {
             .expect("XR_FB_display_refresh_rate not loaded");
         unsafe {
             let mut out = MaybeUninit::uninit();
-            cvt((ext.get_display_refresh_rate)(
-                self.as_raw(),
-                out.as_mut_ptr(),
-            ))?;
+            cvt(
+                "xrGetDisplayRefreshRateFB",
+                (ext.get_display_refresh_rate)(self.as_raw(), out.as_mut_ptr()),
+            )?;
             Ok(out.assume_init())
         }
     }
@@ -64,7 +67,9 @@ impl<G> Session<G> {
             .fb_display_refresh_rate
             .as_ref()
             .expect("XR_FB_display_refresh_rate not loaded");
-        cvt(unsafe { (ext.request_display_refresh_rate)(self.as_raw(), display_refresh_rate) })?;
+        cvt("xrRequestDisplayRefreshRateFB", unsafe {
+            (ext.request_display_refresh_rate)(self.as_raw(), display_refresh_rate)
+        })?;
         Ok(())
     }
 }
@@ -16,7 +16,8 @@ use crate::*;
 /// `linked` feature, this can be obtained at compile time with the `linked` constructor. The
 /// `static` feature provides a built-in copy of the Khronos OpenXR loader for use in this
 /// pattern. Alternatively, the `loaded` feature exposes the `load` and `load_from` constructors to
-/// manually load an OpenXR implementation at run-time.
+/// manually load an OpenXR implementation at run-time, and the `rust-loader` feature exposes the
+/// `rust_loader` constructor to find the active runtime without any external loader.
 #[derive(Clone)]
 pub struct Entry {
     inner: Arc<Inner>,
@@ -95,6 +96,27 @@ impl Entry {
         })
     }
 
+    /// Load entry points from the active runtime and API layers, using the loader built into this
+    /// crate
+    ///
+    /// Available if the `rust-loader` feature is enabled. See the [`loader`] module for how the
+    /// runtime and layers are found.
+    ///
+    /// # Safety
+    ///
+    /// The shared libraries named by the runtime and enabled layer manifests must conform to the
+    /// OpenXR specification and loader interface.
+    #[cfg(feature = "rust-loader")]
+    pub unsafe fn rust_loader() -> std::result::Result<Self, loader::Error> {
+        Ok(Self {
+            inner: Arc::new(Inner {
+                raw: loader::init()?,
+                #[cfg(feature = "loaded")]
+                _lib_guard: None,
+            }),
+        })
+    }
+
     /// Load entry points using an arbitrary `xrGetInstanceProcAddr` implementation
     ///
     /// # Safety
@@ -164,9 +186,10 @@ impl Entry {
         };
 
         unsafe {
-            cvt((loader_init.initialize_loader)(
-                &loader_info as *const _ as _,
-            ))?;
+            cvt(
+                "xrInitializeLoaderKHR",
+                (loader_init.initialize_loader)(&loader_info as *const _ as _),
+            )?;
         }
 
         Ok(())
@@ -182,6 +205,34 @@ impl Entry {
         app_info: &ApplicationInfo,
         required_extensions: &ExtensionSet,
         layers: &[&str],
+    ) -> Result<Instance> {
+        self.create_instance_inner(app_info, required_extensions, layers, None)
+    }
+
+    /// Like [`create_instance`](Self::create_instance), additionally receiving debug messages
+    /// generated while the instance is created and destroyed
+    ///
+    /// The callback stays alive as long as the instance; other messages require a separate
+    /// [`DebugUtilsMessenger`]. `required_extensions` must include `ext_debug_utils`.
+    pub fn create_instance_with_debug_messenger(
+        &self,
+        app_info: &ApplicationInfo,
+        required_extensions: &ExtensionSet,
+        layers: &[&str],
+        debug_messenger: DebugUtilsMessengerCreateInfo,
+    ) -> Result<Instance> {
+        if !required_extensions.ext_debug_utils {
+            return Err(sys::Result::ERROR_EXTENSION_NOT_PRESENT.into());
+        }
+        self.create_instance_inner(app_info, required_extensions, layers, Some(debug_messenger))
+    }
+
+    fn create_instance_inner(
+        &self,
+        app_info: &ApplicationInfo,
+        required_extensions: &ExtensionSet,
+        layers: &[&str],
+        debug_messenger: Option<DebugUtilsMessengerCreateInfo>,
     ) -> Result<Instance> {
         assert!(
             app_info.application_name.len() < sys::MAX_APPLICATION_NAME_SIZE,
@@ -227,6 +278,15 @@ impl Entry {
             ptr::null()
         };
 
+        let mut debug_info = debug_messenger.as_ref().map(|x| x.as_raw());
+        let next = match debug_info {
+            Some(ref mut x) => {
+                x.next = next;
+                x as *const _ as _
+            }
+            None => next,
+        };
+
         let mut info = sys::InstanceCreateInfo {
             ty: sys::InstanceCreateInfo::TYPE,
             next,
@@ -236,7 +296,7 @@ impl Entry {
                 application_version: app_info.application_version,
                 engine_name: [0; sys::MAX_ENGINE_NAME_SIZE],
                 engine_version: app_info.engine_version,
-                api_version: CURRENT_API_VERSION,
+                api_version: app_info.api_version.unwrap_or(CURRENT_API_VERSION),
             },
             enabled_api_layer_count: layer_ptrs.len() as _,
             enabled_api_layer_names: layer_ptrs.as_ptr(),
@@ -250,10 +310,25 @@ impl Entry {
         place_cstr(&mut info.application_info.engine_name, app_info.engine_name);
         unsafe {
             let mut handle = sys::Instance::NULL;
-            cvt((self.fp().create_instance)(&info, &mut handle))?;
+            let mut result = (self.fp().create_instance)(&info, &mut handle);
+            let requested = info.application_info.api_version;
+            if result == sys::Result::ERROR_API_VERSION_UNSUPPORTED
+                && requested.major() == 1
+                && requested.minor() > 0
+            {
+                info.application_info.api_version = sys::API_VERSION_1_0;
+                result = (self.fp().create_instance)(&info, &mut handle);
+            }
+            cvt("xrCreateInstance", result)?;
 
             let exts = InstanceExtensions::load(self, handle, required_extensions)?;
-            Instance::from_raw(self.clone(), handle, exts)
+            Instance::from_raw_with_debug_callback(
+                self.clone(),
+                handle,
+                info.application_info.api_version,
+                exts,
+                debug_messenger.map(|x| x.callback),
+            )
         }
     }
 
@@ -261,6 +336,7 @@ impl Entry {
     pub fn enumerate_extensions(&self) -> Result<ExtensionSet> {
         unsafe {
             let exts = get_arr_init(
+                "xrEnumerateInstanceExtensionProperties",
                 sys::ExtensionProperties::out(ptr::null_mut()),
                 |cap, count, buf| {
                     (self.fp().enumerate_instance_extension_properties)(
@@ -278,6 +354,7 @@ impl Entry {
     pub fn enumerate_layers(&self) -> Result<Vec<ApiLayerProperties>> {
         unsafe {
             let layers = get_arr_init(
+                "xrEnumerateApiLayerProperties",
                 sys::ApiLayerProperties::out(ptr::null_mut()),
                 |cap, count, buf| (self.fp().enumerate_api_layer_properties)(cap, count, buf as _),
             )?;
@@ -304,7 +381,10 @@ unsafe fn get_instance_proc_addr_helper(
     name: &CStr,
 ) -> Result<unsafe extern "system" fn()> {
     let mut f = None;
-    cvt((get_instance_proc_addr)(instance, name.as_ptr(), &mut f))?;
+    cvt(
+        "xrGetInstanceProcAddr",
+        (get_instance_proc_addr)(instance, name.as_ptr(), &mut f),
+    )?;
     Ok(f.unwrap())
 }
 
@@ -352,6 +432,12 @@ pub struct ApplicationInfo<'a> {
     pub application_version: u32,
     pub engine_name: &'a str,
     pub engine_version: u32,
+    /// Newest OpenXR version the application is written against
+    ///
+    /// `None` requests [`CURRENT_API_VERSION`]. If the runtime doesn't support a requested minor
+    /// version above 1.0, instance creation falls back to 1.0; [`Instance::api_version`] reports
+    /// the outcome.
+    pub api_version: Option<Version>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -0,0 +1,107 @@
+use std::fmt;
+
+use crate::*;
+
+/// A failed OpenXR call
+///
+/// Records the failing command alongside its result, so errors propagated far from their source
+/// still say where they came from. Converts to and from [`sys::Result`], and compares equal to the
+/// `sys::Result` it holds:
+///
+/// ```
+/// # use openxr::{sys, Error};
+/// let error = Error::new("xrBeginFrame", sys::Result::ERROR_CALL_ORDER_INVALID);
+/// assert_eq!(error, sys::Result::ERROR_CALL_ORDER_INVALID);
+/// assert_eq!(sys::Result::from(error), sys::Result::ERROR_CALL_ORDER_INVALID);
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct Error {
+    result: sys::Result,
+    function: Option<&'static str>,
+}
+
+impl Error {
+    /// An error returned by the OpenXR command `function`, e.g. `xrBeginFrame`
+    #[inline]
+    pub fn new(function: &'static str, result: sys::Result) -> Self {
+        Self {
+            result,
+            function: Some(function),
+        }
+    }
+
+    /// The raw result code
+    #[inline]
+    pub fn result(&self) -> sys::Result {
+        self.result
+    }
+
+    /// The OpenXR command that failed, if the error came from one
+    ///
+    /// Errors raised by this crate without calling into OpenXR, such as
+    /// `ERROR_EXTENSION_NOT_PRESENT` when a required extension wasn't enabled, have none, unless
+    /// they stand in for a single command the crate declined to call, as when creating a
+    /// [`Headless`] session without `XR_MND_headless`.
+    #[inline]
+    pub fn function(&self) -> Option<&'static str> {
+        self.function
+    }
+
+    /// The runtime's name for the result code, via `xrResultToString`
+    ///
+    /// Useful for result codes of extensions newer than this crate, which have no description.
+    pub fn runtime_string(&self, instance: &Instance) -> Result<String> {
+        instance.result_to_string(self.result)
+    }
+}
+
+impl From<sys::Result> for Error {
+    #[inline]
+    fn from(result: sys::Result) -> Self {
+        Self {
+            result,
+            function: None,
+        }
+    }
+}
+
+impl From<Error> for sys::Result {
+    #[inline]
+    fn from(error: Error) -> Self {
+        error.result
+    }
+}
+
+impl PartialEq<sys::Result> for Error {
+    #[inline]
+    fn eq(&self, other: &sys::Result) -> bool {
+        self.result == *other
+    }
+}
+
+impl PartialEq<Error> for sys::Result {
+    #[inline]
+    fn eq(&self, other: &Error) -> bool {
+        *self == other.result
+    }
+}
+
+impl fmt::Debug for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.function {
+            Some(function) => write!(f, "{}: {:?}", function, self.result),
+            None => write!(f, "{:?}", self.result),
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.function {
+            Some(function) => write!(f, "{} failed: {}", function, self.result),
+            None => write!(f, "{}", self.result),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
@@ -54,11 +54,10 @@ impl EyeTrackerSocial {
         let mut eye_gazes = sys::EyeGazesFB::out(ptr::null_mut());
 
         let eye_gazes = unsafe {
-            cvt((self.fp().get_eye_gazes)(
-                self.handle,
-                &gaze_info,
-                eye_gazes.as_mut_ptr(),
-            ))?;
+            cvt(
+                "xrGetEyeGazesFB",
+                (self.fp().get_eye_gazes)(self.handle, &gaze_info, eye_gazes.as_mut_ptr()),
+            )?;
 
             eye_gazes.assume_init()
         };
@@ -98,7 +97,10 @@ impl<G> Session<G> {
             next: ptr::null(),
         };
         let handle = unsafe {
-            cvt((fp.create_eye_tracker)(self.as_raw(), &info, &mut out))?;
+            cvt(
+                "xrCreateEyeTrackerFB",
+                (fp.create_eye_tracker)(self.as_raw(), &info, &mut out),
+            )?;
             out
         };
         Ok(EyeTrackerSocial {
@@ -62,11 +62,14 @@ impl FaceTrackerFB {
         };
 
         unsafe {
-            cvt((self.fp().get_face_expression_weights)(
-                self.handle,
-                &expression_info,
-                &mut expression_weights,
-            ))?;
+            cvt(
+                "xrGetFaceExpressionWeightsFB",
+                (self.fp().get_face_expression_weights)(
+                    self.handle,
+                    &expression_info,
+                    &mut expression_weights,
+                ),
+            )?;
 
             if expression_weights.status.is_valid.into() {
                 Ok(Some(FaceExpressionWeightsFB {
@@ -103,7 +106,10 @@ impl<G> Session<G> {
             face_expression_set: FaceExpressionSetFB::DEFAULT,
         };
         let handle = unsafe {
-            cvt((fp.create_face_tracker)(self.as_raw(), &info, &mut out))?;
+            cvt(
+                "xrCreateFaceTrackerFB",
+                (fp.create_face_tracker)(self.as_raw(), &info, &mut out),
+            )?;
             out
         };
         Ok(FaceTrackerFB {
@@ -64,13 +64,13 @@ impl<G> Session<G> {
         let mut profile = sys::FoveationProfileFB::NULL;
         let res =
             unsafe { (fp.create_foveation_profile)(self.as_raw(), &mut create_info, &mut profile) };
-        cvt(res)?;
+        cvt("xrCreateFoveationProfileFB", res)?;
 
         Ok(unsafe { FoveationProfileFB::from_raw(self.instance().clone(), profile) })
     }
 }
 
-impl<G: Graphics> Session<G> {
+impl<G: RendersFrames> Session<G> {
     pub fn create_swapchain_with_foveation(
         &self,
         info: &SwapchainCreateInfo<G>,
@@ -97,11 +97,10 @@ impl<G: Graphics> Session<G> {
             mip_count: info.mip_count,
         };
         unsafe {
-            cvt((self.instance().fp().create_swapchain)(
-                self.as_raw(),
-                &info,
-                &mut out,
-            ))?;
+            cvt(
+                "xrCreateSwapchain",
+                (self.instance().fp().create_swapchain)(self.as_raw(), &info, &mut out),
+            )?;
             Ok(Swapchain::from_raw(self.clone(), out))
         }
     }
@@ -123,7 +122,12 @@ impl<G: Graphics> Swapchain<G> {
             profile: profile.as_raw(),
         };
 
-        unsafe { cvt((fp.update_swapchain)(self.as_raw(), &info as *const _ as _))? };
+        unsafe {
+            cvt(
+                "xrUpdateSwapchainFB",
+                (fp.update_swapchain)(self.as_raw(), &info as *const _ as _),
+            )?
+        };
 
         Ok(())
     }
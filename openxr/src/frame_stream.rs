@@ -13,7 +13,7 @@ use crate::*;
 /// A typical presentation loop body should look roughly as follows:
 ///
 /// ```no_run
-/// # fn dummy<G: openxr::Graphics>(
+/// # fn dummy<G: openxr::RendersFrames>(
 /// #     session: &openxr::Session<G>,
 /// #     swapchain: &mut openxr::Swapchain<G>,
 /// #     frame_waiter: &mut openxr::FrameWaiter,
@@ -21,30 +21,30 @@ use crate::*;
 /// #     world_space: &openxr::Space,
 /// #     view_resolution: &[openxr::Extent2Di],
 /// # ) {
-/// let state = frame_waiter.wait().unwrap();
-/// let image = swapchain.acquire_image().unwrap();
-/// swapchain.wait_image(openxr::Duration::INFINITE).unwrap();
+/// let frame = frame_waiter.wait().unwrap();
+/// let mut image = swapchain.acquire_image().unwrap();
+/// let index = image.wait(openxr::Duration::INFINITE).unwrap().unwrap();
 ///
-/// frame_stream.begin().unwrap();
+/// let frame = frame_stream.begin(frame).unwrap();
 ///
-/// if state.should_render {
-///     // draw scene...
+/// if frame.should_render {
+///     // draw scene to the image at `index`...
 /// }
 ///
 /// let (view_flags, views) = session
 ///     .locate_views(
 ///         openxr::ViewConfigurationType::PRIMARY_STEREO,
-///         state.predicted_display_time,
+///         frame.predicted_display_time,
 ///         world_space,
 ///     )
 ///     .unwrap();
 ///
 /// // set view matrices and submit to GPU...
 ///
-/// swapchain.release_image().unwrap();
+/// let image = image.release().unwrap();
 /// frame_stream
 ///     .end(
-///         state.predicted_display_time,
+///         frame,
 ///         openxr::EnvironmentBlendMode::OPAQUE,
 ///         &[&openxr::CompositionLayerProjection::new()
 ///             .space(world_space)
@@ -53,8 +53,8 @@ use crate::*;
 ///                     .pose(views[0].pose)
 ///                     .fov(views[0].fov)
 ///                     .sub_image(
-///                         openxr::SwapchainSubImage::new()
-///                             .swapchain(swapchain)
+///                         image
+///                             .sub_image()
 ///                             .image_array_index(0)
 ///                             .image_rect(openxr::Rect2Di {
 ///                                 offset: openxr::Offset2Di { x: 0, y: 0 },
@@ -65,8 +65,8 @@ use crate::*;
 ///                     .pose(views[1].pose)
 ///                     .fov(views[1].fov)
 ///                     .sub_image(
-///                         openxr::SwapchainSubImage::new()
-///                             .swapchain(swapchain)
+///                         image
+///                             .sub_image()
 ///                             .image_array_index(1)
 ///                             .image_rect(openxr::Rect2Di {
 ///                                 offset: openxr::Offset2Di { x: 0, y: 0 },
@@ -87,41 +87,92 @@ impl<G: Graphics> FrameStream<G> {
         Self { session }
     }
 
-    /// Indicate that graphics device work is beginning
+    /// Indicate that graphics device work is beginning on the frame `frame` was waited for
+    ///
+    /// Panics if `frame` came from a different session.
     #[inline]
-    pub fn begin(&mut self) -> Result<()> {
+    pub fn begin(&mut self, frame: FrameToken) -> Result<BegunFrame> {
+        self.check_session(frame.session());
         unsafe {
-            cvt((self.fp().begin_frame)(self.session.as_raw(), ptr::null()))?;
+            cvt(
+                "xrBeginFrame",
+                (self.fp().begin_frame)(self.session.as_raw(), ptr::null()),
+            )?;
         }
-        Ok(())
+        Ok(BegunFrame::new(frame))
     }
 
-    /// Indicate that all graphics work for the frame has been submitted
+    /// Indicate that `frame` is complete without submitting any composition layers
     ///
-    /// `layers` is an array of references to any type of composition layer,
-    /// e.g. `CompositionLayerProjection`.
+    /// The only way to end frames of sessions that don't render, such as [`Headless`] ones.
+    ///
+    /// Panics if `frame` came from a different session.
     #[inline]
-    pub fn end(
+    pub fn end_empty(
         &mut self,
-        display_time: Time,
+        frame: BegunFrame,
+        environment_blend_mode: EnvironmentBlendMode,
+    ) -> Result<()> {
+        self.end_inner(frame, environment_blend_mode, &[])
+    }
+
+    fn end_inner(
+        &mut self,
+        frame: BegunFrame,
         environment_blend_mode: EnvironmentBlendMode,
         layers: &[&CompositionLayerBase<'_, G>],
     ) -> Result<()> {
-        assert!(layers.len() <= u32::max_value() as usize);
+        self.check_session(frame.session());
+        assert!(layers.len() <= u32::MAX as usize);
         let info = sys::FrameEndInfo {
             ty: sys::FrameEndInfo::TYPE,
             next: ptr::null(),
-            display_time,
+            display_time: frame.predicted_display_time,
             environment_blend_mode,
             layer_count: layers.len() as u32,
             layers: layers.as_ptr() as _,
         };
         unsafe {
-            cvt((self.fp().end_frame)(self.session.as_raw(), &info))?;
+            cvt(
+                "xrEndFrame",
+                (self.fp().end_frame)(self.session.as_raw(), &info),
+            )?;
         }
         Ok(())
     }
 
+    fn check_session(&self, session: sys::Session) {
+        assert_eq!(
+            session,
+            self.session.as_raw(),
+            "frame must come from the `FrameWaiter` of the same `Session`"
+        );
+    }
+
+    // Private helper
+    #[inline]
+    fn fp(&self) -> &raw::Instance {
+        self.session.instance().fp()
+    }
+}
+
+impl<G: RendersFrames> FrameStream<G> {
+    /// Indicate that all graphics work for `frame` has been submitted
+    ///
+    /// `layers` is an array of references to any type of composition layer,
+    /// e.g. `CompositionLayerProjection`.
+    ///
+    /// Panics if `frame` came from a different session.
+    #[inline]
+    pub fn end(
+        &mut self,
+        frame: BegunFrame,
+        environment_blend_mode: EnvironmentBlendMode,
+        layers: &[&CompositionLayerBase<'_, G>],
+    ) -> Result<()> {
+        self.end_inner(frame, environment_blend_mode, layers)
+    }
+
     /// Indicate that all graphics work for the frame has been submitted
     ///
     /// `layers` is an array of references to any type of composition layer,
@@ -134,44 +185,66 @@ impl<G: Graphics> FrameStream<G> {
     #[inline]
     pub fn end_secondary(
         &mut self,
-        display_time: Time,
+        frame: BegunFrame,
         environment_blend_mode: EnvironmentBlendMode,
         layers: &[&CompositionLayerBase<'_, G>],
         secondary_info: SecondaryEndInfo<'_, '_, '_, G>,
     ) -> Result<()> {
-        assert!(layers.len() <= u32::max_value() as usize);
-        assert!(secondary_info.layers.len() <= u32::max_value() as usize);
-        let single_secondary_info = [sys::SecondaryViewConfigurationLayerInfoMSFT {
-            ty: sys::SecondaryViewConfigurationLayerInfoMSFT::TYPE,
-            next: ptr::null(),
-            view_configuration_type: secondary_info.ty,
-            environment_blend_mode: secondary_info.environment_blend_mode,
-            layer_count: secondary_info.layers.len() as u32,
-            layers: secondary_info.layers.as_ptr() as *const _,
-        }];
+        self.end_secondary_multiple(frame, environment_blend_mode, layers, &[secondary_info])
+    }
+
+    /// Same as .end_secondary() but for any number of secondary views
+    ///
+    /// `secondary_info` has one element for each secondary view configuration that was reported
+    /// as active by `FrameWaiter::wait_secondary_multiple` and is rendered this frame.
+    ///
+    /// `XR_MSFT_secondary_view_configuration` must be loaded and the session
+    /// must have been started with begin_secondary
+    #[inline]
+    pub fn end_secondary_multiple(
+        &mut self,
+        frame: BegunFrame,
+        environment_blend_mode: EnvironmentBlendMode,
+        layers: &[&CompositionLayerBase<'_, G>],
+        secondary_info: &[SecondaryEndInfo<'_, '_, '_, G>],
+    ) -> Result<()> {
+        self.check_session(frame.session());
+        assert!(layers.len() <= u32::MAX as usize);
+        assert!(secondary_info.len() <= u32::MAX as usize);
+        let secondary_layers = secondary_info
+            .iter()
+            .map(|info| {
+                assert!(info.layers.len() <= u32::MAX as usize);
+                sys::SecondaryViewConfigurationLayerInfoMSFT {
+                    ty: sys::SecondaryViewConfigurationLayerInfoMSFT::TYPE,
+                    next: ptr::null(),
+                    view_configuration_type: info.ty,
+                    environment_blend_mode: info.environment_blend_mode,
+                    layer_count: info.layers.len() as u32,
+                    layers: info.layers.as_ptr() as *const _,
+                }
+            })
+            .collect::<Vec<_>>();
         let secondary_info = sys::SecondaryViewConfigurationFrameEndInfoMSFT {
             ty: sys::SecondaryViewConfigurationFrameEndInfoMSFT::TYPE,
             next: ptr::null(),
-            view_configuration_count: 1,
-            view_configuration_layers_info: single_secondary_info.as_ptr() as *const _,
+            view_configuration_count: secondary_layers.len() as u32,
+            view_configuration_layers_info: secondary_layers.as_ptr(),
         };
         let info = sys::FrameEndInfo {
             ty: sys::FrameEndInfo::TYPE,
             next: &secondary_info as *const _ as *const _,
-            display_time,
+            display_time: frame.predicted_display_time,
             environment_blend_mode,
             layer_count: layers.len() as u32,
             layers: layers.as_ptr() as _,
         };
         unsafe {
-            cvt((self.fp().end_frame)(self.session.as_raw(), &info))?;
+            cvt(
+                "xrEndFrame",
+                (self.fp().end_frame)(self.session.as_raw(), &info),
+            )?;
         }
         Ok(())
     }
-
-    // Private helper
-    #[inline]
-    fn fp(&self) -> &raw::Instance {
-        self.session.instance().fp()
-    }
 }
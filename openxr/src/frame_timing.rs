@@ -0,0 +1,435 @@
+use std::{
+    collections::VecDeque,
+    io::{self, Write},
+    sync::{Arc, Mutex, MutexGuard},
+    time::Instant,
+};
+
+use crate::*;
+
+/// Records the timing of each frame passing through a [`FrameWaiter`] and [`FrameStream`]
+///
+/// Use [`wait`](Self::wait), [`begin`](Self::begin) and [`end`](Self::end), or their variants, in
+/// place of the methods of the same names on the waiter and stream. Cloning a recorder yields another handle to the same
+/// record, so the waiter and stream may be driven from different threads.
+///
+/// Runtime timestamps are obtained through [`Instance::now`], which requires
+/// `XR_KHR_convert_timespec_time` (or `XR_KHR_win32_convert_performance_counter_time` on
+/// Windows). Without it, they are omitted, and late submissions can't be detected.
+///
+/// # Example
+///
+/// ```no_run
+/// # fn dummy<G: openxr::RendersFrames>(
+/// #     instance: &openxr::Instance,
+/// #     frame_waiter: &mut openxr::FrameWaiter,
+/// #     frame_stream: &mut openxr::FrameStream<G>,
+/// # ) -> openxr::Result<()> {
+/// let timing = openxr::FrameTimingRecorder::new(instance.clone(), 900);
+/// for _ in 0..900 {
+///     let frame = timing.wait(frame_waiter)?;
+///     let frame = timing.begin(frame_stream, frame)?;
+///     // render if frame.should_render...
+///     timing.end(frame_stream, frame, openxr::EnvironmentBlendMode::OPAQUE, &[])?;
+/// }
+/// let stats = timing.stats();
+/// println!("{} of {} frames late", stats.late, stats.frames);
+/// timing.write_csv(std::fs::File::create("frames.csv").unwrap()).unwrap();
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct FrameTimingRecorder {
+    instance: Instance,
+    inner: Arc<Mutex<Inner>>,
+}
+
+struct Inner {
+    capacity: usize,
+    /// Frames waited for but not yet ended, keyed by predicted display time
+    pending: VecDeque<Pending>,
+    /// The most recently ended frames, oldest first
+    frames: VecDeque<FrameTiming>,
+    total: u64,
+    dropped: u64,
+    late: u64,
+}
+
+struct Pending {
+    timing: FrameTiming,
+    begun: Option<Instant>,
+}
+
+impl FrameTimingRecorder {
+    /// Create a recorder keeping statistics over the last `window` frames
+    pub fn new(instance: Instance, window: usize) -> Self {
+        Self {
+            instance,
+            inner: Arc::new(Mutex::new(Inner {
+                capacity: window.max(1),
+                pending: VecDeque::new(),
+                frames: VecDeque::new(),
+                total: 0,
+                dropped: 0,
+                late: 0,
+            })),
+        }
+    }
+
+    /// Call [`FrameWaiter::wait`], recording how long it blocked
+    pub fn wait(&self, waiter: &mut FrameWaiter) -> Result<FrameToken> {
+        let start = Instant::now();
+        let frame = waiter.wait()?;
+        self.waited(&frame, start);
+        Ok(frame)
+    }
+
+    /// Call [`FrameWaiter::wait_secondary`], recording how long it blocked
+    pub fn wait_secondary(
+        &self,
+        waiter: &mut FrameWaiter,
+    ) -> Result<(FrameToken, SecondaryViewState)> {
+        let start = Instant::now();
+        let out = waiter.wait_secondary()?;
+        self.waited(&out.0, start);
+        Ok(out)
+    }
+
+    /// Call [`FrameWaiter::wait_secondary_multiple`], recording how long it blocked
+    pub fn wait_secondary_multiple(
+        &self,
+        waiter: &mut FrameWaiter,
+        count: u32,
+    ) -> Result<(FrameToken, Vec<SecondaryViewState>)> {
+        let start = Instant::now();
+        let out = waiter.wait_secondary_multiple(count)?;
+        self.waited(&out.0, start);
+        Ok(out)
+    }
+
+    /// Call [`FrameStream::begin`], starting the frame's CPU time
+    pub fn begin<G: Graphics>(
+        &self,
+        stream: &mut FrameStream<G>,
+        frame: FrameToken,
+    ) -> Result<BegunFrame> {
+        let time = frame.predicted_display_time;
+        let frame = stream.begin(frame)?;
+        if let Some(pending) = self.lock().find(time) {
+            pending.begun = Some(Instant::now());
+        }
+        Ok(frame)
+    }
+
+    /// Call [`FrameStream::end`], completing the frame's record
+    pub fn end<G: RendersFrames>(
+        &self,
+        stream: &mut FrameStream<G>,
+        frame: BegunFrame,
+        environment_blend_mode: EnvironmentBlendMode,
+        layers: &[&CompositionLayerBase<'_, G>],
+    ) -> Result<()> {
+        self.finish(frame, |frame| {
+            stream.end(frame, environment_blend_mode, layers)
+        })
+    }
+
+    /// Call [`FrameStream::end_empty`], completing the frame's record
+    pub fn end_empty<G: Graphics>(
+        &self,
+        stream: &mut FrameStream<G>,
+        frame: BegunFrame,
+        environment_blend_mode: EnvironmentBlendMode,
+    ) -> Result<()> {
+        self.finish(frame, |frame| {
+            stream.end_empty(frame, environment_blend_mode)
+        })
+    }
+
+    /// Call [`FrameStream::end_secondary`], completing the frame's record
+    pub fn end_secondary<G: RendersFrames>(
+        &self,
+        stream: &mut FrameStream<G>,
+        frame: BegunFrame,
+        environment_blend_mode: EnvironmentBlendMode,
+        layers: &[&CompositionLayerBase<'_, G>],
+        secondary_info: SecondaryEndInfo<'_, '_, '_, G>,
+    ) -> Result<()> {
+        self.finish(frame, |frame| {
+            stream.end_secondary(frame, environment_blend_mode, layers, secondary_info)
+        })
+    }
+
+    /// Call [`FrameStream::end_secondary_multiple`], completing the frame's record
+    pub fn end_secondary_multiple<G: RendersFrames>(
+        &self,
+        stream: &mut FrameStream<G>,
+        frame: BegunFrame,
+        environment_blend_mode: EnvironmentBlendMode,
+        layers: &[&CompositionLayerBase<'_, G>],
+        secondary_info: &[SecondaryEndInfo<'_, '_, '_, G>],
+    ) -> Result<()> {
+        self.finish(frame, |frame| {
+            stream.end_secondary_multiple(frame, environment_blend_mode, layers, secondary_info)
+        })
+    }
+
+    /// Start the record of a frame whose wait began at `start` and just returned
+    fn waited(&self, frame: &FrameToken, start: Instant) {
+        let wait_duration = start.elapsed();
+        let timing = FrameTiming {
+            predicted_display_time: frame.predicted_display_time,
+            predicted_display_period: frame.predicted_display_period,
+            should_render: frame.should_render,
+            wait_duration,
+            cpu_duration: std::time::Duration::ZERO,
+            wait_end_time: self.now(),
+            end_time: None,
+            late: false,
+        };
+        let mut inner = self.lock();
+        // Frames that were never ended can't be told apart from ones still in flight, so bound the
+        // backlog by the depth of any reasonable pipeline
+        if inner.pending.len() >= 8 {
+            inner.pending.pop_front();
+        }
+        inner.pending.push_back(Pending {
+            timing,
+            begun: None,
+        });
+    }
+
+    /// End `frame` through `end`, completing its record
+    fn finish(&self, frame: BegunFrame, end: impl FnOnce(BegunFrame) -> Result<()>) -> Result<()> {
+        let time = frame.predicted_display_time;
+        let result = end(frame);
+        let ended = Instant::now();
+        let end_time = self.now();
+        let mut inner = self.lock();
+        let index = inner
+            .pending
+            .iter()
+            .position(|x| x.timing.predicted_display_time == time);
+        let pending = match index.and_then(|i| inner.pending.remove(i)) {
+            Some(x) => x,
+            None => return result,
+        };
+        result?;
+        let mut timing = pending.timing;
+        timing.cpu_duration = pending
+            .begun
+            .map_or(std::time::Duration::ZERO, |x| ended - x);
+        timing.end_time = end_time;
+        timing.late =
+            end_time.is_some_and(|x| x.as_nanos() > timing.predicted_display_time.as_nanos());
+        inner.record(timing);
+        Ok(())
+    }
+
+    /// Summarize the recorded frames
+    pub fn stats(&self) -> FrameTimingStats {
+        let inner = self.lock();
+        let percentiles = |f: &dyn Fn(&FrameTiming) -> std::time::Duration| {
+            let mut values = inner.frames.iter().map(f).collect::<Vec<_>>();
+            values.sort_unstable();
+            Percentiles::from_sorted(&values)
+        };
+        FrameTimingStats {
+            frames: inner.total,
+            dropped: inner.dropped,
+            late: inner.late,
+            wait: percentiles(&|x| x.wait_duration),
+            cpu: percentiles(&|x| x.cpu_duration),
+            display_period: percentiles(&|x| {
+                std::time::Duration::from_nanos(x.predicted_display_period.as_nanos().max(0) as u64)
+            }),
+        }
+    }
+
+    /// The most recently ended frames, oldest first
+    pub fn frames(&self) -> Vec<FrameTiming> {
+        self.lock().frames.iter().copied().collect()
+    }
+
+    /// Write the most recently ended frames as CSV, one row per frame
+    ///
+    /// Times and durations are in nanoseconds. Missing runtime timestamps are left empty.
+    pub fn write_csv(&self, mut out: impl Write) -> io::Result<()> {
+        writeln!(
+            out,
+            "predicted_display_time,predicted_display_period,should_render,wait_duration,\
+             cpu_duration,wait_end_time,end_time,late"
+        )?;
+        for x in self.frames() {
+            writeln!(
+                out,
+                "{},{},{},{},{},{},{},{}",
+                x.predicted_display_time.as_nanos(),
+                x.predicted_display_period.as_nanos(),
+                x.should_render,
+                x.wait_duration.as_nanos(),
+                x.cpu_duration.as_nanos(),
+                x.wait_end_time
+                    .map_or_else(String::new, |x| x.as_nanos().to_string()),
+                x.end_time
+                    .map_or_else(String::new, |x| x.as_nanos().to_string()),
+                x.late,
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Write the summary and the most recently ended frames as a JSON object
+    ///
+    /// Times and durations are in nanoseconds. Missing runtime timestamps are `null`.
+    pub fn write_json(&self, mut out: impl Write) -> io::Result<()> {
+        let stats = self.stats();
+        write!(
+            out,
+            "{{\"frames\":{},\"dropped\":{},\"late\":{},\"wait\":{},\"cpu\":{},\
+             \"display_period\":{},\"records\":[",
+            stats.frames,
+            stats.dropped,
+            stats.late,
+            stats.wait.json(),
+            stats.cpu.json(),
+            stats.display_period.json(),
+        )?;
+        let json_time =
+            |x: Option<Time>| x.map_or_else(|| "null".to_owned(), |x| x.as_nanos().to_string());
+        for (i, x) in self.frames().into_iter().enumerate() {
+            write!(
+                out,
+                "{}{{\"predicted_display_time\":{},\"predicted_display_period\":{},\
+                 \"should_render\":{},\"wait_duration\":{},\"cpu_duration\":{},\
+                 \"wait_end_time\":{},\"end_time\":{},\"late\":{}}}",
+                if i == 0 { "" } else { "," },
+                x.predicted_display_time.as_nanos(),
+                x.predicted_display_period.as_nanos(),
+                x.should_render,
+                x.wait_duration.as_nanos(),
+                x.cpu_duration.as_nanos(),
+                json_time(x.wait_end_time),
+                json_time(x.end_time),
+                x.late,
+            )?;
+        }
+        writeln!(out, "]}}")
+    }
+
+    fn now(&self) -> Option<Time> {
+        #[cfg(not(windows))]
+        let supported = self.instance.exts().khr_convert_timespec_time.is_some();
+        #[cfg(windows)]
+        let supported = self
+            .instance
+            .exts()
+            .khr_win32_convert_performance_counter_time
+            .is_some();
+        if !supported {
+            return None;
+        }
+        self.instance.now().ok()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Inner> {
+        self.inner.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl Inner {
+    fn find(&mut self, time: Time) -> Option<&mut Pending> {
+        self.pending
+            .iter_mut()
+            .find(|x| x.timing.predicted_display_time == time)
+    }
+
+    fn record(&mut self, timing: FrameTiming) {
+        self.total += 1;
+        if !timing.should_render {
+            self.dropped += 1;
+        }
+        if timing.late {
+            self.late += 1;
+        }
+        if self.frames.len() == self.capacity {
+            self.frames.pop_front();
+        }
+        self.frames.push_back(timing);
+    }
+}
+
+/// Timing of a single frame, as recorded by [`FrameTimingRecorder`]
+#[derive(Debug, Copy, Clone)]
+pub struct FrameTiming {
+    pub predicted_display_time: Time,
+    pub predicted_display_period: Duration,
+    /// Whether the runtime asked for the frame to be rendered
+    pub should_render: bool,
+    /// Time spent blocked in `xrWaitFrame`
+    pub wait_duration: std::time::Duration,
+    /// Time from `xrBeginFrame` returning to `xrEndFrame` returning
+    pub cpu_duration: std::time::Duration,
+    /// Runtime time at which `xrWaitFrame` returned
+    pub wait_end_time: Option<Time>,
+    /// Runtime time at which `xrEndFrame` returned
+    pub end_time: Option<Time>,
+    /// Whether the frame was submitted after its predicted display time
+    pub late: bool,
+}
+
+/// Summary of the frames recorded by a [`FrameTimingRecorder`]
+///
+/// Counts cover every recorded frame, while percentiles cover only the frames in the recorder's
+/// window.
+#[derive(Debug, Copy, Clone)]
+pub struct FrameTimingStats {
+    /// Number of frames ended
+    pub frames: u64,
+    /// Number of frames the runtime asked not to be rendered
+    pub dropped: u64,
+    /// Number of frames submitted after their predicted display time
+    pub late: u64,
+    /// Time spent blocked in `xrWaitFrame`
+    pub wait: Percentiles,
+    /// Time from `xrBeginFrame` to `xrEndFrame`
+    pub cpu: Percentiles,
+    /// Predicted display period
+    pub display_period: Percentiles,
+}
+
+/// Distribution of a duration over recent frames
+///
+/// All zero if no frames were recorded.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct Percentiles {
+    pub p50: std::time::Duration,
+    pub p90: std::time::Duration,
+    pub p99: std::time::Duration,
+    pub max: std::time::Duration,
+}
+
+impl Percentiles {
+    fn from_sorted(values: &[std::time::Duration]) -> Self {
+        let rank = |p: usize| match values.len() {
+            0 => std::time::Duration::ZERO,
+            n => values[((n * p).div_ceil(100)).clamp(1, n) - 1],
+        };
+        Self {
+            p50: rank(50),
+            p90: rank(90),
+            p99: rank(99),
+            max: values.last().copied().unwrap_or_default(),
+        }
+    }
+
+    fn json(&self) -> String {
+        format!(
+            "{{\"p50\":{},\"p90\":{},\"p99\":{},\"max\":{}}}",
+            self.p50.as_nanos(),
+            self.p90.as_nanos(),
+            self.p99.as_nanos(),
+            self.max.as_nanos()
+        )
+    }
+}
@@ -4,23 +4,24 @@ use crate::*;
 use std::borrow::Cow;
 use std::mem::MaybeUninit;
 pub use sys::platform::{
-    EGLenum, VkComponentSwizzle, VkFilter, VkSamplerAddressMode, VkSamplerMipmapMode,
+    EGLenum, VkComponentSwizzle, VkFilter, VkFormat, VkImageCreateFlags, VkImageUsageFlags,
+    VkSamplerAddressMode, VkSamplerMipmapMode,
 };
 pub use sys::{
     ActionType, AndroidSurfaceSwapchainFlagsFB, AndroidThreadTypeKHR, BlendFactorFB, BodyJointFB,
-    BodyJointLocationFB, BodyJointSetFB, BodySkeletonJointFB, Color4f, ColorSpaceFB, CompareOpFB,
-    CompositionLayerFlags, CompositionLayerImageLayoutFlagsFB,
+    BodyJointLocationFB, BodyJointSetFB, BodySkeletonJointFB, Boxf, BoxfKHR, Color3f, Color3fKHR,
+    Color4f, ColorSpaceFB, CompareOpFB, CompositionLayerFlags, CompositionLayerImageLayoutFlagsFB,
     CompositionLayerSecureContentFlagsFB, CompositionLayerSettingsFlagsFB,
     CompositionLayerSpaceWarpInfoFlagsFB, DebugUtilsMessageSeverityFlagsEXT,
     DebugUtilsMessageTypeFlagsEXT, DigitalLensControlFlagsALMALENCE, EnvironmentBlendMode,
-    Extent2Df, Extent2Di, Extent3DfEXT, Extent3DfFB, ExternalCameraAttachedToDeviceOCULUS,
-    ExternalCameraExtrinsicsOCULUS, ExternalCameraIntrinsicsOCULUS,
-    ExternalCameraStatusFlagsOCULUS, EyeExpressionHTC, EyePositionFB, EyeVisibility,
-    FaceConfidenceFB, FaceExpressionFB, FaceExpressionSetFB, FacialTrackingTypeHTC,
-    ForceFeedbackCurlApplyLocationMNDX, ForceFeedbackCurlLocationMNDX, FormFactor,
-    FoveationConfigurationHTC, FoveationDynamicFB, FoveationDynamicFlagsHTC,
+    Extent2Df, Extent2Di, Extent3Df, Extent3DfEXT, Extent3DfFB, Extent3DfKHR,
+    ExternalCameraAttachedToDeviceOCULUS, ExternalCameraExtrinsicsOCULUS,
+    ExternalCameraIntrinsicsOCULUS, ExternalCameraStatusFlagsOCULUS, EyeExpressionHTC,
+    EyePositionFB, EyeVisibility, FaceConfidenceFB, FaceExpressionFB, FaceExpressionSetFB,
+    FacialTrackingTypeHTC, ForceFeedbackCurlApplyLocationMNDX, ForceFeedbackCurlLocationMNDX,
+    FormFactor, FoveationConfigurationHTC, FoveationDynamicFB, FoveationDynamicFlagsHTC,
     FoveationEyeTrackedProfileCreateFlagsMETA, FoveationEyeTrackedStateFlagsMETA, FoveationLevelFB,
-    FoveationLevelHTC, FoveationModeHTC, Fovf, FrameEndInfoFlagsML,
+    FoveationLevelHTC, FoveationModeHTC, Fovf, FrameEndInfoFlagsML, Frustumf, FrustumfKHR,
     GlobalDimmerFrameEndInfoFlagsML, HandEXT, HandForearmJointULTRALEAP, HandJointEXT,
     HandJointLocationEXT, HandJointSetEXT, HandJointVelocityEXT, HandJointsMotionRangeEXT,
     HandMeshVertexMSFT, HandPoseTypeMSFT, HandTrackingAimFlagsFB, HandTrackingDataSourceEXT,
@@ -38,10 +39,11 @@ pub use sys::{
     SceneComponentTypeMSFT, SceneComputeConsistencyMSFT, SceneComputeFeatureMSFT,
     SceneComputeStateMSFT, SceneObjectTypeMSFT, ScenePlaneAlignmentTypeMSFT,
     SemanticLabelsSupportFlagsFB, SessionCreateFlags, SessionState, SpaceComponentTypeFB,
-    SpaceLocationFlags, SpacePersistenceModeFB, SpaceQueryActionFB, SpaceStorageLocationFB,
-    SpaceVelocityFlags, SpatialGraphNodeTypeMSFT, StructureType, SwapchainCreateFlags,
-    SwapchainCreateFoveationFlagsFB, SwapchainStateFoveationFlagsFB, SwapchainUsageFlags,
-    SystemGraphicsProperties, TrackingOptimizationSettingsDomainQCOM,
+    SpaceLocationData, SpaceLocationDataKHR, SpaceLocationFlags, SpacePersistenceModeFB,
+    SpaceQueryActionFB, SpaceStorageLocationFB, SpaceVelocityData, SpaceVelocityDataKHR,
+    SpaceVelocityFlags, SpatialGraphNodeTypeMSFT, Spheref, SpherefKHR, StructureType,
+    SwapchainCreateFlags, SwapchainCreateFoveationFlagsFB, SwapchainStateFoveationFlagsFB,
+    SwapchainUsageFlags, SystemGraphicsProperties, TrackingOptimizationSettingsDomainQCOM,
     TrackingOptimizationSettingsHintQCOM, TriangleMeshFlagsFB, Vector2f, Vector3f, Vector4f,
     Vector4sFB, ViewConfigurationType, ViewStateFlags, VirtualKeyboardInputSourceMETA,
     VirtualKeyboardInputStateFlagsMETA, VirtualKeyboardLocationTypeMETA, VisibilityMaskTypeKHR,
@@ -154,6 +156,8 @@ pub struct ExtensionSet {
     pub khr_composition_layer_equirect2: bool,
     pub khr_binding_modification: bool,
     pub khr_swapchain_usage_input_attachment_bit: bool,
+    pub khr_locate_spaces: bool,
+    pub khr_maintenance1: bool,
     pub meta_foveation_eye_tracked: bool,
     pub meta_local_dimming: bool,
     pub meta_passthrough_preferences: bool,
@@ -497,6 +501,12 @@ impl ExtensionSet {
                 raw::SwapchainUsageInputAttachmentBitKHR::NAME => {
                     out.khr_swapchain_usage_input_attachment_bit = true;
                 }
+                raw::LocateSpacesKHR::NAME => {
+                    out.khr_locate_spaces = true;
+                }
+                raw::Maintenance1KHR::NAME => {
+                    out.khr_maintenance1 = true;
+                }
                 raw::FoveationEyeTrackedMETA::NAME => {
                     out.meta_foveation_eye_tracked = true;
                 }
@@ -1115,6 +1125,16 @@ impl ExtensionSet {
                 out.push(raw::SwapchainUsageInputAttachmentBitKHR::NAME.into());
             }
         }
+        {
+            if self.khr_locate_spaces {
+                out.push(raw::LocateSpacesKHR::NAME.into());
+            }
+        }
+        {
+            if self.khr_maintenance1 {
+                out.push(raw::Maintenance1KHR::NAME.into());
+            }
+        }
         {
             if self.meta_foveation_eye_tracked {
                 out.push(raw::FoveationEyeTrackedMETA::NAME.into());
@@ -1450,6 +1470,8 @@ pub struct InstanceExtensions {
     pub khr_composition_layer_equirect2: Option<raw::CompositionLayerEquirect2KHR>,
     pub khr_binding_modification: Option<raw::BindingModificationKHR>,
     pub khr_swapchain_usage_input_attachment_bit: Option<raw::SwapchainUsageInputAttachmentBitKHR>,
+    pub khr_locate_spaces: Option<raw::LocateSpacesKHR>,
+    pub khr_maintenance1: Option<raw::Maintenance1KHR>,
     pub meta_foveation_eye_tracked: Option<raw::FoveationEyeTrackedMETA>,
     pub meta_local_dimming: Option<raw::LocalDimmingMETA>,
     pub meta_passthrough_preferences: Option<raw::PassthroughPreferencesMETA>,
@@ -1998,6 +2020,16 @@ impl InstanceExtensions {
             } else {
                 None
             },
+            khr_locate_spaces: if required.khr_locate_spaces {
+                Some(raw::LocateSpacesKHR::load(entry, instance)?)
+            } else {
+                None
+            },
+            khr_maintenance1: if required.khr_maintenance1 {
+                Some(raw::Maintenance1KHR {})
+            } else {
+                None
+            },
             meta_foveation_eye_tracked: if required.meta_foveation_eye_tracked {
                 Some(raw::FoveationEyeTrackedMETA::load(entry, instance)?)
             } else {
@@ -3244,6 +3276,27 @@ pub mod raw {
             })
         }
     }
+    #[doc = "Commands introduced in OpenXR 1.1"]
+    #[derive(Copy, Clone)]
+    pub struct Instance1_1 {
+        pub locate_spaces: pfn::LocateSpaces,
+    }
+    impl Instance1_1 {
+        #[doc = r" Load the function pointer table"]
+        #[doc = r""]
+        #[doc = r" # Safety"]
+        #[doc = r""]
+        #[doc = r" `instance` must be a valid instance handle created with this API version or"]
+        #[doc = r" later."]
+        pub unsafe fn load(entry: &Entry, instance: sys::Instance) -> Result<Self> {
+            Ok(Self {
+                locate_spaces: mem::transmute(entry.get_instance_proc_addr(
+                    instance,
+                    CStr::from_bytes_with_nul_unchecked(b"xrLocateSpaces\0"),
+                )?),
+            })
+        }
+    }
     #[derive(Copy, Clone)]
     pub struct DigitalLensControlALMALENCE {
         pub set_digital_lens_control: pfn::SetDigitalLensControlALMALENCE,
@@ -4899,6 +4952,33 @@ pub mod raw {
             sys::KHR_SWAPCHAIN_USAGE_INPUT_ATTACHMENT_BIT_EXTENSION_NAME;
     }
     #[derive(Copy, Clone)]
+    pub struct LocateSpacesKHR {
+        pub locate_spaces: pfn::LocateSpacesKHR,
+    }
+    impl LocateSpacesKHR {
+        pub const VERSION: u32 = sys::KHR_locate_spaces_SPEC_VERSION;
+        pub const NAME: &'static [u8] = sys::KHR_LOCATE_SPACES_EXTENSION_NAME;
+        #[doc = r" Load the extension's function pointer table"]
+        #[doc = r""]
+        #[doc = r" # Safety"]
+        #[doc = r""]
+        #[doc = r" `instance` must be a valid instance handle."]
+        pub unsafe fn load(entry: &Entry, instance: sys::Instance) -> Result<Self> {
+            Ok(Self {
+                locate_spaces: mem::transmute(entry.get_instance_proc_addr(
+                    instance,
+                    CStr::from_bytes_with_nul_unchecked(b"xrLocateSpacesKHR\0"),
+                )?),
+            })
+        }
+    }
+    #[derive(Copy, Clone)]
+    pub struct Maintenance1KHR {}
+    impl Maintenance1KHR {
+        pub const VERSION: u32 = sys::KHR_maintenance1_SPEC_VERSION;
+        pub const NAME: &'static [u8] = sys::KHR_MAINTENANCE1_EXTENSION_NAME;
+    }
+    #[derive(Copy, Clone)]
     pub struct FoveationEyeTrackedMETA {
         pub get_foveation_eye_tracked_state: pfn::GetFoveationEyeTrackedStateMETA,
     }
@@ -5764,8 +5844,8 @@ pub(crate) mod builder {
             &self.inner
         }
         #[inline]
-        pub fn swapchain(mut self, value: &'a Swapchain<G>) -> Self {
-            self.inner.swapchain = value.as_raw();
+        pub fn swapchain(mut self, value: &ReleasedImage<'a, G>) -> Self {
+            self.inner.swapchain = value.swapchain().as_raw();
             self
         }
         #[inline]
@@ -5837,6 +5917,14 @@ pub(crate) mod builder {
             self.inner.sub_image = value.inner;
             self
         }
+        #[doc = r" Prepend `value` to the `next` chain, replacing any chain it was previously linked into"]
+        #[inline]
+        pub fn push_next<T: Extends<Self>>(mut self, value: &'a mut T) -> Self {
+            unsafe {
+                push_next(&mut self.inner.next as *mut _ as _, value as *mut T as _);
+            }
+            self
+        }
     }
     impl<'a, G: Graphics> Default for CompositionLayerProjectionView<'a, G> {
         fn default() -> Self {
@@ -6026,6 +6114,14 @@ pub(crate) mod builder {
             self.inner.view_count = value.len() as u32;
             self
         }
+        #[doc = r" Prepend `value` to the `next` chain, replacing any chain it was previously linked into"]
+        #[inline]
+        pub fn push_next<T: Extends<Self>>(mut self, value: &'a mut T) -> Self {
+            unsafe {
+                push_next(&mut self.inner.next as *mut _ as _, value as *mut T as _);
+            }
+            self
+        }
     }
     impl<'a, G: Graphics> Deref for CompositionLayerProjection<'a, G> {
         type Target = CompositionLayerBase<'a, G>;
@@ -6107,6 +6203,14 @@ pub(crate) mod builder {
             self.inner.size = value;
             self
         }
+        #[doc = r" Prepend `value` to the `next` chain, replacing any chain it was previously linked into"]
+        #[inline]
+        pub fn push_next<T: Extends<Self>>(mut self, value: &'a mut T) -> Self {
+            unsafe {
+                push_next(&mut self.inner.next as *mut _ as _, value as *mut T as _);
+            }
+            self
+        }
     }
     impl<'a, G: Graphics> Deref for CompositionLayerQuad<'a, G> {
         type Target = CompositionLayerBase<'a, G>;
@@ -6198,6 +6302,14 @@ pub(crate) mod builder {
             self.inner.aspect_ratio = value;
             self
         }
+        #[doc = r" Prepend `value` to the `next` chain, replacing any chain it was previously linked into"]
+        #[inline]
+        pub fn push_next<T: Extends<Self>>(mut self, value: &'a mut T) -> Self {
+            unsafe {
+                push_next(&mut self.inner.next as *mut _ as _, value as *mut T as _);
+            }
+            self
+        }
     }
     impl<'a, G: Graphics> Deref for CompositionLayerCylinderKHR<'a, G> {
         type Target = CompositionLayerBase<'a, G>;
@@ -6265,8 +6377,8 @@ pub(crate) mod builder {
             self
         }
         #[inline]
-        pub fn swapchain(mut self, value: &'a Swapchain<G>) -> Self {
-            self.inner.swapchain = value.as_raw();
+        pub fn swapchain(mut self, value: &ReleasedImage<'a, G>) -> Self {
+            self.inner.swapchain = value.swapchain().as_raw();
             self
         }
         #[inline]
@@ -6279,6 +6391,14 @@ pub(crate) mod builder {
             self.inner.orientation = value;
             self
         }
+        #[doc = r" Prepend `value` to the `next` chain, replacing any chain it was previously linked into"]
+        #[inline]
+        pub fn push_next<T: Extends<Self>>(mut self, value: &'a mut T) -> Self {
+            unsafe {
+                push_next(&mut self.inner.next as *mut _ as _, value as *mut T as _);
+            }
+            self
+        }
     }
     impl<'a, G: Graphics> Deref for CompositionLayerCubeKHR<'a, G> {
         type Target = CompositionLayerBase<'a, G>;
@@ -6370,6 +6490,14 @@ pub(crate) mod builder {
             self.inner.bias = value;
             self
         }
+        #[doc = r" Prepend `value` to the `next` chain, replacing any chain it was previously linked into"]
+        #[inline]
+        pub fn push_next<T: Extends<Self>>(mut self, value: &'a mut T) -> Self {
+            unsafe {
+                push_next(&mut self.inner.next as *mut _ as _, value as *mut T as _);
+            }
+            self
+        }
     }
     impl<'a, G: Graphics> Deref for CompositionLayerEquirectKHR<'a, G> {
         type Target = CompositionLayerBase<'a, G>;
@@ -6466,6 +6594,14 @@ pub(crate) mod builder {
             self.inner.lower_vertical_angle = value;
             self
         }
+        #[doc = r" Prepend `value` to the `next` chain, replacing any chain it was previously linked into"]
+        #[inline]
+        pub fn push_next<T: Extends<Self>>(mut self, value: &'a mut T) -> Self {
+            unsafe {
+                push_next(&mut self.inner.next as *mut _ as _, value as *mut T as _);
+            }
+            self
+        }
     }
     impl<'a, G: Graphics> Deref for CompositionLayerEquirect2KHR<'a, G> {
         type Target = CompositionLayerBase<'a, G>;
@@ -6537,6 +6673,14 @@ pub(crate) mod builder {
         //     self.inner.color = value.inner;
         //     self
         // }
+        #[doc = r" Prepend `value` to the `next` chain, replacing any chain it was previously linked into"]
+        #[inline]
+        pub fn push_next<T: Extends<Self>>(mut self, value: &'a mut T) -> Self {
+            unsafe {
+                push_next(&mut self.inner.next as *mut _ as _, value as *mut T as _);
+            }
+            self
+        }
     }
     impl<'a, G: Graphics> Deref for CompositionLayerPassthroughHTC<'a, G> {
         type Target = CompositionLayerBase<'a, G>;
@@ -7474,4 +7618,1100 @@ pub(crate) mod builder {
             Self::new()
         }
     }
+    #[derive(Copy, Clone)]
+    #[repr(transparent)]
+    pub struct CompositionLayerDepthInfoKHR<'a, G: Graphics> {
+        inner: sys::CompositionLayerDepthInfoKHR,
+        _marker: PhantomData<&'a G>,
+    }
+    impl<'a, G: Graphics> CompositionLayerDepthInfoKHR<'a, G> {
+        #[inline]
+        pub fn new() -> Self {
+            Self {
+                inner: sys::CompositionLayerDepthInfoKHR {
+                    ty: sys::StructureType::COMPOSITION_LAYER_DEPTH_INFO_KHR,
+                    ..unsafe { mem::zeroed() }
+                },
+                _marker: PhantomData,
+            }
+        }
+        #[doc = r" Initialize with the supplied raw values"]
+        #[doc = r""]
+        #[doc = r" # Safety"]
+        #[doc = r""]
+        #[doc = r" The guarantees normally enforced by this builder (e.g. lifetimes) must be"]
+        #[doc = r" preserved."]
+        #[inline]
+        pub unsafe fn from_raw(inner: sys::CompositionLayerDepthInfoKHR) -> Self {
+            Self {
+                inner,
+                _marker: PhantomData,
+            }
+        }
+        #[inline]
+        pub fn into_raw(self) -> sys::CompositionLayerDepthInfoKHR {
+            self.inner
+        }
+        #[inline]
+        pub fn as_raw(&self) -> &sys::CompositionLayerDepthInfoKHR {
+            &self.inner
+        }
+        #[inline]
+        pub fn sub_image(mut self, value: SwapchainSubImage<'a, G>) -> Self {
+            self.inner.sub_image = value.inner;
+            self
+        }
+        #[inline]
+        pub fn min_depth(mut self, value: f32) -> Self {
+            self.inner.min_depth = value;
+            self
+        }
+        #[inline]
+        pub fn max_depth(mut self, value: f32) -> Self {
+            self.inner.max_depth = value;
+            self
+        }
+        #[inline]
+        pub fn near_z(mut self, value: f32) -> Self {
+            self.inner.near_z = value;
+            self
+        }
+        #[inline]
+        pub fn far_z(mut self, value: f32) -> Self {
+            self.inner.far_z = value;
+            self
+        }
+    }
+    impl<'a, G: Graphics> Default for CompositionLayerDepthInfoKHR<'a, G> {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+    unsafe impl<'a, 'b, G: Graphics> Extends<CompositionLayerProjectionView<'b, G>>
+        for CompositionLayerDepthInfoKHR<'a, G>
+    {
+    }
+    #[derive(Copy, Clone)]
+    #[repr(transparent)]
+    pub struct VulkanSwapchainFormatListCreateInfoKHR<'a> {
+        inner: sys::VulkanSwapchainFormatListCreateInfoKHR,
+        _marker: PhantomData<&'a ()>,
+    }
+    impl<'a> VulkanSwapchainFormatListCreateInfoKHR<'a> {
+        #[inline]
+        pub fn new() -> Self {
+            Self {
+                inner: sys::VulkanSwapchainFormatListCreateInfoKHR {
+                    ty: sys::StructureType::VULKAN_SWAPCHAIN_FORMAT_LIST_CREATE_INFO_KHR,
+                    ..unsafe { mem::zeroed() }
+                },
+                _marker: PhantomData,
+            }
+        }
+        #[doc = r" Initialize with the supplied raw values"]
+        #[doc = r""]
+        #[doc = r" # Safety"]
+        #[doc = r""]
+        #[doc = r" The guarantees normally enforced by this builder (e.g. lifetimes) must be"]
+        #[doc = r" preserved."]
+        #[inline]
+        pub unsafe fn from_raw(inner: sys::VulkanSwapchainFormatListCreateInfoKHR) -> Self {
+            Self {
+                inner,
+                _marker: PhantomData,
+            }
+        }
+        #[inline]
+        pub fn into_raw(self) -> sys::VulkanSwapchainFormatListCreateInfoKHR {
+            self.inner
+        }
+        #[inline]
+        pub fn as_raw(&self) -> &sys::VulkanSwapchainFormatListCreateInfoKHR {
+            &self.inner
+        }
+        #[inline]
+        pub fn view_formats(mut self, value: &'a [VkFormat]) -> Self {
+            self.inner.view_formats = value.as_ptr() as *const _ as _;
+            self.inner.view_format_count = value.len() as u32;
+            self
+        }
+    }
+    impl<'a> Default for VulkanSwapchainFormatListCreateInfoKHR<'a> {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+    unsafe impl<'a, G: Graphics> Extends<SwapchainCreateInfo<G>>
+        for VulkanSwapchainFormatListCreateInfoKHR<'a>
+    {
+    }
+    #[derive(Copy, Clone)]
+    #[repr(transparent)]
+    pub struct VulkanSwapchainCreateInfoMETA<'a> {
+        inner: sys::VulkanSwapchainCreateInfoMETA,
+        _marker: PhantomData<&'a ()>,
+    }
+    impl<'a> VulkanSwapchainCreateInfoMETA<'a> {
+        #[inline]
+        pub fn new() -> Self {
+            Self {
+                inner: sys::VulkanSwapchainCreateInfoMETA {
+                    ty: sys::StructureType::VULKAN_SWAPCHAIN_CREATE_INFO_META,
+                    ..unsafe { mem::zeroed() }
+                },
+                _marker: PhantomData,
+            }
+        }
+        #[doc = r" Initialize with the supplied raw values"]
+        #[doc = r""]
+        #[doc = r" # Safety"]
+        #[doc = r""]
+        #[doc = r" The guarantees normally enforced by this builder (e.g. lifetimes) must be"]
+        #[doc = r" preserved."]
+        #[inline]
+        pub unsafe fn from_raw(inner: sys::VulkanSwapchainCreateInfoMETA) -> Self {
+            Self {
+                inner,
+                _marker: PhantomData,
+            }
+        }
+        #[inline]
+        pub fn into_raw(self) -> sys::VulkanSwapchainCreateInfoMETA {
+            self.inner
+        }
+        #[inline]
+        pub fn as_raw(&self) -> &sys::VulkanSwapchainCreateInfoMETA {
+            &self.inner
+        }
+        #[inline]
+        pub fn additional_create_flags(mut self, value: VkImageCreateFlags) -> Self {
+            self.inner.additional_create_flags = value;
+            self
+        }
+        #[inline]
+        pub fn additional_usage_flags(mut self, value: VkImageUsageFlags) -> Self {
+            self.inner.additional_usage_flags = value;
+            self
+        }
+    }
+    impl<'a> Default for VulkanSwapchainCreateInfoMETA<'a> {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+    unsafe impl<'a, G: Graphics> Extends<SwapchainCreateInfo<G>> for VulkanSwapchainCreateInfoMETA<'a> {}
+    #[derive(Copy, Clone)]
+    #[repr(transparent)]
+    pub struct CompositionLayerImageLayoutFB<'a> {
+        inner: sys::CompositionLayerImageLayoutFB,
+        _marker: PhantomData<&'a ()>,
+    }
+    impl<'a> CompositionLayerImageLayoutFB<'a> {
+        #[inline]
+        pub fn new() -> Self {
+            Self {
+                inner: sys::CompositionLayerImageLayoutFB {
+                    ty: sys::StructureType::COMPOSITION_LAYER_IMAGE_LAYOUT_FB,
+                    ..unsafe { mem::zeroed() }
+                },
+                _marker: PhantomData,
+            }
+        }
+        #[doc = r" Initialize with the supplied raw values"]
+        #[doc = r""]
+        #[doc = r" # Safety"]
+        #[doc = r""]
+        #[doc = r" The guarantees normally enforced by this builder (e.g. lifetimes) must be"]
+        #[doc = r" preserved."]
+        #[inline]
+        pub unsafe fn from_raw(inner: sys::CompositionLayerImageLayoutFB) -> Self {
+            Self {
+                inner,
+                _marker: PhantomData,
+            }
+        }
+        #[inline]
+        pub fn into_raw(self) -> sys::CompositionLayerImageLayoutFB {
+            self.inner
+        }
+        #[inline]
+        pub fn as_raw(&self) -> &sys::CompositionLayerImageLayoutFB {
+            &self.inner
+        }
+        #[inline]
+        pub fn flags(mut self, value: CompositionLayerImageLayoutFlagsFB) -> Self {
+            self.inner.flags = value;
+            self
+        }
+    }
+    impl<'a> Default for CompositionLayerImageLayoutFB<'a> {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+    unsafe impl<'a, 'b, G: Graphics> Extends<CompositionLayerProjection<'b, G>>
+        for CompositionLayerImageLayoutFB<'a>
+    {
+    }
+    unsafe impl<'a, 'b, G: Graphics> Extends<CompositionLayerQuad<'b, G>>
+        for CompositionLayerImageLayoutFB<'a>
+    {
+    }
+    unsafe impl<'a, 'b, G: Graphics> Extends<CompositionLayerCylinderKHR<'b, G>>
+        for CompositionLayerImageLayoutFB<'a>
+    {
+    }
+    unsafe impl<'a, 'b, G: Graphics> Extends<CompositionLayerCubeKHR<'b, G>>
+        for CompositionLayerImageLayoutFB<'a>
+    {
+    }
+    unsafe impl<'a, 'b, G: Graphics> Extends<CompositionLayerEquirectKHR<'b, G>>
+        for CompositionLayerImageLayoutFB<'a>
+    {
+    }
+    unsafe impl<'a, 'b, G: Graphics> Extends<CompositionLayerEquirect2KHR<'b, G>>
+        for CompositionLayerImageLayoutFB<'a>
+    {
+    }
+    unsafe impl<'a, 'b, G: Graphics> Extends<CompositionLayerPassthroughHTC<'b, G>>
+        for CompositionLayerImageLayoutFB<'a>
+    {
+    }
+    #[derive(Copy, Clone)]
+    #[repr(transparent)]
+    pub struct CompositionLayerAlphaBlendFB<'a> {
+        inner: sys::CompositionLayerAlphaBlendFB,
+        _marker: PhantomData<&'a ()>,
+    }
+    impl<'a> CompositionLayerAlphaBlendFB<'a> {
+        #[inline]
+        pub fn new() -> Self {
+            Self {
+                inner: sys::CompositionLayerAlphaBlendFB {
+                    ty: sys::StructureType::COMPOSITION_LAYER_ALPHA_BLEND_FB,
+                    ..unsafe { mem::zeroed() }
+                },
+                _marker: PhantomData,
+            }
+        }
+        #[doc = r" Initialize with the supplied raw values"]
+        #[doc = r""]
+        #[doc = r" # Safety"]
+        #[doc = r""]
+        #[doc = r" The guarantees normally enforced by this builder (e.g. lifetimes) must be"]
+        #[doc = r" preserved."]
+        #[inline]
+        pub unsafe fn from_raw(inner: sys::CompositionLayerAlphaBlendFB) -> Self {
+            Self {
+                inner,
+                _marker: PhantomData,
+            }
+        }
+        #[inline]
+        pub fn into_raw(self) -> sys::CompositionLayerAlphaBlendFB {
+            self.inner
+        }
+        #[inline]
+        pub fn as_raw(&self) -> &sys::CompositionLayerAlphaBlendFB {
+            &self.inner
+        }
+        #[inline]
+        pub fn src_factor_color(mut self, value: BlendFactorFB) -> Self {
+            self.inner.src_factor_color = value;
+            self
+        }
+        #[inline]
+        pub fn dst_factor_color(mut self, value: BlendFactorFB) -> Self {
+            self.inner.dst_factor_color = value;
+            self
+        }
+        #[inline]
+        pub fn src_factor_alpha(mut self, value: BlendFactorFB) -> Self {
+            self.inner.src_factor_alpha = value;
+            self
+        }
+        #[inline]
+        pub fn dst_factor_alpha(mut self, value: BlendFactorFB) -> Self {
+            self.inner.dst_factor_alpha = value;
+            self
+        }
+    }
+    impl<'a> Default for CompositionLayerAlphaBlendFB<'a> {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+    unsafe impl<'a, 'b, G: Graphics> Extends<CompositionLayerProjection<'b, G>>
+        for CompositionLayerAlphaBlendFB<'a>
+    {
+    }
+    unsafe impl<'a, 'b, G: Graphics> Extends<CompositionLayerQuad<'b, G>>
+        for CompositionLayerAlphaBlendFB<'a>
+    {
+    }
+    unsafe impl<'a, 'b, G: Graphics> Extends<CompositionLayerCylinderKHR<'b, G>>
+        for CompositionLayerAlphaBlendFB<'a>
+    {
+    }
+    unsafe impl<'a, 'b, G: Graphics> Extends<CompositionLayerCubeKHR<'b, G>>
+        for CompositionLayerAlphaBlendFB<'a>
+    {
+    }
+    unsafe impl<'a, 'b, G: Graphics> Extends<CompositionLayerEquirectKHR<'b, G>>
+        for CompositionLayerAlphaBlendFB<'a>
+    {
+    }
+    unsafe impl<'a, 'b, G: Graphics> Extends<CompositionLayerEquirect2KHR<'b, G>>
+        for CompositionLayerAlphaBlendFB<'a>
+    {
+    }
+    unsafe impl<'a, 'b, G: Graphics> Extends<CompositionLayerPassthroughHTC<'b, G>>
+        for CompositionLayerAlphaBlendFB<'a>
+    {
+    }
+    #[derive(Copy, Clone)]
+    #[repr(transparent)]
+    pub struct SecondaryViewConfigurationSwapchainCreateInfoMSFT<'a> {
+        inner: sys::SecondaryViewConfigurationSwapchainCreateInfoMSFT,
+        _marker: PhantomData<&'a ()>,
+    }
+    impl<'a> SecondaryViewConfigurationSwapchainCreateInfoMSFT<'a> {
+        #[inline]
+        pub fn new() -> Self {
+            Self {
+                inner: sys::SecondaryViewConfigurationSwapchainCreateInfoMSFT {
+                    ty: sys::StructureType::SECONDARY_VIEW_CONFIGURATION_SWAPCHAIN_CREATE_INFO_MSFT,
+                    ..unsafe { mem::zeroed() }
+                },
+                _marker: PhantomData,
+            }
+        }
+        #[doc = r" Initialize with the supplied raw values"]
+        #[doc = r""]
+        #[doc = r" # Safety"]
+        #[doc = r""]
+        #[doc = r" The guarantees normally enforced by this builder (e.g. lifetimes) must be"]
+        #[doc = r" preserved."]
+        #[inline]
+        pub unsafe fn from_raw(
+            inner: sys::SecondaryViewConfigurationSwapchainCreateInfoMSFT,
+        ) -> Self {
+            Self {
+                inner,
+                _marker: PhantomData,
+            }
+        }
+        #[inline]
+        pub fn into_raw(self) -> sys::SecondaryViewConfigurationSwapchainCreateInfoMSFT {
+            self.inner
+        }
+        #[inline]
+        pub fn as_raw(&self) -> &sys::SecondaryViewConfigurationSwapchainCreateInfoMSFT {
+            &self.inner
+        }
+        #[inline]
+        pub fn view_configuration_type(mut self, value: ViewConfigurationType) -> Self {
+            self.inner.view_configuration_type = value;
+            self
+        }
+    }
+    impl<'a> Default for SecondaryViewConfigurationSwapchainCreateInfoMSFT<'a> {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+    unsafe impl<'a, G: Graphics> Extends<SwapchainCreateInfo<G>>
+        for SecondaryViewConfigurationSwapchainCreateInfoMSFT<'a>
+    {
+    }
+    #[derive(Copy, Clone)]
+    #[repr(transparent)]
+    #[cfg(target_os = "android")]
+    pub struct AndroidSurfaceSwapchainCreateInfoFB<'a> {
+        inner: sys::AndroidSurfaceSwapchainCreateInfoFB,
+        _marker: PhantomData<&'a ()>,
+    }
+    #[cfg(target_os = "android")]
+    impl<'a> AndroidSurfaceSwapchainCreateInfoFB<'a> {
+        #[inline]
+        pub fn new() -> Self {
+            Self {
+                inner: sys::AndroidSurfaceSwapchainCreateInfoFB {
+                    ty: sys::StructureType::ANDROID_SURFACE_SWAPCHAIN_CREATE_INFO_FB,
+                    ..unsafe { mem::zeroed() }
+                },
+                _marker: PhantomData,
+            }
+        }
+        #[doc = r" Initialize with the supplied raw values"]
+        #[doc = r""]
+        #[doc = r" # Safety"]
+        #[doc = r""]
+        #[doc = r" The guarantees normally enforced by this builder (e.g. lifetimes) must be"]
+        #[doc = r" preserved."]
+        #[inline]
+        pub unsafe fn from_raw(inner: sys::AndroidSurfaceSwapchainCreateInfoFB) -> Self {
+            Self {
+                inner,
+                _marker: PhantomData,
+            }
+        }
+        #[inline]
+        pub fn into_raw(self) -> sys::AndroidSurfaceSwapchainCreateInfoFB {
+            self.inner
+        }
+        #[inline]
+        pub fn as_raw(&self) -> &sys::AndroidSurfaceSwapchainCreateInfoFB {
+            &self.inner
+        }
+        #[inline]
+        pub fn create_flags(mut self, value: AndroidSurfaceSwapchainFlagsFB) -> Self {
+            self.inner.create_flags = value;
+            self
+        }
+    }
+    #[cfg(target_os = "android")]
+    impl<'a> Default for AndroidSurfaceSwapchainCreateInfoFB<'a> {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+    #[cfg(target_os = "android")]
+    unsafe impl<'a, G: Graphics> Extends<SwapchainCreateInfo<G>>
+        for AndroidSurfaceSwapchainCreateInfoFB<'a>
+    {
+    }
+    #[derive(Copy, Clone)]
+    #[repr(transparent)]
+    pub struct CompositionLayerSecureContentFB<'a> {
+        inner: sys::CompositionLayerSecureContentFB,
+        _marker: PhantomData<&'a ()>,
+    }
+    impl<'a> CompositionLayerSecureContentFB<'a> {
+        #[inline]
+        pub fn new() -> Self {
+            Self {
+                inner: sys::CompositionLayerSecureContentFB {
+                    ty: sys::StructureType::COMPOSITION_LAYER_SECURE_CONTENT_FB,
+                    ..unsafe { mem::zeroed() }
+                },
+                _marker: PhantomData,
+            }
+        }
+        #[doc = r" Initialize with the supplied raw values"]
+        #[doc = r""]
+        #[doc = r" # Safety"]
+        #[doc = r""]
+        #[doc = r" The guarantees normally enforced by this builder (e.g. lifetimes) must be"]
+        #[doc = r" preserved."]
+        #[inline]
+        pub unsafe fn from_raw(inner: sys::CompositionLayerSecureContentFB) -> Self {
+            Self {
+                inner,
+                _marker: PhantomData,
+            }
+        }
+        #[inline]
+        pub fn into_raw(self) -> sys::CompositionLayerSecureContentFB {
+            self.inner
+        }
+        #[inline]
+        pub fn as_raw(&self) -> &sys::CompositionLayerSecureContentFB {
+            &self.inner
+        }
+        #[inline]
+        pub fn flags(mut self, value: CompositionLayerSecureContentFlagsFB) -> Self {
+            self.inner.flags = value;
+            self
+        }
+    }
+    impl<'a> Default for CompositionLayerSecureContentFB<'a> {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+    unsafe impl<'a, 'b, G: Graphics> Extends<CompositionLayerProjection<'b, G>>
+        for CompositionLayerSecureContentFB<'a>
+    {
+    }
+    unsafe impl<'a, 'b, G: Graphics> Extends<CompositionLayerQuad<'b, G>>
+        for CompositionLayerSecureContentFB<'a>
+    {
+    }
+    unsafe impl<'a, 'b, G: Graphics> Extends<CompositionLayerCylinderKHR<'b, G>>
+        for CompositionLayerSecureContentFB<'a>
+    {
+    }
+    unsafe impl<'a, 'b, G: Graphics> Extends<CompositionLayerCubeKHR<'b, G>>
+        for CompositionLayerSecureContentFB<'a>
+    {
+    }
+    unsafe impl<'a, 'b, G: Graphics> Extends<CompositionLayerEquirectKHR<'b, G>>
+        for CompositionLayerSecureContentFB<'a>
+    {
+    }
+    unsafe impl<'a, 'b, G: Graphics> Extends<CompositionLayerEquirect2KHR<'b, G>>
+        for CompositionLayerSecureContentFB<'a>
+    {
+    }
+    unsafe impl<'a, 'b, G: Graphics> Extends<CompositionLayerPassthroughHTC<'b, G>>
+        for CompositionLayerSecureContentFB<'a>
+    {
+    }
+    #[derive(Copy, Clone)]
+    #[repr(transparent)]
+    pub struct CompositionLayerColorScaleBiasKHR<'a> {
+        inner: sys::CompositionLayerColorScaleBiasKHR,
+        _marker: PhantomData<&'a ()>,
+    }
+    impl<'a> CompositionLayerColorScaleBiasKHR<'a> {
+        #[inline]
+        pub fn new() -> Self {
+            Self {
+                inner: sys::CompositionLayerColorScaleBiasKHR {
+                    ty: sys::StructureType::COMPOSITION_LAYER_COLOR_SCALE_BIAS_KHR,
+                    ..unsafe { mem::zeroed() }
+                },
+                _marker: PhantomData,
+            }
+        }
+        #[doc = r" Initialize with the supplied raw values"]
+        #[doc = r""]
+        #[doc = r" # Safety"]
+        #[doc = r""]
+        #[doc = r" The guarantees normally enforced by this builder (e.g. lifetimes) must be"]
+        #[doc = r" preserved."]
+        #[inline]
+        pub unsafe fn from_raw(inner: sys::CompositionLayerColorScaleBiasKHR) -> Self {
+            Self {
+                inner,
+                _marker: PhantomData,
+            }
+        }
+        #[inline]
+        pub fn into_raw(self) -> sys::CompositionLayerColorScaleBiasKHR {
+            self.inner
+        }
+        #[inline]
+        pub fn as_raw(&self) -> &sys::CompositionLayerColorScaleBiasKHR {
+            &self.inner
+        }
+        #[inline]
+        pub fn color_scale(mut self, value: Color4f) -> Self {
+            self.inner.color_scale = value;
+            self
+        }
+        #[inline]
+        pub fn color_bias(mut self, value: Color4f) -> Self {
+            self.inner.color_bias = value;
+            self
+        }
+    }
+    impl<'a> Default for CompositionLayerColorScaleBiasKHR<'a> {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+    unsafe impl<'a, 'b, G: Graphics> Extends<CompositionLayerProjection<'b, G>>
+        for CompositionLayerColorScaleBiasKHR<'a>
+    {
+    }
+    unsafe impl<'a, 'b, G: Graphics> Extends<CompositionLayerQuad<'b, G>>
+        for CompositionLayerColorScaleBiasKHR<'a>
+    {
+    }
+    unsafe impl<'a, 'b, G: Graphics> Extends<CompositionLayerCylinderKHR<'b, G>>
+        for CompositionLayerColorScaleBiasKHR<'a>
+    {
+    }
+    unsafe impl<'a, 'b, G: Graphics> Extends<CompositionLayerCubeKHR<'b, G>>
+        for CompositionLayerColorScaleBiasKHR<'a>
+    {
+    }
+    unsafe impl<'a, 'b, G: Graphics> Extends<CompositionLayerEquirectKHR<'b, G>>
+        for CompositionLayerColorScaleBiasKHR<'a>
+    {
+    }
+    unsafe impl<'a, 'b, G: Graphics> Extends<CompositionLayerEquirect2KHR<'b, G>>
+        for CompositionLayerColorScaleBiasKHR<'a>
+    {
+    }
+    unsafe impl<'a, 'b, G: Graphics> Extends<CompositionLayerPassthroughHTC<'b, G>>
+        for CompositionLayerColorScaleBiasKHR<'a>
+    {
+    }
+    #[derive(Copy, Clone)]
+    #[repr(transparent)]
+    pub struct SwapchainCreateInfoFoveationFB<'a> {
+        inner: sys::SwapchainCreateInfoFoveationFB,
+        _marker: PhantomData<&'a ()>,
+    }
+    impl<'a> SwapchainCreateInfoFoveationFB<'a> {
+        #[inline]
+        pub fn new() -> Self {
+            Self {
+                inner: sys::SwapchainCreateInfoFoveationFB {
+                    ty: sys::StructureType::SWAPCHAIN_CREATE_INFO_FOVEATION_FB,
+                    ..unsafe { mem::zeroed() }
+                },
+                _marker: PhantomData,
+            }
+        }
+        #[doc = r" Initialize with the supplied raw values"]
+        #[doc = r""]
+        #[doc = r" # Safety"]
+        #[doc = r""]
+        #[doc = r" The guarantees normally enforced by this builder (e.g. lifetimes) must be"]
+        #[doc = r" preserved."]
+        #[inline]
+        pub unsafe fn from_raw(inner: sys::SwapchainCreateInfoFoveationFB) -> Self {
+            Self {
+                inner,
+                _marker: PhantomData,
+            }
+        }
+        #[inline]
+        pub fn into_raw(self) -> sys::SwapchainCreateInfoFoveationFB {
+            self.inner
+        }
+        #[inline]
+        pub fn as_raw(&self) -> &sys::SwapchainCreateInfoFoveationFB {
+            &self.inner
+        }
+        #[inline]
+        pub fn flags(mut self, value: SwapchainCreateFoveationFlagsFB) -> Self {
+            self.inner.flags = value;
+            self
+        }
+    }
+    impl<'a> Default for SwapchainCreateInfoFoveationFB<'a> {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+    unsafe impl<'a, G: Graphics> Extends<SwapchainCreateInfo<G>>
+        for SwapchainCreateInfoFoveationFB<'a>
+    {
+    }
+    #[derive(Copy, Clone)]
+    #[repr(transparent)]
+    pub struct CompositionLayerDepthTestVARJO<'a> {
+        inner: sys::CompositionLayerDepthTestVARJO,
+        _marker: PhantomData<&'a ()>,
+    }
+    impl<'a> CompositionLayerDepthTestVARJO<'a> {
+        #[inline]
+        pub fn new() -> Self {
+            Self {
+                inner: sys::CompositionLayerDepthTestVARJO {
+                    ty: sys::StructureType::COMPOSITION_LAYER_DEPTH_TEST_VARJO,
+                    ..unsafe { mem::zeroed() }
+                },
+                _marker: PhantomData,
+            }
+        }
+        #[doc = r" Initialize with the supplied raw values"]
+        #[doc = r""]
+        #[doc = r" # Safety"]
+        #[doc = r""]
+        #[doc = r" The guarantees normally enforced by this builder (e.g. lifetimes) must be"]
+        #[doc = r" preserved."]
+        #[inline]
+        pub unsafe fn from_raw(inner: sys::CompositionLayerDepthTestVARJO) -> Self {
+            Self {
+                inner,
+                _marker: PhantomData,
+            }
+        }
+        #[inline]
+        pub fn into_raw(self) -> sys::CompositionLayerDepthTestVARJO {
+            self.inner
+        }
+        #[inline]
+        pub fn as_raw(&self) -> &sys::CompositionLayerDepthTestVARJO {
+            &self.inner
+        }
+        #[inline]
+        pub fn depth_test_range_near_z(mut self, value: f32) -> Self {
+            self.inner.depth_test_range_near_z = value;
+            self
+        }
+        #[inline]
+        pub fn depth_test_range_far_z(mut self, value: f32) -> Self {
+            self.inner.depth_test_range_far_z = value;
+            self
+        }
+    }
+    impl<'a> Default for CompositionLayerDepthTestVARJO<'a> {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+    unsafe impl<'a, 'b, G: Graphics> Extends<CompositionLayerProjection<'b, G>>
+        for CompositionLayerDepthTestVARJO<'a>
+    {
+    }
+    #[derive(Copy, Clone)]
+    #[repr(transparent)]
+    pub struct CompositionLayerReprojectionInfoMSFT<'a> {
+        inner: sys::CompositionLayerReprojectionInfoMSFT,
+        _marker: PhantomData<&'a ()>,
+    }
+    impl<'a> CompositionLayerReprojectionInfoMSFT<'a> {
+        #[inline]
+        pub fn new() -> Self {
+            Self {
+                inner: sys::CompositionLayerReprojectionInfoMSFT {
+                    ty: sys::StructureType::COMPOSITION_LAYER_REPROJECTION_INFO_MSFT,
+                    ..unsafe { mem::zeroed() }
+                },
+                _marker: PhantomData,
+            }
+        }
+        #[doc = r" Initialize with the supplied raw values"]
+        #[doc = r""]
+        #[doc = r" # Safety"]
+        #[doc = r""]
+        #[doc = r" The guarantees normally enforced by this builder (e.g. lifetimes) must be"]
+        #[doc = r" preserved."]
+        #[inline]
+        pub unsafe fn from_raw(inner: sys::CompositionLayerReprojectionInfoMSFT) -> Self {
+            Self {
+                inner,
+                _marker: PhantomData,
+            }
+        }
+        #[inline]
+        pub fn into_raw(self) -> sys::CompositionLayerReprojectionInfoMSFT {
+            self.inner
+        }
+        #[inline]
+        pub fn as_raw(&self) -> &sys::CompositionLayerReprojectionInfoMSFT {
+            &self.inner
+        }
+        #[inline]
+        pub fn reprojection_mode(mut self, value: ReprojectionModeMSFT) -> Self {
+            self.inner.reprojection_mode = value;
+            self
+        }
+    }
+    impl<'a> Default for CompositionLayerReprojectionInfoMSFT<'a> {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+    unsafe impl<'a, 'b, G: Graphics> Extends<CompositionLayerProjection<'b, G>>
+        for CompositionLayerReprojectionInfoMSFT<'a>
+    {
+    }
+    #[derive(Copy, Clone)]
+    #[repr(transparent)]
+    pub struct CompositionLayerReprojectionPlaneOverrideMSFT<'a> {
+        inner: sys::CompositionLayerReprojectionPlaneOverrideMSFT,
+        _marker: PhantomData<&'a ()>,
+    }
+    impl<'a> CompositionLayerReprojectionPlaneOverrideMSFT<'a> {
+        #[inline]
+        pub fn new() -> Self {
+            Self {
+                inner: sys::CompositionLayerReprojectionPlaneOverrideMSFT {
+                    ty: sys::StructureType::COMPOSITION_LAYER_REPROJECTION_PLANE_OVERRIDE_MSFT,
+                    ..unsafe { mem::zeroed() }
+                },
+                _marker: PhantomData,
+            }
+        }
+        #[doc = r" Initialize with the supplied raw values"]
+        #[doc = r""]
+        #[doc = r" # Safety"]
+        #[doc = r""]
+        #[doc = r" The guarantees normally enforced by this builder (e.g. lifetimes) must be"]
+        #[doc = r" preserved."]
+        #[inline]
+        pub unsafe fn from_raw(inner: sys::CompositionLayerReprojectionPlaneOverrideMSFT) -> Self {
+            Self {
+                inner,
+                _marker: PhantomData,
+            }
+        }
+        #[inline]
+        pub fn into_raw(self) -> sys::CompositionLayerReprojectionPlaneOverrideMSFT {
+            self.inner
+        }
+        #[inline]
+        pub fn as_raw(&self) -> &sys::CompositionLayerReprojectionPlaneOverrideMSFT {
+            &self.inner
+        }
+        #[inline]
+        pub fn position(mut self, value: Vector3f) -> Self {
+            self.inner.position = value;
+            self
+        }
+        #[inline]
+        pub fn normal(mut self, value: Vector3f) -> Self {
+            self.inner.normal = value;
+            self
+        }
+        #[inline]
+        pub fn velocity(mut self, value: Vector3f) -> Self {
+            self.inner.velocity = value;
+            self
+        }
+    }
+    impl<'a> Default for CompositionLayerReprojectionPlaneOverrideMSFT<'a> {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+    unsafe impl<'a, 'b, G: Graphics> Extends<CompositionLayerProjection<'b, G>>
+        for CompositionLayerReprojectionPlaneOverrideMSFT<'a>
+    {
+    }
+    #[derive(Copy, Clone)]
+    #[repr(transparent)]
+    pub struct CompositionLayerSpaceWarpInfoFB<'a, G: Graphics> {
+        inner: sys::CompositionLayerSpaceWarpInfoFB,
+        _marker: PhantomData<&'a G>,
+    }
+    impl<'a, G: Graphics> CompositionLayerSpaceWarpInfoFB<'a, G> {
+        #[inline]
+        pub fn new() -> Self {
+            Self {
+                inner: sys::CompositionLayerSpaceWarpInfoFB {
+                    ty: sys::StructureType::COMPOSITION_LAYER_SPACE_WARP_INFO_FB,
+                    ..unsafe { mem::zeroed() }
+                },
+                _marker: PhantomData,
+            }
+        }
+        #[doc = r" Initialize with the supplied raw values"]
+        #[doc = r""]
+        #[doc = r" # Safety"]
+        #[doc = r""]
+        #[doc = r" The guarantees normally enforced by this builder (e.g. lifetimes) must be"]
+        #[doc = r" preserved."]
+        #[inline]
+        pub unsafe fn from_raw(inner: sys::CompositionLayerSpaceWarpInfoFB) -> Self {
+            Self {
+                inner,
+                _marker: PhantomData,
+            }
+        }
+        #[inline]
+        pub fn into_raw(self) -> sys::CompositionLayerSpaceWarpInfoFB {
+            self.inner
+        }
+        #[inline]
+        pub fn as_raw(&self) -> &sys::CompositionLayerSpaceWarpInfoFB {
+            &self.inner
+        }
+        #[inline]
+        pub fn layer_flags(mut self, value: CompositionLayerSpaceWarpInfoFlagsFB) -> Self {
+            self.inner.layer_flags = value;
+            self
+        }
+        #[inline]
+        pub fn motion_vector_sub_image(mut self, value: SwapchainSubImage<'a, G>) -> Self {
+            self.inner.motion_vector_sub_image = value.inner;
+            self
+        }
+        #[inline]
+        pub fn app_space_delta_pose(mut self, value: Posef) -> Self {
+            self.inner.app_space_delta_pose = value;
+            self
+        }
+        #[inline]
+        pub fn depth_sub_image(mut self, value: SwapchainSubImage<'a, G>) -> Self {
+            self.inner.depth_sub_image = value.inner;
+            self
+        }
+        #[inline]
+        pub fn min_depth(mut self, value: f32) -> Self {
+            self.inner.min_depth = value;
+            self
+        }
+        #[inline]
+        pub fn max_depth(mut self, value: f32) -> Self {
+            self.inner.max_depth = value;
+            self
+        }
+        #[inline]
+        pub fn near_z(mut self, value: f32) -> Self {
+            self.inner.near_z = value;
+            self
+        }
+        #[inline]
+        pub fn far_z(mut self, value: f32) -> Self {
+            self.inner.far_z = value;
+            self
+        }
+    }
+    impl<'a, G: Graphics> Default for CompositionLayerSpaceWarpInfoFB<'a, G> {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+    unsafe impl<'a, 'b, G: Graphics> Extends<CompositionLayerProjectionView<'b, G>>
+        for CompositionLayerSpaceWarpInfoFB<'a, G>
+    {
+    }
+    #[derive(Copy, Clone)]
+    #[repr(transparent)]
+    pub struct CompositionLayerSettingsFB<'a> {
+        inner: sys::CompositionLayerSettingsFB,
+        _marker: PhantomData<&'a ()>,
+    }
+    impl<'a> CompositionLayerSettingsFB<'a> {
+        #[inline]
+        pub fn new() -> Self {
+            Self {
+                inner: sys::CompositionLayerSettingsFB {
+                    ty: sys::StructureType::COMPOSITION_LAYER_SETTINGS_FB,
+                    ..unsafe { mem::zeroed() }
+                },
+                _marker: PhantomData,
+            }
+        }
+        #[doc = r" Initialize with the supplied raw values"]
+        #[doc = r""]
+        #[doc = r" # Safety"]
+        #[doc = r""]
+        #[doc = r" The guarantees normally enforced by this builder (e.g. lifetimes) must be"]
+        #[doc = r" preserved."]
+        #[inline]
+        pub unsafe fn from_raw(inner: sys::CompositionLayerSettingsFB) -> Self {
+            Self {
+                inner,
+                _marker: PhantomData,
+            }
+        }
+        #[inline]
+        pub fn into_raw(self) -> sys::CompositionLayerSettingsFB {
+            self.inner
+        }
+        #[inline]
+        pub fn as_raw(&self) -> &sys::CompositionLayerSettingsFB {
+            &self.inner
+        }
+        #[inline]
+        pub fn layer_flags(mut self, value: CompositionLayerSettingsFlagsFB) -> Self {
+            self.inner.layer_flags = value;
+            self
+        }
+    }
+    impl<'a> Default for CompositionLayerSettingsFB<'a> {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+    unsafe impl<'a, 'b, G: Graphics> Extends<CompositionLayerProjection<'b, G>>
+        for CompositionLayerSettingsFB<'a>
+    {
+    }
+    unsafe impl<'a, 'b, G: Graphics> Extends<CompositionLayerQuad<'b, G>>
+        for CompositionLayerSettingsFB<'a>
+    {
+    }
+    unsafe impl<'a, 'b, G: Graphics> Extends<CompositionLayerCylinderKHR<'b, G>>
+        for CompositionLayerSettingsFB<'a>
+    {
+    }
+    unsafe impl<'a, 'b, G: Graphics> Extends<CompositionLayerCubeKHR<'b, G>>
+        for CompositionLayerSettingsFB<'a>
+    {
+    }
+    unsafe impl<'a, 'b, G: Graphics> Extends<CompositionLayerEquirectKHR<'b, G>>
+        for CompositionLayerSettingsFB<'a>
+    {
+    }
+    unsafe impl<'a, 'b, G: Graphics> Extends<CompositionLayerEquirect2KHR<'b, G>>
+        for CompositionLayerSettingsFB<'a>
+    {
+    }
+    unsafe impl<'a, 'b, G: Graphics> Extends<CompositionLayerPassthroughHTC<'b, G>>
+        for CompositionLayerSettingsFB<'a>
+    {
+    }
+    #[derive(Copy, Clone)]
+    #[repr(transparent)]
+    pub struct CompositionLayerDepthTestFB<'a> {
+        inner: sys::CompositionLayerDepthTestFB,
+        _marker: PhantomData<&'a ()>,
+    }
+    impl<'a> CompositionLayerDepthTestFB<'a> {
+        #[inline]
+        pub fn new() -> Self {
+            Self {
+                inner: sys::CompositionLayerDepthTestFB {
+                    ty: sys::StructureType::COMPOSITION_LAYER_DEPTH_TEST_FB,
+                    ..unsafe { mem::zeroed() }
+                },
+                _marker: PhantomData,
+            }
+        }
+        #[doc = r" Initialize with the supplied raw values"]
+        #[doc = r""]
+        #[doc = r" # Safety"]
+        #[doc = r""]
+        #[doc = r" The guarantees normally enforced by this builder (e.g. lifetimes) must be"]
+        #[doc = r" preserved."]
+        #[inline]
+        pub unsafe fn from_raw(inner: sys::CompositionLayerDepthTestFB) -> Self {
+            Self {
+                inner,
+                _marker: PhantomData,
+            }
+        }
+        #[inline]
+        pub fn into_raw(self) -> sys::CompositionLayerDepthTestFB {
+            self.inner
+        }
+        #[inline]
+        pub fn as_raw(&self) -> &sys::CompositionLayerDepthTestFB {
+            &self.inner
+        }
+        #[inline]
+        pub fn depth_mask(mut self, value: bool) -> Self {
+            self.inner.depth_mask = value.into();
+            self
+        }
+        #[inline]
+        pub fn compare_op(mut self, value: CompareOpFB) -> Self {
+            self.inner.compare_op = value;
+            self
+        }
+    }
+    impl<'a> Default for CompositionLayerDepthTestFB<'a> {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+    unsafe impl<'a, 'b, G: Graphics> Extends<CompositionLayerProjection<'b, G>>
+        for CompositionLayerDepthTestFB<'a>
+    {
+    }
+    unsafe impl<'a, 'b, G: Graphics> Extends<CompositionLayerQuad<'b, G>>
+        for CompositionLayerDepthTestFB<'a>
+    {
+    }
+    unsafe impl<'a, 'b, G: Graphics> Extends<CompositionLayerCylinderKHR<'b, G>>
+        for CompositionLayerDepthTestFB<'a>
+    {
+    }
+    unsafe impl<'a, 'b, G: Graphics> Extends<CompositionLayerCubeKHR<'b, G>>
+        for CompositionLayerDepthTestFB<'a>
+    {
+    }
+    unsafe impl<'a, 'b, G: Graphics> Extends<CompositionLayerEquirectKHR<'b, G>>
+        for CompositionLayerDepthTestFB<'a>
+    {
+    }
+    unsafe impl<'a, 'b, G: Graphics> Extends<CompositionLayerEquirect2KHR<'b, G>>
+        for CompositionLayerDepthTestFB<'a>
+    {
+    }
+    unsafe impl<'a, 'b, G: Graphics> Extends<CompositionLayerPassthroughHTC<'b, G>>
+        for CompositionLayerDepthTestFB<'a>
+    {
+    }
 }
@@ -24,14 +24,51 @@ impl Graphics for D3D11 {
         x.into()
     }
 
+    fn describe_format(format: u32) -> Option<FormatInfo> {
+        Some(match format {
+            // DXGI_FORMAT_B5G6R5_UNORM
+            85 => FormatInfo::color(5, 0, false, false),
+            // B8G8R8X8_UNORM
+            88 => FormatInfo::color(8, 0, false, false),
+            // B8G8R8X8_UNORM_SRGB
+            93 => FormatInfo::color(8, 0, true, false),
+            // R8G8B8A8_UNORM, B8G8R8A8_UNORM
+            28 | 87 => FormatInfo::color(8, 8, false, false),
+            // R8G8B8A8_UNORM_SRGB, B8G8R8A8_UNORM_SRGB
+            29 | 91 => FormatInfo::color(8, 8, true, false),
+            // R10G10B10A2_UNORM
+            24 => FormatInfo::color(10, 2, false, false),
+            // R16G16B16A16_UNORM
+            11 => FormatInfo::color(16, 16, false, false),
+            // R16G16B16A16_FLOAT
+            10 => FormatInfo::color(16, 16, false, true),
+            // R32G32B32A32_FLOAT
+            2 => FormatInfo::color(32, 32, false, true),
+            // R11G11B10_FLOAT
+            26 => FormatInfo::color(10, 0, false, true),
+            // D16_UNORM
+            55 => FormatInfo::depth(16, 0, false),
+            // D32_FLOAT
+            40 => FormatInfo::depth(32, 0, true),
+            // D24_UNORM_S8_UINT
+            45 => FormatInfo::depth(24, 8, false),
+            // D32_FLOAT_S8X24_UINT
+            20 => FormatInfo::depth(32, 8, true),
+            _ => return None,
+        })
+    }
+
     fn requirements(inst: &Instance, system: SystemId) -> Result<Requirements> {
         let out = unsafe {
             let mut x = sys::GraphicsRequirementsD3D11KHR::out(ptr::null_mut());
-            cvt((inst.d3d11().get_d3d11_graphics_requirements)(
-                inst.as_raw(),
-                system,
-                x.as_mut_ptr(),
-            ))?;
+            cvt(
+                "xrGetD3D11GraphicsRequirementsKHR",
+                (inst.d3d11().get_d3d11_graphics_requirements)(
+                    inst.as_raw(),
+                    system,
+                    x.as_mut_ptr(),
+                ),
+            )?;
             x.assume_init()
         };
         Ok(Requirements {
@@ -50,43 +87,28 @@ impl Graphics for D3D11 {
             next: ptr::null(),
             device: info.device,
         };
-        let info = sys::SessionCreateInfo {
-            ty: sys::SessionCreateInfo::TYPE,
-            next: &binding as *const _ as *const _,
-            create_flags: Default::default(),
-            system_id: system,
-        };
-        let mut out = sys::Session::NULL;
-        cvt((instance.fp().create_session)(
-            instance.as_raw(),
-            &info,
-            &mut out,
-        ))?;
-        Ok(out)
+        create_session_with_binding(instance, system, &binding)
     }
 
     fn enumerate_swapchain_images(
         swapchain: &Swapchain<Self>,
     ) -> Result<Vec<Self::SwapchainImage>> {
-        let images = get_arr_init(
-            sys::SwapchainImageD3D11KHR {
-                ty: sys::SwapchainImageD3D11KHR::TYPE,
-                next: ptr::null_mut(),
-                texture: ptr::null_mut(),
-            },
-            |capacity, count, buf| unsafe {
-                (swapchain.instance().fp().enumerate_swapchain_images)(
-                    swapchain.as_raw(),
-                    capacity,
-                    count,
-                    buf as *mut _,
-                )
-            },
-        )?;
+        let images = unsafe {
+            enumerate_swapchain_images_with(
+                swapchain,
+                sys::SwapchainImageD3D11KHR {
+                    ty: sys::SwapchainImageD3D11KHR::TYPE,
+                    next: ptr::null_mut(),
+                    texture: ptr::null_mut(),
+                },
+            )?
+        };
         Ok(images.into_iter().map(|x| x.texture).collect())
     }
 }
 
+impl RendersFrames for D3D11 {}
+
 #[derive(Copy, Clone)]
 pub struct Requirements {
     pub adapter_luid: LUID,
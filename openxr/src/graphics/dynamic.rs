@@ -0,0 +1,251 @@
+use std::ptr;
+
+use sys::platform::*;
+
+use crate::*;
+
+/// A graphics API chosen at runtime from [`Vulkan`], [`OpenGL`] and [`OpenGlEs`]
+///
+/// Sessions, frame streams, swapchains and composition layers have the same type whichever API a
+/// session is created with, so code handling them needn't be instantiated once per API. The API
+/// is selected by the [`SessionCreateInfo`] variant and reported by [`Session::backend`], and
+/// swapchain images are tagged with it.
+///
+/// Swapchain formats are in their OpenXR representation: a `VkFormat` for Vulkan sessions, or a GL
+/// internal format for OpenGL and OpenGL ES sessions. As formats don't record their API,
+/// [`Graphics::describe_format`] describes none of them, but [`Session::select_swapchain_formats`]
+/// and [`Session::create_depth_swapchain`] describe them according to the session's API.
+///
+/// # Example
+///
+/// ```no_run
+/// # unsafe fn dummy(
+/// #     instance: &openxr::Instance,
+/// #     system: openxr::SystemId,
+/// #     vulkan: Option<openxr::vulkan::SessionCreateInfo>,
+/// #     opengl: openxr::opengl::SessionCreateInfo,
+/// # ) -> openxr::Result<()> {
+/// use openxr::dynamic::{SessionCreateInfo, SwapchainImage};
+///
+/// let info = match vulkan {
+///     Some(x) => SessionCreateInfo::Vulkan(x),
+///     None => SessionCreateInfo::OpenGL(opengl),
+/// };
+/// let (session, frame_waiter, frame_stream) =
+///     instance.create_session::<openxr::Dynamic>(system, &info)?;
+/// let format = session.enumerate_swapchain_formats()?[0];
+/// # let swapchain_info: openxr::SwapchainCreateInfo<openxr::Dynamic> = unimplemented!();
+/// let swapchain = session.create_swapchain(&swapchain_info)?;
+/// for image in swapchain.enumerate_images()? {
+///     match image {
+///         SwapchainImage::Vulkan(image) => { /* wrap VkImage */ }
+///         SwapchainImage::OpenGL(texture) | SwapchainImage::OpenGlEs(texture) => {
+///             /* wrap GL texture name */
+///         }
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub enum Dynamic {}
+
+impl Graphics for Dynamic {
+    type Requirements = Requirements;
+    type SessionCreateInfo = SessionCreateInfo;
+    type Format = i64;
+    type SwapchainImage = SwapchainImage;
+
+    fn raise_format(x: i64) -> i64 {
+        x
+    }
+    fn lower_format(x: i64) -> i64 {
+        x
+    }
+
+    fn requirements(instance: &Instance, system: SystemId) -> Result<Requirements> {
+        let exts = instance.exts();
+        Ok(Requirements {
+            vulkan: if exts.khr_vulkan_enable2.is_some() || exts.khr_vulkan_enable.is_some() {
+                Some(Vulkan::requirements(instance, system)?)
+            } else {
+                None
+            },
+            opengl: if exts.khr_opengl_enable.is_some() {
+                Some(OpenGL::requirements(instance, system)?)
+            } else {
+                None
+            },
+            opengles: if exts.khr_opengl_es_enable.is_some() {
+                Some(OpenGlEs::requirements(instance, system)?)
+            } else {
+                None
+            },
+        })
+    }
+
+    unsafe fn create_session(
+        instance: &Instance,
+        system: SystemId,
+        info: &Self::SessionCreateInfo,
+    ) -> Result<sys::Session> {
+        match *info {
+            SessionCreateInfo::Vulkan(ref info) => Vulkan::create_session(instance, system, info),
+            SessionCreateInfo::OpenGL(ref info) => OpenGL::create_session(instance, system, info),
+            SessionCreateInfo::OpenGlEs(ref info) => {
+                OpenGlEs::create_session(instance, system, info)
+            }
+        }
+    }
+
+    fn dynamic_backend(info: &SessionCreateInfo) -> Option<Backend> {
+        Some(match *info {
+            SessionCreateInfo::Vulkan(_) => Backend::Vulkan,
+            SessionCreateInfo::OpenGL(_) => Backend::OpenGL,
+            SessionCreateInfo::OpenGlEs(_) => Backend::OpenGlEs,
+        })
+    }
+
+    fn enumerate_swapchain_images(
+        swapchain: &Swapchain<Self>,
+    ) -> Result<Vec<Self::SwapchainImage>> {
+        // Sessions taken with `Session::from_raw` rather than `from_raw_with_backend` don't know
+        // their API
+        let backend = swapchain
+            .session
+            .inner
+            .backend
+            .ok_or(sys::Result::ERROR_GRAPHICS_DEVICE_INVALID)?;
+        unsafe {
+            Ok(match backend {
+                Backend::Vulkan => enumerate_swapchain_images_with(
+                    swapchain,
+                    sys::SwapchainImageVulkanKHR {
+                        ty: sys::SwapchainImageVulkanKHR::TYPE,
+                        next: ptr::null_mut(),
+                        image: 0,
+                    },
+                )?
+                .into_iter()
+                .map(|x| SwapchainImage::Vulkan(x.image))
+                .collect(),
+                Backend::OpenGL => enumerate_swapchain_images_with(
+                    swapchain,
+                    sys::SwapchainImageOpenGLKHR {
+                        ty: sys::SwapchainImageOpenGLKHR::TYPE,
+                        next: ptr::null_mut(),
+                        image: 0,
+                    },
+                )?
+                .into_iter()
+                .map(|x| SwapchainImage::OpenGL(x.image))
+                .collect(),
+                Backend::OpenGlEs => enumerate_swapchain_images_with(
+                    swapchain,
+                    sys::SwapchainImageOpenGLESKHR {
+                        ty: sys::SwapchainImageOpenGLESKHR::TYPE,
+                        next: ptr::null_mut(),
+                        image: 0,
+                    },
+                )?
+                .into_iter()
+                .map(|x| SwapchainImage::OpenGlEs(x.image))
+                .collect(),
+            })
+        }
+    }
+}
+
+impl RendersFrames for Dynamic {}
+
+impl Session<Dynamic> {
+    /// Take ownership of an existing session handle created with graphics API `backend`
+    ///
+    /// Unlike [`Session::from_raw`], the resulting session's swapchain images can be enumerated.
+    ///
+    /// # Safety
+    ///
+    /// As for [`Session::from_raw`], with `backend` as the graphics API.
+    #[inline]
+    pub unsafe fn from_raw_with_backend(
+        instance: Instance,
+        handle: sys::Session,
+        drop_guard: DropGuard,
+        backend: Backend,
+    ) -> (Self, FrameWaiter, FrameStream<Dynamic>) {
+        Self::from_raw_inner(instance, handle, drop_guard, Some(backend))
+    }
+
+    /// The graphics API the session was created with
+    ///
+    /// `None` if the session was taken with [`Session::from_raw`], in which case its swapchain
+    /// images can't be enumerated.
+    #[inline]
+    pub fn backend(&self) -> Option<Backend> {
+        self.inner.backend
+    }
+}
+
+/// A graphics API supported by [`Dynamic`]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Backend {
+    Vulkan,
+    OpenGL,
+    OpenGlEs,
+}
+
+impl Backend {
+    /// Describe `format` in the API's representation
+    pub(crate) fn describe_format(self, format: i64) -> Option<FormatInfo> {
+        match self {
+            Backend::Vulkan => Vulkan::describe_format(Vulkan::raise_format(format)),
+            Backend::OpenGL => OpenGL::describe_format(OpenGL::raise_format(format)),
+            Backend::OpenGlEs => OpenGlEs::describe_format(OpenGlEs::raise_format(format)),
+        }
+    }
+}
+
+/// Requirements of each API whose extension is enabled on the instance
+#[derive(Copy, Clone)]
+pub struct Requirements {
+    /// Set if `XR_KHR_vulkan_enable2` or `XR_KHR_vulkan_enable` is enabled
+    pub vulkan: Option<vulkan::Requirements>,
+    /// Set if `XR_KHR_opengl_enable` is enabled
+    pub opengl: Option<opengl::Requirements>,
+    /// Set if `XR_KHR_opengl_es_enable` is enabled
+    pub opengles: Option<opengles::Requirements>,
+}
+
+/// Parameters for creating a session with the API of the chosen variant
+pub enum SessionCreateInfo {
+    Vulkan(vulkan::SessionCreateInfo),
+    OpenGL(opengl::SessionCreateInfo),
+    OpenGlEs(opengles::SessionCreateInfo),
+}
+
+impl From<vulkan::SessionCreateInfo> for SessionCreateInfo {
+    fn from(x: vulkan::SessionCreateInfo) -> Self {
+        Self::Vulkan(x)
+    }
+}
+
+impl From<opengl::SessionCreateInfo> for SessionCreateInfo {
+    fn from(x: opengl::SessionCreateInfo) -> Self {
+        Self::OpenGL(x)
+    }
+}
+
+impl From<opengles::SessionCreateInfo> for SessionCreateInfo {
+    fn from(x: opengles::SessionCreateInfo) -> Self {
+        Self::OpenGlEs(x)
+    }
+}
+
+/// A swapchain image, tagged with the API of its session
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SwapchainImage {
+    Vulkan(VkImage),
+    /// A GL texture name
+    OpenGL(u32),
+    /// A GL texture name
+    OpenGlEs(u32),
+}
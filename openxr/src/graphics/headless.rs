@@ -0,0 +1,58 @@
+use std::{convert::Infallible, ptr};
+
+use crate::*;
+
+/// Sessions that don't render, for input and tracking only
+///
+/// Sessions are created without a graphics binding, which requires [`XR_MND_headless`] to be
+/// enabled; otherwise creation fails with `ERROR_EXTENSION_NOT_PRESENT` before reaching the
+/// runtime. Frames
+/// may still be waited for, begun and ended to keep the session's timing, but `Headless` doesn't
+/// implement [`RendersFrames`], so no swapchains can be created and frames are ended with
+/// [`FrameStream::end_empty`].
+///
+/// [`XR_MND_headless`]: https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_MND_headless
+pub enum Headless {}
+
+impl Graphics for Headless {
+    type Requirements = ();
+    type SessionCreateInfo = SessionCreateInfo;
+    type Format = i64;
+    type SwapchainImage = Infallible;
+
+    fn raise_format(x: i64) -> i64 {
+        x
+    }
+    fn lower_format(x: i64) -> i64 {
+        x
+    }
+
+    fn requirements(_instance: &Instance, _system: SystemId) -> Result<()> {
+        Ok(())
+    }
+
+    unsafe fn create_session(
+        instance: &Instance,
+        system: SystemId,
+        _info: &Self::SessionCreateInfo,
+    ) -> Result<sys::Session> {
+        if instance.exts().mnd_headless.is_none() {
+            return Err(Error::new(
+                "xrCreateSession",
+                sys::Result::ERROR_EXTENSION_NOT_PRESENT,
+            ));
+        }
+        create_session_raw(instance, system, ptr::null())
+    }
+
+    fn enumerate_swapchain_images(
+        _swapchain: &Swapchain<Self>,
+    ) -> Result<Vec<Self::SwapchainImage>> {
+        // Headless sessions have no images to render to
+        Err(sys::Result::ERROR_VALIDATION_FAILURE.into())
+    }
+}
+
+/// Parameters for creating a headless session, of which there are none
+#[derive(Debug, Copy, Clone, Default)]
+pub struct SessionCreateInfo;
@@ -1,9 +1,91 @@
+use std::os::raw::c_void;
+
 use crate::*;
 
 /// Static dispatch for OpenXR graphics bindings
 ///
-/// The types and functions defined by this trait are an implementation detail, and should not be
-/// referenced externally.
+/// Each graphics API is represented by a type implementing this trait, such as [`Vulkan`], which
+/// parametrizes the sessions, swapchains and composition layers using that API.
+///
+/// # Custom backends
+///
+/// APIs without built-in support can be added outside this crate by implementing `Graphics` for a
+/// new type. [`create_session`](Self::create_session) supplies the API's graphics binding
+/// structure to `xrCreateSession`, usually through [`create_session_with_binding`], and
+/// [`enumerate_swapchain_images`](Self::enumerate_swapchain_images) reads the API's swapchain image
+/// structures, usually through [`enumerate_swapchain_images_with`]. Methods with default
+/// implementations need only be overridden to support the features that use them. APIs that render
+/// must also implement [`RendersFrames`] to allow creating swapchains.
+///
+/// Any extensions the API requires, e.g. `XR_KHR_D3D12_enable`, must be enabled by the
+/// application when creating the instance.
+///
+/// # Example
+///
+/// ```no_run
+/// # #[cfg(windows)]
+/// # mod example {
+/// use openxr as xr;
+/// use xr::sys;
+///
+/// pub enum D3D12 {}
+///
+/// pub struct SessionCreateInfo {
+///     pub device: *mut sys::platform::ID3D12Device,
+///     pub queue: *mut sys::platform::ID3D12CommandQueue,
+/// }
+///
+/// impl xr::Graphics for D3D12 {
+///     type Requirements = ();
+///     type SessionCreateInfo = SessionCreateInfo;
+///     type Format = u32;
+///     type SwapchainImage = *mut sys::platform::ID3D12Resource;
+///
+///     fn raise_format(x: i64) -> u32 {
+///         x as _
+///     }
+///     fn lower_format(x: u32) -> i64 {
+///         x.into()
+///     }
+///
+///     fn requirements(_: &xr::Instance, _: xr::SystemId) -> xr::Result<()> {
+///         Ok(())
+///     }
+///
+///     unsafe fn create_session(
+///         instance: &xr::Instance,
+///         system: xr::SystemId,
+///         info: &SessionCreateInfo,
+///     ) -> xr::Result<sys::Session> {
+///         let binding = sys::GraphicsBindingD3D12KHR {
+///             ty: sys::GraphicsBindingD3D12KHR::TYPE,
+///             next: std::ptr::null(),
+///             device: info.device,
+///             queue: info.queue,
+///         };
+///         xr::create_session_with_binding(instance, system, &binding)
+///     }
+///
+///     fn enumerate_swapchain_images(
+///         swapchain: &xr::Swapchain<Self>,
+///     ) -> xr::Result<Vec<*mut sys::platform::ID3D12Resource>> {
+///         let images = unsafe {
+///             xr::enumerate_swapchain_images_with(
+///                 swapchain,
+///                 sys::SwapchainImageD3D12KHR {
+///                     ty: sys::SwapchainImageD3D12KHR::TYPE,
+///                     next: std::ptr::null_mut(),
+///                     texture: std::ptr::null_mut(),
+///                 },
+///             )?
+///         };
+///         Ok(images.into_iter().map(|x| x.texture).collect())
+///     }
+/// }
+///
+/// impl xr::RendersFrames for D3D12 {}
+/// # }
+/// ```
 pub trait Graphics: Sized {
     /// Compatibility details within this graphics API
     type Requirements;
@@ -14,26 +96,126 @@ pub trait Graphics: Sized {
     /// Identifiers for images to render to
     type SwapchainImage;
 
-    #[doc(hidden)]
+    /// Convert a format from its representation in OpenXR, e.g. as returned by
+    /// `xrEnumerateSwapchainFormats`
     fn raise_format(x: i64) -> Self::Format;
-    #[doc(hidden)]
+    /// Convert a format to its representation in OpenXR, e.g. as passed to `xrCreateSwapchain`
     fn lower_format(x: Self::Format) -> i64;
 
-    #[doc(hidden)]
+    /// Describe `format` in terms common to all graphics APIs, if it's a known color or depth
+    /// format
+    ///
+    /// Used by [`FormatPreferences::select`] and [`Session::create_depth_swapchain`]. Defaults to
+    /// describing no formats.
+    fn describe_format(_format: Self::Format) -> Option<FormatInfo> {
+        None
+    }
+
+    /// Query the graphics API compatibility requirements of `system`
+    ///
+    /// Called by [`Instance::graphics_requirements`]. OpenXR requires this to be called before a
+    /// session is created for most APIs.
     fn requirements(instance: &Instance, system: SystemId) -> Result<Self::Requirements>;
 
-    #[doc(hidden)]
+    /// Create a session for `system` using the graphics binding described by `info`
+    ///
+    /// Called by [`Instance::create_session`].
+    ///
+    /// # Safety
+    ///
+    /// The handles in `info` must be valid as required by the graphics binding structure's
+    /// extension.
     unsafe fn create_session(
         instance: &Instance,
         system: SystemId,
         info: &Self::SessionCreateInfo,
     ) -> Result<sys::Session>;
 
+    /// The API a [`Dynamic`] session created from `info` uses
+    ///
+    /// An implementation detail of `Dynamic`, which must not be overridden elsewhere.
     #[doc(hidden)]
+    fn dynamic_backend(_info: &Self::SessionCreateInfo) -> Option<dynamic::Backend> {
+        None
+    }
+
+    /// Get the images of `swapchain`, in the order the runtime indexes them
+    ///
+    /// Called by [`Swapchain::enumerate_images`].
     fn enumerate_swapchain_images(swapchain: &Swapchain<Self>)
         -> Result<Vec<Self::SwapchainImage>>;
 }
 
+/// A [`Graphics`] API whose sessions render to swapchains
+///
+/// Creating swapchains and submitting composition layers require this, so that sessions which
+/// can't render, such as [`Headless`] ones, can't be misused. Implemented by every built-in API but
+/// [`Headless`].
+pub trait RendersFrames: Graphics {}
+
+/// Call `xrCreateSession` for `system` with `binding` as the graphics binding structure
+///
+/// A building block for [`Graphics::create_session`].
+///
+/// # Safety
+///
+/// `binding` must be a graphics binding structure, beginning with `type` and `next` fields, which
+/// is valid for an extension enabled on `instance`.
+pub unsafe fn create_session_with_binding<T>(
+    instance: &Instance,
+    system: SystemId,
+    binding: &T,
+) -> Result<sys::Session> {
+    create_session_raw(instance, system, binding as *const T as *const c_void)
+}
+
+/// Call `xrCreateSession` for `system` with `next` as the `next` chain of the create info
+pub(crate) unsafe fn create_session_raw(
+    instance: &Instance,
+    system: SystemId,
+    next: *const c_void,
+) -> Result<sys::Session> {
+    let info = sys::SessionCreateInfo {
+        ty: sys::SessionCreateInfo::TYPE,
+        next,
+        create_flags: Default::default(),
+        system_id: system,
+    };
+    let mut out = sys::Session::NULL;
+    cvt(
+        "xrCreateSession",
+        (instance.fp().create_session)(instance.as_raw(), &info, &mut out),
+    )?;
+    Ok(out)
+}
+
+/// Call `xrEnumerateSwapchainImages` for `swapchain`, with `init` as the initial value of each
+/// image structure
+///
+/// A building block for [`Graphics::enumerate_swapchain_images`].
+///
+/// # Safety
+///
+/// `T` must be the swapchain image structure for the graphics API `swapchain` was created with,
+/// and `init` must have its `type` field set accordingly.
+pub unsafe fn enumerate_swapchain_images_with<G: Graphics, T: Copy>(
+    swapchain: &Swapchain<G>,
+    init: T,
+) -> Result<Vec<T>> {
+    get_arr_init(
+        "xrEnumerateSwapchainImages",
+        init,
+        |capacity, count, buf| {
+            (swapchain.instance().fp().enumerate_swapchain_images)(
+                swapchain.as_raw(),
+                capacity,
+                count,
+                buf as *mut _,
+            )
+        },
+    )
+}
+
 #[cfg(windows)]
 pub mod d3d;
 #[cfg(windows)]
@@ -47,3 +229,9 @@ pub use opengl::OpenGL;
 
 pub mod opengles;
 pub use opengles::OpenGlEs;
+
+pub mod headless;
+pub use headless::Headless;
+
+pub mod dynamic;
+pub use dynamic::Dynamic;
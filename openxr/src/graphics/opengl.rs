@@ -6,8 +6,10 @@ use crate::*;
 
 /// The OpenGL graphics API
 ///
-/// See [`XR_KHR_opengl_enable`] for safety details.
+/// See [`XR_KHR_opengl_enable`] for safety details. Sessions using EGL, via
+/// [`SessionCreateInfo::Egl`], additionally require [`XR_MNDX_egl_enable`].
 ///
+/// [`XR_MNDX_egl_enable`]: https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_MNDX_egl_enable
 /// [`XR_KHR_opengl_enable`]: https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_KHR_opengl_enable
 pub enum OpenGL {}
 
@@ -24,14 +26,57 @@ impl Graphics for OpenGL {
         x.into()
     }
 
+    fn describe_format(format: u32) -> Option<FormatInfo> {
+        Some(match format {
+            // GL_RGB565
+            0x8D62 => FormatInfo::color(5, 0, false, false),
+            // GL_RGB8
+            0x8051 => FormatInfo::color(8, 0, false, false),
+            // GL_SRGB8
+            0x8C41 => FormatInfo::color(8, 0, true, false),
+            // GL_RGBA8
+            0x8058 => FormatInfo::color(8, 8, false, false),
+            // GL_SRGB8_ALPHA8
+            0x8C43 => FormatInfo::color(8, 8, true, false),
+            // GL_RGB10_A2
+            0x8059 => FormatInfo::color(10, 2, false, false),
+            // GL_RGBA16
+            0x805B => FormatInfo::color(16, 16, false, false),
+            // GL_RGB16F
+            0x881B => FormatInfo::color(16, 0, false, true),
+            // GL_RGBA16F
+            0x881A => FormatInfo::color(16, 16, false, true),
+            // GL_RGBA32F
+            0x8814 => FormatInfo::color(32, 32, false, true),
+            // GL_R11F_G11F_B10F
+            0x8C3A => FormatInfo::color(10, 0, false, true),
+            // GL_DEPTH_COMPONENT16
+            0x81A5 => FormatInfo::depth(16, 0, false),
+            // GL_DEPTH_COMPONENT24
+            0x81A6 => FormatInfo::depth(24, 0, false),
+            // GL_DEPTH_COMPONENT32
+            0x81A7 => FormatInfo::depth(32, 0, false),
+            // GL_DEPTH_COMPONENT32F
+            0x8CAC => FormatInfo::depth(32, 0, true),
+            // GL_DEPTH24_STENCIL8
+            0x88F0 => FormatInfo::depth(24, 8, false),
+            // GL_DEPTH32F_STENCIL8
+            0x8CAD => FormatInfo::depth(32, 8, true),
+            _ => return None,
+        })
+    }
+
     fn requirements(inst: &Instance, system: SystemId) -> Result<Requirements> {
         let out = unsafe {
             let mut x = sys::GraphicsRequirementsOpenGLKHR::out(ptr::null_mut());
-            cvt((inst.opengl().get_open_gl_graphics_requirements)(
-                inst.as_raw(),
-                system,
-                x.as_mut_ptr(),
-            ))?;
+            cvt(
+                "xrGetOpenGLGraphicsRequirementsKHR",
+                (inst.opengl().get_open_gl_graphics_requirements)(
+                    inst.as_raw(),
+                    system,
+                    x.as_mut_ptr(),
+                ),
+            )?;
             x.assume_init()
         };
         Ok(Requirements {
@@ -54,19 +99,7 @@ impl Graphics for OpenGL {
                     h_dc,
                     h_glrc,
                 };
-                let info = sys::SessionCreateInfo {
-                    ty: sys::SessionCreateInfo::TYPE,
-                    next: &binding as *const _ as *const _,
-                    create_flags: Default::default(),
-                    system_id: system,
-                };
-                let mut out = sys::Session::NULL;
-                cvt((instance.fp().create_session)(
-                    instance.as_raw(),
-                    &info,
-                    &mut out,
-                ))?;
-                Ok(out)
+                create_session_with_binding(instance, system, &binding)
             }
             SessionCreateInfo::Xlib {
                 x_display,
@@ -84,19 +117,23 @@ impl Graphics for OpenGL {
                     glx_drawable,
                     glx_context,
                 };
-                let info = sys::SessionCreateInfo {
-                    ty: sys::SessionCreateInfo::TYPE,
-                    next: &binding as *const _ as *const _,
-                    create_flags: Default::default(),
-                    system_id: system,
+                create_session_with_binding(instance, system, &binding)
+            }
+            SessionCreateInfo::Egl {
+                get_proc_address,
+                display,
+                config,
+                context,
+            } => {
+                let binding = sys::GraphicsBindingEGLMNDX {
+                    ty: sys::GraphicsBindingEGLMNDX::TYPE,
+                    next: ptr::null(),
+                    get_proc_address: Some(get_proc_address),
+                    display,
+                    config,
+                    context,
                 };
-                let mut out = sys::Session::NULL;
-                cvt((instance.fp().create_session)(
-                    instance.as_raw(),
-                    &info,
-                    &mut out,
-                ))?;
-                Ok(out)
+                create_session_with_binding(instance, system, &binding)
             }
         }
     }
@@ -104,31 +141,30 @@ impl Graphics for OpenGL {
     fn enumerate_swapchain_images(
         swapchain: &Swapchain<Self>,
     ) -> Result<Vec<Self::SwapchainImage>> {
-        let images = get_arr_init(
-            sys::SwapchainImageOpenGLKHR {
-                ty: sys::SwapchainImageOpenGLKHR::TYPE,
-                next: ptr::null_mut(),
-                image: 0,
-            },
-            |capacity, count, buf| unsafe {
-                (swapchain.instance().fp().enumerate_swapchain_images)(
-                    swapchain.as_raw(),
-                    capacity,
-                    count,
-                    buf as *mut _,
-                )
-            },
-        )?;
+        let images = unsafe {
+            enumerate_swapchain_images_with(
+                swapchain,
+                sys::SwapchainImageOpenGLKHR {
+                    ty: sys::SwapchainImageOpenGLKHR::TYPE,
+                    next: ptr::null_mut(),
+                    image: 0,
+                },
+            )?
+        };
         Ok(images.into_iter().map(|x| x.image).collect())
     }
 }
 
+impl RendersFrames for OpenGL {}
+
 #[derive(Copy, Clone)]
 pub struct Requirements {
     pub min_api_version_supported: Version,
     pub max_api_version_supported: Version,
 }
 
+/// The platform's OpenGL context, of which more kinds may be supported in future
+#[non_exhaustive]
 pub enum SessionCreateInfo {
     Xlib {
         x_display: *mut Display,
@@ -139,4 +175,14 @@ pub enum SessionCreateInfo {
     },
     #[cfg(windows)]
     Windows { h_dc: HDC, h_glrc: HGLRC },
+    /// An EGL context, e.g. under Wayland or without a display server
+    ///
+    /// Requires `XR_MNDX_egl_enable`.
+    Egl {
+        /// `eglGetProcAddress`, used by the runtime to load the EGL and GL functions it needs
+        get_proc_address: EglGetProcAddressMNDX,
+        display: EGLDisplay,
+        config: EGLConfig,
+        context: EGLContext,
+    },
 }
@@ -25,14 +25,22 @@ impl Graphics for OpenGlEs {
         x.into()
     }
 
+    fn describe_format(format: u32) -> Option<FormatInfo> {
+        // Internal formats are shared with desktop GL
+        OpenGL::describe_format(format)
+    }
+
     fn requirements(inst: &Instance, system: SystemId) -> Result<Requirements> {
         let out = unsafe {
             let mut x = sys::GraphicsRequirementsOpenGLESKHR::out(ptr::null_mut());
-            cvt((inst.opengles().get_open_gles_graphics_requirements)(
-                inst.as_raw(),
-                system,
-                x.as_mut_ptr(),
-            ))?;
+            cvt(
+                "xrGetOpenGLESGraphicsRequirementsKHR",
+                (inst.opengles().get_open_gles_graphics_requirements)(
+                    inst.as_raw(),
+                    system,
+                    x.as_mut_ptr(),
+                ),
+            )?;
             x.assume_init()
         };
         Ok(Requirements {
@@ -60,20 +68,7 @@ impl Graphics for OpenGlEs {
                     config,
                     context,
                 };
-                let info = sys::SessionCreateInfo {
-                    ty: sys::SessionCreateInfo::TYPE,
-                    next: &binding as *const _ as *const _,
-                    create_flags: Default::default(),
-                    system_id: system,
-                };
-                let mut out = sys::Session::NULL;
-                cvt((instance.fp().create_session)(
-                    instance.as_raw(),
-                    &info,
-                    &mut out,
-                ))?;
-
-                Ok(out)
+                create_session_with_binding(instance, system, &binding)
             }
         }
     }
@@ -81,25 +76,22 @@ impl Graphics for OpenGlEs {
     fn enumerate_swapchain_images(
         swapchain: &Swapchain<Self>,
     ) -> Result<Vec<Self::SwapchainImage>> {
-        let images = get_arr_init(
-            sys::SwapchainImageOpenGLESKHR {
-                ty: sys::SwapchainImageOpenGLESKHR::TYPE,
-                next: ptr::null_mut(),
-                image: 0,
-            },
-            |capacity, count, buf| unsafe {
-                (swapchain.instance().fp().enumerate_swapchain_images)(
-                    swapchain.as_raw(),
-                    capacity,
-                    count,
-                    buf as *mut _,
-                )
-            },
-        )?;
+        let images = unsafe {
+            enumerate_swapchain_images_with(
+                swapchain,
+                sys::SwapchainImageOpenGLESKHR {
+                    ty: sys::SwapchainImageOpenGLESKHR::TYPE,
+                    next: ptr::null_mut(),
+                    image: 0,
+                },
+            )?
+        };
         Ok(images.into_iter().map(|x| x.image).collect())
     }
 }
 
+impl RendersFrames for OpenGlEs {}
+
 #[derive(Copy, Clone)]
 pub struct Requirements {
     pub min_api_version_supported: Version,
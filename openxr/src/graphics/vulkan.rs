@@ -24,15 +24,59 @@ impl Graphics for Vulkan {
         x as _
     }
 
+    fn describe_format(format: VkFormat) -> Option<FormatInfo> {
+        Some(match format {
+            // VK_FORMAT_R5G6B5_UNORM_PACK16
+            4 => FormatInfo::color(5, 0, false, false),
+            // R8G8B8_UNORM, B8G8R8_UNORM
+            23 | 30 => FormatInfo::color(8, 0, false, false),
+            // R8G8B8_SRGB, B8G8R8_SRGB
+            29 | 36 => FormatInfo::color(8, 0, true, false),
+            // R8G8B8A8_UNORM, B8G8R8A8_UNORM, A8B8G8R8_UNORM_PACK32
+            37 | 44 | 51 => FormatInfo::color(8, 8, false, false),
+            // R8G8B8A8_SRGB, B8G8R8A8_SRGB, A8B8G8R8_SRGB_PACK32
+            43 | 50 | 57 => FormatInfo::color(8, 8, true, false),
+            // A2R10G10B10_UNORM_PACK32, A2B10G10R10_UNORM_PACK32
+            58 | 64 => FormatInfo::color(10, 2, false, false),
+            // R16G16B16A16_UNORM
+            91 => FormatInfo::color(16, 16, false, false),
+            // R16G16B16A16_SFLOAT
+            97 => FormatInfo::color(16, 16, false, true),
+            // R32G32B32A32_SFLOAT
+            109 => FormatInfo::color(32, 32, false, true),
+            // B10G11R11_UFLOAT_PACK32
+            122 => FormatInfo::color(10, 0, false, true),
+            // D16_UNORM
+            124 => FormatInfo::depth(16, 0, false),
+            // X8_D24_UNORM_PACK32
+            125 => FormatInfo::depth(24, 0, false),
+            // D32_SFLOAT
+            126 => FormatInfo::depth(32, 0, true),
+            // D16_UNORM_S8_UINT
+            128 => FormatInfo::depth(16, 8, false),
+            // D24_UNORM_S8_UINT
+            129 => FormatInfo::depth(24, 8, false),
+            // D32_SFLOAT_S8_UINT
+            130 => FormatInfo::depth(32, 8, true),
+            _ => return None,
+        })
+    }
+
     fn requirements(instance: &Instance, system: SystemId) -> Result<Requirements> {
         let out = unsafe {
             let mut x = sys::GraphicsRequirementsVulkanKHR::out(ptr::null_mut());
-            let fp = if instance.exts().khr_vulkan_enable2.is_some() {
-                instance.vulkan().get_vulkan_graphics_requirements2
+            let (name, fp) = if instance.exts().khr_vulkan_enable2.is_some() {
+                (
+                    "xrGetVulkanGraphicsRequirements2KHR",
+                    instance.vulkan().get_vulkan_graphics_requirements2,
+                )
             } else {
-                instance.vulkan_legacy().get_vulkan_graphics_requirements
+                (
+                    "xrGetVulkanGraphicsRequirementsKHR",
+                    instance.vulkan_legacy().get_vulkan_graphics_requirements,
+                )
             };
-            cvt(fp(instance.as_raw(), system, x.as_mut_ptr()))?;
+            cvt(name, fp(instance.as_raw(), system, x.as_mut_ptr()))?;
             x.assume_init()
         };
         Ok(Requirements {
@@ -55,49 +99,153 @@ impl Graphics for Vulkan {
             queue_family_index: info.queue_family_index,
             queue_index: info.queue_index,
         };
-        let info = sys::SessionCreateInfo {
-            ty: sys::SessionCreateInfo::TYPE,
-            next: &binding as *const _ as *const _,
-            create_flags: Default::default(),
-            system_id: system,
-        };
-        let mut out = sys::Session::NULL;
-        cvt((instance.fp().create_session)(
-            instance.as_raw(),
-            &info,
-            &mut out,
-        ))?;
-        Ok(out)
+        create_session_with_binding(instance, system, &binding)
     }
 
     fn enumerate_swapchain_images(
         swapchain: &Swapchain<Self>,
     ) -> Result<Vec<Self::SwapchainImage>> {
-        let images = get_arr_init(
-            sys::SwapchainImageVulkanKHR {
-                ty: sys::SwapchainImageVulkanKHR::TYPE,
-                next: ptr::null_mut(),
-                image: 0,
-            },
-            |capacity, count, buf| unsafe {
-                (swapchain.instance().fp().enumerate_swapchain_images)(
-                    swapchain.as_raw(),
-                    capacity,
-                    count,
-                    buf as *mut _,
-                )
-            },
-        )?;
+        let images = unsafe {
+            enumerate_swapchain_images_with(
+                swapchain,
+                sys::SwapchainImageVulkanKHR {
+                    ty: sys::SwapchainImageVulkanKHR::TYPE,
+                    next: ptr::null_mut(),
+                    image: 0,
+                },
+            )?
+        };
         Ok(images.into_iter().map(|x| x.image as _).collect())
     }
 }
 
+impl RendersFrames for Vulkan {}
+
+impl Session<Vulkan> {
+    /// Create a swapchain, with additional parameters for the `VkImage`s backing it
+    ///
+    /// Fails with `ERROR_EXTENSION_NOT_PRESENT` if `image_info` relies on an extension that isn't
+    /// enabled, or if `info` requests [`SwapchainUsageFlags::INPUT_ATTACHMENT`] without
+    /// `XR_KHR_swapchain_usage_input_attachment_bit` or `XR_MND_swapchain_usage_input_attachment_bit`.
+    ///
+    /// # Example
+    ///
+    /// Rendering to an sRGB swapchain through a linear view:
+    ///
+    /// ```no_run
+    /// # fn dummy(
+    /// #     session: &openxr::Session<openxr::Vulkan>,
+    /// #     info: &mut openxr::SwapchainCreateInfo<openxr::Vulkan>,
+    /// # ) -> openxr::Result<()> {
+    /// // VK_FORMAT_R8G8B8A8_SRGB, VK_FORMAT_R8G8B8A8_UNORM
+    /// info.format = 43;
+    /// info.usage_flags |= openxr::SwapchainUsageFlags::MUTABLE_FORMAT;
+    /// let swapchain = session.create_vulkan_swapchain(
+    ///     info,
+    ///     &openxr::vulkan::ImageCreateInfo {
+    ///         view_formats: &[43, 37],
+    ///         ..Default::default()
+    ///     },
+    /// )?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn create_vulkan_swapchain(
+        &self,
+        info: &crate::SwapchainCreateInfo<Vulkan>,
+        image_info: &ImageCreateInfo<'_>,
+    ) -> Result<Swapchain<Vulkan>> {
+        let exts = self.instance().exts();
+        let has_view_formats = !image_info.view_formats.is_empty();
+        let has_flags =
+            image_info.additional_create_flags != 0 || image_info.additional_usage_flags != 0;
+        let missing = (has_view_formats && exts.khr_vulkan_swapchain_format_list.is_none())
+            || (has_flags && exts.meta_vulkan_swapchain_create_info.is_none())
+            || (info
+                .usage_flags
+                .contains(SwapchainUsageFlags::INPUT_ATTACHMENT)
+                && exts.khr_swapchain_usage_input_attachment_bit.is_none()
+                && exts.mnd_swapchain_usage_input_attachment_bit.is_none());
+        if missing {
+            return Err(sys::Result::ERROR_EXTENSION_NOT_PRESENT.into());
+        }
+
+        let mut format_list =
+            VulkanSwapchainFormatListCreateInfoKHR::new().view_formats(image_info.view_formats);
+        let mut meta = VulkanSwapchainCreateInfoMETA::new()
+            .additional_create_flags(image_info.additional_create_flags)
+            .additional_usage_flags(image_info.additional_usage_flags);
+        let mut next = NextChain::new();
+        if has_view_formats {
+            next = next.push(&mut format_list);
+        }
+        if has_flags {
+            next = next.push(&mut meta);
+        }
+        self.create_swapchain_with_next(info, next)
+    }
+}
+
+/// The `VkImageUsageFlags` of the images of a swapchain created with `usage`
+///
+/// Useful e.g. for limiting the usage of image views in a `VkImageViewUsageCreateInfo`. Doesn't
+/// include any additional usage flags passed to [`Session::create_vulkan_swapchain`].
+pub fn image_usage_flags(usage: SwapchainUsageFlags) -> VkImageUsageFlags {
+    [
+        // VK_IMAGE_USAGE_COLOR_ATTACHMENT_BIT
+        (SwapchainUsageFlags::COLOR_ATTACHMENT, 0x10),
+        // VK_IMAGE_USAGE_DEPTH_STENCIL_ATTACHMENT_BIT
+        (SwapchainUsageFlags::DEPTH_STENCIL_ATTACHMENT, 0x20),
+        // VK_IMAGE_USAGE_STORAGE_BIT
+        (SwapchainUsageFlags::UNORDERED_ACCESS, 0x08),
+        // VK_IMAGE_USAGE_TRANSFER_SRC_BIT
+        (SwapchainUsageFlags::TRANSFER_SRC, 0x01),
+        // VK_IMAGE_USAGE_TRANSFER_DST_BIT
+        (SwapchainUsageFlags::TRANSFER_DST, 0x02),
+        // VK_IMAGE_USAGE_SAMPLED_BIT
+        (SwapchainUsageFlags::SAMPLED, 0x04),
+        // VK_IMAGE_USAGE_INPUT_ATTACHMENT_BIT
+        (SwapchainUsageFlags::INPUT_ATTACHMENT, 0x80),
+    ]
+    .iter()
+    .filter(|&&(flag, _)| usage.contains(flag))
+    .fold(0, |acc, &(_, bit)| acc | bit)
+}
+
+/// The `VkImageCreateFlags` of the images of a swapchain created with `usage`
+///
+/// Doesn't include any additional create flags passed to [`Session::create_vulkan_swapchain`].
+pub fn image_create_flags(usage: SwapchainUsageFlags) -> VkImageCreateFlags {
+    if usage.contains(SwapchainUsageFlags::MUTABLE_FORMAT) {
+        // VK_IMAGE_CREATE_MUTABLE_FORMAT_BIT
+        0x08
+    } else {
+        0
+    }
+}
+
 #[derive(Debug, Copy, Clone)]
 pub struct Requirements {
     pub min_api_version_supported: Version,
     pub max_api_version_supported: Version,
 }
 
+/// Additional parameters for the `VkImage`s backing a swapchain, for
+/// [`Session::create_vulkan_swapchain`]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct ImageCreateInfo<'a> {
+    /// Formats the images may be viewed as, e.g. both the `_SRGB` and `_UNORM` variants of the
+    /// swapchain's format
+    ///
+    /// Requires `XR_KHR_vulkan_swapchain_format_list`, and is only meaningful with
+    /// [`SwapchainUsageFlags::MUTABLE_FORMAT`].
+    pub view_formats: &'a [VkFormat],
+    /// Requires `XR_META_vulkan_swapchain_create_info`
+    pub additional_create_flags: VkImageCreateFlags,
+    /// Requires `XR_META_vulkan_swapchain_create_info`
+    pub additional_usage_flags: VkImageUsageFlags,
+}
+
 #[derive(Copy, Clone)]
 pub struct SessionCreateInfo {
     pub instance: VkInstance,
@@ -38,7 +38,7 @@ impl HandTracker {
         let fp = if let Some(fp) = fp {
             fp
         } else {
-            return Err(sys::Result::ERROR_EXTENSION_NOT_PRESENT);
+            return Err(sys::Result::ERROR_EXTENSION_NOT_PRESENT.into());
         };
 
         let mut out = sys::HandTrackerEXT::NULL;
@@ -50,7 +50,10 @@ impl HandTracker {
             hand_joint_set: sys::HandJointSetEXT::DEFAULT,
         };
         let handle = unsafe {
-            cvt((fp.create_hand_tracker)(session.as_raw(), &info, &mut out))?;
+            cvt(
+                "xrCreateHandTrackerEXT",
+                (fp.create_hand_tracker)(session.as_raw(), &info, &mut out),
+            )?;
             out
         };
         Ok(HandTracker {
@@ -56,10 +56,10 @@ impl FacialTrackerHTC {
         };
 
         unsafe {
-            cvt((self.fp().get_facial_expressions)(
-                self.handle,
-                &mut facial_expressions,
-            ))?;
+            cvt(
+                "xrGetFacialExpressionsHTC",
+                (self.fp().get_facial_expressions)(self.handle, &mut facial_expressions),
+            )?;
 
             if facial_expressions.is_active.into() {
                 Ok(Some(FaceExpressionWeightsHTC {
@@ -93,7 +93,10 @@ impl<G> Session<G> {
             facial_tracking_type,
         };
         let handle = unsafe {
-            cvt((fp.create_facial_tracker)(self.as_raw(), &info, &mut out))?;
+            cvt(
+                "xrCreateFacialTrackerHTC",
+                (fp.create_facial_tracker)(self.as_raw(), &info, &mut out),
+            )?;
             out
         };
         let expression_count = if facial_tracking_type == FacialTrackingTypeHTC::EYE_DEFAULT {
@@ -19,7 +19,7 @@ pub struct Instance {
 }
 
 impl Instance {
-    /// Take ownership of an existing instance handle
+    /// Take ownership of an existing instance handle created with OpenXR 1.0
     ///
     /// # Safety
     ///
@@ -29,13 +29,47 @@ impl Instance {
         handle: sys::Instance,
         exts: InstanceExtensions,
     ) -> Result<Self> {
+        Self::from_raw_with_version(entry, handle, sys::API_VERSION_1_0, exts)
+    }
+
+    /// Take ownership of an existing instance handle created with `api_version`
+    ///
+    /// # Safety
+    ///
+    /// `handle` must be the instance handle that was used to load `exts`, created with
+    /// `api_version`.
+    pub unsafe fn from_raw_with_version(
+        entry: Entry,
+        handle: sys::Instance,
+        api_version: Version,
+        exts: InstanceExtensions,
+    ) -> Result<Self> {
+        Self::from_raw_with_debug_callback(entry, handle, api_version, exts, None)
+    }
+
+    /// `debug_callback` must outlive `handle` if it was passed to `xrCreateInstance`
+    pub(crate) unsafe fn from_raw_with_debug_callback(
+        entry: Entry,
+        handle: sys::Instance,
+        api_version: Version,
+        exts: InstanceExtensions,
+        debug_callback: Option<Box<debug_utils::Callback>>,
+    ) -> Result<Self> {
+        let raw1_1 = if (api_version.major(), api_version.minor()) >= (1, 1) {
+            Some(raw::Instance1_1::load(&entry, handle)?)
+        } else {
+            None
+        };
         Ok(Self {
             inner: Arc::new(InstanceInner {
                 raw: raw::Instance::load(&entry, handle)?,
+                raw1_1,
+                api_version,
                 exts,
                 handle,
                 entry,
                 set_name_lock: Mutex::new(()),
+                _debug_callback: debug_callback,
             }),
         })
     }
@@ -57,12 +91,33 @@ impl Instance {
         &self.inner.raw
     }
 
+    /// Access the function pointers introduced in OpenXR 1.1, if the instance was created with
+    /// that version or later
+    #[inline]
+    pub fn fp1_1(&self) -> Option<&raw::Instance1_1> {
+        self.inner.raw1_1.as_ref()
+    }
+
     /// Access the internal extension function pointers
     #[inline]
     pub fn exts(&self) -> &InstanceExtensions {
         &self.inner.exts
     }
 
+    /// The OpenXR version negotiated when the instance was created
+    #[inline]
+    pub fn api_version(&self) -> Version {
+        self.inner.api_version
+    }
+
+    /// `xrLocateSpaces` from OpenXR 1.1, or its `XR_KHR_locate_spaces` equivalent
+    #[inline]
+    pub(crate) fn locate_spaces_fp(&self) -> Option<sys::pfn::LocateSpaces> {
+        self.fp1_1()
+            .map(|x| x.locate_spaces)
+            .or_else(|| self.exts().khr_locate_spaces.map(|x| x.locate_spaces))
+    }
+
     /// Set the debug name of this `Instance`, if `XR_EXT_debug_utils` is loaded
     #[inline]
     pub fn set_name(&mut self, name: &str) -> Result<()> {
@@ -76,7 +131,10 @@ impl Instance {
                 ty: sys::InstanceProperties::TYPE,
                 ..mem::zeroed()
             };
-            cvt((self.fp().get_instance_properties)(self.as_raw(), &mut p))?;
+            cvt(
+                "xrGetInstanceProperties",
+                (self.fp().get_instance_properties)(self.as_raw(), &mut p),
+            )?;
             Ok(InstanceProperties {
                 runtime_version: p.runtime_version,
                 runtime_name: fixed_str(&p.runtime_name).into(),
@@ -88,11 +146,10 @@ impl Instance {
     pub fn result_to_string(&self, result: sys::Result) -> Result<String> {
         unsafe {
             let mut s = [0; sys::MAX_RESULT_STRING_SIZE];
-            cvt((self.fp().result_to_string)(
-                self.as_raw(),
-                result,
-                s.as_mut_ptr(),
-            ))?;
+            cvt(
+                "xrResultToString",
+                (self.fp().result_to_string)(self.as_raw(), result, s.as_mut_ptr()),
+            )?;
             Ok(fixed_str(&s).into())
         }
     }
@@ -101,11 +158,10 @@ impl Instance {
     pub fn structure_type_to_string(&self, ty: StructureType) -> Result<String> {
         unsafe {
             let mut s = [0; sys::MAX_STRUCTURE_NAME_SIZE];
-            cvt((self.fp().structure_type_to_string)(
-                self.as_raw(),
-                ty,
-                s.as_mut_ptr(),
-            ))?;
+            cvt(
+                "xrStructureTypeToString",
+                (self.fp().structure_type_to_string)(self.as_raw(), ty, s.as_mut_ptr()),
+            )?;
             Ok(fixed_str(&s).into())
         }
     }
@@ -119,7 +175,10 @@ impl Instance {
         };
         let mut out = SystemId::NULL;
         unsafe {
-            cvt((self.fp().get_system)(self.as_raw(), &info, &mut out))?;
+            cvt(
+                "xrGetSystem",
+                (self.fp().get_system)(self.as_raw(), &info, &mut out),
+            )?;
         }
         Ok(out)
     }
@@ -131,11 +190,10 @@ impl Instance {
                 ty: sys::SystemProperties::TYPE,
                 ..mem::zeroed()
             };
-            cvt((self.fp().get_system_properties)(
-                self.as_raw(),
-                system,
-                &mut p,
-            ))?;
+            cvt(
+                "xrGetSystemProperties",
+                (self.fp().get_system_properties)(self.as_raw(), system, &mut p),
+            )?;
             Ok(SystemProperties {
                 system_id: p.system_id,
                 vendor_id: p.vendor_id,
@@ -157,11 +215,10 @@ impl Instance {
         let mut ext_props = get_ext_props(ptr::null_mut());
         let mut p = sys::SystemProperties::out(&mut ext_props as *mut _ as _);
         unsafe {
-            cvt((self.fp().get_system_properties)(
-                self.as_raw(),
-                system,
-                p.as_mut_ptr(),
-            ))?;
+            cvt(
+                "xrGetSystemProperties",
+                (self.fp().get_system_properties)(self.as_raw(), system, p.as_mut_ptr()),
+            )?;
             Ok(ext_props.assume_init())
         }
     }
@@ -262,11 +319,10 @@ impl Instance {
         };
         let mut p = sys::SystemProperties::out(&mut props as *mut _ as _);
         unsafe {
-            cvt((self.fp().get_system_properties)(
-                self.as_raw(),
-                system,
-                p.as_mut_ptr(),
-            ))?;
+            cvt(
+                "xrGetSystemProperties",
+                (self.fp().get_system_properties)(self.as_raw(), system, p.as_mut_ptr()),
+            )?;
         }
         Ok(props.capabilities)
     }
@@ -279,18 +335,17 @@ impl Instance {
         let string = CString::new(string).map_err(|_| sys::Result::ERROR_PATH_FORMAT_INVALID)?;
         let mut out = Path::NULL;
         unsafe {
-            cvt((self.fp().string_to_path)(
-                self.as_raw(),
-                string.as_ptr(),
-                &mut out,
-            ))?;
+            cvt(
+                "xrStringToPath",
+                (self.fp().string_to_path)(self.as_raw(), string.as_ptr(), &mut out),
+            )?;
         }
         Ok(out)
     }
 
     #[inline]
     pub fn path_to_string(&self, path: Path) -> Result<String> {
-        get_str(|input, output, buf| unsafe {
+        get_str("xrPathToString", |input, output, buf| unsafe {
             (self.fp().path_to_string)(self.as_raw(), path, input, output, buf)
         })
     }
@@ -313,20 +368,23 @@ impl Instance {
     ) -> Result<Result<VkInstance, VkResult>> {
         let mut instance = ptr::null();
         let mut result = 0;
-        cvt((self.vulkan().create_vulkan_instance)(
-            self.as_raw(),
-            &sys::VulkanInstanceCreateInfoKHR {
-                ty: sys::VulkanInstanceCreateInfoKHR::TYPE,
-                next: ptr::null(),
-                system_id: system,
-                create_flags: sys::VulkanInstanceCreateFlagsKHR::EMPTY,
-                pfn_get_instance_proc_addr: Some(get_instance_proc_addr),
-                vulkan_create_info: create_info,
-                vulkan_allocator: ptr::null(),
-            },
-            &mut instance,
-            &mut result,
-        ))?;
+        cvt(
+            "xrCreateVulkanInstanceKHR",
+            (self.vulkan().create_vulkan_instance)(
+                self.as_raw(),
+                &sys::VulkanInstanceCreateInfoKHR {
+                    ty: sys::VulkanInstanceCreateInfoKHR::TYPE,
+                    next: ptr::null(),
+                    system_id: system,
+                    create_flags: sys::VulkanInstanceCreateFlagsKHR::EMPTY,
+                    pfn_get_instance_proc_addr: Some(get_instance_proc_addr),
+                    vulkan_create_info: create_info,
+                    vulkan_allocator: ptr::null(),
+                },
+                &mut instance,
+                &mut result,
+            ),
+        )?;
         if result < 0 {
             return Ok(Err(result));
         }
@@ -341,15 +399,18 @@ impl Instance {
     /// `khr_vulkan_enable2` instead, if possible.
     #[inline]
     pub fn vulkan_legacy_instance_extensions(&self, system: SystemId) -> Result<String> {
-        get_str(|input, output, buf| unsafe {
-            (self.vulkan_legacy().get_vulkan_instance_extensions)(
-                self.as_raw(),
-                system,
-                input,
-                output,
-                buf,
-            )
-        })
+        get_str(
+            "xrGetVulkanInstanceExtensionsKHR",
+            |input, output, buf| unsafe {
+                (self.vulkan_legacy().get_vulkan_instance_extensions)(
+                    self.as_raw(),
+                    system,
+                    input,
+                    output,
+                    buf,
+                )
+            },
+        )
     }
 
     /// Identify the Vulkan device extensions required by a system
@@ -360,15 +421,18 @@ impl Instance {
     /// `khr_vulkan_enable2` instead, if possible.
     #[inline]
     pub fn vulkan_legacy_device_extensions(&self, system: SystemId) -> Result<String> {
-        get_str(|input, output, buf| unsafe {
-            (self.vulkan_legacy().get_vulkan_device_extensions)(
-                self.as_raw(),
-                system,
-                input,
-                output,
-                buf,
-            )
-        })
+        get_str(
+            "xrGetVulkanDeviceExtensionsKHR",
+            |input, output, buf| unsafe {
+                (self.vulkan_legacy().get_vulkan_device_extensions)(
+                    self.as_raw(),
+                    system,
+                    input,
+                    output,
+                    buf,
+                )
+            },
+        )
     }
 
     /// Get a suitable [`VkPhysicalDevice`] for use with a particular `system`
@@ -388,23 +452,29 @@ impl Instance {
     ) -> Result<VkPhysicalDevice> {
         let mut out = ptr::null();
         if self.exts().khr_vulkan_enable2.is_some() {
-            cvt((self.vulkan().get_vulkan_graphics_device2)(
-                self.as_raw(),
-                &sys::VulkanGraphicsDeviceGetInfoKHR {
-                    ty: sys::VulkanGraphicsDeviceGetInfoKHR::TYPE,
-                    next: ptr::null(),
-                    system_id: system,
-                    vulkan_instance,
-                },
-                &mut out,
-            ))?;
+            cvt(
+                "xrGetVulkanGraphicsDevice2KHR",
+                (self.vulkan().get_vulkan_graphics_device2)(
+                    self.as_raw(),
+                    &sys::VulkanGraphicsDeviceGetInfoKHR {
+                        ty: sys::VulkanGraphicsDeviceGetInfoKHR::TYPE,
+                        next: ptr::null(),
+                        system_id: system,
+                        vulkan_instance,
+                    },
+                    &mut out,
+                ),
+            )?;
         } else {
-            cvt((self.vulkan_legacy().get_vulkan_graphics_device)(
-                self.as_raw(),
-                system,
-                vulkan_instance,
-                &mut out,
-            ))?;
+            cvt(
+                "xrGetVulkanGraphicsDeviceKHR",
+                (self.vulkan_legacy().get_vulkan_graphics_device)(
+                    self.as_raw(),
+                    system,
+                    vulkan_instance,
+                    &mut out,
+                ),
+            )?;
         }
         Ok(out)
     }
@@ -428,21 +498,24 @@ impl Instance {
     ) -> Result<Result<VkDevice, VkResult>> {
         let mut device = ptr::null();
         let mut result = 0;
-        cvt((self.vulkan().create_vulkan_device)(
-            self.as_raw(),
-            &sys::VulkanDeviceCreateInfoKHR {
-                ty: sys::VulkanDeviceCreateInfoKHR::TYPE,
-                next: ptr::null(),
-                system_id: system,
-                create_flags: sys::VulkanDeviceCreateFlagsKHR::EMPTY,
-                pfn_get_instance_proc_addr: Some(get_instance_proc_addr),
-                vulkan_physical_device: physical_device,
-                vulkan_create_info: create_info,
-                vulkan_allocator: ptr::null(),
-            },
-            &mut device,
-            &mut result,
-        ))?;
+        cvt(
+            "xrCreateVulkanDeviceKHR",
+            (self.vulkan().create_vulkan_device)(
+                self.as_raw(),
+                &sys::VulkanDeviceCreateInfoKHR {
+                    ty: sys::VulkanDeviceCreateInfoKHR::TYPE,
+                    next: ptr::null(),
+                    system_id: system,
+                    create_flags: sys::VulkanDeviceCreateFlagsKHR::EMPTY,
+                    pfn_get_instance_proc_addr: Some(get_instance_proc_addr),
+                    vulkan_physical_device: physical_device,
+                    vulkan_create_info: create_info,
+                    vulkan_allocator: ptr::null(),
+                },
+                &mut device,
+                &mut result,
+            ),
+        )?;
         if result < 0 {
             return Ok(Err(result));
         }
@@ -479,7 +552,12 @@ impl Instance {
         info: &G::SessionCreateInfo,
     ) -> Result<(Session<G>, FrameWaiter, FrameStream<G>)> {
         let handle = G::create_session(self, system, info)?;
-        Ok(Session::from_raw(self.clone(), handle, Box::new(())))
+        Ok(Session::from_raw_inner(
+            self.clone(),
+            handle,
+            Box::new(()),
+            G::dynamic_backend(info),
+        ))
     }
 
     /// Refer to [`Instance::create_session()`]. The extra `drop_guard` argument is dropped after
@@ -498,7 +576,12 @@ impl Instance {
         drop_guard: DropGuard,
     ) -> Result<(Session<G>, FrameWaiter, FrameStream<G>)> {
         let handle = G::create_session(self, system, info)?;
-        Ok(Session::from_raw(self.clone(), handle, drop_guard))
+        Ok(Session::from_raw_inner(
+            self.clone(),
+            handle,
+            drop_guard,
+            G::dynamic_backend(info),
+        ))
     }
 
     /// Get the next event, if available
@@ -516,10 +599,10 @@ impl Instance {
                         next: ptr::null(),
                     },
                 );
-                let status = cvt((self.fp().poll_event)(
-                    self.as_raw(),
-                    (*storage).inner.as_mut_ptr(),
-                ))?;
+                let status = cvt(
+                    "xrPollEvent",
+                    (self.fp().poll_event)(self.as_raw(), (*storage).inner.as_mut_ptr()),
+                )?;
                 if status == sys::Result::EVENT_UNAVAILABLE {
                     return Ok(None);
                 }
@@ -537,7 +620,7 @@ impl Instance {
         &self,
         system: SystemId,
     ) -> Result<Vec<ViewConfigurationType>> {
-        get_arr(|cap, count, buf| unsafe {
+        get_arr("xrEnumerateViewConfigurations", |cap, count, buf| unsafe {
             (self.fp().enumerate_view_configurations)(self.as_raw(), system, cap, count, buf)
         })
     }
@@ -551,12 +634,15 @@ impl Instance {
     ) -> Result<ViewConfigurationProperties> {
         let out = unsafe {
             let mut x = sys::ViewConfigurationProperties::out(ptr::null_mut());
-            cvt((self.fp().get_view_configuration_properties)(
-                self.as_raw(),
-                system,
-                ty,
-                x.as_mut_ptr(),
-            ))?;
+            cvt(
+                "xrGetViewConfigurationProperties",
+                (self.fp().get_view_configuration_properties)(
+                    self.as_raw(),
+                    system,
+                    ty,
+                    x.as_mut_ptr(),
+                ),
+            )?;
             x.assume_init()
         };
         Ok(ViewConfigurationProperties {
@@ -572,6 +658,7 @@ impl Instance {
         ty: ViewConfigurationType,
     ) -> Result<Vec<ViewConfigurationView>> {
         let views = get_arr_init(
+            "xrEnumerateViewConfigurationViews",
             sys::ViewConfigurationView::out(ptr::null_mut()),
             |capacity, count, buf| unsafe {
                 (self.fp().enumerate_view_configuration_views)(
@@ -606,16 +693,19 @@ impl Instance {
         system: SystemId,
         view_configuration_type: ViewConfigurationType,
     ) -> Result<Vec<EnvironmentBlendMode>> {
-        get_arr(|cap, count, buf| unsafe {
-            (self.fp().enumerate_environment_blend_modes)(
-                self.as_raw(),
-                system,
-                view_configuration_type,
-                cap,
-                count,
-                buf,
-            )
-        })
+        get_arr(
+            "xrEnumerateEnvironmentBlendModes",
+            |cap, count, buf| unsafe {
+                (self.fp().enumerate_environment_blend_modes)(
+                    self.as_raw(),
+                    system,
+                    view_configuration_type,
+                    cap,
+                    count,
+                    buf,
+                )
+            },
+        )
     }
 
     /// Obtain the current `Time`
@@ -630,16 +720,17 @@ impl Instance {
             libc::clock_gettime(libc::CLOCK_MONOTONIC, now.as_mut_ptr());
             let now = now.assume_init();
             let mut out = MaybeUninit::uninit();
-            cvt((self
-                .exts()
-                .khr_convert_timespec_time
-                .as_ref()
-                .expect("KHR_convert_timespec_time not loaded")
-                .convert_timespec_time_to_time)(
-                self.as_raw(),
-                &now,
-                out.as_mut_ptr(),
-            ))?;
+            cvt(
+                "xrConvertTimespecTimeToTimeKHR",
+                (self
+                    .exts()
+                    .khr_convert_timespec_time
+                    .as_ref()
+                    .expect("KHR_convert_timespec_time not loaded")
+                    .convert_timespec_time_to_time)(
+                    self.as_raw(), &now, out.as_mut_ptr()
+                ),
+            )?;
             Ok(out.assume_init())
         }
     }
@@ -660,16 +751,19 @@ impl Instance {
             QueryPerformanceCounter(now.as_mut_ptr());
             let now = now.assume_init();
             let mut out = MaybeUninit::uninit();
-            cvt((self
-                .exts()
-                .khr_win32_convert_performance_counter_time
-                .as_ref()
-                .expect("KHR_win32_convert_performance_counter_time not loaded")
-                .convert_win32_performance_counter_to_time)(
-                self.as_raw(),
-                &now,
-                out.as_mut_ptr(),
-            ))?;
+            cvt(
+                "xrConvertWin32PerformanceCounterToTimeKHR",
+                (self
+                    .exts()
+                    .khr_win32_convert_performance_counter_time
+                    .as_ref()
+                    .expect("KHR_win32_convert_performance_counter_time not loaded")
+                    .convert_win32_performance_counter_to_time)(
+                    self.as_raw(),
+                    &now,
+                    out.as_mut_ptr(),
+                ),
+            )?;
             Ok(out.assume_init())
         }
     }
@@ -689,10 +783,10 @@ impl Instance {
             suggested_bindings: bindings.as_ptr() as *const _ as _,
         };
         unsafe {
-            cvt((self.fp().suggest_interaction_profile_bindings)(
-                self.as_raw(),
-                &info,
-            ))?;
+            cvt(
+                "xrSuggestInteractionProfileBindings",
+                (self.fp().suggest_interaction_profile_bindings)(self.as_raw(), &info),
+            )?;
         }
         Ok(())
     }
@@ -713,15 +807,25 @@ impl Instance {
             .priority(priority);
         unsafe {
             let mut out = sys::ActionSet::NULL;
-            cvt((self.fp().create_action_set)(
-                self.as_raw(),
-                info.as_raw(),
-                &mut out,
-            ))?;
+            cvt(
+                "xrCreateActionSet",
+                (self.fp().create_action_set)(self.as_raw(), info.as_raw(), &mut out),
+            )?;
             Ok(ActionSet::from_raw(self.clone(), out))
         }
     }
 
+    /// Receive diagnostics from the runtime and API layers
+    ///
+    /// Requires `XR_EXT_debug_utils`.
+    #[inline]
+    pub fn create_debug_utils_messenger(
+        &self,
+        info: DebugUtilsMessengerCreateInfo,
+    ) -> Result<DebugUtilsMessenger> {
+        DebugUtilsMessenger::create(self, info)
+    }
+
     //
     // Internal helpers
     //
@@ -740,7 +844,10 @@ impl Instance {
             // performance-relevant, so we use a conservative instance-global lock for simplicity.
             let guard = self.inner.set_name_lock.lock().unwrap();
             unsafe {
-                cvt((fp.set_debug_utils_object_name)(self.as_raw(), &info))?;
+                cvt(
+                    "xrSetDebugUtilsObjectNameEXT",
+                    (fp.set_debug_utils_object_name)(self.as_raw(), &info),
+                )?;
             }
             drop(guard);
         }
@@ -789,8 +896,12 @@ struct InstanceInner {
     entry: Entry,
     handle: sys::Instance,
     raw: raw::Instance,
+    raw1_1: Option<raw::Instance1_1>,
+    api_version: Version,
     exts: InstanceExtensions,
     set_name_lock: Mutex<()>,
+    /// Messenger callback chained to `xrCreateInstance`, which is also used by `xrDestroyInstance`
+    _debug_callback: Option<Box<debug_utils::Callback>>,
 }
 
 impl Drop for InstanceInner {
@@ -839,7 +950,7 @@ pub struct ViewConfigurationView {
 }
 
 pub struct EventDataBuffer {
-    inner: MaybeUninit<sys::EventDataBuffer>,
+    pub(crate) inner: MaybeUninit<sys::EventDataBuffer>,
 }
 
 impl EventDataBuffer {
@@ -0,0 +1,7968 @@
+//! Automatically generated code; do not edit!
+
+#![allow(unused, clippy::too_many_arguments, clippy::missing_safety_doc)]
+use libc::{timespec, wchar_t};
+use std::mem;
+use std::os::raw::{c_char, c_void};
+
+use sys::platform::*;
+use sys::*;
+
+#[doc = r" Function pointers for the next link in the call chain"]
+#[doc = r""]
+#[doc = r" Commands the next link doesn't provide, e.g. because their extension isn't enabled,"]
+#[doc = r" are `None`."]
+#[derive(Copy, Clone)]
+pub struct Dispatch {
+    pub get_instance_proc_addr: pfn::GetInstanceProcAddr,
+    pub destroy_instance: Option<pfn::DestroyInstance>,
+    pub result_to_string: Option<pfn::ResultToString>,
+    pub structure_type_to_string: Option<pfn::StructureTypeToString>,
+    pub get_instance_properties: Option<pfn::GetInstanceProperties>,
+    pub get_system: Option<pfn::GetSystem>,
+    pub get_system_properties: Option<pfn::GetSystemProperties>,
+    pub create_session: Option<pfn::CreateSession>,
+    pub destroy_session: Option<pfn::DestroySession>,
+    pub destroy_space: Option<pfn::DestroySpace>,
+    pub enumerate_swapchain_formats: Option<pfn::EnumerateSwapchainFormats>,
+    pub create_swapchain: Option<pfn::CreateSwapchain>,
+    pub destroy_swapchain: Option<pfn::DestroySwapchain>,
+    pub enumerate_swapchain_images: Option<pfn::EnumerateSwapchainImages>,
+    pub acquire_swapchain_image: Option<pfn::AcquireSwapchainImage>,
+    pub wait_swapchain_image: Option<pfn::WaitSwapchainImage>,
+    pub release_swapchain_image: Option<pfn::ReleaseSwapchainImage>,
+    pub begin_session: Option<pfn::BeginSession>,
+    pub end_session: Option<pfn::EndSession>,
+    pub request_exit_session: Option<pfn::RequestExitSession>,
+    pub enumerate_reference_spaces: Option<pfn::EnumerateReferenceSpaces>,
+    pub create_reference_space: Option<pfn::CreateReferenceSpace>,
+    pub create_action_space: Option<pfn::CreateActionSpace>,
+    pub locate_space: Option<pfn::LocateSpace>,
+    pub enumerate_view_configurations: Option<pfn::EnumerateViewConfigurations>,
+    pub enumerate_environment_blend_modes: Option<pfn::EnumerateEnvironmentBlendModes>,
+    pub get_view_configuration_properties: Option<pfn::GetViewConfigurationProperties>,
+    pub enumerate_view_configuration_views: Option<pfn::EnumerateViewConfigurationViews>,
+    pub begin_frame: Option<pfn::BeginFrame>,
+    pub locate_views: Option<pfn::LocateViews>,
+    pub end_frame: Option<pfn::EndFrame>,
+    pub wait_frame: Option<pfn::WaitFrame>,
+    pub apply_haptic_feedback: Option<pfn::ApplyHapticFeedback>,
+    pub stop_haptic_feedback: Option<pfn::StopHapticFeedback>,
+    pub poll_event: Option<pfn::PollEvent>,
+    pub string_to_path: Option<pfn::StringToPath>,
+    pub path_to_string: Option<pfn::PathToString>,
+    pub get_reference_space_bounds_rect: Option<pfn::GetReferenceSpaceBoundsRect>,
+    #[cfg(target_os = "android")]
+    pub set_android_application_thread_khr: Option<pfn::SetAndroidApplicationThreadKHR>,
+    #[cfg(target_os = "android")]
+    pub create_swapchain_android_surface_khr: Option<pfn::CreateSwapchainAndroidSurfaceKHR>,
+    pub get_action_state_boolean: Option<pfn::GetActionStateBoolean>,
+    pub get_action_state_float: Option<pfn::GetActionStateFloat>,
+    pub get_action_state_vector2f: Option<pfn::GetActionStateVector2f>,
+    pub get_action_state_pose: Option<pfn::GetActionStatePose>,
+    pub create_action_set: Option<pfn::CreateActionSet>,
+    pub destroy_action_set: Option<pfn::DestroyActionSet>,
+    pub create_action: Option<pfn::CreateAction>,
+    pub destroy_action: Option<pfn::DestroyAction>,
+    pub suggest_interaction_profile_bindings: Option<pfn::SuggestInteractionProfileBindings>,
+    pub attach_session_action_sets: Option<pfn::AttachSessionActionSets>,
+    pub get_current_interaction_profile: Option<pfn::GetCurrentInteractionProfile>,
+    pub sync_actions: Option<pfn::SyncActions>,
+    pub enumerate_bound_sources_for_action: Option<pfn::EnumerateBoundSourcesForAction>,
+    pub get_input_source_localized_name: Option<pfn::GetInputSourceLocalizedName>,
+    pub locate_spaces: Option<pfn::LocateSpaces>,
+    pub get_vulkan_instance_extensions_khr: Option<pfn::GetVulkanInstanceExtensionsKHR>,
+    pub get_vulkan_device_extensions_khr: Option<pfn::GetVulkanDeviceExtensionsKHR>,
+    pub get_vulkan_graphics_device_khr: Option<pfn::GetVulkanGraphicsDeviceKHR>,
+    pub get_open_gl_graphics_requirements_khr: Option<pfn::GetOpenGLGraphicsRequirementsKHR>,
+    pub get_open_gles_graphics_requirements_khr: Option<pfn::GetOpenGLESGraphicsRequirementsKHR>,
+    pub get_vulkan_graphics_requirements_khr: Option<pfn::GetVulkanGraphicsRequirementsKHR>,
+    #[cfg(windows)]
+    pub get_d3d11_graphics_requirements_khr: Option<pfn::GetD3D11GraphicsRequirementsKHR>,
+    #[cfg(windows)]
+    pub get_d3d12_graphics_requirements_khr: Option<pfn::GetD3D12GraphicsRequirementsKHR>,
+    pub perf_settings_set_performance_level_ext: Option<pfn::PerfSettingsSetPerformanceLevelEXT>,
+    pub thermal_get_temperature_trend_ext: Option<pfn::ThermalGetTemperatureTrendEXT>,
+    pub set_debug_utils_object_name_ext: Option<pfn::SetDebugUtilsObjectNameEXT>,
+    pub create_debug_utils_messenger_ext: Option<pfn::CreateDebugUtilsMessengerEXT>,
+    pub destroy_debug_utils_messenger_ext: Option<pfn::DestroyDebugUtilsMessengerEXT>,
+    pub submit_debug_utils_message_ext: Option<pfn::SubmitDebugUtilsMessageEXT>,
+    pub session_begin_debug_utils_label_region_ext:
+        Option<pfn::SessionBeginDebugUtilsLabelRegionEXT>,
+    pub session_end_debug_utils_label_region_ext: Option<pfn::SessionEndDebugUtilsLabelRegionEXT>,
+    pub session_insert_debug_utils_label_ext: Option<pfn::SessionInsertDebugUtilsLabelEXT>,
+    #[cfg(windows)]
+    pub convert_time_to_win32_performance_counter_khr:
+        Option<pfn::ConvertTimeToWin32PerformanceCounterKHR>,
+    #[cfg(windows)]
+    pub convert_win32_performance_counter_to_time_khr:
+        Option<pfn::ConvertWin32PerformanceCounterToTimeKHR>,
+    pub create_vulkan_instance_khr: Option<pfn::CreateVulkanInstanceKHR>,
+    pub create_vulkan_device_khr: Option<pfn::CreateVulkanDeviceKHR>,
+    pub get_vulkan_graphics_device2_khr: Option<pfn::GetVulkanGraphicsDevice2KHR>,
+    pub convert_time_to_timespec_time_khr: Option<pfn::ConvertTimeToTimespecTimeKHR>,
+    pub convert_timespec_time_to_time_khr: Option<pfn::ConvertTimespecTimeToTimeKHR>,
+    pub get_visibility_mask_khr: Option<pfn::GetVisibilityMaskKHR>,
+    pub create_spatial_anchor_msft: Option<pfn::CreateSpatialAnchorMSFT>,
+    pub create_spatial_anchor_space_msft: Option<pfn::CreateSpatialAnchorSpaceMSFT>,
+    pub destroy_spatial_anchor_msft: Option<pfn::DestroySpatialAnchorMSFT>,
+    pub set_input_device_active_ext: Option<pfn::SetInputDeviceActiveEXT>,
+    pub set_input_device_state_bool_ext: Option<pfn::SetInputDeviceStateBoolEXT>,
+    pub set_input_device_state_float_ext: Option<pfn::SetInputDeviceStateFloatEXT>,
+    pub set_input_device_state_vector2f_ext: Option<pfn::SetInputDeviceStateVector2fEXT>,
+    pub set_input_device_location_ext: Option<pfn::SetInputDeviceLocationEXT>,
+    pub create_spatial_graph_node_space_msft: Option<pfn::CreateSpatialGraphNodeSpaceMSFT>,
+    pub try_create_spatial_graph_static_node_binding_msft:
+        Option<pfn::TryCreateSpatialGraphStaticNodeBindingMSFT>,
+    pub destroy_spatial_graph_node_binding_msft: Option<pfn::DestroySpatialGraphNodeBindingMSFT>,
+    pub get_spatial_graph_node_binding_properties_msft:
+        Option<pfn::GetSpatialGraphNodeBindingPropertiesMSFT>,
+    pub create_hand_tracker_ext: Option<pfn::CreateHandTrackerEXT>,
+    pub destroy_hand_tracker_ext: Option<pfn::DestroyHandTrackerEXT>,
+    pub locate_hand_joints_ext: Option<pfn::LocateHandJointsEXT>,
+    pub create_face_tracker_fb: Option<pfn::CreateFaceTrackerFB>,
+    pub destroy_face_tracker_fb: Option<pfn::DestroyFaceTrackerFB>,
+    pub get_face_expression_weights_fb: Option<pfn::GetFaceExpressionWeightsFB>,
+    pub create_body_tracker_fb: Option<pfn::CreateBodyTrackerFB>,
+    pub destroy_body_tracker_fb: Option<pfn::DestroyBodyTrackerFB>,
+    pub locate_body_joints_fb: Option<pfn::LocateBodyJointsFB>,
+    pub get_body_skeleton_fb: Option<pfn::GetBodySkeletonFB>,
+    pub create_eye_tracker_fb: Option<pfn::CreateEyeTrackerFB>,
+    pub destroy_eye_tracker_fb: Option<pfn::DestroyEyeTrackerFB>,
+    pub get_eye_gazes_fb: Option<pfn::GetEyeGazesFB>,
+    pub create_hand_mesh_space_msft: Option<pfn::CreateHandMeshSpaceMSFT>,
+    pub update_hand_mesh_msft: Option<pfn::UpdateHandMeshMSFT>,
+    pub get_controller_model_key_msft: Option<pfn::GetControllerModelKeyMSFT>,
+    pub load_controller_model_msft: Option<pfn::LoadControllerModelMSFT>,
+    pub get_controller_model_properties_msft: Option<pfn::GetControllerModelPropertiesMSFT>,
+    pub get_controller_model_state_msft: Option<pfn::GetControllerModelStateMSFT>,
+    pub enumerate_display_refresh_rates_fb: Option<pfn::EnumerateDisplayRefreshRatesFB>,
+    pub get_display_refresh_rate_fb: Option<pfn::GetDisplayRefreshRateFB>,
+    pub request_display_refresh_rate_fb: Option<pfn::RequestDisplayRefreshRateFB>,
+    #[cfg(windows)]
+    pub create_spatial_anchor_from_perception_anchor_msft:
+        Option<pfn::CreateSpatialAnchorFromPerceptionAnchorMSFT>,
+    #[cfg(windows)]
+    pub try_get_perception_anchor_from_spatial_anchor_msft:
+        Option<pfn::TryGetPerceptionAnchorFromSpatialAnchorMSFT>,
+    pub update_swapchain_fb: Option<pfn::UpdateSwapchainFB>,
+    pub get_swapchain_state_fb: Option<pfn::GetSwapchainStateFB>,
+    pub enumerate_color_spaces_fb: Option<pfn::EnumerateColorSpacesFB>,
+    pub set_color_space_fb: Option<pfn::SetColorSpaceFB>,
+    pub create_foveation_profile_fb: Option<pfn::CreateFoveationProfileFB>,
+    pub destroy_foveation_profile_fb: Option<pfn::DestroyFoveationProfileFB>,
+    pub get_foveation_eye_tracked_state_meta: Option<pfn::GetFoveationEyeTrackedStateMETA>,
+    pub get_hand_mesh_fb: Option<pfn::GetHandMeshFB>,
+    pub enumerate_render_model_paths_fb: Option<pfn::EnumerateRenderModelPathsFB>,
+    pub get_render_model_properties_fb: Option<pfn::GetRenderModelPropertiesFB>,
+    pub load_render_model_fb: Option<pfn::LoadRenderModelFB>,
+    pub query_system_tracked_keyboard_fb: Option<pfn::QuerySystemTrackedKeyboardFB>,
+    pub create_keyboard_space_fb: Option<pfn::CreateKeyboardSpaceFB>,
+    pub set_environment_depth_estimation_varjo: Option<pfn::SetEnvironmentDepthEstimationVARJO>,
+    pub enumerate_reprojection_modes_msft: Option<pfn::EnumerateReprojectionModesMSFT>,
+    pub get_audio_output_device_guid_oculus: Option<pfn::GetAudioOutputDeviceGuidOculus>,
+    pub get_audio_input_device_guid_oculus: Option<pfn::GetAudioInputDeviceGuidOculus>,
+    pub create_spatial_anchor_fb: Option<pfn::CreateSpatialAnchorFB>,
+    pub get_space_uuid_fb: Option<pfn::GetSpaceUuidFB>,
+    pub enumerate_space_supported_components_fb: Option<pfn::EnumerateSpaceSupportedComponentsFB>,
+    pub set_space_component_status_fb: Option<pfn::SetSpaceComponentStatusFB>,
+    pub get_space_component_status_fb: Option<pfn::GetSpaceComponentStatusFB>,
+    pub create_triangle_mesh_fb: Option<pfn::CreateTriangleMeshFB>,
+    pub destroy_triangle_mesh_fb: Option<pfn::DestroyTriangleMeshFB>,
+    pub triangle_mesh_get_vertex_buffer_fb: Option<pfn::TriangleMeshGetVertexBufferFB>,
+    pub triangle_mesh_get_index_buffer_fb: Option<pfn::TriangleMeshGetIndexBufferFB>,
+    pub triangle_mesh_begin_update_fb: Option<pfn::TriangleMeshBeginUpdateFB>,
+    pub triangle_mesh_end_update_fb: Option<pfn::TriangleMeshEndUpdateFB>,
+    pub triangle_mesh_begin_vertex_buffer_update_fb:
+        Option<pfn::TriangleMeshBeginVertexBufferUpdateFB>,
+    pub triangle_mesh_end_vertex_buffer_update_fb: Option<pfn::TriangleMeshEndVertexBufferUpdateFB>,
+    pub create_passthrough_fb: Option<pfn::CreatePassthroughFB>,
+    pub destroy_passthrough_fb: Option<pfn::DestroyPassthroughFB>,
+    pub passthrough_start_fb: Option<pfn::PassthroughStartFB>,
+    pub passthrough_pause_fb: Option<pfn::PassthroughPauseFB>,
+    pub create_passthrough_layer_fb: Option<pfn::CreatePassthroughLayerFB>,
+    pub destroy_passthrough_layer_fb: Option<pfn::DestroyPassthroughLayerFB>,
+    pub passthrough_layer_pause_fb: Option<pfn::PassthroughLayerPauseFB>,
+    pub passthrough_layer_resume_fb: Option<pfn::PassthroughLayerResumeFB>,
+    pub passthrough_layer_set_style_fb: Option<pfn::PassthroughLayerSetStyleFB>,
+    pub create_geometry_instance_fb: Option<pfn::CreateGeometryInstanceFB>,
+    pub destroy_geometry_instance_fb: Option<pfn::DestroyGeometryInstanceFB>,
+    pub geometry_instance_set_transform_fb: Option<pfn::GeometryInstanceSetTransformFB>,
+    pub query_spaces_fb: Option<pfn::QuerySpacesFB>,
+    pub retrieve_space_query_results_fb: Option<pfn::RetrieveSpaceQueryResultsFB>,
+    pub save_space_fb: Option<pfn::SaveSpaceFB>,
+    pub erase_space_fb: Option<pfn::EraseSpaceFB>,
+    pub save_space_list_fb: Option<pfn::SaveSpaceListFB>,
+    pub share_spaces_fb: Option<pfn::ShareSpacesFB>,
+    pub get_space_container_fb: Option<pfn::GetSpaceContainerFB>,
+    pub get_space_bounding_box2_dfb: Option<pfn::GetSpaceBoundingBox2DFB>,
+    pub get_space_bounding_box3_dfb: Option<pfn::GetSpaceBoundingBox3DFB>,
+    pub get_space_semantic_labels_fb: Option<pfn::GetSpaceSemanticLabelsFB>,
+    pub get_space_boundary2_dfb: Option<pfn::GetSpaceBoundary2DFB>,
+    pub get_space_room_layout_fb: Option<pfn::GetSpaceRoomLayoutFB>,
+    pub request_scene_capture_fb: Option<pfn::RequestSceneCaptureFB>,
+    pub passthrough_layer_set_keyboard_hands_intensity_fb:
+        Option<pfn::PassthroughLayerSetKeyboardHandsIntensityFB>,
+    pub create_spatial_anchor_store_connection_msft:
+        Option<pfn::CreateSpatialAnchorStoreConnectionMSFT>,
+    pub destroy_spatial_anchor_store_connection_msft:
+        Option<pfn::DestroySpatialAnchorStoreConnectionMSFT>,
+    pub persist_spatial_anchor_msft: Option<pfn::PersistSpatialAnchorMSFT>,
+    pub enumerate_persisted_spatial_anchor_names_msft:
+        Option<pfn::EnumeratePersistedSpatialAnchorNamesMSFT>,
+    pub create_spatial_anchor_from_persisted_name_msft:
+        Option<pfn::CreateSpatialAnchorFromPersistedNameMSFT>,
+    pub unpersist_spatial_anchor_msft: Option<pfn::UnpersistSpatialAnchorMSFT>,
+    pub clear_spatial_anchor_store_msft: Option<pfn::ClearSpatialAnchorStoreMSFT>,
+    pub create_facial_tracker_htc: Option<pfn::CreateFacialTrackerHTC>,
+    pub destroy_facial_tracker_htc: Option<pfn::DestroyFacialTrackerHTC>,
+    pub get_facial_expressions_htc: Option<pfn::GetFacialExpressionsHTC>,
+    pub create_passthrough_htc: Option<pfn::CreatePassthroughHTC>,
+    pub destroy_passthrough_htc: Option<pfn::DestroyPassthroughHTC>,
+    pub enumerate_vive_tracker_paths_htcx: Option<pfn::EnumerateViveTrackerPathsHTCX>,
+    pub set_marker_tracking_varjo: Option<pfn::SetMarkerTrackingVARJO>,
+    pub set_marker_tracking_timeout_varjo: Option<pfn::SetMarkerTrackingTimeoutVARJO>,
+    pub set_marker_tracking_prediction_varjo: Option<pfn::SetMarkerTrackingPredictionVARJO>,
+    pub get_marker_size_varjo: Option<pfn::GetMarkerSizeVARJO>,
+    pub create_marker_space_varjo: Option<pfn::CreateMarkerSpaceVARJO>,
+    pub set_digital_lens_control_almalence: Option<pfn::SetDigitalLensControlALMALENCE>,
+    pub set_view_offset_varjo: Option<pfn::SetViewOffsetVARJO>,
+    pub enumerate_external_cameras_oculus: Option<pfn::EnumerateExternalCamerasOCULUS>,
+    pub create_passthrough_color_lut_meta: Option<pfn::CreatePassthroughColorLutMETA>,
+    pub destroy_passthrough_color_lut_meta: Option<pfn::DestroyPassthroughColorLutMETA>,
+    pub update_passthrough_color_lut_meta: Option<pfn::UpdatePassthroughColorLutMETA>,
+    pub enumerate_performance_metrics_counter_paths_meta:
+        Option<pfn::EnumeratePerformanceMetricsCounterPathsMETA>,
+    pub set_performance_metrics_state_meta: Option<pfn::SetPerformanceMetricsStateMETA>,
+    pub get_performance_metrics_state_meta: Option<pfn::GetPerformanceMetricsStateMETA>,
+    pub query_performance_metrics_counter_meta: Option<pfn::QueryPerformanceMetricsCounterMETA>,
+    pub get_passthrough_preferences_meta: Option<pfn::GetPassthroughPreferencesMETA>,
+    pub apply_foveation_htc: Option<pfn::ApplyFoveationHTC>,
+    pub create_space_from_coordinate_frame_uidml: Option<pfn::CreateSpaceFromCoordinateFrameUIDML>,
+    pub get_device_sample_rate_fb: Option<pfn::GetDeviceSampleRateFB>,
+    pub set_tracking_optimization_settings_hint_qcom:
+        Option<pfn::SetTrackingOptimizationSettingsHintQCOM>,
+    pub create_space_user_fb: Option<pfn::CreateSpaceUserFB>,
+    pub get_space_user_id_fb: Option<pfn::GetSpaceUserIdFB>,
+    pub destroy_space_user_fb: Option<pfn::DestroySpaceUserFB>,
+    pub apply_force_feedback_curl_mndx: Option<pfn::ApplyForceFeedbackCurlMNDX>,
+    pub create_plane_detector_ext: Option<pfn::CreatePlaneDetectorEXT>,
+    pub destroy_plane_detector_ext: Option<pfn::DestroyPlaneDetectorEXT>,
+    pub begin_plane_detection_ext: Option<pfn::BeginPlaneDetectionEXT>,
+    pub get_plane_detection_state_ext: Option<pfn::GetPlaneDetectionStateEXT>,
+    pub get_plane_detections_ext: Option<pfn::GetPlaneDetectionsEXT>,
+    pub get_plane_polygon_buffer_ext: Option<pfn::GetPlanePolygonBufferEXT>,
+    pub create_virtual_keyboard_meta: Option<pfn::CreateVirtualKeyboardMETA>,
+    pub destroy_virtual_keyboard_meta: Option<pfn::DestroyVirtualKeyboardMETA>,
+    pub create_virtual_keyboard_space_meta: Option<pfn::CreateVirtualKeyboardSpaceMETA>,
+    pub suggest_virtual_keyboard_location_meta: Option<pfn::SuggestVirtualKeyboardLocationMETA>,
+    pub get_virtual_keyboard_scale_meta: Option<pfn::GetVirtualKeyboardScaleMETA>,
+    pub set_virtual_keyboard_model_visibility_meta:
+        Option<pfn::SetVirtualKeyboardModelVisibilityMETA>,
+    pub get_virtual_keyboard_model_animation_states_meta:
+        Option<pfn::GetVirtualKeyboardModelAnimationStatesMETA>,
+    pub get_virtual_keyboard_dirty_textures_meta: Option<pfn::GetVirtualKeyboardDirtyTexturesMETA>,
+    pub get_virtual_keyboard_texture_data_meta: Option<pfn::GetVirtualKeyboardTextureDataMETA>,
+    pub send_virtual_keyboard_input_meta: Option<pfn::SendVirtualKeyboardInputMETA>,
+    pub change_virtual_keyboard_text_context_meta:
+        Option<pfn::ChangeVirtualKeyboardTextContextMETA>,
+    pub get_vulkan_graphics_requirements2_khr: Option<pfn::GetVulkanGraphicsRequirements2KHR>,
+}
+
+impl Dispatch {
+    #[doc = r" Load all commands for `instance` from `get_instance_proc_addr`"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r""]
+    #[doc = r" `get_instance_proc_addr` must be a valid `xrGetInstanceProcAddr` implementation and"]
+    #[doc = r" `instance` a handle it recognizes."]
+    pub unsafe fn load(
+        get_instance_proc_addr: pfn::GetInstanceProcAddr,
+        instance: Instance,
+    ) -> Self {
+        Self {
+            get_instance_proc_addr,
+            destroy_instance: super::load(get_instance_proc_addr, instance, b"xrDestroyInstance\0")
+                .map(|f| mem::transmute::<pfn::VoidFunction, pfn::DestroyInstance>(f)),
+            result_to_string: super::load(get_instance_proc_addr, instance, b"xrResultToString\0")
+                .map(|f| mem::transmute::<pfn::VoidFunction, pfn::ResultToString>(f)),
+            structure_type_to_string: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrStructureTypeToString\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::StructureTypeToString>(f)),
+            get_instance_properties: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetInstanceProperties\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetInstanceProperties>(f)),
+            get_system: super::load(get_instance_proc_addr, instance, b"xrGetSystem\0")
+                .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetSystem>(f)),
+            get_system_properties: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetSystemProperties\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetSystemProperties>(f)),
+            create_session: super::load(get_instance_proc_addr, instance, b"xrCreateSession\0")
+                .map(|f| mem::transmute::<pfn::VoidFunction, pfn::CreateSession>(f)),
+            destroy_session: super::load(get_instance_proc_addr, instance, b"xrDestroySession\0")
+                .map(|f| mem::transmute::<pfn::VoidFunction, pfn::DestroySession>(f)),
+            destroy_space: super::load(get_instance_proc_addr, instance, b"xrDestroySpace\0")
+                .map(|f| mem::transmute::<pfn::VoidFunction, pfn::DestroySpace>(f)),
+            enumerate_swapchain_formats: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrEnumerateSwapchainFormats\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::EnumerateSwapchainFormats>(f)),
+            create_swapchain: super::load(get_instance_proc_addr, instance, b"xrCreateSwapchain\0")
+                .map(|f| mem::transmute::<pfn::VoidFunction, pfn::CreateSwapchain>(f)),
+            destroy_swapchain: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrDestroySwapchain\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::DestroySwapchain>(f)),
+            enumerate_swapchain_images: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrEnumerateSwapchainImages\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::EnumerateSwapchainImages>(f)),
+            acquire_swapchain_image: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrAcquireSwapchainImage\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::AcquireSwapchainImage>(f)),
+            wait_swapchain_image: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrWaitSwapchainImage\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::WaitSwapchainImage>(f)),
+            release_swapchain_image: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrReleaseSwapchainImage\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::ReleaseSwapchainImage>(f)),
+            begin_session: super::load(get_instance_proc_addr, instance, b"xrBeginSession\0")
+                .map(|f| mem::transmute::<pfn::VoidFunction, pfn::BeginSession>(f)),
+            end_session: super::load(get_instance_proc_addr, instance, b"xrEndSession\0")
+                .map(|f| mem::transmute::<pfn::VoidFunction, pfn::EndSession>(f)),
+            request_exit_session: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrRequestExitSession\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::RequestExitSession>(f)),
+            enumerate_reference_spaces: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrEnumerateReferenceSpaces\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::EnumerateReferenceSpaces>(f)),
+            create_reference_space: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrCreateReferenceSpace\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::CreateReferenceSpace>(f)),
+            create_action_space: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrCreateActionSpace\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::CreateActionSpace>(f)),
+            locate_space: super::load(get_instance_proc_addr, instance, b"xrLocateSpace\0")
+                .map(|f| mem::transmute::<pfn::VoidFunction, pfn::LocateSpace>(f)),
+            enumerate_view_configurations: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrEnumerateViewConfigurations\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::EnumerateViewConfigurations>(f)),
+            enumerate_environment_blend_modes: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrEnumerateEnvironmentBlendModes\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::EnumerateEnvironmentBlendModes>(f)),
+            get_view_configuration_properties: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetViewConfigurationProperties\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetViewConfigurationProperties>(f)),
+            enumerate_view_configuration_views: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrEnumerateViewConfigurationViews\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::EnumerateViewConfigurationViews>(f)),
+            begin_frame: super::load(get_instance_proc_addr, instance, b"xrBeginFrame\0")
+                .map(|f| mem::transmute::<pfn::VoidFunction, pfn::BeginFrame>(f)),
+            locate_views: super::load(get_instance_proc_addr, instance, b"xrLocateViews\0")
+                .map(|f| mem::transmute::<pfn::VoidFunction, pfn::LocateViews>(f)),
+            end_frame: super::load(get_instance_proc_addr, instance, b"xrEndFrame\0")
+                .map(|f| mem::transmute::<pfn::VoidFunction, pfn::EndFrame>(f)),
+            wait_frame: super::load(get_instance_proc_addr, instance, b"xrWaitFrame\0")
+                .map(|f| mem::transmute::<pfn::VoidFunction, pfn::WaitFrame>(f)),
+            apply_haptic_feedback: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrApplyHapticFeedback\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::ApplyHapticFeedback>(f)),
+            stop_haptic_feedback: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrStopHapticFeedback\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::StopHapticFeedback>(f)),
+            poll_event: super::load(get_instance_proc_addr, instance, b"xrPollEvent\0")
+                .map(|f| mem::transmute::<pfn::VoidFunction, pfn::PollEvent>(f)),
+            string_to_path: super::load(get_instance_proc_addr, instance, b"xrStringToPath\0")
+                .map(|f| mem::transmute::<pfn::VoidFunction, pfn::StringToPath>(f)),
+            path_to_string: super::load(get_instance_proc_addr, instance, b"xrPathToString\0")
+                .map(|f| mem::transmute::<pfn::VoidFunction, pfn::PathToString>(f)),
+            get_reference_space_bounds_rect: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetReferenceSpaceBoundsRect\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetReferenceSpaceBoundsRect>(f)),
+            #[cfg(target_os = "android")]
+            set_android_application_thread_khr: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrSetAndroidApplicationThreadKHR\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::SetAndroidApplicationThreadKHR>(f)),
+            #[cfg(target_os = "android")]
+            create_swapchain_android_surface_khr: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrCreateSwapchainAndroidSurfaceKHR\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::CreateSwapchainAndroidSurfaceKHR>(f)),
+            get_action_state_boolean: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetActionStateBoolean\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetActionStateBoolean>(f)),
+            get_action_state_float: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetActionStateFloat\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetActionStateFloat>(f)),
+            get_action_state_vector2f: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetActionStateVector2f\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetActionStateVector2f>(f)),
+            get_action_state_pose: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetActionStatePose\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetActionStatePose>(f)),
+            create_action_set: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrCreateActionSet\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::CreateActionSet>(f)),
+            destroy_action_set: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrDestroyActionSet\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::DestroyActionSet>(f)),
+            create_action: super::load(get_instance_proc_addr, instance, b"xrCreateAction\0")
+                .map(|f| mem::transmute::<pfn::VoidFunction, pfn::CreateAction>(f)),
+            destroy_action: super::load(get_instance_proc_addr, instance, b"xrDestroyAction\0")
+                .map(|f| mem::transmute::<pfn::VoidFunction, pfn::DestroyAction>(f)),
+            suggest_interaction_profile_bindings: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrSuggestInteractionProfileBindings\0",
+            )
+            .map(|f| {
+                mem::transmute::<pfn::VoidFunction, pfn::SuggestInteractionProfileBindings>(f)
+            }),
+            attach_session_action_sets: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrAttachSessionActionSets\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::AttachSessionActionSets>(f)),
+            get_current_interaction_profile: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetCurrentInteractionProfile\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetCurrentInteractionProfile>(f)),
+            sync_actions: super::load(get_instance_proc_addr, instance, b"xrSyncActions\0")
+                .map(|f| mem::transmute::<pfn::VoidFunction, pfn::SyncActions>(f)),
+            enumerate_bound_sources_for_action: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrEnumerateBoundSourcesForAction\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::EnumerateBoundSourcesForAction>(f)),
+            get_input_source_localized_name: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetInputSourceLocalizedName\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetInputSourceLocalizedName>(f)),
+            locate_spaces: super::load(get_instance_proc_addr, instance, b"xrLocateSpaces\0")
+                .or_else(|| super::load(get_instance_proc_addr, instance, b"xrLocateSpacesKHR\0"))
+                .map(|f| mem::transmute::<pfn::VoidFunction, pfn::LocateSpaces>(f)),
+            get_vulkan_instance_extensions_khr: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetVulkanInstanceExtensionsKHR\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetVulkanInstanceExtensionsKHR>(f)),
+            get_vulkan_device_extensions_khr: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetVulkanDeviceExtensionsKHR\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetVulkanDeviceExtensionsKHR>(f)),
+            get_vulkan_graphics_device_khr: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetVulkanGraphicsDeviceKHR\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetVulkanGraphicsDeviceKHR>(f)),
+            get_open_gl_graphics_requirements_khr: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetOpenGLGraphicsRequirementsKHR\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetOpenGLGraphicsRequirementsKHR>(f)),
+            get_open_gles_graphics_requirements_khr: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetOpenGLESGraphicsRequirementsKHR\0",
+            )
+            .map(|f| {
+                mem::transmute::<pfn::VoidFunction, pfn::GetOpenGLESGraphicsRequirementsKHR>(f)
+            }),
+            get_vulkan_graphics_requirements_khr: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetVulkanGraphicsRequirementsKHR\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetVulkanGraphicsRequirementsKHR>(f)),
+            #[cfg(windows)]
+            get_d3d11_graphics_requirements_khr: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetD3D11GraphicsRequirementsKHR\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetD3D11GraphicsRequirementsKHR>(f)),
+            #[cfg(windows)]
+            get_d3d12_graphics_requirements_khr: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetD3D12GraphicsRequirementsKHR\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetD3D12GraphicsRequirementsKHR>(f)),
+            perf_settings_set_performance_level_ext: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrPerfSettingsSetPerformanceLevelEXT\0",
+            )
+            .map(|f| {
+                mem::transmute::<pfn::VoidFunction, pfn::PerfSettingsSetPerformanceLevelEXT>(f)
+            }),
+            thermal_get_temperature_trend_ext: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrThermalGetTemperatureTrendEXT\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::ThermalGetTemperatureTrendEXT>(f)),
+            set_debug_utils_object_name_ext: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrSetDebugUtilsObjectNameEXT\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::SetDebugUtilsObjectNameEXT>(f)),
+            create_debug_utils_messenger_ext: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrCreateDebugUtilsMessengerEXT\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::CreateDebugUtilsMessengerEXT>(f)),
+            destroy_debug_utils_messenger_ext: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrDestroyDebugUtilsMessengerEXT\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::DestroyDebugUtilsMessengerEXT>(f)),
+            submit_debug_utils_message_ext: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrSubmitDebugUtilsMessageEXT\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::SubmitDebugUtilsMessageEXT>(f)),
+            session_begin_debug_utils_label_region_ext: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrSessionBeginDebugUtilsLabelRegionEXT\0",
+            )
+            .map(|f| {
+                mem::transmute::<pfn::VoidFunction, pfn::SessionBeginDebugUtilsLabelRegionEXT>(f)
+            }),
+            session_end_debug_utils_label_region_ext: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrSessionEndDebugUtilsLabelRegionEXT\0",
+            )
+            .map(|f| {
+                mem::transmute::<pfn::VoidFunction, pfn::SessionEndDebugUtilsLabelRegionEXT>(f)
+            }),
+            session_insert_debug_utils_label_ext: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrSessionInsertDebugUtilsLabelEXT\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::SessionInsertDebugUtilsLabelEXT>(f)),
+            #[cfg(windows)]
+            convert_time_to_win32_performance_counter_khr: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrConvertTimeToWin32PerformanceCounterKHR\0",
+            )
+            .map(|f| {
+                mem::transmute::<pfn::VoidFunction, pfn::ConvertTimeToWin32PerformanceCounterKHR>(f)
+            }),
+            #[cfg(windows)]
+            convert_win32_performance_counter_to_time_khr: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrConvertWin32PerformanceCounterToTimeKHR\0",
+            )
+            .map(|f| {
+                mem::transmute::<pfn::VoidFunction, pfn::ConvertWin32PerformanceCounterToTimeKHR>(f)
+            }),
+            create_vulkan_instance_khr: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrCreateVulkanInstanceKHR\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::CreateVulkanInstanceKHR>(f)),
+            create_vulkan_device_khr: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrCreateVulkanDeviceKHR\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::CreateVulkanDeviceKHR>(f)),
+            get_vulkan_graphics_device2_khr: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetVulkanGraphicsDevice2KHR\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetVulkanGraphicsDevice2KHR>(f)),
+            convert_time_to_timespec_time_khr: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrConvertTimeToTimespecTimeKHR\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::ConvertTimeToTimespecTimeKHR>(f)),
+            convert_timespec_time_to_time_khr: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrConvertTimespecTimeToTimeKHR\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::ConvertTimespecTimeToTimeKHR>(f)),
+            get_visibility_mask_khr: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetVisibilityMaskKHR\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetVisibilityMaskKHR>(f)),
+            create_spatial_anchor_msft: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrCreateSpatialAnchorMSFT\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::CreateSpatialAnchorMSFT>(f)),
+            create_spatial_anchor_space_msft: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrCreateSpatialAnchorSpaceMSFT\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::CreateSpatialAnchorSpaceMSFT>(f)),
+            destroy_spatial_anchor_msft: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrDestroySpatialAnchorMSFT\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::DestroySpatialAnchorMSFT>(f)),
+            set_input_device_active_ext: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrSetInputDeviceActiveEXT\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::SetInputDeviceActiveEXT>(f)),
+            set_input_device_state_bool_ext: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrSetInputDeviceStateBoolEXT\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::SetInputDeviceStateBoolEXT>(f)),
+            set_input_device_state_float_ext: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrSetInputDeviceStateFloatEXT\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::SetInputDeviceStateFloatEXT>(f)),
+            set_input_device_state_vector2f_ext: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrSetInputDeviceStateVector2fEXT\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::SetInputDeviceStateVector2fEXT>(f)),
+            set_input_device_location_ext: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrSetInputDeviceLocationEXT\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::SetInputDeviceLocationEXT>(f)),
+            create_spatial_graph_node_space_msft: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrCreateSpatialGraphNodeSpaceMSFT\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::CreateSpatialGraphNodeSpaceMSFT>(f)),
+            try_create_spatial_graph_static_node_binding_msft:
+                super::load(
+                    get_instance_proc_addr,
+                    instance,
+                    b"xrTryCreateSpatialGraphStaticNodeBindingMSFT\0",
+                )
+                .map(|f| {
+                    mem::transmute::<
+                        pfn::VoidFunction,
+                        pfn::TryCreateSpatialGraphStaticNodeBindingMSFT,
+                    >(f)
+                }),
+            destroy_spatial_graph_node_binding_msft: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrDestroySpatialGraphNodeBindingMSFT\0",
+            )
+            .map(|f| {
+                mem::transmute::<pfn::VoidFunction, pfn::DestroySpatialGraphNodeBindingMSFT>(f)
+            }),
+            get_spatial_graph_node_binding_properties_msft: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetSpatialGraphNodeBindingPropertiesMSFT\0",
+            )
+            .map(|f| {
+                mem::transmute::<pfn::VoidFunction, pfn::GetSpatialGraphNodeBindingPropertiesMSFT>(
+                    f,
+                )
+            }),
+            create_hand_tracker_ext: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrCreateHandTrackerEXT\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::CreateHandTrackerEXT>(f)),
+            destroy_hand_tracker_ext: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrDestroyHandTrackerEXT\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::DestroyHandTrackerEXT>(f)),
+            locate_hand_joints_ext: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrLocateHandJointsEXT\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::LocateHandJointsEXT>(f)),
+            create_face_tracker_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrCreateFaceTrackerFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::CreateFaceTrackerFB>(f)),
+            destroy_face_tracker_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrDestroyFaceTrackerFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::DestroyFaceTrackerFB>(f)),
+            get_face_expression_weights_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetFaceExpressionWeightsFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetFaceExpressionWeightsFB>(f)),
+            create_body_tracker_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrCreateBodyTrackerFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::CreateBodyTrackerFB>(f)),
+            destroy_body_tracker_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrDestroyBodyTrackerFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::DestroyBodyTrackerFB>(f)),
+            locate_body_joints_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrLocateBodyJointsFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::LocateBodyJointsFB>(f)),
+            get_body_skeleton_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetBodySkeletonFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetBodySkeletonFB>(f)),
+            create_eye_tracker_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrCreateEyeTrackerFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::CreateEyeTrackerFB>(f)),
+            destroy_eye_tracker_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrDestroyEyeTrackerFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::DestroyEyeTrackerFB>(f)),
+            get_eye_gazes_fb: super::load(get_instance_proc_addr, instance, b"xrGetEyeGazesFB\0")
+                .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetEyeGazesFB>(f)),
+            create_hand_mesh_space_msft: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrCreateHandMeshSpaceMSFT\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::CreateHandMeshSpaceMSFT>(f)),
+            update_hand_mesh_msft: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrUpdateHandMeshMSFT\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::UpdateHandMeshMSFT>(f)),
+            get_controller_model_key_msft: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetControllerModelKeyMSFT\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetControllerModelKeyMSFT>(f)),
+            load_controller_model_msft: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrLoadControllerModelMSFT\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::LoadControllerModelMSFT>(f)),
+            get_controller_model_properties_msft: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetControllerModelPropertiesMSFT\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetControllerModelPropertiesMSFT>(f)),
+            get_controller_model_state_msft: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetControllerModelStateMSFT\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetControllerModelStateMSFT>(f)),
+            enumerate_display_refresh_rates_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrEnumerateDisplayRefreshRatesFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::EnumerateDisplayRefreshRatesFB>(f)),
+            get_display_refresh_rate_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetDisplayRefreshRateFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetDisplayRefreshRateFB>(f)),
+            request_display_refresh_rate_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrRequestDisplayRefreshRateFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::RequestDisplayRefreshRateFB>(f)),
+            #[cfg(windows)]
+            create_spatial_anchor_from_perception_anchor_msft:
+                super::load(
+                    get_instance_proc_addr,
+                    instance,
+                    b"xrCreateSpatialAnchorFromPerceptionAnchorMSFT\0",
+                )
+                .map(|f| {
+                    mem::transmute::<
+                        pfn::VoidFunction,
+                        pfn::CreateSpatialAnchorFromPerceptionAnchorMSFT,
+                    >(f)
+                }),
+            #[cfg(windows)]
+            try_get_perception_anchor_from_spatial_anchor_msft:
+                super::load(
+                    get_instance_proc_addr,
+                    instance,
+                    b"xrTryGetPerceptionAnchorFromSpatialAnchorMSFT\0",
+                )
+                .map(|f| {
+                    mem::transmute::<
+                        pfn::VoidFunction,
+                        pfn::TryGetPerceptionAnchorFromSpatialAnchorMSFT,
+                    >(f)
+                }),
+            update_swapchain_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrUpdateSwapchainFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::UpdateSwapchainFB>(f)),
+            get_swapchain_state_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetSwapchainStateFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetSwapchainStateFB>(f)),
+            enumerate_color_spaces_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrEnumerateColorSpacesFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::EnumerateColorSpacesFB>(f)),
+            set_color_space_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrSetColorSpaceFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::SetColorSpaceFB>(f)),
+            create_foveation_profile_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrCreateFoveationProfileFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::CreateFoveationProfileFB>(f)),
+            destroy_foveation_profile_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrDestroyFoveationProfileFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::DestroyFoveationProfileFB>(f)),
+            get_foveation_eye_tracked_state_meta: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetFoveationEyeTrackedStateMETA\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetFoveationEyeTrackedStateMETA>(f)),
+            get_hand_mesh_fb: super::load(get_instance_proc_addr, instance, b"xrGetHandMeshFB\0")
+                .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetHandMeshFB>(f)),
+            enumerate_render_model_paths_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrEnumerateRenderModelPathsFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::EnumerateRenderModelPathsFB>(f)),
+            get_render_model_properties_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetRenderModelPropertiesFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetRenderModelPropertiesFB>(f)),
+            load_render_model_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrLoadRenderModelFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::LoadRenderModelFB>(f)),
+            query_system_tracked_keyboard_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrQuerySystemTrackedKeyboardFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::QuerySystemTrackedKeyboardFB>(f)),
+            create_keyboard_space_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrCreateKeyboardSpaceFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::CreateKeyboardSpaceFB>(f)),
+            set_environment_depth_estimation_varjo: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrSetEnvironmentDepthEstimationVARJO\0",
+            )
+            .map(|f| {
+                mem::transmute::<pfn::VoidFunction, pfn::SetEnvironmentDepthEstimationVARJO>(f)
+            }),
+            enumerate_reprojection_modes_msft: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrEnumerateReprojectionModesMSFT\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::EnumerateReprojectionModesMSFT>(f)),
+            get_audio_output_device_guid_oculus: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetAudioOutputDeviceGuidOculus\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetAudioOutputDeviceGuidOculus>(f)),
+            get_audio_input_device_guid_oculus: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetAudioInputDeviceGuidOculus\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetAudioInputDeviceGuidOculus>(f)),
+            create_spatial_anchor_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrCreateSpatialAnchorFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::CreateSpatialAnchorFB>(f)),
+            get_space_uuid_fb: super::load(get_instance_proc_addr, instance, b"xrGetSpaceUuidFB\0")
+                .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetSpaceUuidFB>(f)),
+            enumerate_space_supported_components_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrEnumerateSpaceSupportedComponentsFB\0",
+            )
+            .map(|f| {
+                mem::transmute::<pfn::VoidFunction, pfn::EnumerateSpaceSupportedComponentsFB>(f)
+            }),
+            set_space_component_status_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrSetSpaceComponentStatusFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::SetSpaceComponentStatusFB>(f)),
+            get_space_component_status_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetSpaceComponentStatusFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetSpaceComponentStatusFB>(f)),
+            create_triangle_mesh_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrCreateTriangleMeshFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::CreateTriangleMeshFB>(f)),
+            destroy_triangle_mesh_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrDestroyTriangleMeshFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::DestroyTriangleMeshFB>(f)),
+            triangle_mesh_get_vertex_buffer_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrTriangleMeshGetVertexBufferFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::TriangleMeshGetVertexBufferFB>(f)),
+            triangle_mesh_get_index_buffer_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrTriangleMeshGetIndexBufferFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::TriangleMeshGetIndexBufferFB>(f)),
+            triangle_mesh_begin_update_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrTriangleMeshBeginUpdateFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::TriangleMeshBeginUpdateFB>(f)),
+            triangle_mesh_end_update_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrTriangleMeshEndUpdateFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::TriangleMeshEndUpdateFB>(f)),
+            triangle_mesh_begin_vertex_buffer_update_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrTriangleMeshBeginVertexBufferUpdateFB\0",
+            )
+            .map(|f| {
+                mem::transmute::<pfn::VoidFunction, pfn::TriangleMeshBeginVertexBufferUpdateFB>(f)
+            }),
+            triangle_mesh_end_vertex_buffer_update_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrTriangleMeshEndVertexBufferUpdateFB\0",
+            )
+            .map(|f| {
+                mem::transmute::<pfn::VoidFunction, pfn::TriangleMeshEndVertexBufferUpdateFB>(f)
+            }),
+            create_passthrough_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrCreatePassthroughFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::CreatePassthroughFB>(f)),
+            destroy_passthrough_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrDestroyPassthroughFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::DestroyPassthroughFB>(f)),
+            passthrough_start_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrPassthroughStartFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::PassthroughStartFB>(f)),
+            passthrough_pause_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrPassthroughPauseFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::PassthroughPauseFB>(f)),
+            create_passthrough_layer_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrCreatePassthroughLayerFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::CreatePassthroughLayerFB>(f)),
+            destroy_passthrough_layer_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrDestroyPassthroughLayerFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::DestroyPassthroughLayerFB>(f)),
+            passthrough_layer_pause_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrPassthroughLayerPauseFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::PassthroughLayerPauseFB>(f)),
+            passthrough_layer_resume_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrPassthroughLayerResumeFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::PassthroughLayerResumeFB>(f)),
+            passthrough_layer_set_style_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrPassthroughLayerSetStyleFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::PassthroughLayerSetStyleFB>(f)),
+            create_geometry_instance_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrCreateGeometryInstanceFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::CreateGeometryInstanceFB>(f)),
+            destroy_geometry_instance_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrDestroyGeometryInstanceFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::DestroyGeometryInstanceFB>(f)),
+            geometry_instance_set_transform_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGeometryInstanceSetTransformFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GeometryInstanceSetTransformFB>(f)),
+            query_spaces_fb: super::load(get_instance_proc_addr, instance, b"xrQuerySpacesFB\0")
+                .map(|f| mem::transmute::<pfn::VoidFunction, pfn::QuerySpacesFB>(f)),
+            retrieve_space_query_results_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrRetrieveSpaceQueryResultsFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::RetrieveSpaceQueryResultsFB>(f)),
+            save_space_fb: super::load(get_instance_proc_addr, instance, b"xrSaveSpaceFB\0")
+                .map(|f| mem::transmute::<pfn::VoidFunction, pfn::SaveSpaceFB>(f)),
+            erase_space_fb: super::load(get_instance_proc_addr, instance, b"xrEraseSpaceFB\0")
+                .map(|f| mem::transmute::<pfn::VoidFunction, pfn::EraseSpaceFB>(f)),
+            save_space_list_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrSaveSpaceListFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::SaveSpaceListFB>(f)),
+            share_spaces_fb: super::load(get_instance_proc_addr, instance, b"xrShareSpacesFB\0")
+                .map(|f| mem::transmute::<pfn::VoidFunction, pfn::ShareSpacesFB>(f)),
+            get_space_container_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetSpaceContainerFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetSpaceContainerFB>(f)),
+            get_space_bounding_box2_dfb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetSpaceBoundingBox2DFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetSpaceBoundingBox2DFB>(f)),
+            get_space_bounding_box3_dfb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetSpaceBoundingBox3DFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetSpaceBoundingBox3DFB>(f)),
+            get_space_semantic_labels_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetSpaceSemanticLabelsFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetSpaceSemanticLabelsFB>(f)),
+            get_space_boundary2_dfb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetSpaceBoundary2DFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetSpaceBoundary2DFB>(f)),
+            get_space_room_layout_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetSpaceRoomLayoutFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetSpaceRoomLayoutFB>(f)),
+            request_scene_capture_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrRequestSceneCaptureFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::RequestSceneCaptureFB>(f)),
+            passthrough_layer_set_keyboard_hands_intensity_fb:
+                super::load(
+                    get_instance_proc_addr,
+                    instance,
+                    b"xrPassthroughLayerSetKeyboardHandsIntensityFB\0",
+                )
+                .map(|f| {
+                    mem::transmute::<
+                        pfn::VoidFunction,
+                        pfn::PassthroughLayerSetKeyboardHandsIntensityFB,
+                    >(f)
+                }),
+            create_spatial_anchor_store_connection_msft: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrCreateSpatialAnchorStoreConnectionMSFT\0",
+            )
+            .map(|f| {
+                mem::transmute::<pfn::VoidFunction, pfn::CreateSpatialAnchorStoreConnectionMSFT>(f)
+            }),
+            destroy_spatial_anchor_store_connection_msft: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrDestroySpatialAnchorStoreConnectionMSFT\0",
+            )
+            .map(|f| {
+                mem::transmute::<pfn::VoidFunction, pfn::DestroySpatialAnchorStoreConnectionMSFT>(f)
+            }),
+            persist_spatial_anchor_msft: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrPersistSpatialAnchorMSFT\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::PersistSpatialAnchorMSFT>(f)),
+            enumerate_persisted_spatial_anchor_names_msft: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrEnumeratePersistedSpatialAnchorNamesMSFT\0",
+            )
+            .map(|f| {
+                mem::transmute::<pfn::VoidFunction, pfn::EnumeratePersistedSpatialAnchorNamesMSFT>(
+                    f,
+                )
+            }),
+            create_spatial_anchor_from_persisted_name_msft: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrCreateSpatialAnchorFromPersistedNameMSFT\0",
+            )
+            .map(|f| {
+                mem::transmute::<pfn::VoidFunction, pfn::CreateSpatialAnchorFromPersistedNameMSFT>(
+                    f,
+                )
+            }),
+            unpersist_spatial_anchor_msft: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrUnpersistSpatialAnchorMSFT\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::UnpersistSpatialAnchorMSFT>(f)),
+            clear_spatial_anchor_store_msft: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrClearSpatialAnchorStoreMSFT\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::ClearSpatialAnchorStoreMSFT>(f)),
+            create_facial_tracker_htc: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrCreateFacialTrackerHTC\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::CreateFacialTrackerHTC>(f)),
+            destroy_facial_tracker_htc: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrDestroyFacialTrackerHTC\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::DestroyFacialTrackerHTC>(f)),
+            get_facial_expressions_htc: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetFacialExpressionsHTC\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetFacialExpressionsHTC>(f)),
+            create_passthrough_htc: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrCreatePassthroughHTC\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::CreatePassthroughHTC>(f)),
+            destroy_passthrough_htc: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrDestroyPassthroughHTC\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::DestroyPassthroughHTC>(f)),
+            enumerate_vive_tracker_paths_htcx: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrEnumerateViveTrackerPathsHTCX\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::EnumerateViveTrackerPathsHTCX>(f)),
+            set_marker_tracking_varjo: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrSetMarkerTrackingVARJO\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::SetMarkerTrackingVARJO>(f)),
+            set_marker_tracking_timeout_varjo: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrSetMarkerTrackingTimeoutVARJO\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::SetMarkerTrackingTimeoutVARJO>(f)),
+            set_marker_tracking_prediction_varjo: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrSetMarkerTrackingPredictionVARJO\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::SetMarkerTrackingPredictionVARJO>(f)),
+            get_marker_size_varjo: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetMarkerSizeVARJO\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetMarkerSizeVARJO>(f)),
+            create_marker_space_varjo: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrCreateMarkerSpaceVARJO\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::CreateMarkerSpaceVARJO>(f)),
+            set_digital_lens_control_almalence: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrSetDigitalLensControlALMALENCE\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::SetDigitalLensControlALMALENCE>(f)),
+            set_view_offset_varjo: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrSetViewOffsetVARJO\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::SetViewOffsetVARJO>(f)),
+            enumerate_external_cameras_oculus: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrEnumerateExternalCamerasOCULUS\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::EnumerateExternalCamerasOCULUS>(f)),
+            create_passthrough_color_lut_meta: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrCreatePassthroughColorLutMETA\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::CreatePassthroughColorLutMETA>(f)),
+            destroy_passthrough_color_lut_meta: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrDestroyPassthroughColorLutMETA\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::DestroyPassthroughColorLutMETA>(f)),
+            update_passthrough_color_lut_meta: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrUpdatePassthroughColorLutMETA\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::UpdatePassthroughColorLutMETA>(f)),
+            enumerate_performance_metrics_counter_paths_meta:
+                super::load(
+                    get_instance_proc_addr,
+                    instance,
+                    b"xrEnumeratePerformanceMetricsCounterPathsMETA\0",
+                )
+                .map(|f| {
+                    mem::transmute::<
+                        pfn::VoidFunction,
+                        pfn::EnumeratePerformanceMetricsCounterPathsMETA,
+                    >(f)
+                }),
+            set_performance_metrics_state_meta: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrSetPerformanceMetricsStateMETA\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::SetPerformanceMetricsStateMETA>(f)),
+            get_performance_metrics_state_meta: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetPerformanceMetricsStateMETA\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetPerformanceMetricsStateMETA>(f)),
+            query_performance_metrics_counter_meta: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrQueryPerformanceMetricsCounterMETA\0",
+            )
+            .map(|f| {
+                mem::transmute::<pfn::VoidFunction, pfn::QueryPerformanceMetricsCounterMETA>(f)
+            }),
+            get_passthrough_preferences_meta: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetPassthroughPreferencesMETA\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetPassthroughPreferencesMETA>(f)),
+            apply_foveation_htc: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrApplyFoveationHTC\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::ApplyFoveationHTC>(f)),
+            create_space_from_coordinate_frame_uidml: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrCreateSpaceFromCoordinateFrameUIDML\0",
+            )
+            .map(|f| {
+                mem::transmute::<pfn::VoidFunction, pfn::CreateSpaceFromCoordinateFrameUIDML>(f)
+            }),
+            get_device_sample_rate_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetDeviceSampleRateFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetDeviceSampleRateFB>(f)),
+            set_tracking_optimization_settings_hint_qcom: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrSetTrackingOptimizationSettingsHintQCOM\0",
+            )
+            .map(|f| {
+                mem::transmute::<pfn::VoidFunction, pfn::SetTrackingOptimizationSettingsHintQCOM>(f)
+            }),
+            create_space_user_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrCreateSpaceUserFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::CreateSpaceUserFB>(f)),
+            get_space_user_id_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetSpaceUserIdFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetSpaceUserIdFB>(f)),
+            destroy_space_user_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrDestroySpaceUserFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::DestroySpaceUserFB>(f)),
+            apply_force_feedback_curl_mndx: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrApplyForceFeedbackCurlMNDX\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::ApplyForceFeedbackCurlMNDX>(f)),
+            create_plane_detector_ext: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrCreatePlaneDetectorEXT\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::CreatePlaneDetectorEXT>(f)),
+            destroy_plane_detector_ext: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrDestroyPlaneDetectorEXT\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::DestroyPlaneDetectorEXT>(f)),
+            begin_plane_detection_ext: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrBeginPlaneDetectionEXT\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::BeginPlaneDetectionEXT>(f)),
+            get_plane_detection_state_ext: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetPlaneDetectionStateEXT\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetPlaneDetectionStateEXT>(f)),
+            get_plane_detections_ext: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetPlaneDetectionsEXT\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetPlaneDetectionsEXT>(f)),
+            get_plane_polygon_buffer_ext: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetPlanePolygonBufferEXT\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetPlanePolygonBufferEXT>(f)),
+            create_virtual_keyboard_meta: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrCreateVirtualKeyboardMETA\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::CreateVirtualKeyboardMETA>(f)),
+            destroy_virtual_keyboard_meta: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrDestroyVirtualKeyboardMETA\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::DestroyVirtualKeyboardMETA>(f)),
+            create_virtual_keyboard_space_meta: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrCreateVirtualKeyboardSpaceMETA\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::CreateVirtualKeyboardSpaceMETA>(f)),
+            suggest_virtual_keyboard_location_meta: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrSuggestVirtualKeyboardLocationMETA\0",
+            )
+            .map(|f| {
+                mem::transmute::<pfn::VoidFunction, pfn::SuggestVirtualKeyboardLocationMETA>(f)
+            }),
+            get_virtual_keyboard_scale_meta: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetVirtualKeyboardScaleMETA\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetVirtualKeyboardScaleMETA>(f)),
+            set_virtual_keyboard_model_visibility_meta: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrSetVirtualKeyboardModelVisibilityMETA\0",
+            )
+            .map(|f| {
+                mem::transmute::<pfn::VoidFunction, pfn::SetVirtualKeyboardModelVisibilityMETA>(f)
+            }),
+            get_virtual_keyboard_model_animation_states_meta:
+                super::load(
+                    get_instance_proc_addr,
+                    instance,
+                    b"xrGetVirtualKeyboardModelAnimationStatesMETA\0",
+                )
+                .map(|f| {
+                    mem::transmute::<
+                        pfn::VoidFunction,
+                        pfn::GetVirtualKeyboardModelAnimationStatesMETA,
+                    >(f)
+                }),
+            get_virtual_keyboard_dirty_textures_meta: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetVirtualKeyboardDirtyTexturesMETA\0",
+            )
+            .map(|f| {
+                mem::transmute::<pfn::VoidFunction, pfn::GetVirtualKeyboardDirtyTexturesMETA>(f)
+            }),
+            get_virtual_keyboard_texture_data_meta: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetVirtualKeyboardTextureDataMETA\0",
+            )
+            .map(|f| {
+                mem::transmute::<pfn::VoidFunction, pfn::GetVirtualKeyboardTextureDataMETA>(f)
+            }),
+            send_virtual_keyboard_input_meta: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrSendVirtualKeyboardInputMETA\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::SendVirtualKeyboardInputMETA>(f)),
+            change_virtual_keyboard_text_context_meta: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrChangeVirtualKeyboardTextContextMETA\0",
+            )
+            .map(|f| {
+                mem::transmute::<pfn::VoidFunction, pfn::ChangeVirtualKeyboardTextContextMETA>(f)
+            }),
+            get_vulkan_graphics_requirements2_khr: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetVulkanGraphicsRequirements2KHR\0",
+            )
+            .map(|f| {
+                mem::transmute::<pfn::VoidFunction, pfn::GetVulkanGraphicsRequirements2KHR>(f)
+            }),
+        }
+    }
+}
+
+#[doc = r" An OpenXR API layer"]
+#[doc = r""]
+#[doc = r" Every method corresponds to an OpenXR command, and receives the function pointers of"]
+#[doc = r" the next link in the call chain alongside the command's arguments. The default"]
+#[doc = r" implementations pass the call through unchanged."]
+#[doc = r""]
+#[doc = r" # Safety"]
+#[doc = r""]
+#[doc = r" Methods receive raw pointers straight from the application, with the same validity"]
+#[doc = r" guarantees the OpenXR specification makes to runtimes."]
+pub trait Layer: Send + Sync + 'static {
+    #[doc = "See [xrDestroyInstance](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrDestroyInstance)"]
+    unsafe fn destroy_instance(&self, next: &Dispatch, instance: Instance) -> Result {
+        match next.destroy_instance {
+            Some(f) => f(instance),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrResultToString](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrResultToString)"]
+    unsafe fn result_to_string(
+        &self,
+        next: &Dispatch,
+        instance: Instance,
+        value: Result,
+        buffer: *mut c_char,
+    ) -> Result {
+        match next.result_to_string {
+            Some(f) => f(instance, value, buffer),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrStructureTypeToString](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrStructureTypeToString)"]
+    unsafe fn structure_type_to_string(
+        &self,
+        next: &Dispatch,
+        instance: Instance,
+        value: StructureType,
+        buffer: *mut c_char,
+    ) -> Result {
+        match next.structure_type_to_string {
+            Some(f) => f(instance, value, buffer),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrGetInstanceProperties](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrGetInstanceProperties)"]
+    unsafe fn get_instance_properties(
+        &self,
+        next: &Dispatch,
+        instance: Instance,
+        instance_properties: *mut InstanceProperties,
+    ) -> Result {
+        match next.get_instance_properties {
+            Some(f) => f(instance, instance_properties),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrGetSystem](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrGetSystem)"]
+    unsafe fn get_system(
+        &self,
+        next: &Dispatch,
+        instance: Instance,
+        get_info: *const SystemGetInfo,
+        system_id: *mut SystemId,
+    ) -> Result {
+        match next.get_system {
+            Some(f) => f(instance, get_info, system_id),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrGetSystemProperties](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrGetSystemProperties)"]
+    unsafe fn get_system_properties(
+        &self,
+        next: &Dispatch,
+        instance: Instance,
+        system_id: SystemId,
+        properties: *mut SystemProperties,
+    ) -> Result {
+        match next.get_system_properties {
+            Some(f) => f(instance, system_id, properties),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrCreateSession](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrCreateSession)"]
+    unsafe fn create_session(
+        &self,
+        next: &Dispatch,
+        instance: Instance,
+        create_info: *const SessionCreateInfo,
+        session: *mut Session,
+    ) -> Result {
+        match next.create_session {
+            Some(f) => f(instance, create_info, session),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrDestroySession](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrDestroySession)"]
+    unsafe fn destroy_session(&self, next: &Dispatch, session: Session) -> Result {
+        match next.destroy_session {
+            Some(f) => f(session),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrDestroySpace](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrDestroySpace)"]
+    unsafe fn destroy_space(&self, next: &Dispatch, space: Space) -> Result {
+        match next.destroy_space {
+            Some(f) => f(space),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrEnumerateSwapchainFormats](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrEnumerateSwapchainFormats)"]
+    unsafe fn enumerate_swapchain_formats(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        format_capacity_input: u32,
+        format_count_output: *mut u32,
+        formats: *mut i64,
+    ) -> Result {
+        match next.enumerate_swapchain_formats {
+            Some(f) => f(session, format_capacity_input, format_count_output, formats),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrCreateSwapchain](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrCreateSwapchain)"]
+    unsafe fn create_swapchain(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        create_info: *const SwapchainCreateInfo,
+        swapchain: *mut Swapchain,
+    ) -> Result {
+        match next.create_swapchain {
+            Some(f) => f(session, create_info, swapchain),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrDestroySwapchain](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrDestroySwapchain)"]
+    unsafe fn destroy_swapchain(&self, next: &Dispatch, swapchain: Swapchain) -> Result {
+        match next.destroy_swapchain {
+            Some(f) => f(swapchain),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrEnumerateSwapchainImages](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrEnumerateSwapchainImages)"]
+    unsafe fn enumerate_swapchain_images(
+        &self,
+        next: &Dispatch,
+        swapchain: Swapchain,
+        image_capacity_input: u32,
+        image_count_output: *mut u32,
+        images: *mut SwapchainImageBaseHeader,
+    ) -> Result {
+        match next.enumerate_swapchain_images {
+            Some(f) => f(swapchain, image_capacity_input, image_count_output, images),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrAcquireSwapchainImage](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrAcquireSwapchainImage)"]
+    unsafe fn acquire_swapchain_image(
+        &self,
+        next: &Dispatch,
+        swapchain: Swapchain,
+        acquire_info: *const SwapchainImageAcquireInfo,
+        index: *mut u32,
+    ) -> Result {
+        match next.acquire_swapchain_image {
+            Some(f) => f(swapchain, acquire_info, index),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrWaitSwapchainImage](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrWaitSwapchainImage)"]
+    unsafe fn wait_swapchain_image(
+        &self,
+        next: &Dispatch,
+        swapchain: Swapchain,
+        wait_info: *const SwapchainImageWaitInfo,
+    ) -> Result {
+        match next.wait_swapchain_image {
+            Some(f) => f(swapchain, wait_info),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrReleaseSwapchainImage](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrReleaseSwapchainImage)"]
+    unsafe fn release_swapchain_image(
+        &self,
+        next: &Dispatch,
+        swapchain: Swapchain,
+        release_info: *const SwapchainImageReleaseInfo,
+    ) -> Result {
+        match next.release_swapchain_image {
+            Some(f) => f(swapchain, release_info),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrBeginSession](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrBeginSession)"]
+    unsafe fn begin_session(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        begin_info: *const SessionBeginInfo,
+    ) -> Result {
+        match next.begin_session {
+            Some(f) => f(session, begin_info),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrEndSession](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrEndSession)"]
+    unsafe fn end_session(&self, next: &Dispatch, session: Session) -> Result {
+        match next.end_session {
+            Some(f) => f(session),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrRequestExitSession](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrRequestExitSession)"]
+    unsafe fn request_exit_session(&self, next: &Dispatch, session: Session) -> Result {
+        match next.request_exit_session {
+            Some(f) => f(session),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrEnumerateReferenceSpaces](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrEnumerateReferenceSpaces)"]
+    unsafe fn enumerate_reference_spaces(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        space_capacity_input: u32,
+        space_count_output: *mut u32,
+        spaces: *mut ReferenceSpaceType,
+    ) -> Result {
+        match next.enumerate_reference_spaces {
+            Some(f) => f(session, space_capacity_input, space_count_output, spaces),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrCreateReferenceSpace](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrCreateReferenceSpace)"]
+    unsafe fn create_reference_space(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        create_info: *const ReferenceSpaceCreateInfo,
+        space: *mut Space,
+    ) -> Result {
+        match next.create_reference_space {
+            Some(f) => f(session, create_info, space),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrCreateActionSpace](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrCreateActionSpace)"]
+    unsafe fn create_action_space(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        create_info: *const ActionSpaceCreateInfo,
+        space: *mut Space,
+    ) -> Result {
+        match next.create_action_space {
+            Some(f) => f(session, create_info, space),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrLocateSpace](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrLocateSpace)"]
+    unsafe fn locate_space(
+        &self,
+        next: &Dispatch,
+        space: Space,
+        base_space: Space,
+        time: Time,
+        location: *mut SpaceLocation,
+    ) -> Result {
+        match next.locate_space {
+            Some(f) => f(space, base_space, time, location),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrEnumerateViewConfigurations](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrEnumerateViewConfigurations)"]
+    unsafe fn enumerate_view_configurations(
+        &self,
+        next: &Dispatch,
+        instance: Instance,
+        system_id: SystemId,
+        view_configuration_type_capacity_input: u32,
+        view_configuration_type_count_output: *mut u32,
+        view_configuration_types: *mut ViewConfigurationType,
+    ) -> Result {
+        match next.enumerate_view_configurations {
+            Some(f) => f(
+                instance,
+                system_id,
+                view_configuration_type_capacity_input,
+                view_configuration_type_count_output,
+                view_configuration_types,
+            ),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrEnumerateEnvironmentBlendModes](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrEnumerateEnvironmentBlendModes)"]
+    unsafe fn enumerate_environment_blend_modes(
+        &self,
+        next: &Dispatch,
+        instance: Instance,
+        system_id: SystemId,
+        view_configuration_type: ViewConfigurationType,
+        environment_blend_mode_capacity_input: u32,
+        environment_blend_mode_count_output: *mut u32,
+        environment_blend_modes: *mut EnvironmentBlendMode,
+    ) -> Result {
+        match next.enumerate_environment_blend_modes {
+            Some(f) => f(
+                instance,
+                system_id,
+                view_configuration_type,
+                environment_blend_mode_capacity_input,
+                environment_blend_mode_count_output,
+                environment_blend_modes,
+            ),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrGetViewConfigurationProperties](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrGetViewConfigurationProperties)"]
+    unsafe fn get_view_configuration_properties(
+        &self,
+        next: &Dispatch,
+        instance: Instance,
+        system_id: SystemId,
+        view_configuration_type: ViewConfigurationType,
+        configuration_properties: *mut ViewConfigurationProperties,
+    ) -> Result {
+        match next.get_view_configuration_properties {
+            Some(f) => f(
+                instance,
+                system_id,
+                view_configuration_type,
+                configuration_properties,
+            ),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrEnumerateViewConfigurationViews](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrEnumerateViewConfigurationViews)"]
+    unsafe fn enumerate_view_configuration_views(
+        &self,
+        next: &Dispatch,
+        instance: Instance,
+        system_id: SystemId,
+        view_configuration_type: ViewConfigurationType,
+        view_capacity_input: u32,
+        view_count_output: *mut u32,
+        views: *mut ViewConfigurationView,
+    ) -> Result {
+        match next.enumerate_view_configuration_views {
+            Some(f) => f(
+                instance,
+                system_id,
+                view_configuration_type,
+                view_capacity_input,
+                view_count_output,
+                views,
+            ),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrBeginFrame](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrBeginFrame)"]
+    unsafe fn begin_frame(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        frame_begin_info: *const FrameBeginInfo,
+    ) -> Result {
+        match next.begin_frame {
+            Some(f) => f(session, frame_begin_info),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrLocateViews](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrLocateViews)"]
+    unsafe fn locate_views(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        view_locate_info: *const ViewLocateInfo,
+        view_state: *mut ViewState,
+        view_capacity_input: u32,
+        view_count_output: *mut u32,
+        views: *mut View,
+    ) -> Result {
+        match next.locate_views {
+            Some(f) => f(
+                session,
+                view_locate_info,
+                view_state,
+                view_capacity_input,
+                view_count_output,
+                views,
+            ),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrEndFrame](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrEndFrame)"]
+    unsafe fn end_frame(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        frame_end_info: *const FrameEndInfo,
+    ) -> Result {
+        match next.end_frame {
+            Some(f) => f(session, frame_end_info),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrWaitFrame](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrWaitFrame)"]
+    unsafe fn wait_frame(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        frame_wait_info: *const FrameWaitInfo,
+        frame_state: *mut FrameState,
+    ) -> Result {
+        match next.wait_frame {
+            Some(f) => f(session, frame_wait_info, frame_state),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrApplyHapticFeedback](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrApplyHapticFeedback)"]
+    unsafe fn apply_haptic_feedback(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        haptic_action_info: *const HapticActionInfo,
+        haptic_feedback: *const HapticBaseHeader,
+    ) -> Result {
+        match next.apply_haptic_feedback {
+            Some(f) => f(session, haptic_action_info, haptic_feedback),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrStopHapticFeedback](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrStopHapticFeedback)"]
+    unsafe fn stop_haptic_feedback(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        haptic_action_info: *const HapticActionInfo,
+    ) -> Result {
+        match next.stop_haptic_feedback {
+            Some(f) => f(session, haptic_action_info),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrPollEvent](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrPollEvent)"]
+    unsafe fn poll_event(
+        &self,
+        next: &Dispatch,
+        instance: Instance,
+        event_data: *mut EventDataBuffer,
+    ) -> Result {
+        match next.poll_event {
+            Some(f) => f(instance, event_data),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrStringToPath](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrStringToPath)"]
+    unsafe fn string_to_path(
+        &self,
+        next: &Dispatch,
+        instance: Instance,
+        path_string: *const c_char,
+        path: *mut Path,
+    ) -> Result {
+        match next.string_to_path {
+            Some(f) => f(instance, path_string, path),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrPathToString](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrPathToString)"]
+    unsafe fn path_to_string(
+        &self,
+        next: &Dispatch,
+        instance: Instance,
+        path: Path,
+        buffer_capacity_input: u32,
+        buffer_count_output: *mut u32,
+        buffer: *mut c_char,
+    ) -> Result {
+        match next.path_to_string {
+            Some(f) => f(
+                instance,
+                path,
+                buffer_capacity_input,
+                buffer_count_output,
+                buffer,
+            ),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrGetReferenceSpaceBoundsRect](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrGetReferenceSpaceBoundsRect)"]
+    unsafe fn get_reference_space_bounds_rect(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        reference_space_type: ReferenceSpaceType,
+        bounds: *mut Extent2Df,
+    ) -> Result {
+        match next.get_reference_space_bounds_rect {
+            Some(f) => f(session, reference_space_type, bounds),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[cfg(target_os = "android")]
+    #[doc = "See [xrSetAndroidApplicationThreadKHR](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrSetAndroidApplicationThreadKHR) - defined by [XR_KHR_android_thread_settings](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_KHR_android_thread_settings)"]
+    unsafe fn set_android_application_thread_khr(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        thread_type: AndroidThreadTypeKHR,
+        thread_id: u32,
+    ) -> Result {
+        match next.set_android_application_thread_khr {
+            Some(f) => f(session, thread_type, thread_id),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[cfg(target_os = "android")]
+    #[doc = "See [xrCreateSwapchainAndroidSurfaceKHR](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrCreateSwapchainAndroidSurfaceKHR) - defined by [XR_KHR_android_surface_swapchain](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_KHR_android_surface_swapchain)"]
+    unsafe fn create_swapchain_android_surface_khr(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        info: *const SwapchainCreateInfo,
+        swapchain: *mut Swapchain,
+        surface: *mut jobject,
+    ) -> Result {
+        match next.create_swapchain_android_surface_khr {
+            Some(f) => f(session, info, swapchain, surface),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrGetActionStateBoolean](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrGetActionStateBoolean)"]
+    unsafe fn get_action_state_boolean(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        get_info: *const ActionStateGetInfo,
+        state: *mut ActionStateBoolean,
+    ) -> Result {
+        match next.get_action_state_boolean {
+            Some(f) => f(session, get_info, state),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrGetActionStateFloat](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrGetActionStateFloat)"]
+    unsafe fn get_action_state_float(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        get_info: *const ActionStateGetInfo,
+        state: *mut ActionStateFloat,
+    ) -> Result {
+        match next.get_action_state_float {
+            Some(f) => f(session, get_info, state),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrGetActionStateVector2f](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrGetActionStateVector2f)"]
+    unsafe fn get_action_state_vector2f(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        get_info: *const ActionStateGetInfo,
+        state: *mut ActionStateVector2f,
+    ) -> Result {
+        match next.get_action_state_vector2f {
+            Some(f) => f(session, get_info, state),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrGetActionStatePose](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrGetActionStatePose)"]
+    unsafe fn get_action_state_pose(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        get_info: *const ActionStateGetInfo,
+        state: *mut ActionStatePose,
+    ) -> Result {
+        match next.get_action_state_pose {
+            Some(f) => f(session, get_info, state),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrCreateActionSet](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrCreateActionSet)"]
+    unsafe fn create_action_set(
+        &self,
+        next: &Dispatch,
+        instance: Instance,
+        create_info: *const ActionSetCreateInfo,
+        action_set: *mut ActionSet,
+    ) -> Result {
+        match next.create_action_set {
+            Some(f) => f(instance, create_info, action_set),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrDestroyActionSet](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrDestroyActionSet)"]
+    unsafe fn destroy_action_set(&self, next: &Dispatch, action_set: ActionSet) -> Result {
+        match next.destroy_action_set {
+            Some(f) => f(action_set),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrCreateAction](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrCreateAction)"]
+    unsafe fn create_action(
+        &self,
+        next: &Dispatch,
+        action_set: ActionSet,
+        create_info: *const ActionCreateInfo,
+        action: *mut Action,
+    ) -> Result {
+        match next.create_action {
+            Some(f) => f(action_set, create_info, action),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrDestroyAction](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrDestroyAction)"]
+    unsafe fn destroy_action(&self, next: &Dispatch, action: Action) -> Result {
+        match next.destroy_action {
+            Some(f) => f(action),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrSuggestInteractionProfileBindings](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrSuggestInteractionProfileBindings)"]
+    unsafe fn suggest_interaction_profile_bindings(
+        &self,
+        next: &Dispatch,
+        instance: Instance,
+        suggested_bindings: *const InteractionProfileSuggestedBinding,
+    ) -> Result {
+        match next.suggest_interaction_profile_bindings {
+            Some(f) => f(instance, suggested_bindings),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrAttachSessionActionSets](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrAttachSessionActionSets)"]
+    unsafe fn attach_session_action_sets(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        attach_info: *const SessionActionSetsAttachInfo,
+    ) -> Result {
+        match next.attach_session_action_sets {
+            Some(f) => f(session, attach_info),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrGetCurrentInteractionProfile](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrGetCurrentInteractionProfile)"]
+    unsafe fn get_current_interaction_profile(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        top_level_user_path: Path,
+        interaction_profile: *mut InteractionProfileState,
+    ) -> Result {
+        match next.get_current_interaction_profile {
+            Some(f) => f(session, top_level_user_path, interaction_profile),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrSyncActions](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrSyncActions)"]
+    unsafe fn sync_actions(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        sync_info: *const ActionsSyncInfo,
+    ) -> Result {
+        match next.sync_actions {
+            Some(f) => f(session, sync_info),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrEnumerateBoundSourcesForAction](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrEnumerateBoundSourcesForAction)"]
+    unsafe fn enumerate_bound_sources_for_action(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        enumerate_info: *const BoundSourcesForActionEnumerateInfo,
+        source_capacity_input: u32,
+        source_count_output: *mut u32,
+        sources: *mut Path,
+    ) -> Result {
+        match next.enumerate_bound_sources_for_action {
+            Some(f) => f(
+                session,
+                enumerate_info,
+                source_capacity_input,
+                source_count_output,
+                sources,
+            ),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrGetInputSourceLocalizedName](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrGetInputSourceLocalizedName)"]
+    unsafe fn get_input_source_localized_name(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        get_info: *const InputSourceLocalizedNameGetInfo,
+        buffer_capacity_input: u32,
+        buffer_count_output: *mut u32,
+        buffer: *mut c_char,
+    ) -> Result {
+        match next.get_input_source_localized_name {
+            Some(f) => f(
+                session,
+                get_info,
+                buffer_capacity_input,
+                buffer_count_output,
+                buffer,
+            ),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrLocateSpaces](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrLocateSpaces)"]
+    unsafe fn locate_spaces(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        locate_info: *const SpacesLocateInfo,
+        space_locations: *mut SpaceLocations,
+    ) -> Result {
+        match next.locate_spaces {
+            Some(f) => f(session, locate_info, space_locations),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrGetVulkanInstanceExtensionsKHR](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrGetVulkanInstanceExtensionsKHR) - defined by [XR_KHR_vulkan_enable](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_KHR_vulkan_enable)"]
+    unsafe fn get_vulkan_instance_extensions_khr(
+        &self,
+        next: &Dispatch,
+        instance: Instance,
+        system_id: SystemId,
+        buffer_capacity_input: u32,
+        buffer_count_output: *mut u32,
+        buffer: *mut c_char,
+    ) -> Result {
+        match next.get_vulkan_instance_extensions_khr {
+            Some(f) => f(
+                instance,
+                system_id,
+                buffer_capacity_input,
+                buffer_count_output,
+                buffer,
+            ),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrGetVulkanDeviceExtensionsKHR](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrGetVulkanDeviceExtensionsKHR) - defined by [XR_KHR_vulkan_enable](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_KHR_vulkan_enable)"]
+    unsafe fn get_vulkan_device_extensions_khr(
+        &self,
+        next: &Dispatch,
+        instance: Instance,
+        system_id: SystemId,
+        buffer_capacity_input: u32,
+        buffer_count_output: *mut u32,
+        buffer: *mut c_char,
+    ) -> Result {
+        match next.get_vulkan_device_extensions_khr {
+            Some(f) => f(
+                instance,
+                system_id,
+                buffer_capacity_input,
+                buffer_count_output,
+                buffer,
+            ),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrGetVulkanGraphicsDeviceKHR](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrGetVulkanGraphicsDeviceKHR) - defined by [XR_KHR_vulkan_enable](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_KHR_vulkan_enable)"]
+    unsafe fn get_vulkan_graphics_device_khr(
+        &self,
+        next: &Dispatch,
+        instance: Instance,
+        system_id: SystemId,
+        vk_instance: VkInstance,
+        vk_physical_device: *mut VkPhysicalDevice,
+    ) -> Result {
+        match next.get_vulkan_graphics_device_khr {
+            Some(f) => f(instance, system_id, vk_instance, vk_physical_device),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrGetOpenGLGraphicsRequirementsKHR](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrGetOpenGLGraphicsRequirementsKHR) - defined by [XR_KHR_opengl_enable](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_KHR_opengl_enable)"]
+    unsafe fn get_open_gl_graphics_requirements_khr(
+        &self,
+        next: &Dispatch,
+        instance: Instance,
+        system_id: SystemId,
+        graphics_requirements: *mut GraphicsRequirementsOpenGLKHR,
+    ) -> Result {
+        match next.get_open_gl_graphics_requirements_khr {
+            Some(f) => f(instance, system_id, graphics_requirements),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrGetOpenGLESGraphicsRequirementsKHR](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrGetOpenGLESGraphicsRequirementsKHR) - defined by [XR_KHR_opengl_es_enable](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_KHR_opengl_es_enable)"]
+    unsafe fn get_open_gles_graphics_requirements_khr(
+        &self,
+        next: &Dispatch,
+        instance: Instance,
+        system_id: SystemId,
+        graphics_requirements: *mut GraphicsRequirementsOpenGLESKHR,
+    ) -> Result {
+        match next.get_open_gles_graphics_requirements_khr {
+            Some(f) => f(instance, system_id, graphics_requirements),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrGetVulkanGraphicsRequirementsKHR](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrGetVulkanGraphicsRequirementsKHR) - defined by [XR_KHR_vulkan_enable](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_KHR_vulkan_enable)"]
+    unsafe fn get_vulkan_graphics_requirements_khr(
+        &self,
+        next: &Dispatch,
+        instance: Instance,
+        system_id: SystemId,
+        graphics_requirements: *mut GraphicsRequirementsVulkanKHR,
+    ) -> Result {
+        match next.get_vulkan_graphics_requirements_khr {
+            Some(f) => f(instance, system_id, graphics_requirements),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[cfg(windows)]
+    #[doc = "See [xrGetD3D11GraphicsRequirementsKHR](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrGetD3D11GraphicsRequirementsKHR) - defined by [XR_KHR_D3D11_enable](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_KHR_D3D11_enable)"]
+    unsafe fn get_d3d11_graphics_requirements_khr(
+        &self,
+        next: &Dispatch,
+        instance: Instance,
+        system_id: SystemId,
+        graphics_requirements: *mut GraphicsRequirementsD3D11KHR,
+    ) -> Result {
+        match next.get_d3d11_graphics_requirements_khr {
+            Some(f) => f(instance, system_id, graphics_requirements),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[cfg(windows)]
+    #[doc = "See [xrGetD3D12GraphicsRequirementsKHR](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrGetD3D12GraphicsRequirementsKHR) - defined by [XR_KHR_D3D12_enable](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_KHR_D3D12_enable)"]
+    unsafe fn get_d3d12_graphics_requirements_khr(
+        &self,
+        next: &Dispatch,
+        instance: Instance,
+        system_id: SystemId,
+        graphics_requirements: *mut GraphicsRequirementsD3D12KHR,
+    ) -> Result {
+        match next.get_d3d12_graphics_requirements_khr {
+            Some(f) => f(instance, system_id, graphics_requirements),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrPerfSettingsSetPerformanceLevelEXT](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrPerfSettingsSetPerformanceLevelEXT) - defined by [XR_EXT_performance_settings](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_EXT_performance_settings)"]
+    unsafe fn perf_settings_set_performance_level_ext(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        domain: PerfSettingsDomainEXT,
+        level: PerfSettingsLevelEXT,
+    ) -> Result {
+        match next.perf_settings_set_performance_level_ext {
+            Some(f) => f(session, domain, level),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrThermalGetTemperatureTrendEXT](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrThermalGetTemperatureTrendEXT) - defined by [XR_EXT_thermal_query](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_EXT_thermal_query)"]
+    unsafe fn thermal_get_temperature_trend_ext(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        domain: PerfSettingsDomainEXT,
+        notification_level: *mut PerfSettingsNotificationLevelEXT,
+        temp_headroom: *mut f32,
+        temp_slope: *mut f32,
+    ) -> Result {
+        match next.thermal_get_temperature_trend_ext {
+            Some(f) => f(
+                session,
+                domain,
+                notification_level,
+                temp_headroom,
+                temp_slope,
+            ),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrSetDebugUtilsObjectNameEXT](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrSetDebugUtilsObjectNameEXT) - defined by [XR_EXT_debug_utils](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_EXT_debug_utils)"]
+    unsafe fn set_debug_utils_object_name_ext(
+        &self,
+        next: &Dispatch,
+        instance: Instance,
+        name_info: *const DebugUtilsObjectNameInfoEXT,
+    ) -> Result {
+        match next.set_debug_utils_object_name_ext {
+            Some(f) => f(instance, name_info),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrCreateDebugUtilsMessengerEXT](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrCreateDebugUtilsMessengerEXT) - defined by [XR_EXT_debug_utils](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_EXT_debug_utils)"]
+    unsafe fn create_debug_utils_messenger_ext(
+        &self,
+        next: &Dispatch,
+        instance: Instance,
+        create_info: *const DebugUtilsMessengerCreateInfoEXT,
+        messenger: *mut DebugUtilsMessengerEXT,
+    ) -> Result {
+        match next.create_debug_utils_messenger_ext {
+            Some(f) => f(instance, create_info, messenger),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrDestroyDebugUtilsMessengerEXT](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrDestroyDebugUtilsMessengerEXT) - defined by [XR_EXT_debug_utils](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_EXT_debug_utils)"]
+    unsafe fn destroy_debug_utils_messenger_ext(
+        &self,
+        next: &Dispatch,
+        messenger: DebugUtilsMessengerEXT,
+    ) -> Result {
+        match next.destroy_debug_utils_messenger_ext {
+            Some(f) => f(messenger),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrSubmitDebugUtilsMessageEXT](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrSubmitDebugUtilsMessageEXT) - defined by [XR_EXT_debug_utils](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_EXT_debug_utils)"]
+    unsafe fn submit_debug_utils_message_ext(
+        &self,
+        next: &Dispatch,
+        instance: Instance,
+        message_severity: DebugUtilsMessageSeverityFlagsEXT,
+        message_types: DebugUtilsMessageTypeFlagsEXT,
+        callback_data: *const DebugUtilsMessengerCallbackDataEXT,
+    ) -> Result {
+        match next.submit_debug_utils_message_ext {
+            Some(f) => f(instance, message_severity, message_types, callback_data),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrSessionBeginDebugUtilsLabelRegionEXT](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrSessionBeginDebugUtilsLabelRegionEXT) - defined by [XR_EXT_debug_utils](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_EXT_debug_utils)"]
+    unsafe fn session_begin_debug_utils_label_region_ext(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        label_info: *const DebugUtilsLabelEXT,
+    ) -> Result {
+        match next.session_begin_debug_utils_label_region_ext {
+            Some(f) => f(session, label_info),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrSessionEndDebugUtilsLabelRegionEXT](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrSessionEndDebugUtilsLabelRegionEXT) - defined by [XR_EXT_debug_utils](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_EXT_debug_utils)"]
+    unsafe fn session_end_debug_utils_label_region_ext(
+        &self,
+        next: &Dispatch,
+        session: Session,
+    ) -> Result {
+        match next.session_end_debug_utils_label_region_ext {
+            Some(f) => f(session),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrSessionInsertDebugUtilsLabelEXT](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrSessionInsertDebugUtilsLabelEXT) - defined by [XR_EXT_debug_utils](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_EXT_debug_utils)"]
+    unsafe fn session_insert_debug_utils_label_ext(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        label_info: *const DebugUtilsLabelEXT,
+    ) -> Result {
+        match next.session_insert_debug_utils_label_ext {
+            Some(f) => f(session, label_info),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[cfg(windows)]
+    #[doc = "See [xrConvertTimeToWin32PerformanceCounterKHR](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrConvertTimeToWin32PerformanceCounterKHR) - defined by [XR_KHR_win32_convert_performance_counter_time](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_KHR_win32_convert_performance_counter_time)"]
+    unsafe fn convert_time_to_win32_performance_counter_khr(
+        &self,
+        next: &Dispatch,
+        instance: Instance,
+        time: Time,
+        performance_counter: *mut LARGE_INTEGER,
+    ) -> Result {
+        match next.convert_time_to_win32_performance_counter_khr {
+            Some(f) => f(instance, time, performance_counter),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[cfg(windows)]
+    #[doc = "See [xrConvertWin32PerformanceCounterToTimeKHR](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrConvertWin32PerformanceCounterToTimeKHR) - defined by [XR_KHR_win32_convert_performance_counter_time](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_KHR_win32_convert_performance_counter_time)"]
+    unsafe fn convert_win32_performance_counter_to_time_khr(
+        &self,
+        next: &Dispatch,
+        instance: Instance,
+        performance_counter: *const LARGE_INTEGER,
+        time: *mut Time,
+    ) -> Result {
+        match next.convert_win32_performance_counter_to_time_khr {
+            Some(f) => f(instance, performance_counter, time),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrCreateVulkanInstanceKHR](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrCreateVulkanInstanceKHR) - defined by [XR_KHR_vulkan_enable2](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_KHR_vulkan_enable2)"]
+    unsafe fn create_vulkan_instance_khr(
+        &self,
+        next: &Dispatch,
+        instance: Instance,
+        create_info: *const VulkanInstanceCreateInfoKHR,
+        vulkan_instance: *mut VkInstance,
+        vulkan_result: *mut VkResult,
+    ) -> Result {
+        match next.create_vulkan_instance_khr {
+            Some(f) => f(instance, create_info, vulkan_instance, vulkan_result),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrCreateVulkanDeviceKHR](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrCreateVulkanDeviceKHR) - defined by [XR_KHR_vulkan_enable2](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_KHR_vulkan_enable2)"]
+    unsafe fn create_vulkan_device_khr(
+        &self,
+        next: &Dispatch,
+        instance: Instance,
+        create_info: *const VulkanDeviceCreateInfoKHR,
+        vulkan_device: *mut VkDevice,
+        vulkan_result: *mut VkResult,
+    ) -> Result {
+        match next.create_vulkan_device_khr {
+            Some(f) => f(instance, create_info, vulkan_device, vulkan_result),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrGetVulkanGraphicsDevice2KHR](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrGetVulkanGraphicsDevice2KHR) - defined by [XR_KHR_vulkan_enable2](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_KHR_vulkan_enable2)"]
+    unsafe fn get_vulkan_graphics_device2_khr(
+        &self,
+        next: &Dispatch,
+        instance: Instance,
+        get_info: *const VulkanGraphicsDeviceGetInfoKHR,
+        vulkan_physical_device: *mut VkPhysicalDevice,
+    ) -> Result {
+        match next.get_vulkan_graphics_device2_khr {
+            Some(f) => f(instance, get_info, vulkan_physical_device),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrConvertTimeToTimespecTimeKHR](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrConvertTimeToTimespecTimeKHR) - defined by [XR_KHR_convert_timespec_time](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_KHR_convert_timespec_time)"]
+    unsafe fn convert_time_to_timespec_time_khr(
+        &self,
+        next: &Dispatch,
+        instance: Instance,
+        time: Time,
+        timespec_time: *mut timespec,
+    ) -> Result {
+        match next.convert_time_to_timespec_time_khr {
+            Some(f) => f(instance, time, timespec_time),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrConvertTimespecTimeToTimeKHR](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrConvertTimespecTimeToTimeKHR) - defined by [XR_KHR_convert_timespec_time](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_KHR_convert_timespec_time)"]
+    unsafe fn convert_timespec_time_to_time_khr(
+        &self,
+        next: &Dispatch,
+        instance: Instance,
+        timespec_time: *const timespec,
+        time: *mut Time,
+    ) -> Result {
+        match next.convert_timespec_time_to_time_khr {
+            Some(f) => f(instance, timespec_time, time),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrGetVisibilityMaskKHR](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrGetVisibilityMaskKHR) - defined by [XR_KHR_visibility_mask](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_KHR_visibility_mask)"]
+    unsafe fn get_visibility_mask_khr(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        view_configuration_type: ViewConfigurationType,
+        view_index: u32,
+        visibility_mask_type: VisibilityMaskTypeKHR,
+        visibility_mask: *mut VisibilityMaskKHR,
+    ) -> Result {
+        match next.get_visibility_mask_khr {
+            Some(f) => f(
+                session,
+                view_configuration_type,
+                view_index,
+                visibility_mask_type,
+                visibility_mask,
+            ),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrCreateSpatialAnchorMSFT](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrCreateSpatialAnchorMSFT) - defined by [XR_MSFT_spatial_anchor](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_MSFT_spatial_anchor)"]
+    unsafe fn create_spatial_anchor_msft(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        create_info: *const SpatialAnchorCreateInfoMSFT,
+        anchor: *mut SpatialAnchorMSFT,
+    ) -> Result {
+        match next.create_spatial_anchor_msft {
+            Some(f) => f(session, create_info, anchor),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrCreateSpatialAnchorSpaceMSFT](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrCreateSpatialAnchorSpaceMSFT) - defined by [XR_MSFT_spatial_anchor](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_MSFT_spatial_anchor)"]
+    unsafe fn create_spatial_anchor_space_msft(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        create_info: *const SpatialAnchorSpaceCreateInfoMSFT,
+        space: *mut Space,
+    ) -> Result {
+        match next.create_spatial_anchor_space_msft {
+            Some(f) => f(session, create_info, space),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrDestroySpatialAnchorMSFT](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrDestroySpatialAnchorMSFT) - defined by [XR_MSFT_spatial_anchor](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_MSFT_spatial_anchor)"]
+    unsafe fn destroy_spatial_anchor_msft(
+        &self,
+        next: &Dispatch,
+        anchor: SpatialAnchorMSFT,
+    ) -> Result {
+        match next.destroy_spatial_anchor_msft {
+            Some(f) => f(anchor),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrSetInputDeviceActiveEXT](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrSetInputDeviceActiveEXT) - defined by [XR_EXT_conformance_automation](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_EXT_conformance_automation)"]
+    unsafe fn set_input_device_active_ext(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        interaction_profile: Path,
+        top_level_path: Path,
+        is_active: Bool32,
+    ) -> Result {
+        match next.set_input_device_active_ext {
+            Some(f) => f(session, interaction_profile, top_level_path, is_active),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrSetInputDeviceStateBoolEXT](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrSetInputDeviceStateBoolEXT) - defined by [XR_EXT_conformance_automation](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_EXT_conformance_automation)"]
+    unsafe fn set_input_device_state_bool_ext(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        top_level_path: Path,
+        input_source_path: Path,
+        state: Bool32,
+    ) -> Result {
+        match next.set_input_device_state_bool_ext {
+            Some(f) => f(session, top_level_path, input_source_path, state),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrSetInputDeviceStateFloatEXT](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrSetInputDeviceStateFloatEXT) - defined by [XR_EXT_conformance_automation](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_EXT_conformance_automation)"]
+    unsafe fn set_input_device_state_float_ext(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        top_level_path: Path,
+        input_source_path: Path,
+        state: f32,
+    ) -> Result {
+        match next.set_input_device_state_float_ext {
+            Some(f) => f(session, top_level_path, input_source_path, state),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrSetInputDeviceStateVector2fEXT](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrSetInputDeviceStateVector2fEXT) - defined by [XR_EXT_conformance_automation](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_EXT_conformance_automation)"]
+    unsafe fn set_input_device_state_vector2f_ext(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        top_level_path: Path,
+        input_source_path: Path,
+        state: Vector2f,
+    ) -> Result {
+        match next.set_input_device_state_vector2f_ext {
+            Some(f) => f(session, top_level_path, input_source_path, state),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrSetInputDeviceLocationEXT](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrSetInputDeviceLocationEXT) - defined by [XR_EXT_conformance_automation](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_EXT_conformance_automation)"]
+    unsafe fn set_input_device_location_ext(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        top_level_path: Path,
+        input_source_path: Path,
+        space: Space,
+        pose: Posef,
+    ) -> Result {
+        match next.set_input_device_location_ext {
+            Some(f) => f(session, top_level_path, input_source_path, space, pose),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrCreateSpatialGraphNodeSpaceMSFT](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrCreateSpatialGraphNodeSpaceMSFT) - defined by [XR_MSFT_spatial_graph_bridge](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_MSFT_spatial_graph_bridge)"]
+    unsafe fn create_spatial_graph_node_space_msft(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        create_info: *const SpatialGraphNodeSpaceCreateInfoMSFT,
+        space: *mut Space,
+    ) -> Result {
+        match next.create_spatial_graph_node_space_msft {
+            Some(f) => f(session, create_info, space),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrTryCreateSpatialGraphStaticNodeBindingMSFT](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrTryCreateSpatialGraphStaticNodeBindingMSFT) - defined by [XR_MSFT_spatial_graph_bridge](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_MSFT_spatial_graph_bridge)"]
+    unsafe fn try_create_spatial_graph_static_node_binding_msft(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        create_info: *const SpatialGraphStaticNodeBindingCreateInfoMSFT,
+        node_binding: *mut SpatialGraphNodeBindingMSFT,
+    ) -> Result {
+        match next.try_create_spatial_graph_static_node_binding_msft {
+            Some(f) => f(session, create_info, node_binding),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrDestroySpatialGraphNodeBindingMSFT](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrDestroySpatialGraphNodeBindingMSFT) - defined by [XR_MSFT_spatial_graph_bridge](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_MSFT_spatial_graph_bridge)"]
+    unsafe fn destroy_spatial_graph_node_binding_msft(
+        &self,
+        next: &Dispatch,
+        node_binding: SpatialGraphNodeBindingMSFT,
+    ) -> Result {
+        match next.destroy_spatial_graph_node_binding_msft {
+            Some(f) => f(node_binding),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrGetSpatialGraphNodeBindingPropertiesMSFT](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrGetSpatialGraphNodeBindingPropertiesMSFT) - defined by [XR_MSFT_spatial_graph_bridge](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_MSFT_spatial_graph_bridge)"]
+    unsafe fn get_spatial_graph_node_binding_properties_msft(
+        &self,
+        next: &Dispatch,
+        node_binding: SpatialGraphNodeBindingMSFT,
+        get_info: *const SpatialGraphNodeBindingPropertiesGetInfoMSFT,
+        properties: *mut SpatialGraphNodeBindingPropertiesMSFT,
+    ) -> Result {
+        match next.get_spatial_graph_node_binding_properties_msft {
+            Some(f) => f(node_binding, get_info, properties),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrCreateHandTrackerEXT](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrCreateHandTrackerEXT) - defined by [XR_EXT_hand_tracking](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_EXT_hand_tracking)"]
+    unsafe fn create_hand_tracker_ext(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        create_info: *const HandTrackerCreateInfoEXT,
+        hand_tracker: *mut HandTrackerEXT,
+    ) -> Result {
+        match next.create_hand_tracker_ext {
+            Some(f) => f(session, create_info, hand_tracker),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrDestroyHandTrackerEXT](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrDestroyHandTrackerEXT) - defined by [XR_EXT_hand_tracking](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_EXT_hand_tracking)"]
+    unsafe fn destroy_hand_tracker_ext(
+        &self,
+        next: &Dispatch,
+        hand_tracker: HandTrackerEXT,
+    ) -> Result {
+        match next.destroy_hand_tracker_ext {
+            Some(f) => f(hand_tracker),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrLocateHandJointsEXT](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrLocateHandJointsEXT) - defined by [XR_EXT_hand_tracking](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_EXT_hand_tracking)"]
+    unsafe fn locate_hand_joints_ext(
+        &self,
+        next: &Dispatch,
+        hand_tracker: HandTrackerEXT,
+        locate_info: *const HandJointsLocateInfoEXT,
+        locations: *mut HandJointLocationsEXT,
+    ) -> Result {
+        match next.locate_hand_joints_ext {
+            Some(f) => f(hand_tracker, locate_info, locations),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrCreateFaceTrackerFB](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrCreateFaceTrackerFB) - defined by [XR_FB_face_tracking](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_FB_face_tracking)"]
+    unsafe fn create_face_tracker_fb(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        create_info: *const FaceTrackerCreateInfoFB,
+        face_tracker: *mut FaceTrackerFB,
+    ) -> Result {
+        match next.create_face_tracker_fb {
+            Some(f) => f(session, create_info, face_tracker),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrDestroyFaceTrackerFB](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrDestroyFaceTrackerFB) - defined by [XR_FB_face_tracking](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_FB_face_tracking)"]
+    unsafe fn destroy_face_tracker_fb(
+        &self,
+        next: &Dispatch,
+        face_tracker: FaceTrackerFB,
+    ) -> Result {
+        match next.destroy_face_tracker_fb {
+            Some(f) => f(face_tracker),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrGetFaceExpressionWeightsFB](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrGetFaceExpressionWeightsFB) - defined by [XR_FB_face_tracking](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_FB_face_tracking)"]
+    unsafe fn get_face_expression_weights_fb(
+        &self,
+        next: &Dispatch,
+        face_tracker: FaceTrackerFB,
+        expression_info: *const FaceExpressionInfoFB,
+        expression_weights: *mut FaceExpressionWeightsFB,
+    ) -> Result {
+        match next.get_face_expression_weights_fb {
+            Some(f) => f(face_tracker, expression_info, expression_weights),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrCreateBodyTrackerFB](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrCreateBodyTrackerFB) - defined by [XR_FB_body_tracking](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_FB_body_tracking)"]
+    unsafe fn create_body_tracker_fb(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        create_info: *const BodyTrackerCreateInfoFB,
+        body_tracker: *mut BodyTrackerFB,
+    ) -> Result {
+        match next.create_body_tracker_fb {
+            Some(f) => f(session, create_info, body_tracker),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrDestroyBodyTrackerFB](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrDestroyBodyTrackerFB) - defined by [XR_FB_body_tracking](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_FB_body_tracking)"]
+    unsafe fn destroy_body_tracker_fb(
+        &self,
+        next: &Dispatch,
+        body_tracker: BodyTrackerFB,
+    ) -> Result {
+        match next.destroy_body_tracker_fb {
+            Some(f) => f(body_tracker),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrLocateBodyJointsFB](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrLocateBodyJointsFB) - defined by [XR_FB_body_tracking](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_FB_body_tracking)"]
+    unsafe fn locate_body_joints_fb(
+        &self,
+        next: &Dispatch,
+        body_tracker: BodyTrackerFB,
+        locate_info: *const BodyJointsLocateInfoFB,
+        locations: *mut BodyJointLocationsFB,
+    ) -> Result {
+        match next.locate_body_joints_fb {
+            Some(f) => f(body_tracker, locate_info, locations),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrGetBodySkeletonFB](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrGetBodySkeletonFB) - defined by [XR_FB_body_tracking](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_FB_body_tracking)"]
+    unsafe fn get_body_skeleton_fb(
+        &self,
+        next: &Dispatch,
+        body_tracker: BodyTrackerFB,
+        skeleton: *mut BodySkeletonFB,
+    ) -> Result {
+        match next.get_body_skeleton_fb {
+            Some(f) => f(body_tracker, skeleton),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrCreateEyeTrackerFB](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrCreateEyeTrackerFB) - defined by [XR_FB_eye_tracking_social](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_FB_eye_tracking_social)"]
+    unsafe fn create_eye_tracker_fb(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        create_info: *const EyeTrackerCreateInfoFB,
+        eye_tracker: *mut EyeTrackerFB,
+    ) -> Result {
+        match next.create_eye_tracker_fb {
+            Some(f) => f(session, create_info, eye_tracker),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrDestroyEyeTrackerFB](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrDestroyEyeTrackerFB) - defined by [XR_FB_eye_tracking_social](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_FB_eye_tracking_social)"]
+    unsafe fn destroy_eye_tracker_fb(&self, next: &Dispatch, eye_tracker: EyeTrackerFB) -> Result {
+        match next.destroy_eye_tracker_fb {
+            Some(f) => f(eye_tracker),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrGetEyeGazesFB](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrGetEyeGazesFB) - defined by [XR_FB_eye_tracking_social](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_FB_eye_tracking_social)"]
+    unsafe fn get_eye_gazes_fb(
+        &self,
+        next: &Dispatch,
+        eye_tracker: EyeTrackerFB,
+        gaze_info: *const EyeGazesInfoFB,
+        eye_gazes: *mut EyeGazesFB,
+    ) -> Result {
+        match next.get_eye_gazes_fb {
+            Some(f) => f(eye_tracker, gaze_info, eye_gazes),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrCreateHandMeshSpaceMSFT](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrCreateHandMeshSpaceMSFT) - defined by [XR_MSFT_hand_tracking_mesh](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_MSFT_hand_tracking_mesh)"]
+    unsafe fn create_hand_mesh_space_msft(
+        &self,
+        next: &Dispatch,
+        hand_tracker: HandTrackerEXT,
+        create_info: *const HandMeshSpaceCreateInfoMSFT,
+        space: *mut Space,
+    ) -> Result {
+        match next.create_hand_mesh_space_msft {
+            Some(f) => f(hand_tracker, create_info, space),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrUpdateHandMeshMSFT](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrUpdateHandMeshMSFT) - defined by [XR_MSFT_hand_tracking_mesh](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_MSFT_hand_tracking_mesh)"]
+    unsafe fn update_hand_mesh_msft(
+        &self,
+        next: &Dispatch,
+        hand_tracker: HandTrackerEXT,
+        update_info: *const HandMeshUpdateInfoMSFT,
+        hand_mesh: *mut HandMeshMSFT,
+    ) -> Result {
+        match next.update_hand_mesh_msft {
+            Some(f) => f(hand_tracker, update_info, hand_mesh),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrGetControllerModelKeyMSFT](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrGetControllerModelKeyMSFT) - defined by [XR_MSFT_controller_model](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_MSFT_controller_model)"]
+    unsafe fn get_controller_model_key_msft(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        top_level_user_path: Path,
+        controller_model_key_state: *mut ControllerModelKeyStateMSFT,
+    ) -> Result {
+        match next.get_controller_model_key_msft {
+            Some(f) => f(session, top_level_user_path, controller_model_key_state),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrLoadControllerModelMSFT](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrLoadControllerModelMSFT) - defined by [XR_MSFT_controller_model](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_MSFT_controller_model)"]
+    unsafe fn load_controller_model_msft(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        model_key: ControllerModelKeyMSFT,
+        buffer_capacity_input: u32,
+        buffer_count_output: *mut u32,
+        buffer: *mut u8,
+    ) -> Result {
+        match next.load_controller_model_msft {
+            Some(f) => f(
+                session,
+                model_key,
+                buffer_capacity_input,
+                buffer_count_output,
+                buffer,
+            ),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrGetControllerModelPropertiesMSFT](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrGetControllerModelPropertiesMSFT) - defined by [XR_MSFT_controller_model](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_MSFT_controller_model)"]
+    unsafe fn get_controller_model_properties_msft(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        model_key: ControllerModelKeyMSFT,
+        properties: *mut ControllerModelPropertiesMSFT,
+    ) -> Result {
+        match next.get_controller_model_properties_msft {
+            Some(f) => f(session, model_key, properties),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrGetControllerModelStateMSFT](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrGetControllerModelStateMSFT) - defined by [XR_MSFT_controller_model](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_MSFT_controller_model)"]
+    unsafe fn get_controller_model_state_msft(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        model_key: ControllerModelKeyMSFT,
+        state: *mut ControllerModelStateMSFT,
+    ) -> Result {
+        match next.get_controller_model_state_msft {
+            Some(f) => f(session, model_key, state),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrEnumerateDisplayRefreshRatesFB](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrEnumerateDisplayRefreshRatesFB) - defined by [XR_FB_display_refresh_rate](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_FB_display_refresh_rate)"]
+    unsafe fn enumerate_display_refresh_rates_fb(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        display_refresh_rate_capacity_input: u32,
+        display_refresh_rate_count_output: *mut u32,
+        display_refresh_rates: *mut f32,
+    ) -> Result {
+        match next.enumerate_display_refresh_rates_fb {
+            Some(f) => f(
+                session,
+                display_refresh_rate_capacity_input,
+                display_refresh_rate_count_output,
+                display_refresh_rates,
+            ),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrGetDisplayRefreshRateFB](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrGetDisplayRefreshRateFB) - defined by [XR_FB_display_refresh_rate](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_FB_display_refresh_rate)"]
+    unsafe fn get_display_refresh_rate_fb(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        display_refresh_rate: *mut f32,
+    ) -> Result {
+        match next.get_display_refresh_rate_fb {
+            Some(f) => f(session, display_refresh_rate),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrRequestDisplayRefreshRateFB](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrRequestDisplayRefreshRateFB) - defined by [XR_FB_display_refresh_rate](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_FB_display_refresh_rate)"]
+    unsafe fn request_display_refresh_rate_fb(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        display_refresh_rate: f32,
+    ) -> Result {
+        match next.request_display_refresh_rate_fb {
+            Some(f) => f(session, display_refresh_rate),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[cfg(windows)]
+    #[doc = "See [xrCreateSpatialAnchorFromPerceptionAnchorMSFT](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrCreateSpatialAnchorFromPerceptionAnchorMSFT) - defined by [XR_MSFT_perception_anchor_interop](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_MSFT_perception_anchor_interop)"]
+    unsafe fn create_spatial_anchor_from_perception_anchor_msft(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        perception_anchor: *mut IUnknown,
+        anchor: *mut SpatialAnchorMSFT,
+    ) -> Result {
+        match next.create_spatial_anchor_from_perception_anchor_msft {
+            Some(f) => f(session, perception_anchor, anchor),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[cfg(windows)]
+    #[doc = "See [xrTryGetPerceptionAnchorFromSpatialAnchorMSFT](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrTryGetPerceptionAnchorFromSpatialAnchorMSFT) - defined by [XR_MSFT_perception_anchor_interop](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_MSFT_perception_anchor_interop)"]
+    unsafe fn try_get_perception_anchor_from_spatial_anchor_msft(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        anchor: SpatialAnchorMSFT,
+        perception_anchor: *mut *mut IUnknown,
+    ) -> Result {
+        match next.try_get_perception_anchor_from_spatial_anchor_msft {
+            Some(f) => f(session, anchor, perception_anchor),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrUpdateSwapchainFB](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrUpdateSwapchainFB) - defined by [XR_FB_swapchain_update_state](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_FB_swapchain_update_state)"]
+    unsafe fn update_swapchain_fb(
+        &self,
+        next: &Dispatch,
+        swapchain: Swapchain,
+        state: *const SwapchainStateBaseHeaderFB,
+    ) -> Result {
+        match next.update_swapchain_fb {
+            Some(f) => f(swapchain, state),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrGetSwapchainStateFB](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrGetSwapchainStateFB) - defined by [XR_FB_swapchain_update_state](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_FB_swapchain_update_state)"]
+    unsafe fn get_swapchain_state_fb(
+        &self,
+        next: &Dispatch,
+        swapchain: Swapchain,
+        state: *mut SwapchainStateBaseHeaderFB,
+    ) -> Result {
+        match next.get_swapchain_state_fb {
+            Some(f) => f(swapchain, state),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrEnumerateColorSpacesFB](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrEnumerateColorSpacesFB) - defined by [XR_FB_color_space](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_FB_color_space)"]
+    unsafe fn enumerate_color_spaces_fb(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        color_space_capacity_input: u32,
+        color_space_count_output: *mut u32,
+        color_spaces: *mut ColorSpaceFB,
+    ) -> Result {
+        match next.enumerate_color_spaces_fb {
+            Some(f) => f(
+                session,
+                color_space_capacity_input,
+                color_space_count_output,
+                color_spaces,
+            ),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrSetColorSpaceFB](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrSetColorSpaceFB) - defined by [XR_FB_color_space](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_FB_color_space)"]
+    unsafe fn set_color_space_fb(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        colorspace: ColorSpaceFB,
+    ) -> Result {
+        match next.set_color_space_fb {
+            Some(f) => f(session, colorspace),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrCreateFoveationProfileFB](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrCreateFoveationProfileFB) - defined by [XR_FB_foveation](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_FB_foveation)"]
+    unsafe fn create_foveation_profile_fb(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        create_info: *const FoveationProfileCreateInfoFB,
+        profile: *mut FoveationProfileFB,
+    ) -> Result {
+        match next.create_foveation_profile_fb {
+            Some(f) => f(session, create_info, profile),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrDestroyFoveationProfileFB](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrDestroyFoveationProfileFB) - defined by [XR_FB_foveation](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_FB_foveation)"]
+    unsafe fn destroy_foveation_profile_fb(
+        &self,
+        next: &Dispatch,
+        profile: FoveationProfileFB,
+    ) -> Result {
+        match next.destroy_foveation_profile_fb {
+            Some(f) => f(profile),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrGetFoveationEyeTrackedStateMETA](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrGetFoveationEyeTrackedStateMETA) - defined by [XR_META_foveation_eye_tracked](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_META_foveation_eye_tracked)"]
+    unsafe fn get_foveation_eye_tracked_state_meta(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        foveation_state: *mut FoveationEyeTrackedStateMETA,
+    ) -> Result {
+        match next.get_foveation_eye_tracked_state_meta {
+            Some(f) => f(session, foveation_state),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrGetHandMeshFB](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrGetHandMeshFB) - defined by [XR_FB_hand_tracking_mesh](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_FB_hand_tracking_mesh)"]
+    unsafe fn get_hand_mesh_fb(
+        &self,
+        next: &Dispatch,
+        hand_tracker: HandTrackerEXT,
+        mesh: *mut HandTrackingMeshFB,
+    ) -> Result {
+        match next.get_hand_mesh_fb {
+            Some(f) => f(hand_tracker, mesh),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrEnumerateRenderModelPathsFB](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrEnumerateRenderModelPathsFB) - defined by [XR_FB_render_model](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_FB_render_model)"]
+    unsafe fn enumerate_render_model_paths_fb(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        path_capacity_input: u32,
+        path_count_output: *mut u32,
+        paths: *mut RenderModelPathInfoFB,
+    ) -> Result {
+        match next.enumerate_render_model_paths_fb {
+            Some(f) => f(session, path_capacity_input, path_count_output, paths),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrGetRenderModelPropertiesFB](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrGetRenderModelPropertiesFB) - defined by [XR_FB_render_model](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_FB_render_model)"]
+    unsafe fn get_render_model_properties_fb(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        path: Path,
+        properties: *mut RenderModelPropertiesFB,
+    ) -> Result {
+        match next.get_render_model_properties_fb {
+            Some(f) => f(session, path, properties),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrLoadRenderModelFB](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrLoadRenderModelFB) - defined by [XR_FB_render_model](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_FB_render_model)"]
+    unsafe fn load_render_model_fb(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        info: *const RenderModelLoadInfoFB,
+        buffer: *mut RenderModelBufferFB,
+    ) -> Result {
+        match next.load_render_model_fb {
+            Some(f) => f(session, info, buffer),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrQuerySystemTrackedKeyboardFB](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrQuerySystemTrackedKeyboardFB) - defined by [XR_FB_keyboard_tracking](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_FB_keyboard_tracking)"]
+    unsafe fn query_system_tracked_keyboard_fb(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        query_info: *const KeyboardTrackingQueryFB,
+        keyboard: *mut KeyboardTrackingDescriptionFB,
+    ) -> Result {
+        match next.query_system_tracked_keyboard_fb {
+            Some(f) => f(session, query_info, keyboard),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrCreateKeyboardSpaceFB](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrCreateKeyboardSpaceFB) - defined by [XR_FB_keyboard_tracking](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_FB_keyboard_tracking)"]
+    unsafe fn create_keyboard_space_fb(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        create_info: *const KeyboardSpaceCreateInfoFB,
+        keyboard_space: *mut Space,
+    ) -> Result {
+        match next.create_keyboard_space_fb {
+            Some(f) => f(session, create_info, keyboard_space),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrSetEnvironmentDepthEstimationVARJO](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrSetEnvironmentDepthEstimationVARJO) - defined by [XR_VARJO_environment_depth_estimation](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_VARJO_environment_depth_estimation)"]
+    unsafe fn set_environment_depth_estimation_varjo(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        enabled: Bool32,
+    ) -> Result {
+        match next.set_environment_depth_estimation_varjo {
+            Some(f) => f(session, enabled),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrEnumerateReprojectionModesMSFT](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrEnumerateReprojectionModesMSFT) - defined by [XR_MSFT_composition_layer_reprojection](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_MSFT_composition_layer_reprojection)"]
+    unsafe fn enumerate_reprojection_modes_msft(
+        &self,
+        next: &Dispatch,
+        instance: Instance,
+        system_id: SystemId,
+        view_configuration_type: ViewConfigurationType,
+        mode_capacity_input: u32,
+        mode_count_output: *mut u32,
+        modes: *mut ReprojectionModeMSFT,
+    ) -> Result {
+        match next.enumerate_reprojection_modes_msft {
+            Some(f) => f(
+                instance,
+                system_id,
+                view_configuration_type,
+                mode_capacity_input,
+                mode_count_output,
+                modes,
+            ),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrGetAudioOutputDeviceGuidOculus](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrGetAudioOutputDeviceGuidOculus) - defined by [XR_OCULUS_audio_device_guid](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_OCULUS_audio_device_guid)"]
+    unsafe fn get_audio_output_device_guid_oculus(
+        &self,
+        next: &Dispatch,
+        instance: Instance,
+        buffer: *mut wchar_t,
+    ) -> Result {
+        match next.get_audio_output_device_guid_oculus {
+            Some(f) => f(instance, buffer),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrGetAudioInputDeviceGuidOculus](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrGetAudioInputDeviceGuidOculus) - defined by [XR_OCULUS_audio_device_guid](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_OCULUS_audio_device_guid)"]
+    unsafe fn get_audio_input_device_guid_oculus(
+        &self,
+        next: &Dispatch,
+        instance: Instance,
+        buffer: *mut wchar_t,
+    ) -> Result {
+        match next.get_audio_input_device_guid_oculus {
+            Some(f) => f(instance, buffer),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrCreateSpatialAnchorFB](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrCreateSpatialAnchorFB) - defined by [XR_FB_spatial_entity](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_FB_spatial_entity)"]
+    unsafe fn create_spatial_anchor_fb(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        info: *const SpatialAnchorCreateInfoFB,
+        request_id: *mut AsyncRequestIdFB,
+    ) -> Result {
+        match next.create_spatial_anchor_fb {
+            Some(f) => f(session, info, request_id),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrGetSpaceUuidFB](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrGetSpaceUuidFB) - defined by [XR_FB_spatial_entity](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_FB_spatial_entity)"]
+    unsafe fn get_space_uuid_fb(
+        &self,
+        next: &Dispatch,
+        space: Space,
+        uuid: *mut UuidEXT,
+    ) -> Result {
+        match next.get_space_uuid_fb {
+            Some(f) => f(space, uuid),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrEnumerateSpaceSupportedComponentsFB](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrEnumerateSpaceSupportedComponentsFB) - defined by [XR_FB_spatial_entity](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_FB_spatial_entity)"]
+    unsafe fn enumerate_space_supported_components_fb(
+        &self,
+        next: &Dispatch,
+        space: Space,
+        component_type_capacity_input: u32,
+        component_type_count_output: *mut u32,
+        component_types: *mut SpaceComponentTypeFB,
+    ) -> Result {
+        match next.enumerate_space_supported_components_fb {
+            Some(f) => f(
+                space,
+                component_type_capacity_input,
+                component_type_count_output,
+                component_types,
+            ),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrSetSpaceComponentStatusFB](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrSetSpaceComponentStatusFB) - defined by [XR_FB_spatial_entity](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_FB_spatial_entity)"]
+    unsafe fn set_space_component_status_fb(
+        &self,
+        next: &Dispatch,
+        space: Space,
+        info: *const SpaceComponentStatusSetInfoFB,
+        request_id: *mut AsyncRequestIdFB,
+    ) -> Result {
+        match next.set_space_component_status_fb {
+            Some(f) => f(space, info, request_id),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrGetSpaceComponentStatusFB](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrGetSpaceComponentStatusFB) - defined by [XR_FB_spatial_entity](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_FB_spatial_entity)"]
+    unsafe fn get_space_component_status_fb(
+        &self,
+        next: &Dispatch,
+        space: Space,
+        component_type: SpaceComponentTypeFB,
+        status: *mut SpaceComponentStatusFB,
+    ) -> Result {
+        match next.get_space_component_status_fb {
+            Some(f) => f(space, component_type, status),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrCreateTriangleMeshFB](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrCreateTriangleMeshFB) - defined by [XR_FB_triangle_mesh](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_FB_triangle_mesh)"]
+    unsafe fn create_triangle_mesh_fb(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        create_info: *const TriangleMeshCreateInfoFB,
+        out_triangle_mesh: *mut TriangleMeshFB,
+    ) -> Result {
+        match next.create_triangle_mesh_fb {
+            Some(f) => f(session, create_info, out_triangle_mesh),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrDestroyTriangleMeshFB](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrDestroyTriangleMeshFB) - defined by [XR_FB_triangle_mesh](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_FB_triangle_mesh)"]
+    unsafe fn destroy_triangle_mesh_fb(&self, next: &Dispatch, mesh: TriangleMeshFB) -> Result {
+        match next.destroy_triangle_mesh_fb {
+            Some(f) => f(mesh),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrTriangleMeshGetVertexBufferFB](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrTriangleMeshGetVertexBufferFB) - defined by [XR_FB_triangle_mesh](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_FB_triangle_mesh)"]
+    unsafe fn triangle_mesh_get_vertex_buffer_fb(
+        &self,
+        next: &Dispatch,
+        mesh: TriangleMeshFB,
+        out_vertex_buffer: *mut *mut Vector3f,
+    ) -> Result {
+        match next.triangle_mesh_get_vertex_buffer_fb {
+            Some(f) => f(mesh, out_vertex_buffer),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrTriangleMeshGetIndexBufferFB](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrTriangleMeshGetIndexBufferFB) - defined by [XR_FB_triangle_mesh](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_FB_triangle_mesh)"]
+    unsafe fn triangle_mesh_get_index_buffer_fb(
+        &self,
+        next: &Dispatch,
+        mesh: TriangleMeshFB,
+        out_index_buffer: *mut *mut u32,
+    ) -> Result {
+        match next.triangle_mesh_get_index_buffer_fb {
+            Some(f) => f(mesh, out_index_buffer),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrTriangleMeshBeginUpdateFB](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrTriangleMeshBeginUpdateFB) - defined by [XR_FB_triangle_mesh](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_FB_triangle_mesh)"]
+    unsafe fn triangle_mesh_begin_update_fb(
+        &self,
+        next: &Dispatch,
+        mesh: TriangleMeshFB,
+    ) -> Result {
+        match next.triangle_mesh_begin_update_fb {
+            Some(f) => f(mesh),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrTriangleMeshEndUpdateFB](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrTriangleMeshEndUpdateFB) - defined by [XR_FB_triangle_mesh](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_FB_triangle_mesh)"]
+    unsafe fn triangle_mesh_end_update_fb(
+        &self,
+        next: &Dispatch,
+        mesh: TriangleMeshFB,
+        vertex_count: u32,
+        triangle_count: u32,
+    ) -> Result {
+        match next.triangle_mesh_end_update_fb {
+            Some(f) => f(mesh, vertex_count, triangle_count),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrTriangleMeshBeginVertexBufferUpdateFB](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrTriangleMeshBeginVertexBufferUpdateFB) - defined by [XR_FB_triangle_mesh](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_FB_triangle_mesh)"]
+    unsafe fn triangle_mesh_begin_vertex_buffer_update_fb(
+        &self,
+        next: &Dispatch,
+        mesh: TriangleMeshFB,
+        out_vertex_count: *mut u32,
+    ) -> Result {
+        match next.triangle_mesh_begin_vertex_buffer_update_fb {
+            Some(f) => f(mesh, out_vertex_count),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrTriangleMeshEndVertexBufferUpdateFB](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrTriangleMeshEndVertexBufferUpdateFB) - defined by [XR_FB_triangle_mesh](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_FB_triangle_mesh)"]
+    unsafe fn triangle_mesh_end_vertex_buffer_update_fb(
+        &self,
+        next: &Dispatch,
+        mesh: TriangleMeshFB,
+    ) -> Result {
+        match next.triangle_mesh_end_vertex_buffer_update_fb {
+            Some(f) => f(mesh),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrCreatePassthroughFB](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrCreatePassthroughFB) - defined by [XR_FB_passthrough](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_FB_passthrough)"]
+    unsafe fn create_passthrough_fb(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        create_info: *const PassthroughCreateInfoFB,
+        out_passthrough: *mut PassthroughFB,
+    ) -> Result {
+        match next.create_passthrough_fb {
+            Some(f) => f(session, create_info, out_passthrough),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrDestroyPassthroughFB](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrDestroyPassthroughFB) - defined by [XR_FB_passthrough](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_FB_passthrough)"]
+    unsafe fn destroy_passthrough_fb(&self, next: &Dispatch, passthrough: PassthroughFB) -> Result {
+        match next.destroy_passthrough_fb {
+            Some(f) => f(passthrough),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrPassthroughStartFB](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrPassthroughStartFB) - defined by [XR_FB_passthrough](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_FB_passthrough)"]
+    unsafe fn passthrough_start_fb(&self, next: &Dispatch, passthrough: PassthroughFB) -> Result {
+        match next.passthrough_start_fb {
+            Some(f) => f(passthrough),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrPassthroughPauseFB](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrPassthroughPauseFB) - defined by [XR_FB_passthrough](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_FB_passthrough)"]
+    unsafe fn passthrough_pause_fb(&self, next: &Dispatch, passthrough: PassthroughFB) -> Result {
+        match next.passthrough_pause_fb {
+            Some(f) => f(passthrough),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrCreatePassthroughLayerFB](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrCreatePassthroughLayerFB) - defined by [XR_FB_passthrough](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_FB_passthrough)"]
+    unsafe fn create_passthrough_layer_fb(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        create_info: *const PassthroughLayerCreateInfoFB,
+        out_layer: *mut PassthroughLayerFB,
+    ) -> Result {
+        match next.create_passthrough_layer_fb {
+            Some(f) => f(session, create_info, out_layer),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrDestroyPassthroughLayerFB](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrDestroyPassthroughLayerFB) - defined by [XR_FB_passthrough](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_FB_passthrough)"]
+    unsafe fn destroy_passthrough_layer_fb(
+        &self,
+        next: &Dispatch,
+        layer: PassthroughLayerFB,
+    ) -> Result {
+        match next.destroy_passthrough_layer_fb {
+            Some(f) => f(layer),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrPassthroughLayerPauseFB](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrPassthroughLayerPauseFB) - defined by [XR_FB_passthrough](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_FB_passthrough)"]
+    unsafe fn passthrough_layer_pause_fb(
+        &self,
+        next: &Dispatch,
+        layer: PassthroughLayerFB,
+    ) -> Result {
+        match next.passthrough_layer_pause_fb {
+            Some(f) => f(layer),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrPassthroughLayerResumeFB](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrPassthroughLayerResumeFB) - defined by [XR_FB_passthrough](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_FB_passthrough)"]
+    unsafe fn passthrough_layer_resume_fb(
+        &self,
+        next: &Dispatch,
+        layer: PassthroughLayerFB,
+    ) -> Result {
+        match next.passthrough_layer_resume_fb {
+            Some(f) => f(layer),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrPassthroughLayerSetStyleFB](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrPassthroughLayerSetStyleFB) - defined by [XR_FB_passthrough](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_FB_passthrough)"]
+    unsafe fn passthrough_layer_set_style_fb(
+        &self,
+        next: &Dispatch,
+        layer: PassthroughLayerFB,
+        style: *const PassthroughStyleFB,
+    ) -> Result {
+        match next.passthrough_layer_set_style_fb {
+            Some(f) => f(layer, style),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrCreateGeometryInstanceFB](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrCreateGeometryInstanceFB) - defined by [XR_FB_passthrough](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_FB_passthrough)"]
+    unsafe fn create_geometry_instance_fb(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        create_info: *const GeometryInstanceCreateInfoFB,
+        out_geometry_instance: *mut GeometryInstanceFB,
+    ) -> Result {
+        match next.create_geometry_instance_fb {
+            Some(f) => f(session, create_info, out_geometry_instance),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrDestroyGeometryInstanceFB](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrDestroyGeometryInstanceFB) - defined by [XR_FB_passthrough](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_FB_passthrough)"]
+    unsafe fn destroy_geometry_instance_fb(
+        &self,
+        next: &Dispatch,
+        instance: GeometryInstanceFB,
+    ) -> Result {
+        match next.destroy_geometry_instance_fb {
+            Some(f) => f(instance),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrGeometryInstanceSetTransformFB](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrGeometryInstanceSetTransformFB) - defined by [XR_FB_passthrough](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_FB_passthrough)"]
+    unsafe fn geometry_instance_set_transform_fb(
+        &self,
+        next: &Dispatch,
+        instance: GeometryInstanceFB,
+        transformation: *const GeometryInstanceTransformFB,
+    ) -> Result {
+        match next.geometry_instance_set_transform_fb {
+            Some(f) => f(instance, transformation),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrQuerySpacesFB](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrQuerySpacesFB) - defined by [XR_FB_spatial_entity_query](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_FB_spatial_entity_query)"]
+    unsafe fn query_spaces_fb(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        info: *const SpaceQueryInfoBaseHeaderFB,
+        request_id: *mut AsyncRequestIdFB,
+    ) -> Result {
+        match next.query_spaces_fb {
+            Some(f) => f(session, info, request_id),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrRetrieveSpaceQueryResultsFB](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrRetrieveSpaceQueryResultsFB) - defined by [XR_FB_spatial_entity_query](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_FB_spatial_entity_query)"]
+    unsafe fn retrieve_space_query_results_fb(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        request_id: AsyncRequestIdFB,
+        results: *mut SpaceQueryResultsFB,
+    ) -> Result {
+        match next.retrieve_space_query_results_fb {
+            Some(f) => f(session, request_id, results),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrSaveSpaceFB](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrSaveSpaceFB) - defined by [XR_FB_spatial_entity_storage](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_FB_spatial_entity_storage)"]
+    unsafe fn save_space_fb(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        info: *const SpaceSaveInfoFB,
+        request_id: *mut AsyncRequestIdFB,
+    ) -> Result {
+        match next.save_space_fb {
+            Some(f) => f(session, info, request_id),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrEraseSpaceFB](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrEraseSpaceFB) - defined by [XR_FB_spatial_entity_storage](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_FB_spatial_entity_storage)"]
+    unsafe fn erase_space_fb(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        info: *const SpaceEraseInfoFB,
+        request_id: *mut AsyncRequestIdFB,
+    ) -> Result {
+        match next.erase_space_fb {
+            Some(f) => f(session, info, request_id),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrSaveSpaceListFB](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrSaveSpaceListFB) - defined by [XR_FB_spatial_entity_storage_batch](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_FB_spatial_entity_storage_batch)"]
+    unsafe fn save_space_list_fb(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        info: *const SpaceListSaveInfoFB,
+        request_id: *mut AsyncRequestIdFB,
+    ) -> Result {
+        match next.save_space_list_fb {
+            Some(f) => f(session, info, request_id),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrShareSpacesFB](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrShareSpacesFB) - defined by [XR_FB_spatial_entity_sharing](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_FB_spatial_entity_sharing)"]
+    unsafe fn share_spaces_fb(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        info: *const SpaceShareInfoFB,
+        request_id: *mut AsyncRequestIdFB,
+    ) -> Result {
+        match next.share_spaces_fb {
+            Some(f) => f(session, info, request_id),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrGetSpaceContainerFB](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrGetSpaceContainerFB) - defined by [XR_FB_spatial_entity_container](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_FB_spatial_entity_container)"]
+    unsafe fn get_space_container_fb(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        space: Space,
+        space_container_output: *mut SpaceContainerFB,
+    ) -> Result {
+        match next.get_space_container_fb {
+            Some(f) => f(session, space, space_container_output),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrGetSpaceBoundingBox2DFB](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrGetSpaceBoundingBox2DFB) - defined by [XR_FB_scene](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_FB_scene)"]
+    unsafe fn get_space_bounding_box2_dfb(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        space: Space,
+        bounding_box2_d_output: *mut Rect2Df,
+    ) -> Result {
+        match next.get_space_bounding_box2_dfb {
+            Some(f) => f(session, space, bounding_box2_d_output),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrGetSpaceBoundingBox3DFB](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrGetSpaceBoundingBox3DFB) - defined by [XR_FB_scene](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_FB_scene)"]
+    unsafe fn get_space_bounding_box3_dfb(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        space: Space,
+        bounding_box3_d_output: *mut Rect3DfFB,
+    ) -> Result {
+        match next.get_space_bounding_box3_dfb {
+            Some(f) => f(session, space, bounding_box3_d_output),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrGetSpaceSemanticLabelsFB](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrGetSpaceSemanticLabelsFB) - defined by [XR_FB_scene](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_FB_scene)"]
+    unsafe fn get_space_semantic_labels_fb(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        space: Space,
+        semantic_labels_output: *mut SemanticLabelsFB,
+    ) -> Result {
+        match next.get_space_semantic_labels_fb {
+            Some(f) => f(session, space, semantic_labels_output),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrGetSpaceBoundary2DFB](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrGetSpaceBoundary2DFB) - defined by [XR_FB_scene](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_FB_scene)"]
+    unsafe fn get_space_boundary2_dfb(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        space: Space,
+        boundary2_d_output: *mut Boundary2DFB,
+    ) -> Result {
+        match next.get_space_boundary2_dfb {
+            Some(f) => f(session, space, boundary2_d_output),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrGetSpaceRoomLayoutFB](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrGetSpaceRoomLayoutFB) - defined by [XR_FB_scene](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_FB_scene)"]
+    unsafe fn get_space_room_layout_fb(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        space: Space,
+        room_layout_output: *mut RoomLayoutFB,
+    ) -> Result {
+        match next.get_space_room_layout_fb {
+            Some(f) => f(session, space, room_layout_output),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrRequestSceneCaptureFB](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrRequestSceneCaptureFB) - defined by [XR_FB_scene_capture](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_FB_scene_capture)"]
+    unsafe fn request_scene_capture_fb(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        info: *const SceneCaptureRequestInfoFB,
+        request_id: *mut AsyncRequestIdFB,
+    ) -> Result {
+        match next.request_scene_capture_fb {
+            Some(f) => f(session, info, request_id),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrPassthroughLayerSetKeyboardHandsIntensityFB](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrPassthroughLayerSetKeyboardHandsIntensityFB) - defined by [XR_FB_passthrough_keyboard_hands](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_FB_passthrough_keyboard_hands)"]
+    unsafe fn passthrough_layer_set_keyboard_hands_intensity_fb(
+        &self,
+        next: &Dispatch,
+        layer: PassthroughLayerFB,
+        intensity: *const PassthroughKeyboardHandsIntensityFB,
+    ) -> Result {
+        match next.passthrough_layer_set_keyboard_hands_intensity_fb {
+            Some(f) => f(layer, intensity),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrCreateSpatialAnchorStoreConnectionMSFT](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrCreateSpatialAnchorStoreConnectionMSFT) - defined by [XR_MSFT_spatial_anchor_persistence](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_MSFT_spatial_anchor_persistence)"]
+    unsafe fn create_spatial_anchor_store_connection_msft(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        spatial_anchor_store: *mut SpatialAnchorStoreConnectionMSFT,
+    ) -> Result {
+        match next.create_spatial_anchor_store_connection_msft {
+            Some(f) => f(session, spatial_anchor_store),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrDestroySpatialAnchorStoreConnectionMSFT](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrDestroySpatialAnchorStoreConnectionMSFT) - defined by [XR_MSFT_spatial_anchor_persistence](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_MSFT_spatial_anchor_persistence)"]
+    unsafe fn destroy_spatial_anchor_store_connection_msft(
+        &self,
+        next: &Dispatch,
+        spatial_anchor_store: SpatialAnchorStoreConnectionMSFT,
+    ) -> Result {
+        match next.destroy_spatial_anchor_store_connection_msft {
+            Some(f) => f(spatial_anchor_store),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrPersistSpatialAnchorMSFT](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrPersistSpatialAnchorMSFT) - defined by [XR_MSFT_spatial_anchor_persistence](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_MSFT_spatial_anchor_persistence)"]
+    unsafe fn persist_spatial_anchor_msft(
+        &self,
+        next: &Dispatch,
+        spatial_anchor_store: SpatialAnchorStoreConnectionMSFT,
+        spatial_anchor_persistence_info: *const SpatialAnchorPersistenceInfoMSFT,
+    ) -> Result {
+        match next.persist_spatial_anchor_msft {
+            Some(f) => f(spatial_anchor_store, spatial_anchor_persistence_info),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrEnumeratePersistedSpatialAnchorNamesMSFT](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrEnumeratePersistedSpatialAnchorNamesMSFT) - defined by [XR_MSFT_spatial_anchor_persistence](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_MSFT_spatial_anchor_persistence)"]
+    unsafe fn enumerate_persisted_spatial_anchor_names_msft(
+        &self,
+        next: &Dispatch,
+        spatial_anchor_store: SpatialAnchorStoreConnectionMSFT,
+        spatial_anchor_names_capacity_input: u32,
+        spatial_anchor_names_count_output: *mut u32,
+        persisted_anchor_names: *mut SpatialAnchorPersistenceNameMSFT,
+    ) -> Result {
+        match next.enumerate_persisted_spatial_anchor_names_msft {
+            Some(f) => f(
+                spatial_anchor_store,
+                spatial_anchor_names_capacity_input,
+                spatial_anchor_names_count_output,
+                persisted_anchor_names,
+            ),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrCreateSpatialAnchorFromPersistedNameMSFT](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrCreateSpatialAnchorFromPersistedNameMSFT) - defined by [XR_MSFT_spatial_anchor_persistence](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_MSFT_spatial_anchor_persistence)"]
+    unsafe fn create_spatial_anchor_from_persisted_name_msft(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        spatial_anchor_create_info: *const SpatialAnchorFromPersistedAnchorCreateInfoMSFT,
+        spatial_anchor: *mut SpatialAnchorMSFT,
+    ) -> Result {
+        match next.create_spatial_anchor_from_persisted_name_msft {
+            Some(f) => f(session, spatial_anchor_create_info, spatial_anchor),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrUnpersistSpatialAnchorMSFT](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrUnpersistSpatialAnchorMSFT) - defined by [XR_MSFT_spatial_anchor_persistence](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_MSFT_spatial_anchor_persistence)"]
+    unsafe fn unpersist_spatial_anchor_msft(
+        &self,
+        next: &Dispatch,
+        spatial_anchor_store: SpatialAnchorStoreConnectionMSFT,
+        spatial_anchor_persistence_name: *const SpatialAnchorPersistenceNameMSFT,
+    ) -> Result {
+        match next.unpersist_spatial_anchor_msft {
+            Some(f) => f(spatial_anchor_store, spatial_anchor_persistence_name),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrClearSpatialAnchorStoreMSFT](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrClearSpatialAnchorStoreMSFT) - defined by [XR_MSFT_spatial_anchor_persistence](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_MSFT_spatial_anchor_persistence)"]
+    unsafe fn clear_spatial_anchor_store_msft(
+        &self,
+        next: &Dispatch,
+        spatial_anchor_store: SpatialAnchorStoreConnectionMSFT,
+    ) -> Result {
+        match next.clear_spatial_anchor_store_msft {
+            Some(f) => f(spatial_anchor_store),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrCreateFacialTrackerHTC](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrCreateFacialTrackerHTC) - defined by [XR_HTC_facial_tracking](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_HTC_facial_tracking)"]
+    unsafe fn create_facial_tracker_htc(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        create_info: *const FacialTrackerCreateInfoHTC,
+        facial_tracker: *mut FacialTrackerHTC,
+    ) -> Result {
+        match next.create_facial_tracker_htc {
+            Some(f) => f(session, create_info, facial_tracker),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrDestroyFacialTrackerHTC](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrDestroyFacialTrackerHTC) - defined by [XR_HTC_facial_tracking](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_HTC_facial_tracking)"]
+    unsafe fn destroy_facial_tracker_htc(
+        &self,
+        next: &Dispatch,
+        facial_tracker: FacialTrackerHTC,
+    ) -> Result {
+        match next.destroy_facial_tracker_htc {
+            Some(f) => f(facial_tracker),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrGetFacialExpressionsHTC](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrGetFacialExpressionsHTC) - defined by [XR_HTC_facial_tracking](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_HTC_facial_tracking)"]
+    unsafe fn get_facial_expressions_htc(
+        &self,
+        next: &Dispatch,
+        facial_tracker: FacialTrackerHTC,
+        facial_expressions: *mut FacialExpressionsHTC,
+    ) -> Result {
+        match next.get_facial_expressions_htc {
+            Some(f) => f(facial_tracker, facial_expressions),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrCreatePassthroughHTC](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrCreatePassthroughHTC) - defined by [XR_HTC_passthrough](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_HTC_passthrough)"]
+    unsafe fn create_passthrough_htc(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        create_info: *const PassthroughCreateInfoHTC,
+        passthrough: *mut PassthroughHTC,
+    ) -> Result {
+        match next.create_passthrough_htc {
+            Some(f) => f(session, create_info, passthrough),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrDestroyPassthroughHTC](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrDestroyPassthroughHTC) - defined by [XR_HTC_passthrough](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_HTC_passthrough)"]
+    unsafe fn destroy_passthrough_htc(
+        &self,
+        next: &Dispatch,
+        passthrough: PassthroughHTC,
+    ) -> Result {
+        match next.destroy_passthrough_htc {
+            Some(f) => f(passthrough),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrEnumerateViveTrackerPathsHTCX](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrEnumerateViveTrackerPathsHTCX) - defined by [XR_HTCX_vive_tracker_interaction](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_HTCX_vive_tracker_interaction)"]
+    unsafe fn enumerate_vive_tracker_paths_htcx(
+        &self,
+        next: &Dispatch,
+        instance: Instance,
+        path_capacity_input: u32,
+        path_count_output: *mut u32,
+        paths: *mut ViveTrackerPathsHTCX,
+    ) -> Result {
+        match next.enumerate_vive_tracker_paths_htcx {
+            Some(f) => f(instance, path_capacity_input, path_count_output, paths),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrSetMarkerTrackingVARJO](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrSetMarkerTrackingVARJO) - defined by [XR_VARJO_marker_tracking](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_VARJO_marker_tracking)"]
+    unsafe fn set_marker_tracking_varjo(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        enabled: Bool32,
+    ) -> Result {
+        match next.set_marker_tracking_varjo {
+            Some(f) => f(session, enabled),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrSetMarkerTrackingTimeoutVARJO](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrSetMarkerTrackingTimeoutVARJO) - defined by [XR_VARJO_marker_tracking](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_VARJO_marker_tracking)"]
+    unsafe fn set_marker_tracking_timeout_varjo(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        marker_id: u64,
+        timeout: Duration,
+    ) -> Result {
+        match next.set_marker_tracking_timeout_varjo {
+            Some(f) => f(session, marker_id, timeout),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrSetMarkerTrackingPredictionVARJO](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrSetMarkerTrackingPredictionVARJO) - defined by [XR_VARJO_marker_tracking](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_VARJO_marker_tracking)"]
+    unsafe fn set_marker_tracking_prediction_varjo(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        marker_id: u64,
+        enabled: Bool32,
+    ) -> Result {
+        match next.set_marker_tracking_prediction_varjo {
+            Some(f) => f(session, marker_id, enabled),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrGetMarkerSizeVARJO](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrGetMarkerSizeVARJO) - defined by [XR_VARJO_marker_tracking](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_VARJO_marker_tracking)"]
+    unsafe fn get_marker_size_varjo(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        marker_id: u64,
+        size: *mut Extent2Df,
+    ) -> Result {
+        match next.get_marker_size_varjo {
+            Some(f) => f(session, marker_id, size),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrCreateMarkerSpaceVARJO](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrCreateMarkerSpaceVARJO) - defined by [XR_VARJO_marker_tracking](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_VARJO_marker_tracking)"]
+    unsafe fn create_marker_space_varjo(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        create_info: *const MarkerSpaceCreateInfoVARJO,
+        space: *mut Space,
+    ) -> Result {
+        match next.create_marker_space_varjo {
+            Some(f) => f(session, create_info, space),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrSetDigitalLensControlALMALENCE](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrSetDigitalLensControlALMALENCE) - defined by [XR_ALMALENCE_digital_lens_control](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_ALMALENCE_digital_lens_control)"]
+    unsafe fn set_digital_lens_control_almalence(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        digital_lens_control: *const DigitalLensControlALMALENCE,
+    ) -> Result {
+        match next.set_digital_lens_control_almalence {
+            Some(f) => f(session, digital_lens_control),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrSetViewOffsetVARJO](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrSetViewOffsetVARJO) - defined by [XR_VARJO_view_offset](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_VARJO_view_offset)"]
+    unsafe fn set_view_offset_varjo(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        offset: f32,
+    ) -> Result {
+        match next.set_view_offset_varjo {
+            Some(f) => f(session, offset),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrEnumerateExternalCamerasOCULUS](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrEnumerateExternalCamerasOCULUS) - defined by [XR_OCULUS_external_camera](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_OCULUS_external_camera)"]
+    unsafe fn enumerate_external_cameras_oculus(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        camera_capacity_input: u32,
+        camera_count_output: *mut u32,
+        cameras: *mut ExternalCameraOCULUS,
+    ) -> Result {
+        match next.enumerate_external_cameras_oculus {
+            Some(f) => f(session, camera_capacity_input, camera_count_output, cameras),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrCreatePassthroughColorLutMETA](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrCreatePassthroughColorLutMETA) - defined by [XR_META_passthrough_color_lut](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_META_passthrough_color_lut)"]
+    unsafe fn create_passthrough_color_lut_meta(
+        &self,
+        next: &Dispatch,
+        passthrough: PassthroughFB,
+        create_info: *const PassthroughColorLutCreateInfoMETA,
+        color_lut: *mut PassthroughColorLutMETA,
+    ) -> Result {
+        match next.create_passthrough_color_lut_meta {
+            Some(f) => f(passthrough, create_info, color_lut),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrDestroyPassthroughColorLutMETA](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrDestroyPassthroughColorLutMETA) - defined by [XR_META_passthrough_color_lut](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_META_passthrough_color_lut)"]
+    unsafe fn destroy_passthrough_color_lut_meta(
+        &self,
+        next: &Dispatch,
+        color_lut: PassthroughColorLutMETA,
+    ) -> Result {
+        match next.destroy_passthrough_color_lut_meta {
+            Some(f) => f(color_lut),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrUpdatePassthroughColorLutMETA](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrUpdatePassthroughColorLutMETA) - defined by [XR_META_passthrough_color_lut](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_META_passthrough_color_lut)"]
+    unsafe fn update_passthrough_color_lut_meta(
+        &self,
+        next: &Dispatch,
+        color_lut: PassthroughColorLutMETA,
+        update_info: *const PassthroughColorLutUpdateInfoMETA,
+    ) -> Result {
+        match next.update_passthrough_color_lut_meta {
+            Some(f) => f(color_lut, update_info),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrEnumeratePerformanceMetricsCounterPathsMETA](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrEnumeratePerformanceMetricsCounterPathsMETA) - defined by [XR_META_performance_metrics](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_META_performance_metrics)"]
+    unsafe fn enumerate_performance_metrics_counter_paths_meta(
+        &self,
+        next: &Dispatch,
+        instance: Instance,
+        counter_path_capacity_input: u32,
+        counter_path_count_output: *mut u32,
+        counter_paths: *mut Path,
+    ) -> Result {
+        match next.enumerate_performance_metrics_counter_paths_meta {
+            Some(f) => f(
+                instance,
+                counter_path_capacity_input,
+                counter_path_count_output,
+                counter_paths,
+            ),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrSetPerformanceMetricsStateMETA](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrSetPerformanceMetricsStateMETA) - defined by [XR_META_performance_metrics](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_META_performance_metrics)"]
+    unsafe fn set_performance_metrics_state_meta(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        state: *const PerformanceMetricsStateMETA,
+    ) -> Result {
+        match next.set_performance_metrics_state_meta {
+            Some(f) => f(session, state),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrGetPerformanceMetricsStateMETA](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrGetPerformanceMetricsStateMETA) - defined by [XR_META_performance_metrics](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_META_performance_metrics)"]
+    unsafe fn get_performance_metrics_state_meta(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        state: *mut PerformanceMetricsStateMETA,
+    ) -> Result {
+        match next.get_performance_metrics_state_meta {
+            Some(f) => f(session, state),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrQueryPerformanceMetricsCounterMETA](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrQueryPerformanceMetricsCounterMETA) - defined by [XR_META_performance_metrics](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_META_performance_metrics)"]
+    unsafe fn query_performance_metrics_counter_meta(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        counter_path: Path,
+        counter: *mut PerformanceMetricsCounterMETA,
+    ) -> Result {
+        match next.query_performance_metrics_counter_meta {
+            Some(f) => f(session, counter_path, counter),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrGetPassthroughPreferencesMETA](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrGetPassthroughPreferencesMETA) - defined by [XR_META_passthrough_preferences](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_META_passthrough_preferences)"]
+    unsafe fn get_passthrough_preferences_meta(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        preferences: *mut PassthroughPreferencesMETA,
+    ) -> Result {
+        match next.get_passthrough_preferences_meta {
+            Some(f) => f(session, preferences),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrApplyFoveationHTC](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrApplyFoveationHTC) - defined by [XR_HTC_foveation](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_HTC_foveation)"]
+    unsafe fn apply_foveation_htc(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        apply_info: *const FoveationApplyInfoHTC,
+    ) -> Result {
+        match next.apply_foveation_htc {
+            Some(f) => f(session, apply_info),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrCreateSpaceFromCoordinateFrameUIDML](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrCreateSpaceFromCoordinateFrameUIDML) - defined by [XR_ML_compat](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_ML_compat)"]
+    unsafe fn create_space_from_coordinate_frame_uidml(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        create_info: *const CoordinateSpaceCreateInfoML,
+        space: *mut Space,
+    ) -> Result {
+        match next.create_space_from_coordinate_frame_uidml {
+            Some(f) => f(session, create_info, space),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrGetDeviceSampleRateFB](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrGetDeviceSampleRateFB) - defined by [XR_FB_haptic_pcm](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_FB_haptic_pcm)"]
+    unsafe fn get_device_sample_rate_fb(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        haptic_action_info: *const HapticActionInfo,
+        device_sample_rate: *mut DevicePcmSampleRateStateFB,
+    ) -> Result {
+        match next.get_device_sample_rate_fb {
+            Some(f) => f(session, haptic_action_info, device_sample_rate),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrSetTrackingOptimizationSettingsHintQCOM](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrSetTrackingOptimizationSettingsHintQCOM) - defined by [XR_QCOM_tracking_optimization_settings](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_QCOM_tracking_optimization_settings)"]
+    unsafe fn set_tracking_optimization_settings_hint_qcom(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        domain: TrackingOptimizationSettingsDomainQCOM,
+        hint: TrackingOptimizationSettingsHintQCOM,
+    ) -> Result {
+        match next.set_tracking_optimization_settings_hint_qcom {
+            Some(f) => f(session, domain, hint),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrCreateSpaceUserFB](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrCreateSpaceUserFB) - defined by [XR_FB_spatial_entity_user](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_FB_spatial_entity_user)"]
+    unsafe fn create_space_user_fb(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        info: *const SpaceUserCreateInfoFB,
+        user: *mut SpaceUserFB,
+    ) -> Result {
+        match next.create_space_user_fb {
+            Some(f) => f(session, info, user),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrGetSpaceUserIdFB](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrGetSpaceUserIdFB) - defined by [XR_FB_spatial_entity_user](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_FB_spatial_entity_user)"]
+    unsafe fn get_space_user_id_fb(
+        &self,
+        next: &Dispatch,
+        user: SpaceUserFB,
+        user_id: *mut SpaceUserIdFB,
+    ) -> Result {
+        match next.get_space_user_id_fb {
+            Some(f) => f(user, user_id),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrDestroySpaceUserFB](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrDestroySpaceUserFB) - defined by [XR_FB_spatial_entity_user](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_FB_spatial_entity_user)"]
+    unsafe fn destroy_space_user_fb(&self, next: &Dispatch, user: SpaceUserFB) -> Result {
+        match next.destroy_space_user_fb {
+            Some(f) => f(user),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrApplyForceFeedbackCurlMNDX](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrApplyForceFeedbackCurlMNDX) - defined by [XR_MNDX_force_feedback_curl](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_MNDX_force_feedback_curl)"]
+    unsafe fn apply_force_feedback_curl_mndx(
+        &self,
+        next: &Dispatch,
+        hand_tracker: HandTrackerEXT,
+        locations: *const ForceFeedbackCurlApplyLocationsMNDX,
+    ) -> Result {
+        match next.apply_force_feedback_curl_mndx {
+            Some(f) => f(hand_tracker, locations),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrCreatePlaneDetectorEXT](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrCreatePlaneDetectorEXT) - defined by [XR_EXT_plane_detection](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_EXT_plane_detection)"]
+    unsafe fn create_plane_detector_ext(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        create_info: *const PlaneDetectorCreateInfoEXT,
+        plane_detector: *mut PlaneDetectorEXT,
+    ) -> Result {
+        match next.create_plane_detector_ext {
+            Some(f) => f(session, create_info, plane_detector),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrDestroyPlaneDetectorEXT](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrDestroyPlaneDetectorEXT) - defined by [XR_EXT_plane_detection](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_EXT_plane_detection)"]
+    unsafe fn destroy_plane_detector_ext(
+        &self,
+        next: &Dispatch,
+        plane_detector: PlaneDetectorEXT,
+    ) -> Result {
+        match next.destroy_plane_detector_ext {
+            Some(f) => f(plane_detector),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrBeginPlaneDetectionEXT](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrBeginPlaneDetectionEXT) - defined by [XR_EXT_plane_detection](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_EXT_plane_detection)"]
+    unsafe fn begin_plane_detection_ext(
+        &self,
+        next: &Dispatch,
+        plane_detector: PlaneDetectorEXT,
+        begin_info: *const PlaneDetectorBeginInfoEXT,
+    ) -> Result {
+        match next.begin_plane_detection_ext {
+            Some(f) => f(plane_detector, begin_info),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrGetPlaneDetectionStateEXT](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrGetPlaneDetectionStateEXT) - defined by [XR_EXT_plane_detection](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_EXT_plane_detection)"]
+    unsafe fn get_plane_detection_state_ext(
+        &self,
+        next: &Dispatch,
+        plane_detector: PlaneDetectorEXT,
+        state: *mut PlaneDetectionStateEXT,
+    ) -> Result {
+        match next.get_plane_detection_state_ext {
+            Some(f) => f(plane_detector, state),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrGetPlaneDetectionsEXT](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrGetPlaneDetectionsEXT) - defined by [XR_EXT_plane_detection](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_EXT_plane_detection)"]
+    unsafe fn get_plane_detections_ext(
+        &self,
+        next: &Dispatch,
+        plane_detector: PlaneDetectorEXT,
+        info: *const PlaneDetectorGetInfoEXT,
+        locations: *mut PlaneDetectorLocationsEXT,
+    ) -> Result {
+        match next.get_plane_detections_ext {
+            Some(f) => f(plane_detector, info, locations),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrGetPlanePolygonBufferEXT](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrGetPlanePolygonBufferEXT) - defined by [XR_EXT_plane_detection](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_EXT_plane_detection)"]
+    unsafe fn get_plane_polygon_buffer_ext(
+        &self,
+        next: &Dispatch,
+        plane_detector: PlaneDetectorEXT,
+        plane_id: u64,
+        polygon_buffer_index: u32,
+        polygon_buffer: *mut PlaneDetectorPolygonBufferEXT,
+    ) -> Result {
+        match next.get_plane_polygon_buffer_ext {
+            Some(f) => f(
+                plane_detector,
+                plane_id,
+                polygon_buffer_index,
+                polygon_buffer,
+            ),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrCreateVirtualKeyboardMETA](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrCreateVirtualKeyboardMETA) - defined by [XR_META_virtual_keyboard](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_META_virtual_keyboard)"]
+    unsafe fn create_virtual_keyboard_meta(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        create_info: *const VirtualKeyboardCreateInfoMETA,
+        keyboard: *mut VirtualKeyboardMETA,
+    ) -> Result {
+        match next.create_virtual_keyboard_meta {
+            Some(f) => f(session, create_info, keyboard),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrDestroyVirtualKeyboardMETA](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrDestroyVirtualKeyboardMETA) - defined by [XR_META_virtual_keyboard](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_META_virtual_keyboard)"]
+    unsafe fn destroy_virtual_keyboard_meta(
+        &self,
+        next: &Dispatch,
+        keyboard: VirtualKeyboardMETA,
+    ) -> Result {
+        match next.destroy_virtual_keyboard_meta {
+            Some(f) => f(keyboard),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrCreateVirtualKeyboardSpaceMETA](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrCreateVirtualKeyboardSpaceMETA) - defined by [XR_META_virtual_keyboard](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_META_virtual_keyboard)"]
+    unsafe fn create_virtual_keyboard_space_meta(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        keyboard: VirtualKeyboardMETA,
+        create_info: *const VirtualKeyboardSpaceCreateInfoMETA,
+        keyboard_space: *mut Space,
+    ) -> Result {
+        match next.create_virtual_keyboard_space_meta {
+            Some(f) => f(session, keyboard, create_info, keyboard_space),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrSuggestVirtualKeyboardLocationMETA](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrSuggestVirtualKeyboardLocationMETA) - defined by [XR_META_virtual_keyboard](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_META_virtual_keyboard)"]
+    unsafe fn suggest_virtual_keyboard_location_meta(
+        &self,
+        next: &Dispatch,
+        keyboard: VirtualKeyboardMETA,
+        location_info: *const VirtualKeyboardLocationInfoMETA,
+    ) -> Result {
+        match next.suggest_virtual_keyboard_location_meta {
+            Some(f) => f(keyboard, location_info),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrGetVirtualKeyboardScaleMETA](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrGetVirtualKeyboardScaleMETA) - defined by [XR_META_virtual_keyboard](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_META_virtual_keyboard)"]
+    unsafe fn get_virtual_keyboard_scale_meta(
+        &self,
+        next: &Dispatch,
+        keyboard: VirtualKeyboardMETA,
+        scale: *mut f32,
+    ) -> Result {
+        match next.get_virtual_keyboard_scale_meta {
+            Some(f) => f(keyboard, scale),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrSetVirtualKeyboardModelVisibilityMETA](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrSetVirtualKeyboardModelVisibilityMETA) - defined by [XR_META_virtual_keyboard](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_META_virtual_keyboard)"]
+    unsafe fn set_virtual_keyboard_model_visibility_meta(
+        &self,
+        next: &Dispatch,
+        keyboard: VirtualKeyboardMETA,
+        model_visibility: *const VirtualKeyboardModelVisibilitySetInfoMETA,
+    ) -> Result {
+        match next.set_virtual_keyboard_model_visibility_meta {
+            Some(f) => f(keyboard, model_visibility),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrGetVirtualKeyboardModelAnimationStatesMETA](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrGetVirtualKeyboardModelAnimationStatesMETA) - defined by [XR_META_virtual_keyboard](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_META_virtual_keyboard)"]
+    unsafe fn get_virtual_keyboard_model_animation_states_meta(
+        &self,
+        next: &Dispatch,
+        keyboard: VirtualKeyboardMETA,
+        animation_states: *mut VirtualKeyboardModelAnimationStatesMETA,
+    ) -> Result {
+        match next.get_virtual_keyboard_model_animation_states_meta {
+            Some(f) => f(keyboard, animation_states),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrGetVirtualKeyboardDirtyTexturesMETA](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrGetVirtualKeyboardDirtyTexturesMETA) - defined by [XR_META_virtual_keyboard](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_META_virtual_keyboard)"]
+    unsafe fn get_virtual_keyboard_dirty_textures_meta(
+        &self,
+        next: &Dispatch,
+        keyboard: VirtualKeyboardMETA,
+        texture_id_capacity_input: u32,
+        texture_id_count_output: *mut u32,
+        texture_ids: *mut u64,
+    ) -> Result {
+        match next.get_virtual_keyboard_dirty_textures_meta {
+            Some(f) => f(
+                keyboard,
+                texture_id_capacity_input,
+                texture_id_count_output,
+                texture_ids,
+            ),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrGetVirtualKeyboardTextureDataMETA](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrGetVirtualKeyboardTextureDataMETA) - defined by [XR_META_virtual_keyboard](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_META_virtual_keyboard)"]
+    unsafe fn get_virtual_keyboard_texture_data_meta(
+        &self,
+        next: &Dispatch,
+        keyboard: VirtualKeyboardMETA,
+        texture_id: u64,
+        texture_data: *mut VirtualKeyboardTextureDataMETA,
+    ) -> Result {
+        match next.get_virtual_keyboard_texture_data_meta {
+            Some(f) => f(keyboard, texture_id, texture_data),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrSendVirtualKeyboardInputMETA](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrSendVirtualKeyboardInputMETA) - defined by [XR_META_virtual_keyboard](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_META_virtual_keyboard)"]
+    unsafe fn send_virtual_keyboard_input_meta(
+        &self,
+        next: &Dispatch,
+        keyboard: VirtualKeyboardMETA,
+        info: *const VirtualKeyboardInputInfoMETA,
+        interactor_root_pose: *mut Posef,
+    ) -> Result {
+        match next.send_virtual_keyboard_input_meta {
+            Some(f) => f(keyboard, info, interactor_root_pose),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrChangeVirtualKeyboardTextContextMETA](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrChangeVirtualKeyboardTextContextMETA) - defined by [XR_META_virtual_keyboard](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_META_virtual_keyboard)"]
+    unsafe fn change_virtual_keyboard_text_context_meta(
+        &self,
+        next: &Dispatch,
+        keyboard: VirtualKeyboardMETA,
+        change_info: *const VirtualKeyboardTextContextChangeInfoMETA,
+    ) -> Result {
+        match next.change_virtual_keyboard_text_context_meta {
+            Some(f) => f(keyboard, change_info),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+    #[doc = "See [xrGetVulkanGraphicsRequirements2KHR](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrGetVulkanGraphicsRequirements2KHR) - defined by [XR_KHR_vulkan_enable](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#XR_KHR_vulkan_enable)"]
+    unsafe fn get_vulkan_graphics_requirements2_khr(
+        &self,
+        next: &Dispatch,
+        instance: Instance,
+        system_id: SystemId,
+        graphics_requirements: *mut GraphicsRequirementsVulkanKHR,
+    ) -> Result {
+        match next.get_vulkan_graphics_requirements2_khr {
+            Some(f) => f(instance, system_id, graphics_requirements),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    }
+}
+
+unsafe extern "system" fn destroy_instance(instance: Instance) -> Result {
+    let result = super::call::<Instance>(instance.into_raw(), |this, next| {
+        this.destroy_instance(next, instance)
+    });
+    if result.into_raw() >= 0 {
+        super::destroyed::<Instance>(instance.into_raw());
+    }
+    result
+}
+unsafe extern "system" fn result_to_string(
+    instance: Instance,
+    value: Result,
+    buffer: *mut c_char,
+) -> Result {
+    super::call::<Instance>(instance.into_raw(), |this, next| {
+        this.result_to_string(next, instance, value, buffer)
+    })
+}
+unsafe extern "system" fn structure_type_to_string(
+    instance: Instance,
+    value: StructureType,
+    buffer: *mut c_char,
+) -> Result {
+    super::call::<Instance>(instance.into_raw(), |this, next| {
+        this.structure_type_to_string(next, instance, value, buffer)
+    })
+}
+unsafe extern "system" fn get_instance_properties(
+    instance: Instance,
+    instance_properties: *mut InstanceProperties,
+) -> Result {
+    super::call::<Instance>(instance.into_raw(), |this, next| {
+        this.get_instance_properties(next, instance, instance_properties)
+    })
+}
+unsafe extern "system" fn get_system(
+    instance: Instance,
+    get_info: *const SystemGetInfo,
+    system_id: *mut SystemId,
+) -> Result {
+    super::call::<Instance>(instance.into_raw(), |this, next| {
+        this.get_system(next, instance, get_info, system_id)
+    })
+}
+unsafe extern "system" fn get_system_properties(
+    instance: Instance,
+    system_id: SystemId,
+    properties: *mut SystemProperties,
+) -> Result {
+    super::call::<Instance>(instance.into_raw(), |this, next| {
+        this.get_system_properties(next, instance, system_id, properties)
+    })
+}
+unsafe extern "system" fn create_session(
+    instance: Instance,
+    create_info: *const SessionCreateInfo,
+    session: *mut Session,
+) -> Result {
+    let result = super::call::<Instance>(instance.into_raw(), |this, next| {
+        this.create_session(next, instance, create_info, session)
+    });
+    if result.into_raw() >= 0 {
+        super::created::<Instance, Session>(instance.into_raw(), (*session).into_raw());
+    }
+    result
+}
+unsafe extern "system" fn destroy_session(session: Session) -> Result {
+    let result = super::call::<Session>(session.into_raw(), |this, next| {
+        this.destroy_session(next, session)
+    });
+    if result.into_raw() >= 0 {
+        super::destroyed::<Session>(session.into_raw());
+    }
+    result
+}
+unsafe extern "system" fn destroy_space(space: Space) -> Result {
+    let result = super::call::<Space>(space.into_raw(), |this, next| {
+        this.destroy_space(next, space)
+    });
+    if result.into_raw() >= 0 {
+        super::destroyed::<Space>(space.into_raw());
+    }
+    result
+}
+unsafe extern "system" fn enumerate_swapchain_formats(
+    session: Session,
+    format_capacity_input: u32,
+    format_count_output: *mut u32,
+    formats: *mut i64,
+) -> Result {
+    super::call::<Session>(session.into_raw(), |this, next| {
+        this.enumerate_swapchain_formats(
+            next,
+            session,
+            format_capacity_input,
+            format_count_output,
+            formats,
+        )
+    })
+}
+unsafe extern "system" fn create_swapchain(
+    session: Session,
+    create_info: *const SwapchainCreateInfo,
+    swapchain: *mut Swapchain,
+) -> Result {
+    let result = super::call::<Session>(session.into_raw(), |this, next| {
+        this.create_swapchain(next, session, create_info, swapchain)
+    });
+    if result.into_raw() >= 0 {
+        super::created::<Session, Swapchain>(session.into_raw(), (*swapchain).into_raw());
+    }
+    result
+}
+unsafe extern "system" fn destroy_swapchain(swapchain: Swapchain) -> Result {
+    let result = super::call::<Swapchain>(swapchain.into_raw(), |this, next| {
+        this.destroy_swapchain(next, swapchain)
+    });
+    if result.into_raw() >= 0 {
+        super::destroyed::<Swapchain>(swapchain.into_raw());
+    }
+    result
+}
+unsafe extern "system" fn enumerate_swapchain_images(
+    swapchain: Swapchain,
+    image_capacity_input: u32,
+    image_count_output: *mut u32,
+    images: *mut SwapchainImageBaseHeader,
+) -> Result {
+    super::call::<Swapchain>(swapchain.into_raw(), |this, next| {
+        this.enumerate_swapchain_images(
+            next,
+            swapchain,
+            image_capacity_input,
+            image_count_output,
+            images,
+        )
+    })
+}
+unsafe extern "system" fn acquire_swapchain_image(
+    swapchain: Swapchain,
+    acquire_info: *const SwapchainImageAcquireInfo,
+    index: *mut u32,
+) -> Result {
+    super::call::<Swapchain>(swapchain.into_raw(), |this, next| {
+        this.acquire_swapchain_image(next, swapchain, acquire_info, index)
+    })
+}
+unsafe extern "system" fn wait_swapchain_image(
+    swapchain: Swapchain,
+    wait_info: *const SwapchainImageWaitInfo,
+) -> Result {
+    super::call::<Swapchain>(swapchain.into_raw(), |this, next| {
+        this.wait_swapchain_image(next, swapchain, wait_info)
+    })
+}
+unsafe extern "system" fn release_swapchain_image(
+    swapchain: Swapchain,
+    release_info: *const SwapchainImageReleaseInfo,
+) -> Result {
+    super::call::<Swapchain>(swapchain.into_raw(), |this, next| {
+        this.release_swapchain_image(next, swapchain, release_info)
+    })
+}
+unsafe extern "system" fn begin_session(
+    session: Session,
+    begin_info: *const SessionBeginInfo,
+) -> Result {
+    super::call::<Session>(session.into_raw(), |this, next| {
+        this.begin_session(next, session, begin_info)
+    })
+}
+unsafe extern "system" fn end_session(session: Session) -> Result {
+    super::call::<Session>(session.into_raw(), |this, next| {
+        this.end_session(next, session)
+    })
+}
+unsafe extern "system" fn request_exit_session(session: Session) -> Result {
+    super::call::<Session>(session.into_raw(), |this, next| {
+        this.request_exit_session(next, session)
+    })
+}
+unsafe extern "system" fn enumerate_reference_spaces(
+    session: Session,
+    space_capacity_input: u32,
+    space_count_output: *mut u32,
+    spaces: *mut ReferenceSpaceType,
+) -> Result {
+    super::call::<Session>(session.into_raw(), |this, next| {
+        this.enumerate_reference_spaces(
+            next,
+            session,
+            space_capacity_input,
+            space_count_output,
+            spaces,
+        )
+    })
+}
+unsafe extern "system" fn create_reference_space(
+    session: Session,
+    create_info: *const ReferenceSpaceCreateInfo,
+    space: *mut Space,
+) -> Result {
+    let result = super::call::<Session>(session.into_raw(), |this, next| {
+        this.create_reference_space(next, session, create_info, space)
+    });
+    if result.into_raw() >= 0 {
+        super::created::<Session, Space>(session.into_raw(), (*space).into_raw());
+    }
+    result
+}
+unsafe extern "system" fn create_action_space(
+    session: Session,
+    create_info: *const ActionSpaceCreateInfo,
+    space: *mut Space,
+) -> Result {
+    let result = super::call::<Session>(session.into_raw(), |this, next| {
+        this.create_action_space(next, session, create_info, space)
+    });
+    if result.into_raw() >= 0 {
+        super::created::<Session, Space>(session.into_raw(), (*space).into_raw());
+    }
+    result
+}
+unsafe extern "system" fn locate_space(
+    space: Space,
+    base_space: Space,
+    time: Time,
+    location: *mut SpaceLocation,
+) -> Result {
+    super::call::<Space>(space.into_raw(), |this, next| {
+        this.locate_space(next, space, base_space, time, location)
+    })
+}
+unsafe extern "system" fn enumerate_view_configurations(
+    instance: Instance,
+    system_id: SystemId,
+    view_configuration_type_capacity_input: u32,
+    view_configuration_type_count_output: *mut u32,
+    view_configuration_types: *mut ViewConfigurationType,
+) -> Result {
+    super::call::<Instance>(instance.into_raw(), |this, next| {
+        this.enumerate_view_configurations(
+            next,
+            instance,
+            system_id,
+            view_configuration_type_capacity_input,
+            view_configuration_type_count_output,
+            view_configuration_types,
+        )
+    })
+}
+unsafe extern "system" fn enumerate_environment_blend_modes(
+    instance: Instance,
+    system_id: SystemId,
+    view_configuration_type: ViewConfigurationType,
+    environment_blend_mode_capacity_input: u32,
+    environment_blend_mode_count_output: *mut u32,
+    environment_blend_modes: *mut EnvironmentBlendMode,
+) -> Result {
+    super::call::<Instance>(instance.into_raw(), |this, next| {
+        this.enumerate_environment_blend_modes(
+            next,
+            instance,
+            system_id,
+            view_configuration_type,
+            environment_blend_mode_capacity_input,
+            environment_blend_mode_count_output,
+            environment_blend_modes,
+        )
+    })
+}
+unsafe extern "system" fn get_view_configuration_properties(
+    instance: Instance,
+    system_id: SystemId,
+    view_configuration_type: ViewConfigurationType,
+    configuration_properties: *mut ViewConfigurationProperties,
+) -> Result {
+    super::call::<Instance>(instance.into_raw(), |this, next| {
+        this.get_view_configuration_properties(
+            next,
+            instance,
+            system_id,
+            view_configuration_type,
+            configuration_properties,
+        )
+    })
+}
+unsafe extern "system" fn enumerate_view_configuration_views(
+    instance: Instance,
+    system_id: SystemId,
+    view_configuration_type: ViewConfigurationType,
+    view_capacity_input: u32,
+    view_count_output: *mut u32,
+    views: *mut ViewConfigurationView,
+) -> Result {
+    super::call::<Instance>(instance.into_raw(), |this, next| {
+        this.enumerate_view_configuration_views(
+            next,
+            instance,
+            system_id,
+            view_configuration_type,
+            view_capacity_input,
+            view_count_output,
+            views,
+        )
+    })
+}
+unsafe extern "system" fn begin_frame(
+    session: Session,
+    frame_begin_info: *const FrameBeginInfo,
+) -> Result {
+    super::call::<Session>(session.into_raw(), |this, next| {
+        this.begin_frame(next, session, frame_begin_info)
+    })
+}
+unsafe extern "system" fn locate_views(
+    session: Session,
+    view_locate_info: *const ViewLocateInfo,
+    view_state: *mut ViewState,
+    view_capacity_input: u32,
+    view_count_output: *mut u32,
+    views: *mut View,
+) -> Result {
+    super::call::<Session>(session.into_raw(), |this, next| {
+        this.locate_views(
+            next,
+            session,
+            view_locate_info,
+            view_state,
+            view_capacity_input,
+            view_count_output,
+            views,
+        )
+    })
+}
+unsafe extern "system" fn end_frame(
+    session: Session,
+    frame_end_info: *const FrameEndInfo,
+) -> Result {
+    super::call::<Session>(session.into_raw(), |this, next| {
+        this.end_frame(next, session, frame_end_info)
+    })
+}
+unsafe extern "system" fn wait_frame(
+    session: Session,
+    frame_wait_info: *const FrameWaitInfo,
+    frame_state: *mut FrameState,
+) -> Result {
+    super::call::<Session>(session.into_raw(), |this, next| {
+        this.wait_frame(next, session, frame_wait_info, frame_state)
+    })
+}
+unsafe extern "system" fn apply_haptic_feedback(
+    session: Session,
+    haptic_action_info: *const HapticActionInfo,
+    haptic_feedback: *const HapticBaseHeader,
+) -> Result {
+    super::call::<Session>(session.into_raw(), |this, next| {
+        this.apply_haptic_feedback(next, session, haptic_action_info, haptic_feedback)
+    })
+}
+unsafe extern "system" fn stop_haptic_feedback(
+    session: Session,
+    haptic_action_info: *const HapticActionInfo,
+) -> Result {
+    super::call::<Session>(session.into_raw(), |this, next| {
+        this.stop_haptic_feedback(next, session, haptic_action_info)
+    })
+}
+unsafe extern "system" fn poll_event(
+    instance: Instance,
+    event_data: *mut EventDataBuffer,
+) -> Result {
+    super::call::<Instance>(instance.into_raw(), |this, next| {
+        this.poll_event(next, instance, event_data)
+    })
+}
+unsafe extern "system" fn string_to_path(
+    instance: Instance,
+    path_string: *const c_char,
+    path: *mut Path,
+) -> Result {
+    super::call::<Instance>(instance.into_raw(), |this, next| {
+        this.string_to_path(next, instance, path_string, path)
+    })
+}
+unsafe extern "system" fn path_to_string(
+    instance: Instance,
+    path: Path,
+    buffer_capacity_input: u32,
+    buffer_count_output: *mut u32,
+    buffer: *mut c_char,
+) -> Result {
+    super::call::<Instance>(instance.into_raw(), |this, next| {
+        this.path_to_string(
+            next,
+            instance,
+            path,
+            buffer_capacity_input,
+            buffer_count_output,
+            buffer,
+        )
+    })
+}
+unsafe extern "system" fn get_reference_space_bounds_rect(
+    session: Session,
+    reference_space_type: ReferenceSpaceType,
+    bounds: *mut Extent2Df,
+) -> Result {
+    super::call::<Session>(session.into_raw(), |this, next| {
+        this.get_reference_space_bounds_rect(next, session, reference_space_type, bounds)
+    })
+}
+#[cfg(target_os = "android")]
+unsafe extern "system" fn set_android_application_thread_khr(
+    session: Session,
+    thread_type: AndroidThreadTypeKHR,
+    thread_id: u32,
+) -> Result {
+    super::call::<Session>(session.into_raw(), |this, next| {
+        this.set_android_application_thread_khr(next, session, thread_type, thread_id)
+    })
+}
+#[cfg(target_os = "android")]
+unsafe extern "system" fn create_swapchain_android_surface_khr(
+    session: Session,
+    info: *const SwapchainCreateInfo,
+    swapchain: *mut Swapchain,
+    surface: *mut jobject,
+) -> Result {
+    let result = super::call::<Session>(session.into_raw(), |this, next| {
+        this.create_swapchain_android_surface_khr(next, session, info, swapchain, surface)
+    });
+    if result.into_raw() >= 0 {
+        super::created::<Session, Swapchain>(session.into_raw(), (*swapchain).into_raw());
+    }
+    result
+}
+unsafe extern "system" fn get_action_state_boolean(
+    session: Session,
+    get_info: *const ActionStateGetInfo,
+    state: *mut ActionStateBoolean,
+) -> Result {
+    super::call::<Session>(session.into_raw(), |this, next| {
+        this.get_action_state_boolean(next, session, get_info, state)
+    })
+}
+unsafe extern "system" fn get_action_state_float(
+    session: Session,
+    get_info: *const ActionStateGetInfo,
+    state: *mut ActionStateFloat,
+) -> Result {
+    super::call::<Session>(session.into_raw(), |this, next| {
+        this.get_action_state_float(next, session, get_info, state)
+    })
+}
+unsafe extern "system" fn get_action_state_vector2f(
+    session: Session,
+    get_info: *const ActionStateGetInfo,
+    state: *mut ActionStateVector2f,
+) -> Result {
+    super::call::<Session>(session.into_raw(), |this, next| {
+        this.get_action_state_vector2f(next, session, get_info, state)
+    })
+}
+unsafe extern "system" fn get_action_state_pose(
+    session: Session,
+    get_info: *const ActionStateGetInfo,
+    state: *mut ActionStatePose,
+) -> Result {
+    super::call::<Session>(session.into_raw(), |this, next| {
+        this.get_action_state_pose(next, session, get_info, state)
+    })
+}
+unsafe extern "system" fn create_action_set(
+    instance: Instance,
+    create_info: *const ActionSetCreateInfo,
+    action_set: *mut ActionSet,
+) -> Result {
+    let result = super::call::<Instance>(instance.into_raw(), |this, next| {
+        this.create_action_set(next, instance, create_info, action_set)
+    });
+    if result.into_raw() >= 0 {
+        super::created::<Instance, ActionSet>(instance.into_raw(), (*action_set).into_raw());
+    }
+    result
+}
+unsafe extern "system" fn destroy_action_set(action_set: ActionSet) -> Result {
+    let result = super::call::<ActionSet>(action_set.into_raw(), |this, next| {
+        this.destroy_action_set(next, action_set)
+    });
+    if result.into_raw() >= 0 {
+        super::destroyed::<ActionSet>(action_set.into_raw());
+    }
+    result
+}
+unsafe extern "system" fn create_action(
+    action_set: ActionSet,
+    create_info: *const ActionCreateInfo,
+    action: *mut Action,
+) -> Result {
+    let result = super::call::<ActionSet>(action_set.into_raw(), |this, next| {
+        this.create_action(next, action_set, create_info, action)
+    });
+    if result.into_raw() >= 0 {
+        super::created::<ActionSet, Action>(action_set.into_raw(), (*action).into_raw());
+    }
+    result
+}
+unsafe extern "system" fn destroy_action(action: Action) -> Result {
+    let result = super::call::<Action>(action.into_raw(), |this, next| {
+        this.destroy_action(next, action)
+    });
+    if result.into_raw() >= 0 {
+        super::destroyed::<Action>(action.into_raw());
+    }
+    result
+}
+unsafe extern "system" fn suggest_interaction_profile_bindings(
+    instance: Instance,
+    suggested_bindings: *const InteractionProfileSuggestedBinding,
+) -> Result {
+    super::call::<Instance>(instance.into_raw(), |this, next| {
+        this.suggest_interaction_profile_bindings(next, instance, suggested_bindings)
+    })
+}
+unsafe extern "system" fn attach_session_action_sets(
+    session: Session,
+    attach_info: *const SessionActionSetsAttachInfo,
+) -> Result {
+    super::call::<Session>(session.into_raw(), |this, next| {
+        this.attach_session_action_sets(next, session, attach_info)
+    })
+}
+unsafe extern "system" fn get_current_interaction_profile(
+    session: Session,
+    top_level_user_path: Path,
+    interaction_profile: *mut InteractionProfileState,
+) -> Result {
+    super::call::<Session>(session.into_raw(), |this, next| {
+        this.get_current_interaction_profile(
+            next,
+            session,
+            top_level_user_path,
+            interaction_profile,
+        )
+    })
+}
+unsafe extern "system" fn sync_actions(
+    session: Session,
+    sync_info: *const ActionsSyncInfo,
+) -> Result {
+    super::call::<Session>(session.into_raw(), |this, next| {
+        this.sync_actions(next, session, sync_info)
+    })
+}
+unsafe extern "system" fn enumerate_bound_sources_for_action(
+    session: Session,
+    enumerate_info: *const BoundSourcesForActionEnumerateInfo,
+    source_capacity_input: u32,
+    source_count_output: *mut u32,
+    sources: *mut Path,
+) -> Result {
+    super::call::<Session>(session.into_raw(), |this, next| {
+        this.enumerate_bound_sources_for_action(
+            next,
+            session,
+            enumerate_info,
+            source_capacity_input,
+            source_count_output,
+            sources,
+        )
+    })
+}
+unsafe extern "system" fn get_input_source_localized_name(
+    session: Session,
+    get_info: *const InputSourceLocalizedNameGetInfo,
+    buffer_capacity_input: u32,
+    buffer_count_output: *mut u32,
+    buffer: *mut c_char,
+) -> Result {
+    super::call::<Session>(session.into_raw(), |this, next| {
+        this.get_input_source_localized_name(
+            next,
+            session,
+            get_info,
+            buffer_capacity_input,
+            buffer_count_output,
+            buffer,
+        )
+    })
+}
+unsafe extern "system" fn locate_spaces(
+    session: Session,
+    locate_info: *const SpacesLocateInfo,
+    space_locations: *mut SpaceLocations,
+) -> Result {
+    super::call::<Session>(session.into_raw(), |this, next| {
+        this.locate_spaces(next, session, locate_info, space_locations)
+    })
+}
+unsafe extern "system" fn get_vulkan_instance_extensions_khr(
+    instance: Instance,
+    system_id: SystemId,
+    buffer_capacity_input: u32,
+    buffer_count_output: *mut u32,
+    buffer: *mut c_char,
+) -> Result {
+    super::call::<Instance>(instance.into_raw(), |this, next| {
+        this.get_vulkan_instance_extensions_khr(
+            next,
+            instance,
+            system_id,
+            buffer_capacity_input,
+            buffer_count_output,
+            buffer,
+        )
+    })
+}
+unsafe extern "system" fn get_vulkan_device_extensions_khr(
+    instance: Instance,
+    system_id: SystemId,
+    buffer_capacity_input: u32,
+    buffer_count_output: *mut u32,
+    buffer: *mut c_char,
+) -> Result {
+    super::call::<Instance>(instance.into_raw(), |this, next| {
+        this.get_vulkan_device_extensions_khr(
+            next,
+            instance,
+            system_id,
+            buffer_capacity_input,
+            buffer_count_output,
+            buffer,
+        )
+    })
+}
+unsafe extern "system" fn get_vulkan_graphics_device_khr(
+    instance: Instance,
+    system_id: SystemId,
+    vk_instance: VkInstance,
+    vk_physical_device: *mut VkPhysicalDevice,
+) -> Result {
+    super::call::<Instance>(instance.into_raw(), |this, next| {
+        this.get_vulkan_graphics_device_khr(
+            next,
+            instance,
+            system_id,
+            vk_instance,
+            vk_physical_device,
+        )
+    })
+}
+unsafe extern "system" fn get_open_gl_graphics_requirements_khr(
+    instance: Instance,
+    system_id: SystemId,
+    graphics_requirements: *mut GraphicsRequirementsOpenGLKHR,
+) -> Result {
+    super::call::<Instance>(instance.into_raw(), |this, next| {
+        this.get_open_gl_graphics_requirements_khr(next, instance, system_id, graphics_requirements)
+    })
+}
+unsafe extern "system" fn get_open_gles_graphics_requirements_khr(
+    instance: Instance,
+    system_id: SystemId,
+    graphics_requirements: *mut GraphicsRequirementsOpenGLESKHR,
+) -> Result {
+    super::call::<Instance>(instance.into_raw(), |this, next| {
+        this.get_open_gles_graphics_requirements_khr(
+            next,
+            instance,
+            system_id,
+            graphics_requirements,
+        )
+    })
+}
+unsafe extern "system" fn get_vulkan_graphics_requirements_khr(
+    instance: Instance,
+    system_id: SystemId,
+    graphics_requirements: *mut GraphicsRequirementsVulkanKHR,
+) -> Result {
+    super::call::<Instance>(instance.into_raw(), |this, next| {
+        this.get_vulkan_graphics_requirements_khr(next, instance, system_id, graphics_requirements)
+    })
+}
+#[cfg(windows)]
+unsafe extern "system" fn get_d3d11_graphics_requirements_khr(
+    instance: Instance,
+    system_id: SystemId,
+    graphics_requirements: *mut GraphicsRequirementsD3D11KHR,
+) -> Result {
+    super::call::<Instance>(instance.into_raw(), |this, next| {
+        this.get_d3d11_graphics_requirements_khr(next, instance, system_id, graphics_requirements)
+    })
+}
+#[cfg(windows)]
+unsafe extern "system" fn get_d3d12_graphics_requirements_khr(
+    instance: Instance,
+    system_id: SystemId,
+    graphics_requirements: *mut GraphicsRequirementsD3D12KHR,
+) -> Result {
+    super::call::<Instance>(instance.into_raw(), |this, next| {
+        this.get_d3d12_graphics_requirements_khr(next, instance, system_id, graphics_requirements)
+    })
+}
+unsafe extern "system" fn perf_settings_set_performance_level_ext(
+    session: Session,
+    domain: PerfSettingsDomainEXT,
+    level: PerfSettingsLevelEXT,
+) -> Result {
+    super::call::<Session>(session.into_raw(), |this, next| {
+        this.perf_settings_set_performance_level_ext(next, session, domain, level)
+    })
+}
+unsafe extern "system" fn thermal_get_temperature_trend_ext(
+    session: Session,
+    domain: PerfSettingsDomainEXT,
+    notification_level: *mut PerfSettingsNotificationLevelEXT,
+    temp_headroom: *mut f32,
+    temp_slope: *mut f32,
+) -> Result {
+    super::call::<Session>(session.into_raw(), |this, next| {
+        this.thermal_get_temperature_trend_ext(
+            next,
+            session,
+            domain,
+            notification_level,
+            temp_headroom,
+            temp_slope,
+        )
+    })
+}
+unsafe extern "system" fn set_debug_utils_object_name_ext(
+    instance: Instance,
+    name_info: *const DebugUtilsObjectNameInfoEXT,
+) -> Result {
+    super::call::<Instance>(instance.into_raw(), |this, next| {
+        this.set_debug_utils_object_name_ext(next, instance, name_info)
+    })
+}
+unsafe extern "system" fn create_debug_utils_messenger_ext(
+    instance: Instance,
+    create_info: *const DebugUtilsMessengerCreateInfoEXT,
+    messenger: *mut DebugUtilsMessengerEXT,
+) -> Result {
+    let result = super::call::<Instance>(instance.into_raw(), |this, next| {
+        this.create_debug_utils_messenger_ext(next, instance, create_info, messenger)
+    });
+    if result.into_raw() >= 0 {
+        super::created::<Instance, DebugUtilsMessengerEXT>(
+            instance.into_raw(),
+            (*messenger).into_raw(),
+        );
+    }
+    result
+}
+unsafe extern "system" fn destroy_debug_utils_messenger_ext(
+    messenger: DebugUtilsMessengerEXT,
+) -> Result {
+    let result = super::call::<DebugUtilsMessengerEXT>(messenger.into_raw(), |this, next| {
+        this.destroy_debug_utils_messenger_ext(next, messenger)
+    });
+    if result.into_raw() >= 0 {
+        super::destroyed::<DebugUtilsMessengerEXT>(messenger.into_raw());
+    }
+    result
+}
+unsafe extern "system" fn submit_debug_utils_message_ext(
+    instance: Instance,
+    message_severity: DebugUtilsMessageSeverityFlagsEXT,
+    message_types: DebugUtilsMessageTypeFlagsEXT,
+    callback_data: *const DebugUtilsMessengerCallbackDataEXT,
+) -> Result {
+    super::call::<Instance>(instance.into_raw(), |this, next| {
+        this.submit_debug_utils_message_ext(
+            next,
+            instance,
+            message_severity,
+            message_types,
+            callback_data,
+        )
+    })
+}
+unsafe extern "system" fn session_begin_debug_utils_label_region_ext(
+    session: Session,
+    label_info: *const DebugUtilsLabelEXT,
+) -> Result {
+    super::call::<Session>(session.into_raw(), |this, next| {
+        this.session_begin_debug_utils_label_region_ext(next, session, label_info)
+    })
+}
+unsafe extern "system" fn session_end_debug_utils_label_region_ext(session: Session) -> Result {
+    super::call::<Session>(session.into_raw(), |this, next| {
+        this.session_end_debug_utils_label_region_ext(next, session)
+    })
+}
+unsafe extern "system" fn session_insert_debug_utils_label_ext(
+    session: Session,
+    label_info: *const DebugUtilsLabelEXT,
+) -> Result {
+    super::call::<Session>(session.into_raw(), |this, next| {
+        this.session_insert_debug_utils_label_ext(next, session, label_info)
+    })
+}
+#[cfg(windows)]
+unsafe extern "system" fn convert_time_to_win32_performance_counter_khr(
+    instance: Instance,
+    time: Time,
+    performance_counter: *mut LARGE_INTEGER,
+) -> Result {
+    super::call::<Instance>(instance.into_raw(), |this, next| {
+        this.convert_time_to_win32_performance_counter_khr(
+            next,
+            instance,
+            time,
+            performance_counter,
+        )
+    })
+}
+#[cfg(windows)]
+unsafe extern "system" fn convert_win32_performance_counter_to_time_khr(
+    instance: Instance,
+    performance_counter: *const LARGE_INTEGER,
+    time: *mut Time,
+) -> Result {
+    super::call::<Instance>(instance.into_raw(), |this, next| {
+        this.convert_win32_performance_counter_to_time_khr(
+            next,
+            instance,
+            performance_counter,
+            time,
+        )
+    })
+}
+unsafe extern "system" fn create_vulkan_instance_khr(
+    instance: Instance,
+    create_info: *const VulkanInstanceCreateInfoKHR,
+    vulkan_instance: *mut VkInstance,
+    vulkan_result: *mut VkResult,
+) -> Result {
+    super::call::<Instance>(instance.into_raw(), |this, next| {
+        this.create_vulkan_instance_khr(next, instance, create_info, vulkan_instance, vulkan_result)
+    })
+}
+unsafe extern "system" fn create_vulkan_device_khr(
+    instance: Instance,
+    create_info: *const VulkanDeviceCreateInfoKHR,
+    vulkan_device: *mut VkDevice,
+    vulkan_result: *mut VkResult,
+) -> Result {
+    super::call::<Instance>(instance.into_raw(), |this, next| {
+        this.create_vulkan_device_khr(next, instance, create_info, vulkan_device, vulkan_result)
+    })
+}
+unsafe extern "system" fn get_vulkan_graphics_device2_khr(
+    instance: Instance,
+    get_info: *const VulkanGraphicsDeviceGetInfoKHR,
+    vulkan_physical_device: *mut VkPhysicalDevice,
+) -> Result {
+    super::call::<Instance>(instance.into_raw(), |this, next| {
+        this.get_vulkan_graphics_device2_khr(next, instance, get_info, vulkan_physical_device)
+    })
+}
+unsafe extern "system" fn convert_time_to_timespec_time_khr(
+    instance: Instance,
+    time: Time,
+    timespec_time: *mut timespec,
+) -> Result {
+    super::call::<Instance>(instance.into_raw(), |this, next| {
+        this.convert_time_to_timespec_time_khr(next, instance, time, timespec_time)
+    })
+}
+unsafe extern "system" fn convert_timespec_time_to_time_khr(
+    instance: Instance,
+    timespec_time: *const timespec,
+    time: *mut Time,
+) -> Result {
+    super::call::<Instance>(instance.into_raw(), |this, next| {
+        this.convert_timespec_time_to_time_khr(next, instance, timespec_time, time)
+    })
+}
+unsafe extern "system" fn get_visibility_mask_khr(
+    session: Session,
+    view_configuration_type: ViewConfigurationType,
+    view_index: u32,
+    visibility_mask_type: VisibilityMaskTypeKHR,
+    visibility_mask: *mut VisibilityMaskKHR,
+) -> Result {
+    super::call::<Session>(session.into_raw(), |this, next| {
+        this.get_visibility_mask_khr(
+            next,
+            session,
+            view_configuration_type,
+            view_index,
+            visibility_mask_type,
+            visibility_mask,
+        )
+    })
+}
+unsafe extern "system" fn create_spatial_anchor_msft(
+    session: Session,
+    create_info: *const SpatialAnchorCreateInfoMSFT,
+    anchor: *mut SpatialAnchorMSFT,
+) -> Result {
+    let result = super::call::<Session>(session.into_raw(), |this, next| {
+        this.create_spatial_anchor_msft(next, session, create_info, anchor)
+    });
+    if result.into_raw() >= 0 {
+        super::created::<Session, SpatialAnchorMSFT>(session.into_raw(), (*anchor).into_raw());
+    }
+    result
+}
+unsafe extern "system" fn create_spatial_anchor_space_msft(
+    session: Session,
+    create_info: *const SpatialAnchorSpaceCreateInfoMSFT,
+    space: *mut Space,
+) -> Result {
+    let result = super::call::<Session>(session.into_raw(), |this, next| {
+        this.create_spatial_anchor_space_msft(next, session, create_info, space)
+    });
+    if result.into_raw() >= 0 {
+        super::created::<Session, Space>(session.into_raw(), (*space).into_raw());
+    }
+    result
+}
+unsafe extern "system" fn destroy_spatial_anchor_msft(anchor: SpatialAnchorMSFT) -> Result {
+    let result = super::call::<SpatialAnchorMSFT>(anchor.into_raw(), |this, next| {
+        this.destroy_spatial_anchor_msft(next, anchor)
+    });
+    if result.into_raw() >= 0 {
+        super::destroyed::<SpatialAnchorMSFT>(anchor.into_raw());
+    }
+    result
+}
+unsafe extern "system" fn set_input_device_active_ext(
+    session: Session,
+    interaction_profile: Path,
+    top_level_path: Path,
+    is_active: Bool32,
+) -> Result {
+    super::call::<Session>(session.into_raw(), |this, next| {
+        this.set_input_device_active_ext(
+            next,
+            session,
+            interaction_profile,
+            top_level_path,
+            is_active,
+        )
+    })
+}
+unsafe extern "system" fn set_input_device_state_bool_ext(
+    session: Session,
+    top_level_path: Path,
+    input_source_path: Path,
+    state: Bool32,
+) -> Result {
+    super::call::<Session>(session.into_raw(), |this, next| {
+        this.set_input_device_state_bool_ext(
+            next,
+            session,
+            top_level_path,
+            input_source_path,
+            state,
+        )
+    })
+}
+unsafe extern "system" fn set_input_device_state_float_ext(
+    session: Session,
+    top_level_path: Path,
+    input_source_path: Path,
+    state: f32,
+) -> Result {
+    super::call::<Session>(session.into_raw(), |this, next| {
+        this.set_input_device_state_float_ext(
+            next,
+            session,
+            top_level_path,
+            input_source_path,
+            state,
+        )
+    })
+}
+unsafe extern "system" fn set_input_device_state_vector2f_ext(
+    session: Session,
+    top_level_path: Path,
+    input_source_path: Path,
+    state: Vector2f,
+) -> Result {
+    super::call::<Session>(session.into_raw(), |this, next| {
+        this.set_input_device_state_vector2f_ext(
+            next,
+            session,
+            top_level_path,
+            input_source_path,
+            state,
+        )
+    })
+}
+unsafe extern "system" fn set_input_device_location_ext(
+    session: Session,
+    top_level_path: Path,
+    input_source_path: Path,
+    space: Space,
+    pose: Posef,
+) -> Result {
+    super::call::<Session>(session.into_raw(), |this, next| {
+        this.set_input_device_location_ext(
+            next,
+            session,
+            top_level_path,
+            input_source_path,
+            space,
+            pose,
+        )
+    })
+}
+unsafe extern "system" fn create_spatial_graph_node_space_msft(
+    session: Session,
+    create_info: *const SpatialGraphNodeSpaceCreateInfoMSFT,
+    space: *mut Space,
+) -> Result {
+    let result = super::call::<Session>(session.into_raw(), |this, next| {
+        this.create_spatial_graph_node_space_msft(next, session, create_info, space)
+    });
+    if result.into_raw() >= 0 {
+        super::created::<Session, Space>(session.into_raw(), (*space).into_raw());
+    }
+    result
+}
+unsafe extern "system" fn try_create_spatial_graph_static_node_binding_msft(
+    session: Session,
+    create_info: *const SpatialGraphStaticNodeBindingCreateInfoMSFT,
+    node_binding: *mut SpatialGraphNodeBindingMSFT,
+) -> Result {
+    let result = super::call::<Session>(session.into_raw(), |this, next| {
+        this.try_create_spatial_graph_static_node_binding_msft(
+            next,
+            session,
+            create_info,
+            node_binding,
+        )
+    });
+    if result.into_raw() >= 0 {
+        super::created::<Session, SpatialGraphNodeBindingMSFT>(
+            session.into_raw(),
+            (*node_binding).into_raw(),
+        );
+    }
+    result
+}
+unsafe extern "system" fn destroy_spatial_graph_node_binding_msft(
+    node_binding: SpatialGraphNodeBindingMSFT,
+) -> Result {
+    let result =
+        super::call::<SpatialGraphNodeBindingMSFT>(node_binding.into_raw(), |this, next| {
+            this.destroy_spatial_graph_node_binding_msft(next, node_binding)
+        });
+    if result.into_raw() >= 0 {
+        super::destroyed::<SpatialGraphNodeBindingMSFT>(node_binding.into_raw());
+    }
+    result
+}
+unsafe extern "system" fn get_spatial_graph_node_binding_properties_msft(
+    node_binding: SpatialGraphNodeBindingMSFT,
+    get_info: *const SpatialGraphNodeBindingPropertiesGetInfoMSFT,
+    properties: *mut SpatialGraphNodeBindingPropertiesMSFT,
+) -> Result {
+    super::call::<SpatialGraphNodeBindingMSFT>(node_binding.into_raw(), |this, next| {
+        this.get_spatial_graph_node_binding_properties_msft(
+            next,
+            node_binding,
+            get_info,
+            properties,
+        )
+    })
+}
+unsafe extern "system" fn create_hand_tracker_ext(
+    session: Session,
+    create_info: *const HandTrackerCreateInfoEXT,
+    hand_tracker: *mut HandTrackerEXT,
+) -> Result {
+    let result = super::call::<Session>(session.into_raw(), |this, next| {
+        this.create_hand_tracker_ext(next, session, create_info, hand_tracker)
+    });
+    if result.into_raw() >= 0 {
+        super::created::<Session, HandTrackerEXT>(session.into_raw(), (*hand_tracker).into_raw());
+    }
+    result
+}
+unsafe extern "system" fn destroy_hand_tracker_ext(hand_tracker: HandTrackerEXT) -> Result {
+    let result = super::call::<HandTrackerEXT>(hand_tracker.into_raw(), |this, next| {
+        this.destroy_hand_tracker_ext(next, hand_tracker)
+    });
+    if result.into_raw() >= 0 {
+        super::destroyed::<HandTrackerEXT>(hand_tracker.into_raw());
+    }
+    result
+}
+unsafe extern "system" fn locate_hand_joints_ext(
+    hand_tracker: HandTrackerEXT,
+    locate_info: *const HandJointsLocateInfoEXT,
+    locations: *mut HandJointLocationsEXT,
+) -> Result {
+    super::call::<HandTrackerEXT>(hand_tracker.into_raw(), |this, next| {
+        this.locate_hand_joints_ext(next, hand_tracker, locate_info, locations)
+    })
+}
+unsafe extern "system" fn create_face_tracker_fb(
+    session: Session,
+    create_info: *const FaceTrackerCreateInfoFB,
+    face_tracker: *mut FaceTrackerFB,
+) -> Result {
+    let result = super::call::<Session>(session.into_raw(), |this, next| {
+        this.create_face_tracker_fb(next, session, create_info, face_tracker)
+    });
+    if result.into_raw() >= 0 {
+        super::created::<Session, FaceTrackerFB>(session.into_raw(), (*face_tracker).into_raw());
+    }
+    result
+}
+unsafe extern "system" fn destroy_face_tracker_fb(face_tracker: FaceTrackerFB) -> Result {
+    let result = super::call::<FaceTrackerFB>(face_tracker.into_raw(), |this, next| {
+        this.destroy_face_tracker_fb(next, face_tracker)
+    });
+    if result.into_raw() >= 0 {
+        super::destroyed::<FaceTrackerFB>(face_tracker.into_raw());
+    }
+    result
+}
+unsafe extern "system" fn get_face_expression_weights_fb(
+    face_tracker: FaceTrackerFB,
+    expression_info: *const FaceExpressionInfoFB,
+    expression_weights: *mut FaceExpressionWeightsFB,
+) -> Result {
+    super::call::<FaceTrackerFB>(face_tracker.into_raw(), |this, next| {
+        this.get_face_expression_weights_fb(next, face_tracker, expression_info, expression_weights)
+    })
+}
+unsafe extern "system" fn create_body_tracker_fb(
+    session: Session,
+    create_info: *const BodyTrackerCreateInfoFB,
+    body_tracker: *mut BodyTrackerFB,
+) -> Result {
+    let result = super::call::<Session>(session.into_raw(), |this, next| {
+        this.create_body_tracker_fb(next, session, create_info, body_tracker)
+    });
+    if result.into_raw() >= 0 {
+        super::created::<Session, BodyTrackerFB>(session.into_raw(), (*body_tracker).into_raw());
+    }
+    result
+}
+unsafe extern "system" fn destroy_body_tracker_fb(body_tracker: BodyTrackerFB) -> Result {
+    let result = super::call::<BodyTrackerFB>(body_tracker.into_raw(), |this, next| {
+        this.destroy_body_tracker_fb(next, body_tracker)
+    });
+    if result.into_raw() >= 0 {
+        super::destroyed::<BodyTrackerFB>(body_tracker.into_raw());
+    }
+    result
+}
+unsafe extern "system" fn locate_body_joints_fb(
+    body_tracker: BodyTrackerFB,
+    locate_info: *const BodyJointsLocateInfoFB,
+    locations: *mut BodyJointLocationsFB,
+) -> Result {
+    super::call::<BodyTrackerFB>(body_tracker.into_raw(), |this, next| {
+        this.locate_body_joints_fb(next, body_tracker, locate_info, locations)
+    })
+}
+unsafe extern "system" fn get_body_skeleton_fb(
+    body_tracker: BodyTrackerFB,
+    skeleton: *mut BodySkeletonFB,
+) -> Result {
+    super::call::<BodyTrackerFB>(body_tracker.into_raw(), |this, next| {
+        this.get_body_skeleton_fb(next, body_tracker, skeleton)
+    })
+}
+unsafe extern "system" fn create_eye_tracker_fb(
+    session: Session,
+    create_info: *const EyeTrackerCreateInfoFB,
+    eye_tracker: *mut EyeTrackerFB,
+) -> Result {
+    let result = super::call::<Session>(session.into_raw(), |this, next| {
+        this.create_eye_tracker_fb(next, session, create_info, eye_tracker)
+    });
+    if result.into_raw() >= 0 {
+        super::created::<Session, EyeTrackerFB>(session.into_raw(), (*eye_tracker).into_raw());
+    }
+    result
+}
+unsafe extern "system" fn destroy_eye_tracker_fb(eye_tracker: EyeTrackerFB) -> Result {
+    let result = super::call::<EyeTrackerFB>(eye_tracker.into_raw(), |this, next| {
+        this.destroy_eye_tracker_fb(next, eye_tracker)
+    });
+    if result.into_raw() >= 0 {
+        super::destroyed::<EyeTrackerFB>(eye_tracker.into_raw());
+    }
+    result
+}
+unsafe extern "system" fn get_eye_gazes_fb(
+    eye_tracker: EyeTrackerFB,
+    gaze_info: *const EyeGazesInfoFB,
+    eye_gazes: *mut EyeGazesFB,
+) -> Result {
+    super::call::<EyeTrackerFB>(eye_tracker.into_raw(), |this, next| {
+        this.get_eye_gazes_fb(next, eye_tracker, gaze_info, eye_gazes)
+    })
+}
+unsafe extern "system" fn create_hand_mesh_space_msft(
+    hand_tracker: HandTrackerEXT,
+    create_info: *const HandMeshSpaceCreateInfoMSFT,
+    space: *mut Space,
+) -> Result {
+    let result = super::call::<HandTrackerEXT>(hand_tracker.into_raw(), |this, next| {
+        this.create_hand_mesh_space_msft(next, hand_tracker, create_info, space)
+    });
+    if result.into_raw() >= 0 {
+        super::created::<HandTrackerEXT, Space>(hand_tracker.into_raw(), (*space).into_raw());
+    }
+    result
+}
+unsafe extern "system" fn update_hand_mesh_msft(
+    hand_tracker: HandTrackerEXT,
+    update_info: *const HandMeshUpdateInfoMSFT,
+    hand_mesh: *mut HandMeshMSFT,
+) -> Result {
+    super::call::<HandTrackerEXT>(hand_tracker.into_raw(), |this, next| {
+        this.update_hand_mesh_msft(next, hand_tracker, update_info, hand_mesh)
+    })
+}
+unsafe extern "system" fn get_controller_model_key_msft(
+    session: Session,
+    top_level_user_path: Path,
+    controller_model_key_state: *mut ControllerModelKeyStateMSFT,
+) -> Result {
+    super::call::<Session>(session.into_raw(), |this, next| {
+        this.get_controller_model_key_msft(
+            next,
+            session,
+            top_level_user_path,
+            controller_model_key_state,
+        )
+    })
+}
+unsafe extern "system" fn load_controller_model_msft(
+    session: Session,
+    model_key: ControllerModelKeyMSFT,
+    buffer_capacity_input: u32,
+    buffer_count_output: *mut u32,
+    buffer: *mut u8,
+) -> Result {
+    super::call::<Session>(session.into_raw(), |this, next| {
+        this.load_controller_model_msft(
+            next,
+            session,
+            model_key,
+            buffer_capacity_input,
+            buffer_count_output,
+            buffer,
+        )
+    })
+}
+unsafe extern "system" fn get_controller_model_properties_msft(
+    session: Session,
+    model_key: ControllerModelKeyMSFT,
+    properties: *mut ControllerModelPropertiesMSFT,
+) -> Result {
+    super::call::<Session>(session.into_raw(), |this, next| {
+        this.get_controller_model_properties_msft(next, session, model_key, properties)
+    })
+}
+unsafe extern "system" fn get_controller_model_state_msft(
+    session: Session,
+    model_key: ControllerModelKeyMSFT,
+    state: *mut ControllerModelStateMSFT,
+) -> Result {
+    super::call::<Session>(session.into_raw(), |this, next| {
+        this.get_controller_model_state_msft(next, session, model_key, state)
+    })
+}
+unsafe extern "system" fn enumerate_display_refresh_rates_fb(
+    session: Session,
+    display_refresh_rate_capacity_input: u32,
+    display_refresh_rate_count_output: *mut u32,
+    display_refresh_rates: *mut f32,
+) -> Result {
+    super::call::<Session>(session.into_raw(), |this, next| {
+        this.enumerate_display_refresh_rates_fb(
+            next,
+            session,
+            display_refresh_rate_capacity_input,
+            display_refresh_rate_count_output,
+            display_refresh_rates,
+        )
+    })
+}
+unsafe extern "system" fn get_display_refresh_rate_fb(
+    session: Session,
+    display_refresh_rate: *mut f32,
+) -> Result {
+    super::call::<Session>(session.into_raw(), |this, next| {
+        this.get_display_refresh_rate_fb(next, session, display_refresh_rate)
+    })
+}
+unsafe extern "system" fn request_display_refresh_rate_fb(
+    session: Session,
+    display_refresh_rate: f32,
+) -> Result {
+    super::call::<Session>(session.into_raw(), |this, next| {
+        this.request_display_refresh_rate_fb(next, session, display_refresh_rate)
+    })
+}
+#[cfg(windows)]
+unsafe extern "system" fn create_spatial_anchor_from_perception_anchor_msft(
+    session: Session,
+    perception_anchor: *mut IUnknown,
+    anchor: *mut SpatialAnchorMSFT,
+) -> Result {
+    let result = super::call::<Session>(session.into_raw(), |this, next| {
+        this.create_spatial_anchor_from_perception_anchor_msft(
+            next,
+            session,
+            perception_anchor,
+            anchor,
+        )
+    });
+    if result.into_raw() >= 0 {
+        super::created::<Session, SpatialAnchorMSFT>(session.into_raw(), (*anchor).into_raw());
+    }
+    result
+}
+#[cfg(windows)]
+unsafe extern "system" fn try_get_perception_anchor_from_spatial_anchor_msft(
+    session: Session,
+    anchor: SpatialAnchorMSFT,
+    perception_anchor: *mut *mut IUnknown,
+) -> Result {
+    super::call::<Session>(session.into_raw(), |this, next| {
+        this.try_get_perception_anchor_from_spatial_anchor_msft(
+            next,
+            session,
+            anchor,
+            perception_anchor,
+        )
+    })
+}
+unsafe extern "system" fn update_swapchain_fb(
+    swapchain: Swapchain,
+    state: *const SwapchainStateBaseHeaderFB,
+) -> Result {
+    super::call::<Swapchain>(swapchain.into_raw(), |this, next| {
+        this.update_swapchain_fb(next, swapchain, state)
+    })
+}
+unsafe extern "system" fn get_swapchain_state_fb(
+    swapchain: Swapchain,
+    state: *mut SwapchainStateBaseHeaderFB,
+) -> Result {
+    super::call::<Swapchain>(swapchain.into_raw(), |this, next| {
+        this.get_swapchain_state_fb(next, swapchain, state)
+    })
+}
+unsafe extern "system" fn enumerate_color_spaces_fb(
+    session: Session,
+    color_space_capacity_input: u32,
+    color_space_count_output: *mut u32,
+    color_spaces: *mut ColorSpaceFB,
+) -> Result {
+    super::call::<Session>(session.into_raw(), |this, next| {
+        this.enumerate_color_spaces_fb(
+            next,
+            session,
+            color_space_capacity_input,
+            color_space_count_output,
+            color_spaces,
+        )
+    })
+}
+unsafe extern "system" fn set_color_space_fb(session: Session, colorspace: ColorSpaceFB) -> Result {
+    super::call::<Session>(session.into_raw(), |this, next| {
+        this.set_color_space_fb(next, session, colorspace)
+    })
+}
+unsafe extern "system" fn create_foveation_profile_fb(
+    session: Session,
+    create_info: *const FoveationProfileCreateInfoFB,
+    profile: *mut FoveationProfileFB,
+) -> Result {
+    let result = super::call::<Session>(session.into_raw(), |this, next| {
+        this.create_foveation_profile_fb(next, session, create_info, profile)
+    });
+    if result.into_raw() >= 0 {
+        super::created::<Session, FoveationProfileFB>(session.into_raw(), (*profile).into_raw());
+    }
+    result
+}
+unsafe extern "system" fn destroy_foveation_profile_fb(profile: FoveationProfileFB) -> Result {
+    let result = super::call::<FoveationProfileFB>(profile.into_raw(), |this, next| {
+        this.destroy_foveation_profile_fb(next, profile)
+    });
+    if result.into_raw() >= 0 {
+        super::destroyed::<FoveationProfileFB>(profile.into_raw());
+    }
+    result
+}
+unsafe extern "system" fn get_foveation_eye_tracked_state_meta(
+    session: Session,
+    foveation_state: *mut FoveationEyeTrackedStateMETA,
+) -> Result {
+    super::call::<Session>(session.into_raw(), |this, next| {
+        this.get_foveation_eye_tracked_state_meta(next, session, foveation_state)
+    })
+}
+unsafe extern "system" fn get_hand_mesh_fb(
+    hand_tracker: HandTrackerEXT,
+    mesh: *mut HandTrackingMeshFB,
+) -> Result {
+    super::call::<HandTrackerEXT>(hand_tracker.into_raw(), |this, next| {
+        this.get_hand_mesh_fb(next, hand_tracker, mesh)
+    })
+}
+unsafe extern "system" fn enumerate_render_model_paths_fb(
+    session: Session,
+    path_capacity_input: u32,
+    path_count_output: *mut u32,
+    paths: *mut RenderModelPathInfoFB,
+) -> Result {
+    super::call::<Session>(session.into_raw(), |this, next| {
+        this.enumerate_render_model_paths_fb(
+            next,
+            session,
+            path_capacity_input,
+            path_count_output,
+            paths,
+        )
+    })
+}
+unsafe extern "system" fn get_render_model_properties_fb(
+    session: Session,
+    path: Path,
+    properties: *mut RenderModelPropertiesFB,
+) -> Result {
+    super::call::<Session>(session.into_raw(), |this, next| {
+        this.get_render_model_properties_fb(next, session, path, properties)
+    })
+}
+unsafe extern "system" fn load_render_model_fb(
+    session: Session,
+    info: *const RenderModelLoadInfoFB,
+    buffer: *mut RenderModelBufferFB,
+) -> Result {
+    super::call::<Session>(session.into_raw(), |this, next| {
+        this.load_render_model_fb(next, session, info, buffer)
+    })
+}
+unsafe extern "system" fn query_system_tracked_keyboard_fb(
+    session: Session,
+    query_info: *const KeyboardTrackingQueryFB,
+    keyboard: *mut KeyboardTrackingDescriptionFB,
+) -> Result {
+    super::call::<Session>(session.into_raw(), |this, next| {
+        this.query_system_tracked_keyboard_fb(next, session, query_info, keyboard)
+    })
+}
+unsafe extern "system" fn create_keyboard_space_fb(
+    session: Session,
+    create_info: *const KeyboardSpaceCreateInfoFB,
+    keyboard_space: *mut Space,
+) -> Result {
+    let result = super::call::<Session>(session.into_raw(), |this, next| {
+        this.create_keyboard_space_fb(next, session, create_info, keyboard_space)
+    });
+    if result.into_raw() >= 0 {
+        super::created::<Session, Space>(session.into_raw(), (*keyboard_space).into_raw());
+    }
+    result
+}
+unsafe extern "system" fn set_environment_depth_estimation_varjo(
+    session: Session,
+    enabled: Bool32,
+) -> Result {
+    super::call::<Session>(session.into_raw(), |this, next| {
+        this.set_environment_depth_estimation_varjo(next, session, enabled)
+    })
+}
+unsafe extern "system" fn enumerate_reprojection_modes_msft(
+    instance: Instance,
+    system_id: SystemId,
+    view_configuration_type: ViewConfigurationType,
+    mode_capacity_input: u32,
+    mode_count_output: *mut u32,
+    modes: *mut ReprojectionModeMSFT,
+) -> Result {
+    super::call::<Instance>(instance.into_raw(), |this, next| {
+        this.enumerate_reprojection_modes_msft(
+            next,
+            instance,
+            system_id,
+            view_configuration_type,
+            mode_capacity_input,
+            mode_count_output,
+            modes,
+        )
+    })
+}
+unsafe extern "system" fn get_audio_output_device_guid_oculus(
+    instance: Instance,
+    buffer: *mut wchar_t,
+) -> Result {
+    super::call::<Instance>(instance.into_raw(), |this, next| {
+        this.get_audio_output_device_guid_oculus(next, instance, buffer)
+    })
+}
+unsafe extern "system" fn get_audio_input_device_guid_oculus(
+    instance: Instance,
+    buffer: *mut wchar_t,
+) -> Result {
+    super::call::<Instance>(instance.into_raw(), |this, next| {
+        this.get_audio_input_device_guid_oculus(next, instance, buffer)
+    })
+}
+unsafe extern "system" fn create_spatial_anchor_fb(
+    session: Session,
+    info: *const SpatialAnchorCreateInfoFB,
+    request_id: *mut AsyncRequestIdFB,
+) -> Result {
+    super::call::<Session>(session.into_raw(), |this, next| {
+        this.create_spatial_anchor_fb(next, session, info, request_id)
+    })
+}
+unsafe extern "system" fn get_space_uuid_fb(space: Space, uuid: *mut UuidEXT) -> Result {
+    super::call::<Space>(space.into_raw(), |this, next| {
+        this.get_space_uuid_fb(next, space, uuid)
+    })
+}
+unsafe extern "system" fn enumerate_space_supported_components_fb(
+    space: Space,
+    component_type_capacity_input: u32,
+    component_type_count_output: *mut u32,
+    component_types: *mut SpaceComponentTypeFB,
+) -> Result {
+    super::call::<Space>(space.into_raw(), |this, next| {
+        this.enumerate_space_supported_components_fb(
+            next,
+            space,
+            component_type_capacity_input,
+            component_type_count_output,
+            component_types,
+        )
+    })
+}
+unsafe extern "system" fn set_space_component_status_fb(
+    space: Space,
+    info: *const SpaceComponentStatusSetInfoFB,
+    request_id: *mut AsyncRequestIdFB,
+) -> Result {
+    super::call::<Space>(space.into_raw(), |this, next| {
+        this.set_space_component_status_fb(next, space, info, request_id)
+    })
+}
+unsafe extern "system" fn get_space_component_status_fb(
+    space: Space,
+    component_type: SpaceComponentTypeFB,
+    status: *mut SpaceComponentStatusFB,
+) -> Result {
+    super::call::<Space>(space.into_raw(), |this, next| {
+        this.get_space_component_status_fb(next, space, component_type, status)
+    })
+}
+unsafe extern "system" fn create_triangle_mesh_fb(
+    session: Session,
+    create_info: *const TriangleMeshCreateInfoFB,
+    out_triangle_mesh: *mut TriangleMeshFB,
+) -> Result {
+    let result = super::call::<Session>(session.into_raw(), |this, next| {
+        this.create_triangle_mesh_fb(next, session, create_info, out_triangle_mesh)
+    });
+    if result.into_raw() >= 0 {
+        super::created::<Session, TriangleMeshFB>(
+            session.into_raw(),
+            (*out_triangle_mesh).into_raw(),
+        );
+    }
+    result
+}
+unsafe extern "system" fn destroy_triangle_mesh_fb(mesh: TriangleMeshFB) -> Result {
+    let result = super::call::<TriangleMeshFB>(mesh.into_raw(), |this, next| {
+        this.destroy_triangle_mesh_fb(next, mesh)
+    });
+    if result.into_raw() >= 0 {
+        super::destroyed::<TriangleMeshFB>(mesh.into_raw());
+    }
+    result
+}
+unsafe extern "system" fn triangle_mesh_get_vertex_buffer_fb(
+    mesh: TriangleMeshFB,
+    out_vertex_buffer: *mut *mut Vector3f,
+) -> Result {
+    super::call::<TriangleMeshFB>(mesh.into_raw(), |this, next| {
+        this.triangle_mesh_get_vertex_buffer_fb(next, mesh, out_vertex_buffer)
+    })
+}
+unsafe extern "system" fn triangle_mesh_get_index_buffer_fb(
+    mesh: TriangleMeshFB,
+    out_index_buffer: *mut *mut u32,
+) -> Result {
+    super::call::<TriangleMeshFB>(mesh.into_raw(), |this, next| {
+        this.triangle_mesh_get_index_buffer_fb(next, mesh, out_index_buffer)
+    })
+}
+unsafe extern "system" fn triangle_mesh_begin_update_fb(mesh: TriangleMeshFB) -> Result {
+    super::call::<TriangleMeshFB>(mesh.into_raw(), |this, next| {
+        this.triangle_mesh_begin_update_fb(next, mesh)
+    })
+}
+unsafe extern "system" fn triangle_mesh_end_update_fb(
+    mesh: TriangleMeshFB,
+    vertex_count: u32,
+    triangle_count: u32,
+) -> Result {
+    super::call::<TriangleMeshFB>(mesh.into_raw(), |this, next| {
+        this.triangle_mesh_end_update_fb(next, mesh, vertex_count, triangle_count)
+    })
+}
+unsafe extern "system" fn triangle_mesh_begin_vertex_buffer_update_fb(
+    mesh: TriangleMeshFB,
+    out_vertex_count: *mut u32,
+) -> Result {
+    super::call::<TriangleMeshFB>(mesh.into_raw(), |this, next| {
+        this.triangle_mesh_begin_vertex_buffer_update_fb(next, mesh, out_vertex_count)
+    })
+}
+unsafe extern "system" fn triangle_mesh_end_vertex_buffer_update_fb(
+    mesh: TriangleMeshFB,
+) -> Result {
+    super::call::<TriangleMeshFB>(mesh.into_raw(), |this, next| {
+        this.triangle_mesh_end_vertex_buffer_update_fb(next, mesh)
+    })
+}
+unsafe extern "system" fn create_passthrough_fb(
+    session: Session,
+    create_info: *const PassthroughCreateInfoFB,
+    out_passthrough: *mut PassthroughFB,
+) -> Result {
+    let result = super::call::<Session>(session.into_raw(), |this, next| {
+        this.create_passthrough_fb(next, session, create_info, out_passthrough)
+    });
+    if result.into_raw() >= 0 {
+        super::created::<Session, PassthroughFB>(session.into_raw(), (*out_passthrough).into_raw());
+    }
+    result
+}
+unsafe extern "system" fn destroy_passthrough_fb(passthrough: PassthroughFB) -> Result {
+    let result = super::call::<PassthroughFB>(passthrough.into_raw(), |this, next| {
+        this.destroy_passthrough_fb(next, passthrough)
+    });
+    if result.into_raw() >= 0 {
+        super::destroyed::<PassthroughFB>(passthrough.into_raw());
+    }
+    result
+}
+unsafe extern "system" fn passthrough_start_fb(passthrough: PassthroughFB) -> Result {
+    super::call::<PassthroughFB>(passthrough.into_raw(), |this, next| {
+        this.passthrough_start_fb(next, passthrough)
+    })
+}
+unsafe extern "system" fn passthrough_pause_fb(passthrough: PassthroughFB) -> Result {
+    super::call::<PassthroughFB>(passthrough.into_raw(), |this, next| {
+        this.passthrough_pause_fb(next, passthrough)
+    })
+}
+unsafe extern "system" fn create_passthrough_layer_fb(
+    session: Session,
+    create_info: *const PassthroughLayerCreateInfoFB,
+    out_layer: *mut PassthroughLayerFB,
+) -> Result {
+    let result = super::call::<Session>(session.into_raw(), |this, next| {
+        this.create_passthrough_layer_fb(next, session, create_info, out_layer)
+    });
+    if result.into_raw() >= 0 {
+        super::created::<Session, PassthroughLayerFB>(session.into_raw(), (*out_layer).into_raw());
+    }
+    result
+}
+unsafe extern "system" fn destroy_passthrough_layer_fb(layer: PassthroughLayerFB) -> Result {
+    let result = super::call::<PassthroughLayerFB>(layer.into_raw(), |this, next| {
+        this.destroy_passthrough_layer_fb(next, layer)
+    });
+    if result.into_raw() >= 0 {
+        super::destroyed::<PassthroughLayerFB>(layer.into_raw());
+    }
+    result
+}
+unsafe extern "system" fn passthrough_layer_pause_fb(layer: PassthroughLayerFB) -> Result {
+    super::call::<PassthroughLayerFB>(layer.into_raw(), |this, next| {
+        this.passthrough_layer_pause_fb(next, layer)
+    })
+}
+unsafe extern "system" fn passthrough_layer_resume_fb(layer: PassthroughLayerFB) -> Result {
+    super::call::<PassthroughLayerFB>(layer.into_raw(), |this, next| {
+        this.passthrough_layer_resume_fb(next, layer)
+    })
+}
+unsafe extern "system" fn passthrough_layer_set_style_fb(
+    layer: PassthroughLayerFB,
+    style: *const PassthroughStyleFB,
+) -> Result {
+    super::call::<PassthroughLayerFB>(layer.into_raw(), |this, next| {
+        this.passthrough_layer_set_style_fb(next, layer, style)
+    })
+}
+unsafe extern "system" fn create_geometry_instance_fb(
+    session: Session,
+    create_info: *const GeometryInstanceCreateInfoFB,
+    out_geometry_instance: *mut GeometryInstanceFB,
+) -> Result {
+    let result = super::call::<Session>(session.into_raw(), |this, next| {
+        this.create_geometry_instance_fb(next, session, create_info, out_geometry_instance)
+    });
+    if result.into_raw() >= 0 {
+        super::created::<Session, GeometryInstanceFB>(
+            session.into_raw(),
+            (*out_geometry_instance).into_raw(),
+        );
+    }
+    result
+}
+unsafe extern "system" fn destroy_geometry_instance_fb(instance: GeometryInstanceFB) -> Result {
+    let result = super::call::<GeometryInstanceFB>(instance.into_raw(), |this, next| {
+        this.destroy_geometry_instance_fb(next, instance)
+    });
+    if result.into_raw() >= 0 {
+        super::destroyed::<GeometryInstanceFB>(instance.into_raw());
+    }
+    result
+}
+unsafe extern "system" fn geometry_instance_set_transform_fb(
+    instance: GeometryInstanceFB,
+    transformation: *const GeometryInstanceTransformFB,
+) -> Result {
+    super::call::<GeometryInstanceFB>(instance.into_raw(), |this, next| {
+        this.geometry_instance_set_transform_fb(next, instance, transformation)
+    })
+}
+unsafe extern "system" fn query_spaces_fb(
+    session: Session,
+    info: *const SpaceQueryInfoBaseHeaderFB,
+    request_id: *mut AsyncRequestIdFB,
+) -> Result {
+    super::call::<Session>(session.into_raw(), |this, next| {
+        this.query_spaces_fb(next, session, info, request_id)
+    })
+}
+unsafe extern "system" fn retrieve_space_query_results_fb(
+    session: Session,
+    request_id: AsyncRequestIdFB,
+    results: *mut SpaceQueryResultsFB,
+) -> Result {
+    super::call::<Session>(session.into_raw(), |this, next| {
+        this.retrieve_space_query_results_fb(next, session, request_id, results)
+    })
+}
+unsafe extern "system" fn save_space_fb(
+    session: Session,
+    info: *const SpaceSaveInfoFB,
+    request_id: *mut AsyncRequestIdFB,
+) -> Result {
+    super::call::<Session>(session.into_raw(), |this, next| {
+        this.save_space_fb(next, session, info, request_id)
+    })
+}
+unsafe extern "system" fn erase_space_fb(
+    session: Session,
+    info: *const SpaceEraseInfoFB,
+    request_id: *mut AsyncRequestIdFB,
+) -> Result {
+    super::call::<Session>(session.into_raw(), |this, next| {
+        this.erase_space_fb(next, session, info, request_id)
+    })
+}
+unsafe extern "system" fn save_space_list_fb(
+    session: Session,
+    info: *const SpaceListSaveInfoFB,
+    request_id: *mut AsyncRequestIdFB,
+) -> Result {
+    super::call::<Session>(session.into_raw(), |this, next| {
+        this.save_space_list_fb(next, session, info, request_id)
+    })
+}
+unsafe extern "system" fn share_spaces_fb(
+    session: Session,
+    info: *const SpaceShareInfoFB,
+    request_id: *mut AsyncRequestIdFB,
+) -> Result {
+    super::call::<Session>(session.into_raw(), |this, next| {
+        this.share_spaces_fb(next, session, info, request_id)
+    })
+}
+unsafe extern "system" fn get_space_container_fb(
+    session: Session,
+    space: Space,
+    space_container_output: *mut SpaceContainerFB,
+) -> Result {
+    super::call::<Session>(session.into_raw(), |this, next| {
+        this.get_space_container_fb(next, session, space, space_container_output)
+    })
+}
+unsafe extern "system" fn get_space_bounding_box2_dfb(
+    session: Session,
+    space: Space,
+    bounding_box2_d_output: *mut Rect2Df,
+) -> Result {
+    super::call::<Session>(session.into_raw(), |this, next| {
+        this.get_space_bounding_box2_dfb(next, session, space, bounding_box2_d_output)
+    })
+}
+unsafe extern "system" fn get_space_bounding_box3_dfb(
+    session: Session,
+    space: Space,
+    bounding_box3_d_output: *mut Rect3DfFB,
+) -> Result {
+    super::call::<Session>(session.into_raw(), |this, next| {
+        this.get_space_bounding_box3_dfb(next, session, space, bounding_box3_d_output)
+    })
+}
+unsafe extern "system" fn get_space_semantic_labels_fb(
+    session: Session,
+    space: Space,
+    semantic_labels_output: *mut SemanticLabelsFB,
+) -> Result {
+    super::call::<Session>(session.into_raw(), |this, next| {
+        this.get_space_semantic_labels_fb(next, session, space, semantic_labels_output)
+    })
+}
+unsafe extern "system" fn get_space_boundary2_dfb(
+    session: Session,
+    space: Space,
+    boundary2_d_output: *mut Boundary2DFB,
+) -> Result {
+    super::call::<Session>(session.into_raw(), |this, next| {
+        this.get_space_boundary2_dfb(next, session, space, boundary2_d_output)
+    })
+}
+unsafe extern "system" fn get_space_room_layout_fb(
+    session: Session,
+    space: Space,
+    room_layout_output: *mut RoomLayoutFB,
+) -> Result {
+    super::call::<Session>(session.into_raw(), |this, next| {
+        this.get_space_room_layout_fb(next, session, space, room_layout_output)
+    })
+}
+unsafe extern "system" fn request_scene_capture_fb(
+    session: Session,
+    info: *const SceneCaptureRequestInfoFB,
+    request_id: *mut AsyncRequestIdFB,
+) -> Result {
+    super::call::<Session>(session.into_raw(), |this, next| {
+        this.request_scene_capture_fb(next, session, info, request_id)
+    })
+}
+unsafe extern "system" fn passthrough_layer_set_keyboard_hands_intensity_fb(
+    layer: PassthroughLayerFB,
+    intensity: *const PassthroughKeyboardHandsIntensityFB,
+) -> Result {
+    super::call::<PassthroughLayerFB>(layer.into_raw(), |this, next| {
+        this.passthrough_layer_set_keyboard_hands_intensity_fb(next, layer, intensity)
+    })
+}
+unsafe extern "system" fn create_spatial_anchor_store_connection_msft(
+    session: Session,
+    spatial_anchor_store: *mut SpatialAnchorStoreConnectionMSFT,
+) -> Result {
+    let result = super::call::<Session>(session.into_raw(), |this, next| {
+        this.create_spatial_anchor_store_connection_msft(next, session, spatial_anchor_store)
+    });
+    if result.into_raw() >= 0 {
+        super::created::<Session, SpatialAnchorStoreConnectionMSFT>(
+            session.into_raw(),
+            (*spatial_anchor_store).into_raw(),
+        );
+    }
+    result
+}
+unsafe extern "system" fn destroy_spatial_anchor_store_connection_msft(
+    spatial_anchor_store: SpatialAnchorStoreConnectionMSFT,
+) -> Result {
+    let result = super::call::<SpatialAnchorStoreConnectionMSFT>(
+        spatial_anchor_store.into_raw(),
+        |this, next| this.destroy_spatial_anchor_store_connection_msft(next, spatial_anchor_store),
+    );
+    if result.into_raw() >= 0 {
+        super::destroyed::<SpatialAnchorStoreConnectionMSFT>(spatial_anchor_store.into_raw());
+    }
+    result
+}
+unsafe extern "system" fn persist_spatial_anchor_msft(
+    spatial_anchor_store: SpatialAnchorStoreConnectionMSFT,
+    spatial_anchor_persistence_info: *const SpatialAnchorPersistenceInfoMSFT,
+) -> Result {
+    super::call::<SpatialAnchorStoreConnectionMSFT>(
+        spatial_anchor_store.into_raw(),
+        |this, next| {
+            this.persist_spatial_anchor_msft(
+                next,
+                spatial_anchor_store,
+                spatial_anchor_persistence_info,
+            )
+        },
+    )
+}
+unsafe extern "system" fn enumerate_persisted_spatial_anchor_names_msft(
+    spatial_anchor_store: SpatialAnchorStoreConnectionMSFT,
+    spatial_anchor_names_capacity_input: u32,
+    spatial_anchor_names_count_output: *mut u32,
+    persisted_anchor_names: *mut SpatialAnchorPersistenceNameMSFT,
+) -> Result {
+    super::call::<SpatialAnchorStoreConnectionMSFT>(
+        spatial_anchor_store.into_raw(),
+        |this, next| {
+            this.enumerate_persisted_spatial_anchor_names_msft(
+                next,
+                spatial_anchor_store,
+                spatial_anchor_names_capacity_input,
+                spatial_anchor_names_count_output,
+                persisted_anchor_names,
+            )
+        },
+    )
+}
+unsafe extern "system" fn create_spatial_anchor_from_persisted_name_msft(
+    session: Session,
+    spatial_anchor_create_info: *const SpatialAnchorFromPersistedAnchorCreateInfoMSFT,
+    spatial_anchor: *mut SpatialAnchorMSFT,
+) -> Result {
+    let result = super::call::<Session>(session.into_raw(), |this, next| {
+        this.create_spatial_anchor_from_persisted_name_msft(
+            next,
+            session,
+            spatial_anchor_create_info,
+            spatial_anchor,
+        )
+    });
+    if result.into_raw() >= 0 {
+        super::created::<Session, SpatialAnchorMSFT>(
+            session.into_raw(),
+            (*spatial_anchor).into_raw(),
+        );
+    }
+    result
+}
+unsafe extern "system" fn unpersist_spatial_anchor_msft(
+    spatial_anchor_store: SpatialAnchorStoreConnectionMSFT,
+    spatial_anchor_persistence_name: *const SpatialAnchorPersistenceNameMSFT,
+) -> Result {
+    super::call::<SpatialAnchorStoreConnectionMSFT>(
+        spatial_anchor_store.into_raw(),
+        |this, next| {
+            this.unpersist_spatial_anchor_msft(
+                next,
+                spatial_anchor_store,
+                spatial_anchor_persistence_name,
+            )
+        },
+    )
+}
+unsafe extern "system" fn clear_spatial_anchor_store_msft(
+    spatial_anchor_store: SpatialAnchorStoreConnectionMSFT,
+) -> Result {
+    super::call::<SpatialAnchorStoreConnectionMSFT>(
+        spatial_anchor_store.into_raw(),
+        |this, next| this.clear_spatial_anchor_store_msft(next, spatial_anchor_store),
+    )
+}
+unsafe extern "system" fn create_facial_tracker_htc(
+    session: Session,
+    create_info: *const FacialTrackerCreateInfoHTC,
+    facial_tracker: *mut FacialTrackerHTC,
+) -> Result {
+    let result = super::call::<Session>(session.into_raw(), |this, next| {
+        this.create_facial_tracker_htc(next, session, create_info, facial_tracker)
+    });
+    if result.into_raw() >= 0 {
+        super::created::<Session, FacialTrackerHTC>(
+            session.into_raw(),
+            (*facial_tracker).into_raw(),
+        );
+    }
+    result
+}
+unsafe extern "system" fn destroy_facial_tracker_htc(facial_tracker: FacialTrackerHTC) -> Result {
+    let result = super::call::<FacialTrackerHTC>(facial_tracker.into_raw(), |this, next| {
+        this.destroy_facial_tracker_htc(next, facial_tracker)
+    });
+    if result.into_raw() >= 0 {
+        super::destroyed::<FacialTrackerHTC>(facial_tracker.into_raw());
+    }
+    result
+}
+unsafe extern "system" fn get_facial_expressions_htc(
+    facial_tracker: FacialTrackerHTC,
+    facial_expressions: *mut FacialExpressionsHTC,
+) -> Result {
+    super::call::<FacialTrackerHTC>(facial_tracker.into_raw(), |this, next| {
+        this.get_facial_expressions_htc(next, facial_tracker, facial_expressions)
+    })
+}
+unsafe extern "system" fn create_passthrough_htc(
+    session: Session,
+    create_info: *const PassthroughCreateInfoHTC,
+    passthrough: *mut PassthroughHTC,
+) -> Result {
+    let result = super::call::<Session>(session.into_raw(), |this, next| {
+        this.create_passthrough_htc(next, session, create_info, passthrough)
+    });
+    if result.into_raw() >= 0 {
+        super::created::<Session, PassthroughHTC>(session.into_raw(), (*passthrough).into_raw());
+    }
+    result
+}
+unsafe extern "system" fn destroy_passthrough_htc(passthrough: PassthroughHTC) -> Result {
+    let result = super::call::<PassthroughHTC>(passthrough.into_raw(), |this, next| {
+        this.destroy_passthrough_htc(next, passthrough)
+    });
+    if result.into_raw() >= 0 {
+        super::destroyed::<PassthroughHTC>(passthrough.into_raw());
+    }
+    result
+}
+unsafe extern "system" fn enumerate_vive_tracker_paths_htcx(
+    instance: Instance,
+    path_capacity_input: u32,
+    path_count_output: *mut u32,
+    paths: *mut ViveTrackerPathsHTCX,
+) -> Result {
+    super::call::<Instance>(instance.into_raw(), |this, next| {
+        this.enumerate_vive_tracker_paths_htcx(
+            next,
+            instance,
+            path_capacity_input,
+            path_count_output,
+            paths,
+        )
+    })
+}
+unsafe extern "system" fn set_marker_tracking_varjo(session: Session, enabled: Bool32) -> Result {
+    super::call::<Session>(session.into_raw(), |this, next| {
+        this.set_marker_tracking_varjo(next, session, enabled)
+    })
+}
+unsafe extern "system" fn set_marker_tracking_timeout_varjo(
+    session: Session,
+    marker_id: u64,
+    timeout: Duration,
+) -> Result {
+    super::call::<Session>(session.into_raw(), |this, next| {
+        this.set_marker_tracking_timeout_varjo(next, session, marker_id, timeout)
+    })
+}
+unsafe extern "system" fn set_marker_tracking_prediction_varjo(
+    session: Session,
+    marker_id: u64,
+    enabled: Bool32,
+) -> Result {
+    super::call::<Session>(session.into_raw(), |this, next| {
+        this.set_marker_tracking_prediction_varjo(next, session, marker_id, enabled)
+    })
+}
+unsafe extern "system" fn get_marker_size_varjo(
+    session: Session,
+    marker_id: u64,
+    size: *mut Extent2Df,
+) -> Result {
+    super::call::<Session>(session.into_raw(), |this, next| {
+        this.get_marker_size_varjo(next, session, marker_id, size)
+    })
+}
+unsafe extern "system" fn create_marker_space_varjo(
+    session: Session,
+    create_info: *const MarkerSpaceCreateInfoVARJO,
+    space: *mut Space,
+) -> Result {
+    let result = super::call::<Session>(session.into_raw(), |this, next| {
+        this.create_marker_space_varjo(next, session, create_info, space)
+    });
+    if result.into_raw() >= 0 {
+        super::created::<Session, Space>(session.into_raw(), (*space).into_raw());
+    }
+    result
+}
+unsafe extern "system" fn set_digital_lens_control_almalence(
+    session: Session,
+    digital_lens_control: *const DigitalLensControlALMALENCE,
+) -> Result {
+    super::call::<Session>(session.into_raw(), |this, next| {
+        this.set_digital_lens_control_almalence(next, session, digital_lens_control)
+    })
+}
+unsafe extern "system" fn set_view_offset_varjo(session: Session, offset: f32) -> Result {
+    super::call::<Session>(session.into_raw(), |this, next| {
+        this.set_view_offset_varjo(next, session, offset)
+    })
+}
+unsafe extern "system" fn enumerate_external_cameras_oculus(
+    session: Session,
+    camera_capacity_input: u32,
+    camera_count_output: *mut u32,
+    cameras: *mut ExternalCameraOCULUS,
+) -> Result {
+    super::call::<Session>(session.into_raw(), |this, next| {
+        this.enumerate_external_cameras_oculus(
+            next,
+            session,
+            camera_capacity_input,
+            camera_count_output,
+            cameras,
+        )
+    })
+}
+unsafe extern "system" fn create_passthrough_color_lut_meta(
+    passthrough: PassthroughFB,
+    create_info: *const PassthroughColorLutCreateInfoMETA,
+    color_lut: *mut PassthroughColorLutMETA,
+) -> Result {
+    let result = super::call::<PassthroughFB>(passthrough.into_raw(), |this, next| {
+        this.create_passthrough_color_lut_meta(next, passthrough, create_info, color_lut)
+    });
+    if result.into_raw() >= 0 {
+        super::created::<PassthroughFB, PassthroughColorLutMETA>(
+            passthrough.into_raw(),
+            (*color_lut).into_raw(),
+        );
+    }
+    result
+}
+unsafe extern "system" fn destroy_passthrough_color_lut_meta(
+    color_lut: PassthroughColorLutMETA,
+) -> Result {
+    let result = super::call::<PassthroughColorLutMETA>(color_lut.into_raw(), |this, next| {
+        this.destroy_passthrough_color_lut_meta(next, color_lut)
+    });
+    if result.into_raw() >= 0 {
+        super::destroyed::<PassthroughColorLutMETA>(color_lut.into_raw());
+    }
+    result
+}
+unsafe extern "system" fn update_passthrough_color_lut_meta(
+    color_lut: PassthroughColorLutMETA,
+    update_info: *const PassthroughColorLutUpdateInfoMETA,
+) -> Result {
+    super::call::<PassthroughColorLutMETA>(color_lut.into_raw(), |this, next| {
+        this.update_passthrough_color_lut_meta(next, color_lut, update_info)
+    })
+}
+unsafe extern "system" fn enumerate_performance_metrics_counter_paths_meta(
+    instance: Instance,
+    counter_path_capacity_input: u32,
+    counter_path_count_output: *mut u32,
+    counter_paths: *mut Path,
+) -> Result {
+    super::call::<Instance>(instance.into_raw(), |this, next| {
+        this.enumerate_performance_metrics_counter_paths_meta(
+            next,
+            instance,
+            counter_path_capacity_input,
+            counter_path_count_output,
+            counter_paths,
+        )
+    })
+}
+unsafe extern "system" fn set_performance_metrics_state_meta(
+    session: Session,
+    state: *const PerformanceMetricsStateMETA,
+) -> Result {
+    super::call::<Session>(session.into_raw(), |this, next| {
+        this.set_performance_metrics_state_meta(next, session, state)
+    })
+}
+unsafe extern "system" fn get_performance_metrics_state_meta(
+    session: Session,
+    state: *mut PerformanceMetricsStateMETA,
+) -> Result {
+    super::call::<Session>(session.into_raw(), |this, next| {
+        this.get_performance_metrics_state_meta(next, session, state)
+    })
+}
+unsafe extern "system" fn query_performance_metrics_counter_meta(
+    session: Session,
+    counter_path: Path,
+    counter: *mut PerformanceMetricsCounterMETA,
+) -> Result {
+    super::call::<Session>(session.into_raw(), |this, next| {
+        this.query_performance_metrics_counter_meta(next, session, counter_path, counter)
+    })
+}
+unsafe extern "system" fn get_passthrough_preferences_meta(
+    session: Session,
+    preferences: *mut PassthroughPreferencesMETA,
+) -> Result {
+    super::call::<Session>(session.into_raw(), |this, next| {
+        this.get_passthrough_preferences_meta(next, session, preferences)
+    })
+}
+unsafe extern "system" fn apply_foveation_htc(
+    session: Session,
+    apply_info: *const FoveationApplyInfoHTC,
+) -> Result {
+    super::call::<Session>(session.into_raw(), |this, next| {
+        this.apply_foveation_htc(next, session, apply_info)
+    })
+}
+unsafe extern "system" fn create_space_from_coordinate_frame_uidml(
+    session: Session,
+    create_info: *const CoordinateSpaceCreateInfoML,
+    space: *mut Space,
+) -> Result {
+    let result = super::call::<Session>(session.into_raw(), |this, next| {
+        this.create_space_from_coordinate_frame_uidml(next, session, create_info, space)
+    });
+    if result.into_raw() >= 0 {
+        super::created::<Session, Space>(session.into_raw(), (*space).into_raw());
+    }
+    result
+}
+unsafe extern "system" fn get_device_sample_rate_fb(
+    session: Session,
+    haptic_action_info: *const HapticActionInfo,
+    device_sample_rate: *mut DevicePcmSampleRateStateFB,
+) -> Result {
+    super::call::<Session>(session.into_raw(), |this, next| {
+        this.get_device_sample_rate_fb(next, session, haptic_action_info, device_sample_rate)
+    })
+}
+unsafe extern "system" fn set_tracking_optimization_settings_hint_qcom(
+    session: Session,
+    domain: TrackingOptimizationSettingsDomainQCOM,
+    hint: TrackingOptimizationSettingsHintQCOM,
+) -> Result {
+    super::call::<Session>(session.into_raw(), |this, next| {
+        this.set_tracking_optimization_settings_hint_qcom(next, session, domain, hint)
+    })
+}
+unsafe extern "system" fn create_space_user_fb(
+    session: Session,
+    info: *const SpaceUserCreateInfoFB,
+    user: *mut SpaceUserFB,
+) -> Result {
+    let result = super::call::<Session>(session.into_raw(), |this, next| {
+        this.create_space_user_fb(next, session, info, user)
+    });
+    if result.into_raw() >= 0 {
+        super::created::<Session, SpaceUserFB>(session.into_raw(), (*user).into_raw());
+    }
+    result
+}
+unsafe extern "system" fn get_space_user_id_fb(
+    user: SpaceUserFB,
+    user_id: *mut SpaceUserIdFB,
+) -> Result {
+    super::call::<SpaceUserFB>(user.into_raw(), |this, next| {
+        this.get_space_user_id_fb(next, user, user_id)
+    })
+}
+unsafe extern "system" fn destroy_space_user_fb(user: SpaceUserFB) -> Result {
+    let result = super::call::<SpaceUserFB>(user.into_raw(), |this, next| {
+        this.destroy_space_user_fb(next, user)
+    });
+    if result.into_raw() >= 0 {
+        super::destroyed::<SpaceUserFB>(user.into_raw());
+    }
+    result
+}
+unsafe extern "system" fn apply_force_feedback_curl_mndx(
+    hand_tracker: HandTrackerEXT,
+    locations: *const ForceFeedbackCurlApplyLocationsMNDX,
+) -> Result {
+    super::call::<HandTrackerEXT>(hand_tracker.into_raw(), |this, next| {
+        this.apply_force_feedback_curl_mndx(next, hand_tracker, locations)
+    })
+}
+unsafe extern "system" fn create_plane_detector_ext(
+    session: Session,
+    create_info: *const PlaneDetectorCreateInfoEXT,
+    plane_detector: *mut PlaneDetectorEXT,
+) -> Result {
+    let result = super::call::<Session>(session.into_raw(), |this, next| {
+        this.create_plane_detector_ext(next, session, create_info, plane_detector)
+    });
+    if result.into_raw() >= 0 {
+        super::created::<Session, PlaneDetectorEXT>(
+            session.into_raw(),
+            (*plane_detector).into_raw(),
+        );
+    }
+    result
+}
+unsafe extern "system" fn destroy_plane_detector_ext(plane_detector: PlaneDetectorEXT) -> Result {
+    let result = super::call::<PlaneDetectorEXT>(plane_detector.into_raw(), |this, next| {
+        this.destroy_plane_detector_ext(next, plane_detector)
+    });
+    if result.into_raw() >= 0 {
+        super::destroyed::<PlaneDetectorEXT>(plane_detector.into_raw());
+    }
+    result
+}
+unsafe extern "system" fn begin_plane_detection_ext(
+    plane_detector: PlaneDetectorEXT,
+    begin_info: *const PlaneDetectorBeginInfoEXT,
+) -> Result {
+    super::call::<PlaneDetectorEXT>(plane_detector.into_raw(), |this, next| {
+        this.begin_plane_detection_ext(next, plane_detector, begin_info)
+    })
+}
+unsafe extern "system" fn get_plane_detection_state_ext(
+    plane_detector: PlaneDetectorEXT,
+    state: *mut PlaneDetectionStateEXT,
+) -> Result {
+    super::call::<PlaneDetectorEXT>(plane_detector.into_raw(), |this, next| {
+        this.get_plane_detection_state_ext(next, plane_detector, state)
+    })
+}
+unsafe extern "system" fn get_plane_detections_ext(
+    plane_detector: PlaneDetectorEXT,
+    info: *const PlaneDetectorGetInfoEXT,
+    locations: *mut PlaneDetectorLocationsEXT,
+) -> Result {
+    super::call::<PlaneDetectorEXT>(plane_detector.into_raw(), |this, next| {
+        this.get_plane_detections_ext(next, plane_detector, info, locations)
+    })
+}
+unsafe extern "system" fn get_plane_polygon_buffer_ext(
+    plane_detector: PlaneDetectorEXT,
+    plane_id: u64,
+    polygon_buffer_index: u32,
+    polygon_buffer: *mut PlaneDetectorPolygonBufferEXT,
+) -> Result {
+    super::call::<PlaneDetectorEXT>(plane_detector.into_raw(), |this, next| {
+        this.get_plane_polygon_buffer_ext(
+            next,
+            plane_detector,
+            plane_id,
+            polygon_buffer_index,
+            polygon_buffer,
+        )
+    })
+}
+unsafe extern "system" fn create_virtual_keyboard_meta(
+    session: Session,
+    create_info: *const VirtualKeyboardCreateInfoMETA,
+    keyboard: *mut VirtualKeyboardMETA,
+) -> Result {
+    let result = super::call::<Session>(session.into_raw(), |this, next| {
+        this.create_virtual_keyboard_meta(next, session, create_info, keyboard)
+    });
+    if result.into_raw() >= 0 {
+        super::created::<Session, VirtualKeyboardMETA>(session.into_raw(), (*keyboard).into_raw());
+    }
+    result
+}
+unsafe extern "system" fn destroy_virtual_keyboard_meta(keyboard: VirtualKeyboardMETA) -> Result {
+    let result = super::call::<VirtualKeyboardMETA>(keyboard.into_raw(), |this, next| {
+        this.destroy_virtual_keyboard_meta(next, keyboard)
+    });
+    if result.into_raw() >= 0 {
+        super::destroyed::<VirtualKeyboardMETA>(keyboard.into_raw());
+    }
+    result
+}
+unsafe extern "system" fn create_virtual_keyboard_space_meta(
+    session: Session,
+    keyboard: VirtualKeyboardMETA,
+    create_info: *const VirtualKeyboardSpaceCreateInfoMETA,
+    keyboard_space: *mut Space,
+) -> Result {
+    let result = super::call::<Session>(session.into_raw(), |this, next| {
+        this.create_virtual_keyboard_space_meta(
+            next,
+            session,
+            keyboard,
+            create_info,
+            keyboard_space,
+        )
+    });
+    if result.into_raw() >= 0 {
+        super::created::<Session, Space>(session.into_raw(), (*keyboard_space).into_raw());
+    }
+    result
+}
+unsafe extern "system" fn suggest_virtual_keyboard_location_meta(
+    keyboard: VirtualKeyboardMETA,
+    location_info: *const VirtualKeyboardLocationInfoMETA,
+) -> Result {
+    super::call::<VirtualKeyboardMETA>(keyboard.into_raw(), |this, next| {
+        this.suggest_virtual_keyboard_location_meta(next, keyboard, location_info)
+    })
+}
+unsafe extern "system" fn get_virtual_keyboard_scale_meta(
+    keyboard: VirtualKeyboardMETA,
+    scale: *mut f32,
+) -> Result {
+    super::call::<VirtualKeyboardMETA>(keyboard.into_raw(), |this, next| {
+        this.get_virtual_keyboard_scale_meta(next, keyboard, scale)
+    })
+}
+unsafe extern "system" fn set_virtual_keyboard_model_visibility_meta(
+    keyboard: VirtualKeyboardMETA,
+    model_visibility: *const VirtualKeyboardModelVisibilitySetInfoMETA,
+) -> Result {
+    super::call::<VirtualKeyboardMETA>(keyboard.into_raw(), |this, next| {
+        this.set_virtual_keyboard_model_visibility_meta(next, keyboard, model_visibility)
+    })
+}
+unsafe extern "system" fn get_virtual_keyboard_model_animation_states_meta(
+    keyboard: VirtualKeyboardMETA,
+    animation_states: *mut VirtualKeyboardModelAnimationStatesMETA,
+) -> Result {
+    super::call::<VirtualKeyboardMETA>(keyboard.into_raw(), |this, next| {
+        this.get_virtual_keyboard_model_animation_states_meta(next, keyboard, animation_states)
+    })
+}
+unsafe extern "system" fn get_virtual_keyboard_dirty_textures_meta(
+    keyboard: VirtualKeyboardMETA,
+    texture_id_capacity_input: u32,
+    texture_id_count_output: *mut u32,
+    texture_ids: *mut u64,
+) -> Result {
+    super::call::<VirtualKeyboardMETA>(keyboard.into_raw(), |this, next| {
+        this.get_virtual_keyboard_dirty_textures_meta(
+            next,
+            keyboard,
+            texture_id_capacity_input,
+            texture_id_count_output,
+            texture_ids,
+        )
+    })
+}
+unsafe extern "system" fn get_virtual_keyboard_texture_data_meta(
+    keyboard: VirtualKeyboardMETA,
+    texture_id: u64,
+    texture_data: *mut VirtualKeyboardTextureDataMETA,
+) -> Result {
+    super::call::<VirtualKeyboardMETA>(keyboard.into_raw(), |this, next| {
+        this.get_virtual_keyboard_texture_data_meta(next, keyboard, texture_id, texture_data)
+    })
+}
+unsafe extern "system" fn send_virtual_keyboard_input_meta(
+    keyboard: VirtualKeyboardMETA,
+    info: *const VirtualKeyboardInputInfoMETA,
+    interactor_root_pose: *mut Posef,
+) -> Result {
+    super::call::<VirtualKeyboardMETA>(keyboard.into_raw(), |this, next| {
+        this.send_virtual_keyboard_input_meta(next, keyboard, info, interactor_root_pose)
+    })
+}
+unsafe extern "system" fn change_virtual_keyboard_text_context_meta(
+    keyboard: VirtualKeyboardMETA,
+    change_info: *const VirtualKeyboardTextContextChangeInfoMETA,
+) -> Result {
+    super::call::<VirtualKeyboardMETA>(keyboard.into_raw(), |this, next| {
+        this.change_virtual_keyboard_text_context_meta(next, keyboard, change_info)
+    })
+}
+unsafe extern "system" fn get_vulkan_graphics_requirements2_khr(
+    instance: Instance,
+    system_id: SystemId,
+    graphics_requirements: *mut GraphicsRequirementsVulkanKHR,
+) -> Result {
+    super::call::<Instance>(instance.into_raw(), |this, next| {
+        this.get_vulkan_graphics_requirements2_khr(next, instance, system_id, graphics_requirements)
+    })
+}
+
+#[doc = r" Look up the entry point for the command named `name`"]
+pub(super) fn thunk(name: &[u8]) -> Option<pfn::VoidFunction> {
+    unsafe {
+        Some(match name {
+            b"xrLocateSpacesKHR" => {
+                mem::transmute::<pfn::LocateSpaces, pfn::VoidFunction>(locate_spaces)
+            }
+            b"xrDestroyInstance" => {
+                mem::transmute::<pfn::DestroyInstance, pfn::VoidFunction>(destroy_instance)
+            }
+            b"xrResultToString" => {
+                mem::transmute::<pfn::ResultToString, pfn::VoidFunction>(result_to_string)
+            }
+            b"xrStructureTypeToString" => mem::transmute::<
+                pfn::StructureTypeToString,
+                pfn::VoidFunction,
+            >(structure_type_to_string),
+            b"xrGetInstanceProperties" => mem::transmute::<
+                pfn::GetInstanceProperties,
+                pfn::VoidFunction,
+            >(get_instance_properties),
+            b"xrGetSystem" => mem::transmute::<pfn::GetSystem, pfn::VoidFunction>(get_system),
+            b"xrGetSystemProperties" => {
+                mem::transmute::<pfn::GetSystemProperties, pfn::VoidFunction>(get_system_properties)
+            }
+            b"xrCreateSession" => {
+                mem::transmute::<pfn::CreateSession, pfn::VoidFunction>(create_session)
+            }
+            b"xrDestroySession" => {
+                mem::transmute::<pfn::DestroySession, pfn::VoidFunction>(destroy_session)
+            }
+            b"xrDestroySpace" => {
+                mem::transmute::<pfn::DestroySpace, pfn::VoidFunction>(destroy_space)
+            }
+            b"xrEnumerateSwapchainFormats" => mem::transmute::<
+                pfn::EnumerateSwapchainFormats,
+                pfn::VoidFunction,
+            >(enumerate_swapchain_formats),
+            b"xrCreateSwapchain" => {
+                mem::transmute::<pfn::CreateSwapchain, pfn::VoidFunction>(create_swapchain)
+            }
+            b"xrDestroySwapchain" => {
+                mem::transmute::<pfn::DestroySwapchain, pfn::VoidFunction>(destroy_swapchain)
+            }
+            b"xrEnumerateSwapchainImages" => mem::transmute::<
+                pfn::EnumerateSwapchainImages,
+                pfn::VoidFunction,
+            >(enumerate_swapchain_images),
+            b"xrAcquireSwapchainImage" => mem::transmute::<
+                pfn::AcquireSwapchainImage,
+                pfn::VoidFunction,
+            >(acquire_swapchain_image),
+            b"xrWaitSwapchainImage" => {
+                mem::transmute::<pfn::WaitSwapchainImage, pfn::VoidFunction>(wait_swapchain_image)
+            }
+            b"xrReleaseSwapchainImage" => mem::transmute::<
+                pfn::ReleaseSwapchainImage,
+                pfn::VoidFunction,
+            >(release_swapchain_image),
+            b"xrBeginSession" => {
+                mem::transmute::<pfn::BeginSession, pfn::VoidFunction>(begin_session)
+            }
+            b"xrEndSession" => mem::transmute::<pfn::EndSession, pfn::VoidFunction>(end_session),
+            b"xrRequestExitSession" => {
+                mem::transmute::<pfn::RequestExitSession, pfn::VoidFunction>(request_exit_session)
+            }
+            b"xrEnumerateReferenceSpaces" => mem::transmute::<
+                pfn::EnumerateReferenceSpaces,
+                pfn::VoidFunction,
+            >(enumerate_reference_spaces),
+            b"xrCreateReferenceSpace" => mem::transmute::<
+                pfn::CreateReferenceSpace,
+                pfn::VoidFunction,
+            >(create_reference_space),
+            b"xrCreateActionSpace" => {
+                mem::transmute::<pfn::CreateActionSpace, pfn::VoidFunction>(create_action_space)
+            }
+            b"xrLocateSpace" => mem::transmute::<pfn::LocateSpace, pfn::VoidFunction>(locate_space),
+            b"xrEnumerateViewConfigurations" => mem::transmute::<
+                pfn::EnumerateViewConfigurations,
+                pfn::VoidFunction,
+            >(enumerate_view_configurations),
+            b"xrEnumerateEnvironmentBlendModes" => mem::transmute::<
+                pfn::EnumerateEnvironmentBlendModes,
+                pfn::VoidFunction,
+            >(enumerate_environment_blend_modes),
+            b"xrGetViewConfigurationProperties" => mem::transmute::<
+                pfn::GetViewConfigurationProperties,
+                pfn::VoidFunction,
+            >(get_view_configuration_properties),
+            b"xrEnumerateViewConfigurationViews" => {
+                mem::transmute::<pfn::EnumerateViewConfigurationViews, pfn::VoidFunction>(
+                    enumerate_view_configuration_views,
+                )
+            }
+            b"xrBeginFrame" => mem::transmute::<pfn::BeginFrame, pfn::VoidFunction>(begin_frame),
+            b"xrLocateViews" => mem::transmute::<pfn::LocateViews, pfn::VoidFunction>(locate_views),
+            b"xrEndFrame" => mem::transmute::<pfn::EndFrame, pfn::VoidFunction>(end_frame),
+            b"xrWaitFrame" => mem::transmute::<pfn::WaitFrame, pfn::VoidFunction>(wait_frame),
+            b"xrApplyHapticFeedback" => {
+                mem::transmute::<pfn::ApplyHapticFeedback, pfn::VoidFunction>(apply_haptic_feedback)
+            }
+            b"xrStopHapticFeedback" => {
+                mem::transmute::<pfn::StopHapticFeedback, pfn::VoidFunction>(stop_haptic_feedback)
+            }
+            b"xrPollEvent" => mem::transmute::<pfn::PollEvent, pfn::VoidFunction>(poll_event),
+            b"xrStringToPath" => {
+                mem::transmute::<pfn::StringToPath, pfn::VoidFunction>(string_to_path)
+            }
+            b"xrPathToString" => {
+                mem::transmute::<pfn::PathToString, pfn::VoidFunction>(path_to_string)
+            }
+            b"xrGetReferenceSpaceBoundsRect" => mem::transmute::<
+                pfn::GetReferenceSpaceBoundsRect,
+                pfn::VoidFunction,
+            >(get_reference_space_bounds_rect),
+            #[cfg(target_os = "android")]
+            b"xrSetAndroidApplicationThreadKHR" => mem::transmute::<
+                pfn::SetAndroidApplicationThreadKHR,
+                pfn::VoidFunction,
+            >(set_android_application_thread_khr),
+            #[cfg(target_os = "android")]
+            b"xrCreateSwapchainAndroidSurfaceKHR" => {
+                mem::transmute::<pfn::CreateSwapchainAndroidSurfaceKHR, pfn::VoidFunction>(
+                    create_swapchain_android_surface_khr,
+                )
+            }
+            b"xrGetActionStateBoolean" => mem::transmute::<
+                pfn::GetActionStateBoolean,
+                pfn::VoidFunction,
+            >(get_action_state_boolean),
+            b"xrGetActionStateFloat" => {
+                mem::transmute::<pfn::GetActionStateFloat, pfn::VoidFunction>(
+                    get_action_state_float,
+                )
+            }
+            b"xrGetActionStateVector2f" => mem::transmute::<
+                pfn::GetActionStateVector2f,
+                pfn::VoidFunction,
+            >(get_action_state_vector2f),
+            b"xrGetActionStatePose" => {
+                mem::transmute::<pfn::GetActionStatePose, pfn::VoidFunction>(get_action_state_pose)
+            }
+            b"xrCreateActionSet" => {
+                mem::transmute::<pfn::CreateActionSet, pfn::VoidFunction>(create_action_set)
+            }
+            b"xrDestroyActionSet" => {
+                mem::transmute::<pfn::DestroyActionSet, pfn::VoidFunction>(destroy_action_set)
+            }
+            b"xrCreateAction" => {
+                mem::transmute::<pfn::CreateAction, pfn::VoidFunction>(create_action)
+            }
+            b"xrDestroyAction" => {
+                mem::transmute::<pfn::DestroyAction, pfn::VoidFunction>(destroy_action)
+            }
+            b"xrSuggestInteractionProfileBindings" => {
+                mem::transmute::<pfn::SuggestInteractionProfileBindings, pfn::VoidFunction>(
+                    suggest_interaction_profile_bindings,
+                )
+            }
+            b"xrAttachSessionActionSets" => mem::transmute::<
+                pfn::AttachSessionActionSets,
+                pfn::VoidFunction,
+            >(attach_session_action_sets),
+            b"xrGetCurrentInteractionProfile" => mem::transmute::<
+                pfn::GetCurrentInteractionProfile,
+                pfn::VoidFunction,
+            >(get_current_interaction_profile),
+            b"xrSyncActions" => mem::transmute::<pfn::SyncActions, pfn::VoidFunction>(sync_actions),
+            b"xrEnumerateBoundSourcesForAction" => mem::transmute::<
+                pfn::EnumerateBoundSourcesForAction,
+                pfn::VoidFunction,
+            >(enumerate_bound_sources_for_action),
+            b"xrGetInputSourceLocalizedName" => mem::transmute::<
+                pfn::GetInputSourceLocalizedName,
+                pfn::VoidFunction,
+            >(get_input_source_localized_name),
+            b"xrLocateSpaces" => {
+                mem::transmute::<pfn::LocateSpaces, pfn::VoidFunction>(locate_spaces)
+            }
+            b"xrGetVulkanInstanceExtensionsKHR" => mem::transmute::<
+                pfn::GetVulkanInstanceExtensionsKHR,
+                pfn::VoidFunction,
+            >(get_vulkan_instance_extensions_khr),
+            b"xrGetVulkanDeviceExtensionsKHR" => mem::transmute::<
+                pfn::GetVulkanDeviceExtensionsKHR,
+                pfn::VoidFunction,
+            >(get_vulkan_device_extensions_khr),
+            b"xrGetVulkanGraphicsDeviceKHR" => mem::transmute::<
+                pfn::GetVulkanGraphicsDeviceKHR,
+                pfn::VoidFunction,
+            >(get_vulkan_graphics_device_khr),
+            b"xrGetOpenGLGraphicsRequirementsKHR" => {
+                mem::transmute::<pfn::GetOpenGLGraphicsRequirementsKHR, pfn::VoidFunction>(
+                    get_open_gl_graphics_requirements_khr,
+                )
+            }
+            b"xrGetOpenGLESGraphicsRequirementsKHR" => {
+                mem::transmute::<pfn::GetOpenGLESGraphicsRequirementsKHR, pfn::VoidFunction>(
+                    get_open_gles_graphics_requirements_khr,
+                )
+            }
+            b"xrGetVulkanGraphicsRequirementsKHR" => {
+                mem::transmute::<pfn::GetVulkanGraphicsRequirementsKHR, pfn::VoidFunction>(
+                    get_vulkan_graphics_requirements_khr,
+                )
+            }
+            #[cfg(windows)]
+            b"xrGetD3D11GraphicsRequirementsKHR" => {
+                mem::transmute::<pfn::GetD3D11GraphicsRequirementsKHR, pfn::VoidFunction>(
+                    get_d3d11_graphics_requirements_khr,
+                )
+            }
+            #[cfg(windows)]
+            b"xrGetD3D12GraphicsRequirementsKHR" => {
+                mem::transmute::<pfn::GetD3D12GraphicsRequirementsKHR, pfn::VoidFunction>(
+                    get_d3d12_graphics_requirements_khr,
+                )
+            }
+            b"xrPerfSettingsSetPerformanceLevelEXT" => {
+                mem::transmute::<pfn::PerfSettingsSetPerformanceLevelEXT, pfn::VoidFunction>(
+                    perf_settings_set_performance_level_ext,
+                )
+            }
+            b"xrThermalGetTemperatureTrendEXT" => mem::transmute::<
+                pfn::ThermalGetTemperatureTrendEXT,
+                pfn::VoidFunction,
+            >(thermal_get_temperature_trend_ext),
+            b"xrSetDebugUtilsObjectNameEXT" => mem::transmute::<
+                pfn::SetDebugUtilsObjectNameEXT,
+                pfn::VoidFunction,
+            >(set_debug_utils_object_name_ext),
+            b"xrCreateDebugUtilsMessengerEXT" => mem::transmute::<
+                pfn::CreateDebugUtilsMessengerEXT,
+                pfn::VoidFunction,
+            >(create_debug_utils_messenger_ext),
+            b"xrDestroyDebugUtilsMessengerEXT" => mem::transmute::<
+                pfn::DestroyDebugUtilsMessengerEXT,
+                pfn::VoidFunction,
+            >(destroy_debug_utils_messenger_ext),
+            b"xrSubmitDebugUtilsMessageEXT" => mem::transmute::<
+                pfn::SubmitDebugUtilsMessageEXT,
+                pfn::VoidFunction,
+            >(submit_debug_utils_message_ext),
+            b"xrSessionBeginDebugUtilsLabelRegionEXT" => {
+                mem::transmute::<pfn::SessionBeginDebugUtilsLabelRegionEXT, pfn::VoidFunction>(
+                    session_begin_debug_utils_label_region_ext,
+                )
+            }
+            b"xrSessionEndDebugUtilsLabelRegionEXT" => {
+                mem::transmute::<pfn::SessionEndDebugUtilsLabelRegionEXT, pfn::VoidFunction>(
+                    session_end_debug_utils_label_region_ext,
+                )
+            }
+            b"xrSessionInsertDebugUtilsLabelEXT" => {
+                mem::transmute::<pfn::SessionInsertDebugUtilsLabelEXT, pfn::VoidFunction>(
+                    session_insert_debug_utils_label_ext,
+                )
+            }
+            #[cfg(windows)]
+            b"xrConvertTimeToWin32PerformanceCounterKHR" => {
+                mem::transmute::<pfn::ConvertTimeToWin32PerformanceCounterKHR, pfn::VoidFunction>(
+                    convert_time_to_win32_performance_counter_khr,
+                )
+            }
+            #[cfg(windows)]
+            b"xrConvertWin32PerformanceCounterToTimeKHR" => {
+                mem::transmute::<pfn::ConvertWin32PerformanceCounterToTimeKHR, pfn::VoidFunction>(
+                    convert_win32_performance_counter_to_time_khr,
+                )
+            }
+            b"xrCreateVulkanInstanceKHR" => mem::transmute::<
+                pfn::CreateVulkanInstanceKHR,
+                pfn::VoidFunction,
+            >(create_vulkan_instance_khr),
+            b"xrCreateVulkanDeviceKHR" => mem::transmute::<
+                pfn::CreateVulkanDeviceKHR,
+                pfn::VoidFunction,
+            >(create_vulkan_device_khr),
+            b"xrGetVulkanGraphicsDevice2KHR" => mem::transmute::<
+                pfn::GetVulkanGraphicsDevice2KHR,
+                pfn::VoidFunction,
+            >(get_vulkan_graphics_device2_khr),
+            b"xrConvertTimeToTimespecTimeKHR" => mem::transmute::<
+                pfn::ConvertTimeToTimespecTimeKHR,
+                pfn::VoidFunction,
+            >(convert_time_to_timespec_time_khr),
+            b"xrConvertTimespecTimeToTimeKHR" => mem::transmute::<
+                pfn::ConvertTimespecTimeToTimeKHR,
+                pfn::VoidFunction,
+            >(convert_timespec_time_to_time_khr),
+            b"xrGetVisibilityMaskKHR" => mem::transmute::<
+                pfn::GetVisibilityMaskKHR,
+                pfn::VoidFunction,
+            >(get_visibility_mask_khr),
+            b"xrCreateSpatialAnchorMSFT" => mem::transmute::<
+                pfn::CreateSpatialAnchorMSFT,
+                pfn::VoidFunction,
+            >(create_spatial_anchor_msft),
+            b"xrCreateSpatialAnchorSpaceMSFT" => mem::transmute::<
+                pfn::CreateSpatialAnchorSpaceMSFT,
+                pfn::VoidFunction,
+            >(create_spatial_anchor_space_msft),
+            b"xrDestroySpatialAnchorMSFT" => mem::transmute::<
+                pfn::DestroySpatialAnchorMSFT,
+                pfn::VoidFunction,
+            >(destroy_spatial_anchor_msft),
+            b"xrSetInputDeviceActiveEXT" => mem::transmute::<
+                pfn::SetInputDeviceActiveEXT,
+                pfn::VoidFunction,
+            >(set_input_device_active_ext),
+            b"xrSetInputDeviceStateBoolEXT" => mem::transmute::<
+                pfn::SetInputDeviceStateBoolEXT,
+                pfn::VoidFunction,
+            >(set_input_device_state_bool_ext),
+            b"xrSetInputDeviceStateFloatEXT" => mem::transmute::<
+                pfn::SetInputDeviceStateFloatEXT,
+                pfn::VoidFunction,
+            >(set_input_device_state_float_ext),
+            b"xrSetInputDeviceStateVector2fEXT" => {
+                mem::transmute::<pfn::SetInputDeviceStateVector2fEXT, pfn::VoidFunction>(
+                    set_input_device_state_vector2f_ext,
+                )
+            }
+            b"xrSetInputDeviceLocationEXT" => mem::transmute::<
+                pfn::SetInputDeviceLocationEXT,
+                pfn::VoidFunction,
+            >(set_input_device_location_ext),
+            b"xrCreateSpatialGraphNodeSpaceMSFT" => {
+                mem::transmute::<pfn::CreateSpatialGraphNodeSpaceMSFT, pfn::VoidFunction>(
+                    create_spatial_graph_node_space_msft,
+                )
+            }
+            b"xrTryCreateSpatialGraphStaticNodeBindingMSFT" => {
+                mem::transmute::<pfn::TryCreateSpatialGraphStaticNodeBindingMSFT, pfn::VoidFunction>(
+                    try_create_spatial_graph_static_node_binding_msft,
+                )
+            }
+            b"xrDestroySpatialGraphNodeBindingMSFT" => {
+                mem::transmute::<pfn::DestroySpatialGraphNodeBindingMSFT, pfn::VoidFunction>(
+                    destroy_spatial_graph_node_binding_msft,
+                )
+            }
+            b"xrGetSpatialGraphNodeBindingPropertiesMSFT" => {
+                mem::transmute::<pfn::GetSpatialGraphNodeBindingPropertiesMSFT, pfn::VoidFunction>(
+                    get_spatial_graph_node_binding_properties_msft,
+                )
+            }
+            b"xrCreateHandTrackerEXT" => mem::transmute::<
+                pfn::CreateHandTrackerEXT,
+                pfn::VoidFunction,
+            >(create_hand_tracker_ext),
+            b"xrDestroyHandTrackerEXT" => mem::transmute::<
+                pfn::DestroyHandTrackerEXT,
+                pfn::VoidFunction,
+            >(destroy_hand_tracker_ext),
+            b"xrLocateHandJointsEXT" => {
+                mem::transmute::<pfn::LocateHandJointsEXT, pfn::VoidFunction>(
+                    locate_hand_joints_ext,
+                )
+            }
+            b"xrCreateFaceTrackerFB" => {
+                mem::transmute::<pfn::CreateFaceTrackerFB, pfn::VoidFunction>(
+                    create_face_tracker_fb,
+                )
+            }
+            b"xrDestroyFaceTrackerFB" => mem::transmute::<
+                pfn::DestroyFaceTrackerFB,
+                pfn::VoidFunction,
+            >(destroy_face_tracker_fb),
+            b"xrGetFaceExpressionWeightsFB" => mem::transmute::<
+                pfn::GetFaceExpressionWeightsFB,
+                pfn::VoidFunction,
+            >(get_face_expression_weights_fb),
+            b"xrCreateBodyTrackerFB" => {
+                mem::transmute::<pfn::CreateBodyTrackerFB, pfn::VoidFunction>(
+                    create_body_tracker_fb,
+                )
+            }
+            b"xrDestroyBodyTrackerFB" => mem::transmute::<
+                pfn::DestroyBodyTrackerFB,
+                pfn::VoidFunction,
+            >(destroy_body_tracker_fb),
+            b"xrLocateBodyJointsFB" => {
+                mem::transmute::<pfn::LocateBodyJointsFB, pfn::VoidFunction>(locate_body_joints_fb)
+            }
+            b"xrGetBodySkeletonFB" => {
+                mem::transmute::<pfn::GetBodySkeletonFB, pfn::VoidFunction>(get_body_skeleton_fb)
+            }
+            b"xrCreateEyeTrackerFB" => {
+                mem::transmute::<pfn::CreateEyeTrackerFB, pfn::VoidFunction>(create_eye_tracker_fb)
+            }
+            b"xrDestroyEyeTrackerFB" => {
+                mem::transmute::<pfn::DestroyEyeTrackerFB, pfn::VoidFunction>(
+                    destroy_eye_tracker_fb,
+                )
+            }
+            b"xrGetEyeGazesFB" => {
+                mem::transmute::<pfn::GetEyeGazesFB, pfn::VoidFunction>(get_eye_gazes_fb)
+            }
+            b"xrCreateHandMeshSpaceMSFT" => mem::transmute::<
+                pfn::CreateHandMeshSpaceMSFT,
+                pfn::VoidFunction,
+            >(create_hand_mesh_space_msft),
+            b"xrUpdateHandMeshMSFT" => {
+                mem::transmute::<pfn::UpdateHandMeshMSFT, pfn::VoidFunction>(update_hand_mesh_msft)
+            }
+            b"xrGetControllerModelKeyMSFT" => mem::transmute::<
+                pfn::GetControllerModelKeyMSFT,
+                pfn::VoidFunction,
+            >(get_controller_model_key_msft),
+            b"xrLoadControllerModelMSFT" => mem::transmute::<
+                pfn::LoadControllerModelMSFT,
+                pfn::VoidFunction,
+            >(load_controller_model_msft),
+            b"xrGetControllerModelPropertiesMSFT" => {
+                mem::transmute::<pfn::GetControllerModelPropertiesMSFT, pfn::VoidFunction>(
+                    get_controller_model_properties_msft,
+                )
+            }
+            b"xrGetControllerModelStateMSFT" => mem::transmute::<
+                pfn::GetControllerModelStateMSFT,
+                pfn::VoidFunction,
+            >(get_controller_model_state_msft),
+            b"xrEnumerateDisplayRefreshRatesFB" => mem::transmute::<
+                pfn::EnumerateDisplayRefreshRatesFB,
+                pfn::VoidFunction,
+            >(enumerate_display_refresh_rates_fb),
+            b"xrGetDisplayRefreshRateFB" => mem::transmute::<
+                pfn::GetDisplayRefreshRateFB,
+                pfn::VoidFunction,
+            >(get_display_refresh_rate_fb),
+            b"xrRequestDisplayRefreshRateFB" => mem::transmute::<
+                pfn::RequestDisplayRefreshRateFB,
+                pfn::VoidFunction,
+            >(request_display_refresh_rate_fb),
+            #[cfg(windows)]
+            b"xrCreateSpatialAnchorFromPerceptionAnchorMSFT" => {
+                mem::transmute::<pfn::CreateSpatialAnchorFromPerceptionAnchorMSFT, pfn::VoidFunction>(
+                    create_spatial_anchor_from_perception_anchor_msft,
+                )
+            }
+            #[cfg(windows)]
+            b"xrTryGetPerceptionAnchorFromSpatialAnchorMSFT" => {
+                mem::transmute::<pfn::TryGetPerceptionAnchorFromSpatialAnchorMSFT, pfn::VoidFunction>(
+                    try_get_perception_anchor_from_spatial_anchor_msft,
+                )
+            }
+            b"xrUpdateSwapchainFB" => {
+                mem::transmute::<pfn::UpdateSwapchainFB, pfn::VoidFunction>(update_swapchain_fb)
+            }
+            b"xrGetSwapchainStateFB" => {
+                mem::transmute::<pfn::GetSwapchainStateFB, pfn::VoidFunction>(
+                    get_swapchain_state_fb,
+                )
+            }
+            b"xrEnumerateColorSpacesFB" => mem::transmute::<
+                pfn::EnumerateColorSpacesFB,
+                pfn::VoidFunction,
+            >(enumerate_color_spaces_fb),
+            b"xrSetColorSpaceFB" => {
+                mem::transmute::<pfn::SetColorSpaceFB, pfn::VoidFunction>(set_color_space_fb)
+            }
+            b"xrCreateFoveationProfileFB" => mem::transmute::<
+                pfn::CreateFoveationProfileFB,
+                pfn::VoidFunction,
+            >(create_foveation_profile_fb),
+            b"xrDestroyFoveationProfileFB" => mem::transmute::<
+                pfn::DestroyFoveationProfileFB,
+                pfn::VoidFunction,
+            >(destroy_foveation_profile_fb),
+            b"xrGetFoveationEyeTrackedStateMETA" => {
+                mem::transmute::<pfn::GetFoveationEyeTrackedStateMETA, pfn::VoidFunction>(
+                    get_foveation_eye_tracked_state_meta,
+                )
+            }
+            b"xrGetHandMeshFB" => {
+                mem::transmute::<pfn::GetHandMeshFB, pfn::VoidFunction>(get_hand_mesh_fb)
+            }
+            b"xrEnumerateRenderModelPathsFB" => mem::transmute::<
+                pfn::EnumerateRenderModelPathsFB,
+                pfn::VoidFunction,
+            >(enumerate_render_model_paths_fb),
+            b"xrGetRenderModelPropertiesFB" => mem::transmute::<
+                pfn::GetRenderModelPropertiesFB,
+                pfn::VoidFunction,
+            >(get_render_model_properties_fb),
+            b"xrLoadRenderModelFB" => {
+                mem::transmute::<pfn::LoadRenderModelFB, pfn::VoidFunction>(load_render_model_fb)
+            }
+            b"xrQuerySystemTrackedKeyboardFB" => mem::transmute::<
+                pfn::QuerySystemTrackedKeyboardFB,
+                pfn::VoidFunction,
+            >(query_system_tracked_keyboard_fb),
+            b"xrCreateKeyboardSpaceFB" => mem::transmute::<
+                pfn::CreateKeyboardSpaceFB,
+                pfn::VoidFunction,
+            >(create_keyboard_space_fb),
+            b"xrSetEnvironmentDepthEstimationVARJO" => {
+                mem::transmute::<pfn::SetEnvironmentDepthEstimationVARJO, pfn::VoidFunction>(
+                    set_environment_depth_estimation_varjo,
+                )
+            }
+            b"xrEnumerateReprojectionModesMSFT" => mem::transmute::<
+                pfn::EnumerateReprojectionModesMSFT,
+                pfn::VoidFunction,
+            >(enumerate_reprojection_modes_msft),
+            b"xrGetAudioOutputDeviceGuidOculus" => {
+                mem::transmute::<pfn::GetAudioOutputDeviceGuidOculus, pfn::VoidFunction>(
+                    get_audio_output_device_guid_oculus,
+                )
+            }
+            b"xrGetAudioInputDeviceGuidOculus" => mem::transmute::<
+                pfn::GetAudioInputDeviceGuidOculus,
+                pfn::VoidFunction,
+            >(get_audio_input_device_guid_oculus),
+            b"xrCreateSpatialAnchorFB" => mem::transmute::<
+                pfn::CreateSpatialAnchorFB,
+                pfn::VoidFunction,
+            >(create_spatial_anchor_fb),
+            b"xrGetSpaceUuidFB" => {
+                mem::transmute::<pfn::GetSpaceUuidFB, pfn::VoidFunction>(get_space_uuid_fb)
+            }
+            b"xrEnumerateSpaceSupportedComponentsFB" => {
+                mem::transmute::<pfn::EnumerateSpaceSupportedComponentsFB, pfn::VoidFunction>(
+                    enumerate_space_supported_components_fb,
+                )
+            }
+            b"xrSetSpaceComponentStatusFB" => mem::transmute::<
+                pfn::SetSpaceComponentStatusFB,
+                pfn::VoidFunction,
+            >(set_space_component_status_fb),
+            b"xrGetSpaceComponentStatusFB" => mem::transmute::<
+                pfn::GetSpaceComponentStatusFB,
+                pfn::VoidFunction,
+            >(get_space_component_status_fb),
+            b"xrCreateTriangleMeshFB" => mem::transmute::<
+                pfn::CreateTriangleMeshFB,
+                pfn::VoidFunction,
+            >(create_triangle_mesh_fb),
+            b"xrDestroyTriangleMeshFB" => mem::transmute::<
+                pfn::DestroyTriangleMeshFB,
+                pfn::VoidFunction,
+            >(destroy_triangle_mesh_fb),
+            b"xrTriangleMeshGetVertexBufferFB" => mem::transmute::<
+                pfn::TriangleMeshGetVertexBufferFB,
+                pfn::VoidFunction,
+            >(triangle_mesh_get_vertex_buffer_fb),
+            b"xrTriangleMeshGetIndexBufferFB" => mem::transmute::<
+                pfn::TriangleMeshGetIndexBufferFB,
+                pfn::VoidFunction,
+            >(triangle_mesh_get_index_buffer_fb),
+            b"xrTriangleMeshBeginUpdateFB" => mem::transmute::<
+                pfn::TriangleMeshBeginUpdateFB,
+                pfn::VoidFunction,
+            >(triangle_mesh_begin_update_fb),
+            b"xrTriangleMeshEndUpdateFB" => mem::transmute::<
+                pfn::TriangleMeshEndUpdateFB,
+                pfn::VoidFunction,
+            >(triangle_mesh_end_update_fb),
+            b"xrTriangleMeshBeginVertexBufferUpdateFB" => {
+                mem::transmute::<pfn::TriangleMeshBeginVertexBufferUpdateFB, pfn::VoidFunction>(
+                    triangle_mesh_begin_vertex_buffer_update_fb,
+                )
+            }
+            b"xrTriangleMeshEndVertexBufferUpdateFB" => {
+                mem::transmute::<pfn::TriangleMeshEndVertexBufferUpdateFB, pfn::VoidFunction>(
+                    triangle_mesh_end_vertex_buffer_update_fb,
+                )
+            }
+            b"xrCreatePassthroughFB" => {
+                mem::transmute::<pfn::CreatePassthroughFB, pfn::VoidFunction>(create_passthrough_fb)
+            }
+            b"xrDestroyPassthroughFB" => mem::transmute::<
+                pfn::DestroyPassthroughFB,
+                pfn::VoidFunction,
+            >(destroy_passthrough_fb),
+            b"xrPassthroughStartFB" => {
+                mem::transmute::<pfn::PassthroughStartFB, pfn::VoidFunction>(passthrough_start_fb)
+            }
+            b"xrPassthroughPauseFB" => {
+                mem::transmute::<pfn::PassthroughPauseFB, pfn::VoidFunction>(passthrough_pause_fb)
+            }
+            b"xrCreatePassthroughLayerFB" => mem::transmute::<
+                pfn::CreatePassthroughLayerFB,
+                pfn::VoidFunction,
+            >(create_passthrough_layer_fb),
+            b"xrDestroyPassthroughLayerFB" => mem::transmute::<
+                pfn::DestroyPassthroughLayerFB,
+                pfn::VoidFunction,
+            >(destroy_passthrough_layer_fb),
+            b"xrPassthroughLayerPauseFB" => mem::transmute::<
+                pfn::PassthroughLayerPauseFB,
+                pfn::VoidFunction,
+            >(passthrough_layer_pause_fb),
+            b"xrPassthroughLayerResumeFB" => mem::transmute::<
+                pfn::PassthroughLayerResumeFB,
+                pfn::VoidFunction,
+            >(passthrough_layer_resume_fb),
+            b"xrPassthroughLayerSetStyleFB" => mem::transmute::<
+                pfn::PassthroughLayerSetStyleFB,
+                pfn::VoidFunction,
+            >(passthrough_layer_set_style_fb),
+            b"xrCreateGeometryInstanceFB" => mem::transmute::<
+                pfn::CreateGeometryInstanceFB,
+                pfn::VoidFunction,
+            >(create_geometry_instance_fb),
+            b"xrDestroyGeometryInstanceFB" => mem::transmute::<
+                pfn::DestroyGeometryInstanceFB,
+                pfn::VoidFunction,
+            >(destroy_geometry_instance_fb),
+            b"xrGeometryInstanceSetTransformFB" => mem::transmute::<
+                pfn::GeometryInstanceSetTransformFB,
+                pfn::VoidFunction,
+            >(geometry_instance_set_transform_fb),
+            b"xrQuerySpacesFB" => {
+                mem::transmute::<pfn::QuerySpacesFB, pfn::VoidFunction>(query_spaces_fb)
+            }
+            b"xrRetrieveSpaceQueryResultsFB" => mem::transmute::<
+                pfn::RetrieveSpaceQueryResultsFB,
+                pfn::VoidFunction,
+            >(retrieve_space_query_results_fb),
+            b"xrSaveSpaceFB" => {
+                mem::transmute::<pfn::SaveSpaceFB, pfn::VoidFunction>(save_space_fb)
+            }
+            b"xrEraseSpaceFB" => {
+                mem::transmute::<pfn::EraseSpaceFB, pfn::VoidFunction>(erase_space_fb)
+            }
+            b"xrSaveSpaceListFB" => {
+                mem::transmute::<pfn::SaveSpaceListFB, pfn::VoidFunction>(save_space_list_fb)
+            }
+            b"xrShareSpacesFB" => {
+                mem::transmute::<pfn::ShareSpacesFB, pfn::VoidFunction>(share_spaces_fb)
+            }
+            b"xrGetSpaceContainerFB" => {
+                mem::transmute::<pfn::GetSpaceContainerFB, pfn::VoidFunction>(
+                    get_space_container_fb,
+                )
+            }
+            b"xrGetSpaceBoundingBox2DFB" => mem::transmute::<
+                pfn::GetSpaceBoundingBox2DFB,
+                pfn::VoidFunction,
+            >(get_space_bounding_box2_dfb),
+            b"xrGetSpaceBoundingBox3DFB" => mem::transmute::<
+                pfn::GetSpaceBoundingBox3DFB,
+                pfn::VoidFunction,
+            >(get_space_bounding_box3_dfb),
+            b"xrGetSpaceSemanticLabelsFB" => mem::transmute::<
+                pfn::GetSpaceSemanticLabelsFB,
+                pfn::VoidFunction,
+            >(get_space_semantic_labels_fb),
+            b"xrGetSpaceBoundary2DFB" => mem::transmute::<
+                pfn::GetSpaceBoundary2DFB,
+                pfn::VoidFunction,
+            >(get_space_boundary2_dfb),
+            b"xrGetSpaceRoomLayoutFB" => mem::transmute::<
+                pfn::GetSpaceRoomLayoutFB,
+                pfn::VoidFunction,
+            >(get_space_room_layout_fb),
+            b"xrRequestSceneCaptureFB" => mem::transmute::<
+                pfn::RequestSceneCaptureFB,
+                pfn::VoidFunction,
+            >(request_scene_capture_fb),
+            b"xrPassthroughLayerSetKeyboardHandsIntensityFB" => {
+                mem::transmute::<pfn::PassthroughLayerSetKeyboardHandsIntensityFB, pfn::VoidFunction>(
+                    passthrough_layer_set_keyboard_hands_intensity_fb,
+                )
+            }
+            b"xrCreateSpatialAnchorStoreConnectionMSFT" => {
+                mem::transmute::<pfn::CreateSpatialAnchorStoreConnectionMSFT, pfn::VoidFunction>(
+                    create_spatial_anchor_store_connection_msft,
+                )
+            }
+            b"xrDestroySpatialAnchorStoreConnectionMSFT" => {
+                mem::transmute::<pfn::DestroySpatialAnchorStoreConnectionMSFT, pfn::VoidFunction>(
+                    destroy_spatial_anchor_store_connection_msft,
+                )
+            }
+            b"xrPersistSpatialAnchorMSFT" => mem::transmute::<
+                pfn::PersistSpatialAnchorMSFT,
+                pfn::VoidFunction,
+            >(persist_spatial_anchor_msft),
+            b"xrEnumeratePersistedSpatialAnchorNamesMSFT" => {
+                mem::transmute::<pfn::EnumeratePersistedSpatialAnchorNamesMSFT, pfn::VoidFunction>(
+                    enumerate_persisted_spatial_anchor_names_msft,
+                )
+            }
+            b"xrCreateSpatialAnchorFromPersistedNameMSFT" => {
+                mem::transmute::<pfn::CreateSpatialAnchorFromPersistedNameMSFT, pfn::VoidFunction>(
+                    create_spatial_anchor_from_persisted_name_msft,
+                )
+            }
+            b"xrUnpersistSpatialAnchorMSFT" => mem::transmute::<
+                pfn::UnpersistSpatialAnchorMSFT,
+                pfn::VoidFunction,
+            >(unpersist_spatial_anchor_msft),
+            b"xrClearSpatialAnchorStoreMSFT" => mem::transmute::<
+                pfn::ClearSpatialAnchorStoreMSFT,
+                pfn::VoidFunction,
+            >(clear_spatial_anchor_store_msft),
+            b"xrCreateFacialTrackerHTC" => mem::transmute::<
+                pfn::CreateFacialTrackerHTC,
+                pfn::VoidFunction,
+            >(create_facial_tracker_htc),
+            b"xrDestroyFacialTrackerHTC" => mem::transmute::<
+                pfn::DestroyFacialTrackerHTC,
+                pfn::VoidFunction,
+            >(destroy_facial_tracker_htc),
+            b"xrGetFacialExpressionsHTC" => mem::transmute::<
+                pfn::GetFacialExpressionsHTC,
+                pfn::VoidFunction,
+            >(get_facial_expressions_htc),
+            b"xrCreatePassthroughHTC" => mem::transmute::<
+                pfn::CreatePassthroughHTC,
+                pfn::VoidFunction,
+            >(create_passthrough_htc),
+            b"xrDestroyPassthroughHTC" => mem::transmute::<
+                pfn::DestroyPassthroughHTC,
+                pfn::VoidFunction,
+            >(destroy_passthrough_htc),
+            b"xrEnumerateViveTrackerPathsHTCX" => mem::transmute::<
+                pfn::EnumerateViveTrackerPathsHTCX,
+                pfn::VoidFunction,
+            >(enumerate_vive_tracker_paths_htcx),
+            b"xrSetMarkerTrackingVARJO" => mem::transmute::<
+                pfn::SetMarkerTrackingVARJO,
+                pfn::VoidFunction,
+            >(set_marker_tracking_varjo),
+            b"xrSetMarkerTrackingTimeoutVARJO" => mem::transmute::<
+                pfn::SetMarkerTrackingTimeoutVARJO,
+                pfn::VoidFunction,
+            >(set_marker_tracking_timeout_varjo),
+            b"xrSetMarkerTrackingPredictionVARJO" => {
+                mem::transmute::<pfn::SetMarkerTrackingPredictionVARJO, pfn::VoidFunction>(
+                    set_marker_tracking_prediction_varjo,
+                )
+            }
+            b"xrGetMarkerSizeVARJO" => {
+                mem::transmute::<pfn::GetMarkerSizeVARJO, pfn::VoidFunction>(get_marker_size_varjo)
+            }
+            b"xrCreateMarkerSpaceVARJO" => mem::transmute::<
+                pfn::CreateMarkerSpaceVARJO,
+                pfn::VoidFunction,
+            >(create_marker_space_varjo),
+            b"xrSetDigitalLensControlALMALENCE" => mem::transmute::<
+                pfn::SetDigitalLensControlALMALENCE,
+                pfn::VoidFunction,
+            >(set_digital_lens_control_almalence),
+            b"xrSetViewOffsetVARJO" => {
+                mem::transmute::<pfn::SetViewOffsetVARJO, pfn::VoidFunction>(set_view_offset_varjo)
+            }
+            b"xrEnumerateExternalCamerasOCULUS" => mem::transmute::<
+                pfn::EnumerateExternalCamerasOCULUS,
+                pfn::VoidFunction,
+            >(enumerate_external_cameras_oculus),
+            b"xrCreatePassthroughColorLutMETA" => mem::transmute::<
+                pfn::CreatePassthroughColorLutMETA,
+                pfn::VoidFunction,
+            >(create_passthrough_color_lut_meta),
+            b"xrDestroyPassthroughColorLutMETA" => mem::transmute::<
+                pfn::DestroyPassthroughColorLutMETA,
+                pfn::VoidFunction,
+            >(destroy_passthrough_color_lut_meta),
+            b"xrUpdatePassthroughColorLutMETA" => mem::transmute::<
+                pfn::UpdatePassthroughColorLutMETA,
+                pfn::VoidFunction,
+            >(update_passthrough_color_lut_meta),
+            b"xrEnumeratePerformanceMetricsCounterPathsMETA" => {
+                mem::transmute::<pfn::EnumeratePerformanceMetricsCounterPathsMETA, pfn::VoidFunction>(
+                    enumerate_performance_metrics_counter_paths_meta,
+                )
+            }
+            b"xrSetPerformanceMetricsStateMETA" => mem::transmute::<
+                pfn::SetPerformanceMetricsStateMETA,
+                pfn::VoidFunction,
+            >(set_performance_metrics_state_meta),
+            b"xrGetPerformanceMetricsStateMETA" => mem::transmute::<
+                pfn::GetPerformanceMetricsStateMETA,
+                pfn::VoidFunction,
+            >(get_performance_metrics_state_meta),
+            b"xrQueryPerformanceMetricsCounterMETA" => {
+                mem::transmute::<pfn::QueryPerformanceMetricsCounterMETA, pfn::VoidFunction>(
+                    query_performance_metrics_counter_meta,
+                )
+            }
+            b"xrGetPassthroughPreferencesMETA" => mem::transmute::<
+                pfn::GetPassthroughPreferencesMETA,
+                pfn::VoidFunction,
+            >(get_passthrough_preferences_meta),
+            b"xrApplyFoveationHTC" => {
+                mem::transmute::<pfn::ApplyFoveationHTC, pfn::VoidFunction>(apply_foveation_htc)
+            }
+            b"xrCreateSpaceFromCoordinateFrameUIDML" => {
+                mem::transmute::<pfn::CreateSpaceFromCoordinateFrameUIDML, pfn::VoidFunction>(
+                    create_space_from_coordinate_frame_uidml,
+                )
+            }
+            b"xrGetDeviceSampleRateFB" => mem::transmute::<
+                pfn::GetDeviceSampleRateFB,
+                pfn::VoidFunction,
+            >(get_device_sample_rate_fb),
+            b"xrSetTrackingOptimizationSettingsHintQCOM" => {
+                mem::transmute::<pfn::SetTrackingOptimizationSettingsHintQCOM, pfn::VoidFunction>(
+                    set_tracking_optimization_settings_hint_qcom,
+                )
+            }
+            b"xrCreateSpaceUserFB" => {
+                mem::transmute::<pfn::CreateSpaceUserFB, pfn::VoidFunction>(create_space_user_fb)
+            }
+            b"xrGetSpaceUserIdFB" => {
+                mem::transmute::<pfn::GetSpaceUserIdFB, pfn::VoidFunction>(get_space_user_id_fb)
+            }
+            b"xrDestroySpaceUserFB" => {
+                mem::transmute::<pfn::DestroySpaceUserFB, pfn::VoidFunction>(destroy_space_user_fb)
+            }
+            b"xrApplyForceFeedbackCurlMNDX" => mem::transmute::<
+                pfn::ApplyForceFeedbackCurlMNDX,
+                pfn::VoidFunction,
+            >(apply_force_feedback_curl_mndx),
+            b"xrCreatePlaneDetectorEXT" => mem::transmute::<
+                pfn::CreatePlaneDetectorEXT,
+                pfn::VoidFunction,
+            >(create_plane_detector_ext),
+            b"xrDestroyPlaneDetectorEXT" => mem::transmute::<
+                pfn::DestroyPlaneDetectorEXT,
+                pfn::VoidFunction,
+            >(destroy_plane_detector_ext),
+            b"xrBeginPlaneDetectionEXT" => mem::transmute::<
+                pfn::BeginPlaneDetectionEXT,
+                pfn::VoidFunction,
+            >(begin_plane_detection_ext),
+            b"xrGetPlaneDetectionStateEXT" => mem::transmute::<
+                pfn::GetPlaneDetectionStateEXT,
+                pfn::VoidFunction,
+            >(get_plane_detection_state_ext),
+            b"xrGetPlaneDetectionsEXT" => mem::transmute::<
+                pfn::GetPlaneDetectionsEXT,
+                pfn::VoidFunction,
+            >(get_plane_detections_ext),
+            b"xrGetPlanePolygonBufferEXT" => mem::transmute::<
+                pfn::GetPlanePolygonBufferEXT,
+                pfn::VoidFunction,
+            >(get_plane_polygon_buffer_ext),
+            b"xrCreateVirtualKeyboardMETA" => mem::transmute::<
+                pfn::CreateVirtualKeyboardMETA,
+                pfn::VoidFunction,
+            >(create_virtual_keyboard_meta),
+            b"xrDestroyVirtualKeyboardMETA" => mem::transmute::<
+                pfn::DestroyVirtualKeyboardMETA,
+                pfn::VoidFunction,
+            >(destroy_virtual_keyboard_meta),
+            b"xrCreateVirtualKeyboardSpaceMETA" => mem::transmute::<
+                pfn::CreateVirtualKeyboardSpaceMETA,
+                pfn::VoidFunction,
+            >(create_virtual_keyboard_space_meta),
+            b"xrSuggestVirtualKeyboardLocationMETA" => {
+                mem::transmute::<pfn::SuggestVirtualKeyboardLocationMETA, pfn::VoidFunction>(
+                    suggest_virtual_keyboard_location_meta,
+                )
+            }
+            b"xrGetVirtualKeyboardScaleMETA" => mem::transmute::<
+                pfn::GetVirtualKeyboardScaleMETA,
+                pfn::VoidFunction,
+            >(get_virtual_keyboard_scale_meta),
+            b"xrSetVirtualKeyboardModelVisibilityMETA" => {
+                mem::transmute::<pfn::SetVirtualKeyboardModelVisibilityMETA, pfn::VoidFunction>(
+                    set_virtual_keyboard_model_visibility_meta,
+                )
+            }
+            b"xrGetVirtualKeyboardModelAnimationStatesMETA" => {
+                mem::transmute::<pfn::GetVirtualKeyboardModelAnimationStatesMETA, pfn::VoidFunction>(
+                    get_virtual_keyboard_model_animation_states_meta,
+                )
+            }
+            b"xrGetVirtualKeyboardDirtyTexturesMETA" => {
+                mem::transmute::<pfn::GetVirtualKeyboardDirtyTexturesMETA, pfn::VoidFunction>(
+                    get_virtual_keyboard_dirty_textures_meta,
+                )
+            }
+            b"xrGetVirtualKeyboardTextureDataMETA" => {
+                mem::transmute::<pfn::GetVirtualKeyboardTextureDataMETA, pfn::VoidFunction>(
+                    get_virtual_keyboard_texture_data_meta,
+                )
+            }
+            b"xrSendVirtualKeyboardInputMETA" => mem::transmute::<
+                pfn::SendVirtualKeyboardInputMETA,
+                pfn::VoidFunction,
+            >(send_virtual_keyboard_input_meta),
+            b"xrChangeVirtualKeyboardTextContextMETA" => {
+                mem::transmute::<pfn::ChangeVirtualKeyboardTextContextMETA, pfn::VoidFunction>(
+                    change_virtual_keyboard_text_context_meta,
+                )
+            }
+            b"xrGetVulkanGraphicsRequirements2KHR" => {
+                mem::transmute::<pfn::GetVulkanGraphicsRequirements2KHR, pfn::VoidFunction>(
+                    get_vulkan_graphics_requirements2_khr,
+                )
+            }
+            _ => return None,
+        })
+    }
+}
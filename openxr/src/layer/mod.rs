@@ -0,0 +1,570 @@
+//! Framework for implementing OpenXR API layers in Rust
+//!
+//! An API layer is a shared library the OpenXR loader inserts between an application and the
+//! runtime, letting it observe or alter every OpenXR call. To write one, implement [`Layer`],
+//! overriding only the commands of interest, and export it from a `cdylib` crate with
+//! [`export_layer!`](crate::export_layer). The loader discovers layers through JSON manifests,
+//! which [`Manifest`] can generate. Alternatively, an application can insert a layer in front of
+//! its own [`Entry`] with [`InProcess`].
+//!
+//! Available if the `layer` feature is enabled.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use openxr::{layer, sys};
+//!
+//! struct FrameCounter(std::sync::atomic::AtomicU64);
+//!
+//! impl layer::Layer for FrameCounter {
+//!     unsafe fn end_frame(
+//!         &self,
+//!         next: &layer::Dispatch,
+//!         session: sys::Session,
+//!         frame_end_info: *const sys::FrameEndInfo,
+//!     ) -> sys::Result {
+//!         self.0.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+//!         (next.end_frame.unwrap())(session, frame_end_info)
+//!     }
+//! }
+//!
+//! openxr::export_layer!("XR_APILAYER_example_frame_counter", |_info| {
+//!     Ok(FrameCounter(Default::default()))
+//! });
+//! ```
+
+use std::any::TypeId;
+use std::collections::BTreeMap;
+use std::ffi::CStr;
+use std::fmt::Write as _;
+use std::os::raw::c_char;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, RwLock, RwLockReadGuard, RwLockWriteGuard, Weak};
+use std::{fs, io, mem};
+
+use sys::loader::{
+    ApiLayerCreateInfo, XrNegotiateApiLayerRequest, XrNegotiateLoaderInfo,
+    CURRENT_LOADER_API_LAYER_VERSION,
+};
+use sys::pfn;
+
+use crate::loader_interface::{self, ManifestWriter};
+use crate::*;
+
+mod generated;
+pub use generated::{Dispatch, Layer};
+
+/// Information about an instance whose creation passed through the layer
+#[derive(Copy, Clone)]
+pub struct InstanceInfo<'a> {
+    /// The newly created instance
+    pub instance: sys::Instance,
+    pub application_name: &'a str,
+    pub application_version: u32,
+    pub engine_name: &'a str,
+    pub engine_version: u32,
+    pub api_version: Version,
+    pub enabled_extensions: &'a [&'a str],
+    /// Settings file the loader found for this layer, if any
+    pub settings_file: Option<&'a Path>,
+    /// Function pointers for the next link in the call chain
+    pub next: &'a Dispatch,
+}
+
+#[doc(hidden)]
+pub type CreateFn = fn(&InstanceInfo<'_>) -> Result<Box<dyn Layer>>;
+
+#[derive(Copy, Clone)]
+struct Registration {
+    name: &'static str,
+    create: CreateFn,
+}
+
+struct InstanceState {
+    layer: Box<dyn Layer>,
+    next: Dispatch,
+}
+
+static REGISTRATION: Mutex<Option<Registration>> = Mutex::new(None);
+
+/// Every handle created through the layer, keyed by handle type and raw value, mapped to the state
+/// of the instance that owns it
+#[allow(clippy::type_complexity)]
+static OBJECTS: RwLock<BTreeMap<(TypeId, u64), Arc<InstanceState>>> = RwLock::new(BTreeMap::new());
+
+fn objects() -> RwLockReadGuard<'static, BTreeMap<(TypeId, u64), Arc<InstanceState>>> {
+    OBJECTS.read().unwrap_or_else(|e| e.into_inner())
+}
+
+fn objects_mut() -> RwLockWriteGuard<'static, BTreeMap<(TypeId, u64), Arc<InstanceState>>> {
+    OBJECTS.write().unwrap_or_else(|e| e.into_inner())
+}
+
+fn lookup<H: 'static>(handle: u64) -> Option<Arc<InstanceState>> {
+    let objects = objects();
+    if let Some(state) = objects.get(&(TypeId::of::<H>(), handle)) {
+        return Some(state.clone());
+    }
+    // Handles the layer never saw created, e.g. those returned through extension structures, can
+    // still be attributed unambiguously when only one instance exists.
+    let mut instances = objects
+        .iter()
+        .filter(|((ty, _), _)| *ty == TypeId::of::<sys::Instance>());
+    let (_, state) = instances.next()?;
+    if instances.next().is_some() {
+        return None;
+    }
+    Some(state.clone())
+}
+
+/// Dispatch a command to the layer responsible for `handle`
+fn call<H: 'static>(
+    handle: u64,
+    f: impl FnOnce(&dyn Layer, &Dispatch) -> sys::Result,
+) -> sys::Result {
+    let state = match lookup::<H>(handle) {
+        Some(x) => x,
+        None => return sys::Result::ERROR_HANDLE_INVALID,
+    };
+    // Unwinding into the application is undefined behavior
+    panic::catch_unwind(AssertUnwindSafe(|| f(&*state.layer, &state.next)))
+        .unwrap_or(sys::Result::ERROR_RUNTIME_FAILURE)
+}
+
+fn created<P: 'static, H: 'static>(parent: u64, handle: u64) {
+    if let Some(state) = lookup::<P>(parent) {
+        objects_mut().insert((TypeId::of::<H>(), handle), state);
+    }
+}
+
+fn destroyed<H: 'static>(handle: u64) {
+    let mut objects = objects_mut();
+    let state = match objects.remove(&(TypeId::of::<H>(), handle)) {
+        Some(x) => x,
+        None => return,
+    };
+    if TypeId::of::<H>() == TypeId::of::<sys::Instance>() {
+        objects.retain(|_, x| !Arc::ptr_eq(x, &state));
+    }
+    drop(objects);
+    // The layer itself may be dropped here, outside the lock
+    drop(state);
+}
+
+unsafe fn load(
+    get_instance_proc_addr: pfn::GetInstanceProcAddr,
+    instance: sys::Instance,
+    name: &[u8],
+) -> Option<pfn::VoidFunction> {
+    let mut f = None;
+    let result = get_instance_proc_addr(instance, name.as_ptr() as *const c_char, &mut f);
+    if result.into_raw() < 0 {
+        return None;
+    }
+    f
+}
+
+unsafe extern "system" fn get_instance_proc_addr(
+    instance: sys::Instance,
+    name: *const c_char,
+    function: *mut Option<pfn::VoidFunction>,
+) -> sys::Result {
+    let name = CStr::from_ptr(name).to_bytes();
+    if name == b"xrGetInstanceProcAddr" {
+        *function = Some(
+            mem::transmute::<pfn::GetInstanceProcAddr, pfn::VoidFunction>(get_instance_proc_addr),
+        );
+        return sys::Result::SUCCESS;
+    }
+    let state = objects()
+        .get(&(TypeId::of::<sys::Instance>(), instance.into_raw()))
+        .cloned();
+    let state = match state {
+        Some(x) => x,
+        None => {
+            // Commands usable without an instance are handled by the loader itself
+            *function = None;
+            return if instance == sys::Instance::NULL {
+                sys::Result::ERROR_FUNCTION_UNSUPPORTED
+            } else {
+                sys::Result::ERROR_HANDLE_INVALID
+            };
+        }
+    };
+    let result = (state.next.get_instance_proc_addr)(instance, name.as_ptr() as _, function);
+    if result.into_raw() >= 0 && (*function).is_some() {
+        if let Some(thunk) = generated::thunk(name) {
+            *function = Some(thunk);
+        }
+    }
+    result
+}
+
+unsafe extern "system" fn create_api_layer_instance(
+    info: *const sys::InstanceCreateInfo,
+    api_layer_info: *const ApiLayerCreateInfo,
+    instance: *mut sys::Instance,
+) -> sys::Result {
+    let registration = match *REGISTRATION.lock().unwrap_or_else(|e| e.into_inner()) {
+        Some(x) => x,
+        None => return sys::Result::ERROR_INITIALIZATION_FAILED,
+    };
+    if api_layer_info.is_null() || (*api_layer_info).next_info.is_null() {
+        return sys::Result::ERROR_INITIALIZATION_FAILED;
+    }
+    let api_layer_info = &*api_layer_info;
+    let next_info = &*api_layer_info.next_info;
+    if fixed_str(&next_info.layer_name) != registration.name {
+        return sys::Result::ERROR_INITIALIZATION_FAILED;
+    }
+    let settings_file = fixed_str(&api_layer_info.settings_file_location);
+    let settings_file = PathBuf::from(settings_file);
+    let settings_file = if settings_file.as_os_str().is_empty() {
+        None
+    } else {
+        Some(&*settings_file)
+    };
+
+    create_instance(
+        info,
+        instance,
+        || {
+            // Pass creation down the chain, consuming our link
+            let mut next_api_layer_info = *api_layer_info;
+            next_api_layer_info.next_info = next_info.next;
+            (next_info.next_create_api_layer_instance)(info, &next_api_layer_info, instance)
+        },
+        next_info.next_get_instance_proc_addr,
+        settings_file,
+        &registration.create,
+    )
+}
+
+/// Create an instance through `create_next`, then the layer that will see its calls
+unsafe fn create_instance(
+    info: *const sys::InstanceCreateInfo,
+    instance: *mut sys::Instance,
+    create_next: impl FnOnce() -> sys::Result,
+    next_get_instance_proc_addr: pfn::GetInstanceProcAddr,
+    settings_file: Option<&Path>,
+    create: &dyn Fn(&InstanceInfo<'_>) -> Result<Box<dyn Layer>>,
+) -> sys::Result {
+    let result = create_next();
+    if result.into_raw() < 0 {
+        return result;
+    }
+    let next = Dispatch::load(next_get_instance_proc_addr, *instance);
+
+    let info = &*info;
+    let app = &info.application_info;
+    let enabled_extensions = (0..info.enabled_extension_count as usize)
+        .map(|i| {
+            let name = CStr::from_ptr(*info.enabled_extension_names.add(i));
+            name.to_str().unwrap_or_default()
+        })
+        .collect::<Vec<_>>();
+    let instance_info = InstanceInfo {
+        instance: *instance,
+        application_name: fixed_str(&app.application_name),
+        application_version: app.application_version,
+        engine_name: fixed_str(&app.engine_name),
+        engine_version: app.engine_version,
+        api_version: app.api_version,
+        enabled_extensions: &enabled_extensions,
+        settings_file,
+        next: &next,
+    };
+    let layer = panic::catch_unwind(AssertUnwindSafe(|| create(&instance_info)))
+        .unwrap_or(Err(sys::Result::ERROR_RUNTIME_FAILURE.into()));
+    match layer {
+        Ok(layer) => {
+            objects_mut().insert(
+                (TypeId::of::<sys::Instance>(), (*instance).into_raw()),
+                Arc::new(InstanceState { layer, next }),
+            );
+            result
+        }
+        Err(e) => {
+            if let Some(destroy_instance) = next.destroy_instance {
+                destroy_instance(*instance);
+            }
+            *instance = sys::Instance::NULL;
+            e.into()
+        }
+    }
+}
+
+/// Implementation of `xrNegotiateLoaderApiLayerInterface`; use [`export_layer!`](crate::export_layer)
+/// instead
+#[doc(hidden)]
+pub unsafe fn negotiate(
+    loader_info: *const XrNegotiateLoaderInfo,
+    api_layer_name: *const c_char,
+    api_layer_request: *mut XrNegotiateApiLayerRequest,
+    name: &'static str,
+    create: CreateFn,
+) -> sys::Result {
+    if loader_info.is_null() || api_layer_name.is_null() || api_layer_request.is_null() {
+        return sys::Result::ERROR_INITIALIZATION_FAILED;
+    }
+    if !loader_interface::loader_info_compatible(&*loader_info, CURRENT_LOADER_API_LAYER_VERSION) {
+        return sys::Result::ERROR_INITIALIZATION_FAILED;
+    }
+    if CStr::from_ptr(api_layer_name).to_bytes() != name.as_bytes() {
+        return sys::Result::ERROR_INITIALIZATION_FAILED;
+    }
+    let request = &mut *api_layer_request;
+    if request.ty != XrNegotiateApiLayerRequest::TYPE
+        || request.struct_version != XrNegotiateApiLayerRequest::VERSION
+        || request.struct_size != mem::size_of::<XrNegotiateApiLayerRequest>()
+    {
+        return sys::Result::ERROR_INITIALIZATION_FAILED;
+    }
+
+    *REGISTRATION.lock().unwrap_or_else(|e| e.into_inner()) = Some(Registration { name, create });
+    request.layer_interface_version = CURRENT_LOADER_API_LAYER_VERSION;
+    request.layer_api_version = CURRENT_API_VERSION;
+    request.get_instance_proc_addr = Some(get_instance_proc_addr);
+    request.create_api_layer_instance = Some(create_api_layer_instance);
+    sys::Result::SUCCESS
+}
+
+/// Export an API layer from a shared library
+///
+/// Defines the library's `xrNegotiateLoaderApiLayerInterface`, through which the loader finds the
+/// layer named by the first argument. The second argument is called with an [`InstanceInfo`] for
+/// each instance created with the layer enabled, and returns the [`Layer`] that will see that
+/// instance's calls, or an error that fails instance creation.
+///
+/// The crate must be built with `crate-type = ["cdylib"]`, and may export at most one layer.
+///
+/// [`InstanceInfo`]: crate::layer::InstanceInfo
+/// [`Layer`]: crate::layer::Layer
+#[macro_export]
+macro_rules! export_layer {
+    ($name:expr, $create:expr) => {
+        #[no_mangle]
+        #[allow(non_snake_case)]
+        pub unsafe extern "system" fn xrNegotiateLoaderApiLayerInterface(
+            loader_info: *const $crate::sys::loader::XrNegotiateLoaderInfo,
+            api_layer_name: *const ::std::os::raw::c_char,
+            api_layer_request: *mut $crate::sys::loader::XrNegotiateApiLayerRequest,
+        ) -> $crate::sys::Result {
+            fn create(
+                info: &$crate::layer::InstanceInfo<'_>,
+            ) -> $crate::Result<::std::boxed::Box<dyn $crate::layer::Layer>> {
+                let layer: $crate::Result<_> = ($create)(info);
+                Ok(::std::boxed::Box::new(layer?))
+            }
+            $crate::layer::negotiate(
+                loader_info,
+                api_layer_name,
+                api_layer_request,
+                $name,
+                create,
+            )
+        }
+    };
+}
+
+/// A layer inserted in front of an [`Entry`] by the application itself, bypassing the loader
+///
+/// Instances created through [`InProcess::entry`] have their calls routed through a layer made by
+/// the given function, exactly as if the loader had enabled it. This suits layers shipped as part
+/// of an application, and testing layers without installing them. Up to 32 may be alive at once.
+pub struct InProcess {
+    shared: Arc<Local>,
+}
+
+type LocalCreateFn = dyn Fn(&InstanceInfo<'_>) -> Result<Box<dyn Layer>> + Send + Sync;
+
+struct Local {
+    next: Entry,
+    create: Box<LocalCreateFn>,
+    slot: usize,
+}
+
+impl InProcess {
+    pub fn new<L: Layer>(
+        next: &Entry,
+        create: impl Fn(&InstanceInfo<'_>) -> Result<L> + Send + Sync + 'static,
+    ) -> Self {
+        let mut slots = LOCAL_SLOTS.lock().unwrap_or_else(|e| e.into_inner());
+        let slot = match slots.iter().position(|x| x.strong_count() == 0) {
+            Some(x) => x,
+            None => {
+                assert!(
+                    slots.len() < LOCAL_SLOT_COUNT,
+                    "at most {} in-process layers may be alive at once",
+                    LOCAL_SLOT_COUNT
+                );
+                slots.push(Weak::new());
+                slots.len() - 1
+            }
+        };
+        let shared = Arc::new(Local {
+            next: next.clone(),
+            create: Box::new(move |info| Ok(Box::new(create(info)?) as Box<dyn Layer>)),
+            slot,
+        });
+        slots[slot] = Arc::downgrade(&shared);
+        Self { shared }
+    }
+
+    /// An `Entry` that creates instances with the layer enabled
+    pub fn entry(&self) -> Entry {
+        unsafe {
+            Entry::from_get_instance_proc_addr(LOCAL_SLOT_FNS[self.shared.slot].0)
+                .expect("next entry exposes all global commands")
+        }
+    }
+}
+
+const LOCAL_SLOT_COUNT: usize = 32;
+
+/// In-process layers by slot
+///
+/// `xrGetInstanceProcAddr` and `xrCreateInstance` carry no handle to tell layers apart by, so each
+/// layer gets a slot with its own monomorphized copies of them.
+static LOCAL_SLOTS: Mutex<Vec<Weak<Local>>> = Mutex::new(Vec::new());
+
+macro_rules! local_slots {
+    ($($n:literal)*) => {
+        const LOCAL_SLOT_FNS: [(pfn::GetInstanceProcAddr, pfn::CreateInstance); LOCAL_SLOT_COUNT] = [
+            $((local_get_instance_proc_addr::<$n>, local_create_instance::<$n>),)*
+        ];
+    };
+}
+
+local_slots!(0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31);
+
+fn local(slot: usize) -> Option<Arc<Local>> {
+    LOCAL_SLOTS
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .get(slot)?
+        .upgrade()
+}
+
+unsafe extern "system" fn local_get_instance_proc_addr<const N: usize>(
+    instance: sys::Instance,
+    name: *const c_char,
+    function: *mut Option<pfn::VoidFunction>,
+) -> sys::Result {
+    let (local_get_instance_proc_addr, local_create_instance) = LOCAL_SLOT_FNS[N];
+    match CStr::from_ptr(name).to_bytes() {
+        b"xrGetInstanceProcAddr" => {
+            *function = Some(
+                mem::transmute::<pfn::GetInstanceProcAddr, pfn::VoidFunction>(
+                    local_get_instance_proc_addr,
+                ),
+            );
+            sys::Result::SUCCESS
+        }
+        b"xrCreateInstance" => {
+            *function = Some(mem::transmute::<pfn::CreateInstance, pfn::VoidFunction>(
+                local_create_instance,
+            ));
+            sys::Result::SUCCESS
+        }
+        _ if instance == sys::Instance::NULL => match local(N) {
+            Some(local) => (local.next.fp().get_instance_proc_addr)(instance, name, function),
+            None => sys::Result::ERROR_RUNTIME_FAILURE,
+        },
+        _ => get_instance_proc_addr(instance, name, function),
+    }
+}
+
+unsafe extern "system" fn local_create_instance<const N: usize>(
+    info: *const sys::InstanceCreateInfo,
+    instance: *mut sys::Instance,
+) -> sys::Result {
+    let local = match local(N) {
+        Some(x) => x,
+        None => return sys::Result::ERROR_RUNTIME_FAILURE,
+    };
+    create_instance(
+        info,
+        instance,
+        || (local.next.fp().create_instance)(info, instance),
+        local.next.fp().get_instance_proc_addr,
+        None,
+        &*local.create,
+    )
+}
+
+/// Description of an API layer for the loader
+///
+/// The loader discovers layers through JSON manifests. Explicit layers are only loaded when an
+/// application or the `XR_ENABLE_API_LAYERS` environment variable requests them; implicit layers
+/// are loaded automatically unless disabled through `disable_environment`. See the [loader
+/// documentation](https://registry.khronos.org/OpenXR/specs/1.0/loader.html#api-layer-manifest-file-format)
+/// for where manifests are searched for.
+#[derive(Debug, Clone)]
+pub struct Manifest {
+    /// Name of the layer, as passed to [`export_layer!`](crate::export_layer)
+    pub name: String,
+    /// Path to the shared library, absolute or relative to the manifest
+    pub library_path: PathBuf,
+    pub api_version: Version,
+    pub implementation_version: String,
+    pub description: String,
+    /// Instance extensions implemented by the layer, with their spec versions
+    pub instance_extensions: Vec<(String, u32)>,
+    /// Environment variable that disables the layer when set; required for implicit layers
+    pub disable_environment: Option<String>,
+    /// Environment variable that must be set for an implicit layer to be enabled
+    pub enable_environment: Option<String>,
+}
+
+impl Manifest {
+    pub fn new(name: impl Into<String>, library_path: impl Into<PathBuf>) -> Self {
+        Self {
+            name: name.into(),
+            library_path: library_path.into(),
+            api_version: CURRENT_API_VERSION,
+            implementation_version: "1".into(),
+            description: String::new(),
+            instance_extensions: Vec::new(),
+            disable_environment: None,
+            enable_environment: None,
+        }
+    }
+
+    /// Serialize in the loader's manifest format
+    pub fn to_json(&self) -> String {
+        let mut out = ManifestWriter::new("api_layer");
+        out.string("name", &self.name);
+        out.string("library_path", &self.library_path.to_string_lossy());
+        let api_version = format!("{}.{}", self.api_version.major(), self.api_version.minor());
+        out.string("api_version", &api_version);
+        out.string("implementation_version", &self.implementation_version);
+        out.string("description", &self.description);
+        if !self.instance_extensions.is_empty() {
+            let mut json = String::from("[");
+            for (i, (name, version)) in self.instance_extensions.iter().enumerate() {
+                if i != 0 {
+                    json.push(',');
+                }
+                json.push_str("\n            { \"name\": ");
+                loader_interface::write_str(&mut json, name);
+                write!(json, ", \"extension_version\": \"{}\" }}", version).unwrap();
+            }
+            json.push_str("\n        ]");
+            out.value("instance_extensions", &json);
+        }
+        if let Some(ref var) = self.disable_environment {
+            out.string("disable_environment", var);
+        }
+        if let Some(ref var) = self.enable_environment {
+            out.string("enable_environment", var);
+        }
+        out.finish()
+    }
+
+    /// Write the manifest to `path`
+    pub fn write(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        fs::write(path, self.to_json())
+    }
+}
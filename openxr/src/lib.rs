@@ -1,7 +1,11 @@
 //! To get started, construct an `Entry` object.
 
 // deref_addrof false positive: https://github.com/rust-lang/rust-clippy/issues/8247
-#![allow(clippy::transmute_ptr_to_ptr, clippy::deref_addrof)]
+#![allow(
+    clippy::transmute_ptr_to_ptr,
+    clippy::deref_addrof,
+    clippy::missing_transmute_annotations
+)]
 use std::os::raw::c_char;
 
 pub use sys::{
@@ -11,18 +15,32 @@ pub use sys::{
 
 mod generated;
 pub use generated::*;
+mod error;
+pub use error::*;
+mod next_chain;
+pub use next_chain::*;
 mod entry;
 pub use entry::*;
 mod instance;
 pub use instance::*;
 mod session;
 pub use session::*;
+mod session_driver;
+pub use session_driver::*;
+mod async_loop;
+pub use async_loop::*;
 mod frame_stream;
 pub use frame_stream::*;
+mod frame_timing;
+pub use frame_timing::*;
 mod graphics;
 pub use graphics::*;
 mod swapchain;
 pub use swapchain::*;
+mod swapchain_format;
+pub use swapchain_format::*;
+mod swapchain_layout;
+pub use swapchain_layout::*;
 mod space;
 pub use space::*;
 mod action_set;
@@ -38,7 +56,6 @@ pub use foveation_fb::*;
 mod vive_tracker_paths;
 pub use vive_tracker_paths::*;
 mod display_refresh_rate;
-pub use display_refresh_rate::*;
 mod passthrough;
 pub use passthrough::*;
 mod eye_tracking_social;
@@ -47,14 +64,39 @@ mod face_tracking_fb;
 pub use face_tracking_fb::*;
 mod htc_facial_tracking;
 pub use htc_facial_tracking::*;
+mod debug_utils;
+pub use debug_utils::*;
 
+#[cfg(feature = "layer")]
+pub mod layer;
+#[cfg(feature = "rust-loader")]
+pub mod loader;
+#[cfg(any(feature = "layer", feature = "runtime"))]
+mod loader_interface;
+#[cfg(feature = "mock")]
+pub mod mock;
+#[cfg(feature = "runtime")]
+pub mod runtime;
+#[cfg(feature = "trace")]
+pub mod trace;
+#[cfg(feature = "validation")]
+pub mod validation;
+
+#[cfg(target_os = "android")]
+pub use builder::AndroidSurfaceSwapchainCreateInfoFB;
 pub use builder::{
-    CompositionLayerBase, CompositionLayerCubeKHR, CompositionLayerCylinderKHR,
-    CompositionLayerEquirectKHR, CompositionLayerProjection, CompositionLayerProjectionView,
-    CompositionLayerQuad, HapticBase, HapticVibration, SwapchainSubImage,
+    CompositionLayerAlphaBlendFB, CompositionLayerBase, CompositionLayerColorScaleBiasKHR,
+    CompositionLayerCubeKHR, CompositionLayerCylinderKHR, CompositionLayerDepthInfoKHR,
+    CompositionLayerDepthTestFB, CompositionLayerDepthTestVARJO, CompositionLayerEquirectKHR,
+    CompositionLayerImageLayoutFB, CompositionLayerProjection, CompositionLayerProjectionView,
+    CompositionLayerQuad, CompositionLayerReprojectionInfoMSFT,
+    CompositionLayerReprojectionPlaneOverrideMSFT, CompositionLayerSecureContentFB,
+    CompositionLayerSettingsFB, CompositionLayerSpaceWarpInfoFB, HapticBase, HapticVibration,
+    SecondaryViewConfigurationSwapchainCreateInfoMSFT, SwapchainCreateInfoFoveationFB,
+    SwapchainSubImage, VulkanSwapchainCreateInfoMETA, VulkanSwapchainFormatListCreateInfoKHR,
 };
 
-pub type Result<T, E = sys::Result> = std::result::Result<T, E>;
+pub type Result<T, E = Error> = std::result::Result<T, E>;
 
 // Reserved semantic paths
 pub const USER_HAND_LEFT: &str = "/user/hand/left";
@@ -64,11 +106,11 @@ pub const USER_GAMEPAD: &str = "/user/gamepad";
 pub const USER_TREADMILL: &str = "/user/treadmill";
 
 // FFI helpers
-fn cvt(x: sys::Result) -> Result<sys::Result> {
+fn cvt(function: &'static str, x: sys::Result) -> Result<sys::Result> {
     if x.into_raw() >= 0 {
         Ok(x)
     } else {
-        Err(x)
+        Err(Error::new(function, x))
     }
 }
 
@@ -96,8 +138,11 @@ fn fixed_str_bytes(x: &[c_char]) -> &[u8] {
     unsafe { std::mem::transmute(&x[..=end]) }
 }
 
-fn get_str(mut getter: impl FnMut(u32, &mut u32, *mut c_char) -> sys::Result) -> Result<String> {
-    let mut bytes = get_arr(|x, y, z| getter(x, y, z as _))?;
+fn get_str(
+    function: &'static str,
+    mut getter: impl FnMut(u32, &mut u32, *mut c_char) -> sys::Result,
+) -> Result<String> {
+    let mut bytes = get_arr(function, |x, y, z| getter(x, y, z as _))?;
     // Truncate at first null byte
     let first_nt = bytes
         .iter()
@@ -110,24 +155,28 @@ fn get_str(mut getter: impl FnMut(u32, &mut u32, *mut c_char) -> sys::Result) ->
 }
 
 fn get_arr<T: Copy>(
+    function: &'static str,
     mut getter: impl FnMut(u32, &mut u32, *mut T) -> sys::Result,
 ) -> Result<Vec<T>> {
     let mut output = 0;
-    cvt(getter(0, &mut output, std::ptr::null_mut()))?;
+    cvt(function, getter(0, &mut output, std::ptr::null_mut()))?;
     let mut buffer = Vec::with_capacity(output as usize);
     loop {
-        match cvt(getter(
-            buffer.capacity() as u32,
-            &mut output,
-            buffer.as_mut_ptr() as _,
-        )) {
+        match cvt(
+            function,
+            getter(
+                buffer.capacity() as u32,
+                &mut output,
+                buffer.as_mut_ptr() as _,
+            ),
+        ) {
             Ok(_) => {
                 unsafe {
                     buffer.set_len(output as usize);
                 }
                 return Ok(buffer);
             }
-            Err(sys::Result::ERROR_SIZE_INSUFFICIENT) => {
+            Err(e) if e == sys::Result::ERROR_SIZE_INSUFFICIENT => {
                 buffer.reserve(output as usize - buffer.capacity());
             }
             Err(e) => {
@@ -138,19 +187,23 @@ fn get_arr<T: Copy>(
 }
 
 fn get_arr_init<T: Copy>(
+    function: &'static str,
     init: T,
     mut getter: impl FnMut(u32, &mut u32, *mut T) -> sys::Result,
 ) -> Result<Vec<T>> {
     let mut output = 0;
-    cvt(getter(0, &mut output, std::ptr::null_mut()))?;
+    cvt(function, getter(0, &mut output, std::ptr::null_mut()))?;
     let mut buffer = vec![init; output as usize];
     loop {
-        match cvt(getter(output, &mut output, buffer.as_mut_ptr() as _)) {
+        match cvt(
+            function,
+            getter(output, &mut output, buffer.as_mut_ptr() as _),
+        ) {
             Ok(_) => {
                 buffer.truncate(output as usize);
                 return Ok(buffer);
             }
-            Err(sys::Result::ERROR_SIZE_INSUFFICIENT) => {
+            Err(e) if e == sys::Result::ERROR_SIZE_INSUFFICIENT => {
                 buffer.resize(output as usize, init);
             }
             Err(e) => {
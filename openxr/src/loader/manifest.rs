@@ -0,0 +1,489 @@
+use std::collections::HashMap;
+use std::env;
+use std::ffi::OsString;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::json::{self, Value};
+use super::Error;
+use crate::*;
+
+/// A runtime manifest, as found by [`RuntimeManifest::find_active`]
+#[derive(Debug, Clone)]
+pub struct RuntimeManifest {
+    /// Location of the manifest itself
+    pub path: PathBuf,
+    /// Shared library implementing the runtime
+    ///
+    /// Relative paths containing a directory are resolved against the manifest's directory; bare
+    /// file names are left for the platform's dynamic loader to search for.
+    pub library_path: PathBuf,
+    pub name: Option<String>,
+    /// Names under which the library exports loader interface functions, if not their own
+    pub functions: HashMap<String, String>,
+}
+
+impl RuntimeManifest {
+    pub fn from_file(path: &Path) -> Result<Self, Error> {
+        let manifest = read_manifest(path)?;
+        let runtime = manifest
+            .get("runtime")
+            .ok_or_else(|| invalid(path, "missing \"runtime\""))?;
+        Ok(Self {
+            path: path.into(),
+            library_path: library_path(path, runtime)?,
+            name: runtime.get("name").and_then(Value::as_str).map(Into::into),
+            functions: functions(path, runtime)?,
+        })
+    }
+
+    /// Find the manifest of the system's active runtime
+    ///
+    /// `XR_RUNTIME_JSON` takes precedence. Otherwise, on Unix, the first
+    /// `openxr/<major>/active_runtime.json` in `$XDG_CONFIG_HOME`, `$XDG_CONFIG_DIRS` and
+    /// `/etc` is used. Other platforms have no fallback.
+    pub fn find_active() -> Result<Self, Error> {
+        if let Some(path) = env::var_os("XR_RUNTIME_JSON").filter(|x| !x.is_empty()) {
+            return Self::from_file(Path::new(&path));
+        }
+        let relative = Path::new("openxr")
+            .join(CURRENT_API_VERSION.major().to_string())
+            .join("active_runtime.json");
+        config_dirs()
+            .into_iter()
+            .map(|dir| dir.join(&relative))
+            .find(|path| path.is_file())
+            .ok_or(Error::NoRuntime)
+            .and_then(|path| Self::from_file(&path))
+    }
+}
+
+/// An API layer manifest
+#[derive(Debug, Clone)]
+pub struct LayerManifest {
+    /// Location of the manifest itself
+    pub path: PathBuf,
+    pub name: String,
+    /// Shared library implementing the layer, resolved as for [`RuntimeManifest::library_path`]
+    pub library_path: PathBuf,
+    pub api_version: Version,
+    pub implementation_version: u32,
+    pub description: String,
+    /// Instance extensions implemented by the layer, with their spec versions
+    pub instance_extensions: Vec<(String, u32)>,
+    /// Environment variable that disables an implicit layer when set
+    pub disable_environment: Option<String>,
+    /// Environment variable that must be set for an implicit layer to be enabled
+    pub enable_environment: Option<String>,
+    /// Names under which the library exports loader interface functions, if not their own
+    pub functions: HashMap<String, String>,
+}
+
+impl LayerManifest {
+    /// Parse a manifest, which may describe one layer or several
+    pub fn from_file(path: &Path) -> Result<Vec<Self>, Error> {
+        let manifest = read_manifest(path)?;
+        if let Some(layer) = manifest.get("api_layer") {
+            return Ok(vec![Self::parse(path, layer)?]);
+        }
+        manifest
+            .get("api_layers")
+            .and_then(Value::as_array)
+            .ok_or_else(|| invalid(path, "missing \"api_layer\""))?
+            .iter()
+            .map(|layer| Self::parse(path, layer))
+            .collect()
+    }
+
+    fn parse(path: &Path, layer: &Value) -> Result<Self, Error> {
+        let string = |key: &str| -> Result<String, Error> {
+            layer
+                .get(key)
+                .and_then(Value::as_str)
+                .map(Into::into)
+                .ok_or_else(|| invalid(path, &format!("missing \"{}\"", key)))
+        };
+        let name = string("name")?;
+        if name.len() >= sys::MAX_API_LAYER_NAME_SIZE {
+            return Err(invalid(path, "layer name too long"));
+        }
+        let api_version = string("api_version")?;
+        let api_version =
+            parse_version(&api_version).ok_or_else(|| invalid(path, "invalid \"api_version\""))?;
+        let instance_extensions = match layer.get("instance_extensions") {
+            None => Vec::new(),
+            Some(exts) => exts
+                .as_array()
+                .ok_or_else(|| invalid(path, "invalid \"instance_extensions\""))?
+                .iter()
+                .map(|ext| {
+                    let name = ext.get("name").and_then(Value::as_str);
+                    // Written as a string by convention, but numbers are unambiguous
+                    let version = match ext.get("extension_version") {
+                        Some(Value::String(x)) => x.parse().ok(),
+                        Some(&Value::Number(x)) => Some(x as u32),
+                        _ => None,
+                    };
+                    match (name, version) {
+                        (Some(name), Some(version))
+                            if name.len() < sys::MAX_EXTENSION_NAME_SIZE =>
+                        {
+                            Ok((name.into(), version))
+                        }
+                        _ => Err(invalid(path, "invalid \"instance_extensions\"")),
+                    }
+                })
+                .collect::<Result<_, _>>()?,
+        };
+        Ok(Self {
+            path: path.into(),
+            library_path: library_path(path, layer)?,
+            api_version,
+            // Khronos's loader is similarly lenient
+            implementation_version: string("implementation_version")?.parse().unwrap_or(0),
+            description: string("description").unwrap_or_default(),
+            instance_extensions,
+            disable_environment: layer
+                .get("disable_environment")
+                .and_then(Value::as_str)
+                .map(Into::into),
+            enable_environment: layer
+                .get("enable_environment")
+                .and_then(Value::as_str)
+                .map(Into::into),
+            functions: functions(path, layer)?,
+            name,
+        })
+    }
+
+    /// Find the manifests of implicit layers, which are enabled unless the environment disables
+    /// them
+    ///
+    /// On Unix, these are found in `openxr/<major>/api_layers/implicit.d` under the XDG config
+    /// and data directories. Malformed manifests are skipped.
+    pub fn implicit() -> Vec<Self> {
+        search(&layer_dirs("implicit.d"))
+    }
+
+    /// Find the manifests of explicit layers, which are enabled on request
+    ///
+    /// These are found in the directories listed in `XR_API_LAYER_PATH` if set, or else as for
+    /// implicit layers, in `explicit.d`. Malformed manifests are skipped.
+    pub fn explicit() -> Vec<Self> {
+        let dirs = match env::var_os("XR_API_LAYER_PATH").filter(|x| !x.is_empty()) {
+            Some(paths) => env::split_paths(&paths).collect(),
+            None => layer_dirs("explicit.d"),
+        };
+        search(&dirs)
+    }
+
+    /// Whether the environment enables this layer, if it's implicit
+    pub fn enabled_implicitly(&self) -> bool {
+        let set = |var: &Option<String>| var.as_ref().map(|x| env::var_os(x).is_some());
+        // A layer without a way to disable it is invalid as an implicit layer
+        set(&self.disable_environment) == Some(false)
+            && set(&self.enable_environment).unwrap_or(true)
+    }
+}
+
+fn invalid(path: &Path, reason: &str) -> Error {
+    Error::Manifest(path.into(), reason.into())
+}
+
+fn read_manifest(path: &Path) -> Result<Value, Error> {
+    let text = fs::read_to_string(path).map_err(|e| Error::Io(path.into(), e))?;
+    let manifest = json::parse(&text).map_err(|e| Error::Manifest(path.into(), e))?;
+    match manifest.get("file_format_version").and_then(Value::as_str) {
+        Some(x) if x.starts_with("1.") => Ok(manifest),
+        _ => Err(invalid(path, "unsupported \"file_format_version\"")),
+    }
+}
+
+fn library_path(manifest: &Path, object: &Value) -> Result<PathBuf, Error> {
+    let library = object
+        .get("library_path")
+        .and_then(Value::as_str)
+        .map(Path::new)
+        .ok_or_else(|| invalid(manifest, "missing \"library_path\""))?;
+    if library.is_absolute() || library.components().count() == 1 {
+        return Ok(library.into());
+    }
+    Ok(manifest
+        .parent()
+        .unwrap_or_else(|| Path::new(""))
+        .join(library))
+}
+
+fn functions(manifest: &Path, object: &Value) -> Result<HashMap<String, String>, Error> {
+    let functions = match object.get("functions") {
+        None => return Ok(HashMap::new()),
+        Some(x) => x
+            .as_object()
+            .ok_or_else(|| invalid(manifest, "invalid \"functions\""))?,
+    };
+    functions
+        .iter()
+        .map(|(name, value)| match value.as_str() {
+            Some(value) => Ok((name.clone(), value.into())),
+            None => Err(invalid(manifest, "invalid \"functions\"")),
+        })
+        .collect()
+}
+
+/// Parse a `major.minor[.patch]` version
+fn parse_version(s: &str) -> Option<Version> {
+    let mut parts = s.split('.');
+    let major = parts.next()?.parse().ok()?;
+    let minor = parts.next()?.parse().ok()?;
+    let patch = parts.next().map_or(Some(0), |x| x.parse().ok())?;
+    if parts.next().is_some() {
+        return None;
+    }
+    Some(Version::new(major, minor, patch))
+}
+
+fn search(dirs: &[PathBuf]) -> Vec<LayerManifest> {
+    let mut layers = Vec::<LayerManifest>::new();
+    for dir in dirs {
+        let mut paths = match fs::read_dir(dir) {
+            Ok(entries) => entries
+                .filter_map(|entry| Some(entry.ok()?.path()))
+                .filter(|path| path.extension().is_some_and(|x| x == "json"))
+                .collect::<Vec<_>>(),
+            Err(_) => continue,
+        };
+        paths.sort();
+        for path in paths {
+            for layer in LayerManifest::from_file(&path).unwrap_or_default() {
+                // Earlier directories take precedence
+                if layers.iter().all(|x| x.name != layer.name) {
+                    layers.push(layer);
+                }
+            }
+        }
+    }
+    layers
+}
+
+fn layer_dirs(kind: &str) -> Vec<PathBuf> {
+    let relative = Path::new("openxr")
+        .join(CURRENT_API_VERSION.major().to_string())
+        .join("api_layers")
+        .join(kind);
+    config_dirs()
+        .into_iter()
+        .chain(data_dirs())
+        .map(|dir| dir.join(&relative))
+        .collect()
+}
+
+/// `$XDG_CONFIG_HOME`, `$XDG_CONFIG_DIRS`, then `/etc`
+fn config_dirs() -> Vec<PathBuf> {
+    if !cfg!(unix) {
+        return Vec::new();
+    }
+    let mut dirs = Vec::new();
+    dirs.extend(xdg_home("XDG_CONFIG_HOME", ".config"));
+    dirs.extend(xdg_dirs("XDG_CONFIG_DIRS", "/etc/xdg"));
+    dirs.push("/etc".into());
+    dirs
+}
+
+/// `$XDG_DATA_HOME`, then `$XDG_DATA_DIRS`
+fn data_dirs() -> Vec<PathBuf> {
+    if !cfg!(unix) {
+        return Vec::new();
+    }
+    let mut dirs = Vec::new();
+    dirs.extend(xdg_home("XDG_DATA_HOME", ".local/share"));
+    dirs.extend(xdg_dirs("XDG_DATA_DIRS", "/usr/local/share:/usr/share"));
+    dirs
+}
+
+fn xdg_home(var: &str, default: &str) -> Option<PathBuf> {
+    match env::var_os(var).filter(|x| !x.is_empty()) {
+        Some(x) => Some(x.into()),
+        None => Some(PathBuf::from(env::var_os("HOME")?).join(default)),
+    }
+}
+
+fn xdg_dirs(var: &str, default: &str) -> Vec<PathBuf> {
+    let dirs = env::var_os(var)
+        .filter(|x| !x.is_empty())
+        .unwrap_or_else(|| OsString::from(default));
+    env::split_paths(&dirs)
+        .filter(|x| x.is_absolute())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A fresh directory for `name` under the system temporary directory
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("openxr-loader-{}-{}", std::process::id(), name));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn layer_json(name: &str, environment: &str) -> String {
+        format!(
+            r#"{{
+                "file_format_version": "1.0.0",
+                "api_layer": {{
+                    "name": "{}",
+                    "library_path": "lib/layer.so",
+                    "api_version": "1.0",
+                    "implementation_version": "2",
+                    "description": "test layer",
+                    "instance_extensions": [
+                        {{ "name": "XR_EXT_test", "extension_version": "3" }}
+                    ]{}
+                }}
+            }}"#,
+            name, environment
+        )
+    }
+
+    fn layer(disable: Option<&str>, enable: Option<&str>) -> LayerManifest {
+        LayerManifest {
+            path: PathBuf::new(),
+            name: "XR_APILAYER_test".into(),
+            library_path: PathBuf::new(),
+            api_version: Version::new(1, 0, 0),
+            implementation_version: 1,
+            description: String::new(),
+            instance_extensions: Vec::new(),
+            disable_environment: disable.map(Into::into),
+            enable_environment: enable.map(Into::into),
+            functions: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn parse_layer() {
+        let dir = temp_dir("parse");
+        let path = dir.join("layer.json");
+        fs::write(
+            &path,
+            layer_json(
+                "XR_APILAYER_test",
+                r#", "disable_environment": "DISABLE_TEST""#,
+            ),
+        )
+        .unwrap();
+        let layers = LayerManifest::from_file(&path).unwrap();
+        assert_eq!(layers.len(), 1);
+        let layer = &layers[0];
+        assert_eq!(layer.name, "XR_APILAYER_test");
+        assert_eq!(layer.library_path, dir.join("lib/layer.so"));
+        assert_eq!(layer.api_version, Version::new(1, 0, 0));
+        assert_eq!(layer.implementation_version, 2);
+        assert_eq!(layer.instance_extensions, [("XR_EXT_test".into(), 3)]);
+        assert_eq!(layer.disable_environment.as_deref(), Some("DISABLE_TEST"));
+        assert_eq!(layer.enable_environment, None);
+
+        fs::write(
+            &path,
+            r#"{"file_format_version": "2.0.0", "api_layer": {}}"#,
+        )
+        .unwrap();
+        assert!(matches!(
+            LayerManifest::from_file(&path),
+            Err(Error::Manifest(..))
+        ));
+        fs::write(&path, "{").unwrap();
+        assert!(matches!(
+            LayerManifest::from_file(&path),
+            Err(Error::Manifest(..))
+        ));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn implicit_layer_environment() {
+        let disable = "OPENXR_LOADER_TEST_DISABLE";
+        let enable = "OPENXR_LOADER_TEST_ENABLE";
+        env::remove_var(disable);
+        env::remove_var(enable);
+
+        assert!(!layer(None, None).enabled_implicitly());
+        assert!(!layer(None, Some(enable)).enabled_implicitly());
+        assert!(layer(Some(disable), None).enabled_implicitly());
+        assert!(!layer(Some(disable), Some(enable)).enabled_implicitly());
+
+        env::set_var(enable, "1");
+        assert!(layer(Some(disable), Some(enable)).enabled_implicitly());
+        // Set but empty still counts
+        env::set_var(disable, "");
+        assert!(!layer(Some(disable), None).enabled_implicitly());
+        assert!(!layer(Some(disable), Some(enable)).enabled_implicitly());
+
+        env::remove_var(disable);
+        env::remove_var(enable);
+    }
+
+    #[test]
+    fn discovery() {
+        let root = temp_dir("discovery");
+        let config_home = root.join("config");
+        let data_home = root.join("data");
+        let explicit_path = root.join("explicit");
+        let implicit_dir = Path::new("openxr")
+            .join(CURRENT_API_VERSION.major().to_string())
+            .join("api_layers/implicit.d");
+        for dir in [
+            config_home.join(&implicit_dir),
+            data_home.join(&implicit_dir),
+            explicit_path.clone(),
+        ] {
+            fs::create_dir_all(dir).unwrap();
+        }
+        let environment = r#", "disable_environment": "DISABLE_TEST""#;
+        fs::write(
+            config_home.join(&implicit_dir).join("a.json"),
+            layer_json("XR_APILAYER_config", environment),
+        )
+        .unwrap();
+        fs::write(
+            config_home.join(&implicit_dir).join("b.json"),
+            layer_json("XR_APILAYER_shared", environment),
+        )
+        .unwrap();
+        // Shadowed by the config directory's layer of the same name
+        fs::write(
+            data_home.join(&implicit_dir).join("a.json"),
+            layer_json("XR_APILAYER_shared", ""),
+        )
+        .unwrap();
+        fs::write(data_home.join(&implicit_dir).join("c.json"), "not json").unwrap();
+        fs::write(data_home.join(&implicit_dir).join("d.txt"), "ignored").unwrap();
+        fs::write(
+            explicit_path.join("e.json"),
+            layer_json("XR_APILAYER_explicit", ""),
+        )
+        .unwrap();
+
+        env::set_var("XDG_CONFIG_HOME", &config_home);
+        env::set_var("XDG_CONFIG_DIRS", root.join("none"));
+        env::set_var("XDG_DATA_HOME", &data_home);
+        env::set_var("XDG_DATA_DIRS", root.join("none"));
+        env::set_var("XR_API_LAYER_PATH", &explicit_path);
+
+        let implicit = LayerManifest::implicit();
+        let find = |name: &str| implicit.iter().find(|x| x.name == name);
+        let config = find("XR_APILAYER_config").unwrap();
+        assert_eq!(config.path, config_home.join(&implicit_dir).join("a.json"));
+        let shared = find("XR_APILAYER_shared").unwrap();
+        assert!(shared.path.starts_with(&config_home));
+        assert!(shared.disable_environment.is_some());
+
+        let explicit = LayerManifest::explicit();
+        assert_eq!(explicit.len(), 1);
+        assert_eq!(explicit[0].name, "XR_APILAYER_explicit");
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,649 @@
+//! A pure-Rust OpenXR loader
+//!
+//! Finds the active runtime and API layers through their JSON manifests, negotiates with them as
+//! the Khronos loader does, and dispatches to them, without needing a C++ toolchain to build. Use
+//! it through [`Entry::rust_loader`].
+//!
+//! See [`RuntimeManifest::find_active`], [`LayerManifest::implicit`] and
+//! [`LayerManifest::explicit`] for where manifests are searched for. Explicit layers are enabled
+//! by the application, or by listing them in `XR_ENABLE_API_LAYERS`. Windows registry and
+//! Android broker discovery are not supported; set `XR_RUNTIME_JSON` on those platforms.
+//!
+//! Layer manifests are read once, when the loader is first initialized; the runtime and layers
+//! stay loaded for the life of the process.
+//!
+//! Available if the `rust-loader` feature is enabled.
+
+use std::collections::HashMap;
+use std::ffi::{CStr, CString};
+use std::os::raw::c_char;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex, OnceLock, RwLock};
+use std::{env, error, fmt, io, mem, ptr};
+
+use libloading::Library;
+use sys::loader::{
+    ApiLayerCreateInfo, FnNegotiateLoaderApiLayerInterface, FnNegotiateLoaderRuntimeInterface,
+    XrApiLayerNextInfo, XrNegotiateApiLayerRequest, XrNegotiateLoaderInfo,
+    XrNegotiateRuntimeRequest, API_LAYER_MAX_SETTINGS_PATH_SIZE, CURRENT_LOADER_API_LAYER_VERSION,
+    CURRENT_LOADER_RUNTIME_VERSION,
+};
+use sys::pfn;
+
+use crate::*;
+
+mod json;
+mod manifest;
+pub use manifest::{LayerManifest, RuntimeManifest};
+
+/// An error encountered while initializing the loader
+#[derive(Debug)]
+pub enum Error {
+    /// No active runtime manifest was found
+    NoRuntime,
+    /// A manifest could not be read
+    Io(PathBuf, io::Error),
+    /// A manifest was malformed
+    Manifest(PathBuf, String),
+    /// A library could not be loaded, or lacks a required function
+    Library(PathBuf, libloading::Error),
+    /// A library was incompatible with this loader
+    Negotiation(PathBuf, sys::Result),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::NoRuntime => f.pad("no active OpenXR runtime found"),
+            Error::Io(ref path, ref e) => write!(f, "failed to read {}: {}", path.display(), e),
+            Error::Manifest(ref path, ref e) => {
+                write!(f, "invalid manifest {}: {}", path.display(), e)
+            }
+            Error::Library(ref path, ref e) => {
+                write!(f, "failed to load {}: {}", path.display(), e)
+            }
+            Error::Negotiation(ref path, e) => {
+                write!(f, "negotiation with {} failed: {}", path.display(), e)
+            }
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match *self {
+            Error::Io(_, ref e) => Some(e),
+            Error::Library(_, ref e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+/// Initialize the process-wide loader, if necessary, and get its entry points
+pub(crate) unsafe fn init() -> std::result::Result<RawEntry, Error> {
+    {
+        let _guard = INIT.lock().unwrap_or_else(|e| e.into_inner());
+        if LOADER.get().is_none() {
+            let loader = Loader::new()?;
+            let _ = LOADER.set(loader);
+        }
+    }
+    Ok(RawEntry {
+        get_instance_proc_addr,
+        create_instance,
+        enumerate_instance_extension_properties,
+        enumerate_api_layer_properties,
+    })
+}
+
+static INIT: Mutex<()> = Mutex::new(());
+static LOADER: OnceLock<Loader> = OnceLock::new();
+
+struct Loader {
+    runtime: Runtime,
+    implicit_layers: Vec<LayerManifest>,
+    explicit_layers: Vec<LayerManifest>,
+    /// Layers are only loaded once an instance enables them
+    loaded_layers: Mutex<HashMap<String, Arc<Layer>>>,
+    instances: RwLock<HashMap<u64, Chain>>,
+}
+
+struct Runtime {
+    _lib: Library,
+    get_instance_proc_addr: pfn::GetInstanceProcAddr,
+    create_instance: pfn::CreateInstance,
+    enumerate_instance_extension_properties: pfn::EnumerateInstanceExtensionProperties,
+}
+
+struct Layer {
+    _lib: Library,
+    get_instance_proc_addr: pfn::GetInstanceProcAddr,
+    create_api_layer_instance: sys::loader::FnCreateApiLayerInstance,
+}
+
+/// Entry points for an instance's call chain
+#[derive(Copy, Clone)]
+struct Chain {
+    get_instance_proc_addr: pfn::GetInstanceProcAddr,
+}
+
+impl Loader {
+    unsafe fn new() -> std::result::Result<Self, Error> {
+        let manifest = RuntimeManifest::find_active()?;
+        Ok(Self {
+            runtime: Runtime::load(&manifest)?,
+            implicit_layers: LayerManifest::implicit(),
+            explicit_layers: LayerManifest::explicit(),
+            loaded_layers: Mutex::new(HashMap::new()),
+            instances: RwLock::new(HashMap::new()),
+        })
+    }
+
+    fn layer_manifest(&self, name: &str) -> Option<&LayerManifest> {
+        self.implicit_layers
+            .iter()
+            .chain(&self.explicit_layers)
+            .find(|x| x.name == name)
+    }
+
+    unsafe fn layer(&self, name: &str) -> Result<Arc<Layer>, sys::Result> {
+        let mut loaded = self.loaded_layers.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(layer) = loaded.get(name) {
+            return Ok(layer.clone());
+        }
+        let manifest = self
+            .layer_manifest(name)
+            .ok_or(sys::Result::ERROR_API_LAYER_NOT_PRESENT)?;
+        // The application can't see why a layer failed to load, so it's reported as absent
+        let layer =
+            Arc::new(Layer::load(manifest).map_err(|_| sys::Result::ERROR_API_LAYER_NOT_PRESENT)?);
+        loaded.insert(name.into(), layer.clone());
+        Ok(layer)
+    }
+
+    unsafe fn runtime_extensions(&self) -> Result<Vec<(String, u32)>, sys::Result> {
+        let exts = get_arr_init(
+            "xrEnumerateInstanceExtensionProperties",
+            sys::ExtensionProperties::out(ptr::null_mut()),
+            |cap, count, buf| {
+                (self.runtime.enumerate_instance_extension_properties)(
+                    ptr::null(),
+                    cap,
+                    count,
+                    buf as _,
+                )
+            },
+        )?;
+        Ok(exts
+            .into_iter()
+            .map(|x| {
+                let x = x.assume_init();
+                (fixed_str(&x.extension_name).into(), x.extension_version)
+            })
+            .collect())
+    }
+
+    /// Names of the layers to enable for `info`, closest to the application first
+    unsafe fn enabled_layers(&self, info: &sys::InstanceCreateInfo) -> Vec<String> {
+        let mut names = self
+            .implicit_layers
+            .iter()
+            .filter(|x| x.enabled_implicitly())
+            .map(|x| x.name.clone())
+            .collect::<Vec<_>>();
+        if let Some(var) = env::var_os("XR_ENABLE_API_LAYERS") {
+            names.extend(env::split_paths(&var).map(|x| x.to_string_lossy().into_owned()));
+        }
+        for i in 0..info.enabled_api_layer_count as usize {
+            let name = CStr::from_ptr(*info.enabled_api_layer_names.add(i));
+            names.push(name.to_string_lossy().into_owned());
+        }
+        let mut unique = Vec::<String>::with_capacity(names.len());
+        for name in names {
+            if !name.is_empty() && !unique.contains(&name) {
+                unique.push(name);
+            }
+        }
+        unique
+    }
+
+    unsafe fn create_instance(
+        &self,
+        info: &sys::InstanceCreateInfo,
+        instance: *mut sys::Instance,
+    ) -> Result<sys::Result, sys::Result> {
+        let names = self.enabled_layers(info);
+        let layers = names
+            .iter()
+            .map(|name| self.layer(name))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // Every extension must come from the runtime or an enabled layer
+        let runtime_extensions = self.runtime_extensions()?;
+        for i in 0..info.enabled_extension_count as usize {
+            let ext = CStr::from_ptr(*info.enabled_extension_names.add(i)).to_string_lossy();
+            let provided = runtime_extensions
+                .iter()
+                .chain(
+                    names
+                        .iter()
+                        .filter_map(|x| self.layer_manifest(x))
+                        .flat_map(|x| &x.instance_extensions),
+                )
+                .any(|x| x.0 == ext);
+            if !provided {
+                return Err(sys::Result::ERROR_EXTENSION_NOT_PRESENT);
+            }
+        }
+
+        let (result, get_instance_proc_addr) = if layers.is_empty() {
+            (
+                (self.runtime.create_instance)(info, instance),
+                self.runtime.get_instance_proc_addr,
+            )
+        } else {
+            // Each layer's link holds the entry points of the next layer, or of the runtime
+            let mut links = names
+                .iter()
+                .enumerate()
+                .map(|(i, name)| {
+                    let (get_instance_proc_addr, create) = match layers.get(i + 1) {
+                        Some(next) => (next.get_instance_proc_addr, next.create_api_layer_instance),
+                        None => (
+                            self.runtime.get_instance_proc_addr,
+                            terminator_create_instance as sys::loader::FnCreateApiLayerInstance,
+                        ),
+                    };
+                    let mut link = XrApiLayerNextInfo {
+                        ty: XrApiLayerNextInfo::TYPE,
+                        struct_version: XrApiLayerNextInfo::VERSION,
+                        struct_size: mem::size_of::<XrApiLayerNextInfo>(),
+                        layer_name: [0; sys::MAX_API_LAYER_NAME_SIZE],
+                        next_get_instance_proc_addr: get_instance_proc_addr,
+                        next_create_api_layer_instance: create,
+                        next: ptr::null_mut(),
+                    };
+                    place_cstr(&mut link.layer_name, name);
+                    link
+                })
+                .collect::<Vec<_>>();
+            let base = links.as_mut_ptr();
+            for i in 1..links.len() {
+                (*base.add(i - 1)).next = base.add(i);
+            }
+            let api_layer_info = ApiLayerCreateInfo {
+                ty: ApiLayerCreateInfo::TYPE,
+                struct_version: ApiLayerCreateInfo::VERSION,
+                struct_size: mem::size_of::<ApiLayerCreateInfo>(),
+                loader_instance: ptr::null(),
+                settings_file_location: [0; API_LAYER_MAX_SETTINGS_PATH_SIZE],
+                next_info: base,
+            };
+            (
+                (layers[0].create_api_layer_instance)(info, &api_layer_info, instance),
+                layers[0].get_instance_proc_addr,
+            )
+        };
+        if result.into_raw() < 0 {
+            return Ok(result);
+        }
+        // `xrDestroyInstance` is looked up when it's called, since failing here would leak the
+        // instance
+        self.instances
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(
+                (*instance).into_raw(),
+                Chain {
+                    get_instance_proc_addr,
+                },
+            );
+        Ok(result)
+    }
+}
+
+impl Runtime {
+    unsafe fn load(manifest: &RuntimeManifest) -> std::result::Result<Self, Error> {
+        let path = &manifest.library_path;
+        let lib = Library::new(path).map_err(|e| Error::Library(path.clone(), e))?;
+        let negotiate = *lib
+            .get::<FnNegotiateLoaderRuntimeInterface>(&function_name(
+                &manifest.functions,
+                "xrNegotiateLoaderRuntimeInterface",
+            ))
+            .map_err(|e| Error::Library(path.clone(), e))?;
+        let mut request = XrNegotiateRuntimeRequest {
+            ty: XrNegotiateRuntimeRequest::TYPE,
+            struct_version: XrNegotiateRuntimeRequest::VERSION,
+            struct_size: mem::size_of::<XrNegotiateRuntimeRequest>(),
+            runtime_interface_version: 0,
+            runtime_api_version: Version::new(0, 0, 0),
+            get_instance_proc_addr: None,
+        };
+        let result = negotiate(&loader_info(CURRENT_LOADER_RUNTIME_VERSION), &mut request);
+        let get_instance_proc_addr = match request.get_instance_proc_addr {
+            Some(f)
+                if result.into_raw() >= 0
+                    && request.runtime_interface_version == CURRENT_LOADER_RUNTIME_VERSION =>
+            {
+                f
+            }
+            _ => {
+                return Err(Error::Negotiation(
+                    path.clone(),
+                    failure(result, sys::Result::ERROR_INITIALIZATION_FAILED),
+                ))
+            }
+        };
+        let load = |name: &[u8]| {
+            let mut f = None;
+            let result = get_instance_proc_addr(sys::Instance::NULL, name.as_ptr() as _, &mut f);
+            match f {
+                Some(f) if result.into_raw() >= 0 => Ok(f),
+                _ => Err(Error::Negotiation(
+                    path.clone(),
+                    failure(result, sys::Result::ERROR_FUNCTION_UNSUPPORTED),
+                )),
+            }
+        };
+        Ok(Self {
+            create_instance: mem::transmute(load(b"xrCreateInstance\0")?),
+            enumerate_instance_extension_properties: mem::transmute(load(
+                b"xrEnumerateInstanceExtensionProperties\0",
+            )?),
+            get_instance_proc_addr,
+            _lib: lib,
+        })
+    }
+}
+
+impl Layer {
+    unsafe fn load(manifest: &LayerManifest) -> std::result::Result<Self, Error> {
+        let path = &manifest.library_path;
+        let lib = Library::new(path).map_err(|e| Error::Library(path.clone(), e))?;
+        let negotiate = *lib
+            .get::<FnNegotiateLoaderApiLayerInterface>(&function_name(
+                &manifest.functions,
+                "xrNegotiateLoaderApiLayerInterface",
+            ))
+            .map_err(|e| Error::Library(path.clone(), e))?;
+        let mut request = XrNegotiateApiLayerRequest {
+            ty: XrNegotiateApiLayerRequest::TYPE,
+            struct_version: XrNegotiateApiLayerRequest::VERSION,
+            struct_size: mem::size_of::<XrNegotiateApiLayerRequest>(),
+            layer_interface_version: 0,
+            layer_api_version: Version::new(0, 0, 0),
+            get_instance_proc_addr: None,
+            create_api_layer_instance: None,
+        };
+        // Manifest names contain no nulls
+        let name = CString::new(manifest.name.as_bytes()).unwrap();
+        let result = negotiate(
+            &loader_info(CURRENT_LOADER_API_LAYER_VERSION),
+            name.as_ptr(),
+            &mut request,
+        );
+        match (
+            request.get_instance_proc_addr,
+            request.create_api_layer_instance,
+        ) {
+            (Some(get_instance_proc_addr), Some(create_api_layer_instance))
+                if result.into_raw() >= 0
+                    && request.layer_interface_version == CURRENT_LOADER_API_LAYER_VERSION =>
+            {
+                Ok(Self {
+                    _lib: lib,
+                    get_instance_proc_addr,
+                    create_api_layer_instance,
+                })
+            }
+            _ => Err(Error::Negotiation(
+                path.clone(),
+                failure(result, sys::Result::ERROR_INITIALIZATION_FAILED),
+            )),
+        }
+    }
+}
+
+fn loader_info(interface_version: u32) -> XrNegotiateLoaderInfo {
+    XrNegotiateLoaderInfo {
+        ty: XrNegotiateLoaderInfo::TYPE,
+        struct_version: XrNegotiateLoaderInfo::VERSION,
+        struct_size: mem::size_of::<XrNegotiateLoaderInfo>(),
+        min_interface_version: 1,
+        max_interface_version: interface_version,
+        min_api_version: Version::new(1, 0, 0),
+        max_api_version: Version::new(CURRENT_API_VERSION.major(), 0x3ff, 0xfff),
+    }
+}
+
+/// `result` if it indicates failure, otherwise `default`
+fn failure(result: sys::Result, default: sys::Result) -> sys::Result {
+    if result.into_raw() < 0 {
+        result
+    } else {
+        default
+    }
+}
+
+/// Null-terminated name of the exported function implementing `name`
+fn function_name(functions: &HashMap<String, String>, name: &str) -> Vec<u8> {
+    let mut name = functions.get(name).map_or(name, |x| x).as_bytes().to_vec();
+    name.push(0);
+    name
+}
+
+/// Run an entry point, which must not unwind into the application
+fn guard(f: impl FnOnce(&Loader) -> sys::Result) -> sys::Result {
+    let loader = match LOADER.get() {
+        Some(x) => x,
+        None => return sys::Result::ERROR_RUNTIME_FAILURE,
+    };
+    panic::catch_unwind(AssertUnwindSafe(|| f(loader)))
+        .unwrap_or(sys::Result::ERROR_RUNTIME_FAILURE)
+}
+
+unsafe extern "system" fn get_instance_proc_addr(
+    instance: sys::Instance,
+    name: *const c_char,
+    function: *mut Option<pfn::VoidFunction>,
+) -> sys::Result {
+    if name.is_null() || function.is_null() {
+        return sys::Result::ERROR_VALIDATION_FAILURE;
+    }
+    *function = None;
+    guard(|loader| {
+        let bytes = CStr::from_ptr(name).to_bytes();
+        let own = match bytes {
+            b"xrGetInstanceProcAddr" => Some(mem::transmute::<
+                pfn::GetInstanceProcAddr,
+                pfn::VoidFunction,
+            >(get_instance_proc_addr)),
+            b"xrDestroyInstance" if instance != sys::Instance::NULL => {
+                Some(mem::transmute::<pfn::DestroyInstance, pfn::VoidFunction>(
+                    destroy_instance,
+                ))
+            }
+            b"xrCreateInstance" => Some(mem::transmute::<pfn::CreateInstance, pfn::VoidFunction>(
+                create_instance,
+            )),
+            b"xrEnumerateInstanceExtensionProperties" => {
+                Some(mem::transmute::<
+                    pfn::EnumerateInstanceExtensionProperties,
+                    pfn::VoidFunction,
+                >(enumerate_instance_extension_properties))
+            }
+            b"xrEnumerateApiLayerProperties" => Some(mem::transmute::<
+                pfn::EnumerateApiLayerProperties,
+                pfn::VoidFunction,
+            >(enumerate_api_layer_properties)),
+            _ => None,
+        };
+        if own.is_some() {
+            *function = own;
+            return sys::Result::SUCCESS;
+        }
+        let chain = loader
+            .instances
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(&instance.into_raw())
+            .copied();
+        match chain {
+            Some(chain) => (chain.get_instance_proc_addr)(instance, name, function),
+            None => sys::Result::ERROR_HANDLE_INVALID,
+        }
+    })
+}
+
+unsafe extern "system" fn destroy_instance(instance: sys::Instance) -> sys::Result {
+    guard(|loader| {
+        let mut instances = loader.instances.write().unwrap_or_else(|e| e.into_inner());
+        let chain = match instances.get(&instance.into_raw()) {
+            Some(x) => *x,
+            None => return sys::Result::ERROR_HANDLE_INVALID,
+        };
+        let mut f = None;
+        let result =
+            (chain.get_instance_proc_addr)(instance, b"xrDestroyInstance\0".as_ptr() as _, &mut f);
+        let f = match f {
+            Some(f) if result.into_raw() >= 0 => {
+                mem::transmute::<pfn::VoidFunction, pfn::DestroyInstance>(f)
+            }
+            _ => return failure(result, sys::Result::ERROR_FUNCTION_UNSUPPORTED),
+        };
+        instances.remove(&instance.into_raw());
+        drop(instances);
+        f(instance)
+    })
+}
+
+unsafe extern "system" fn create_instance(
+    info: *const sys::InstanceCreateInfo,
+    instance: *mut sys::Instance,
+) -> sys::Result {
+    if info.is_null() || instance.is_null() {
+        return sys::Result::ERROR_VALIDATION_FAILURE;
+    }
+    guard(|loader| {
+        loader
+            .create_instance(&*info, instance)
+            .unwrap_or_else(|e| e)
+    })
+}
+
+/// End of the layer chain
+unsafe extern "system" fn terminator_create_instance(
+    info: *const sys::InstanceCreateInfo,
+    _api_layer_info: *const ApiLayerCreateInfo,
+    instance: *mut sys::Instance,
+) -> sys::Result {
+    guard(|loader| (loader.runtime.create_instance)(info, instance))
+}
+
+unsafe extern "system" fn enumerate_instance_extension_properties(
+    layer_name: *const c_char,
+    property_capacity_input: u32,
+    property_count_output: *mut u32,
+    properties: *mut sys::ExtensionProperties,
+) -> sys::Result {
+    guard(|loader| {
+        let exts = if layer_name.is_null() {
+            let mut exts = match loader.runtime_extensions() {
+                Ok(x) => x,
+                Err(e) => return e,
+            };
+            for layer in loader.implicit_layers.iter() {
+                if !layer.enabled_implicitly() {
+                    continue;
+                }
+                for ext in &layer.instance_extensions {
+                    if exts.iter().all(|x| x.0 != ext.0) {
+                        exts.push(ext.clone());
+                    }
+                }
+            }
+            exts
+        } else {
+            let name = CStr::from_ptr(layer_name).to_string_lossy();
+            match loader.layer_manifest(&name) {
+                Some(layer) => layer.instance_extensions.clone(),
+                None => return sys::Result::ERROR_API_LAYER_NOT_PRESENT,
+            }
+        };
+        write_array(
+            exts.len(),
+            property_capacity_input,
+            property_count_output,
+            properties,
+            |i, out| {
+                place_cstr(&mut out.extension_name, &exts[i].0);
+                out.extension_version = exts[i].1;
+            },
+        )
+    })
+}
+
+unsafe extern "system" fn enumerate_api_layer_properties(
+    property_capacity_input: u32,
+    property_count_output: *mut u32,
+    properties: *mut sys::ApiLayerProperties,
+) -> sys::Result {
+    guard(|loader| {
+        let mut layers = Vec::<&LayerManifest>::new();
+        for layer in loader.implicit_layers.iter().chain(&loader.explicit_layers) {
+            if layers.iter().all(|x| x.name != layer.name) {
+                layers.push(layer);
+            }
+        }
+        write_array(
+            layers.len(),
+            property_capacity_input,
+            property_count_output,
+            properties,
+            |i, out| {
+                place_cstr(&mut out.layer_name, &layers[i].name);
+                out.spec_version = layers[i].api_version;
+                out.layer_version = layers[i].implementation_version;
+                place_truncated(&mut out.description, &layers[i].description);
+            },
+        )
+    })
+}
+
+/// Return `len` elements through the two-call idiom, filling each in with `f`
+unsafe fn write_array<T>(
+    len: usize,
+    capacity_input: u32,
+    count_output: *mut u32,
+    out: *mut T,
+    mut f: impl FnMut(usize, &mut T),
+) -> sys::Result {
+    if count_output.is_null() {
+        return sys::Result::ERROR_VALIDATION_FAILURE;
+    }
+    *count_output = len as u32;
+    if capacity_input == 0 {
+        return sys::Result::SUCCESS;
+    }
+    if (capacity_input as usize) < len {
+        return sys::Result::ERROR_SIZE_INSUFFICIENT;
+    }
+    if out.is_null() {
+        return sys::Result::ERROR_VALIDATION_FAILURE;
+    }
+    for i in 0..len {
+        f(i, &mut *out.add(i));
+    }
+    sys::Result::SUCCESS
+}
+
+/// Like `place_cstr`, but truncates `s` at a character boundary rather than panicking
+fn place_truncated(out: &mut [c_char], s: &str) {
+    let mut len = s.len().min(out.len() - 1);
+    while !s.is_char_boundary(len) {
+        len -= 1;
+    }
+    place_cstr(out, &s[..len]);
+}
@@ -0,0 +1,76 @@
+//! Pieces of the loader interface shared by API layers and runtimes
+
+use std::fmt::Write as _;
+use std::mem;
+
+use sys::loader::XrNegotiateLoaderInfo;
+
+use crate::CURRENT_API_VERSION;
+
+/// Whether the loader described by `info` can talk to us through `interface_version`
+pub(crate) fn loader_info_compatible(info: &XrNegotiateLoaderInfo, interface_version: u32) -> bool {
+    info.ty == XrNegotiateLoaderInfo::TYPE
+        && info.struct_version == XrNegotiateLoaderInfo::VERSION
+        && info.struct_size == mem::size_of::<XrNegotiateLoaderInfo>()
+        && (info.min_interface_version..=info.max_interface_version).contains(&interface_version)
+        && (info.min_api_version.major()..=info.max_api_version.major())
+            .contains(&CURRENT_API_VERSION.major())
+}
+
+/// Emits a loader manifest, a JSON object holding a format version and a single object of `kind`
+pub(crate) struct ManifestWriter {
+    out: String,
+    fields: usize,
+}
+
+impl ManifestWriter {
+    pub(crate) fn new(kind: &str) -> Self {
+        let mut out = String::new();
+        write!(
+            out,
+            "{{\n    \"file_format_version\": \"1.0.0\",\n    \"{}\": {{\n",
+            kind
+        )
+        .unwrap();
+        Self { out, fields: 0 }
+    }
+
+    pub(crate) fn string(&mut self, name: &str, value: &str) {
+        let mut json = String::new();
+        write_str(&mut json, value);
+        self.value(name, &json);
+    }
+
+    /// Add a field whose value is already serialized
+    pub(crate) fn value(&mut self, name: &str, json: &str) {
+        if self.fields != 0 {
+            self.out.push_str(",\n");
+        }
+        self.fields += 1;
+        self.out.push_str("        ");
+        write_str(&mut self.out, name);
+        self.out.push_str(": ");
+        self.out.push_str(json);
+    }
+
+    pub(crate) fn finish(mut self) -> String {
+        self.out.push_str("\n    }\n}\n");
+        self.out
+    }
+}
+
+pub(crate) fn write_str(out: &mut String, s: &str) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => write!(out, "\\u{:04x}", c as u32).unwrap(),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
@@ -0,0 +1,2003 @@
+//! `extern "system"` entry points of the mock runtime
+//!
+//! Every command other than `xrGetInstanceProcAddr` and `xrCreateInstance` is shared by all
+//! runtimes, which are told apart by looking up the handle passed in. Those two commands carry no
+//! handle, so each runtime is assigned a slot with its own monomorphized copies of them.
+
+use std::{
+    collections::{BTreeMap, HashMap, VecDeque},
+    ffi::CStr,
+    mem,
+    os::raw::c_char,
+    panic::{self, AssertUnwindSafe},
+    ptr, slice,
+    sync::{Arc, Mutex, MutexGuard, Weak},
+};
+
+use sys::pfn;
+
+use super::state::*;
+use super::{
+    HapticFeedback, HapticVibrationDescription, MockRuntime, Shared, SubmittedFrame,
+    SubmittedLayer, SubmittedProjectionView, SubmittedSubImage,
+};
+use crate::*;
+
+const SLOT_COUNT: usize = 32;
+
+static SLOTS: Mutex<Vec<Weak<Shared>>> = Mutex::new(Vec::new());
+
+/// The runtime owning each live handle
+static OBJECTS: Mutex<BTreeMap<u64, Arc<Shared>>> = Mutex::new(BTreeMap::new());
+
+fn lock<T>(x: &Mutex<T>) -> MutexGuard<'_, T> {
+    x.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+pub(super) fn register(make: impl FnOnce(usize) -> Shared) -> MockRuntime {
+    let mut slots = lock(&SLOTS);
+    let slot = match slots.iter().position(|x| x.strong_count() == 0) {
+        Some(x) => x,
+        None => {
+            assert!(
+                slots.len() < SLOT_COUNT,
+                "at most {} mock runtimes may be alive at once",
+                SLOT_COUNT
+            );
+            slots.push(Weak::new());
+            slots.len() - 1
+        }
+    };
+    let shared = Arc::new(make(slot));
+    slots[slot] = Arc::downgrade(&shared);
+    MockRuntime { shared }
+}
+
+pub(super) fn get_instance_proc_addr(slot: usize) -> pfn::GetInstanceProcAddr {
+    SLOT_FNS[slot].0
+}
+
+macro_rules! slots {
+    ($($n:literal)*) => {
+        const SLOT_FNS: [(pfn::GetInstanceProcAddr, pfn::CreateInstance); SLOT_COUNT] = [
+            $((slot_get_instance_proc_addr::<$n>, slot_create_instance::<$n>),)*
+        ];
+    };
+}
+
+slots!(0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31);
+
+unsafe extern "system" fn slot_get_instance_proc_addr<const N: usize>(
+    instance: sys::Instance,
+    name: *const c_char,
+    function: *mut Option<pfn::VoidFunction>,
+) -> sys::Result {
+    guard(|| {
+        *function = None;
+        let name = CStr::from_ptr(name).to_bytes();
+        if instance == sys::Instance::NULL {
+            *function = match name {
+                b"xrGetInstanceProcAddr" => Some(mem::transmute::<
+                    pfn::GetInstanceProcAddr,
+                    pfn::VoidFunction,
+                >(SLOT_FNS[N].0)),
+                b"xrCreateInstance" => Some(
+                    mem::transmute::<pfn::CreateInstance, pfn::VoidFunction>(SLOT_FNS[N].1),
+                ),
+                b"xrEnumerateInstanceExtensionProperties" => Some(mem::transmute::<
+                    pfn::EnumerateInstanceExtensionProperties,
+                    pfn::VoidFunction,
+                >(
+                    enumerate_instance_extension_properties,
+                )),
+                b"xrEnumerateApiLayerProperties" => {
+                    Some(mem::transmute::<
+                        pfn::EnumerateApiLayerProperties,
+                        pfn::VoidFunction,
+                    >(enumerate_api_layer_properties))
+                }
+                _ => return Err(sys::Result::ERROR_HANDLE_INVALID),
+            };
+            return Ok(sys::Result::SUCCESS);
+        }
+        with(instance.into_raw(), |state| {
+            state.instance(instance.into_raw())?;
+            Ok(())
+        })?;
+        *function = Some(match name {
+            b"xrGetInstanceProcAddr" => {
+                mem::transmute::<pfn::GetInstanceProcAddr, pfn::VoidFunction>(SLOT_FNS[N].0)
+            }
+            b"xrCreateInstance" => {
+                mem::transmute::<pfn::CreateInstance, pfn::VoidFunction>(SLOT_FNS[N].1)
+            }
+            _ => lookup(name).ok_or(sys::Result::ERROR_FUNCTION_UNSUPPORTED)?,
+        });
+        Ok(sys::Result::SUCCESS)
+    })
+}
+
+unsafe extern "system" fn slot_create_instance<const N: usize>(
+    create_info: *const sys::InstanceCreateInfo,
+    instance: *mut sys::Instance,
+) -> sys::Result {
+    guard(|| {
+        let shared = lock(&SLOTS)
+            .get(N)
+            .and_then(|x| x.upgrade())
+            .ok_or(sys::Result::ERROR_RUNTIME_FAILURE)?;
+        let info = &*create_info;
+        if info.enabled_api_layer_count != 0 {
+            return Err(sys::Result::ERROR_API_LAYER_NOT_PRESENT);
+        }
+        let mut headless = false;
+        for i in 0..info.enabled_extension_count as usize {
+            let name = CStr::from_ptr(*info.enabled_extension_names.add(i));
+            let name = name
+                .to_str()
+                .map_err(|_| sys::Result::ERROR_EXTENSION_NOT_PRESENT)?;
+            if !EXTENSIONS.iter().any(|&(x, _)| x == name) {
+                return Err(sys::Result::ERROR_EXTENSION_NOT_PRESENT);
+            }
+            headless |= name == "XR_MND_headless";
+        }
+        let app = &info.application_info;
+        if read_fixed(&app.application_name).map_or(true, |x| x.is_empty()) {
+            return Err(sys::Result::ERROR_NAME_INVALID);
+        }
+        let handle = {
+            let mut state = shared.lock();
+            let max = state.api_version;
+            if app.api_version.major() != max.major() || app.api_version.minor() > max.minor() {
+                return Err(sys::Result::ERROR_API_VERSION_UNSUPPORTED);
+            }
+            let handle = state.alloc();
+            state.instances.insert(
+                handle,
+                MockInstance {
+                    paths: Vec::new(),
+                    events: VecDeque::new(),
+                    lost_events: 0,
+                    loss_time: None,
+                    headless,
+                },
+            );
+            state.created.clear();
+            handle
+        };
+        lock(&OBJECTS).insert(handle, shared);
+        *instance = sys::Instance::from_raw(handle);
+        Ok(sys::Result::SUCCESS)
+    })
+}
+
+/// Run `f`, converting errors and panics into result codes
+fn guard(f: impl FnOnce() -> Res) -> sys::Result {
+    match panic::catch_unwind(AssertUnwindSafe(f)) {
+        Ok(Ok(x)) => x,
+        Ok(Err(x)) => x,
+        Err(_) => sys::Result::ERROR_RUNTIME_FAILURE,
+    }
+}
+
+/// Run `f` on the state of the runtime owning `handle`, then update the handle registry
+fn with<T>(handle: u64, f: impl FnOnce(&mut State) -> Res<T>) -> Res<T> {
+    let shared = lock(&OBJECTS)
+        .get(&handle)
+        .cloned()
+        .ok_or(sys::Result::ERROR_HANDLE_INVALID)?;
+    let (result, created, destroyed) = {
+        let mut state = shared.lock();
+        let result = f(&mut state);
+        (
+            result,
+            mem::take(&mut state.created),
+            mem::take(&mut state.destroyed),
+        )
+    };
+    let mut objects = lock(&OBJECTS);
+    for handle in created {
+        objects.insert(handle, shared.clone());
+    }
+    let removed = destroyed
+        .into_iter()
+        .filter_map(|x| objects.remove(&x))
+        .collect::<Vec<_>>();
+    drop(objects);
+    // The runtime may be dropped here, so this must happen outside of any locks
+    drop(removed);
+    result
+}
+
+fn lookup(name: &[u8]) -> Option<pfn::VoidFunction> {
+    macro_rules! commands {
+        ($($name:literal => $f:ident: $pfn:ident,)*) => {
+            match name {
+                $($name => Some(unsafe { mem::transmute::<pfn::$pfn, pfn::VoidFunction>($f) }),)*
+                _ => None,
+            }
+        };
+    }
+    commands! {
+        b"xrEnumerateApiLayerProperties" => enumerate_api_layer_properties: EnumerateApiLayerProperties,
+        b"xrEnumerateInstanceExtensionProperties" => enumerate_instance_extension_properties: EnumerateInstanceExtensionProperties,
+        b"xrDestroyInstance" => destroy_instance: DestroyInstance,
+        b"xrResultToString" => result_to_string: ResultToString,
+        b"xrStructureTypeToString" => structure_type_to_string: StructureTypeToString,
+        b"xrGetInstanceProperties" => get_instance_properties: GetInstanceProperties,
+        b"xrGetSystem" => get_system: GetSystem,
+        b"xrGetSystemProperties" => get_system_properties: GetSystemProperties,
+        b"xrCreateSession" => create_session: CreateSession,
+        b"xrDestroySession" => destroy_session: DestroySession,
+        b"xrDestroySpace" => destroy_space: DestroySpace,
+        b"xrEnumerateSwapchainFormats" => enumerate_swapchain_formats: EnumerateSwapchainFormats,
+        b"xrCreateSwapchain" => create_swapchain: CreateSwapchain,
+        b"xrDestroySwapchain" => destroy_swapchain: DestroySwapchain,
+        b"xrEnumerateSwapchainImages" => enumerate_swapchain_images: EnumerateSwapchainImages,
+        b"xrAcquireSwapchainImage" => acquire_swapchain_image: AcquireSwapchainImage,
+        b"xrWaitSwapchainImage" => wait_swapchain_image: WaitSwapchainImage,
+        b"xrReleaseSwapchainImage" => release_swapchain_image: ReleaseSwapchainImage,
+        b"xrBeginSession" => begin_session: BeginSession,
+        b"xrEndSession" => end_session: EndSession,
+        b"xrRequestExitSession" => request_exit_session: RequestExitSession,
+        b"xrEnumerateReferenceSpaces" => enumerate_reference_spaces: EnumerateReferenceSpaces,
+        b"xrCreateReferenceSpace" => create_reference_space: CreateReferenceSpace,
+        b"xrCreateActionSpace" => create_action_space: CreateActionSpace,
+        b"xrLocateSpace" => locate_space: LocateSpace,
+        b"xrLocateSpaces" => locate_spaces: LocateSpaces,
+        b"xrEnumerateViewConfigurations" => enumerate_view_configurations: EnumerateViewConfigurations,
+        b"xrEnumerateEnvironmentBlendModes" => enumerate_environment_blend_modes: EnumerateEnvironmentBlendModes,
+        b"xrGetViewConfigurationProperties" => get_view_configuration_properties: GetViewConfigurationProperties,
+        b"xrEnumerateViewConfigurationViews" => enumerate_view_configuration_views: EnumerateViewConfigurationViews,
+        b"xrBeginFrame" => begin_frame: BeginFrame,
+        b"xrLocateViews" => locate_views: LocateViews,
+        b"xrEndFrame" => end_frame: EndFrame,
+        b"xrWaitFrame" => wait_frame: WaitFrame,
+        b"xrApplyHapticFeedback" => apply_haptic_feedback: ApplyHapticFeedback,
+        b"xrStopHapticFeedback" => stop_haptic_feedback: StopHapticFeedback,
+        b"xrPollEvent" => poll_event: PollEvent,
+        b"xrStringToPath" => string_to_path: StringToPath,
+        b"xrPathToString" => path_to_string: PathToString,
+        b"xrGetReferenceSpaceBoundsRect" => get_reference_space_bounds_rect: GetReferenceSpaceBoundsRect,
+        b"xrGetActionStateBoolean" => get_action_state_boolean: GetActionStateBoolean,
+        b"xrGetActionStateFloat" => get_action_state_float: GetActionStateFloat,
+        b"xrGetActionStateVector2f" => get_action_state_vector2f: GetActionStateVector2f,
+        b"xrGetActionStatePose" => get_action_state_pose: GetActionStatePose,
+        b"xrCreateActionSet" => create_action_set: CreateActionSet,
+        b"xrDestroyActionSet" => destroy_action_set: DestroyActionSet,
+        b"xrCreateAction" => create_action: CreateAction,
+        b"xrDestroyAction" => destroy_action: DestroyAction,
+        b"xrSuggestInteractionProfileBindings" => suggest_interaction_profile_bindings: SuggestInteractionProfileBindings,
+        b"xrAttachSessionActionSets" => attach_session_action_sets: AttachSessionActionSets,
+        b"xrGetCurrentInteractionProfile" => get_current_interaction_profile: GetCurrentInteractionProfile,
+        b"xrSyncActions" => sync_actions: SyncActions,
+        b"xrEnumerateBoundSourcesForAction" => enumerate_bound_sources_for_action: EnumerateBoundSourcesForAction,
+        b"xrGetInputSourceLocalizedName" => get_input_source_localized_name: GetInputSourceLocalizedName,
+    }
+}
+
+//
+// Helpers
+//
+
+/// Implement the two-call idiom for an array of `len` elements, each written by `write`
+unsafe fn two_call<T>(
+    len: usize,
+    capacity: u32,
+    count: *mut u32,
+    out: *mut T,
+    mut write: impl FnMut(&mut T, usize),
+) -> Res {
+    *count = len as u32;
+    if capacity == 0 {
+        return Ok(sys::Result::SUCCESS);
+    }
+    if (capacity as usize) < len {
+        return Err(sys::Result::ERROR_SIZE_INSUFFICIENT);
+    }
+    for i in 0..len {
+        write(&mut *out.add(i), i);
+    }
+    Ok(sys::Result::SUCCESS)
+}
+
+unsafe fn two_call_str(s: &str, capacity: u32, count: *mut u32, out: *mut c_char) -> Res {
+    let bytes = s.as_bytes();
+    two_call(bytes.len() + 1, capacity, count, out, |o, i| {
+        *o = bytes.get(i).map_or(0, |&x| x as c_char);
+    })
+}
+
+/// Read a null-terminated UTF-8 string from a fixed-size buffer
+fn read_fixed(x: &[c_char]) -> Option<&str> {
+    let bytes = unsafe { slice::from_raw_parts(x.as_ptr() as *const u8, x.len()) };
+    let end = bytes.iter().position(|&x| x == 0)?;
+    std::str::from_utf8(&bytes[..end]).ok()
+}
+
+/// Write a string to a fixed-size buffer, truncating if necessary
+fn write_fixed(out: &mut [c_char], s: &str) {
+    let len = s.len().min(out.len() - 1);
+    for (o, &i) in out.iter_mut().zip(&s.as_bytes()[..len]) {
+        *o = i as c_char;
+    }
+    out[len] = 0;
+}
+
+fn check_system(system_id: sys::SystemId) -> Res<()> {
+    if system_id.into_raw() != SYSTEM_ID {
+        return Err(sys::Result::ERROR_SYSTEM_INVALID);
+    }
+    Ok(())
+}
+
+/// Whether `x` is a well-formed path string
+fn is_valid_path(x: &str) -> bool {
+    x.len() < sys::MAX_PATH_LENGTH
+        && x.starts_with('/')
+        && x[1..].split('/').all(|component| {
+            !component.is_empty()
+                && component.bytes().any(|c| c != b'.')
+                && component
+                    .bytes()
+                    .all(|c| matches!(c, b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.'))
+        })
+}
+
+/// Whether `x` is a well-formed action or action set name
+fn is_valid_name(x: &str) -> bool {
+    !x.is_empty()
+        && x.bytes()
+            .all(|c| matches!(c, b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.'))
+}
+
+//
+// Instances
+//
+
+unsafe extern "system" fn enumerate_api_layer_properties(
+    capacity: u32,
+    count: *mut u32,
+    properties: *mut sys::ApiLayerProperties,
+) -> sys::Result {
+    guard(|| two_call(0, capacity, count, properties, |_, _| {}))
+}
+
+unsafe extern "system" fn enumerate_instance_extension_properties(
+    layer_name: *const c_char,
+    capacity: u32,
+    count: *mut u32,
+    properties: *mut sys::ExtensionProperties,
+) -> sys::Result {
+    guard(|| {
+        if !layer_name.is_null() {
+            return Err(sys::Result::ERROR_API_LAYER_NOT_PRESENT);
+        }
+        two_call(EXTENSIONS.len(), capacity, count, properties, |o, i| {
+            let (name, version) = EXTENSIONS[i];
+            write_fixed(&mut o.extension_name, name);
+            o.extension_version = version;
+        })
+    })
+}
+
+unsafe extern "system" fn destroy_instance(instance: sys::Instance) -> sys::Result {
+    guard(|| {
+        with(instance.into_raw(), |state| {
+            state.destroy_instance(instance.into_raw());
+            Ok(sys::Result::SUCCESS)
+        })
+    })
+}
+
+unsafe extern "system" fn result_to_string(
+    instance: sys::Instance,
+    value: sys::Result,
+    buffer: *mut c_char,
+) -> sys::Result {
+    guard(|| {
+        with(instance.into_raw(), |state| {
+            state.instance(instance.into_raw())?;
+            let name = format!("{:?}", value);
+            let s = if name.starts_with(|c: char| c == '-' || c.is_ascii_digit()) {
+                if value.into_raw() >= 0 {
+                    format!("XR_UNKNOWN_SUCCESS_{}", value.into_raw())
+                } else {
+                    format!("XR_UNKNOWN_FAILURE_{}", value.into_raw())
+                }
+            } else {
+                format!("XR_{}", name)
+            };
+            write_fixed(
+                slice::from_raw_parts_mut(buffer, sys::MAX_RESULT_STRING_SIZE),
+                &s,
+            );
+            Ok(sys::Result::SUCCESS)
+        })
+    })
+}
+
+unsafe extern "system" fn structure_type_to_string(
+    instance: sys::Instance,
+    value: StructureType,
+    buffer: *mut c_char,
+) -> sys::Result {
+    guard(|| {
+        with(instance.into_raw(), |state| {
+            state.instance(instance.into_raw())?;
+            let name = format!("{:?}", value);
+            let s = if name.starts_with(|c: char| c == '-' || c.is_ascii_digit()) {
+                format!("XR_UNKNOWN_STRUCTURE_TYPE_{}", value.into_raw())
+            } else {
+                format!("XR_TYPE_{}", name)
+            };
+            write_fixed(
+                slice::from_raw_parts_mut(buffer, sys::MAX_STRUCTURE_NAME_SIZE),
+                &s,
+            );
+            Ok(sys::Result::SUCCESS)
+        })
+    })
+}
+
+unsafe extern "system" fn get_instance_properties(
+    instance: sys::Instance,
+    properties: *mut sys::InstanceProperties,
+) -> sys::Result {
+    guard(|| {
+        with(instance.into_raw(), |state| {
+            state.instance(instance.into_raw())?;
+            let out = &mut *properties;
+            out.runtime_version = Version::new(0, 1, 0);
+            write_fixed(&mut out.runtime_name, &state.runtime_name);
+            Ok(sys::Result::SUCCESS)
+        })
+    })
+}
+
+unsafe extern "system" fn poll_event(
+    instance: sys::Instance,
+    event_data: *mut sys::EventDataBuffer,
+) -> sys::Result {
+    guard(|| {
+        with(instance.into_raw(), |state| {
+            let instance = state
+                .instances
+                .get_mut(&instance.into_raw())
+                .ok_or(sys::Result::ERROR_HANDLE_INVALID)?;
+            if instance.lost_events != 0 {
+                (event_data as *mut sys::EventDataEventsLost).write(sys::EventDataEventsLost {
+                    ty: sys::EventDataEventsLost::TYPE,
+                    next: ptr::null(),
+                    lost_event_count: mem::take(&mut instance.lost_events),
+                });
+                return Ok(sys::Result::SUCCESS);
+            }
+            let event = match instance.events.pop_front() {
+                Some(x) => x,
+                None => return Ok(sys::Result::EVENT_UNAVAILABLE),
+            };
+            match event {
+                PendingEvent::SessionStateChanged {
+                    session,
+                    state,
+                    time,
+                } => (event_data as *mut sys::EventDataSessionStateChanged).write(
+                    sys::EventDataSessionStateChanged {
+                        ty: sys::EventDataSessionStateChanged::TYPE,
+                        next: ptr::null(),
+                        session,
+                        state,
+                        time,
+                    },
+                ),
+                PendingEvent::InstanceLossPending { loss_time } => (event_data
+                    as *mut sys::EventDataInstanceLossPending)
+                    .write(sys::EventDataInstanceLossPending {
+                        ty: sys::EventDataInstanceLossPending::TYPE,
+                        next: ptr::null(),
+                        loss_time,
+                    }),
+                PendingEvent::InteractionProfileChanged { session } => (event_data
+                    as *mut sys::EventDataInteractionProfileChanged)
+                    .write(sys::EventDataInteractionProfileChanged {
+                        ty: sys::EventDataInteractionProfileChanged::TYPE,
+                        next: ptr::null(),
+                        session,
+                    }),
+            }
+            Ok(sys::Result::SUCCESS)
+        })
+    })
+}
+
+unsafe extern "system" fn string_to_path(
+    instance: sys::Instance,
+    path_string: *const c_char,
+    path: *mut sys::Path,
+) -> sys::Result {
+    guard(|| {
+        with(instance.into_raw(), |state| {
+            let instance = state.instance(instance.into_raw())?;
+            let s = CStr::from_ptr(path_string)
+                .to_str()
+                .map_err(|_| sys::Result::ERROR_PATH_FORMAT_INVALID)?;
+            if !is_valid_path(s) {
+                return Err(sys::Result::ERROR_PATH_FORMAT_INVALID);
+            }
+            *path = instance.intern(s);
+            Ok(sys::Result::SUCCESS)
+        })
+    })
+}
+
+unsafe extern "system" fn path_to_string(
+    instance: sys::Instance,
+    path: sys::Path,
+    capacity: u32,
+    count: *mut u32,
+    buffer: *mut c_char,
+) -> sys::Result {
+    guard(|| {
+        with(instance.into_raw(), |state| {
+            let instance = state.instance(instance.into_raw())?;
+            two_call_str(instance.path_string(path)?, capacity, count, buffer)
+        })
+    })
+}
+
+//
+// Systems
+//
+
+unsafe extern "system" fn get_system(
+    instance: sys::Instance,
+    get_info: *const sys::SystemGetInfo,
+    system_id: *mut sys::SystemId,
+) -> sys::Result {
+    guard(|| {
+        with(instance.into_raw(), |state| {
+            state.instance(instance.into_raw())?;
+            if (*get_info).form_factor != FormFactor::HEAD_MOUNTED_DISPLAY {
+                return Err(sys::Result::ERROR_FORM_FACTOR_UNSUPPORTED);
+            }
+            *system_id = sys::SystemId::from_raw(SYSTEM_ID);
+            Ok(sys::Result::SUCCESS)
+        })
+    })
+}
+
+unsafe extern "system" fn get_system_properties(
+    instance: sys::Instance,
+    system_id: sys::SystemId,
+    properties: *mut sys::SystemProperties,
+) -> sys::Result {
+    guard(|| {
+        with(instance.into_raw(), |state| {
+            state.instance(instance.into_raw())?;
+            check_system(system_id)?;
+            let out = &mut *properties;
+            out.system_id = system_id;
+            out.vendor_id = 0;
+            write_fixed(&mut out.system_name, "Mock HMD");
+            out.graphics_properties = sys::SystemGraphicsProperties {
+                max_swapchain_image_height: MAX_SWAPCHAIN_EXTENT,
+                max_swapchain_image_width: MAX_SWAPCHAIN_EXTENT,
+                max_layer_count: MAX_LAYER_COUNT,
+            };
+            out.tracking_properties = sys::SystemTrackingProperties {
+                orientation_tracking: sys::TRUE,
+                position_tracking: sys::TRUE,
+            };
+            Ok(sys::Result::SUCCESS)
+        })
+    })
+}
+
+unsafe extern "system" fn enumerate_view_configurations(
+    instance: sys::Instance,
+    system_id: sys::SystemId,
+    capacity: u32,
+    count: *mut u32,
+    out: *mut ViewConfigurationType,
+) -> sys::Result {
+    guard(|| {
+        with(instance.into_raw(), |state| {
+            state.instance(instance.into_raw())?;
+            check_system(system_id)?;
+            let tys = &state.view_configurations;
+            two_call(tys.len(), capacity, count, out, |o, i| *o = tys[i].0)
+        })
+    })
+}
+
+unsafe extern "system" fn enumerate_environment_blend_modes(
+    instance: sys::Instance,
+    system_id: sys::SystemId,
+    view_configuration_type: ViewConfigurationType,
+    capacity: u32,
+    count: *mut u32,
+    out: *mut EnvironmentBlendMode,
+) -> sys::Result {
+    guard(|| {
+        with(instance.into_raw(), |state| {
+            state.instance(instance.into_raw())?;
+            check_system(system_id)?;
+            state.view_configuration(view_configuration_type)?;
+            two_call(1, capacity, count, out, |o, _| {
+                *o = EnvironmentBlendMode::OPAQUE
+            })
+        })
+    })
+}
+
+unsafe extern "system" fn get_view_configuration_properties(
+    instance: sys::Instance,
+    system_id: sys::SystemId,
+    view_configuration_type: ViewConfigurationType,
+    properties: *mut sys::ViewConfigurationProperties,
+) -> sys::Result {
+    guard(|| {
+        with(instance.into_raw(), |state| {
+            state.instance(instance.into_raw())?;
+            check_system(system_id)?;
+            state.view_configuration(view_configuration_type)?;
+            let out = &mut *properties;
+            out.view_configuration_type = view_configuration_type;
+            out.fov_mutable = sys::FALSE;
+            Ok(sys::Result::SUCCESS)
+        })
+    })
+}
+
+unsafe extern "system" fn enumerate_view_configuration_views(
+    instance: sys::Instance,
+    system_id: sys::SystemId,
+    view_configuration_type: ViewConfigurationType,
+    capacity: u32,
+    count: *mut u32,
+    out: *mut sys::ViewConfigurationView,
+) -> sys::Result {
+    guard(|| {
+        with(instance.into_raw(), |state| {
+            state.instance(instance.into_raw())?;
+            check_system(system_id)?;
+            let views = state.view_configuration(view_configuration_type)?;
+            two_call(views.len(), capacity, count, out, |o, i| {
+                let x = &views[i];
+                o.recommended_image_rect_width = x.recommended_image_rect_width;
+                o.max_image_rect_width = x.max_image_rect_width;
+                o.recommended_image_rect_height = x.recommended_image_rect_height;
+                o.max_image_rect_height = x.max_image_rect_height;
+                o.recommended_swapchain_sample_count = x.recommended_swapchain_sample_count;
+                o.max_swapchain_sample_count = x.max_swapchain_sample_count;
+            })
+        })
+    })
+}
+
+//
+// Sessions
+//
+
+unsafe extern "system" fn create_session(
+    instance: sys::Instance,
+    create_info: *const sys::SessionCreateInfo,
+    session: *mut sys::Session,
+) -> sys::Result {
+    guard(|| {
+        with(instance.into_raw(), |state| {
+            let headless = state.instance(instance.into_raw())?.headless;
+            let info = &*create_info;
+            check_system(info.system_id)?;
+            let binding = info.next as *const sys::BaseInStructure;
+            let graphics = if binding.is_null() {
+                if !headless {
+                    return Err(sys::Result::ERROR_GRAPHICS_DEVICE_INVALID);
+                }
+                GraphicsApi::Headless
+            } else {
+                GraphicsApi::from_binding((*binding).ty)
+                    .ok_or(sys::Result::ERROR_GRAPHICS_DEVICE_INVALID)?
+            };
+            let handle = state.alloc();
+            state.sessions.insert(
+                handle,
+                MockSession {
+                    instance: instance.into_raw(),
+                    state: SessionState::UNKNOWN,
+                    graphics,
+                    running: false,
+                    exit_requested: false,
+                    view_configuration: None,
+                    frame_waited: false,
+                    frame_begun: false,
+                    attached_action_sets: None,
+                    actions: HashMap::new(),
+                },
+            );
+            state.transition(handle, SessionState::IDLE);
+            state.auto_transition(handle, &[SessionState::READY]);
+            *session = sys::Session::from_raw(handle);
+            Ok(sys::Result::SUCCESS)
+        })
+    })
+}
+
+unsafe extern "system" fn destroy_session(session: sys::Session) -> sys::Result {
+    guard(|| {
+        with(session.into_raw(), |state| {
+            state.destroy_session(session.into_raw());
+            Ok(sys::Result::SUCCESS)
+        })
+    })
+}
+
+unsafe extern "system" fn begin_session(
+    session: sys::Session,
+    begin_info: *const sys::SessionBeginInfo,
+) -> sys::Result {
+    guard(|| {
+        with(session.into_raw(), |state| {
+            let ty = (*begin_info).primary_view_configuration_type;
+            state.view_configuration(ty)?;
+            let s = state.session(session.into_raw())?;
+            if s.running {
+                return Err(sys::Result::ERROR_SESSION_RUNNING);
+            }
+            if s.state != SessionState::READY {
+                return Err(sys::Result::ERROR_SESSION_NOT_READY);
+            }
+            s.running = true;
+            s.view_configuration = Some(ty);
+            state.auto_transition(
+                session.into_raw(),
+                &[
+                    SessionState::SYNCHRONIZED,
+                    SessionState::VISIBLE,
+                    SessionState::FOCUSED,
+                ],
+            );
+            Ok(sys::Result::SUCCESS)
+        })
+    })
+}
+
+unsafe extern "system" fn end_session(session: sys::Session) -> sys::Result {
+    guard(|| {
+        with(session.into_raw(), |state| {
+            let s = state.session(session.into_raw())?;
+            if !s.running {
+                return Err(sys::Result::ERROR_SESSION_NOT_RUNNING);
+            }
+            if s.state != SessionState::STOPPING {
+                return Err(sys::Result::ERROR_SESSION_NOT_STOPPING);
+            }
+            s.running = false;
+            s.view_configuration = None;
+            s.frame_waited = false;
+            s.frame_begun = false;
+            let next: &[_] = if s.exit_requested {
+                &[SessionState::IDLE, SessionState::EXITING]
+            } else {
+                &[SessionState::IDLE]
+            };
+            state.auto_transition(session.into_raw(), next);
+            Ok(sys::Result::SUCCESS)
+        })
+    })
+}
+
+unsafe extern "system" fn request_exit_session(session: sys::Session) -> sys::Result {
+    guard(|| {
+        with(session.into_raw(), |state| {
+            let s = state.session(session.into_raw())?;
+            if !s.running {
+                return Err(sys::Result::ERROR_SESSION_NOT_RUNNING);
+            }
+            s.exit_requested = true;
+            let steps = [
+                SessionState::FOCUSED,
+                SessionState::VISIBLE,
+                SessionState::SYNCHRONIZED,
+                SessionState::STOPPING,
+            ];
+            let current = steps.iter().position(|&x| x == s.state).unwrap_or(0);
+            state.auto_transition(session.into_raw(), &steps[current + 1..]);
+            Ok(sys::Result::SUCCESS)
+        })
+    })
+}
+
+//
+// Frames
+//
+
+unsafe extern "system" fn wait_frame(
+    session: sys::Session,
+    _frame_wait_info: *const sys::FrameWaitInfo,
+    frame_state: *mut sys::FrameState,
+) -> sys::Result {
+    guard(|| {
+        with(session.into_raw(), |state| {
+            state.enter("xrWaitFrame")?;
+            let period = state.display_period;
+            let s = state.session(session.into_raw())?;
+            if !s.running {
+                return Err(sys::Result::ERROR_SESSION_NOT_RUNNING);
+            }
+            if s.frame_waited {
+                // A real runtime would block until the previous frame is begun, which could never
+                // happen in a single-threaded test.
+                return Err(sys::Result::ERROR_CALL_ORDER_INVALID);
+            }
+            s.frame_waited = true;
+            let should_render =
+                s.state == SessionState::VISIBLE || s.state == SessionState::FOCUSED;
+            state.time += period;
+            let out = &mut *frame_state;
+            out.predicted_display_time = Time::from_nanos(state.time + period);
+            out.predicted_display_period = Duration::from_nanos(period);
+            out.should_render = should_render.into();
+            Ok(sys::Result::SUCCESS)
+        })
+    })
+}
+
+unsafe extern "system" fn begin_frame(
+    session: sys::Session,
+    _frame_begin_info: *const sys::FrameBeginInfo,
+) -> sys::Result {
+    guard(|| {
+        with(session.into_raw(), |state| {
+            state.enter("xrBeginFrame")?;
+            let s = state.session(session.into_raw())?;
+            if !s.running {
+                return Err(sys::Result::ERROR_SESSION_NOT_RUNNING);
+            }
+            if !s.frame_waited {
+                return Err(sys::Result::ERROR_CALL_ORDER_INVALID);
+            }
+            s.frame_waited = false;
+            if mem::replace(&mut s.frame_begun, true) {
+                return Ok(sys::Result::FRAME_DISCARDED);
+            }
+            Ok(sys::Result::SUCCESS)
+        })
+    })
+}
+
+unsafe extern "system" fn end_frame(
+    session: sys::Session,
+    frame_end_info: *const sys::FrameEndInfo,
+) -> sys::Result {
+    guard(|| {
+        with(session.into_raw(), |state| {
+            state.enter("xrEndFrame")?;
+            let info = &*frame_end_info;
+            let s = state.session(session.into_raw())?;
+            if !s.running {
+                return Err(sys::Result::ERROR_SESSION_NOT_RUNNING);
+            }
+            if !s.frame_begun {
+                return Err(sys::Result::ERROR_CALL_ORDER_INVALID);
+            }
+            if info.display_time.as_nanos() <= 0 {
+                return Err(sys::Result::ERROR_TIME_INVALID);
+            }
+            if info.environment_blend_mode != EnvironmentBlendMode::OPAQUE {
+                return Err(sys::Result::ERROR_ENVIRONMENT_BLEND_MODE_UNSUPPORTED);
+            }
+            if info.layer_count > MAX_LAYER_COUNT {
+                return Err(sys::Result::ERROR_LAYER_LIMIT_EXCEEDED);
+            }
+            if s.graphics == GraphicsApi::Headless && info.layer_count != 0 {
+                return Err(sys::Result::ERROR_LAYER_INVALID);
+            }
+            let view_configuration = s.view_configuration.unwrap();
+            let view_count = state.view_configuration(view_configuration)?.len();
+            let layers = if info.layer_count == 0 {
+                &[]
+            } else {
+                slice::from_raw_parts(info.layers, info.layer_count as usize)
+            };
+            let layers = layers
+                .iter()
+                .map(|&layer| decode_layer(state, session.into_raw(), view_count, layer))
+                .collect::<Res<Vec<_>>>()?;
+            state
+                .sessions
+                .get_mut(&session.into_raw())
+                .unwrap()
+                .frame_begun = false;
+            state.frames.push(SubmittedFrame {
+                session,
+                display_time: info.display_time,
+                environment_blend_mode: info.environment_blend_mode,
+                layers,
+            });
+            Ok(sys::Result::SUCCESS)
+        })
+    })
+}
+
+unsafe fn decode_layer(
+    state: &State,
+    session: u64,
+    view_count: usize,
+    layer: *const sys::CompositionLayerBaseHeader,
+) -> Res<SubmittedLayer> {
+    if layer.is_null() {
+        return Err(sys::Result::ERROR_LAYER_INVALID);
+    }
+    let check_space = |space: sys::Space| match state.spaces.get(&space.into_raw()) {
+        Some(x) if x.session == session => Ok(()),
+        _ => Err(sys::Result::ERROR_HANDLE_INVALID),
+    };
+    Ok(match (*layer).ty {
+        StructureType::COMPOSITION_LAYER_PROJECTION => {
+            let layer = &*(layer as *const sys::CompositionLayerProjection);
+            check_space(layer.space)?;
+            if layer.view_count as usize != view_count {
+                return Err(sys::Result::ERROR_VALIDATION_FAILURE);
+            }
+            let views = slice::from_raw_parts(layer.views, view_count)
+                .iter()
+                .map(|view| {
+                    Ok(SubmittedProjectionView {
+                        pose: view.pose,
+                        fov: view.fov,
+                        sub_image: decode_sub_image(state, session, &view.sub_image)?,
+                    })
+                })
+                .collect::<Res<Vec<_>>>()?;
+            SubmittedLayer::Projection {
+                layer_flags: layer.layer_flags,
+                space: layer.space,
+                views,
+            }
+        }
+        StructureType::COMPOSITION_LAYER_QUAD => {
+            let layer = &*(layer as *const sys::CompositionLayerQuad);
+            check_space(layer.space)?;
+            SubmittedLayer::Quad {
+                layer_flags: layer.layer_flags,
+                space: layer.space,
+                eye_visibility: layer.eye_visibility,
+                sub_image: decode_sub_image(state, session, &layer.sub_image)?,
+                pose: layer.pose,
+                size: layer.size,
+            }
+        }
+        ty => SubmittedLayer::Other(ty),
+    })
+}
+
+fn decode_sub_image(
+    state: &State,
+    session: u64,
+    x: &sys::SwapchainSubImage,
+) -> Res<SubmittedSubImage> {
+    let swapchain = match state.swapchains.get(&x.swapchain.into_raw()) {
+        Some(s) if s.session == session => s,
+        _ => return Err(sys::Result::ERROR_HANDLE_INVALID),
+    };
+    let image_index = swapchain
+        .last_released
+        .ok_or(sys::Result::ERROR_LAYER_INVALID)?;
+    let rect = x.image_rect;
+    if rect.offset.x < 0
+        || rect.offset.y < 0
+        || rect.extent.width <= 0
+        || rect.extent.height <= 0
+        || (rect.offset.x + rect.extent.width) as u32 > swapchain.info.width
+        || (rect.offset.y + rect.extent.height) as u32 > swapchain.info.height
+    {
+        return Err(sys::Result::ERROR_SWAPCHAIN_RECT_INVALID);
+    }
+    if x.image_array_index >= swapchain.info.array_size {
+        return Err(sys::Result::ERROR_VALIDATION_FAILURE);
+    }
+    Ok(SubmittedSubImage {
+        swapchain: x.swapchain,
+        image_rect: rect,
+        image_array_index: x.image_array_index,
+        image_index,
+    })
+}
+
+unsafe extern "system" fn locate_views(
+    session: sys::Session,
+    view_locate_info: *const sys::ViewLocateInfo,
+    view_state: *mut sys::ViewState,
+    capacity: u32,
+    count: *mut u32,
+    views: *mut sys::View,
+) -> sys::Result {
+    guard(|| {
+        with(session.into_raw(), |state| {
+            let info = &*view_locate_info;
+            state.session(session.into_raw())?;
+            match state.spaces.get(&info.space.into_raw()) {
+                Some(x) if x.session == session.into_raw() => {}
+                _ => return Err(sys::Result::ERROR_HANDLE_INVALID),
+            }
+            if info.display_time.as_nanos() <= 0 {
+                return Err(sys::Result::ERROR_TIME_INVALID);
+            }
+            let view_count = state
+                .view_configuration(info.view_configuration_type)?
+                .len();
+            let base = state.space_pose(info.space.into_raw());
+            let flags = if base.is_some() {
+                state.view_state_flags
+            } else {
+                ViewStateFlags::EMPTY
+            };
+            let head = inverse(base.unwrap_or(Posef::IDENTITY));
+            let head = compose(head, state.head_pose);
+            (*view_state).view_state_flags = flags;
+            two_call(view_count, capacity, count, views, |o, i| {
+                let view = state
+                    .views
+                    .get(i)
+                    .or_else(|| state.views.last())
+                    .copied()
+                    .unwrap_or_default();
+                o.pose = compose(head, view.pose);
+                o.fov = view.fov;
+            })
+        })
+    })
+}
+
+//
+// Spaces
+//
+
+unsafe extern "system" fn enumerate_reference_spaces(
+    session: sys::Session,
+    capacity: u32,
+    count: *mut u32,
+    spaces: *mut ReferenceSpaceType,
+) -> sys::Result {
+    const SPACES: [ReferenceSpaceType; 3] = [
+        ReferenceSpaceType::VIEW,
+        ReferenceSpaceType::LOCAL,
+        ReferenceSpaceType::STAGE,
+    ];
+    guard(|| {
+        with(session.into_raw(), |state| {
+            state.session(session.into_raw())?;
+            two_call(SPACES.len(), capacity, count, spaces, |o, i| *o = SPACES[i])
+        })
+    })
+}
+
+unsafe extern "system" fn create_reference_space(
+    session: sys::Session,
+    create_info: *const sys::ReferenceSpaceCreateInfo,
+    space: *mut sys::Space,
+) -> sys::Result {
+    guard(|| {
+        with(session.into_raw(), |state| {
+            state.session(session.into_raw())?;
+            let info = &*create_info;
+            let ty = info.reference_space_type;
+            if ty != ReferenceSpaceType::VIEW
+                && ty != ReferenceSpaceType::LOCAL
+                && ty != ReferenceSpaceType::STAGE
+            {
+                return Err(sys::Result::ERROR_REFERENCE_SPACE_UNSUPPORTED);
+            }
+            if !is_valid_pose(&info.pose_in_reference_space) {
+                return Err(sys::Result::ERROR_POSE_INVALID);
+            }
+            let handle = state.alloc();
+            state.spaces.insert(
+                handle,
+                MockSpace {
+                    session: session.into_raw(),
+                    kind: SpaceKind::Reference(ty),
+                    pose: info.pose_in_reference_space,
+                },
+            );
+            *space = sys::Space::from_raw(handle);
+            Ok(sys::Result::SUCCESS)
+        })
+    })
+}
+
+unsafe extern "system" fn create_action_space(
+    session: sys::Session,
+    create_info: *const sys::ActionSpaceCreateInfo,
+    space: *mut sys::Space,
+) -> sys::Result {
+    guard(|| {
+        with(session.into_raw(), |state| {
+            let instance = state.session(session.into_raw())?.instance;
+            let info = &*create_info;
+            let action = state.action(info.action.into_raw())?;
+            if action.ty != ActionType::POSE_INPUT {
+                return Err(sys::Result::ERROR_ACTION_TYPE_MISMATCH);
+            }
+            let subaction_path =
+                state.instances[&instance].subaction_string(info.subaction_path)?;
+            check_subaction_path(&state.actions[&info.action.into_raw()], &subaction_path)?;
+            if !is_valid_pose(&info.pose_in_action_space) {
+                return Err(sys::Result::ERROR_POSE_INVALID);
+            }
+            let handle = state.alloc();
+            state.spaces.insert(
+                handle,
+                MockSpace {
+                    session: session.into_raw(),
+                    kind: SpaceKind::Action {
+                        action: info.action.into_raw(),
+                        subaction_path,
+                    },
+                    pose: info.pose_in_action_space,
+                },
+            );
+            *space = sys::Space::from_raw(handle);
+            Ok(sys::Result::SUCCESS)
+        })
+    })
+}
+
+unsafe extern "system" fn destroy_space(space: sys::Space) -> sys::Result {
+    guard(|| {
+        with(space.into_raw(), |state| {
+            if state.spaces.remove(&space.into_raw()).is_some() {
+                state.destroyed.push(space.into_raw());
+            }
+            Ok(sys::Result::SUCCESS)
+        })
+    })
+}
+
+unsafe extern "system" fn locate_space(
+    space: sys::Space,
+    base_space: sys::Space,
+    time: Time,
+    location: *mut sys::SpaceLocation,
+) -> sys::Result {
+    guard(|| {
+        with(space.into_raw(), |state| {
+            let pose = relative_pose(state, space, base_space, time)?;
+            let out = &mut *location;
+            out.location_flags = location_flags(pose);
+            out.pose = pose.unwrap_or(Posef::IDENTITY);
+            let mut next = out.next as *mut sys::BaseOutStructure;
+            while !next.is_null() {
+                if (*next).ty == sys::SpaceVelocity::TYPE {
+                    let velocity = &mut *(next as *mut sys::SpaceVelocity);
+                    velocity.velocity_flags = velocity_flags(pose);
+                    velocity.linear_velocity = Vector3f::default();
+                    velocity.angular_velocity = Vector3f::default();
+                }
+                next = (*next).next;
+            }
+            Ok(sys::Result::SUCCESS)
+        })
+    })
+}
+
+unsafe extern "system" fn locate_spaces(
+    session: sys::Session,
+    locate_info: *const sys::SpacesLocateInfo,
+    space_locations: *mut sys::SpaceLocations,
+) -> sys::Result {
+    guard(|| {
+        with(session.into_raw(), |state| {
+            state.session(session.into_raw())?;
+            let info = &*locate_info;
+            let out = &mut *space_locations;
+            if info.space_count == 0 || out.location_count != info.space_count {
+                return Err(sys::Result::ERROR_VALIDATION_FAILURE);
+            }
+            let mut velocities = None;
+            let mut next = out.next as *mut sys::BaseOutStructure;
+            while !next.is_null() {
+                if (*next).ty == sys::SpaceVelocities::TYPE {
+                    let x = &mut *(next as *mut sys::SpaceVelocities);
+                    if x.velocity_count != info.space_count {
+                        return Err(sys::Result::ERROR_VALIDATION_FAILURE);
+                    }
+                    velocities = Some(x);
+                }
+                next = (*next).next;
+            }
+            for i in 0..info.space_count as usize {
+                let space = *info.spaces.add(i);
+                match state.spaces.get(&space.into_raw()) {
+                    Some(x) if x.session == session.into_raw() => {}
+                    _ => return Err(sys::Result::ERROR_HANDLE_INVALID),
+                }
+                let pose = relative_pose(state, space, info.base_space, info.time)?;
+                let location = &mut *out.locations.add(i);
+                location.location_flags = location_flags(pose);
+                location.pose = pose.unwrap_or(Posef::IDENTITY);
+                if let Some(ref velocities) = velocities {
+                    let velocity = &mut *velocities.velocities.add(i);
+                    velocity.velocity_flags = velocity_flags(pose);
+                    velocity.linear_velocity = Vector3f::default();
+                    velocity.angular_velocity = Vector3f::default();
+                }
+            }
+            Ok(sys::Result::SUCCESS)
+        })
+    })
+}
+
+/// Pose of `space` in `base_space`, if both are locatable
+fn relative_pose(
+    state: &mut State,
+    space: sys::Space,
+    base_space: sys::Space,
+    time: Time,
+) -> Res<Option<Posef>> {
+    let session = state
+        .spaces
+        .get(&space.into_raw())
+        .ok_or(sys::Result::ERROR_HANDLE_INVALID)?
+        .session;
+    state.session(session)?;
+    match state.spaces.get(&base_space.into_raw()) {
+        Some(x) if x.session == session => {}
+        _ => return Err(sys::Result::ERROR_HANDLE_INVALID),
+    }
+    if time.as_nanos() <= 0 {
+        return Err(sys::Result::ERROR_TIME_INVALID);
+    }
+    Ok(
+        match (
+            state.space_pose(space.into_raw()),
+            state.space_pose(base_space.into_raw()),
+        ) {
+            (Some(space), Some(base)) => Some(compose(inverse(base), space)),
+            _ => None,
+        },
+    )
+}
+
+fn location_flags(pose: Option<Posef>) -> SpaceLocationFlags {
+    if pose.is_some() {
+        SpaceLocationFlags::ORIENTATION_VALID
+            | SpaceLocationFlags::POSITION_VALID
+            | SpaceLocationFlags::ORIENTATION_TRACKED
+            | SpaceLocationFlags::POSITION_TRACKED
+    } else {
+        SpaceLocationFlags::EMPTY
+    }
+}
+
+/// Poses are scripted as static, so any velocity is zero
+fn velocity_flags(pose: Option<Posef>) -> SpaceVelocityFlags {
+    if pose.is_some() {
+        SpaceVelocityFlags::LINEAR_VALID | SpaceVelocityFlags::ANGULAR_VALID
+    } else {
+        SpaceVelocityFlags::EMPTY
+    }
+}
+
+unsafe extern "system" fn get_reference_space_bounds_rect(
+    session: sys::Session,
+    _reference_space_type: ReferenceSpaceType,
+    bounds: *mut Extent2Df,
+) -> sys::Result {
+    guard(|| {
+        with(session.into_raw(), |state| {
+            state.session(session.into_raw())?;
+            *bounds = Extent2Df::default();
+            Ok(sys::Result::SPACE_BOUNDS_UNAVAILABLE)
+        })
+    })
+}
+
+//
+// Swapchains
+//
+
+unsafe extern "system" fn enumerate_swapchain_formats(
+    session: sys::Session,
+    capacity: u32,
+    count: *mut u32,
+    formats: *mut i64,
+) -> sys::Result {
+    guard(|| {
+        with(session.into_raw(), |state| {
+            let graphics = state.session(session.into_raw())?.graphics;
+            let available = match state.swapchain_formats {
+                Some(ref x) if graphics != GraphicsApi::Headless => x.clone(),
+                _ => graphics.default_formats(),
+            };
+            two_call(available.len(), capacity, count, formats, |o, i| {
+                *o = available[i]
+            })
+        })
+    })
+}
+
+unsafe extern "system" fn create_swapchain(
+    session: sys::Session,
+    create_info: *const sys::SwapchainCreateInfo,
+    swapchain: *mut sys::Swapchain,
+) -> sys::Result {
+    guard(|| {
+        with(session.into_raw(), |state| {
+            let graphics = state.session(session.into_raw())?.graphics;
+            let info = &*create_info;
+            let supported = match state.swapchain_formats {
+                Some(ref x) if graphics != GraphicsApi::Headless => x.contains(&info.format),
+                _ => graphics.default_formats().contains(&info.format),
+            };
+            if !supported {
+                return Err(sys::Result::ERROR_SWAPCHAIN_FORMAT_UNSUPPORTED);
+            }
+            if info.width == 0
+                || info.height == 0
+                || info.width > MAX_SWAPCHAIN_EXTENT
+                || info.height > MAX_SWAPCHAIN_EXTENT
+                || info.sample_count == 0
+                || info.array_size == 0
+                || info.mip_count == 0
+                || (info.face_count != 1 && info.face_count != 6)
+            {
+                return Err(sys::Result::ERROR_VALIDATION_FAILURE);
+            }
+            let handle = state.alloc();
+            let image_count = state.swapchain_image_count;
+            state.swapchains.insert(
+                handle,
+                MockSwapchain {
+                    session: session.into_raw(),
+                    info: SwapchainDesc {
+                        format: info.format,
+                        width: info.width,
+                        height: info.height,
+                        sample_count: info.sample_count,
+                        face_count: info.face_count,
+                        array_size: info.array_size,
+                        mip_count: info.mip_count,
+                    },
+                    image_count,
+                    next_image: 0,
+                    acquired: VecDeque::new(),
+                    last_released: None,
+                },
+            );
+            *swapchain = sys::Swapchain::from_raw(handle);
+            Ok(sys::Result::SUCCESS)
+        })
+    })
+}
+
+unsafe extern "system" fn destroy_swapchain(swapchain: sys::Swapchain) -> sys::Result {
+    guard(|| {
+        with(swapchain.into_raw(), |state| {
+            if state.swapchains.remove(&swapchain.into_raw()).is_some() {
+                state.destroyed.push(swapchain.into_raw());
+            }
+            Ok(sys::Result::SUCCESS)
+        })
+    })
+}
+
+unsafe extern "system" fn enumerate_swapchain_images(
+    swapchain: sys::Swapchain,
+    capacity: u32,
+    count: *mut u32,
+    images: *mut sys::SwapchainImageBaseHeader,
+) -> sys::Result {
+    guard(|| {
+        with(swapchain.into_raw(), |state| {
+            let (session, image_count) = {
+                let s = state.swapchain(swapchain.into_raw())?;
+                (s.session, s.image_count as usize)
+            };
+            let graphics = state.sessions[&session].graphics;
+            if capacity != 0 && (*images).ty != graphics.swapchain_image_type() {
+                return Err(sys::Result::ERROR_VALIDATION_FAILURE);
+            }
+            // Fabricate distinct, non-null image names
+            let name = |i: usize| (swapchain.into_raw() << 8) + i as u64 + 1;
+            match graphics {
+                GraphicsApi::Vulkan => two_call(
+                    image_count,
+                    capacity,
+                    count,
+                    images as *mut sys::SwapchainImageVulkanKHR,
+                    |o, i| o.image = name(i),
+                ),
+                GraphicsApi::OpenGl => two_call(
+                    image_count,
+                    capacity,
+                    count,
+                    images as *mut sys::SwapchainImageOpenGLKHR,
+                    |o, i| o.image = name(i) as u32,
+                ),
+                GraphicsApi::OpenGlEs => two_call(
+                    image_count,
+                    capacity,
+                    count,
+                    images as *mut sys::SwapchainImageOpenGLESKHR,
+                    |o, i| o.image = name(i) as u32,
+                ),
+                // Headless sessions can't create swapchains
+                GraphicsApi::Headless => unreachable!(),
+            }
+        })
+    })
+}
+
+unsafe extern "system" fn acquire_swapchain_image(
+    swapchain: sys::Swapchain,
+    _acquire_info: *const sys::SwapchainImageAcquireInfo,
+    index: *mut u32,
+) -> sys::Result {
+    guard(|| {
+        with(swapchain.into_raw(), |state| {
+            state.enter("xrAcquireSwapchainImage")?;
+            let s = state.swapchain(swapchain.into_raw())?;
+            if s.acquired.len() == s.image_count as usize {
+                return Err(sys::Result::ERROR_CALL_ORDER_INVALID);
+            }
+            let image = s.next_image;
+            s.next_image = (image + 1) % s.image_count;
+            s.acquired.push_back((image, false));
+            *index = image;
+            Ok(sys::Result::SUCCESS)
+        })
+    })
+}
+
+unsafe extern "system" fn wait_swapchain_image(
+    swapchain: sys::Swapchain,
+    _wait_info: *const sys::SwapchainImageWaitInfo,
+) -> sys::Result {
+    guard(|| {
+        with(swapchain.into_raw(), |state| {
+            state.enter("xrWaitSwapchainImage")?;
+            let s = state.swapchain(swapchain.into_raw())?;
+            match s.acquired.iter_mut().find(|x| !x.1) {
+                Some(x) => x.1 = true,
+                None => return Err(sys::Result::ERROR_CALL_ORDER_INVALID),
+            }
+            Ok(sys::Result::SUCCESS)
+        })
+    })
+}
+
+unsafe extern "system" fn release_swapchain_image(
+    swapchain: sys::Swapchain,
+    _release_info: *const sys::SwapchainImageReleaseInfo,
+) -> sys::Result {
+    guard(|| {
+        with(swapchain.into_raw(), |state| {
+            state.enter("xrReleaseSwapchainImage")?;
+            let s = state.swapchain(swapchain.into_raw())?;
+            match s.acquired.front() {
+                Some(&(image, true)) => {
+                    s.acquired.pop_front();
+                    s.last_released = Some(image);
+                }
+                _ => return Err(sys::Result::ERROR_CALL_ORDER_INVALID),
+            }
+            Ok(sys::Result::SUCCESS)
+        })
+    })
+}
+
+//
+// Actions
+//
+
+unsafe extern "system" fn create_action_set(
+    instance: sys::Instance,
+    create_info: *const sys::ActionSetCreateInfo,
+    action_set: *mut sys::ActionSet,
+) -> sys::Result {
+    guard(|| {
+        with(instance.into_raw(), |state| {
+            state.instance(instance.into_raw())?;
+            let info = &*create_info;
+            let name = read_fixed(&info.action_set_name)
+                .filter(|x| is_valid_name(x))
+                .ok_or(sys::Result::ERROR_NAME_INVALID)?;
+            let localized_name = read_fixed(&info.localized_action_set_name)
+                .filter(|x| !x.is_empty())
+                .ok_or(sys::Result::ERROR_LOCALIZED_NAME_INVALID)?;
+            for set in state
+                .action_sets
+                .values()
+                .filter(|x| x.instance == instance.into_raw())
+            {
+                if set.name == name {
+                    return Err(sys::Result::ERROR_NAME_DUPLICATED);
+                }
+                if set.localized_name == localized_name {
+                    return Err(sys::Result::ERROR_LOCALIZED_NAME_DUPLICATED);
+                }
+            }
+            let set = MockActionSet {
+                instance: instance.into_raw(),
+                name: name.into(),
+                localized_name: localized_name.into(),
+                attached: false,
+            };
+            let handle = state.alloc();
+            state.action_sets.insert(handle, set);
+            *action_set = sys::ActionSet::from_raw(handle);
+            Ok(sys::Result::SUCCESS)
+        })
+    })
+}
+
+unsafe extern "system" fn destroy_action_set(action_set: sys::ActionSet) -> sys::Result {
+    guard(|| {
+        with(action_set.into_raw(), |state| {
+            state.destroy_action_set(action_set.into_raw());
+            Ok(sys::Result::SUCCESS)
+        })
+    })
+}
+
+unsafe extern "system" fn create_action(
+    action_set: sys::ActionSet,
+    create_info: *const sys::ActionCreateInfo,
+    action: *mut sys::Action,
+) -> sys::Result {
+    guard(|| {
+        with(action_set.into_raw(), |state| {
+            let set = state.action_set(action_set.into_raw())?;
+            if set.attached {
+                return Err(sys::Result::ERROR_ACTIONSETS_ALREADY_ATTACHED);
+            }
+            let instance = set.instance;
+            let info = &*create_info;
+            let name = read_fixed(&info.action_name)
+                .filter(|x| is_valid_name(x))
+                .ok_or(sys::Result::ERROR_NAME_INVALID)?;
+            let localized_name = read_fixed(&info.localized_action_name)
+                .filter(|x| !x.is_empty())
+                .ok_or(sys::Result::ERROR_LOCALIZED_NAME_INVALID)?;
+            for existing in state
+                .actions
+                .values()
+                .filter(|x| x.action_set == action_set.into_raw())
+            {
+                if existing.name == name {
+                    return Err(sys::Result::ERROR_NAME_DUPLICATED);
+                }
+                if existing.localized_name == localized_name {
+                    return Err(sys::Result::ERROR_LOCALIZED_NAME_DUPLICATED);
+                }
+            }
+            let paths = if info.count_subaction_paths == 0 {
+                &[]
+            } else {
+                slice::from_raw_parts(info.subaction_paths, info.count_subaction_paths as usize)
+            };
+            let mut subaction_paths = Vec::<String>::new();
+            for &path in paths {
+                let path = state.instances[&instance].path_string(path)?;
+                if subaction_paths.iter().any(|x| x == path) {
+                    return Err(sys::Result::ERROR_PATH_UNSUPPORTED);
+                }
+                subaction_paths.push(path.into());
+            }
+            let new = MockAction {
+                action_set: action_set.into_raw(),
+                name: name.into(),
+                localized_name: localized_name.into(),
+                ty: info.action_type,
+                subaction_paths,
+            };
+            let handle = state.alloc();
+            state.actions.insert(handle, new);
+            *action = sys::Action::from_raw(handle);
+            Ok(sys::Result::SUCCESS)
+        })
+    })
+}
+
+unsafe extern "system" fn destroy_action(action: sys::Action) -> sys::Result {
+    guard(|| {
+        with(action.into_raw(), |state| {
+            if state.actions.remove(&action.into_raw()).is_some() {
+                state.destroyed.push(action.into_raw());
+            }
+            Ok(sys::Result::SUCCESS)
+        })
+    })
+}
+
+unsafe extern "system" fn suggest_interaction_profile_bindings(
+    instance: sys::Instance,
+    suggested_bindings: *const sys::InteractionProfileSuggestedBinding,
+) -> sys::Result {
+    guard(|| {
+        with(instance.into_raw(), |state| {
+            let info = &*suggested_bindings;
+            {
+                let instance = state.instance(instance.into_raw())?;
+                let profile = instance.path_string(info.interaction_profile)?;
+                if !profile.starts_with("/interaction_profiles/") {
+                    return Err(sys::Result::ERROR_PATH_UNSUPPORTED);
+                }
+            }
+            let bindings = if info.count_suggested_bindings == 0 {
+                &[]
+            } else {
+                slice::from_raw_parts(
+                    info.suggested_bindings,
+                    info.count_suggested_bindings as usize,
+                )
+            };
+            for binding in bindings {
+                state.action(binding.action.into_raw())?;
+                if state.action_sets[&state.actions[&binding.action.into_raw()].action_set].attached
+                {
+                    return Err(sys::Result::ERROR_ACTIONSETS_ALREADY_ATTACHED);
+                }
+                if state.action_instance(binding.action.into_raw()) != instance.into_raw() {
+                    return Err(sys::Result::ERROR_HANDLE_INVALID);
+                }
+                let path = state.instances[&instance.into_raw()].path_string(binding.binding)?;
+                if !path.starts_with("/user/") {
+                    return Err(sys::Result::ERROR_PATH_UNSUPPORTED);
+                }
+            }
+            Ok(sys::Result::SUCCESS)
+        })
+    })
+}
+
+unsafe extern "system" fn attach_session_action_sets(
+    session: sys::Session,
+    attach_info: *const sys::SessionActionSetsAttachInfo,
+) -> sys::Result {
+    guard(|| {
+        with(session.into_raw(), |state| {
+            let instance = {
+                let s = state.session(session.into_raw())?;
+                if s.attached_action_sets.is_some() {
+                    return Err(sys::Result::ERROR_ACTIONSETS_ALREADY_ATTACHED);
+                }
+                s.instance
+            };
+            let info = &*attach_info;
+            let sets = if info.count_action_sets == 0 {
+                &[]
+            } else {
+                slice::from_raw_parts(info.action_sets, info.count_action_sets as usize)
+            };
+            for set in sets {
+                if state.action_set(set.into_raw())?.instance != instance {
+                    return Err(sys::Result::ERROR_HANDLE_INVALID);
+                }
+            }
+            for set in sets {
+                state.action_sets.get_mut(&set.into_raw()).unwrap().attached = true;
+            }
+            state
+                .sessions
+                .get_mut(&session.into_raw())
+                .unwrap()
+                .attached_action_sets = Some(sets.iter().map(|x| x.into_raw()).collect());
+            Ok(sys::Result::SUCCESS)
+        })
+    })
+}
+
+unsafe extern "system" fn get_current_interaction_profile(
+    session: sys::Session,
+    top_level_user_path: sys::Path,
+    interaction_profile: *mut sys::InteractionProfileState,
+) -> sys::Result {
+    guard(|| {
+        with(session.into_raw(), |state| {
+            let s = state.session(session.into_raw())?;
+            if s.attached_action_sets.is_none() {
+                return Err(sys::Result::ERROR_ACTIONSET_NOT_ATTACHED);
+            }
+            let instance = s.instance;
+            let user_path = state.instances[&instance]
+                .path_string(top_level_user_path)?
+                .to_owned();
+            let profile = state.interaction_profiles.get(&user_path).cloned();
+            let instance = state.instances.get_mut(&instance).unwrap();
+            (*interaction_profile).interaction_profile = match profile {
+                Some(x) => instance.intern(&x),
+                None => sys::Path::NULL,
+            };
+            Ok(sys::Result::SUCCESS)
+        })
+    })
+}
+
+unsafe extern "system" fn sync_actions(
+    session: sys::Session,
+    sync_info: *const sys::ActionsSyncInfo,
+) -> sys::Result {
+    guard(|| {
+        with(session.into_raw(), |state| {
+            let (focused, attached) = {
+                let s = state.session(session.into_raw())?;
+                let attached = s
+                    .attached_action_sets
+                    .clone()
+                    .ok_or(sys::Result::ERROR_ACTIONSET_NOT_ATTACHED)?;
+                (s.state == SessionState::FOCUSED, attached)
+            };
+            let info = &*sync_info;
+            let active = if info.count_active_action_sets == 0 {
+                &[]
+            } else {
+                slice::from_raw_parts(
+                    info.active_action_sets,
+                    info.count_active_action_sets as usize,
+                )
+            };
+            if active
+                .iter()
+                .any(|x| !attached.contains(&x.action_set.into_raw()))
+            {
+                return Err(sys::Result::ERROR_ACTIONSET_NOT_ATTACHED);
+            }
+            let mut inputs = Vec::new();
+            for (&handle, action) in &state.actions {
+                let is_active = focused
+                    && active
+                        .iter()
+                        .any(|x| x.action_set.into_raw() == action.action_set);
+                let paths = std::iter::once(None)
+                    .chain(action.subaction_paths.iter().map(|x| Some(x.clone())));
+                for path in paths {
+                    let value = if is_active {
+                        state.input(action, path.as_deref())
+                    } else {
+                        None
+                    };
+                    inputs.push(((handle, path), value));
+                }
+            }
+            let time = state.time;
+            let s = state.sessions.get_mut(&session.into_raw()).unwrap();
+            for (key, value) in inputs {
+                let snapshot = s.actions.entry(key).or_insert(ActionSnapshot {
+                    value: None,
+                    changed: false,
+                    last_change_time: 0,
+                });
+                snapshot.changed =
+                    snapshot.value.is_some() && value.is_some() && snapshot.value != value;
+                if snapshot.value != value {
+                    snapshot.last_change_time = time;
+                }
+                snapshot.value = value;
+            }
+            Ok(if focused {
+                sys::Result::SUCCESS
+            } else {
+                sys::Result::SESSION_NOT_FOCUSED
+            })
+        })
+    })
+}
+
+/// Check that `subaction_path` was declared when `action` was created
+fn check_subaction_path(action: &MockAction, subaction_path: &Option<String>) -> Res<()> {
+    match *subaction_path {
+        Some(ref x) if !action.subaction_paths.contains(x) => {
+            Err(sys::Result::ERROR_PATH_UNSUPPORTED)
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Find the synchronized state of an action of type `ty`
+unsafe fn action_state(
+    state: &mut State,
+    session: sys::Session,
+    get_info: *const sys::ActionStateGetInfo,
+    ty: &[ActionType],
+) -> Res<ActionSnapshot> {
+    let info = &*get_info;
+    let s = state.session(session.into_raw())?;
+    let instance = s.instance;
+    let attached = s.attached_action_sets.clone().unwrap_or_default();
+    let action = state.action(info.action.into_raw())?;
+    if !attached.contains(&action.action_set) {
+        return Err(sys::Result::ERROR_ACTIONSET_NOT_ATTACHED);
+    }
+    if !ty.contains(&action.ty) {
+        return Err(sys::Result::ERROR_ACTION_TYPE_MISMATCH);
+    }
+    let subaction_path = state.instances[&instance].subaction_string(info.subaction_path)?;
+    check_subaction_path(&state.actions[&info.action.into_raw()], &subaction_path)?;
+    Ok(state.sessions[&session.into_raw()]
+        .actions
+        .get(&(info.action.into_raw(), subaction_path))
+        .cloned()
+        .unwrap_or(ActionSnapshot {
+            value: None,
+            changed: false,
+            last_change_time: 0,
+        }))
+}
+
+unsafe extern "system" fn get_action_state_boolean(
+    session: sys::Session,
+    get_info: *const sys::ActionStateGetInfo,
+    out: *mut sys::ActionStateBoolean,
+) -> sys::Result {
+    guard(|| {
+        with(session.into_raw(), |state| {
+            let x = action_state(state, session, get_info, &[ActionType::BOOLEAN_INPUT])?;
+            let value = x.value.and_then(InputValue::as_bool);
+            let out = &mut *out;
+            out.current_state = value.unwrap_or(false).into();
+            out.changed_since_last_sync = (value.is_some() && x.changed).into();
+            out.last_change_time = Time::from_nanos(x.last_change_time);
+            out.is_active = value.is_some().into();
+            Ok(sys::Result::SUCCESS)
+        })
+    })
+}
+
+unsafe extern "system" fn get_action_state_float(
+    session: sys::Session,
+    get_info: *const sys::ActionStateGetInfo,
+    out: *mut sys::ActionStateFloat,
+) -> sys::Result {
+    guard(|| {
+        with(session.into_raw(), |state| {
+            let x = action_state(state, session, get_info, &[ActionType::FLOAT_INPUT])?;
+            let value = x.value.and_then(InputValue::as_float);
+            let out = &mut *out;
+            out.current_state = value.unwrap_or(0.0);
+            out.changed_since_last_sync = (value.is_some() && x.changed).into();
+            out.last_change_time = Time::from_nanos(x.last_change_time);
+            out.is_active = value.is_some().into();
+            Ok(sys::Result::SUCCESS)
+        })
+    })
+}
+
+unsafe extern "system" fn get_action_state_vector2f(
+    session: sys::Session,
+    get_info: *const sys::ActionStateGetInfo,
+    out: *mut sys::ActionStateVector2f,
+) -> sys::Result {
+    guard(|| {
+        with(session.into_raw(), |state| {
+            let x = action_state(state, session, get_info, &[ActionType::VECTOR2F_INPUT])?;
+            let value = x.value.and_then(InputValue::as_vector2f);
+            let out = &mut *out;
+            out.current_state = value.unwrap_or_default();
+            out.changed_since_last_sync = (value.is_some() && x.changed).into();
+            out.last_change_time = Time::from_nanos(x.last_change_time);
+            out.is_active = value.is_some().into();
+            Ok(sys::Result::SUCCESS)
+        })
+    })
+}
+
+unsafe extern "system" fn get_action_state_pose(
+    session: sys::Session,
+    get_info: *const sys::ActionStateGetInfo,
+    out: *mut sys::ActionStatePose,
+) -> sys::Result {
+    guard(|| {
+        with(session.into_raw(), |state| {
+            let x = action_state(state, session, get_info, &[ActionType::POSE_INPUT])?;
+            (*out).is_active = x.value.and_then(InputValue::as_pose).is_some().into();
+            Ok(sys::Result::SUCCESS)
+        })
+    })
+}
+
+unsafe extern "system" fn enumerate_bound_sources_for_action(
+    session: sys::Session,
+    _enumerate_info: *const sys::BoundSourcesForActionEnumerateInfo,
+    capacity: u32,
+    count: *mut u32,
+    sources: *mut sys::Path,
+) -> sys::Result {
+    guard(|| {
+        with(session.into_raw(), |state| {
+            if state
+                .session(session.into_raw())?
+                .attached_action_sets
+                .is_none()
+            {
+                return Err(sys::Result::ERROR_ACTIONSET_NOT_ATTACHED);
+            }
+            two_call(0, capacity, count, sources, |_, _| {})
+        })
+    })
+}
+
+unsafe extern "system" fn get_input_source_localized_name(
+    session: sys::Session,
+    get_info: *const sys::InputSourceLocalizedNameGetInfo,
+    capacity: u32,
+    count: *mut u32,
+    buffer: *mut c_char,
+) -> sys::Result {
+    guard(|| {
+        with(session.into_raw(), |state| {
+            let instance = state.session(session.into_raw())?.instance;
+            let name = state.instances[&instance].path_string((*get_info).source_path)?;
+            two_call_str(name, capacity, count, buffer)
+        })
+    })
+}
+
+unsafe extern "system" fn apply_haptic_feedback(
+    session: sys::Session,
+    haptic_action_info: *const sys::HapticActionInfo,
+    haptic_feedback: *const sys::HapticBaseHeader,
+) -> sys::Result {
+    guard(|| {
+        with(session.into_raw(), |state| {
+            let vibration = if (*haptic_feedback).ty == sys::HapticVibration::TYPE {
+                let x = &*(haptic_feedback as *const sys::HapticVibration);
+                HapticVibrationDescription {
+                    duration: x.duration,
+                    frequency: x.frequency,
+                    amplitude: x.amplitude,
+                }
+            } else {
+                return Err(sys::Result::ERROR_VALIDATION_FAILURE);
+            };
+            record_haptics(state, session, haptic_action_info, Some(vibration))
+        })
+    })
+}
+
+unsafe extern "system" fn stop_haptic_feedback(
+    session: sys::Session,
+    haptic_action_info: *const sys::HapticActionInfo,
+) -> sys::Result {
+    guard(|| {
+        with(session.into_raw(), |state| {
+            record_haptics(state, session, haptic_action_info, None)
+        })
+    })
+}
+
+unsafe fn record_haptics(
+    state: &mut State,
+    session: sys::Session,
+    haptic_action_info: *const sys::HapticActionInfo,
+    vibration: Option<HapticVibrationDescription>,
+) -> Res {
+    let info = &*haptic_action_info;
+    let s = state.session(session.into_raw())?;
+    let instance = s.instance;
+    let attached = s.attached_action_sets.clone().unwrap_or_default();
+    let action = state.action(info.action.into_raw())?;
+    if !attached.contains(&action.action_set) {
+        return Err(sys::Result::ERROR_ACTIONSET_NOT_ATTACHED);
+    }
+    if action.ty != ActionType::VIBRATION_OUTPUT {
+        return Err(sys::Result::ERROR_ACTION_TYPE_MISMATCH);
+    }
+    let subaction_path = state.instances[&instance].subaction_string(info.subaction_path)?;
+    let action = &state.actions[&info.action.into_raw()];
+    check_subaction_path(action, &subaction_path)?;
+    let focused = state.sessions[&session.into_raw()].state == SessionState::FOCUSED;
+    let record = HapticFeedback {
+        action_name: action.name.clone(),
+        subaction_path,
+        vibration,
+    };
+    if !focused {
+        return Ok(sys::Result::SESSION_NOT_FOCUSED);
+    }
+    state.haptics.push(record);
+    Ok(sys::Result::SUCCESS)
+}
@@ -0,0 +1,481 @@
+//! A scriptable in-process OpenXR runtime for headless testing
+//!
+//! [`MockRuntime`] implements the core OpenXR 1.1 command set entirely in Rust, so code built on
+//! [`Session`], [`FrameWaiter`], [`FrameStream`], [`Space`] and [`Action`] can be exercised
+//! without a headset or a system runtime. Tests drive the runtime through methods on
+//! `MockRuntime` (session state transitions, view and action poses, input values, frame timing)
+//! and inspect what the application submitted through [`MockRuntime::submitted_frames`].
+//!
+//! The only extension advertised is `XR_MND_headless`, which allows sessions to be created with
+//! [`Headless`] graphics. In particular, graphics requirements can't be queried, so other sessions
+//! must be created directly.
+//!
+//! Available if the `mock` feature is enabled.
+//!
+//! # Example
+//!
+//! ```
+//! use openxr as xr;
+//!
+//! let runtime = xr::mock::MockRuntime::new();
+//! let app_info = xr::ApplicationInfo {
+//!     application_name: "test",
+//!     ..Default::default()
+//! };
+//! let instance = runtime
+//!     .entry()
+//!     .create_instance(&app_info, &xr::ExtensionSet::default(), &[])
+//!     .unwrap();
+//! let system = instance
+//!     .system(xr::FormFactor::HEAD_MOUNTED_DISPLAY)
+//!     .unwrap();
+//! // Vulkan, OpenGL and OpenGL ES bindings are accepted; their handles are never used.
+//! let (session, mut frame_waiter, mut frame_stream) = unsafe {
+//!     instance.create_session::<xr::Vulkan>(
+//!         system,
+//!         &xr::vulkan::SessionCreateInfo {
+//!             instance: std::ptr::null(),
+//!             physical_device: std::ptr::null(),
+//!             device: std::ptr::null(),
+//!             queue_family_index: 0,
+//!             queue_index: 0,
+//!         },
+//!     )
+//! }
+//! .unwrap();
+//! session.begin(xr::ViewConfigurationType::PRIMARY_STEREO).unwrap();
+//! let frame = frame_waiter.wait().unwrap();
+//! let frame = frame_stream.begin(frame).unwrap();
+//! frame_stream
+//!     .end(frame, xr::EnvironmentBlendMode::OPAQUE, &[])
+//!     .unwrap();
+//! assert_eq!(runtime.submitted_frames().len(), 1);
+//! ```
+
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+use crate::*;
+
+mod ffi;
+mod state;
+
+use state::{InputValue, PendingEvent, State};
+
+/// An in-process OpenXR runtime whose behavior is scripted by the caller
+///
+/// Cloning a `MockRuntime` yields another handle to the same runtime. Any number of runtimes may
+/// be alive at once (e.g. from concurrently running tests), up to an implementation limit of
+/// 32.
+#[derive(Clone)]
+pub struct MockRuntime {
+    shared: Arc<Shared>,
+}
+
+impl MockRuntime {
+    /// Construct a runtime exposing a single head-mounted stereo system
+    pub fn new() -> Self {
+        ffi::register(|slot| Shared {
+            slot,
+            state: Mutex::new(State::new()),
+        })
+    }
+
+    /// An `Entry` that creates instances on this runtime
+    pub fn entry(&self) -> Entry {
+        unsafe {
+            Entry::from_get_instance_proc_addr(self.get_instance_proc_addr())
+                .expect("mock runtime exposes all global commands")
+        }
+    }
+
+    /// The `xrGetInstanceProcAddr` implementation of this runtime
+    ///
+    /// Suitable for [`Entry::from_get_instance_proc_addr`], or for use as the next link in a chain
+    /// of API layers.
+    pub fn get_instance_proc_addr(&self) -> sys::pfn::GetInstanceProcAddr {
+        ffi::get_instance_proc_addr(self.shared.slot)
+    }
+
+    /// Set the name reported by `xrGetInstanceProperties`
+    pub fn set_runtime_name(&self, name: &str) {
+        self.lock().runtime_name = name.into();
+    }
+
+    /// Set the newest API version instances may be created with, 1.1 by default
+    ///
+    /// `xrCreateInstance` fails with `ERROR_API_VERSION_UNSUPPORTED` for newer minor versions.
+    pub fn set_api_version(&self, version: Version) {
+        self.lock().api_version = version;
+    }
+
+    /// Whether the runtime drives sessions through the usual state transitions by itself
+    ///
+    /// When enabled (the default), a new session moves to `READY`, `xrBeginSession` moves it on to
+    /// `FOCUSED`, `xrRequestExitSession` winds it down to `STOPPING`, and `xrEndSession` after an
+    /// exit request moves it to `EXITING`. When disabled, only [`set_session_state`] changes
+    /// session states.
+    ///
+    /// [`set_session_state`]: Self::set_session_state
+    pub fn set_auto_transitions(&self, enabled: bool) {
+        self.lock().auto_transitions = enabled;
+    }
+
+    /// Move every live session to `state`, queueing a `SessionStateChanged` event for each
+    pub fn set_session_state(&self, state: SessionState) {
+        let mut guard = self.lock();
+        let sessions = guard.sessions.keys().copied().collect::<Vec<_>>();
+        for session in sessions {
+            guard.transition(session, state);
+        }
+    }
+
+    /// The state of the most recently created live session, if any
+    pub fn session_state(&self) -> Option<SessionState> {
+        let guard = self.lock();
+        guard
+            .sessions
+            .iter()
+            .max_by_key(|(&handle, _)| handle)
+            .map(|(_, session)| session.state)
+    }
+
+    /// Queue an `InstanceLossPending` event on every live instance
+    pub fn lose_instance(&self, loss_time: Time) {
+        self.lock()
+            .broadcast(PendingEvent::InstanceLossPending { loss_time });
+    }
+
+    /// Limit the number of undelivered events per instance
+    ///
+    /// When the queue is full the oldest event is dropped, and an `EventsLost` event reporting the
+    /// number of dropped events is delivered before the remaining ones. Defaults to 64.
+    pub fn set_event_queue_capacity(&self, capacity: usize) {
+        self.lock().event_queue_capacity = capacity.max(1);
+    }
+
+    /// The runtime's current time
+    pub fn time(&self) -> Time {
+        Time::from_nanos(self.lock().time)
+    }
+
+    /// Advance the runtime's clock
+    ///
+    /// `xrWaitFrame` also advances the clock, by one display period per frame.
+    pub fn advance_time(&self, duration: Duration) {
+        self.lock().time += duration.as_nanos();
+    }
+
+    /// Set the interval between predicted display times reported by `xrWaitFrame`
+    ///
+    /// Defaults to 1/90th of a second.
+    pub fn set_display_period(&self, period: Duration) {
+        self.lock().display_period = period.as_nanos().max(1);
+    }
+
+    /// Replace the views reported for a view configuration, adding it if it's not yet supported
+    pub fn set_view_configuration_views(
+        &self,
+        ty: ViewConfigurationType,
+        views: &[ViewConfigurationView],
+    ) {
+        let mut guard = self.lock();
+        match guard.view_configurations.iter_mut().find(|x| x.0 == ty) {
+            Some(x) => x.1 = views.to_vec(),
+            None => guard.view_configurations.push((ty, views.to_vec())),
+        }
+    }
+
+    /// Replace the swapchain formats reported by `xrEnumerateSwapchainFormats`
+    ///
+    /// By default, a handful of common 8-bit color and depth formats of the session's graphics API
+    /// are reported.
+    pub fn set_swapchain_formats(&self, formats: &[i64]) {
+        self.lock().swapchain_formats = Some(formats.to_vec());
+    }
+
+    /// Set the number of images in subsequently created swapchains, 3 by default
+    pub fn set_swapchain_image_count(&self, count: u32) {
+        self.lock().swapchain_image_count = count.max(1);
+    }
+
+    /// Set the pose of a reference space's origin relative to the `LOCAL` reference space
+    ///
+    /// The `STAGE` origin defaults to 1.6m below the `LOCAL` origin.
+    /// The origin of `VIEW` is controlled by [`set_head_pose`](Self::set_head_pose) instead.
+    pub fn set_reference_space_origin(&self, ty: ReferenceSpaceType, pose: Posef) {
+        let mut guard = self.lock();
+        match guard.reference_origins.iter_mut().find(|x| x.0 == ty) {
+            Some(x) => x.1 = pose,
+            None => guard.reference_origins.push((ty, pose)),
+        }
+    }
+
+    /// Set the pose of the user's head relative to the `LOCAL` reference space
+    ///
+    /// Also moves the views, which are reported relative to the head.
+    pub fn set_head_pose(&self, pose: Posef) {
+        self.lock().head_pose = pose;
+    }
+
+    /// Set the views reported by `xrLocateViews`, with poses relative to the head
+    ///
+    /// Views beyond the end of `views` repeat the last element. Defaults to a pair of eyes 64mm
+    /// apart with 90 degree fields of view.
+    pub fn set_views(&self, views: &[View]) {
+        self.lock().views = views.to_vec();
+    }
+
+    /// Set the flags reported by `xrLocateViews`, all valid and tracked by default
+    pub fn set_view_state_flags(&self, flags: ViewStateFlags) {
+        self.lock().view_state_flags = flags;
+    }
+
+    /// Set the physical state of a boolean input bound to the action named `action_name`
+    ///
+    /// `subaction_path` restricts the value to one top level user path, e.g.
+    /// [`USER_HAND_LEFT`]. Values take effect on the next `xrSyncActions`.
+    pub fn set_boolean_action(&self, action_name: &str, subaction_path: Option<&str>, value: bool) {
+        self.set_input(
+            action_name,
+            subaction_path,
+            Some(InputValue::Boolean(value)),
+        );
+    }
+
+    /// Set the physical state of a float input bound to the action named `action_name`
+    pub fn set_float_action(&self, action_name: &str, subaction_path: Option<&str>, value: f32) {
+        self.set_input(action_name, subaction_path, Some(InputValue::Float(value)));
+    }
+
+    /// Set the physical state of a 2D input bound to the action named `action_name`
+    pub fn set_vector2f_action(
+        &self,
+        action_name: &str,
+        subaction_path: Option<&str>,
+        value: Vector2f,
+    ) {
+        self.set_input(
+            action_name,
+            subaction_path,
+            Some(InputValue::Vector2f(value)),
+        );
+    }
+
+    /// Set the pose, relative to the `LOCAL` reference space, of the device bound to the pose
+    /// action named `action_name`
+    pub fn set_pose_action(&self, action_name: &str, subaction_path: Option<&str>, pose: Posef) {
+        self.set_input(action_name, subaction_path, Some(InputValue::Pose(pose)));
+    }
+
+    /// Mark the input bound to the action named `action_name` as inactive
+    pub fn clear_action(&self, action_name: &str, subaction_path: Option<&str>) {
+        self.set_input(action_name, subaction_path, None);
+    }
+
+    /// Set the interaction profile in use for a top level user path
+    ///
+    /// Queues an `InteractionProfileChanged` event on every live session. `None` indicates that no
+    /// profile is in use.
+    pub fn set_interaction_profile(&self, top_level_user_path: &str, profile: Option<&str>) {
+        let mut guard = self.lock();
+        match profile {
+            Some(x) => guard
+                .interaction_profiles
+                .insert(top_level_user_path.into(), x.into()),
+            None => guard.interaction_profiles.remove(top_level_user_path),
+        };
+        let sessions = guard
+            .sessions
+            .iter()
+            .map(|(&handle, session)| (handle, session.instance))
+            .collect::<Vec<_>>();
+        for (session, instance) in sessions {
+            guard.push_event(
+                instance,
+                PendingEvent::InteractionProfileChanged {
+                    session: sys::Session::from_raw(session),
+                },
+            );
+        }
+    }
+
+    /// Frames submitted through `xrEndFrame` so far, oldest first
+    pub fn submitted_frames(&self) -> Vec<SubmittedFrame> {
+        self.lock().frames.clone()
+    }
+
+    /// Remove and return the frames submitted so far
+    pub fn take_submitted_frames(&self) -> Vec<SubmittedFrame> {
+        std::mem::take(&mut self.lock().frames)
+    }
+
+    /// Haptic feedback applied or stopped so far, oldest first
+    pub fn haptic_feedback(&self) -> Vec<HapticFeedback> {
+        self.lock().haptics.clone()
+    }
+
+    /// Make the next call of `command` fail with `result` before it has any effect
+    ///
+    /// Supported for `xrWaitFrame`, `xrBeginFrame`, `xrEndFrame`, `xrAcquireSwapchainImage`,
+    /// `xrWaitSwapchainImage` and `xrReleaseSwapchainImage`.
+    pub fn fail_next_call(&self, command: &str, result: sys::Result) {
+        self.lock().failures.insert(command.into(), result);
+    }
+
+    /// Number of calls of `command` so far, including failed ones
+    ///
+    /// Supported for the same commands as [`fail_next_call`](Self::fail_next_call).
+    pub fn call_count(&self, command: &str) -> u64 {
+        self.lock().calls.get(command).copied().unwrap_or(0)
+    }
+
+    /// Details of the live swapchains
+    pub fn swapchains(&self) -> Vec<SwapchainDescription> {
+        let guard = self.lock();
+        let mut out = guard
+            .swapchains
+            .iter()
+            .map(|(&handle, x)| SwapchainDescription {
+                swapchain: sys::Swapchain::from_raw(handle),
+                session: sys::Session::from_raw(x.session),
+                format: x.info.format,
+                width: x.info.width,
+                height: x.info.height,
+                sample_count: x.info.sample_count,
+                face_count: x.info.face_count,
+                array_size: x.info.array_size,
+                mip_count: x.info.mip_count,
+                image_count: x.image_count,
+                acquired_images: x.acquired.len() as u32,
+            })
+            .collect::<Vec<_>>();
+        out.sort_by_key(|x| x.swapchain.into_raw());
+        out
+    }
+
+    fn set_input(
+        &self,
+        action_name: &str,
+        subaction_path: Option<&str>,
+        value: Option<InputValue>,
+    ) {
+        let key = (action_name.to_owned(), subaction_path.map(|x| x.to_owned()));
+        let mut guard = self.lock();
+        match value {
+            Some(x) => guard.inputs.insert(key, x),
+            None => guard.inputs.remove(&key),
+        };
+    }
+
+    fn lock(&self) -> MutexGuard<'_, State> {
+        self.shared.lock()
+    }
+}
+
+impl Default for MockRuntime {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub(crate) struct Shared {
+    slot: usize,
+    state: Mutex<State>,
+}
+
+impl Shared {
+    fn lock(&self) -> MutexGuard<'_, State> {
+        // A panic while the lock is held can only originate in the test driving the runtime, which
+        // has already failed; keep serving calls so that destructors can run.
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// A frame submitted to a [`MockRuntime`] through `xrEndFrame`
+#[derive(Debug, Clone)]
+pub struct SubmittedFrame {
+    pub session: sys::Session,
+    pub display_time: Time,
+    pub environment_blend_mode: EnvironmentBlendMode,
+    pub layers: Vec<SubmittedLayer>,
+}
+
+/// A composition layer of a [`SubmittedFrame`]
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum SubmittedLayer {
+    Projection {
+        layer_flags: CompositionLayerFlags,
+        space: sys::Space,
+        views: Vec<SubmittedProjectionView>,
+    },
+    Quad {
+        layer_flags: CompositionLayerFlags,
+        space: sys::Space,
+        eye_visibility: EyeVisibility,
+        sub_image: SubmittedSubImage,
+        pose: Posef,
+        size: Extent2Df,
+    },
+    /// A layer type the mock runtime doesn't decode
+    Other(StructureType),
+}
+
+/// A view of a [`SubmittedLayer::Projection`]
+#[derive(Debug, Copy, Clone)]
+pub struct SubmittedProjectionView {
+    pub pose: Posef,
+    pub fov: Fovf,
+    pub sub_image: SubmittedSubImage,
+}
+
+/// The part of a swapchain referenced by a submitted layer
+#[derive(Debug, Copy, Clone)]
+pub struct SubmittedSubImage {
+    pub swapchain: sys::Swapchain,
+    pub image_rect: Rect2Di,
+    pub image_array_index: u32,
+    /// Index of the most recently released image of `swapchain` at submission time
+    pub image_index: u32,
+}
+
+/// A call to `xrApplyHapticFeedback` or `xrStopHapticFeedback` observed by a [`MockRuntime`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct HapticFeedback {
+    pub action_name: String,
+    pub subaction_path: Option<String>,
+    /// `None` if feedback was stopped
+    pub vibration: Option<HapticVibrationDescription>,
+}
+
+/// Parameters of an applied [`HapticVibration`]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct HapticVibrationDescription {
+    pub duration: Duration,
+    pub frequency: f32,
+    pub amplitude: f32,
+}
+
+/// A swapchain created on a [`MockRuntime`]
+#[derive(Debug, Copy, Clone)]
+pub struct SwapchainDescription {
+    pub swapchain: sys::Swapchain,
+    pub session: sys::Session,
+    pub format: i64,
+    pub width: u32,
+    pub height: u32,
+    pub sample_count: u32,
+    pub face_count: u32,
+    pub array_size: u32,
+    pub mip_count: u32,
+    pub image_count: u32,
+    /// Images acquired and not yet released
+    pub acquired_images: u32,
+}
+
+type InputKey = (String, Option<String>);
+type InputMap = HashMap<InputKey, InputValue>;
+type EventQueue = VecDeque<PendingEvent>;
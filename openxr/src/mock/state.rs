@@ -0,0 +1,647 @@
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use super::{EventQueue, HapticFeedback, InputMap, SubmittedFrame};
+use crate::*;
+
+/// Source of handle values, shared by all runtimes so that a handle identifies its runtime
+static NEXT_HANDLE: AtomicU64 = AtomicU64::new(1);
+
+pub(super) const SYSTEM_ID: u64 = 1;
+pub(super) const MAX_LAYER_COUNT: u32 = 16;
+pub(super) const MAX_SWAPCHAIN_EXTENT: u32 = 4096;
+/// Extensions advertised by the runtime, with their versions
+pub(super) const EXTENSIONS: &[(&str, u32)] = &[("XR_MND_headless", 2)];
+
+pub(super) type Res<T = sys::Result> = std::result::Result<T, sys::Result>;
+
+/// Physical state of an input source, as scripted by the test
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub(super) enum InputValue {
+    Boolean(bool),
+    Float(f32),
+    Vector2f(Vector2f),
+    Pose(Posef),
+}
+
+impl InputValue {
+    pub fn as_bool(self) -> Option<bool> {
+        match self {
+            InputValue::Boolean(x) => Some(x),
+            InputValue::Float(x) => Some(x > 0.5),
+            _ => None,
+        }
+    }
+
+    pub fn as_float(self) -> Option<f32> {
+        match self {
+            InputValue::Boolean(x) => Some(if x { 1.0 } else { 0.0 }),
+            InputValue::Float(x) => Some(x),
+            _ => None,
+        }
+    }
+
+    pub fn as_vector2f(self) -> Option<Vector2f> {
+        match self {
+            InputValue::Vector2f(x) => Some(x),
+            _ => None,
+        }
+    }
+
+    pub fn as_pose(self) -> Option<Posef> {
+        match self {
+            InputValue::Pose(x) => Some(x),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub(super) enum PendingEvent {
+    SessionStateChanged {
+        session: sys::Session,
+        state: SessionState,
+        time: Time,
+    },
+    InstanceLossPending {
+        loss_time: Time,
+    },
+    InteractionProfileChanged {
+        session: sys::Session,
+    },
+}
+
+/// Graphics API a session was created for, determining swapchain formats and image structures
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(super) enum GraphicsApi {
+    Vulkan,
+    OpenGl,
+    OpenGlEs,
+    /// No graphics binding, as allowed by `XR_MND_headless`
+    Headless,
+}
+
+impl GraphicsApi {
+    pub fn from_binding(ty: StructureType) -> Option<Self> {
+        Some(match ty {
+            StructureType::GRAPHICS_BINDING_VULKAN_KHR => GraphicsApi::Vulkan,
+            StructureType::GRAPHICS_BINDING_OPENGL_XLIB_KHR
+            | StructureType::GRAPHICS_BINDING_OPENGL_XCB_KHR
+            | StructureType::GRAPHICS_BINDING_OPENGL_WAYLAND_KHR
+            | StructureType::GRAPHICS_BINDING_OPENGL_WIN32_KHR
+            | StructureType::GRAPHICS_BINDING_EGL_MNDX => GraphicsApi::OpenGl,
+            StructureType::GRAPHICS_BINDING_OPENGL_ES_ANDROID_KHR => GraphicsApi::OpenGlEs,
+            _ => return None,
+        })
+    }
+
+    pub fn default_formats(self) -> Vec<i64> {
+        match self {
+            // VK_FORMAT_R8G8B8A8_SRGB, B8G8R8A8_SRGB, R8G8B8A8_UNORM, B8G8R8A8_UNORM,
+            // D32_SFLOAT, D24_UNORM_S8_UINT
+            GraphicsApi::Vulkan => vec![43, 50, 37, 44, 126, 129],
+            // GL_SRGB8_ALPHA8, GL_RGBA8, GL_DEPTH_COMPONENT32F, GL_DEPTH24_STENCIL8
+            GraphicsApi::OpenGl | GraphicsApi::OpenGlEs => {
+                vec![0x8C43, 0x8058, 0x8CAC, 0x88F0]
+            }
+            GraphicsApi::Headless => Vec::new(),
+        }
+    }
+
+    pub fn swapchain_image_type(self) -> StructureType {
+        match self {
+            GraphicsApi::Vulkan => StructureType::SWAPCHAIN_IMAGE_VULKAN_KHR,
+            GraphicsApi::OpenGl => StructureType::SWAPCHAIN_IMAGE_OPENGL_KHR,
+            GraphicsApi::OpenGlEs => StructureType::SWAPCHAIN_IMAGE_OPENGL_ES_KHR,
+            GraphicsApi::Headless => StructureType::UNKNOWN,
+        }
+    }
+}
+
+pub(super) struct MockInstance {
+    pub paths: Vec<String>,
+    pub events: EventQueue,
+    pub lost_events: u32,
+    pub loss_time: Option<i64>,
+    /// Whether `XR_MND_headless` is enabled
+    pub headless: bool,
+}
+
+impl MockInstance {
+    pub fn path_string(&self, path: sys::Path) -> Res<&str> {
+        let index = path
+            .into_raw()
+            .checked_sub(1)
+            .ok_or(sys::Result::ERROR_PATH_INVALID)?;
+        self.paths
+            .get(index as usize)
+            .map(|x| &x[..])
+            .ok_or(sys::Result::ERROR_PATH_INVALID)
+    }
+
+    /// Like `path_string`, but mapping `NULL` to `None`
+    pub fn subaction_string(&self, path: sys::Path) -> Res<Option<String>> {
+        if path == sys::Path::NULL {
+            return Ok(None);
+        }
+        self.path_string(path).map(|x| Some(x.to_owned()))
+    }
+
+    pub fn intern(&mut self, path: &str) -> sys::Path {
+        let index = match self.paths.iter().position(|x| x == path) {
+            Some(x) => x,
+            None => {
+                self.paths.push(path.into());
+                self.paths.len() - 1
+            }
+        };
+        sys::Path::from_raw(index as u64 + 1)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(super) struct ActionSnapshot {
+    pub value: Option<InputValue>,
+    pub changed: bool,
+    pub last_change_time: i64,
+}
+
+pub(super) struct MockSession {
+    pub instance: u64,
+    pub state: SessionState,
+    pub graphics: GraphicsApi,
+    pub running: bool,
+    pub exit_requested: bool,
+    pub view_configuration: Option<ViewConfigurationType>,
+    /// A frame has been waited for, but not yet begun
+    pub frame_waited: bool,
+    /// A frame has been begun, but not yet ended
+    pub frame_begun: bool,
+    pub attached_action_sets: Option<Vec<u64>>,
+    pub actions: HashMap<(u64, Option<String>), ActionSnapshot>,
+}
+
+pub(super) enum SpaceKind {
+    Reference(ReferenceSpaceType),
+    Action {
+        action: u64,
+        subaction_path: Option<String>,
+    },
+}
+
+pub(super) struct MockSpace {
+    pub session: u64,
+    pub kind: SpaceKind,
+    pub pose: Posef,
+}
+
+pub(super) struct MockSwapchain {
+    pub session: u64,
+    pub info: SwapchainDesc,
+    pub image_count: u32,
+    pub next_image: u32,
+    /// Acquired image indices, oldest first, and whether they've been waited on
+    pub acquired: VecDeque<(u32, bool)>,
+    pub last_released: Option<u32>,
+}
+
+#[derive(Debug, Copy, Clone)]
+pub(super) struct SwapchainDesc {
+    pub format: i64,
+    pub width: u32,
+    pub height: u32,
+    pub sample_count: u32,
+    pub face_count: u32,
+    pub array_size: u32,
+    pub mip_count: u32,
+}
+
+pub(super) struct MockActionSet {
+    pub instance: u64,
+    pub name: String,
+    pub localized_name: String,
+    pub attached: bool,
+}
+
+pub(super) struct MockAction {
+    pub action_set: u64,
+    pub name: String,
+    pub localized_name: String,
+    pub ty: ActionType,
+    pub subaction_paths: Vec<String>,
+}
+
+pub(super) struct State {
+    pub runtime_name: String,
+    pub api_version: Version,
+    pub auto_transitions: bool,
+    pub event_queue_capacity: usize,
+    pub time: i64,
+    pub display_period: i64,
+    pub view_configurations: Vec<(ViewConfigurationType, Vec<ViewConfigurationView>)>,
+    pub swapchain_formats: Option<Vec<i64>>,
+    pub swapchain_image_count: u32,
+    pub reference_origins: Vec<(ReferenceSpaceType, Posef)>,
+    pub head_pose: Posef,
+    pub views: Vec<View>,
+    pub view_state_flags: ViewStateFlags,
+    pub inputs: InputMap,
+    pub interaction_profiles: HashMap<String, String>,
+    pub frames: Vec<SubmittedFrame>,
+    pub haptics: Vec<HapticFeedback>,
+    /// Results with which the next call of each command fails
+    pub failures: HashMap<String, sys::Result>,
+    /// Number of calls of each command that supports scripted failures
+    pub calls: HashMap<String, u64>,
+
+    pub instances: HashMap<u64, MockInstance>,
+    pub sessions: HashMap<u64, MockSession>,
+    pub spaces: HashMap<u64, MockSpace>,
+    pub swapchains: HashMap<u64, MockSwapchain>,
+    pub action_sets: HashMap<u64, MockActionSet>,
+    pub actions: HashMap<u64, MockAction>,
+
+    /// Handles created since the last call into the runtime returned
+    pub created: Vec<u64>,
+    /// Handles destroyed since the last call into the runtime returned
+    pub destroyed: Vec<u64>,
+}
+
+impl State {
+    pub fn new() -> Self {
+        let eye = |x: f32| View {
+            pose: Posef {
+                orientation: Quaternionf::IDENTITY,
+                position: Vector3f { x, y: 0.0, z: 0.0 },
+            },
+            fov: Fovf {
+                angle_left: -std::f32::consts::FRAC_PI_4,
+                angle_right: std::f32::consts::FRAC_PI_4,
+                angle_up: std::f32::consts::FRAC_PI_4,
+                angle_down: -std::f32::consts::FRAC_PI_4,
+            },
+        };
+        let view = ViewConfigurationView {
+            recommended_image_rect_width: 1024,
+            max_image_rect_width: MAX_SWAPCHAIN_EXTENT,
+            recommended_image_rect_height: 1024,
+            max_image_rect_height: MAX_SWAPCHAIN_EXTENT,
+            recommended_swapchain_sample_count: 1,
+            max_swapchain_sample_count: 4,
+        };
+        let stage = Posef {
+            orientation: Quaternionf::IDENTITY,
+            position: Vector3f {
+                x: 0.0,
+                y: -1.6,
+                z: 0.0,
+            },
+        };
+        Self {
+            runtime_name: "openxrs mock runtime".into(),
+            api_version: sys::CURRENT_API_VERSION,
+            auto_transitions: true,
+            event_queue_capacity: 64,
+            time: 1_000_000_000,
+            display_period: 1_000_000_000 / 90,
+            view_configurations: vec![
+                (ViewConfigurationType::PRIMARY_STEREO, vec![view; 2]),
+                (ViewConfigurationType::PRIMARY_MONO, vec![view]),
+            ],
+            swapchain_formats: None,
+            swapchain_image_count: 3,
+            reference_origins: vec![(ReferenceSpaceType::STAGE, stage)],
+            head_pose: Posef::IDENTITY,
+            views: vec![eye(-0.032), eye(0.032)],
+            view_state_flags: ViewStateFlags::ORIENTATION_VALID
+                | ViewStateFlags::POSITION_VALID
+                | ViewStateFlags::ORIENTATION_TRACKED
+                | ViewStateFlags::POSITION_TRACKED,
+            inputs: HashMap::new(),
+            interaction_profiles: HashMap::new(),
+            frames: Vec::new(),
+            haptics: Vec::new(),
+            failures: HashMap::new(),
+            calls: HashMap::new(),
+            instances: HashMap::new(),
+            sessions: HashMap::new(),
+            spaces: HashMap::new(),
+            swapchains: HashMap::new(),
+            action_sets: HashMap::new(),
+            actions: HashMap::new(),
+            created: Vec::new(),
+            destroyed: Vec::new(),
+        }
+    }
+
+    pub fn alloc(&mut self) -> u64 {
+        let handle = NEXT_HANDLE.fetch_add(1, Ordering::Relaxed);
+        self.created.push(handle);
+        handle
+    }
+
+    /// Look up a live instance, failing if it has been lost
+    /// Count a call of `command`, failing it if a failure was scripted
+    pub fn enter(&mut self, command: &str) -> Res<()> {
+        *self.calls.entry(command.into()).or_insert(0) += 1;
+        match self.failures.remove(command) {
+            Some(result) => Err(result),
+            None => Ok(()),
+        }
+    }
+
+    pub fn instance(&mut self, handle: u64) -> Res<&mut MockInstance> {
+        let time = self.time;
+        let instance = self
+            .instances
+            .get_mut(&handle)
+            .ok_or(sys::Result::ERROR_HANDLE_INVALID)?;
+        if instance.loss_time.is_some_and(|x| time >= x) {
+            return Err(sys::Result::ERROR_INSTANCE_LOST);
+        }
+        Ok(instance)
+    }
+
+    pub fn session(&mut self, handle: u64) -> Res<&mut MockSession> {
+        let instance = self
+            .sessions
+            .get(&handle)
+            .ok_or(sys::Result::ERROR_HANDLE_INVALID)?
+            .instance;
+        self.instance(instance)?;
+        Ok(self.sessions.get_mut(&handle).unwrap())
+    }
+
+    pub fn swapchain(&mut self, handle: u64) -> Res<&mut MockSwapchain> {
+        let session = self
+            .swapchains
+            .get(&handle)
+            .ok_or(sys::Result::ERROR_HANDLE_INVALID)?
+            .session;
+        self.session(session)?;
+        Ok(self.swapchains.get_mut(&handle).unwrap())
+    }
+
+    pub fn action_set(&mut self, handle: u64) -> Res<&mut MockActionSet> {
+        let instance = self
+            .action_sets
+            .get(&handle)
+            .ok_or(sys::Result::ERROR_HANDLE_INVALID)?
+            .instance;
+        self.instance(instance)?;
+        Ok(self.action_sets.get_mut(&handle).unwrap())
+    }
+
+    pub fn action(&mut self, handle: u64) -> Res<&mut MockAction> {
+        let set = self
+            .actions
+            .get(&handle)
+            .ok_or(sys::Result::ERROR_HANDLE_INVALID)?
+            .action_set;
+        self.action_set(set)?;
+        Ok(self.actions.get_mut(&handle).unwrap())
+    }
+
+    /// The instance an action belongs to
+    pub fn action_instance(&self, action: u64) -> u64 {
+        self.action_sets[&self.actions[&action].action_set].instance
+    }
+
+    pub fn view_configuration(&self, ty: ViewConfigurationType) -> Res<&[ViewConfigurationView]> {
+        self.view_configurations
+            .iter()
+            .find(|x| x.0 == ty)
+            .map(|x| &x.1[..])
+            .ok_or(sys::Result::ERROR_VIEW_CONFIGURATION_TYPE_UNSUPPORTED)
+    }
+
+    pub fn push_event(&mut self, instance: u64, event: PendingEvent) {
+        let capacity = self.event_queue_capacity;
+        let instance = match self.instances.get_mut(&instance) {
+            Some(x) => x,
+            None => return,
+        };
+        while instance.events.len() >= capacity {
+            instance.events.pop_front();
+            instance.lost_events += 1;
+        }
+        instance.events.push_back(event);
+    }
+
+    pub fn broadcast(&mut self, event: PendingEvent) {
+        if let PendingEvent::InstanceLossPending { loss_time } = event {
+            for instance in self.instances.values_mut() {
+                instance.loss_time = Some(loss_time.as_nanos());
+            }
+        }
+        let instances = self.instances.keys().copied().collect::<Vec<_>>();
+        for instance in instances {
+            self.push_event(instance, event);
+        }
+    }
+
+    /// Move `session` to `state`, queueing an event if that's a change
+    pub fn transition(&mut self, session: u64, state: SessionState) {
+        let time = Time::from_nanos(self.time);
+        let instance = match self.sessions.get_mut(&session) {
+            Some(x) if x.state != state => {
+                x.state = state;
+                x.instance
+            }
+            _ => return,
+        };
+        self.push_event(
+            instance,
+            PendingEvent::SessionStateChanged {
+                session: sys::Session::from_raw(session),
+                state,
+                time,
+            },
+        );
+    }
+
+    /// Apply a sequence of transitions if automatic transitions are enabled
+    pub fn auto_transition(&mut self, session: u64, states: &[SessionState]) {
+        if !self.auto_transitions {
+            return;
+        }
+        for &state in states {
+            self.transition(session, state);
+        }
+    }
+
+    /// Remove an object and everything that depends on it
+    pub fn destroy_instance(&mut self, handle: u64) {
+        if self.instances.remove(&handle).is_none() {
+            return;
+        }
+        self.destroyed.push(handle);
+        let sessions = self
+            .sessions
+            .iter()
+            .filter(|(_, x)| x.instance == handle)
+            .map(|(&h, _)| h)
+            .collect::<Vec<_>>();
+        for session in sessions {
+            self.destroy_session(session);
+        }
+        let sets = self
+            .action_sets
+            .iter()
+            .filter(|(_, x)| x.instance == handle)
+            .map(|(&h, _)| h)
+            .collect::<Vec<_>>();
+        for set in sets {
+            self.destroy_action_set(set);
+        }
+    }
+
+    pub fn destroy_session(&mut self, handle: u64) {
+        if self.sessions.remove(&handle).is_none() {
+            return;
+        }
+        self.destroyed.push(handle);
+        let destroyed = &mut self.destroyed;
+        self.spaces.retain(|&h, x| {
+            let keep = x.session != handle;
+            if !keep {
+                destroyed.push(h);
+            }
+            keep
+        });
+        self.swapchains.retain(|&h, x| {
+            let keep = x.session != handle;
+            if !keep {
+                destroyed.push(h);
+            }
+            keep
+        });
+    }
+
+    pub fn destroy_action_set(&mut self, handle: u64) {
+        if self.action_sets.remove(&handle).is_none() {
+            return;
+        }
+        self.destroyed.push(handle);
+        let destroyed = &mut self.destroyed;
+        self.actions.retain(|&h, x| {
+            let keep = x.action_set != handle;
+            if !keep {
+                destroyed.push(h);
+            }
+            keep
+        });
+    }
+
+    /// The input value bound to an action for a subaction path, or for any of its subaction paths
+    /// if `subaction_path` is `None`
+    pub fn input(&self, action: &MockAction, subaction_path: Option<&str>) -> Option<InputValue> {
+        let lookup = |path: Option<&str>| {
+            self.inputs
+                .get(&(action.name.clone(), path.map(|x| x.to_owned())))
+                .copied()
+        };
+        match subaction_path {
+            Some(_) => lookup(subaction_path),
+            None => lookup(None).or_else(|| {
+                action
+                    .subaction_paths
+                    .iter()
+                    .find_map(|path| lookup(Some(path)))
+            }),
+        }
+    }
+
+    /// Pose of a space relative to the `LOCAL` reference space, if it's currently tracked
+    pub fn space_pose(&self, handle: u64) -> Option<Posef> {
+        let space = &self.spaces[&handle];
+        let origin = match space.kind {
+            SpaceKind::Reference(ReferenceSpaceType::VIEW) => self.head_pose,
+            SpaceKind::Reference(ty) => self
+                .reference_origins
+                .iter()
+                .find(|x| x.0 == ty)
+                .map_or(Posef::IDENTITY, |x| x.1),
+            SpaceKind::Action {
+                action,
+                ref subaction_path,
+            } => {
+                let session = self.sessions.get(&space.session)?;
+                session
+                    .actions
+                    .get(&(action, subaction_path.clone()))?
+                    .value?
+                    .as_pose()?
+            }
+        };
+        Some(compose(origin, space.pose))
+    }
+}
+
+pub(super) fn compose(a: Posef, b: Posef) -> Posef {
+    Posef {
+        orientation: mul(a.orientation, b.orientation),
+        position: add(a.position, rotate(a.orientation, b.position)),
+    }
+}
+
+pub(super) fn inverse(x: Posef) -> Posef {
+    let orientation = conjugate(x.orientation);
+    let p = rotate(orientation, x.position);
+    Posef {
+        orientation,
+        position: Vector3f {
+            x: -p.x,
+            y: -p.y,
+            z: -p.z,
+        },
+    }
+}
+
+/// Whether `x` has a unit-length orientation, as required of poses passed to the runtime
+pub(super) fn is_valid_pose(x: &Posef) -> bool {
+    let q = x.orientation;
+    let norm = q.x * q.x + q.y * q.y + q.z * q.z + q.w * q.w;
+    (norm - 1.0).abs() < 1e-2
+}
+
+fn mul(a: Quaternionf, b: Quaternionf) -> Quaternionf {
+    Quaternionf {
+        x: a.w * b.x + a.x * b.w + a.y * b.z - a.z * b.y,
+        y: a.w * b.y - a.x * b.z + a.y * b.w + a.z * b.x,
+        z: a.w * b.z + a.x * b.y - a.y * b.x + a.z * b.w,
+        w: a.w * b.w - a.x * b.x - a.y * b.y - a.z * b.z,
+    }
+}
+
+fn conjugate(q: Quaternionf) -> Quaternionf {
+    Quaternionf {
+        x: -q.x,
+        y: -q.y,
+        z: -q.z,
+        w: q.w,
+    }
+}
+
+fn rotate(q: Quaternionf, v: Vector3f) -> Vector3f {
+    let p = Quaternionf {
+        x: v.x,
+        y: v.y,
+        z: v.z,
+        w: 0.0,
+    };
+    let r = mul(mul(q, p), conjugate(q));
+    Vector3f {
+        x: r.x,
+        y: r.y,
+        z: r.z,
+    }
+}
+
+fn add(a: Vector3f, b: Vector3f) -> Vector3f {
+    Vector3f {
+        x: a.x + b.x,
+        y: a.y + b.y,
+        z: a.z + b.z,
+    }
+}
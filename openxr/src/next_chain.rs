@@ -0,0 +1,84 @@
+use std::{marker::PhantomData, os::raw::c_void, ptr};
+
+use crate::*;
+
+/// Marks structures that may appear in the `next` chain of `T`
+///
+/// Implemented by the generated extension structure builders, such as
+/// [`CompositionLayerDepthInfoKHR`], for each structure the specification allows them to extend.
+/// Values are linked in by the `push_next` method of builders, or by [`NextChain::push`].
+///
+/// # Safety
+///
+/// `Self` must share the layout of an OpenXR structure beginning with `type` and `next` fields,
+/// which the specification allows in the `next` chain of `T`.
+pub unsafe trait Extends<T> {}
+
+/// A `next` chain for a structure without a builder, e.g. [`SwapchainCreateInfo`]
+///
+/// # Example
+///
+/// ```no_run
+/// # fn dummy<G: openxr::RendersFrames>(
+/// #     session: &openxr::Session<G>,
+/// #     info: &openxr::SwapchainCreateInfo<G>,
+/// # ) -> openxr::Result<()> {
+/// let mut foveation = openxr::SwapchainCreateInfoFoveationFB::new()
+///     .flags(openxr::SwapchainCreateFoveationFlagsFB::SCALED_BIN);
+/// let swapchain = session.create_swapchain_with_next(
+///     info,
+///     openxr::NextChain::new().push(&mut foveation),
+/// )?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct NextChain<'a, T> {
+    head: *const c_void,
+    _marker: PhantomData<&'a mut T>,
+}
+
+impl<'a, T> NextChain<'a, T> {
+    /// An empty chain
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            head: ptr::null(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Prepend `value` to the chain, replacing any chain it was previously linked into
+    #[inline]
+    pub fn push<E: Extends<T>>(mut self, value: &'a mut E) -> Self {
+        unsafe {
+            push_next(&mut self.head, value as *mut E as _);
+        }
+        self
+    }
+
+    /// The first structure in the chain, or null if it's empty
+    #[inline]
+    pub fn as_ptr(&self) -> *const c_void {
+        self.head
+    }
+}
+
+impl<T> Default for NextChain<'_, T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Link `node` in front of the chain `head` points to
+///
+/// `node`'s own `next` pointer is overwritten rather than followed, as it may be left over from a
+/// chain that no longer exists.
+///
+/// # Safety
+///
+/// `head` must point to a valid `next` chain, and `node` to a structure beginning with `type` and
+/// `next` fields.
+pub(crate) unsafe fn push_next(head: *mut *const c_void, node: *mut sys::BaseOutStructure) {
+    (*node).next = *head as _;
+    *head = node as _;
+}
@@ -41,11 +41,10 @@ impl Passthrough {
         let fp = fp(&session.inner);
         let mut handle = sys::PassthroughFB::NULL;
         unsafe {
-            cvt((fp.create_passthrough)(
-                session.as_raw(),
-                &info,
-                &mut handle,
-            ))?;
+            cvt(
+                "xrCreatePassthroughFB",
+                (fp.create_passthrough)(session.as_raw(), &info, &mut handle),
+            )?;
         }
         Ok(Passthrough {
             session: session.inner.clone(),
@@ -58,7 +57,7 @@ impl Passthrough {
     pub fn start(&self) -> Result<()> {
         let fp = fp(&self.session);
         unsafe {
-            cvt((fp.passthrough_pause)(self.handle))?;
+            cvt("xrPassthroughPauseFB", (fp.passthrough_pause)(self.handle))?;
         }
         Ok(())
     }
@@ -68,7 +67,7 @@ impl Passthrough {
     pub fn pause(&self) -> Result<()> {
         let fp = fp(&self.session);
         unsafe {
-            cvt((fp.passthrough_pause)(self.handle))?;
+            cvt("xrPassthroughPauseFB", (fp.passthrough_pause)(self.handle))?;
         }
         Ok(())
     }
@@ -112,11 +111,10 @@ impl PassthroughLayer {
         let fp = fp(&session.inner);
         let mut handle = sys::PassthroughLayerFB::NULL;
         unsafe {
-            cvt((fp.create_passthrough_layer)(
-                session.as_raw(),
-                &info,
-                &mut handle,
-            ))?;
+            cvt(
+                "xrCreatePassthroughLayerFB",
+                (fp.create_passthrough_layer)(session.as_raw(), &info, &mut handle),
+            )?;
         }
         Ok(PassthroughLayer {
             session: session.inner.clone(),
@@ -129,7 +127,10 @@ impl PassthroughLayer {
     pub fn resume(&self) -> Result<()> {
         let fp = fp(&self.session);
         unsafe {
-            cvt((fp.passthrough_layer_resume)(self.handle))?;
+            cvt(
+                "xrPassthroughLayerResumeFB",
+                (fp.passthrough_layer_resume)(self.handle),
+            )?;
         }
         Ok(())
     }
@@ -139,7 +140,10 @@ impl PassthroughLayer {
     pub fn pause(&self) -> Result<()> {
         let fp = fp(&self.session);
         unsafe {
-            cvt((fp.passthrough_layer_pause)(self.handle))?;
+            cvt(
+                "xrPassthroughLayerPauseFB",
+                (fp.passthrough_layer_pause)(self.handle),
+            )?;
         }
         Ok(())
     }
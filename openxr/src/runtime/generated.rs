@@ -0,0 +1,1316 @@
+//! Automatically generated code; do not edit!
+
+#![allow(unused, clippy::too_many_arguments, clippy::missing_safety_doc)]
+use std::ffi::CStr;
+use std::mem;
+use std::os::raw::{c_char, c_void};
+
+use sys::*;
+
+#[doc = r" An OpenXR runtime"]
+#[doc = r""]
+#[doc = r" Every method but the last corresponds to a core OpenXR command, and returns"]
+#[doc = r" `ERROR_FUNCTION_UNSUPPORTED` unless overridden."]
+#[doc = r""]
+#[doc = r" # Safety"]
+#[doc = r""]
+#[doc = r" Methods receive raw pointers straight from the application, with the validity"]
+#[doc = r" guarantees the OpenXR specification makes to runtimes."]
+pub trait Runtime: Send + Sync + 'static {
+    #[doc = "See [xrEnumerateInstanceExtensionProperties](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrEnumerateInstanceExtensionProperties)"]
+    unsafe fn enumerate_instance_extension_properties(
+        &self,
+        layer_name: *const c_char,
+        property_capacity_input: u32,
+        property_count_output: *mut u32,
+        properties: *mut ExtensionProperties,
+    ) -> Result {
+        Result::ERROR_FUNCTION_UNSUPPORTED
+    }
+    #[doc = "See [xrCreateInstance](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrCreateInstance)"]
+    unsafe fn create_instance(
+        &self,
+        create_info: *const InstanceCreateInfo,
+        instance: *mut Instance,
+    ) -> Result {
+        Result::ERROR_FUNCTION_UNSUPPORTED
+    }
+    #[doc = "See [xrDestroyInstance](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrDestroyInstance)"]
+    unsafe fn destroy_instance(&self, instance: Instance) -> Result {
+        Result::ERROR_FUNCTION_UNSUPPORTED
+    }
+    #[doc = "See [xrResultToString](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrResultToString)"]
+    unsafe fn result_to_string(
+        &self,
+        instance: Instance,
+        value: Result,
+        buffer: *mut c_char,
+    ) -> Result {
+        Result::ERROR_FUNCTION_UNSUPPORTED
+    }
+    #[doc = "See [xrStructureTypeToString](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrStructureTypeToString)"]
+    unsafe fn structure_type_to_string(
+        &self,
+        instance: Instance,
+        value: StructureType,
+        buffer: *mut c_char,
+    ) -> Result {
+        Result::ERROR_FUNCTION_UNSUPPORTED
+    }
+    #[doc = "See [xrGetInstanceProperties](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrGetInstanceProperties)"]
+    unsafe fn get_instance_properties(
+        &self,
+        instance: Instance,
+        instance_properties: *mut InstanceProperties,
+    ) -> Result {
+        Result::ERROR_FUNCTION_UNSUPPORTED
+    }
+    #[doc = "See [xrGetSystem](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrGetSystem)"]
+    unsafe fn get_system(
+        &self,
+        instance: Instance,
+        get_info: *const SystemGetInfo,
+        system_id: *mut SystemId,
+    ) -> Result {
+        Result::ERROR_FUNCTION_UNSUPPORTED
+    }
+    #[doc = "See [xrGetSystemProperties](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrGetSystemProperties)"]
+    unsafe fn get_system_properties(
+        &self,
+        instance: Instance,
+        system_id: SystemId,
+        properties: *mut SystemProperties,
+    ) -> Result {
+        Result::ERROR_FUNCTION_UNSUPPORTED
+    }
+    #[doc = "See [xrCreateSession](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrCreateSession)"]
+    unsafe fn create_session(
+        &self,
+        instance: Instance,
+        create_info: *const SessionCreateInfo,
+        session: *mut Session,
+    ) -> Result {
+        Result::ERROR_FUNCTION_UNSUPPORTED
+    }
+    #[doc = "See [xrDestroySession](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrDestroySession)"]
+    unsafe fn destroy_session(&self, session: Session) -> Result {
+        Result::ERROR_FUNCTION_UNSUPPORTED
+    }
+    #[doc = "See [xrDestroySpace](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrDestroySpace)"]
+    unsafe fn destroy_space(&self, space: Space) -> Result {
+        Result::ERROR_FUNCTION_UNSUPPORTED
+    }
+    #[doc = "See [xrEnumerateSwapchainFormats](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrEnumerateSwapchainFormats)"]
+    unsafe fn enumerate_swapchain_formats(
+        &self,
+        session: Session,
+        format_capacity_input: u32,
+        format_count_output: *mut u32,
+        formats: *mut i64,
+    ) -> Result {
+        Result::ERROR_FUNCTION_UNSUPPORTED
+    }
+    #[doc = "See [xrCreateSwapchain](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrCreateSwapchain)"]
+    unsafe fn create_swapchain(
+        &self,
+        session: Session,
+        create_info: *const SwapchainCreateInfo,
+        swapchain: *mut Swapchain,
+    ) -> Result {
+        Result::ERROR_FUNCTION_UNSUPPORTED
+    }
+    #[doc = "See [xrDestroySwapchain](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrDestroySwapchain)"]
+    unsafe fn destroy_swapchain(&self, swapchain: Swapchain) -> Result {
+        Result::ERROR_FUNCTION_UNSUPPORTED
+    }
+    #[doc = "See [xrEnumerateSwapchainImages](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrEnumerateSwapchainImages)"]
+    unsafe fn enumerate_swapchain_images(
+        &self,
+        swapchain: Swapchain,
+        image_capacity_input: u32,
+        image_count_output: *mut u32,
+        images: *mut SwapchainImageBaseHeader,
+    ) -> Result {
+        Result::ERROR_FUNCTION_UNSUPPORTED
+    }
+    #[doc = "See [xrAcquireSwapchainImage](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrAcquireSwapchainImage)"]
+    unsafe fn acquire_swapchain_image(
+        &self,
+        swapchain: Swapchain,
+        acquire_info: *const SwapchainImageAcquireInfo,
+        index: *mut u32,
+    ) -> Result {
+        Result::ERROR_FUNCTION_UNSUPPORTED
+    }
+    #[doc = "See [xrWaitSwapchainImage](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrWaitSwapchainImage)"]
+    unsafe fn wait_swapchain_image(
+        &self,
+        swapchain: Swapchain,
+        wait_info: *const SwapchainImageWaitInfo,
+    ) -> Result {
+        Result::ERROR_FUNCTION_UNSUPPORTED
+    }
+    #[doc = "See [xrReleaseSwapchainImage](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrReleaseSwapchainImage)"]
+    unsafe fn release_swapchain_image(
+        &self,
+        swapchain: Swapchain,
+        release_info: *const SwapchainImageReleaseInfo,
+    ) -> Result {
+        Result::ERROR_FUNCTION_UNSUPPORTED
+    }
+    #[doc = "See [xrBeginSession](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrBeginSession)"]
+    unsafe fn begin_session(
+        &self,
+        session: Session,
+        begin_info: *const SessionBeginInfo,
+    ) -> Result {
+        Result::ERROR_FUNCTION_UNSUPPORTED
+    }
+    #[doc = "See [xrEndSession](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrEndSession)"]
+    unsafe fn end_session(&self, session: Session) -> Result {
+        Result::ERROR_FUNCTION_UNSUPPORTED
+    }
+    #[doc = "See [xrRequestExitSession](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrRequestExitSession)"]
+    unsafe fn request_exit_session(&self, session: Session) -> Result {
+        Result::ERROR_FUNCTION_UNSUPPORTED
+    }
+    #[doc = "See [xrEnumerateReferenceSpaces](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrEnumerateReferenceSpaces)"]
+    unsafe fn enumerate_reference_spaces(
+        &self,
+        session: Session,
+        space_capacity_input: u32,
+        space_count_output: *mut u32,
+        spaces: *mut ReferenceSpaceType,
+    ) -> Result {
+        Result::ERROR_FUNCTION_UNSUPPORTED
+    }
+    #[doc = "See [xrCreateReferenceSpace](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrCreateReferenceSpace)"]
+    unsafe fn create_reference_space(
+        &self,
+        session: Session,
+        create_info: *const ReferenceSpaceCreateInfo,
+        space: *mut Space,
+    ) -> Result {
+        Result::ERROR_FUNCTION_UNSUPPORTED
+    }
+    #[doc = "See [xrCreateActionSpace](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrCreateActionSpace)"]
+    unsafe fn create_action_space(
+        &self,
+        session: Session,
+        create_info: *const ActionSpaceCreateInfo,
+        space: *mut Space,
+    ) -> Result {
+        Result::ERROR_FUNCTION_UNSUPPORTED
+    }
+    #[doc = "See [xrLocateSpace](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrLocateSpace)"]
+    unsafe fn locate_space(
+        &self,
+        space: Space,
+        base_space: Space,
+        time: Time,
+        location: *mut SpaceLocation,
+    ) -> Result {
+        Result::ERROR_FUNCTION_UNSUPPORTED
+    }
+    #[doc = "See [xrEnumerateViewConfigurations](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrEnumerateViewConfigurations)"]
+    unsafe fn enumerate_view_configurations(
+        &self,
+        instance: Instance,
+        system_id: SystemId,
+        view_configuration_type_capacity_input: u32,
+        view_configuration_type_count_output: *mut u32,
+        view_configuration_types: *mut ViewConfigurationType,
+    ) -> Result {
+        Result::ERROR_FUNCTION_UNSUPPORTED
+    }
+    #[doc = "See [xrEnumerateEnvironmentBlendModes](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrEnumerateEnvironmentBlendModes)"]
+    unsafe fn enumerate_environment_blend_modes(
+        &self,
+        instance: Instance,
+        system_id: SystemId,
+        view_configuration_type: ViewConfigurationType,
+        environment_blend_mode_capacity_input: u32,
+        environment_blend_mode_count_output: *mut u32,
+        environment_blend_modes: *mut EnvironmentBlendMode,
+    ) -> Result {
+        Result::ERROR_FUNCTION_UNSUPPORTED
+    }
+    #[doc = "See [xrGetViewConfigurationProperties](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrGetViewConfigurationProperties)"]
+    unsafe fn get_view_configuration_properties(
+        &self,
+        instance: Instance,
+        system_id: SystemId,
+        view_configuration_type: ViewConfigurationType,
+        configuration_properties: *mut ViewConfigurationProperties,
+    ) -> Result {
+        Result::ERROR_FUNCTION_UNSUPPORTED
+    }
+    #[doc = "See [xrEnumerateViewConfigurationViews](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrEnumerateViewConfigurationViews)"]
+    unsafe fn enumerate_view_configuration_views(
+        &self,
+        instance: Instance,
+        system_id: SystemId,
+        view_configuration_type: ViewConfigurationType,
+        view_capacity_input: u32,
+        view_count_output: *mut u32,
+        views: *mut ViewConfigurationView,
+    ) -> Result {
+        Result::ERROR_FUNCTION_UNSUPPORTED
+    }
+    #[doc = "See [xrBeginFrame](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrBeginFrame)"]
+    unsafe fn begin_frame(
+        &self,
+        session: Session,
+        frame_begin_info: *const FrameBeginInfo,
+    ) -> Result {
+        Result::ERROR_FUNCTION_UNSUPPORTED
+    }
+    #[doc = "See [xrLocateViews](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrLocateViews)"]
+    unsafe fn locate_views(
+        &self,
+        session: Session,
+        view_locate_info: *const ViewLocateInfo,
+        view_state: *mut ViewState,
+        view_capacity_input: u32,
+        view_count_output: *mut u32,
+        views: *mut View,
+    ) -> Result {
+        Result::ERROR_FUNCTION_UNSUPPORTED
+    }
+    #[doc = "See [xrEndFrame](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrEndFrame)"]
+    unsafe fn end_frame(&self, session: Session, frame_end_info: *const FrameEndInfo) -> Result {
+        Result::ERROR_FUNCTION_UNSUPPORTED
+    }
+    #[doc = "See [xrWaitFrame](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrWaitFrame)"]
+    unsafe fn wait_frame(
+        &self,
+        session: Session,
+        frame_wait_info: *const FrameWaitInfo,
+        frame_state: *mut FrameState,
+    ) -> Result {
+        Result::ERROR_FUNCTION_UNSUPPORTED
+    }
+    #[doc = "See [xrApplyHapticFeedback](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrApplyHapticFeedback)"]
+    unsafe fn apply_haptic_feedback(
+        &self,
+        session: Session,
+        haptic_action_info: *const HapticActionInfo,
+        haptic_feedback: *const HapticBaseHeader,
+    ) -> Result {
+        Result::ERROR_FUNCTION_UNSUPPORTED
+    }
+    #[doc = "See [xrStopHapticFeedback](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrStopHapticFeedback)"]
+    unsafe fn stop_haptic_feedback(
+        &self,
+        session: Session,
+        haptic_action_info: *const HapticActionInfo,
+    ) -> Result {
+        Result::ERROR_FUNCTION_UNSUPPORTED
+    }
+    #[doc = "See [xrPollEvent](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrPollEvent)"]
+    unsafe fn poll_event(&self, instance: Instance, event_data: *mut EventDataBuffer) -> Result {
+        Result::ERROR_FUNCTION_UNSUPPORTED
+    }
+    #[doc = "See [xrStringToPath](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrStringToPath)"]
+    unsafe fn string_to_path(
+        &self,
+        instance: Instance,
+        path_string: *const c_char,
+        path: *mut Path,
+    ) -> Result {
+        Result::ERROR_FUNCTION_UNSUPPORTED
+    }
+    #[doc = "See [xrPathToString](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrPathToString)"]
+    unsafe fn path_to_string(
+        &self,
+        instance: Instance,
+        path: Path,
+        buffer_capacity_input: u32,
+        buffer_count_output: *mut u32,
+        buffer: *mut c_char,
+    ) -> Result {
+        Result::ERROR_FUNCTION_UNSUPPORTED
+    }
+    #[doc = "See [xrGetReferenceSpaceBoundsRect](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrGetReferenceSpaceBoundsRect)"]
+    unsafe fn get_reference_space_bounds_rect(
+        &self,
+        session: Session,
+        reference_space_type: ReferenceSpaceType,
+        bounds: *mut Extent2Df,
+    ) -> Result {
+        Result::ERROR_FUNCTION_UNSUPPORTED
+    }
+    #[doc = "See [xrGetActionStateBoolean](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrGetActionStateBoolean)"]
+    unsafe fn get_action_state_boolean(
+        &self,
+        session: Session,
+        get_info: *const ActionStateGetInfo,
+        state: *mut ActionStateBoolean,
+    ) -> Result {
+        Result::ERROR_FUNCTION_UNSUPPORTED
+    }
+    #[doc = "See [xrGetActionStateFloat](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrGetActionStateFloat)"]
+    unsafe fn get_action_state_float(
+        &self,
+        session: Session,
+        get_info: *const ActionStateGetInfo,
+        state: *mut ActionStateFloat,
+    ) -> Result {
+        Result::ERROR_FUNCTION_UNSUPPORTED
+    }
+    #[doc = "See [xrGetActionStateVector2f](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrGetActionStateVector2f)"]
+    unsafe fn get_action_state_vector2f(
+        &self,
+        session: Session,
+        get_info: *const ActionStateGetInfo,
+        state: *mut ActionStateVector2f,
+    ) -> Result {
+        Result::ERROR_FUNCTION_UNSUPPORTED
+    }
+    #[doc = "See [xrGetActionStatePose](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrGetActionStatePose)"]
+    unsafe fn get_action_state_pose(
+        &self,
+        session: Session,
+        get_info: *const ActionStateGetInfo,
+        state: *mut ActionStatePose,
+    ) -> Result {
+        Result::ERROR_FUNCTION_UNSUPPORTED
+    }
+    #[doc = "See [xrCreateActionSet](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrCreateActionSet)"]
+    unsafe fn create_action_set(
+        &self,
+        instance: Instance,
+        create_info: *const ActionSetCreateInfo,
+        action_set: *mut ActionSet,
+    ) -> Result {
+        Result::ERROR_FUNCTION_UNSUPPORTED
+    }
+    #[doc = "See [xrDestroyActionSet](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrDestroyActionSet)"]
+    unsafe fn destroy_action_set(&self, action_set: ActionSet) -> Result {
+        Result::ERROR_FUNCTION_UNSUPPORTED
+    }
+    #[doc = "See [xrCreateAction](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrCreateAction)"]
+    unsafe fn create_action(
+        &self,
+        action_set: ActionSet,
+        create_info: *const ActionCreateInfo,
+        action: *mut Action,
+    ) -> Result {
+        Result::ERROR_FUNCTION_UNSUPPORTED
+    }
+    #[doc = "See [xrDestroyAction](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrDestroyAction)"]
+    unsafe fn destroy_action(&self, action: Action) -> Result {
+        Result::ERROR_FUNCTION_UNSUPPORTED
+    }
+    #[doc = "See [xrSuggestInteractionProfileBindings](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrSuggestInteractionProfileBindings)"]
+    unsafe fn suggest_interaction_profile_bindings(
+        &self,
+        instance: Instance,
+        suggested_bindings: *const InteractionProfileSuggestedBinding,
+    ) -> Result {
+        Result::ERROR_FUNCTION_UNSUPPORTED
+    }
+    #[doc = "See [xrAttachSessionActionSets](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrAttachSessionActionSets)"]
+    unsafe fn attach_session_action_sets(
+        &self,
+        session: Session,
+        attach_info: *const SessionActionSetsAttachInfo,
+    ) -> Result {
+        Result::ERROR_FUNCTION_UNSUPPORTED
+    }
+    #[doc = "See [xrGetCurrentInteractionProfile](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrGetCurrentInteractionProfile)"]
+    unsafe fn get_current_interaction_profile(
+        &self,
+        session: Session,
+        top_level_user_path: Path,
+        interaction_profile: *mut InteractionProfileState,
+    ) -> Result {
+        Result::ERROR_FUNCTION_UNSUPPORTED
+    }
+    #[doc = "See [xrSyncActions](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrSyncActions)"]
+    unsafe fn sync_actions(&self, session: Session, sync_info: *const ActionsSyncInfo) -> Result {
+        Result::ERROR_FUNCTION_UNSUPPORTED
+    }
+    #[doc = "See [xrEnumerateBoundSourcesForAction](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrEnumerateBoundSourcesForAction)"]
+    unsafe fn enumerate_bound_sources_for_action(
+        &self,
+        session: Session,
+        enumerate_info: *const BoundSourcesForActionEnumerateInfo,
+        source_capacity_input: u32,
+        source_count_output: *mut u32,
+        sources: *mut Path,
+    ) -> Result {
+        Result::ERROR_FUNCTION_UNSUPPORTED
+    }
+    #[doc = "See [xrGetInputSourceLocalizedName](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrGetInputSourceLocalizedName)"]
+    unsafe fn get_input_source_localized_name(
+        &self,
+        session: Session,
+        get_info: *const InputSourceLocalizedNameGetInfo,
+        buffer_capacity_input: u32,
+        buffer_count_output: *mut u32,
+        buffer: *mut c_char,
+    ) -> Result {
+        Result::ERROR_FUNCTION_UNSUPPORTED
+    }
+    #[doc = "See [xrLocateSpaces](https://www.khronos.org/registry/OpenXR/specs/1.1/html/xrspec.html#xrLocateSpaces)"]
+    unsafe fn locate_spaces(
+        &self,
+        session: Session,
+        locate_info: *const SpacesLocateInfo,
+        space_locations: *mut SpaceLocations,
+    ) -> Result {
+        Result::ERROR_FUNCTION_UNSUPPORTED
+    }
+    #[doc = r" Look up an extension command for `instance`"]
+    #[doc = r""]
+    #[doc = r" Called by `xrGetInstanceProcAddr` for commands outside the core set. Returning"]
+    #[doc = r" `None` reports the command as unsupported."]
+    unsafe fn extension_proc_addr(
+        &self,
+        instance: Instance,
+        name: &CStr,
+    ) -> Option<pfn::VoidFunction> {
+        None
+    }
+}
+
+unsafe extern "system" fn enumerate_instance_extension_properties(
+    layer_name: *const c_char,
+    property_capacity_input: u32,
+    property_count_output: *mut u32,
+    properties: *mut ExtensionProperties,
+) -> Result {
+    super::call(|this| {
+        this.enumerate_instance_extension_properties(
+            layer_name,
+            property_capacity_input,
+            property_count_output,
+            properties,
+        )
+    })
+}
+unsafe extern "system" fn create_instance(
+    create_info: *const InstanceCreateInfo,
+    instance: *mut Instance,
+) -> Result {
+    super::call(|this| this.create_instance(create_info, instance))
+}
+unsafe extern "system" fn destroy_instance(instance: Instance) -> Result {
+    super::call(|this| this.destroy_instance(instance))
+}
+unsafe extern "system" fn result_to_string(
+    instance: Instance,
+    value: Result,
+    buffer: *mut c_char,
+) -> Result {
+    super::call(|this| this.result_to_string(instance, value, buffer))
+}
+unsafe extern "system" fn structure_type_to_string(
+    instance: Instance,
+    value: StructureType,
+    buffer: *mut c_char,
+) -> Result {
+    super::call(|this| this.structure_type_to_string(instance, value, buffer))
+}
+unsafe extern "system" fn get_instance_properties(
+    instance: Instance,
+    instance_properties: *mut InstanceProperties,
+) -> Result {
+    super::call(|this| this.get_instance_properties(instance, instance_properties))
+}
+unsafe extern "system" fn get_system(
+    instance: Instance,
+    get_info: *const SystemGetInfo,
+    system_id: *mut SystemId,
+) -> Result {
+    super::call(|this| this.get_system(instance, get_info, system_id))
+}
+unsafe extern "system" fn get_system_properties(
+    instance: Instance,
+    system_id: SystemId,
+    properties: *mut SystemProperties,
+) -> Result {
+    super::call(|this| this.get_system_properties(instance, system_id, properties))
+}
+unsafe extern "system" fn create_session(
+    instance: Instance,
+    create_info: *const SessionCreateInfo,
+    session: *mut Session,
+) -> Result {
+    super::call(|this| this.create_session(instance, create_info, session))
+}
+unsafe extern "system" fn destroy_session(session: Session) -> Result {
+    super::call(|this| this.destroy_session(session))
+}
+unsafe extern "system" fn destroy_space(space: Space) -> Result {
+    super::call(|this| this.destroy_space(space))
+}
+unsafe extern "system" fn enumerate_swapchain_formats(
+    session: Session,
+    format_capacity_input: u32,
+    format_count_output: *mut u32,
+    formats: *mut i64,
+) -> Result {
+    super::call(|this| {
+        this.enumerate_swapchain_formats(
+            session,
+            format_capacity_input,
+            format_count_output,
+            formats,
+        )
+    })
+}
+unsafe extern "system" fn create_swapchain(
+    session: Session,
+    create_info: *const SwapchainCreateInfo,
+    swapchain: *mut Swapchain,
+) -> Result {
+    super::call(|this| this.create_swapchain(session, create_info, swapchain))
+}
+unsafe extern "system" fn destroy_swapchain(swapchain: Swapchain) -> Result {
+    super::call(|this| this.destroy_swapchain(swapchain))
+}
+unsafe extern "system" fn enumerate_swapchain_images(
+    swapchain: Swapchain,
+    image_capacity_input: u32,
+    image_count_output: *mut u32,
+    images: *mut SwapchainImageBaseHeader,
+) -> Result {
+    super::call(|this| {
+        this.enumerate_swapchain_images(swapchain, image_capacity_input, image_count_output, images)
+    })
+}
+unsafe extern "system" fn acquire_swapchain_image(
+    swapchain: Swapchain,
+    acquire_info: *const SwapchainImageAcquireInfo,
+    index: *mut u32,
+) -> Result {
+    super::call(|this| this.acquire_swapchain_image(swapchain, acquire_info, index))
+}
+unsafe extern "system" fn wait_swapchain_image(
+    swapchain: Swapchain,
+    wait_info: *const SwapchainImageWaitInfo,
+) -> Result {
+    super::call(|this| this.wait_swapchain_image(swapchain, wait_info))
+}
+unsafe extern "system" fn release_swapchain_image(
+    swapchain: Swapchain,
+    release_info: *const SwapchainImageReleaseInfo,
+) -> Result {
+    super::call(|this| this.release_swapchain_image(swapchain, release_info))
+}
+unsafe extern "system" fn begin_session(
+    session: Session,
+    begin_info: *const SessionBeginInfo,
+) -> Result {
+    super::call(|this| this.begin_session(session, begin_info))
+}
+unsafe extern "system" fn end_session(session: Session) -> Result {
+    super::call(|this| this.end_session(session))
+}
+unsafe extern "system" fn request_exit_session(session: Session) -> Result {
+    super::call(|this| this.request_exit_session(session))
+}
+unsafe extern "system" fn enumerate_reference_spaces(
+    session: Session,
+    space_capacity_input: u32,
+    space_count_output: *mut u32,
+    spaces: *mut ReferenceSpaceType,
+) -> Result {
+    super::call(|this| {
+        this.enumerate_reference_spaces(session, space_capacity_input, space_count_output, spaces)
+    })
+}
+unsafe extern "system" fn create_reference_space(
+    session: Session,
+    create_info: *const ReferenceSpaceCreateInfo,
+    space: *mut Space,
+) -> Result {
+    super::call(|this| this.create_reference_space(session, create_info, space))
+}
+unsafe extern "system" fn create_action_space(
+    session: Session,
+    create_info: *const ActionSpaceCreateInfo,
+    space: *mut Space,
+) -> Result {
+    super::call(|this| this.create_action_space(session, create_info, space))
+}
+unsafe extern "system" fn locate_space(
+    space: Space,
+    base_space: Space,
+    time: Time,
+    location: *mut SpaceLocation,
+) -> Result {
+    super::call(|this| this.locate_space(space, base_space, time, location))
+}
+unsafe extern "system" fn enumerate_view_configurations(
+    instance: Instance,
+    system_id: SystemId,
+    view_configuration_type_capacity_input: u32,
+    view_configuration_type_count_output: *mut u32,
+    view_configuration_types: *mut ViewConfigurationType,
+) -> Result {
+    super::call(|this| {
+        this.enumerate_view_configurations(
+            instance,
+            system_id,
+            view_configuration_type_capacity_input,
+            view_configuration_type_count_output,
+            view_configuration_types,
+        )
+    })
+}
+unsafe extern "system" fn enumerate_environment_blend_modes(
+    instance: Instance,
+    system_id: SystemId,
+    view_configuration_type: ViewConfigurationType,
+    environment_blend_mode_capacity_input: u32,
+    environment_blend_mode_count_output: *mut u32,
+    environment_blend_modes: *mut EnvironmentBlendMode,
+) -> Result {
+    super::call(|this| {
+        this.enumerate_environment_blend_modes(
+            instance,
+            system_id,
+            view_configuration_type,
+            environment_blend_mode_capacity_input,
+            environment_blend_mode_count_output,
+            environment_blend_modes,
+        )
+    })
+}
+unsafe extern "system" fn get_view_configuration_properties(
+    instance: Instance,
+    system_id: SystemId,
+    view_configuration_type: ViewConfigurationType,
+    configuration_properties: *mut ViewConfigurationProperties,
+) -> Result {
+    super::call(|this| {
+        this.get_view_configuration_properties(
+            instance,
+            system_id,
+            view_configuration_type,
+            configuration_properties,
+        )
+    })
+}
+unsafe extern "system" fn enumerate_view_configuration_views(
+    instance: Instance,
+    system_id: SystemId,
+    view_configuration_type: ViewConfigurationType,
+    view_capacity_input: u32,
+    view_count_output: *mut u32,
+    views: *mut ViewConfigurationView,
+) -> Result {
+    super::call(|this| {
+        this.enumerate_view_configuration_views(
+            instance,
+            system_id,
+            view_configuration_type,
+            view_capacity_input,
+            view_count_output,
+            views,
+        )
+    })
+}
+unsafe extern "system" fn begin_frame(
+    session: Session,
+    frame_begin_info: *const FrameBeginInfo,
+) -> Result {
+    super::call(|this| this.begin_frame(session, frame_begin_info))
+}
+unsafe extern "system" fn locate_views(
+    session: Session,
+    view_locate_info: *const ViewLocateInfo,
+    view_state: *mut ViewState,
+    view_capacity_input: u32,
+    view_count_output: *mut u32,
+    views: *mut View,
+) -> Result {
+    super::call(|this| {
+        this.locate_views(
+            session,
+            view_locate_info,
+            view_state,
+            view_capacity_input,
+            view_count_output,
+            views,
+        )
+    })
+}
+unsafe extern "system" fn end_frame(
+    session: Session,
+    frame_end_info: *const FrameEndInfo,
+) -> Result {
+    super::call(|this| this.end_frame(session, frame_end_info))
+}
+unsafe extern "system" fn wait_frame(
+    session: Session,
+    frame_wait_info: *const FrameWaitInfo,
+    frame_state: *mut FrameState,
+) -> Result {
+    super::call(|this| this.wait_frame(session, frame_wait_info, frame_state))
+}
+unsafe extern "system" fn apply_haptic_feedback(
+    session: Session,
+    haptic_action_info: *const HapticActionInfo,
+    haptic_feedback: *const HapticBaseHeader,
+) -> Result {
+    super::call(|this| this.apply_haptic_feedback(session, haptic_action_info, haptic_feedback))
+}
+unsafe extern "system" fn stop_haptic_feedback(
+    session: Session,
+    haptic_action_info: *const HapticActionInfo,
+) -> Result {
+    super::call(|this| this.stop_haptic_feedback(session, haptic_action_info))
+}
+unsafe extern "system" fn poll_event(
+    instance: Instance,
+    event_data: *mut EventDataBuffer,
+) -> Result {
+    super::call(|this| this.poll_event(instance, event_data))
+}
+unsafe extern "system" fn string_to_path(
+    instance: Instance,
+    path_string: *const c_char,
+    path: *mut Path,
+) -> Result {
+    super::call(|this| this.string_to_path(instance, path_string, path))
+}
+unsafe extern "system" fn path_to_string(
+    instance: Instance,
+    path: Path,
+    buffer_capacity_input: u32,
+    buffer_count_output: *mut u32,
+    buffer: *mut c_char,
+) -> Result {
+    super::call(|this| {
+        this.path_to_string(
+            instance,
+            path,
+            buffer_capacity_input,
+            buffer_count_output,
+            buffer,
+        )
+    })
+}
+unsafe extern "system" fn get_reference_space_bounds_rect(
+    session: Session,
+    reference_space_type: ReferenceSpaceType,
+    bounds: *mut Extent2Df,
+) -> Result {
+    super::call(|this| this.get_reference_space_bounds_rect(session, reference_space_type, bounds))
+}
+unsafe extern "system" fn get_action_state_boolean(
+    session: Session,
+    get_info: *const ActionStateGetInfo,
+    state: *mut ActionStateBoolean,
+) -> Result {
+    super::call(|this| this.get_action_state_boolean(session, get_info, state))
+}
+unsafe extern "system" fn get_action_state_float(
+    session: Session,
+    get_info: *const ActionStateGetInfo,
+    state: *mut ActionStateFloat,
+) -> Result {
+    super::call(|this| this.get_action_state_float(session, get_info, state))
+}
+unsafe extern "system" fn get_action_state_vector2f(
+    session: Session,
+    get_info: *const ActionStateGetInfo,
+    state: *mut ActionStateVector2f,
+) -> Result {
+    super::call(|this| this.get_action_state_vector2f(session, get_info, state))
+}
+unsafe extern "system" fn get_action_state_pose(
+    session: Session,
+    get_info: *const ActionStateGetInfo,
+    state: *mut ActionStatePose,
+) -> Result {
+    super::call(|this| this.get_action_state_pose(session, get_info, state))
+}
+unsafe extern "system" fn create_action_set(
+    instance: Instance,
+    create_info: *const ActionSetCreateInfo,
+    action_set: *mut ActionSet,
+) -> Result {
+    super::call(|this| this.create_action_set(instance, create_info, action_set))
+}
+unsafe extern "system" fn destroy_action_set(action_set: ActionSet) -> Result {
+    super::call(|this| this.destroy_action_set(action_set))
+}
+unsafe extern "system" fn create_action(
+    action_set: ActionSet,
+    create_info: *const ActionCreateInfo,
+    action: *mut Action,
+) -> Result {
+    super::call(|this| this.create_action(action_set, create_info, action))
+}
+unsafe extern "system" fn destroy_action(action: Action) -> Result {
+    super::call(|this| this.destroy_action(action))
+}
+unsafe extern "system" fn suggest_interaction_profile_bindings(
+    instance: Instance,
+    suggested_bindings: *const InteractionProfileSuggestedBinding,
+) -> Result {
+    super::call(|this| this.suggest_interaction_profile_bindings(instance, suggested_bindings))
+}
+unsafe extern "system" fn attach_session_action_sets(
+    session: Session,
+    attach_info: *const SessionActionSetsAttachInfo,
+) -> Result {
+    super::call(|this| this.attach_session_action_sets(session, attach_info))
+}
+unsafe extern "system" fn get_current_interaction_profile(
+    session: Session,
+    top_level_user_path: Path,
+    interaction_profile: *mut InteractionProfileState,
+) -> Result {
+    super::call(|this| {
+        this.get_current_interaction_profile(session, top_level_user_path, interaction_profile)
+    })
+}
+unsafe extern "system" fn sync_actions(
+    session: Session,
+    sync_info: *const ActionsSyncInfo,
+) -> Result {
+    super::call(|this| this.sync_actions(session, sync_info))
+}
+unsafe extern "system" fn enumerate_bound_sources_for_action(
+    session: Session,
+    enumerate_info: *const BoundSourcesForActionEnumerateInfo,
+    source_capacity_input: u32,
+    source_count_output: *mut u32,
+    sources: *mut Path,
+) -> Result {
+    super::call(|this| {
+        this.enumerate_bound_sources_for_action(
+            session,
+            enumerate_info,
+            source_capacity_input,
+            source_count_output,
+            sources,
+        )
+    })
+}
+unsafe extern "system" fn get_input_source_localized_name(
+    session: Session,
+    get_info: *const InputSourceLocalizedNameGetInfo,
+    buffer_capacity_input: u32,
+    buffer_count_output: *mut u32,
+    buffer: *mut c_char,
+) -> Result {
+    super::call(|this| {
+        this.get_input_source_localized_name(
+            session,
+            get_info,
+            buffer_capacity_input,
+            buffer_count_output,
+            buffer,
+        )
+    })
+}
+unsafe extern "system" fn locate_spaces(
+    session: Session,
+    locate_info: *const SpacesLocateInfo,
+    space_locations: *mut SpaceLocations,
+) -> Result {
+    super::call(|this| this.locate_spaces(session, locate_info, space_locations))
+}
+
+#[doc = r" Look up the entry point for the core command named `name`"]
+pub(super) fn thunk(name: &[u8]) -> Option<pfn::VoidFunction> {
+    unsafe {
+        Some(match name {
+            b"xrEnumerateInstanceExtensionProperties" => {
+                mem::transmute::<pfn::EnumerateInstanceExtensionProperties, pfn::VoidFunction>(
+                    enumerate_instance_extension_properties,
+                )
+            }
+            b"xrCreateInstance" => {
+                mem::transmute::<pfn::CreateInstance, pfn::VoidFunction>(create_instance)
+            }
+            b"xrDestroyInstance" => {
+                mem::transmute::<pfn::DestroyInstance, pfn::VoidFunction>(destroy_instance)
+            }
+            b"xrResultToString" => {
+                mem::transmute::<pfn::ResultToString, pfn::VoidFunction>(result_to_string)
+            }
+            b"xrStructureTypeToString" => mem::transmute::<
+                pfn::StructureTypeToString,
+                pfn::VoidFunction,
+            >(structure_type_to_string),
+            b"xrGetInstanceProperties" => mem::transmute::<
+                pfn::GetInstanceProperties,
+                pfn::VoidFunction,
+            >(get_instance_properties),
+            b"xrGetSystem" => mem::transmute::<pfn::GetSystem, pfn::VoidFunction>(get_system),
+            b"xrGetSystemProperties" => {
+                mem::transmute::<pfn::GetSystemProperties, pfn::VoidFunction>(get_system_properties)
+            }
+            b"xrCreateSession" => {
+                mem::transmute::<pfn::CreateSession, pfn::VoidFunction>(create_session)
+            }
+            b"xrDestroySession" => {
+                mem::transmute::<pfn::DestroySession, pfn::VoidFunction>(destroy_session)
+            }
+            b"xrDestroySpace" => {
+                mem::transmute::<pfn::DestroySpace, pfn::VoidFunction>(destroy_space)
+            }
+            b"xrEnumerateSwapchainFormats" => mem::transmute::<
+                pfn::EnumerateSwapchainFormats,
+                pfn::VoidFunction,
+            >(enumerate_swapchain_formats),
+            b"xrCreateSwapchain" => {
+                mem::transmute::<pfn::CreateSwapchain, pfn::VoidFunction>(create_swapchain)
+            }
+            b"xrDestroySwapchain" => {
+                mem::transmute::<pfn::DestroySwapchain, pfn::VoidFunction>(destroy_swapchain)
+            }
+            b"xrEnumerateSwapchainImages" => mem::transmute::<
+                pfn::EnumerateSwapchainImages,
+                pfn::VoidFunction,
+            >(enumerate_swapchain_images),
+            b"xrAcquireSwapchainImage" => mem::transmute::<
+                pfn::AcquireSwapchainImage,
+                pfn::VoidFunction,
+            >(acquire_swapchain_image),
+            b"xrWaitSwapchainImage" => {
+                mem::transmute::<pfn::WaitSwapchainImage, pfn::VoidFunction>(wait_swapchain_image)
+            }
+            b"xrReleaseSwapchainImage" => mem::transmute::<
+                pfn::ReleaseSwapchainImage,
+                pfn::VoidFunction,
+            >(release_swapchain_image),
+            b"xrBeginSession" => {
+                mem::transmute::<pfn::BeginSession, pfn::VoidFunction>(begin_session)
+            }
+            b"xrEndSession" => mem::transmute::<pfn::EndSession, pfn::VoidFunction>(end_session),
+            b"xrRequestExitSession" => {
+                mem::transmute::<pfn::RequestExitSession, pfn::VoidFunction>(request_exit_session)
+            }
+            b"xrEnumerateReferenceSpaces" => mem::transmute::<
+                pfn::EnumerateReferenceSpaces,
+                pfn::VoidFunction,
+            >(enumerate_reference_spaces),
+            b"xrCreateReferenceSpace" => mem::transmute::<
+                pfn::CreateReferenceSpace,
+                pfn::VoidFunction,
+            >(create_reference_space),
+            b"xrCreateActionSpace" => {
+                mem::transmute::<pfn::CreateActionSpace, pfn::VoidFunction>(create_action_space)
+            }
+            b"xrLocateSpace" => mem::transmute::<pfn::LocateSpace, pfn::VoidFunction>(locate_space),
+            b"xrEnumerateViewConfigurations" => mem::transmute::<
+                pfn::EnumerateViewConfigurations,
+                pfn::VoidFunction,
+            >(enumerate_view_configurations),
+            b"xrEnumerateEnvironmentBlendModes" => mem::transmute::<
+                pfn::EnumerateEnvironmentBlendModes,
+                pfn::VoidFunction,
+            >(enumerate_environment_blend_modes),
+            b"xrGetViewConfigurationProperties" => mem::transmute::<
+                pfn::GetViewConfigurationProperties,
+                pfn::VoidFunction,
+            >(get_view_configuration_properties),
+            b"xrEnumerateViewConfigurationViews" => {
+                mem::transmute::<pfn::EnumerateViewConfigurationViews, pfn::VoidFunction>(
+                    enumerate_view_configuration_views,
+                )
+            }
+            b"xrBeginFrame" => mem::transmute::<pfn::BeginFrame, pfn::VoidFunction>(begin_frame),
+            b"xrLocateViews" => mem::transmute::<pfn::LocateViews, pfn::VoidFunction>(locate_views),
+            b"xrEndFrame" => mem::transmute::<pfn::EndFrame, pfn::VoidFunction>(end_frame),
+            b"xrWaitFrame" => mem::transmute::<pfn::WaitFrame, pfn::VoidFunction>(wait_frame),
+            b"xrApplyHapticFeedback" => {
+                mem::transmute::<pfn::ApplyHapticFeedback, pfn::VoidFunction>(apply_haptic_feedback)
+            }
+            b"xrStopHapticFeedback" => {
+                mem::transmute::<pfn::StopHapticFeedback, pfn::VoidFunction>(stop_haptic_feedback)
+            }
+            b"xrPollEvent" => mem::transmute::<pfn::PollEvent, pfn::VoidFunction>(poll_event),
+            b"xrStringToPath" => {
+                mem::transmute::<pfn::StringToPath, pfn::VoidFunction>(string_to_path)
+            }
+            b"xrPathToString" => {
+                mem::transmute::<pfn::PathToString, pfn::VoidFunction>(path_to_string)
+            }
+            b"xrGetReferenceSpaceBoundsRect" => mem::transmute::<
+                pfn::GetReferenceSpaceBoundsRect,
+                pfn::VoidFunction,
+            >(get_reference_space_bounds_rect),
+            b"xrGetActionStateBoolean" => mem::transmute::<
+                pfn::GetActionStateBoolean,
+                pfn::VoidFunction,
+            >(get_action_state_boolean),
+            b"xrGetActionStateFloat" => {
+                mem::transmute::<pfn::GetActionStateFloat, pfn::VoidFunction>(
+                    get_action_state_float,
+                )
+            }
+            b"xrGetActionStateVector2f" => mem::transmute::<
+                pfn::GetActionStateVector2f,
+                pfn::VoidFunction,
+            >(get_action_state_vector2f),
+            b"xrGetActionStatePose" => {
+                mem::transmute::<pfn::GetActionStatePose, pfn::VoidFunction>(get_action_state_pose)
+            }
+            b"xrCreateActionSet" => {
+                mem::transmute::<pfn::CreateActionSet, pfn::VoidFunction>(create_action_set)
+            }
+            b"xrDestroyActionSet" => {
+                mem::transmute::<pfn::DestroyActionSet, pfn::VoidFunction>(destroy_action_set)
+            }
+            b"xrCreateAction" => {
+                mem::transmute::<pfn::CreateAction, pfn::VoidFunction>(create_action)
+            }
+            b"xrDestroyAction" => {
+                mem::transmute::<pfn::DestroyAction, pfn::VoidFunction>(destroy_action)
+            }
+            b"xrSuggestInteractionProfileBindings" => {
+                mem::transmute::<pfn::SuggestInteractionProfileBindings, pfn::VoidFunction>(
+                    suggest_interaction_profile_bindings,
+                )
+            }
+            b"xrAttachSessionActionSets" => mem::transmute::<
+                pfn::AttachSessionActionSets,
+                pfn::VoidFunction,
+            >(attach_session_action_sets),
+            b"xrGetCurrentInteractionProfile" => mem::transmute::<
+                pfn::GetCurrentInteractionProfile,
+                pfn::VoidFunction,
+            >(get_current_interaction_profile),
+            b"xrSyncActions" => mem::transmute::<pfn::SyncActions, pfn::VoidFunction>(sync_actions),
+            b"xrEnumerateBoundSourcesForAction" => mem::transmute::<
+                pfn::EnumerateBoundSourcesForAction,
+                pfn::VoidFunction,
+            >(enumerate_bound_sources_for_action),
+            b"xrGetInputSourceLocalizedName" => mem::transmute::<
+                pfn::GetInputSourceLocalizedName,
+                pfn::VoidFunction,
+            >(get_input_source_localized_name),
+            b"xrLocateSpaces" => {
+                mem::transmute::<pfn::LocateSpaces, pfn::VoidFunction>(locate_spaces)
+            }
+            _ => return None,
+        })
+    }
+}
+
+impl super::Handle for Instance {
+    fn from_raw(raw: u64) -> Self {
+        Self::from_raw(raw)
+    }
+    fn into_raw(self) -> u64 {
+        self.into_raw()
+    }
+}
+impl super::Handle for Session {
+    fn from_raw(raw: u64) -> Self {
+        Self::from_raw(raw)
+    }
+    fn into_raw(self) -> u64 {
+        self.into_raw()
+    }
+}
+impl super::Handle for ActionSet {
+    fn from_raw(raw: u64) -> Self {
+        Self::from_raw(raw)
+    }
+    fn into_raw(self) -> u64 {
+        self.into_raw()
+    }
+}
+impl super::Handle for Action {
+    fn from_raw(raw: u64) -> Self {
+        Self::from_raw(raw)
+    }
+    fn into_raw(self) -> u64 {
+        self.into_raw()
+    }
+}
+impl super::Handle for Swapchain {
+    fn from_raw(raw: u64) -> Self {
+        Self::from_raw(raw)
+    }
+    fn into_raw(self) -> u64 {
+        self.into_raw()
+    }
+}
+impl super::Handle for Space {
+    fn from_raw(raw: u64) -> Self {
+        Self::from_raw(raw)
+    }
+    fn into_raw(self) -> u64 {
+        self.into_raw()
+    }
+}
+impl super::Handle for DebugUtilsMessengerEXT {
+    fn from_raw(raw: u64) -> Self {
+        Self::from_raw(raw)
+    }
+    fn into_raw(self) -> u64 {
+        self.into_raw()
+    }
+}
+impl super::Handle for SpatialAnchorMSFT {
+    fn from_raw(raw: u64) -> Self {
+        Self::from_raw(raw)
+    }
+    fn into_raw(self) -> u64 {
+        self.into_raw()
+    }
+}
+impl super::Handle for HandTrackerEXT {
+    fn from_raw(raw: u64) -> Self {
+        Self::from_raw(raw)
+    }
+    fn into_raw(self) -> u64 {
+        self.into_raw()
+    }
+}
+impl super::Handle for FoveationProfileFB {
+    fn from_raw(raw: u64) -> Self {
+        Self::from_raw(raw)
+    }
+    fn into_raw(self) -> u64 {
+        self.into_raw()
+    }
+}
+impl super::Handle for TriangleMeshFB {
+    fn from_raw(raw: u64) -> Self {
+        Self::from_raw(raw)
+    }
+    fn into_raw(self) -> u64 {
+        self.into_raw()
+    }
+}
+impl super::Handle for PassthroughFB {
+    fn from_raw(raw: u64) -> Self {
+        Self::from_raw(raw)
+    }
+    fn into_raw(self) -> u64 {
+        self.into_raw()
+    }
+}
+impl super::Handle for PassthroughLayerFB {
+    fn from_raw(raw: u64) -> Self {
+        Self::from_raw(raw)
+    }
+    fn into_raw(self) -> u64 {
+        self.into_raw()
+    }
+}
+impl super::Handle for GeometryInstanceFB {
+    fn from_raw(raw: u64) -> Self {
+        Self::from_raw(raw)
+    }
+    fn into_raw(self) -> u64 {
+        self.into_raw()
+    }
+}
+impl super::Handle for FacialTrackerHTC {
+    fn from_raw(raw: u64) -> Self {
+        Self::from_raw(raw)
+    }
+    fn into_raw(self) -> u64 {
+        self.into_raw()
+    }
+}
+impl super::Handle for PassthroughHTC {
+    fn from_raw(raw: u64) -> Self {
+        Self::from_raw(raw)
+    }
+    fn into_raw(self) -> u64 {
+        self.into_raw()
+    }
+}
+impl super::Handle for FaceTrackerFB {
+    fn from_raw(raw: u64) -> Self {
+        Self::from_raw(raw)
+    }
+    fn into_raw(self) -> u64 {
+        self.into_raw()
+    }
+}
+impl super::Handle for BodyTrackerFB {
+    fn from_raw(raw: u64) -> Self {
+        Self::from_raw(raw)
+    }
+    fn into_raw(self) -> u64 {
+        self.into_raw()
+    }
+}
+impl super::Handle for EyeTrackerFB {
+    fn from_raw(raw: u64) -> Self {
+        Self::from_raw(raw)
+    }
+    fn into_raw(self) -> u64 {
+        self.into_raw()
+    }
+}
+impl super::Handle for SpaceUserFB {
+    fn from_raw(raw: u64) -> Self {
+        Self::from_raw(raw)
+    }
+    fn into_raw(self) -> u64 {
+        self.into_raw()
+    }
+}
+impl super::Handle for PassthroughColorLutMETA {
+    fn from_raw(raw: u64) -> Self {
+        Self::from_raw(raw)
+    }
+    fn into_raw(self) -> u64 {
+        self.into_raw()
+    }
+}
+impl super::Handle for PlaneDetectorEXT {
+    fn from_raw(raw: u64) -> Self {
+        Self::from_raw(raw)
+    }
+    fn into_raw(self) -> u64 {
+        self.into_raw()
+    }
+}
+impl super::Handle for VirtualKeyboardMETA {
+    fn from_raw(raw: u64) -> Self {
+        Self::from_raw(raw)
+    }
+    fn into_raw(self) -> u64 {
+        self.into_raw()
+    }
+}
+impl super::Handle for SpatialGraphNodeBindingMSFT {
+    fn from_raw(raw: u64) -> Self {
+        Self::from_raw(raw)
+    }
+    fn into_raw(self) -> u64 {
+        self.into_raw()
+    }
+}
+impl super::Handle for SceneObserverMSFT {
+    fn from_raw(raw: u64) -> Self {
+        Self::from_raw(raw)
+    }
+    fn into_raw(self) -> u64 {
+        self.into_raw()
+    }
+}
+impl super::Handle for SceneMSFT {
+    fn from_raw(raw: u64) -> Self {
+        Self::from_raw(raw)
+    }
+    fn into_raw(self) -> u64 {
+        self.into_raw()
+    }
+}
+impl super::Handle for SpatialAnchorStoreConnectionMSFT {
+    fn from_raw(raw: u64) -> Self {
+        Self::from_raw(raw)
+    }
+    fn into_raw(self) -> u64 {
+        self.into_raw()
+    }
+}
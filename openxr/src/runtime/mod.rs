@@ -0,0 +1,424 @@
+//! Framework for implementing OpenXR runtimes in Rust
+//!
+//! A runtime is a shared library the OpenXR loader hands application calls to once any API layers
+//! have seen them. To write one, implement [`Runtime`] and export it from a `cdylib` crate with
+//! [`export_runtime!`](crate::export_runtime); the loader finds it through a JSON manifest, which
+//! [`Manifest`] can generate. [`HandleTable`], [`find_in_chain`], [`write_array`] and
+//! [`write_str`] take care of the bookkeeping common to most commands.
+//!
+//! Available if the `runtime` feature is enabled.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use openxr::{runtime, sys};
+//!
+//! struct Instance {
+//!     application_name: String,
+//! }
+//!
+//! #[derive(Default)]
+//! struct Simulator {
+//!     instances: runtime::HandleTable<sys::Instance, Instance>,
+//! }
+//!
+//! impl runtime::Runtime for Simulator {
+//!     unsafe fn enumerate_instance_extension_properties(
+//!         &self,
+//!         _layer_name: *const std::os::raw::c_char,
+//!         property_capacity_input: u32,
+//!         property_count_output: *mut u32,
+//!         properties: *mut sys::ExtensionProperties,
+//!     ) -> sys::Result {
+//!         // No extensions are supported
+//!         runtime::write_array(&[], property_capacity_input, property_count_output, properties)
+//!     }
+//!
+//!     unsafe fn create_instance(
+//!         &self,
+//!         create_info: *const sys::InstanceCreateInfo,
+//!         instance: *mut sys::Instance,
+//!     ) -> sys::Result {
+//!         let app = &(*create_info).application_info;
+//!         let name = std::ffi::CStr::from_ptr(app.application_name.as_ptr());
+//!         *instance = self.instances.insert(Instance {
+//!             application_name: name.to_string_lossy().into_owned(),
+//!         });
+//!         sys::Result::SUCCESS
+//!     }
+//!
+//!     unsafe fn destroy_instance(&self, instance: sys::Instance) -> sys::Result {
+//!         match self.instances.remove(instance) {
+//!             Ok(_) => sys::Result::SUCCESS,
+//!             Err(e) => e,
+//!         }
+//!     }
+//! }
+//!
+//! openxr::export_runtime!(Simulator::default);
+//! ```
+
+use std::collections::HashMap;
+use std::ffi::{c_void, CStr};
+use std::marker::PhantomData;
+use std::os::raw::c_char;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, OnceLock, RwLock};
+use std::{fs, io, mem, ptr};
+
+use sys::loader::{
+    XrNegotiateLoaderInfo, XrNegotiateRuntimeRequest, CURRENT_LOADER_RUNTIME_VERSION,
+};
+use sys::pfn;
+
+use crate::loader_interface::{self, ManifestWriter};
+use crate::*;
+
+mod generated;
+pub use generated::Runtime;
+
+static RUNTIME: OnceLock<Box<dyn Runtime>> = OnceLock::new();
+
+/// Dispatch a command to the runtime
+fn call(f: impl FnOnce(&dyn Runtime) -> sys::Result) -> sys::Result {
+    let runtime = match RUNTIME.get() {
+        Some(x) => x,
+        None => return sys::Result::ERROR_RUNTIME_FAILURE,
+    };
+    // Unwinding into the application is undefined behavior
+    panic::catch_unwind(AssertUnwindSafe(|| f(&**runtime)))
+        .unwrap_or(sys::Result::ERROR_RUNTIME_FAILURE)
+}
+
+unsafe extern "system" fn get_instance_proc_addr(
+    instance: sys::Instance,
+    name: *const c_char,
+    function: *mut Option<pfn::VoidFunction>,
+) -> sys::Result {
+    if name.is_null() || function.is_null() {
+        return sys::Result::ERROR_VALIDATION_FAILURE;
+    }
+    let name = CStr::from_ptr(name);
+    let bytes = name.to_bytes();
+    *function = None;
+    if bytes == b"xrGetInstanceProcAddr" {
+        *function = Some(
+            mem::transmute::<pfn::GetInstanceProcAddr, pfn::VoidFunction>(get_instance_proc_addr),
+        );
+        return sys::Result::SUCCESS;
+    }
+    if bytes == b"xrEnumerateApiLayerProperties" {
+        *function = Some(mem::transmute::<
+            pfn::EnumerateApiLayerProperties,
+            pfn::VoidFunction,
+        >(enumerate_api_layer_properties));
+        return sys::Result::SUCCESS;
+    }
+    if instance == sys::Instance::NULL
+        && bytes != b"xrEnumerateInstanceExtensionProperties"
+        && bytes != b"xrCreateInstance"
+    {
+        return sys::Result::ERROR_HANDLE_INVALID;
+    }
+    if let Some(thunk) = generated::thunk(bytes) {
+        *function = Some(thunk);
+        return sys::Result::SUCCESS;
+    }
+    if instance == sys::Instance::NULL {
+        return sys::Result::ERROR_FUNCTION_UNSUPPORTED;
+    }
+    call(|runtime| {
+        *function = runtime.extension_proc_addr(instance, name);
+        match *function {
+            Some(_) => sys::Result::SUCCESS,
+            None => sys::Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    })
+}
+
+/// Layers are the loader's business, but answering lets the runtime be used without one
+unsafe extern "system" fn enumerate_api_layer_properties(
+    property_capacity_input: u32,
+    property_count_output: *mut u32,
+    properties: *mut sys::ApiLayerProperties,
+) -> sys::Result {
+    write_array_with(
+        0,
+        property_capacity_input,
+        property_count_output,
+        properties,
+        |_, _| {},
+    )
+}
+
+/// Implementation of `xrNegotiateLoaderRuntimeInterface`; use
+/// [`export_runtime!`](crate::export_runtime) instead
+#[doc(hidden)]
+pub unsafe fn negotiate(
+    loader_info: *const XrNegotiateLoaderInfo,
+    runtime_request: *mut XrNegotiateRuntimeRequest,
+    create: fn() -> Box<dyn Runtime>,
+) -> sys::Result {
+    if loader_info.is_null() || runtime_request.is_null() {
+        return sys::Result::ERROR_INITIALIZATION_FAILED;
+    }
+    if !loader_interface::loader_info_compatible(&*loader_info, CURRENT_LOADER_RUNTIME_VERSION) {
+        return sys::Result::ERROR_INITIALIZATION_FAILED;
+    }
+    let request = &mut *runtime_request;
+    if request.ty != XrNegotiateRuntimeRequest::TYPE
+        || request.struct_version != XrNegotiateRuntimeRequest::VERSION
+        || request.struct_size != mem::size_of::<XrNegotiateRuntimeRequest>()
+    {
+        return sys::Result::ERROR_INITIALIZATION_FAILED;
+    }
+
+    // The loader may negotiate more than once over the life of the process; the runtime outlives
+    // them all.
+    if panic::catch_unwind(|| RUNTIME.get_or_init(create)).is_err() {
+        return sys::Result::ERROR_INITIALIZATION_FAILED;
+    }
+    request.runtime_interface_version = CURRENT_LOADER_RUNTIME_VERSION;
+    request.runtime_api_version = CURRENT_API_VERSION;
+    request.get_instance_proc_addr = Some(get_instance_proc_addr);
+    sys::Result::SUCCESS
+}
+
+/// Export a runtime from a shared library
+///
+/// Defines the library's `xrNegotiateLoaderRuntimeInterface`, through which the loader finds the
+/// runtime. The argument is called once, when the loader first negotiates with the library, and
+/// returns the [`Runtime`] that will handle every command.
+///
+/// The crate must be built with `crate-type = ["cdylib"]`, and may export at most one runtime.
+///
+/// [`Runtime`]: crate::runtime::Runtime
+#[macro_export]
+macro_rules! export_runtime {
+    ($create:expr) => {
+        #[no_mangle]
+        #[allow(non_snake_case)]
+        pub unsafe extern "system" fn xrNegotiateLoaderRuntimeInterface(
+            loader_info: *const $crate::sys::loader::XrNegotiateLoaderInfo,
+            runtime_request: *mut $crate::sys::loader::XrNegotiateRuntimeRequest,
+        ) -> $crate::sys::Result {
+            fn create() -> ::std::boxed::Box<dyn $crate::runtime::Runtime> {
+                ::std::boxed::Box::new(($create)())
+            }
+            $crate::runtime::negotiate(loader_info, runtime_request, create)
+        }
+    };
+}
+
+/// Raw OpenXR handle types
+pub trait Handle: Copy + 'static {
+    fn from_raw(raw: u64) -> Self;
+    fn into_raw(self) -> u64;
+}
+
+/// Source of handle values, shared by every table so that no value is ever valid for two objects
+static NEXT_HANDLE: AtomicU64 = AtomicU64::new(1);
+
+/// Maps handles of type `H` to runtime objects of type `T`
+///
+/// Handle values are never reused, so a stale handle is reliably reported as invalid.
+pub struct HandleTable<H, T> {
+    objects: RwLock<HashMap<u64, Arc<T>>>,
+    _marker: PhantomData<fn(H) -> H>,
+}
+
+impl<H: Handle, T> HandleTable<H, T> {
+    pub fn new() -> Self {
+        Self {
+            objects: RwLock::new(HashMap::new()),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Store `value`, returning a new handle that refers to it
+    pub fn insert(&self, value: T) -> H {
+        let raw = NEXT_HANDLE.fetch_add(1, Ordering::Relaxed);
+        self.write().insert(raw, Arc::new(value));
+        H::from_raw(raw)
+    }
+
+    /// Look up the object `handle` refers to, or fail with `ERROR_HANDLE_INVALID`
+    pub fn get(&self, handle: H) -> Result<Arc<T>, sys::Result> {
+        self.read()
+            .get(&handle.into_raw())
+            .cloned()
+            .ok_or(sys::Result::ERROR_HANDLE_INVALID)
+    }
+
+    /// Invalidate `handle`, returning the object it referred to
+    pub fn remove(&self, handle: H) -> Result<Arc<T>, sys::Result> {
+        self.write()
+            .remove(&handle.into_raw())
+            .ok_or(sys::Result::ERROR_HANDLE_INVALID)
+    }
+
+    /// Invalidate the handles of all objects for which `f` returns `false`
+    ///
+    /// Useful for destroying child objects along with their parent.
+    pub fn retain(&self, mut f: impl FnMut(H, &T) -> bool) {
+        self.write().retain(|&raw, x| f(H::from_raw(raw), x));
+    }
+
+    /// Handles of every live object
+    pub fn handles(&self) -> Vec<H> {
+        self.read().keys().map(|&raw| H::from_raw(raw)).collect()
+    }
+
+    fn read(&self) -> std::sync::RwLockReadGuard<'_, HashMap<u64, Arc<T>>> {
+        self.objects.read().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn write(&self) -> std::sync::RwLockWriteGuard<'_, HashMap<u64, Arc<T>>> {
+        self.objects.write().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl<H: Handle, T> Default for HandleTable<H, T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Iterate over the structures chained from a `next` pointer
+///
+/// # Safety
+///
+/// `next` must be null or point to a valid structure chain.
+pub unsafe fn chain<'a>(next: *const c_void) -> impl Iterator<Item = &'a sys::BaseInStructure> {
+    let mut ptr = next as *const sys::BaseInStructure;
+    std::iter::from_fn(move || {
+        let x = ptr.as_ref()?;
+        ptr = x.next;
+        Some(x)
+    })
+}
+
+/// Find the structure of type `ty` chained from a `next` pointer
+///
+/// # Safety
+///
+/// `next` must be null or point to a valid structure chain, and `T` must be the structure
+/// identified by `ty`.
+pub unsafe fn find_in_chain<'a, T>(next: *const c_void, ty: sys::StructureType) -> Option<&'a T> {
+    chain(next)
+        .find(|x| x.ty == ty)
+        .map(|x| &*(x as *const sys::BaseInStructure as *const T))
+}
+
+/// Return `items` through the two-call idiom
+///
+/// # Safety
+///
+/// `count_output` must be null or valid for writes, and `out` must be null or valid for
+/// `capacity_input` writes.
+pub unsafe fn write_array<T: Copy>(
+    items: &[T],
+    capacity_input: u32,
+    count_output: *mut u32,
+    out: *mut T,
+) -> sys::Result {
+    write_array_with(items.len(), capacity_input, count_output, out, |i, x| {
+        *x = items[i]
+    })
+}
+
+/// Return `len` elements through the two-call idiom, filling in each with `f`
+///
+/// Elements are passed to `f` as the application initialized them, so structures' `ty` and
+/// `next` fields can be preserved.
+///
+/// # Safety
+///
+/// `count_output` must be null or valid for writes, and `out` must be null or valid for
+/// `capacity_input` reads and writes.
+pub unsafe fn write_array_with<T>(
+    len: usize,
+    capacity_input: u32,
+    count_output: *mut u32,
+    out: *mut T,
+    mut f: impl FnMut(usize, &mut T),
+) -> sys::Result {
+    if count_output.is_null() {
+        return sys::Result::ERROR_VALIDATION_FAILURE;
+    }
+    *count_output = len as u32;
+    if capacity_input == 0 {
+        return sys::Result::SUCCESS;
+    }
+    if (capacity_input as usize) < len {
+        return sys::Result::ERROR_SIZE_INSUFFICIENT;
+    }
+    if out.is_null() {
+        return sys::Result::ERROR_VALIDATION_FAILURE;
+    }
+    for i in 0..len {
+        f(i, &mut *out.add(i));
+    }
+    sys::Result::SUCCESS
+}
+
+/// Return `s` through the two-call idiom, as a null-terminated string
+///
+/// # Safety
+///
+/// `count_output` must be null or valid for writes, and `out` must be null or valid for
+/// `capacity_input` writes.
+pub unsafe fn write_str(
+    s: &str,
+    capacity_input: u32,
+    count_output: *mut u32,
+    out: *mut c_char,
+) -> sys::Result {
+    let len = s.len() + 1;
+    let result = write_array_with(len, capacity_input, count_output, out, |_, _| {});
+    if result == sys::Result::SUCCESS && capacity_input != 0 {
+        ptr::copy_nonoverlapping(s.as_ptr() as *const c_char, out, s.len());
+        *out.add(s.len()) = 0;
+    }
+    result
+}
+
+/// Description of a runtime for the loader
+///
+/// The loader uses the runtime named by the manifest at `XR_RUNTIME_JSON`, or else the system's
+/// active runtime manifest. See the [loader
+/// documentation](https://registry.khronos.org/OpenXR/specs/1.0/loader.html#runtime-manifest-file-format)
+/// for where that is found.
+#[derive(Debug, Clone)]
+pub struct Manifest {
+    /// Path to the shared library, absolute or relative to the manifest
+    pub library_path: PathBuf,
+    /// Human-readable name of the runtime
+    pub name: Option<String>,
+}
+
+impl Manifest {
+    pub fn new(library_path: impl Into<PathBuf>) -> Self {
+        Self {
+            library_path: library_path.into(),
+            name: None,
+        }
+    }
+
+    /// Serialize in the loader's manifest format
+    pub fn to_json(&self) -> String {
+        let mut out = ManifestWriter::new("runtime");
+        out.string("library_path", &self.library_path.to_string_lossy());
+        if let Some(ref name) = self.name {
+            out.string("name", name);
+        }
+        out.finish()
+    }
+
+    /// Write the manifest to `path`
+    pub fn write(&self, path: impl AsRef<std::path::Path>) -> io::Result<()> {
+        fs::write(path, self.to_json())
+    }
+}
@@ -1,5 +1,6 @@
 use crate::*;
 
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub struct SecondaryViewState {
     pub ty: ViewConfigurationType,
     pub active: bool,
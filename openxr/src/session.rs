@@ -1,5 +1,10 @@
+use std::ffi::CString;
 use std::mem::MaybeUninit;
-use std::{marker::PhantomData, ptr, sync::Arc};
+use std::{
+    marker::PhantomData,
+    ptr,
+    sync::{Arc, Mutex},
+};
 
 use crate::*;
 
@@ -32,6 +37,32 @@ impl<G> Session<G> {
         self.instance().set_name_raw(self.as_raw().into_raw(), name)
     }
 
+    /// Open a region of the session's timeline labeled `name`, if `XR_EXT_debug_utils` is loaded
+    ///
+    /// The region closes when the returned guard is dropped. Regions nest, and must be closed in
+    /// the reverse of the order they were opened in. Without the extension, this does nothing.
+    #[inline]
+    pub fn begin_label_region(&self, name: &str) -> Result<LabelRegion<'_>> {
+        LabelRegion::begin(&self.inner, name)
+    }
+
+    /// Mark the current point of the session's timeline with `name`, if `XR_EXT_debug_utils` is
+    /// loaded
+    #[inline]
+    pub fn insert_label(&self, name: &str) -> Result<()> {
+        if let Some(fp) = self.instance().exts().ext_debug_utils.as_ref() {
+            let name = CString::new(name).unwrap();
+            let label = debug_utils::label(&name);
+            unsafe {
+                cvt(
+                    "xrSessionInsertDebugUtilsLabelEXT",
+                    (fp.session_insert_debug_utils_label)(self.as_raw(), &label),
+                )?;
+            }
+        }
+        Ok(())
+    }
+
     /// Request that the runtime show the application's rendered output to the user
     #[inline]
     pub fn begin(&self, ty: ViewConfigurationType) -> Result<sys::Result> {
@@ -40,7 +71,12 @@ impl<G> Session<G> {
             next: ptr::null(),
             primary_view_configuration_type: ty,
         };
-        unsafe { cvt((self.fp().begin_session)(self.as_raw(), &info)) }
+        unsafe {
+            cvt(
+                "xrBeginSession",
+                (self.fp().begin_session)(self.as_raw(), &info),
+            )
+        }
     }
 
     /// Request that the runtime show the application's rendered output to the user,
@@ -62,14 +98,22 @@ impl<G> Session<G> {
             next: &s as *const _ as *const _,
             primary_view_configuration_type: ty,
         };
-        unsafe { cvt((self.fp().begin_session)(self.as_raw(), &info)) }
+        unsafe {
+            cvt(
+                "xrBeginSession",
+                (self.fp().begin_session)(self.as_raw(), &info),
+            )
+        }
     }
 
     /// Request a transition to `SessionState::STOPPING` so that `end` may be called.
     #[inline]
     pub fn request_exit(&self) -> Result<()> {
         unsafe {
-            cvt((self.fp().request_exit_session)(self.as_raw()))?;
+            cvt(
+                "xrRequestExitSession",
+                (self.fp().request_exit_session)(self.as_raw()),
+            )?;
         }
         Ok(())
     }
@@ -79,18 +123,17 @@ impl<G> Session<G> {
     /// See `request_exit` for active sessions.
     #[inline]
     pub fn end(&self) -> Result<sys::Result> {
-        unsafe { cvt((self.fp().end_session)(self.as_raw())) }
+        unsafe { cvt("xrEndSession", (self.fp().end_session)(self.as_raw())) }
     }
 
     #[inline]
     pub fn reference_space_bounds_rect(&self, ty: ReferenceSpaceType) -> Result<Option<Extent2Df>> {
         unsafe {
             let mut out = MaybeUninit::uninit();
-            let status = cvt((self.fp().get_reference_space_bounds_rect)(
-                self.as_raw(),
-                ty,
-                out.as_mut_ptr(),
-            ))?;
+            let status = cvt(
+                "xrGetReferenceSpaceBoundsRect",
+                (self.fp().get_reference_space_bounds_rect)(self.as_raw(), ty, out.as_mut_ptr()),
+            )?;
             Ok(if status == sys::Result::SPACE_BOUNDS_UNAVAILABLE {
                 None
             } else {
@@ -104,7 +147,7 @@ impl<G> Session<G> {
     /// Constant for the lifetime of the session.
     #[inline]
     pub fn enumerate_reference_spaces(&self) -> Result<Vec<ReferenceSpaceType>> {
-        get_arr(|cap, count, buf| unsafe {
+        get_arr("xrEnumerateReferenceSpaces", |cap, count, buf| unsafe {
             (self.fp().enumerate_reference_spaces)(self.as_raw(), cap, count, buf)
         })
     }
@@ -123,11 +166,10 @@ impl<G> Session<G> {
         };
         let mut out = sys::Space::NULL;
         unsafe {
-            cvt((self.fp().create_reference_space)(
-                self.as_raw(),
-                &info,
-                &mut out,
-            ))?;
+            cvt(
+                "xrCreateReferenceSpace",
+                (self.fp().create_reference_space)(self.as_raw(), &info, &mut out),
+            )?;
             Ok(Space::reference_from_raw(self.clone(), out))
         }
     }
@@ -152,16 +194,20 @@ impl<G> Session<G> {
         };
         let (flags, raw) = unsafe {
             let mut out = sys::ViewState::out(ptr::null_mut());
-            let raw = get_arr_init(sys::View::out(ptr::null_mut()), |cap, count, buf| {
-                (self.fp().locate_views)(
-                    self.as_raw(),
-                    &info,
-                    out.as_mut_ptr(),
-                    cap,
-                    count,
-                    buf as _,
-                )
-            })?;
+            let raw = get_arr_init(
+                "xrLocateViews",
+                sys::View::out(ptr::null_mut()),
+                |cap, count, buf| {
+                    (self.fp().locate_views)(
+                        self.as_raw(),
+                        &info,
+                        out.as_mut_ptr(),
+                        cap,
+                        count,
+                        buf as _,
+                    )
+                },
+            )?;
             (out.assume_init().view_state_flags, raw)
         };
         Ok((
@@ -172,6 +218,122 @@ impl<G> Session<G> {
         ))
     }
 
+    /// Determine the locations of several spaces relative to a base space at a specified time
+    ///
+    /// Requires OpenXR 1.1 or XR_KHR_locate_spaces. Fails with `ERROR_FUNCTION_UNSUPPORTED`
+    /// otherwise.
+    #[inline]
+    pub fn locate_spaces(
+        &self,
+        spaces: &[&Space],
+        base: &Space,
+        time: Time,
+    ) -> Result<Vec<SpaceLocation>> {
+        Ok(self.locate_spaces_inner(spaces, base, time, false)?.0)
+    }
+
+    /// Determine the locations and velocities of several spaces relative to a base space at a
+    /// specified time
+    ///
+    /// Requires OpenXR 1.1 or XR_KHR_locate_spaces. Fails with `ERROR_FUNCTION_UNSUPPORTED`
+    /// otherwise.
+    #[inline]
+    pub fn relate_spaces(
+        &self,
+        spaces: &[&Space],
+        base: &Space,
+        time: Time,
+    ) -> Result<Vec<(SpaceLocation, SpaceVelocity)>> {
+        let (locations, velocities) = self.locate_spaces_inner(spaces, base, time, true)?;
+        Ok(locations.into_iter().zip(velocities).collect())
+    }
+
+    fn locate_spaces_inner(
+        &self,
+        spaces: &[&Space],
+        base: &Space,
+        time: Time,
+        velocities: bool,
+    ) -> Result<(Vec<SpaceLocation>, Vec<SpaceVelocity>)> {
+        // These asserts allow this function to be safe.
+        for space in spaces.iter().copied().chain(Some(base)) {
+            assert!(
+                Arc::ptr_eq(&self.inner, &space.session),
+                "spaces must have been created, allocated, or retrieved from this `Session`"
+            );
+        }
+        if spaces.is_empty() {
+            return Ok((Vec::new(), Vec::new()));
+        }
+        let locate_spaces = self
+            .inner
+            .instance
+            .locate_spaces_fp()
+            .ok_or(sys::Result::ERROR_FUNCTION_UNSUPPORTED)?;
+        let handles = spaces.iter().map(|x| x.as_raw()).collect::<Vec<_>>();
+        let info = sys::SpacesLocateInfo {
+            ty: sys::SpacesLocateInfo::TYPE,
+            next: ptr::null(),
+            base_space: base.as_raw(),
+            time,
+            space_count: handles.len() as u32,
+            spaces: handles.as_ptr(),
+        };
+        let mut raw_velocities =
+            Vec::<MaybeUninit<sys::SpaceVelocityData>>::with_capacity(handles.len());
+        let mut velocity_info = sys::SpaceVelocities {
+            ty: sys::SpaceVelocities::TYPE,
+            next: ptr::null_mut(),
+            velocity_count: handles.len() as u32,
+            velocities: raw_velocities.as_mut_ptr() as _,
+        };
+        let mut raw_locations =
+            Vec::<MaybeUninit<sys::SpaceLocationData>>::with_capacity(handles.len());
+        let mut location_info = sys::SpaceLocations {
+            ty: sys::SpaceLocations::TYPE,
+            next: if velocities {
+                &mut velocity_info as *mut _ as _
+            } else {
+                ptr::null_mut()
+            },
+            location_count: handles.len() as u32,
+            locations: raw_locations.as_mut_ptr() as _,
+        };
+        unsafe {
+            cvt(
+                "xrLocateSpaces",
+                locate_spaces(self.as_raw(), &info, &mut location_info),
+            )?;
+            raw_locations.set_len(handles.len());
+            let locations = raw_locations
+                .iter()
+                .map(|x| {
+                    let ptr = x.as_ptr();
+                    SpaceLocation::from_parts(
+                        *ptr::addr_of!((*ptr).location_flags),
+                        ptr::addr_of!((*ptr).pose),
+                    )
+                })
+                .collect();
+            if !velocities {
+                return Ok((locations, Vec::new()));
+            }
+            raw_velocities.set_len(handles.len());
+            let velocities = raw_velocities
+                .iter()
+                .map(|x| {
+                    let ptr = x.as_ptr();
+                    SpaceVelocity::from_parts(
+                        *ptr::addr_of!((*ptr).velocity_flags),
+                        ptr::addr_of!((*ptr).linear_velocity),
+                        ptr::addr_of!((*ptr).angular_velocity),
+                    )
+                })
+                .collect();
+            Ok((locations, velocities))
+        }
+    }
+
     /// Get the suggested interaction profile in use for a top level user path
     ///
     /// May be NULL.
@@ -179,11 +341,14 @@ impl<G> Session<G> {
     pub fn current_interaction_profile(&self, top_level_user_path: Path) -> Result<Path> {
         unsafe {
             let mut out = sys::InteractionProfileState::out(ptr::null_mut());
-            cvt((self.fp().get_current_interaction_profile)(
-                self.as_raw(),
-                top_level_user_path,
-                out.as_mut_ptr(),
-            ))?;
+            cvt(
+                "xrGetCurrentInteractionProfile",
+                (self.fp().get_current_interaction_profile)(
+                    self.as_raw(),
+                    top_level_user_path,
+                    out.as_mut_ptr(),
+                ),
+            )?;
             Ok(out.assume_init().interaction_profile)
         }
     }
@@ -201,7 +366,10 @@ impl<G> Session<G> {
             action_sets: sets.as_ptr(),
         };
         unsafe {
-            cvt((self.fp().attach_session_action_sets)(self.as_raw(), &info))?;
+            cvt(
+                "xrAttachSessionActionSets",
+                (self.fp().attach_session_action_sets)(self.as_raw(), &info),
+            )?;
         }
         Ok(())
     }
@@ -216,7 +384,10 @@ impl<G> Session<G> {
             active_action_sets: action_sets.as_ptr() as _,
         };
         unsafe {
-            cvt((self.fp().sync_actions)(self.as_raw(), &info))?;
+            cvt(
+                "xrSyncActions",
+                (self.fp().sync_actions)(self.as_raw(), &info),
+            )?;
         }
         Ok(())
     }
@@ -234,7 +405,7 @@ impl<G> Session<G> {
             source_path: source,
             which_components,
         };
-        get_str(|cap, count, buf| unsafe {
+        get_str("xrGetInputSourceLocalizedName", |cap, count, buf| unsafe {
             (self.fp().get_input_source_localized_name)(self.as_raw(), &info, cap, count, buf)
         })
     }
@@ -262,13 +433,16 @@ impl<G> Session<G> {
             indices: ptr::null_mut(),
         };
         unsafe {
-            cvt((self.instance().visibility_mask().get_visibility_mask)(
-                self.as_raw(),
-                view_configuration_type,
-                view_index,
-                visibility_mask_type,
-                &mut info,
-            ))?;
+            cvt(
+                "xrGetVisibilityMaskKHR",
+                (self.instance().visibility_mask().get_visibility_mask)(
+                    self.as_raw(),
+                    view_configuration_type,
+                    view_index,
+                    visibility_mask_type,
+                    &mut info,
+                ),
+            )?;
             let mut out = VisibilityMask {
                 vertices: Vec::with_capacity(info.vertex_count_output as usize),
                 indices: Vec::with_capacity(info.index_count_output as usize),
@@ -278,19 +452,22 @@ impl<G> Session<G> {
                 info.indices = out.indices.as_mut_ptr();
                 info.vertex_capacity_input = out.vertices.capacity() as u32;
                 info.index_capacity_input = out.indices.capacity() as u32;
-                match cvt((self.instance().visibility_mask().get_visibility_mask)(
-                    self.as_raw(),
-                    view_configuration_type,
-                    view_index,
-                    visibility_mask_type,
-                    &mut info,
-                )) {
+                match cvt(
+                    "xrGetVisibilityMaskKHR",
+                    (self.instance().visibility_mask().get_visibility_mask)(
+                        self.as_raw(),
+                        view_configuration_type,
+                        view_index,
+                        visibility_mask_type,
+                        &mut info,
+                    ),
+                ) {
                     Ok(_) => {
                         out.vertices.set_len(info.vertex_count_output as usize);
                         out.indices.set_len(info.index_count_output as usize);
                         return Ok(out);
                     }
-                    Err(sys::Result::ERROR_SIZE_INSUFFICIENT) => {
+                    Err(e) if e == sys::Result::ERROR_SIZE_INSUFFICIENT => {
                         out.vertices.reserve(
                             (info.vertex_count_output as usize)
                                 .saturating_sub(out.vertices.capacity()),
@@ -327,7 +504,7 @@ impl<G> Session<G> {
             .fb_color_space
             .as_ref()
             .expect("FB_color_space not loaded");
-        get_arr(|cap, count, buf| unsafe {
+        get_arr("xrEnumerateColorSpacesFB", |cap, count, buf| unsafe {
             (ext.enumerate_color_spaces)(self.as_raw(), cap, count, buf)
         })
     }
@@ -344,7 +521,10 @@ impl<G> Session<G> {
             .as_ref()
             .expect("FB_color_space not loaded");
         unsafe {
-            cvt((ext.set_color_space)(self.as_raw(), color_space))?;
+            cvt(
+                "xrSetColorSpaceFB",
+                (ext.set_color_space)(self.as_raw(), color_space),
+            )?;
         }
         Ok(())
     }
@@ -379,12 +559,25 @@ impl<G: Graphics> Session<G> {
         instance: Instance,
         handle: sys::Session,
         drop_guard: DropGuard,
+    ) -> (Self, FrameWaiter, FrameStream<G>) {
+        Self::from_raw_inner(instance, handle, drop_guard, None)
+    }
+
+    /// Refer to [`Session::from_raw`]. `backend` records the API a [`Dynamic`] session was created
+    /// with.
+    pub(crate) unsafe fn from_raw_inner(
+        instance: Instance,
+        handle: sys::Session,
+        drop_guard: DropGuard,
+        backend: Option<dynamic::Backend>,
     ) -> (Self, FrameWaiter, FrameStream<G>) {
         let session = Self {
             inner: Arc::new(SessionInner {
                 instance,
                 handle,
                 _drop_guard: drop_guard,
+                label_depth: Mutex::new(0),
+                backend,
             }),
             _marker: PhantomData,
         };
@@ -401,18 +594,33 @@ impl<G: Graphics> Session<G> {
     /// created.
     #[inline]
     pub fn enumerate_swapchain_formats(&self) -> Result<Vec<G::Format>> {
-        let raw = get_arr(|capacity, count, buf| unsafe {
-            (self.fp().enumerate_swapchain_formats)(self.as_raw(), capacity, count, buf)
-        })?;
+        let raw = get_arr(
+            "xrEnumerateSwapchainFormats",
+            |capacity, count, buf| unsafe {
+                (self.fp().enumerate_swapchain_formats)(self.as_raw(), capacity, count, buf)
+            },
+        )?;
         Ok(raw.into_iter().map(G::raise_format).collect())
     }
+}
 
+impl<G: RendersFrames> Session<G> {
     #[inline]
     pub fn create_swapchain(&self, info: &SwapchainCreateInfo<G>) -> Result<Swapchain<G>> {
+        self.create_swapchain_with_next(info, NextChain::new())
+    }
+
+    /// Create a swapchain, passing extension structures such as
+    /// [`SwapchainCreateInfoFoveationFB`] along with `info`
+    pub fn create_swapchain_with_next(
+        &self,
+        info: &SwapchainCreateInfo<G>,
+        next: NextChain<'_, SwapchainCreateInfo<G>>,
+    ) -> Result<Swapchain<G>> {
         let mut out = sys::Swapchain::NULL;
         let info = sys::SwapchainCreateInfo {
             ty: sys::SwapchainCreateInfo::TYPE,
-            next: ptr::null(),
+            next: next.as_ptr(),
             create_flags: info.create_flags,
             usage_flags: info.usage_flags,
             format: G::lower_format(info.format),
@@ -424,11 +632,81 @@ impl<G: Graphics> Session<G> {
             mip_count: info.mip_count,
         };
         unsafe {
-            cvt((self.fp().create_swapchain)(self.as_raw(), &info, &mut out))?;
+            cvt(
+                "xrCreateSwapchain",
+                (self.fp().create_swapchain)(self.as_raw(), &info, &mut out),
+            )?;
             Ok(Swapchain::from_raw(self.clone(), out))
         }
     }
 
+    /// Create a depth swapchain to accompany a color swapchain created from `color`
+    ///
+    /// The depth swapchain has the same dimensions, sample count and layers as the color swapchain,
+    /// and the supported depth format best matching `preferences`, as chosen by
+    /// [`FormatPreferences::select`], which is returned alongside it. Fails with
+    /// `ERROR_SWAPCHAIN_FORMAT_UNSUPPORTED` if the runtime supports no depth format.
+    ///
+    /// Depth is submitted by pushing a [`CompositionLayerDepthInfoKHR`] onto each
+    /// [`CompositionLayerProjectionView`], which requires [`XR_KHR_composition_layer_depth`].
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # fn dummy<G: openxr::RendersFrames>(
+    /// #     session: &openxr::Session<G>,
+    /// #     color_info: &openxr::SwapchainCreateInfo<G>,
+    /// #     color: openxr::ReleasedImage<'_, G>,
+    /// #     view: openxr::View,
+    /// #     rect: openxr::Rect2Di,
+    /// # ) -> openxr::Result<()> {
+    /// let (mut depth, _format) =
+    ///     session.create_depth_swapchain(color_info, &openxr::FormatPreferences::default())?;
+    /// let mut depth_image = depth.acquire_image()?;
+    /// depth_image.wait(openxr::Duration::INFINITE)?;
+    /// // render depth...
+    /// let depth_image = depth_image.release()?;
+    /// let mut depth_info = openxr::CompositionLayerDepthInfoKHR::new()
+    ///     .sub_image(depth_image.sub_image().image_rect(rect))
+    ///     .min_depth(0.0)
+    ///     .max_depth(1.0)
+    ///     .near_z(0.05)
+    ///     .far_z(100.0);
+    /// let projection_view = openxr::CompositionLayerProjectionView::new()
+    ///     .pose(view.pose)
+    ///     .fov(view.fov)
+    ///     .sub_image(color.sub_image().image_rect(rect))
+    ///     .push_next(&mut depth_info);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`XR_KHR_composition_layer_depth`]: https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_KHR_composition_layer_depth
+    pub fn create_depth_swapchain(
+        &self,
+        color: &SwapchainCreateInfo<G>,
+        preferences: &FormatPreferences,
+    ) -> Result<(Swapchain<G>, G::Format)> {
+        let format = self
+            .select_swapchain_formats(preferences)?
+            .depth
+            .ok_or(sys::Result::ERROR_SWAPCHAIN_FORMAT_UNSUPPORTED)?;
+        let swapchain = self.create_swapchain(&SwapchainCreateInfo {
+            create_flags: color.create_flags,
+            usage_flags: SwapchainUsageFlags::DEPTH_STENCIL_ATTACHMENT,
+            format,
+            sample_count: color.sample_count,
+            width: color.width,
+            height: color.height,
+            face_count: color.face_count,
+            array_size: color.array_size,
+            mip_count: 1,
+        })?;
+        Ok((swapchain, format))
+    }
+}
+
+impl<G: Graphics> Session<G> {
     #[inline]
     /// Create a [`Passthrough`].
     ///
@@ -477,6 +755,11 @@ pub(crate) struct SessionInner {
     pub(crate) instance: Instance,
     pub(crate) handle: sys::Session,
     pub(crate) _drop_guard: DropGuard,
+    /// Number of open label regions
+    pub(crate) label_depth: Mutex<u32>,
+    /// API the session was created with, if created as a [`Dynamic`] session, whose formats and
+    /// images are interpreted accordingly
+    pub(crate) backend: Option<dynamic::Backend>,
 }
 
 impl Drop for SessionInner {
@@ -515,14 +798,16 @@ impl View {
         let ptr = raw.as_ptr();
         Self {
             pose: Posef {
-                orientation: flags
-                    .contains(sys::ViewStateFlags::ORIENTATION_VALID)
-                    .then(|| *ptr::addr_of!((*ptr).pose.orientation))
-                    .unwrap_or_default(),
-                position: flags
-                    .contains(sys::ViewStateFlags::POSITION_VALID)
-                    .then(|| *ptr::addr_of!((*ptr).pose.position))
-                    .unwrap_or_default(),
+                orientation: if flags.contains(sys::ViewStateFlags::ORIENTATION_VALID) {
+                    *ptr::addr_of!((*ptr).pose.orientation)
+                } else {
+                    Default::default()
+                },
+                position: if flags.contains(sys::ViewStateFlags::POSITION_VALID) {
+                    *ptr::addr_of!((*ptr).pose.position)
+                } else {
+                    Default::default()
+                },
             },
             fov: *ptr::addr_of!((*ptr).fov),
         }
@@ -573,22 +858,23 @@ impl FrameWaiter {
     }
 
     /// Block until rendering should begin, and return details to guide rendering
+    ///
+    /// The returned token must be passed to [`FrameStream::begin`] to render the frame.
     #[inline]
-    pub fn wait(&mut self) -> Result<FrameState> {
+    pub fn wait(&mut self) -> Result<FrameToken> {
         let out = unsafe {
             let mut x = sys::FrameState::out(ptr::null_mut());
-            cvt((self.session.instance.fp().wait_frame)(
-                self.session.handle,
-                ptr::null(),
-                x.as_mut_ptr(),
-            ))?;
+            cvt(
+                "xrWaitFrame",
+                (self.session.instance.fp().wait_frame)(
+                    self.session.handle,
+                    ptr::null(),
+                    x.as_mut_ptr(),
+                ),
+            )?;
             x.assume_init()
         };
-        Ok(FrameState {
-            predicted_display_time: out.predicted_display_time,
-            predicted_display_period: out.predicted_display_period,
-            should_render: out.should_render.into(),
-        })
+        Ok(self.token(&out))
     }
 
     /// Same as .wait() but also returns whether each secondary view is active
@@ -599,7 +885,7 @@ impl FrameWaiter {
     pub fn wait_secondary_multiple(
         &mut self,
         count: u32,
-    ) -> Result<(FrameState, Vec<SecondaryViewState>)> {
+    ) -> Result<(FrameToken, Vec<SecondaryViewState>)> {
         let mut vec = Vec::new();
         vec.resize(
             count as usize,
@@ -610,11 +896,14 @@ impl FrameWaiter {
             (*secondary.as_mut_ptr()).view_configuration_count = count;
             (*secondary.as_mut_ptr()).view_configuration_states = vec.as_mut_ptr() as *mut _;
             let mut x = sys::FrameState::out(&mut secondary as *mut _ as *mut _);
-            cvt((self.session.instance.fp().wait_frame)(
-                self.session.handle,
-                ptr::null(),
-                x.as_mut_ptr(),
-            ))?;
+            cvt(
+                "xrWaitFrame",
+                (self.session.instance.fp().wait_frame)(
+                    self.session.handle,
+                    ptr::null(),
+                    x.as_mut_ptr(),
+                ),
+            )?;
             x.assume_init()
         };
         let secondary = vec
@@ -627,14 +916,7 @@ impl FrameWaiter {
                 }
             })
             .collect();
-        Ok((
-            FrameState {
-                predicted_display_time: out.predicted_display_time,
-                predicted_display_period: out.predicted_display_period,
-                should_render: out.should_render.into(),
-            },
-            secondary,
-        ))
+        Ok((self.token(&out), secondary))
     }
 
     /// Same as .wait() but also returns whether the secondary view is active,
@@ -643,7 +925,7 @@ impl FrameWaiter {
     ///
     /// There must only be a single enabled secondary view
     #[inline]
-    pub fn wait_secondary(&mut self) -> Result<(FrameState, SecondaryViewState)> {
+    pub fn wait_secondary(&mut self) -> Result<(FrameToken, SecondaryViewState)> {
         let mut state = [sys::SecondaryViewConfigurationStateMSFT::out(
             ptr::null_mut(),
         )];
@@ -652,11 +934,14 @@ impl FrameWaiter {
             (*secondary.as_mut_ptr()).view_configuration_count = 1;
             (*secondary.as_mut_ptr()).view_configuration_states = state.as_mut_ptr() as *mut _;
             let mut x = sys::FrameState::out(&mut secondary as *mut _ as *mut _);
-            cvt((self.session.instance.fp().wait_frame)(
-                self.session.handle,
-                ptr::null(),
-                x.as_mut_ptr(),
-            ))?;
+            cvt(
+                "xrWaitFrame",
+                (self.session.instance.fp().wait_frame)(
+                    self.session.handle,
+                    ptr::null(),
+                    x.as_mut_ptr(),
+                ),
+            )?;
             x.assume_init()
         };
         let state = unsafe { state[0].assume_init() };
@@ -664,14 +949,18 @@ impl FrameWaiter {
             ty: state.view_configuration_type,
             active: state.active.into(),
         };
-        Ok((
-            FrameState {
+        Ok((self.token(&out), state))
+    }
+
+    fn token(&self, out: &sys::FrameState) -> FrameToken {
+        FrameToken {
+            state: FrameState {
                 predicted_display_time: out.predicted_display_time,
                 predicted_display_period: out.predicted_display_period,
                 should_render: out.should_render.into(),
             },
-            state,
-        ))
+            session: self.session.handle,
+        }
     }
 }
 
@@ -682,3 +971,78 @@ pub struct FrameState {
     pub predicted_display_period: Duration,
     pub should_render: bool,
 }
+
+/// A frame that has been waited for, to be begun by [`FrameStream::begin`]
+///
+/// Dereferences to the [`FrameState`] reported by [`FrameWaiter`]. Tokens can be sent to the
+/// thread that owns the `FrameStream`, and since they can't be copied, each frame is begun at most
+/// once.
+#[derive(Debug)]
+#[must_use = "frames must be begun to be rendered"]
+pub struct FrameToken {
+    state: FrameState,
+    session: sys::Session,
+}
+
+impl FrameToken {
+    #[inline]
+    pub fn state(&self) -> FrameState {
+        self.state
+    }
+
+    /// The session whose frame loop produced this token
+    #[inline]
+    pub fn session(&self) -> sys::Session {
+        self.session
+    }
+}
+
+impl std::ops::Deref for FrameToken {
+    type Target = FrameState;
+
+    #[inline]
+    fn deref(&self) -> &FrameState {
+        &self.state
+    }
+}
+
+/// A frame that has been begun, to be submitted by [`FrameStream::end`]
+///
+/// Dereferences to the [`FrameState`] of the frame. The display time passed to `xrEndFrame` is
+/// always the predicted display time of the same frame, and since tokens can't be copied, each
+/// frame is ended at most once.
+#[derive(Debug)]
+#[must_use = "frames must be ended to be displayed"]
+pub struct BegunFrame {
+    state: FrameState,
+    session: sys::Session,
+}
+
+impl BegunFrame {
+    pub(crate) fn new(token: FrameToken) -> Self {
+        Self {
+            state: token.state,
+            session: token.session,
+        }
+    }
+
+    #[inline]
+    pub fn state(&self) -> FrameState {
+        self.state
+    }
+
+    /// The session whose frame loop produced this token
+    #[inline]
+    pub fn session(&self) -> sys::Session {
+        self.session
+    }
+}
+
+impl std::ops::Deref for BegunFrame {
+    type Target = FrameState;
+
+    #[inline]
+    fn deref(&self) -> &FrameState {
+        &self.state
+    }
+}
@@ -0,0 +1,253 @@
+use crate::*;
+
+/// Drives a session through its lifecycle in response to events
+///
+/// Consumes events from [`Instance::poll_event`], tracks the [`SessionState`] of one session, and
+/// calls [`Session::begin`] and [`Session::end`] when the runtime asks for them. After each poll,
+/// the returned [`FrameLoopStatus`] tells the frame loop whether to run.
+///
+/// Lost events are counted but can't be replayed. If a lost event was a state change, the session
+/// may already be running when `READY` is reported again, or already stopped when `STOPPING` is.
+/// The resulting `ERROR_SESSION_RUNNING` and `ERROR_SESSION_NOT_RUNNING` failures are taken as
+/// confirmation of the actual state rather than reported as errors.
+///
+/// # Example
+///
+/// ```no_run
+/// # fn dummy<G: openxr::RendersFrames>(
+/// #     session: openxr::Session<G>,
+/// #     frame_waiter: &mut openxr::FrameWaiter,
+/// #     frame_stream: &mut openxr::FrameStream<G>,
+/// # ) {
+/// use openxr::FrameLoopStatus;
+///
+/// let mut driver =
+///     openxr::SessionDriver::new(session, openxr::ViewConfigurationType::PRIMARY_STEREO);
+/// loop {
+///     match driver.poll().unwrap() {
+///         FrameLoopStatus::Idle => {
+///             std::thread::sleep(std::time::Duration::from_millis(100));
+///             continue;
+///         }
+///         FrameLoopStatus::Running => {}
+///         FrameLoopStatus::Exit(_) => break,
+///     }
+///     let frame = frame_waiter.wait().unwrap();
+///     let frame = frame_stream.begin(frame).unwrap();
+///     // render if frame.should_render...
+///     frame_stream
+///         .end(frame, openxr::EnvironmentBlendMode::OPAQUE, &[])
+///         .unwrap();
+/// }
+/// # }
+/// ```
+pub struct SessionDriver<G> {
+    session: Session<G>,
+    view_configuration_type: ViewConfigurationType,
+    buffer: EventDataBuffer,
+    tracker: Tracker,
+}
+
+impl<G> SessionDriver<G> {
+    /// Drive `session`, beginning it with `view_configuration_type` once it's ready
+    ///
+    /// `session` must not have been begun yet.
+    pub fn new(session: Session<G>, view_configuration_type: ViewConfigurationType) -> Self {
+        Self {
+            session,
+            view_configuration_type,
+            buffer: EventDataBuffer::new(),
+            tracker: Tracker {
+                state: SessionState::UNKNOWN,
+                running: false,
+                exit_requested: false,
+                exit: None,
+                lost_events: 0,
+            },
+        }
+    }
+
+    /// The driven session
+    #[inline]
+    pub fn session(&self) -> &Session<G> {
+        &self.session
+    }
+
+    /// The most recently reported state of the session
+    #[inline]
+    pub fn state(&self) -> SessionState {
+        self.tracker.state
+    }
+
+    /// Whether the session has been begun and not yet ended
+    #[inline]
+    pub fn is_running(&self) -> bool {
+        self.tracker.running
+    }
+
+    /// Whether the session receives input, i.e. its state is `FOCUSED`
+    #[inline]
+    pub fn is_focused(&self) -> bool {
+        self.tracker.state == SessionState::FOCUSED
+    }
+
+    /// Total number of events the runtime reported as lost
+    #[inline]
+    pub fn lost_event_count(&self) -> u64 {
+        self.tracker.lost_events
+    }
+
+    /// What the frame loop should do, as of the last handled event
+    #[inline]
+    pub fn status(&self) -> FrameLoopStatus {
+        self.tracker.status()
+    }
+
+    /// Handle all pending events, discarding those unrelated to the session lifecycle
+    pub fn poll(&mut self) -> Result<FrameLoopStatus> {
+        self.poll_with(|_| {})
+    }
+
+    /// Handle all pending events, passing each to `f` after updating the session state
+    pub fn poll_with(&mut self, mut f: impl FnMut(Event<'_>)) -> Result<FrameLoopStatus> {
+        while let Some(event) = self.session.instance().poll_event(&mut self.buffer)? {
+            self.tracker
+                .handle(&self.session, self.view_configuration_type, event)?;
+            f(event);
+        }
+        Ok(self.tracker.status())
+    }
+
+    /// Handle an event obtained from a separate [`Instance::poll_event`] loop
+    ///
+    /// Events concerning other sessions are ignored.
+    pub fn handle_event(&mut self, event: Event<'_>) -> Result<FrameLoopStatus> {
+        self.tracker
+            .handle(&self.session, self.view_configuration_type, event)?;
+        Ok(self.tracker.status())
+    }
+
+    /// Ask the runtime to wind the session down
+    ///
+    /// If the session is running, the frame loop should continue until the runtime moves it to
+    /// `EXITING`. Otherwise the loop may stop immediately. Repeated calls have no effect.
+    pub fn request_exit(&mut self) -> Result<()> {
+        let tracker = &mut self.tracker;
+        if tracker.exit_requested {
+            return Ok(());
+        }
+        tracker.exit_requested = true;
+        if !tracker.running {
+            tracker.exit.get_or_insert(ExitReason::Exited);
+            return Ok(());
+        }
+        match self.session.request_exit() {
+            Ok(()) => Ok(()),
+            Err(e) if e == sys::Result::ERROR_SESSION_NOT_RUNNING => {
+                tracker.running = false;
+                tracker.exit.get_or_insert(ExitReason::Exited);
+                Ok(())
+            }
+            Err(e) => Err(e),
+        }
+    }
+}
+
+/// What a frame loop should do next, as reported by [`SessionDriver`]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum FrameLoopStatus {
+    /// The session isn't running, so frames must not be waited for
+    ///
+    /// Poll again after a short delay.
+    Idle,
+    /// The session is running, so frames should be waited for, begun and ended
+    Running,
+    /// The session is over and should be destroyed
+    Exit(ExitReason),
+}
+
+/// Why a [`SessionDriver`] stopped the frame loop
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ExitReason {
+    /// The session reached `EXITING`, or an exit was requested while it wasn't running
+    Exited,
+    /// The session reached `LOSS_PENDING`, e.g. because the device was disconnected
+    ///
+    /// A new session may be created on the same instance later.
+    SessionLossPending,
+    /// The instance is about to be lost at `loss_time`
+    ///
+    /// Every object created from the instance should be destroyed, including the instance itself.
+    InstanceLossPending { loss_time: Time },
+}
+
+struct Tracker {
+    state: SessionState,
+    running: bool,
+    exit_requested: bool,
+    exit: Option<ExitReason>,
+    lost_events: u64,
+}
+
+impl Tracker {
+    fn status(&self) -> FrameLoopStatus {
+        match (self.exit, self.running) {
+            (Some(reason), _) => FrameLoopStatus::Exit(reason),
+            (None, true) => FrameLoopStatus::Running,
+            (None, false) => FrameLoopStatus::Idle,
+        }
+    }
+
+    fn handle<G>(
+        &mut self,
+        session: &Session<G>,
+        view_configuration_type: ViewConfigurationType,
+        event: Event<'_>,
+    ) -> Result<()> {
+        match event {
+            Event::SessionStateChanged(e) if e.session() == session.as_raw() => {
+                self.state = e.state();
+                match e.state() {
+                    SessionState::READY => {
+                        match session.begin(view_configuration_type) {
+                            Ok(_) => {}
+                            Err(e) if e == sys::Result::ERROR_SESSION_RUNNING => {}
+                            Err(e) => return Err(e),
+                        }
+                        self.running = true;
+                        if self.exit_requested {
+                            session.request_exit()?;
+                        }
+                    }
+                    SessionState::STOPPING => {
+                        match session.end() {
+                            Ok(_) => {}
+                            Err(e) if e == sys::Result::ERROR_SESSION_NOT_RUNNING => {}
+                            Err(e) => return Err(e),
+                        }
+                        self.running = false;
+                    }
+                    SessionState::EXITING => {
+                        self.exit.get_or_insert(ExitReason::Exited);
+                    }
+                    SessionState::LOSS_PENDING => {
+                        self.running = false;
+                        self.exit.get_or_insert(ExitReason::SessionLossPending);
+                    }
+                    _ => {}
+                }
+            }
+            Event::InstanceLossPending(e) => {
+                self.running = false;
+                self.exit = Some(ExitReason::InstanceLossPending {
+                    loss_time: e.loss_time(),
+                });
+            }
+            Event::EventsLost(e) => {
+                self.lost_events += u64::from(e.lost_event_count());
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+}
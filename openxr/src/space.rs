@@ -67,10 +67,10 @@ impl Space {
                 object_name: name.as_ptr(),
             };
             unsafe {
-                cvt((fp.set_debug_utils_object_name)(
-                    self.instance().as_raw(),
-                    &info,
-                ))?;
+                cvt(
+                    "xrSetDebugUtilsObjectNameEXT",
+                    (fp.set_debug_utils_object_name)(self.instance().as_raw(), &info),
+                )?;
             }
         }
         Ok(())
@@ -85,12 +85,10 @@ impl Space {
                    "`self` and `base` must have been created, allocated, or retrieved from the same `Session`");
         unsafe {
             let mut x = sys::SpaceLocation::out(ptr::null_mut());
-            cvt((self.fp().locate_space)(
-                self.as_raw(),
-                base.as_raw(),
-                time,
-                x.as_mut_ptr(),
-            ))?;
+            cvt(
+                "xrLocateSpace",
+                (self.fp().locate_space)(self.as_raw(), base.as_raw(), time, x.as_mut_ptr()),
+            )?;
             Ok(SpaceLocation::new(&x))
         }
     }
@@ -105,12 +103,10 @@ impl Space {
         unsafe {
             let mut velocity = sys::SpaceVelocity::out(ptr::null_mut());
             let mut location = sys::SpaceLocation::out(&mut velocity as *mut _ as _);
-            cvt((self.fp().locate_space)(
-                self.as_raw(),
-                base.as_raw(),
-                time,
-                location.as_mut_ptr(),
-            ))?;
+            cvt(
+                "xrLocateSpace",
+                (self.fp().locate_space)(self.as_raw(), base.as_raw(), time, location.as_mut_ptr()),
+            )?;
             Ok((SpaceLocation::new(&location), SpaceVelocity::new(&velocity)))
         }
     }
@@ -143,11 +139,14 @@ impl Space {
                 joint_count: HAND_JOINT_COUNT as u32,
                 joint_locations: locations.as_mut_ptr() as _,
             };
-            cvt((tracker.fp().locate_hand_joints)(
-                tracker.as_raw(),
-                &locate_info,
-                &mut location_info,
-            ))?;
+            cvt(
+                "xrLocateHandJointsEXT",
+                (tracker.fp().locate_hand_joints)(
+                    tracker.as_raw(),
+                    &locate_info,
+                    &mut location_info,
+                ),
+            )?;
             Ok(if location_info.is_active.into() {
                 Some(locations.assume_init())
             } else {
@@ -191,11 +190,14 @@ impl Space {
                 joint_count: HAND_JOINT_COUNT as u32,
                 joint_locations: locations.as_mut_ptr() as _,
             };
-            cvt((tracker.fp().locate_hand_joints)(
-                tracker.as_raw(),
-                &locate_info,
-                &mut location_info,
-            ))?;
+            cvt(
+                "xrLocateHandJointsEXT",
+                (tracker.fp().locate_hand_joints)(
+                    tracker.as_raw(),
+                    &locate_info,
+                    &mut location_info,
+                ),
+            )?;
             Ok(if location_info.is_active.into() {
                 Some((locations.assume_init(), velocities.assume_init()))
             } else {
@@ -227,20 +229,29 @@ pub struct SpaceLocation {
 
 impl SpaceLocation {
     unsafe fn new(raw: &MaybeUninit<sys::SpaceLocation>) -> Self {
-        // Applications *must* not read invalid parts of a pose, i.e. they may be uninitialized
         let ptr = raw.as_ptr();
-        let flags = *ptr::addr_of!((*ptr).location_flags);
+        Self::from_parts(
+            *ptr::addr_of!((*ptr).location_flags),
+            ptr::addr_of!((*ptr).pose),
+        )
+    }
+
+    /// Read a location reported as `flags` and a possibly partially initialized `pose`
+    pub(crate) unsafe fn from_parts(flags: SpaceLocationFlags, pose: *const Posef) -> Self {
+        // Applications *must* not read invalid parts of a pose, i.e. they may be uninitialized
         Self {
             location_flags: flags,
             pose: Posef {
-                orientation: flags
-                    .contains(sys::SpaceLocationFlags::ORIENTATION_VALID)
-                    .then(|| *ptr::addr_of!((*ptr).pose.orientation))
-                    .unwrap_or_default(),
-                position: flags
-                    .contains(sys::SpaceLocationFlags::POSITION_VALID)
-                    .then(|| *ptr::addr_of!((*ptr).pose.position))
-                    .unwrap_or_default(),
+                orientation: if flags.contains(sys::SpaceLocationFlags::ORIENTATION_VALID) {
+                    *ptr::addr_of!((*pose).orientation)
+                } else {
+                    Default::default()
+                },
+                position: if flags.contains(sys::SpaceLocationFlags::POSITION_VALID) {
+                    *ptr::addr_of!((*pose).position)
+                } else {
+                    Default::default()
+                },
             },
         }
     }
@@ -255,19 +266,33 @@ pub struct SpaceVelocity {
 
 impl SpaceVelocity {
     unsafe fn new(raw: &MaybeUninit<sys::SpaceVelocity>) -> Self {
-        // Applications *must* not read invalid velocities, i.e. they may be uninitialized
         let ptr = raw.as_ptr();
-        let flags = *ptr::addr_of!((*ptr).velocity_flags);
+        Self::from_parts(
+            *ptr::addr_of!((*ptr).velocity_flags),
+            ptr::addr_of!((*ptr).linear_velocity),
+            ptr::addr_of!((*ptr).angular_velocity),
+        )
+    }
+
+    /// Read a velocity reported as `flags` and possibly uninitialized vectors
+    pub(crate) unsafe fn from_parts(
+        flags: SpaceVelocityFlags,
+        linear: *const Vector3f,
+        angular: *const Vector3f,
+    ) -> Self {
+        // Applications *must* not read invalid velocities, i.e. they may be uninitialized
         Self {
             velocity_flags: flags,
-            linear_velocity: flags
-                .contains(sys::SpaceVelocityFlags::LINEAR_VALID)
-                .then(|| *ptr::addr_of!((*ptr).linear_velocity))
-                .unwrap_or_default(),
-            angular_velocity: flags
-                .contains(sys::SpaceVelocityFlags::ANGULAR_VALID)
-                .then(|| *ptr::addr_of!((*ptr).angular_velocity))
-                .unwrap_or_default(),
+            linear_velocity: if flags.contains(sys::SpaceVelocityFlags::LINEAR_VALID) {
+                *linear
+            } else {
+                Default::default()
+            },
+            angular_velocity: if flags.contains(sys::SpaceVelocityFlags::ANGULAR_VALID) {
+                *angular
+            } else {
+                Default::default()
+            },
         }
     }
 }
@@ -1,14 +1,15 @@
-use std::{ffi::CString, marker::PhantomData, ptr};
+use std::{ffi::CString, marker::PhantomData, mem::ManuallyDrop, ptr};
 
 use crate::*;
 
 /// A set of images to be rendered to using a particular graphics API `G`
 pub struct Swapchain<G: Graphics> {
-    session: Session<G>,
+    pub(crate) session: Session<G>,
     handle: sys::Swapchain,
+    /// Images whose guard was dropped before they were waited for, to be released by the next
+    /// `acquire_image`
+    abandoned: u32,
     _marker: PhantomData<G>,
-    /// Whether `wait_image` was called more recently than `release_image`
-    waited: bool,
 }
 
 impl<G: Graphics> Swapchain<G> {
@@ -22,8 +23,8 @@ impl<G: Graphics> Swapchain<G> {
         Self {
             session,
             handle,
+            abandoned: 0,
             _marker: PhantomData,
-            waited: false,
         }
     }
 
@@ -53,10 +54,10 @@ impl<G: Graphics> Swapchain<G> {
                 object_name: name.as_ptr(),
             };
             unsafe {
-                cvt((fp.set_debug_utils_object_name)(
-                    self.instance().as_raw(),
-                    &info,
-                ))?;
+                cvt(
+                    "xrSetDebugUtilsObjectNameEXT",
+                    (fp.set_debug_utils_object_name)(self.instance().as_raw(), &info),
+                )?;
             }
         }
         Ok(())
@@ -67,53 +68,75 @@ impl<G: Graphics> Swapchain<G> {
         G::enumerate_swapchain_images(self)
     }
 
-    /// Determine the index of the next image to render to in the swapchain image array
+    /// Acquire the next image to render to
+    ///
+    /// The image must be waited for with [`AcquiredImage::wait`] before it's rendered to, which
+    /// reveals its index into [`enumerate_images`](Self::enumerate_images). It's released by
+    /// [`AcquiredImage::release`], or when the guard is dropped.
+    ///
+    /// If a previous guard was dropped before its image was waited for, that image is waited for
+    /// and released first.
     #[inline]
-    pub fn acquire_image(&mut self) -> Result<u32> {
-        let mut out = 0;
+    pub fn acquire_image(&mut self) -> Result<AcquiredImage<'_, G>> {
+        while self.abandoned > 0 {
+            while !self.wait_oldest(Duration::INFINITE)? {}
+            self.release_oldest()?;
+            self.abandoned -= 1;
+        }
+        let mut index = 0;
         unsafe {
-            cvt((self.fp().acquire_swapchain_image)(
-                self.as_raw(),
-                ptr::null(),
-                &mut out,
-            ))?;
+            cvt(
+                "xrAcquireSwapchainImage",
+                (self.fp().acquire_swapchain_image)(self.as_raw(), ptr::null(), &mut index),
+            )?;
         }
-        Ok(out)
+        Ok(AcquiredImage {
+            swapchain: Some(self),
+            index,
+            waited: false,
+        })
     }
 
     /// Wait for the compositor to finish reading from the oldest unwaited acquired image
+    #[deprecated(note = "use `AcquiredImage::wait`")]
     #[inline]
     pub fn wait_image(&mut self, timeout: Duration) -> Result<()> {
-        assert!(
-            !self.waited,
-            "release_image must be called before wait_image can be called again"
-        );
+        self.wait_oldest(timeout)?;
+        Ok(())
+    }
+
+    /// Release the oldest acquired image
+    #[deprecated(note = "use `AcquiredImage::release`")]
+    #[inline]
+    pub fn release_image(&mut self) -> Result<()> {
+        self.release_oldest()
+    }
+
+    /// Wait for the compositor to finish reading from the oldest unwaited acquired image, returning
+    /// `false` if `timeout` expired first
+    fn wait_oldest(&self, timeout: Duration) -> Result<bool> {
         let info = sys::SwapchainImageWaitInfo {
             ty: sys::SwapchainImageWaitInfo::TYPE,
             next: ptr::null_mut(),
             timeout,
         };
-        unsafe {
-            cvt((self.fp().wait_swapchain_image)(self.as_raw(), &info))?;
-        }
-        self.waited = true;
-        Ok(())
+        let status = unsafe {
+            cvt(
+                "xrWaitSwapchainImage",
+                (self.fp().wait_swapchain_image)(self.as_raw(), &info),
+            )?
+        };
+        Ok(status != sys::Result::TIMEOUT_EXPIRED)
     }
 
-    /// Release the oldest acquired image
-    #[inline]
-    pub fn release_image(&mut self) -> Result<()> {
-        assert!(
-            self.waited,
-            "wait_image must be called before release_image"
-        );
+    /// Release the oldest waited image
+    fn release_oldest(&self) -> Result<()> {
         unsafe {
-            cvt((self.fp().release_swapchain_image)(
-                self.as_raw(),
-                ptr::null(),
-            ))?;
+            cvt(
+                "xrReleaseSwapchainImage",
+                (self.fp().release_swapchain_image)(self.as_raw(), ptr::null()),
+            )?;
         }
-        self.waited = false;
         Ok(())
     }
 
@@ -131,3 +154,115 @@ impl<G: Graphics> Drop for Swapchain<G> {
         }
     }
 }
+
+/// An image acquired from a [`Swapchain`] by [`Swapchain::acquire_image`]
+///
+/// If the guard is dropped after the image was waited for, the image is released immediately,
+/// ignoring errors; call [`release`](Self::release) to observe them. If it hadn't been waited for,
+/// it's waited for and released by the next [`Swapchain::acquire_image`] instead, so that dropping
+/// never blocks.
+pub struct AcquiredImage<'a, G: Graphics> {
+    /// `None` once released
+    swapchain: Option<&'a mut Swapchain<G>>,
+    index: u32,
+    waited: bool,
+}
+
+impl<'a, G: Graphics> AcquiredImage<'a, G> {
+    /// Wait for the compositor to finish reading from the image
+    ///
+    /// Returns the image's index into [`Swapchain::enumerate_images`] once it may be rendered to,
+    /// or `None` if `timeout` expired first, in which case the wait may be retried.
+    pub fn wait(&mut self, timeout: Duration) -> Result<Option<u32>> {
+        if !self.waited {
+            let swapchain = self.swapchain.as_deref().expect("image already released");
+            self.waited = swapchain.wait_oldest(timeout)?;
+        }
+        Ok(self.index())
+    }
+
+    /// The image's index into [`Swapchain::enumerate_images`], if it has been waited for
+    #[inline]
+    pub fn index(&self) -> Option<u32> {
+        if self.waited {
+            Some(self.index)
+        } else {
+            None
+        }
+    }
+
+    /// Release the image once rendering commands have been submitted, waiting for it first if
+    /// necessary
+    ///
+    /// The returned [`ReleasedImage`] is the only way for composition layers to refer to the
+    /// swapchain, e.g. through [`ReleasedImage::sub_image`], so that only a swapchain whose image
+    /// was released can be submitted.
+    pub fn release(mut self) -> Result<ReleasedImage<'a, G>> {
+        while self.wait(Duration::INFINITE)?.is_none() {}
+        // Taken first, so that the guard doesn't release the image again if this fails
+        let swapchain = self.swapchain.take().expect("image already released");
+        swapchain.release_oldest()?;
+        Ok(ReleasedImage {
+            swapchain,
+            index: self.index,
+        })
+    }
+
+    /// Give up the guard without waiting for or releasing the image, returning its index
+    ///
+    /// For use with the deprecated [`Swapchain::wait_image`] and [`Swapchain::release_image`],
+    /// which must be used to wait for and release the image before another is acquired.
+    pub fn into_index(self) -> u32 {
+        ManuallyDrop::new(self).index
+    }
+}
+
+impl<G: Graphics> Drop for AcquiredImage<'_, G> {
+    fn drop(&mut self) {
+        if let Some(swapchain) = self.swapchain.take() {
+            if self.waited {
+                let _ = swapchain.release_oldest();
+            } else {
+                swapchain.abandoned += 1;
+            }
+        }
+    }
+}
+
+/// An image rendered to and released by [`AcquiredImage::release`], for submission in
+/// composition layers
+///
+/// Borrows the swapchain, so no other image can be acquired from it until the frame is ended.
+pub struct ReleasedImage<'a, G: Graphics> {
+    swapchain: &'a Swapchain<G>,
+    index: u32,
+}
+
+impl<'a, G: Graphics> ReleasedImage<'a, G> {
+    /// The image's index into [`Swapchain::enumerate_images`]
+    #[inline]
+    pub fn index(&self) -> u32 {
+        self.index
+    }
+
+    /// The swapchain the image belongs to
+    #[inline]
+    pub fn swapchain(&self) -> &'a Swapchain<G> {
+        self.swapchain
+    }
+
+    /// A [`SwapchainSubImage`] referring to the swapchain, whose rectangle and array index remain
+    /// to be set
+    #[inline]
+    pub fn sub_image(&self) -> SwapchainSubImage<'a, G> {
+        SwapchainSubImage::new().swapchain(self)
+    }
+}
+
+impl<G: Graphics> Clone for ReleasedImage<'_, G> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<G: Graphics> Copy for ReleasedImage<'_, G> {}
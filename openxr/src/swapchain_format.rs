@@ -0,0 +1,180 @@
+use std::cmp::Reverse;
+
+use crate::*;
+
+/// Properties of a swapchain format, independent of the graphics API
+///
+/// Obtained for an API's formats through [`Graphics::describe_format`].
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct FormatInfo {
+    /// Bits in the smallest of the red, green and blue channels, or 0 if there are none
+    pub color_bits: u8,
+    /// Bits in the alpha channel, or 0 if there is none
+    pub alpha_bits: u8,
+    /// Whether color is sRGB-encoded, so that linear shader output is encoded on write
+    pub srgb: bool,
+    /// Whether color or depth is stored as floating point
+    pub float: bool,
+    /// Bits of depth, or 0 if there is no depth component
+    pub depth_bits: u8,
+    /// Bits of stencil, or 0 if there is no stencil component
+    pub stencil_bits: u8,
+}
+
+impl FormatInfo {
+    /// A color format with `color_bits` per color channel
+    pub(crate) const fn color(color_bits: u8, alpha_bits: u8, srgb: bool, float: bool) -> Self {
+        Self {
+            color_bits,
+            alpha_bits,
+            srgb,
+            float,
+            depth_bits: 0,
+            stencil_bits: 0,
+        }
+    }
+
+    /// A depth and/or stencil format
+    pub(crate) const fn depth(depth_bits: u8, stencil_bits: u8, float: bool) -> Self {
+        Self {
+            color_bits: 0,
+            alpha_bits: 0,
+            srgb: false,
+            float,
+            depth_bits,
+            stencil_bits,
+        }
+    }
+
+    /// Whether the format has color channels
+    #[inline]
+    pub fn is_color(&self) -> bool {
+        self.color_bits != 0
+    }
+
+    /// Whether the format has a depth component
+    #[inline]
+    pub fn is_depth(&self) -> bool {
+        self.depth_bits != 0
+    }
+}
+
+/// Preferences for choosing swapchain formats with [`FormatPreferences::select`]
+///
+/// Formats failing a preference are only chosen when no better format is available. Among equally
+/// suitable formats, the one the runtime lists first is chosen, as runtimes list their preferred
+/// formats first.
+///
+/// The default prefers 8-bit sRGB color with alpha, and 24-bit depth without stencil.
+///
+/// # Example
+///
+/// ```no_run
+/// # fn dummy<G: openxr::Graphics>(session: &openxr::Session<G>) -> openxr::Result<()> {
+/// let formats = session.select_swapchain_formats(&openxr::FormatPreferences::default())?;
+/// let color = formats.color.expect("no supported color format");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct FormatPreferences {
+    /// Prefer sRGB-encoded color formats
+    pub srgb: bool,
+    /// Prefer floating point color formats, e.g. for HDR rendering
+    pub float: bool,
+    /// Prefer color formats with an alpha channel
+    pub alpha: bool,
+    /// Preferred bits per color channel, with more bits preferred over fewer
+    pub color_bits: u8,
+    /// Preferred bits of depth, with more bits preferred over fewer
+    pub depth_bits: u8,
+    /// Require a stencil component in the depth format
+    pub stencil: bool,
+}
+
+impl Default for FormatPreferences {
+    fn default() -> Self {
+        Self {
+            srgb: true,
+            float: false,
+            alpha: true,
+            color_bits: 8,
+            depth_bits: 24,
+            stencil: false,
+        }
+    }
+}
+
+impl FormatPreferences {
+    /// Choose the color and depth formats best matching these preferences from `formats`, in the
+    /// runtime's order
+    ///
+    /// Formats that `G` can't describe are never chosen.
+    pub fn select<G: Graphics>(&self, formats: &[G::Format]) -> SelectedFormats<G::Format> {
+        self.select_with(formats, G::describe_format)
+    }
+
+    fn select_with<F: Copy>(
+        &self,
+        formats: &[F],
+        describe: impl Fn(F) -> Option<FormatInfo>,
+    ) -> SelectedFormats<F> {
+        let described = || {
+            formats
+                .iter()
+                .enumerate()
+                .filter_map(|(i, &x)| Some((i, x, describe(x)?)))
+        };
+        let color = described()
+            .filter(|(_, _, info)| info.is_color())
+            .max_by_key(|&(i, _, info)| {
+                (
+                    info.srgb == self.srgb,
+                    info.float == self.float,
+                    (info.alpha_bits != 0) == self.alpha,
+                    info.color_bits >= self.color_bits,
+                    Reverse(info.color_bits.abs_diff(self.color_bits)),
+                    Reverse(i),
+                )
+            })
+            .map(|(_, x, _)| x);
+        let depth = described()
+            .filter(|(_, _, info)| info.is_depth() && (info.stencil_bits != 0 || !self.stencil))
+            .max_by_key(|&(i, _, info)| {
+                (
+                    info.depth_bits >= self.depth_bits,
+                    (info.stencil_bits != 0) == self.stencil,
+                    Reverse(info.depth_bits.abs_diff(self.depth_bits)),
+                    Reverse(i),
+                )
+            })
+            .map(|(_, x, _)| x);
+        SelectedFormats { color, depth }
+    }
+}
+
+/// Formats chosen by [`FormatPreferences::select`]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct SelectedFormats<F> {
+    /// The best color format, if any was available
+    pub color: Option<F>,
+    /// The best depth format, if any was available
+    pub depth: Option<F>,
+}
+
+impl<G: Graphics> Session<G> {
+    /// Choose color and depth formats from those supported by the session
+    ///
+    /// See [`FormatPreferences::select`]. Unlike it, this can describe the formats of [`Dynamic`]
+    /// sessions, using the API the session was created with.
+    pub fn select_swapchain_formats(
+        &self,
+        preferences: &FormatPreferences,
+    ) -> Result<SelectedFormats<G::Format>> {
+        let describe = |x| match self.inner.backend {
+            Some(backend) => backend.describe_format(G::lower_format(x)),
+            None => G::describe_format(x),
+        };
+        Ok(preferences.select_with(&self.enumerate_swapchain_formats()?, describe))
+    }
+}
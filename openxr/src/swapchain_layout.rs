@@ -0,0 +1,268 @@
+use crate::*;
+
+/// How the views of a view configuration are arranged in swapchains
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SwapchainLayout {
+    /// One array swapchain for each group of equally sized views, with a layer per view
+    ///
+    /// Stereo views share a single swapchain, while e.g. the context and focus views of
+    /// `PRIMARY_QUAD_VARJO` get one each.
+    Auto,
+    /// One array swapchain with a layer per view, large enough for every view
+    Array,
+    /// One swapchain with the views side by side
+    Atlas,
+    /// One swapchain per view
+    PerView,
+}
+
+/// The swapchains needed to render a view configuration, and where each view is rendered
+///
+/// # Example
+///
+/// ```no_run
+/// # fn dummy<G: openxr::RendersFrames>(
+/// #     instance: &openxr::Instance,
+/// #     system: openxr::SystemId,
+/// #     session: &openxr::Session<G>,
+/// #     format: G::Format,
+/// #     views: &[openxr::View],
+/// # ) -> openxr::Result<()> {
+/// let ty = openxr::ViewConfigurationType::PRIMARY_STEREO;
+/// let plan = openxr::SwapchainPlan::new(
+///     &instance.enumerate_view_configuration_views(system, ty)?,
+///     openxr::SwapchainLayout::Auto,
+///     1.0,
+///     None,
+/// );
+/// let mut swapchains = session.create_planned_swapchains(
+///     &plan,
+///     ty,
+///     format,
+///     openxr::SwapchainUsageFlags::COLOR_ATTACHMENT,
+/// )?;
+///
+/// // Each frame
+/// let mut images = Vec::new();
+/// for swapchain in &mut swapchains {
+///     let mut image = swapchain.acquire_image()?;
+///     image.wait(openxr::Duration::INFINITE)?;
+///     images.push(image);
+/// }
+/// // render each view to `plan.views[i].image_rect`...
+/// let images = images
+///     .into_iter()
+///     .map(|x| x.release())
+///     .collect::<openxr::Result<Vec<_>>>()?;
+/// let projection_views = plan
+///     .views
+///     .iter()
+///     .zip(views)
+///     .map(|(planned, view)| {
+///         openxr::CompositionLayerProjectionView::new()
+///             .pose(view.pose)
+///             .fov(view.fov)
+///             .sub_image(planned.sub_image(&images[planned.swapchain]))
+///     })
+///     .collect::<Vec<_>>();
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct SwapchainPlan {
+    /// Swapchains to create
+    pub swapchains: Vec<PlannedSwapchain>,
+    /// Where each view is rendered, in the order of the view configuration's views
+    pub views: Vec<ViewSubImage>,
+}
+
+impl SwapchainPlan {
+    /// Arrange `views`, as returned by [`Instance::enumerate_view_configuration_views`], in
+    /// swapchains
+    ///
+    /// Each view's recommended size is multiplied by `resolution_scale`, and limited to its
+    /// maximum. The sample count defaults to each view's recommended count, and is limited to the
+    /// maximum of every view sharing a swapchain.
+    pub fn new(
+        views: &[ViewConfigurationView],
+        layout: SwapchainLayout,
+        resolution_scale: f32,
+        sample_count: Option<u32>,
+    ) -> Self {
+        let sizes = views
+            .iter()
+            .map(|view| {
+                let scale = |recommended: u32, max: u32| {
+                    ((recommended as f32 * resolution_scale).round() as u32).clamp(1, max.max(1))
+                };
+                (
+                    scale(view.recommended_image_rect_width, view.max_image_rect_width),
+                    scale(
+                        view.recommended_image_rect_height,
+                        view.max_image_rect_height,
+                    ),
+                )
+            })
+            .collect::<Vec<_>>();
+        let samples = views
+            .iter()
+            .map(|view| {
+                sample_count
+                    .unwrap_or(view.recommended_swapchain_sample_count)
+                    .clamp(1, view.max_swapchain_sample_count.max(1))
+            })
+            .collect::<Vec<_>>();
+
+        // Indices of the views sharing each swapchain
+        let groups: Vec<Vec<usize>> = match layout {
+            SwapchainLayout::Auto => {
+                let mut groups: Vec<Vec<usize>> = Vec::new();
+                for (i, size) in sizes.iter().enumerate() {
+                    match groups.iter_mut().find(|x| sizes[x[0]] == *size) {
+                        Some(group) => group.push(i),
+                        None => groups.push(vec![i]),
+                    }
+                }
+                groups
+            }
+            SwapchainLayout::Array | SwapchainLayout::Atlas if !views.is_empty() => {
+                vec![(0..views.len()).collect()]
+            }
+            SwapchainLayout::Array | SwapchainLayout::Atlas => Vec::new(),
+            SwapchainLayout::PerView => (0..views.len()).map(|i| vec![i]).collect(),
+        };
+
+        let mut plan = Self {
+            swapchains: Vec::with_capacity(groups.len()),
+            views: vec![ViewSubImage::default(); views.len()],
+        };
+        for (swapchain, group) in groups.into_iter().enumerate() {
+            let sample_count = group.iter().map(|&i| samples[i]).min().unwrap_or(1);
+            let height = group.iter().map(|&i| sizes[i].1).max().unwrap_or(1);
+            let (width, array_size) = if layout == SwapchainLayout::Atlas {
+                let mut x = 0;
+                for &i in &group {
+                    plan.views[i] = ViewSubImage::new(swapchain, x, sizes[i], 0);
+                    x += sizes[i].0;
+                }
+                (x, 1)
+            } else {
+                for (layer, &i) in group.iter().enumerate() {
+                    plan.views[i] = ViewSubImage::new(swapchain, 0, sizes[i], layer as u32);
+                }
+                let width = group.iter().map(|&i| sizes[i].0).max().unwrap_or(1);
+                (width, group.len() as u32)
+            };
+            plan.swapchains.push(PlannedSwapchain {
+                width,
+                height,
+                array_size,
+                sample_count,
+            });
+        }
+        plan
+    }
+}
+
+/// Dimensions of a swapchain in a [`SwapchainPlan`]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct PlannedSwapchain {
+    pub width: u32,
+    pub height: u32,
+    pub array_size: u32,
+    pub sample_count: u32,
+}
+
+impl PlannedSwapchain {
+    /// Parameters for creating the swapchain, with a single face and mip level
+    pub fn create_info<G: Graphics>(
+        &self,
+        format: G::Format,
+        usage_flags: SwapchainUsageFlags,
+    ) -> SwapchainCreateInfo<G> {
+        SwapchainCreateInfo {
+            create_flags: SwapchainCreateFlags::EMPTY,
+            usage_flags,
+            format,
+            sample_count: self.sample_count,
+            width: self.width,
+            height: self.height,
+            face_count: 1,
+            array_size: self.array_size,
+            mip_count: 1,
+        }
+    }
+}
+
+/// Where a view is rendered in a [`SwapchainPlan`]
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+pub struct ViewSubImage {
+    /// Index of the swapchain in [`SwapchainPlan::swapchains`]
+    pub swapchain: usize,
+    pub image_rect: Rect2Di,
+    pub image_array_index: u32,
+}
+
+impl ViewSubImage {
+    fn new(swapchain: usize, x: u32, (width, height): (u32, u32), image_array_index: u32) -> Self {
+        Self {
+            swapchain,
+            image_rect: Rect2Di {
+                offset: Offset2Di { x: x as i32, y: 0 },
+                extent: Extent2Di {
+                    width: width as i32,
+                    height: height as i32,
+                },
+            },
+            image_array_index,
+        }
+    }
+
+    /// The view's sub-image of `image`, which must have been released from the view's
+    /// swapchain
+    #[inline]
+    pub fn sub_image<'a, G: Graphics>(
+        &self,
+        image: &ReleasedImage<'a, G>,
+    ) -> SwapchainSubImage<'a, G> {
+        image
+            .sub_image()
+            .image_rect(self.image_rect)
+            .image_array_index(self.image_array_index)
+    }
+}
+
+impl<G: RendersFrames> Session<G> {
+    /// Create the swapchains of `plan` for the views of `view_configuration_type`
+    ///
+    /// Swapchains for secondary view configurations, such as
+    /// `SECONDARY_MONO_FIRST_PERSON_OBSERVER_MSFT`, are created with an
+    /// `XrSecondaryViewConfigurationSwapchainCreateInfoMSFT`, as required by
+    /// `XR_MSFT_secondary_view_configuration`.
+    pub fn create_planned_swapchains(
+        &self,
+        plan: &SwapchainPlan,
+        view_configuration_type: ViewConfigurationType,
+        format: G::Format,
+        usage_flags: SwapchainUsageFlags,
+    ) -> Result<Vec<Swapchain<G>>> {
+        let secondary = matches!(
+            view_configuration_type,
+            ViewConfigurationType::SECONDARY_MONO_FIRST_PERSON_OBSERVER_MSFT
+        );
+        plan.swapchains
+            .iter()
+            .map(|x| {
+                let info = x.create_info(format, usage_flags);
+                let mut secondary_info = SecondaryViewConfigurationSwapchainCreateInfoMSFT::new()
+                    .view_configuration_type(view_configuration_type);
+                let next = if secondary {
+                    NextChain::new().push(&mut secondary_info)
+                } else {
+                    NextChain::new()
+                };
+                self.create_swapchain_with_next(&info, next)
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,7890 @@
+//! Automatically generated code; do not edit!
+
+#![allow(unused, clippy::too_many_arguments)]
+use libc::{timespec, wchar_t};
+use std::mem;
+use std::os::raw::{c_char, c_void};
+
+use sys::platform::*;
+use sys::*;
+
+#[doc = r" Function pointers of the traced implementation"]
+pub(super) struct Next {
+    enumerate_api_layer_properties: Option<pfn::EnumerateApiLayerProperties>,
+    enumerate_instance_extension_properties: Option<pfn::EnumerateInstanceExtensionProperties>,
+    create_instance: Option<pfn::CreateInstance>,
+    destroy_instance: Option<pfn::DestroyInstance>,
+    result_to_string: Option<pfn::ResultToString>,
+    structure_type_to_string: Option<pfn::StructureTypeToString>,
+    get_instance_properties: Option<pfn::GetInstanceProperties>,
+    get_system: Option<pfn::GetSystem>,
+    get_system_properties: Option<pfn::GetSystemProperties>,
+    create_session: Option<pfn::CreateSession>,
+    destroy_session: Option<pfn::DestroySession>,
+    destroy_space: Option<pfn::DestroySpace>,
+    enumerate_swapchain_formats: Option<pfn::EnumerateSwapchainFormats>,
+    create_swapchain: Option<pfn::CreateSwapchain>,
+    destroy_swapchain: Option<pfn::DestroySwapchain>,
+    enumerate_swapchain_images: Option<pfn::EnumerateSwapchainImages>,
+    acquire_swapchain_image: Option<pfn::AcquireSwapchainImage>,
+    wait_swapchain_image: Option<pfn::WaitSwapchainImage>,
+    release_swapchain_image: Option<pfn::ReleaseSwapchainImage>,
+    begin_session: Option<pfn::BeginSession>,
+    end_session: Option<pfn::EndSession>,
+    request_exit_session: Option<pfn::RequestExitSession>,
+    enumerate_reference_spaces: Option<pfn::EnumerateReferenceSpaces>,
+    create_reference_space: Option<pfn::CreateReferenceSpace>,
+    create_action_space: Option<pfn::CreateActionSpace>,
+    locate_space: Option<pfn::LocateSpace>,
+    enumerate_view_configurations: Option<pfn::EnumerateViewConfigurations>,
+    enumerate_environment_blend_modes: Option<pfn::EnumerateEnvironmentBlendModes>,
+    get_view_configuration_properties: Option<pfn::GetViewConfigurationProperties>,
+    enumerate_view_configuration_views: Option<pfn::EnumerateViewConfigurationViews>,
+    begin_frame: Option<pfn::BeginFrame>,
+    locate_views: Option<pfn::LocateViews>,
+    end_frame: Option<pfn::EndFrame>,
+    wait_frame: Option<pfn::WaitFrame>,
+    apply_haptic_feedback: Option<pfn::ApplyHapticFeedback>,
+    stop_haptic_feedback: Option<pfn::StopHapticFeedback>,
+    poll_event: Option<pfn::PollEvent>,
+    string_to_path: Option<pfn::StringToPath>,
+    path_to_string: Option<pfn::PathToString>,
+    get_reference_space_bounds_rect: Option<pfn::GetReferenceSpaceBoundsRect>,
+    #[cfg(target_os = "android")]
+    set_android_application_thread_khr: Option<pfn::SetAndroidApplicationThreadKHR>,
+    #[cfg(target_os = "android")]
+    create_swapchain_android_surface_khr: Option<pfn::CreateSwapchainAndroidSurfaceKHR>,
+    get_action_state_boolean: Option<pfn::GetActionStateBoolean>,
+    get_action_state_float: Option<pfn::GetActionStateFloat>,
+    get_action_state_vector2f: Option<pfn::GetActionStateVector2f>,
+    get_action_state_pose: Option<pfn::GetActionStatePose>,
+    create_action_set: Option<pfn::CreateActionSet>,
+    destroy_action_set: Option<pfn::DestroyActionSet>,
+    create_action: Option<pfn::CreateAction>,
+    destroy_action: Option<pfn::DestroyAction>,
+    suggest_interaction_profile_bindings: Option<pfn::SuggestInteractionProfileBindings>,
+    attach_session_action_sets: Option<pfn::AttachSessionActionSets>,
+    get_current_interaction_profile: Option<pfn::GetCurrentInteractionProfile>,
+    sync_actions: Option<pfn::SyncActions>,
+    enumerate_bound_sources_for_action: Option<pfn::EnumerateBoundSourcesForAction>,
+    get_input_source_localized_name: Option<pfn::GetInputSourceLocalizedName>,
+    locate_spaces: Option<pfn::LocateSpaces>,
+    get_vulkan_instance_extensions_khr: Option<pfn::GetVulkanInstanceExtensionsKHR>,
+    get_vulkan_device_extensions_khr: Option<pfn::GetVulkanDeviceExtensionsKHR>,
+    get_vulkan_graphics_device_khr: Option<pfn::GetVulkanGraphicsDeviceKHR>,
+    get_open_gl_graphics_requirements_khr: Option<pfn::GetOpenGLGraphicsRequirementsKHR>,
+    get_open_gles_graphics_requirements_khr: Option<pfn::GetOpenGLESGraphicsRequirementsKHR>,
+    get_vulkan_graphics_requirements_khr: Option<pfn::GetVulkanGraphicsRequirementsKHR>,
+    #[cfg(windows)]
+    get_d3d11_graphics_requirements_khr: Option<pfn::GetD3D11GraphicsRequirementsKHR>,
+    #[cfg(windows)]
+    get_d3d12_graphics_requirements_khr: Option<pfn::GetD3D12GraphicsRequirementsKHR>,
+    perf_settings_set_performance_level_ext: Option<pfn::PerfSettingsSetPerformanceLevelEXT>,
+    thermal_get_temperature_trend_ext: Option<pfn::ThermalGetTemperatureTrendEXT>,
+    set_debug_utils_object_name_ext: Option<pfn::SetDebugUtilsObjectNameEXT>,
+    create_debug_utils_messenger_ext: Option<pfn::CreateDebugUtilsMessengerEXT>,
+    destroy_debug_utils_messenger_ext: Option<pfn::DestroyDebugUtilsMessengerEXT>,
+    submit_debug_utils_message_ext: Option<pfn::SubmitDebugUtilsMessageEXT>,
+    session_begin_debug_utils_label_region_ext: Option<pfn::SessionBeginDebugUtilsLabelRegionEXT>,
+    session_end_debug_utils_label_region_ext: Option<pfn::SessionEndDebugUtilsLabelRegionEXT>,
+    session_insert_debug_utils_label_ext: Option<pfn::SessionInsertDebugUtilsLabelEXT>,
+    #[cfg(windows)]
+    convert_time_to_win32_performance_counter_khr:
+        Option<pfn::ConvertTimeToWin32PerformanceCounterKHR>,
+    #[cfg(windows)]
+    convert_win32_performance_counter_to_time_khr:
+        Option<pfn::ConvertWin32PerformanceCounterToTimeKHR>,
+    create_vulkan_instance_khr: Option<pfn::CreateVulkanInstanceKHR>,
+    create_vulkan_device_khr: Option<pfn::CreateVulkanDeviceKHR>,
+    get_vulkan_graphics_device2_khr: Option<pfn::GetVulkanGraphicsDevice2KHR>,
+    convert_time_to_timespec_time_khr: Option<pfn::ConvertTimeToTimespecTimeKHR>,
+    convert_timespec_time_to_time_khr: Option<pfn::ConvertTimespecTimeToTimeKHR>,
+    get_visibility_mask_khr: Option<pfn::GetVisibilityMaskKHR>,
+    create_spatial_anchor_msft: Option<pfn::CreateSpatialAnchorMSFT>,
+    create_spatial_anchor_space_msft: Option<pfn::CreateSpatialAnchorSpaceMSFT>,
+    destroy_spatial_anchor_msft: Option<pfn::DestroySpatialAnchorMSFT>,
+    set_input_device_active_ext: Option<pfn::SetInputDeviceActiveEXT>,
+    set_input_device_state_bool_ext: Option<pfn::SetInputDeviceStateBoolEXT>,
+    set_input_device_state_float_ext: Option<pfn::SetInputDeviceStateFloatEXT>,
+    set_input_device_state_vector2f_ext: Option<pfn::SetInputDeviceStateVector2fEXT>,
+    set_input_device_location_ext: Option<pfn::SetInputDeviceLocationEXT>,
+    initialize_loader_khr: Option<pfn::InitializeLoaderKHR>,
+    create_spatial_graph_node_space_msft: Option<pfn::CreateSpatialGraphNodeSpaceMSFT>,
+    try_create_spatial_graph_static_node_binding_msft:
+        Option<pfn::TryCreateSpatialGraphStaticNodeBindingMSFT>,
+    destroy_spatial_graph_node_binding_msft: Option<pfn::DestroySpatialGraphNodeBindingMSFT>,
+    get_spatial_graph_node_binding_properties_msft:
+        Option<pfn::GetSpatialGraphNodeBindingPropertiesMSFT>,
+    create_hand_tracker_ext: Option<pfn::CreateHandTrackerEXT>,
+    destroy_hand_tracker_ext: Option<pfn::DestroyHandTrackerEXT>,
+    locate_hand_joints_ext: Option<pfn::LocateHandJointsEXT>,
+    create_face_tracker_fb: Option<pfn::CreateFaceTrackerFB>,
+    destroy_face_tracker_fb: Option<pfn::DestroyFaceTrackerFB>,
+    get_face_expression_weights_fb: Option<pfn::GetFaceExpressionWeightsFB>,
+    create_body_tracker_fb: Option<pfn::CreateBodyTrackerFB>,
+    destroy_body_tracker_fb: Option<pfn::DestroyBodyTrackerFB>,
+    locate_body_joints_fb: Option<pfn::LocateBodyJointsFB>,
+    get_body_skeleton_fb: Option<pfn::GetBodySkeletonFB>,
+    create_eye_tracker_fb: Option<pfn::CreateEyeTrackerFB>,
+    destroy_eye_tracker_fb: Option<pfn::DestroyEyeTrackerFB>,
+    get_eye_gazes_fb: Option<pfn::GetEyeGazesFB>,
+    create_hand_mesh_space_msft: Option<pfn::CreateHandMeshSpaceMSFT>,
+    update_hand_mesh_msft: Option<pfn::UpdateHandMeshMSFT>,
+    get_controller_model_key_msft: Option<pfn::GetControllerModelKeyMSFT>,
+    load_controller_model_msft: Option<pfn::LoadControllerModelMSFT>,
+    get_controller_model_properties_msft: Option<pfn::GetControllerModelPropertiesMSFT>,
+    get_controller_model_state_msft: Option<pfn::GetControllerModelStateMSFT>,
+    enumerate_display_refresh_rates_fb: Option<pfn::EnumerateDisplayRefreshRatesFB>,
+    get_display_refresh_rate_fb: Option<pfn::GetDisplayRefreshRateFB>,
+    request_display_refresh_rate_fb: Option<pfn::RequestDisplayRefreshRateFB>,
+    #[cfg(windows)]
+    create_spatial_anchor_from_perception_anchor_msft:
+        Option<pfn::CreateSpatialAnchorFromPerceptionAnchorMSFT>,
+    #[cfg(windows)]
+    try_get_perception_anchor_from_spatial_anchor_msft:
+        Option<pfn::TryGetPerceptionAnchorFromSpatialAnchorMSFT>,
+    update_swapchain_fb: Option<pfn::UpdateSwapchainFB>,
+    get_swapchain_state_fb: Option<pfn::GetSwapchainStateFB>,
+    enumerate_color_spaces_fb: Option<pfn::EnumerateColorSpacesFB>,
+    set_color_space_fb: Option<pfn::SetColorSpaceFB>,
+    create_foveation_profile_fb: Option<pfn::CreateFoveationProfileFB>,
+    destroy_foveation_profile_fb: Option<pfn::DestroyFoveationProfileFB>,
+    get_foveation_eye_tracked_state_meta: Option<pfn::GetFoveationEyeTrackedStateMETA>,
+    get_hand_mesh_fb: Option<pfn::GetHandMeshFB>,
+    enumerate_render_model_paths_fb: Option<pfn::EnumerateRenderModelPathsFB>,
+    get_render_model_properties_fb: Option<pfn::GetRenderModelPropertiesFB>,
+    load_render_model_fb: Option<pfn::LoadRenderModelFB>,
+    query_system_tracked_keyboard_fb: Option<pfn::QuerySystemTrackedKeyboardFB>,
+    create_keyboard_space_fb: Option<pfn::CreateKeyboardSpaceFB>,
+    set_environment_depth_estimation_varjo: Option<pfn::SetEnvironmentDepthEstimationVARJO>,
+    enumerate_reprojection_modes_msft: Option<pfn::EnumerateReprojectionModesMSFT>,
+    get_audio_output_device_guid_oculus: Option<pfn::GetAudioOutputDeviceGuidOculus>,
+    get_audio_input_device_guid_oculus: Option<pfn::GetAudioInputDeviceGuidOculus>,
+    create_spatial_anchor_fb: Option<pfn::CreateSpatialAnchorFB>,
+    get_space_uuid_fb: Option<pfn::GetSpaceUuidFB>,
+    enumerate_space_supported_components_fb: Option<pfn::EnumerateSpaceSupportedComponentsFB>,
+    set_space_component_status_fb: Option<pfn::SetSpaceComponentStatusFB>,
+    get_space_component_status_fb: Option<pfn::GetSpaceComponentStatusFB>,
+    create_triangle_mesh_fb: Option<pfn::CreateTriangleMeshFB>,
+    destroy_triangle_mesh_fb: Option<pfn::DestroyTriangleMeshFB>,
+    triangle_mesh_get_vertex_buffer_fb: Option<pfn::TriangleMeshGetVertexBufferFB>,
+    triangle_mesh_get_index_buffer_fb: Option<pfn::TriangleMeshGetIndexBufferFB>,
+    triangle_mesh_begin_update_fb: Option<pfn::TriangleMeshBeginUpdateFB>,
+    triangle_mesh_end_update_fb: Option<pfn::TriangleMeshEndUpdateFB>,
+    triangle_mesh_begin_vertex_buffer_update_fb: Option<pfn::TriangleMeshBeginVertexBufferUpdateFB>,
+    triangle_mesh_end_vertex_buffer_update_fb: Option<pfn::TriangleMeshEndVertexBufferUpdateFB>,
+    create_passthrough_fb: Option<pfn::CreatePassthroughFB>,
+    destroy_passthrough_fb: Option<pfn::DestroyPassthroughFB>,
+    passthrough_start_fb: Option<pfn::PassthroughStartFB>,
+    passthrough_pause_fb: Option<pfn::PassthroughPauseFB>,
+    create_passthrough_layer_fb: Option<pfn::CreatePassthroughLayerFB>,
+    destroy_passthrough_layer_fb: Option<pfn::DestroyPassthroughLayerFB>,
+    passthrough_layer_pause_fb: Option<pfn::PassthroughLayerPauseFB>,
+    passthrough_layer_resume_fb: Option<pfn::PassthroughLayerResumeFB>,
+    passthrough_layer_set_style_fb: Option<pfn::PassthroughLayerSetStyleFB>,
+    create_geometry_instance_fb: Option<pfn::CreateGeometryInstanceFB>,
+    destroy_geometry_instance_fb: Option<pfn::DestroyGeometryInstanceFB>,
+    geometry_instance_set_transform_fb: Option<pfn::GeometryInstanceSetTransformFB>,
+    query_spaces_fb: Option<pfn::QuerySpacesFB>,
+    retrieve_space_query_results_fb: Option<pfn::RetrieveSpaceQueryResultsFB>,
+    save_space_fb: Option<pfn::SaveSpaceFB>,
+    erase_space_fb: Option<pfn::EraseSpaceFB>,
+    save_space_list_fb: Option<pfn::SaveSpaceListFB>,
+    share_spaces_fb: Option<pfn::ShareSpacesFB>,
+    get_space_container_fb: Option<pfn::GetSpaceContainerFB>,
+    get_space_bounding_box2_dfb: Option<pfn::GetSpaceBoundingBox2DFB>,
+    get_space_bounding_box3_dfb: Option<pfn::GetSpaceBoundingBox3DFB>,
+    get_space_semantic_labels_fb: Option<pfn::GetSpaceSemanticLabelsFB>,
+    get_space_boundary2_dfb: Option<pfn::GetSpaceBoundary2DFB>,
+    get_space_room_layout_fb: Option<pfn::GetSpaceRoomLayoutFB>,
+    request_scene_capture_fb: Option<pfn::RequestSceneCaptureFB>,
+    passthrough_layer_set_keyboard_hands_intensity_fb:
+        Option<pfn::PassthroughLayerSetKeyboardHandsIntensityFB>,
+    create_spatial_anchor_store_connection_msft:
+        Option<pfn::CreateSpatialAnchorStoreConnectionMSFT>,
+    destroy_spatial_anchor_store_connection_msft:
+        Option<pfn::DestroySpatialAnchorStoreConnectionMSFT>,
+    persist_spatial_anchor_msft: Option<pfn::PersistSpatialAnchorMSFT>,
+    enumerate_persisted_spatial_anchor_names_msft:
+        Option<pfn::EnumeratePersistedSpatialAnchorNamesMSFT>,
+    create_spatial_anchor_from_persisted_name_msft:
+        Option<pfn::CreateSpatialAnchorFromPersistedNameMSFT>,
+    unpersist_spatial_anchor_msft: Option<pfn::UnpersistSpatialAnchorMSFT>,
+    clear_spatial_anchor_store_msft: Option<pfn::ClearSpatialAnchorStoreMSFT>,
+    create_facial_tracker_htc: Option<pfn::CreateFacialTrackerHTC>,
+    destroy_facial_tracker_htc: Option<pfn::DestroyFacialTrackerHTC>,
+    get_facial_expressions_htc: Option<pfn::GetFacialExpressionsHTC>,
+    create_passthrough_htc: Option<pfn::CreatePassthroughHTC>,
+    destroy_passthrough_htc: Option<pfn::DestroyPassthroughHTC>,
+    enumerate_vive_tracker_paths_htcx: Option<pfn::EnumerateViveTrackerPathsHTCX>,
+    set_marker_tracking_varjo: Option<pfn::SetMarkerTrackingVARJO>,
+    set_marker_tracking_timeout_varjo: Option<pfn::SetMarkerTrackingTimeoutVARJO>,
+    set_marker_tracking_prediction_varjo: Option<pfn::SetMarkerTrackingPredictionVARJO>,
+    get_marker_size_varjo: Option<pfn::GetMarkerSizeVARJO>,
+    create_marker_space_varjo: Option<pfn::CreateMarkerSpaceVARJO>,
+    set_digital_lens_control_almalence: Option<pfn::SetDigitalLensControlALMALENCE>,
+    set_view_offset_varjo: Option<pfn::SetViewOffsetVARJO>,
+    enumerate_external_cameras_oculus: Option<pfn::EnumerateExternalCamerasOCULUS>,
+    create_passthrough_color_lut_meta: Option<pfn::CreatePassthroughColorLutMETA>,
+    destroy_passthrough_color_lut_meta: Option<pfn::DestroyPassthroughColorLutMETA>,
+    update_passthrough_color_lut_meta: Option<pfn::UpdatePassthroughColorLutMETA>,
+    enumerate_performance_metrics_counter_paths_meta:
+        Option<pfn::EnumeratePerformanceMetricsCounterPathsMETA>,
+    set_performance_metrics_state_meta: Option<pfn::SetPerformanceMetricsStateMETA>,
+    get_performance_metrics_state_meta: Option<pfn::GetPerformanceMetricsStateMETA>,
+    query_performance_metrics_counter_meta: Option<pfn::QueryPerformanceMetricsCounterMETA>,
+    get_passthrough_preferences_meta: Option<pfn::GetPassthroughPreferencesMETA>,
+    apply_foveation_htc: Option<pfn::ApplyFoveationHTC>,
+    create_space_from_coordinate_frame_uidml: Option<pfn::CreateSpaceFromCoordinateFrameUIDML>,
+    get_device_sample_rate_fb: Option<pfn::GetDeviceSampleRateFB>,
+    set_tracking_optimization_settings_hint_qcom:
+        Option<pfn::SetTrackingOptimizationSettingsHintQCOM>,
+    create_space_user_fb: Option<pfn::CreateSpaceUserFB>,
+    get_space_user_id_fb: Option<pfn::GetSpaceUserIdFB>,
+    destroy_space_user_fb: Option<pfn::DestroySpaceUserFB>,
+    apply_force_feedback_curl_mndx: Option<pfn::ApplyForceFeedbackCurlMNDX>,
+    create_plane_detector_ext: Option<pfn::CreatePlaneDetectorEXT>,
+    destroy_plane_detector_ext: Option<pfn::DestroyPlaneDetectorEXT>,
+    begin_plane_detection_ext: Option<pfn::BeginPlaneDetectionEXT>,
+    get_plane_detection_state_ext: Option<pfn::GetPlaneDetectionStateEXT>,
+    get_plane_detections_ext: Option<pfn::GetPlaneDetectionsEXT>,
+    get_plane_polygon_buffer_ext: Option<pfn::GetPlanePolygonBufferEXT>,
+    create_virtual_keyboard_meta: Option<pfn::CreateVirtualKeyboardMETA>,
+    destroy_virtual_keyboard_meta: Option<pfn::DestroyVirtualKeyboardMETA>,
+    create_virtual_keyboard_space_meta: Option<pfn::CreateVirtualKeyboardSpaceMETA>,
+    suggest_virtual_keyboard_location_meta: Option<pfn::SuggestVirtualKeyboardLocationMETA>,
+    get_virtual_keyboard_scale_meta: Option<pfn::GetVirtualKeyboardScaleMETA>,
+    set_virtual_keyboard_model_visibility_meta: Option<pfn::SetVirtualKeyboardModelVisibilityMETA>,
+    get_virtual_keyboard_model_animation_states_meta:
+        Option<pfn::GetVirtualKeyboardModelAnimationStatesMETA>,
+    get_virtual_keyboard_dirty_textures_meta: Option<pfn::GetVirtualKeyboardDirtyTexturesMETA>,
+    get_virtual_keyboard_texture_data_meta: Option<pfn::GetVirtualKeyboardTextureDataMETA>,
+    send_virtual_keyboard_input_meta: Option<pfn::SendVirtualKeyboardInputMETA>,
+    change_virtual_keyboard_text_context_meta: Option<pfn::ChangeVirtualKeyboardTextContextMETA>,
+    get_vulkan_graphics_requirements2_khr: Option<pfn::GetVulkanGraphicsRequirements2KHR>,
+}
+
+impl Next {
+    pub(super) unsafe fn load(
+        get_instance_proc_addr: pfn::GetInstanceProcAddr,
+        instance: Instance,
+    ) -> Self {
+        Self {
+            enumerate_api_layer_properties: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrEnumerateApiLayerProperties\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::EnumerateApiLayerProperties>(f)),
+            enumerate_instance_extension_properties: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrEnumerateInstanceExtensionProperties\0",
+            )
+            .map(|f| {
+                mem::transmute::<pfn::VoidFunction, pfn::EnumerateInstanceExtensionProperties>(f)
+            }),
+            create_instance: super::load(get_instance_proc_addr, instance, b"xrCreateInstance\0")
+                .map(|f| mem::transmute::<pfn::VoidFunction, pfn::CreateInstance>(f)),
+            destroy_instance: super::load(get_instance_proc_addr, instance, b"xrDestroyInstance\0")
+                .map(|f| mem::transmute::<pfn::VoidFunction, pfn::DestroyInstance>(f)),
+            result_to_string: super::load(get_instance_proc_addr, instance, b"xrResultToString\0")
+                .map(|f| mem::transmute::<pfn::VoidFunction, pfn::ResultToString>(f)),
+            structure_type_to_string: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrStructureTypeToString\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::StructureTypeToString>(f)),
+            get_instance_properties: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetInstanceProperties\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetInstanceProperties>(f)),
+            get_system: super::load(get_instance_proc_addr, instance, b"xrGetSystem\0")
+                .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetSystem>(f)),
+            get_system_properties: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetSystemProperties\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetSystemProperties>(f)),
+            create_session: super::load(get_instance_proc_addr, instance, b"xrCreateSession\0")
+                .map(|f| mem::transmute::<pfn::VoidFunction, pfn::CreateSession>(f)),
+            destroy_session: super::load(get_instance_proc_addr, instance, b"xrDestroySession\0")
+                .map(|f| mem::transmute::<pfn::VoidFunction, pfn::DestroySession>(f)),
+            destroy_space: super::load(get_instance_proc_addr, instance, b"xrDestroySpace\0")
+                .map(|f| mem::transmute::<pfn::VoidFunction, pfn::DestroySpace>(f)),
+            enumerate_swapchain_formats: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrEnumerateSwapchainFormats\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::EnumerateSwapchainFormats>(f)),
+            create_swapchain: super::load(get_instance_proc_addr, instance, b"xrCreateSwapchain\0")
+                .map(|f| mem::transmute::<pfn::VoidFunction, pfn::CreateSwapchain>(f)),
+            destroy_swapchain: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrDestroySwapchain\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::DestroySwapchain>(f)),
+            enumerate_swapchain_images: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrEnumerateSwapchainImages\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::EnumerateSwapchainImages>(f)),
+            acquire_swapchain_image: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrAcquireSwapchainImage\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::AcquireSwapchainImage>(f)),
+            wait_swapchain_image: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrWaitSwapchainImage\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::WaitSwapchainImage>(f)),
+            release_swapchain_image: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrReleaseSwapchainImage\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::ReleaseSwapchainImage>(f)),
+            begin_session: super::load(get_instance_proc_addr, instance, b"xrBeginSession\0")
+                .map(|f| mem::transmute::<pfn::VoidFunction, pfn::BeginSession>(f)),
+            end_session: super::load(get_instance_proc_addr, instance, b"xrEndSession\0")
+                .map(|f| mem::transmute::<pfn::VoidFunction, pfn::EndSession>(f)),
+            request_exit_session: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrRequestExitSession\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::RequestExitSession>(f)),
+            enumerate_reference_spaces: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrEnumerateReferenceSpaces\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::EnumerateReferenceSpaces>(f)),
+            create_reference_space: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrCreateReferenceSpace\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::CreateReferenceSpace>(f)),
+            create_action_space: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrCreateActionSpace\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::CreateActionSpace>(f)),
+            locate_space: super::load(get_instance_proc_addr, instance, b"xrLocateSpace\0")
+                .map(|f| mem::transmute::<pfn::VoidFunction, pfn::LocateSpace>(f)),
+            enumerate_view_configurations: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrEnumerateViewConfigurations\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::EnumerateViewConfigurations>(f)),
+            enumerate_environment_blend_modes: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrEnumerateEnvironmentBlendModes\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::EnumerateEnvironmentBlendModes>(f)),
+            get_view_configuration_properties: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetViewConfigurationProperties\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetViewConfigurationProperties>(f)),
+            enumerate_view_configuration_views: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrEnumerateViewConfigurationViews\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::EnumerateViewConfigurationViews>(f)),
+            begin_frame: super::load(get_instance_proc_addr, instance, b"xrBeginFrame\0")
+                .map(|f| mem::transmute::<pfn::VoidFunction, pfn::BeginFrame>(f)),
+            locate_views: super::load(get_instance_proc_addr, instance, b"xrLocateViews\0")
+                .map(|f| mem::transmute::<pfn::VoidFunction, pfn::LocateViews>(f)),
+            end_frame: super::load(get_instance_proc_addr, instance, b"xrEndFrame\0")
+                .map(|f| mem::transmute::<pfn::VoidFunction, pfn::EndFrame>(f)),
+            wait_frame: super::load(get_instance_proc_addr, instance, b"xrWaitFrame\0")
+                .map(|f| mem::transmute::<pfn::VoidFunction, pfn::WaitFrame>(f)),
+            apply_haptic_feedback: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrApplyHapticFeedback\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::ApplyHapticFeedback>(f)),
+            stop_haptic_feedback: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrStopHapticFeedback\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::StopHapticFeedback>(f)),
+            poll_event: super::load(get_instance_proc_addr, instance, b"xrPollEvent\0")
+                .map(|f| mem::transmute::<pfn::VoidFunction, pfn::PollEvent>(f)),
+            string_to_path: super::load(get_instance_proc_addr, instance, b"xrStringToPath\0")
+                .map(|f| mem::transmute::<pfn::VoidFunction, pfn::StringToPath>(f)),
+            path_to_string: super::load(get_instance_proc_addr, instance, b"xrPathToString\0")
+                .map(|f| mem::transmute::<pfn::VoidFunction, pfn::PathToString>(f)),
+            get_reference_space_bounds_rect: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetReferenceSpaceBoundsRect\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetReferenceSpaceBoundsRect>(f)),
+            #[cfg(target_os = "android")]
+            set_android_application_thread_khr: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrSetAndroidApplicationThreadKHR\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::SetAndroidApplicationThreadKHR>(f)),
+            #[cfg(target_os = "android")]
+            create_swapchain_android_surface_khr: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrCreateSwapchainAndroidSurfaceKHR\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::CreateSwapchainAndroidSurfaceKHR>(f)),
+            get_action_state_boolean: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetActionStateBoolean\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetActionStateBoolean>(f)),
+            get_action_state_float: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetActionStateFloat\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetActionStateFloat>(f)),
+            get_action_state_vector2f: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetActionStateVector2f\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetActionStateVector2f>(f)),
+            get_action_state_pose: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetActionStatePose\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetActionStatePose>(f)),
+            create_action_set: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrCreateActionSet\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::CreateActionSet>(f)),
+            destroy_action_set: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrDestroyActionSet\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::DestroyActionSet>(f)),
+            create_action: super::load(get_instance_proc_addr, instance, b"xrCreateAction\0")
+                .map(|f| mem::transmute::<pfn::VoidFunction, pfn::CreateAction>(f)),
+            destroy_action: super::load(get_instance_proc_addr, instance, b"xrDestroyAction\0")
+                .map(|f| mem::transmute::<pfn::VoidFunction, pfn::DestroyAction>(f)),
+            suggest_interaction_profile_bindings: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrSuggestInteractionProfileBindings\0",
+            )
+            .map(|f| {
+                mem::transmute::<pfn::VoidFunction, pfn::SuggestInteractionProfileBindings>(f)
+            }),
+            attach_session_action_sets: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrAttachSessionActionSets\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::AttachSessionActionSets>(f)),
+            get_current_interaction_profile: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetCurrentInteractionProfile\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetCurrentInteractionProfile>(f)),
+            sync_actions: super::load(get_instance_proc_addr, instance, b"xrSyncActions\0")
+                .map(|f| mem::transmute::<pfn::VoidFunction, pfn::SyncActions>(f)),
+            enumerate_bound_sources_for_action: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrEnumerateBoundSourcesForAction\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::EnumerateBoundSourcesForAction>(f)),
+            get_input_source_localized_name: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetInputSourceLocalizedName\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetInputSourceLocalizedName>(f)),
+            locate_spaces: super::load(get_instance_proc_addr, instance, b"xrLocateSpaces\0")
+                .or_else(|| super::load(get_instance_proc_addr, instance, b"xrLocateSpacesKHR\0"))
+                .map(|f| mem::transmute::<pfn::VoidFunction, pfn::LocateSpaces>(f)),
+            get_vulkan_instance_extensions_khr: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetVulkanInstanceExtensionsKHR\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetVulkanInstanceExtensionsKHR>(f)),
+            get_vulkan_device_extensions_khr: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetVulkanDeviceExtensionsKHR\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetVulkanDeviceExtensionsKHR>(f)),
+            get_vulkan_graphics_device_khr: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetVulkanGraphicsDeviceKHR\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetVulkanGraphicsDeviceKHR>(f)),
+            get_open_gl_graphics_requirements_khr: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetOpenGLGraphicsRequirementsKHR\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetOpenGLGraphicsRequirementsKHR>(f)),
+            get_open_gles_graphics_requirements_khr: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetOpenGLESGraphicsRequirementsKHR\0",
+            )
+            .map(|f| {
+                mem::transmute::<pfn::VoidFunction, pfn::GetOpenGLESGraphicsRequirementsKHR>(f)
+            }),
+            get_vulkan_graphics_requirements_khr: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetVulkanGraphicsRequirementsKHR\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetVulkanGraphicsRequirementsKHR>(f)),
+            #[cfg(windows)]
+            get_d3d11_graphics_requirements_khr: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetD3D11GraphicsRequirementsKHR\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetD3D11GraphicsRequirementsKHR>(f)),
+            #[cfg(windows)]
+            get_d3d12_graphics_requirements_khr: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetD3D12GraphicsRequirementsKHR\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetD3D12GraphicsRequirementsKHR>(f)),
+            perf_settings_set_performance_level_ext: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrPerfSettingsSetPerformanceLevelEXT\0",
+            )
+            .map(|f| {
+                mem::transmute::<pfn::VoidFunction, pfn::PerfSettingsSetPerformanceLevelEXT>(f)
+            }),
+            thermal_get_temperature_trend_ext: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrThermalGetTemperatureTrendEXT\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::ThermalGetTemperatureTrendEXT>(f)),
+            set_debug_utils_object_name_ext: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrSetDebugUtilsObjectNameEXT\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::SetDebugUtilsObjectNameEXT>(f)),
+            create_debug_utils_messenger_ext: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrCreateDebugUtilsMessengerEXT\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::CreateDebugUtilsMessengerEXT>(f)),
+            destroy_debug_utils_messenger_ext: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrDestroyDebugUtilsMessengerEXT\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::DestroyDebugUtilsMessengerEXT>(f)),
+            submit_debug_utils_message_ext: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrSubmitDebugUtilsMessageEXT\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::SubmitDebugUtilsMessageEXT>(f)),
+            session_begin_debug_utils_label_region_ext: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrSessionBeginDebugUtilsLabelRegionEXT\0",
+            )
+            .map(|f| {
+                mem::transmute::<pfn::VoidFunction, pfn::SessionBeginDebugUtilsLabelRegionEXT>(f)
+            }),
+            session_end_debug_utils_label_region_ext: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrSessionEndDebugUtilsLabelRegionEXT\0",
+            )
+            .map(|f| {
+                mem::transmute::<pfn::VoidFunction, pfn::SessionEndDebugUtilsLabelRegionEXT>(f)
+            }),
+            session_insert_debug_utils_label_ext: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrSessionInsertDebugUtilsLabelEXT\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::SessionInsertDebugUtilsLabelEXT>(f)),
+            #[cfg(windows)]
+            convert_time_to_win32_performance_counter_khr: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrConvertTimeToWin32PerformanceCounterKHR\0",
+            )
+            .map(|f| {
+                mem::transmute::<pfn::VoidFunction, pfn::ConvertTimeToWin32PerformanceCounterKHR>(f)
+            }),
+            #[cfg(windows)]
+            convert_win32_performance_counter_to_time_khr: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrConvertWin32PerformanceCounterToTimeKHR\0",
+            )
+            .map(|f| {
+                mem::transmute::<pfn::VoidFunction, pfn::ConvertWin32PerformanceCounterToTimeKHR>(f)
+            }),
+            create_vulkan_instance_khr: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrCreateVulkanInstanceKHR\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::CreateVulkanInstanceKHR>(f)),
+            create_vulkan_device_khr: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrCreateVulkanDeviceKHR\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::CreateVulkanDeviceKHR>(f)),
+            get_vulkan_graphics_device2_khr: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetVulkanGraphicsDevice2KHR\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetVulkanGraphicsDevice2KHR>(f)),
+            convert_time_to_timespec_time_khr: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrConvertTimeToTimespecTimeKHR\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::ConvertTimeToTimespecTimeKHR>(f)),
+            convert_timespec_time_to_time_khr: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrConvertTimespecTimeToTimeKHR\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::ConvertTimespecTimeToTimeKHR>(f)),
+            get_visibility_mask_khr: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetVisibilityMaskKHR\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetVisibilityMaskKHR>(f)),
+            create_spatial_anchor_msft: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrCreateSpatialAnchorMSFT\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::CreateSpatialAnchorMSFT>(f)),
+            create_spatial_anchor_space_msft: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrCreateSpatialAnchorSpaceMSFT\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::CreateSpatialAnchorSpaceMSFT>(f)),
+            destroy_spatial_anchor_msft: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrDestroySpatialAnchorMSFT\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::DestroySpatialAnchorMSFT>(f)),
+            set_input_device_active_ext: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrSetInputDeviceActiveEXT\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::SetInputDeviceActiveEXT>(f)),
+            set_input_device_state_bool_ext: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrSetInputDeviceStateBoolEXT\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::SetInputDeviceStateBoolEXT>(f)),
+            set_input_device_state_float_ext: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrSetInputDeviceStateFloatEXT\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::SetInputDeviceStateFloatEXT>(f)),
+            set_input_device_state_vector2f_ext: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrSetInputDeviceStateVector2fEXT\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::SetInputDeviceStateVector2fEXT>(f)),
+            set_input_device_location_ext: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrSetInputDeviceLocationEXT\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::SetInputDeviceLocationEXT>(f)),
+            initialize_loader_khr: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrInitializeLoaderKHR\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::InitializeLoaderKHR>(f)),
+            create_spatial_graph_node_space_msft: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrCreateSpatialGraphNodeSpaceMSFT\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::CreateSpatialGraphNodeSpaceMSFT>(f)),
+            try_create_spatial_graph_static_node_binding_msft:
+                super::load(
+                    get_instance_proc_addr,
+                    instance,
+                    b"xrTryCreateSpatialGraphStaticNodeBindingMSFT\0",
+                )
+                .map(|f| {
+                    mem::transmute::<
+                        pfn::VoidFunction,
+                        pfn::TryCreateSpatialGraphStaticNodeBindingMSFT,
+                    >(f)
+                }),
+            destroy_spatial_graph_node_binding_msft: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrDestroySpatialGraphNodeBindingMSFT\0",
+            )
+            .map(|f| {
+                mem::transmute::<pfn::VoidFunction, pfn::DestroySpatialGraphNodeBindingMSFT>(f)
+            }),
+            get_spatial_graph_node_binding_properties_msft: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetSpatialGraphNodeBindingPropertiesMSFT\0",
+            )
+            .map(|f| {
+                mem::transmute::<pfn::VoidFunction, pfn::GetSpatialGraphNodeBindingPropertiesMSFT>(
+                    f,
+                )
+            }),
+            create_hand_tracker_ext: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrCreateHandTrackerEXT\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::CreateHandTrackerEXT>(f)),
+            destroy_hand_tracker_ext: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrDestroyHandTrackerEXT\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::DestroyHandTrackerEXT>(f)),
+            locate_hand_joints_ext: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrLocateHandJointsEXT\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::LocateHandJointsEXT>(f)),
+            create_face_tracker_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrCreateFaceTrackerFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::CreateFaceTrackerFB>(f)),
+            destroy_face_tracker_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrDestroyFaceTrackerFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::DestroyFaceTrackerFB>(f)),
+            get_face_expression_weights_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetFaceExpressionWeightsFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetFaceExpressionWeightsFB>(f)),
+            create_body_tracker_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrCreateBodyTrackerFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::CreateBodyTrackerFB>(f)),
+            destroy_body_tracker_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrDestroyBodyTrackerFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::DestroyBodyTrackerFB>(f)),
+            locate_body_joints_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrLocateBodyJointsFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::LocateBodyJointsFB>(f)),
+            get_body_skeleton_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetBodySkeletonFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetBodySkeletonFB>(f)),
+            create_eye_tracker_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrCreateEyeTrackerFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::CreateEyeTrackerFB>(f)),
+            destroy_eye_tracker_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrDestroyEyeTrackerFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::DestroyEyeTrackerFB>(f)),
+            get_eye_gazes_fb: super::load(get_instance_proc_addr, instance, b"xrGetEyeGazesFB\0")
+                .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetEyeGazesFB>(f)),
+            create_hand_mesh_space_msft: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrCreateHandMeshSpaceMSFT\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::CreateHandMeshSpaceMSFT>(f)),
+            update_hand_mesh_msft: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrUpdateHandMeshMSFT\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::UpdateHandMeshMSFT>(f)),
+            get_controller_model_key_msft: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetControllerModelKeyMSFT\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetControllerModelKeyMSFT>(f)),
+            load_controller_model_msft: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrLoadControllerModelMSFT\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::LoadControllerModelMSFT>(f)),
+            get_controller_model_properties_msft: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetControllerModelPropertiesMSFT\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetControllerModelPropertiesMSFT>(f)),
+            get_controller_model_state_msft: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetControllerModelStateMSFT\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetControllerModelStateMSFT>(f)),
+            enumerate_display_refresh_rates_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrEnumerateDisplayRefreshRatesFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::EnumerateDisplayRefreshRatesFB>(f)),
+            get_display_refresh_rate_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetDisplayRefreshRateFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetDisplayRefreshRateFB>(f)),
+            request_display_refresh_rate_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrRequestDisplayRefreshRateFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::RequestDisplayRefreshRateFB>(f)),
+            #[cfg(windows)]
+            create_spatial_anchor_from_perception_anchor_msft:
+                super::load(
+                    get_instance_proc_addr,
+                    instance,
+                    b"xrCreateSpatialAnchorFromPerceptionAnchorMSFT\0",
+                )
+                .map(|f| {
+                    mem::transmute::<
+                        pfn::VoidFunction,
+                        pfn::CreateSpatialAnchorFromPerceptionAnchorMSFT,
+                    >(f)
+                }),
+            #[cfg(windows)]
+            try_get_perception_anchor_from_spatial_anchor_msft:
+                super::load(
+                    get_instance_proc_addr,
+                    instance,
+                    b"xrTryGetPerceptionAnchorFromSpatialAnchorMSFT\0",
+                )
+                .map(|f| {
+                    mem::transmute::<
+                        pfn::VoidFunction,
+                        pfn::TryGetPerceptionAnchorFromSpatialAnchorMSFT,
+                    >(f)
+                }),
+            update_swapchain_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrUpdateSwapchainFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::UpdateSwapchainFB>(f)),
+            get_swapchain_state_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetSwapchainStateFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetSwapchainStateFB>(f)),
+            enumerate_color_spaces_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrEnumerateColorSpacesFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::EnumerateColorSpacesFB>(f)),
+            set_color_space_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrSetColorSpaceFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::SetColorSpaceFB>(f)),
+            create_foveation_profile_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrCreateFoveationProfileFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::CreateFoveationProfileFB>(f)),
+            destroy_foveation_profile_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrDestroyFoveationProfileFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::DestroyFoveationProfileFB>(f)),
+            get_foveation_eye_tracked_state_meta: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetFoveationEyeTrackedStateMETA\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetFoveationEyeTrackedStateMETA>(f)),
+            get_hand_mesh_fb: super::load(get_instance_proc_addr, instance, b"xrGetHandMeshFB\0")
+                .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetHandMeshFB>(f)),
+            enumerate_render_model_paths_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrEnumerateRenderModelPathsFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::EnumerateRenderModelPathsFB>(f)),
+            get_render_model_properties_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetRenderModelPropertiesFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetRenderModelPropertiesFB>(f)),
+            load_render_model_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrLoadRenderModelFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::LoadRenderModelFB>(f)),
+            query_system_tracked_keyboard_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrQuerySystemTrackedKeyboardFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::QuerySystemTrackedKeyboardFB>(f)),
+            create_keyboard_space_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrCreateKeyboardSpaceFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::CreateKeyboardSpaceFB>(f)),
+            set_environment_depth_estimation_varjo: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrSetEnvironmentDepthEstimationVARJO\0",
+            )
+            .map(|f| {
+                mem::transmute::<pfn::VoidFunction, pfn::SetEnvironmentDepthEstimationVARJO>(f)
+            }),
+            enumerate_reprojection_modes_msft: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrEnumerateReprojectionModesMSFT\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::EnumerateReprojectionModesMSFT>(f)),
+            get_audio_output_device_guid_oculus: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetAudioOutputDeviceGuidOculus\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetAudioOutputDeviceGuidOculus>(f)),
+            get_audio_input_device_guid_oculus: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetAudioInputDeviceGuidOculus\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetAudioInputDeviceGuidOculus>(f)),
+            create_spatial_anchor_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrCreateSpatialAnchorFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::CreateSpatialAnchorFB>(f)),
+            get_space_uuid_fb: super::load(get_instance_proc_addr, instance, b"xrGetSpaceUuidFB\0")
+                .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetSpaceUuidFB>(f)),
+            enumerate_space_supported_components_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrEnumerateSpaceSupportedComponentsFB\0",
+            )
+            .map(|f| {
+                mem::transmute::<pfn::VoidFunction, pfn::EnumerateSpaceSupportedComponentsFB>(f)
+            }),
+            set_space_component_status_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrSetSpaceComponentStatusFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::SetSpaceComponentStatusFB>(f)),
+            get_space_component_status_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetSpaceComponentStatusFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetSpaceComponentStatusFB>(f)),
+            create_triangle_mesh_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrCreateTriangleMeshFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::CreateTriangleMeshFB>(f)),
+            destroy_triangle_mesh_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrDestroyTriangleMeshFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::DestroyTriangleMeshFB>(f)),
+            triangle_mesh_get_vertex_buffer_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrTriangleMeshGetVertexBufferFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::TriangleMeshGetVertexBufferFB>(f)),
+            triangle_mesh_get_index_buffer_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrTriangleMeshGetIndexBufferFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::TriangleMeshGetIndexBufferFB>(f)),
+            triangle_mesh_begin_update_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrTriangleMeshBeginUpdateFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::TriangleMeshBeginUpdateFB>(f)),
+            triangle_mesh_end_update_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrTriangleMeshEndUpdateFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::TriangleMeshEndUpdateFB>(f)),
+            triangle_mesh_begin_vertex_buffer_update_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrTriangleMeshBeginVertexBufferUpdateFB\0",
+            )
+            .map(|f| {
+                mem::transmute::<pfn::VoidFunction, pfn::TriangleMeshBeginVertexBufferUpdateFB>(f)
+            }),
+            triangle_mesh_end_vertex_buffer_update_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrTriangleMeshEndVertexBufferUpdateFB\0",
+            )
+            .map(|f| {
+                mem::transmute::<pfn::VoidFunction, pfn::TriangleMeshEndVertexBufferUpdateFB>(f)
+            }),
+            create_passthrough_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrCreatePassthroughFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::CreatePassthroughFB>(f)),
+            destroy_passthrough_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrDestroyPassthroughFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::DestroyPassthroughFB>(f)),
+            passthrough_start_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrPassthroughStartFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::PassthroughStartFB>(f)),
+            passthrough_pause_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrPassthroughPauseFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::PassthroughPauseFB>(f)),
+            create_passthrough_layer_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrCreatePassthroughLayerFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::CreatePassthroughLayerFB>(f)),
+            destroy_passthrough_layer_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrDestroyPassthroughLayerFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::DestroyPassthroughLayerFB>(f)),
+            passthrough_layer_pause_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrPassthroughLayerPauseFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::PassthroughLayerPauseFB>(f)),
+            passthrough_layer_resume_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrPassthroughLayerResumeFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::PassthroughLayerResumeFB>(f)),
+            passthrough_layer_set_style_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrPassthroughLayerSetStyleFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::PassthroughLayerSetStyleFB>(f)),
+            create_geometry_instance_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrCreateGeometryInstanceFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::CreateGeometryInstanceFB>(f)),
+            destroy_geometry_instance_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrDestroyGeometryInstanceFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::DestroyGeometryInstanceFB>(f)),
+            geometry_instance_set_transform_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGeometryInstanceSetTransformFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GeometryInstanceSetTransformFB>(f)),
+            query_spaces_fb: super::load(get_instance_proc_addr, instance, b"xrQuerySpacesFB\0")
+                .map(|f| mem::transmute::<pfn::VoidFunction, pfn::QuerySpacesFB>(f)),
+            retrieve_space_query_results_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrRetrieveSpaceQueryResultsFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::RetrieveSpaceQueryResultsFB>(f)),
+            save_space_fb: super::load(get_instance_proc_addr, instance, b"xrSaveSpaceFB\0")
+                .map(|f| mem::transmute::<pfn::VoidFunction, pfn::SaveSpaceFB>(f)),
+            erase_space_fb: super::load(get_instance_proc_addr, instance, b"xrEraseSpaceFB\0")
+                .map(|f| mem::transmute::<pfn::VoidFunction, pfn::EraseSpaceFB>(f)),
+            save_space_list_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrSaveSpaceListFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::SaveSpaceListFB>(f)),
+            share_spaces_fb: super::load(get_instance_proc_addr, instance, b"xrShareSpacesFB\0")
+                .map(|f| mem::transmute::<pfn::VoidFunction, pfn::ShareSpacesFB>(f)),
+            get_space_container_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetSpaceContainerFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetSpaceContainerFB>(f)),
+            get_space_bounding_box2_dfb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetSpaceBoundingBox2DFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetSpaceBoundingBox2DFB>(f)),
+            get_space_bounding_box3_dfb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetSpaceBoundingBox3DFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetSpaceBoundingBox3DFB>(f)),
+            get_space_semantic_labels_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetSpaceSemanticLabelsFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetSpaceSemanticLabelsFB>(f)),
+            get_space_boundary2_dfb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetSpaceBoundary2DFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetSpaceBoundary2DFB>(f)),
+            get_space_room_layout_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetSpaceRoomLayoutFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetSpaceRoomLayoutFB>(f)),
+            request_scene_capture_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrRequestSceneCaptureFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::RequestSceneCaptureFB>(f)),
+            passthrough_layer_set_keyboard_hands_intensity_fb:
+                super::load(
+                    get_instance_proc_addr,
+                    instance,
+                    b"xrPassthroughLayerSetKeyboardHandsIntensityFB\0",
+                )
+                .map(|f| {
+                    mem::transmute::<
+                        pfn::VoidFunction,
+                        pfn::PassthroughLayerSetKeyboardHandsIntensityFB,
+                    >(f)
+                }),
+            create_spatial_anchor_store_connection_msft: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrCreateSpatialAnchorStoreConnectionMSFT\0",
+            )
+            .map(|f| {
+                mem::transmute::<pfn::VoidFunction, pfn::CreateSpatialAnchorStoreConnectionMSFT>(f)
+            }),
+            destroy_spatial_anchor_store_connection_msft: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrDestroySpatialAnchorStoreConnectionMSFT\0",
+            )
+            .map(|f| {
+                mem::transmute::<pfn::VoidFunction, pfn::DestroySpatialAnchorStoreConnectionMSFT>(f)
+            }),
+            persist_spatial_anchor_msft: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrPersistSpatialAnchorMSFT\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::PersistSpatialAnchorMSFT>(f)),
+            enumerate_persisted_spatial_anchor_names_msft: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrEnumeratePersistedSpatialAnchorNamesMSFT\0",
+            )
+            .map(|f| {
+                mem::transmute::<pfn::VoidFunction, pfn::EnumeratePersistedSpatialAnchorNamesMSFT>(
+                    f,
+                )
+            }),
+            create_spatial_anchor_from_persisted_name_msft: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrCreateSpatialAnchorFromPersistedNameMSFT\0",
+            )
+            .map(|f| {
+                mem::transmute::<pfn::VoidFunction, pfn::CreateSpatialAnchorFromPersistedNameMSFT>(
+                    f,
+                )
+            }),
+            unpersist_spatial_anchor_msft: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrUnpersistSpatialAnchorMSFT\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::UnpersistSpatialAnchorMSFT>(f)),
+            clear_spatial_anchor_store_msft: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrClearSpatialAnchorStoreMSFT\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::ClearSpatialAnchorStoreMSFT>(f)),
+            create_facial_tracker_htc: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrCreateFacialTrackerHTC\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::CreateFacialTrackerHTC>(f)),
+            destroy_facial_tracker_htc: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrDestroyFacialTrackerHTC\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::DestroyFacialTrackerHTC>(f)),
+            get_facial_expressions_htc: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetFacialExpressionsHTC\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetFacialExpressionsHTC>(f)),
+            create_passthrough_htc: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrCreatePassthroughHTC\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::CreatePassthroughHTC>(f)),
+            destroy_passthrough_htc: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrDestroyPassthroughHTC\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::DestroyPassthroughHTC>(f)),
+            enumerate_vive_tracker_paths_htcx: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrEnumerateViveTrackerPathsHTCX\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::EnumerateViveTrackerPathsHTCX>(f)),
+            set_marker_tracking_varjo: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrSetMarkerTrackingVARJO\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::SetMarkerTrackingVARJO>(f)),
+            set_marker_tracking_timeout_varjo: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrSetMarkerTrackingTimeoutVARJO\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::SetMarkerTrackingTimeoutVARJO>(f)),
+            set_marker_tracking_prediction_varjo: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrSetMarkerTrackingPredictionVARJO\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::SetMarkerTrackingPredictionVARJO>(f)),
+            get_marker_size_varjo: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetMarkerSizeVARJO\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetMarkerSizeVARJO>(f)),
+            create_marker_space_varjo: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrCreateMarkerSpaceVARJO\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::CreateMarkerSpaceVARJO>(f)),
+            set_digital_lens_control_almalence: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrSetDigitalLensControlALMALENCE\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::SetDigitalLensControlALMALENCE>(f)),
+            set_view_offset_varjo: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrSetViewOffsetVARJO\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::SetViewOffsetVARJO>(f)),
+            enumerate_external_cameras_oculus: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrEnumerateExternalCamerasOCULUS\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::EnumerateExternalCamerasOCULUS>(f)),
+            create_passthrough_color_lut_meta: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrCreatePassthroughColorLutMETA\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::CreatePassthroughColorLutMETA>(f)),
+            destroy_passthrough_color_lut_meta: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrDestroyPassthroughColorLutMETA\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::DestroyPassthroughColorLutMETA>(f)),
+            update_passthrough_color_lut_meta: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrUpdatePassthroughColorLutMETA\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::UpdatePassthroughColorLutMETA>(f)),
+            enumerate_performance_metrics_counter_paths_meta:
+                super::load(
+                    get_instance_proc_addr,
+                    instance,
+                    b"xrEnumeratePerformanceMetricsCounterPathsMETA\0",
+                )
+                .map(|f| {
+                    mem::transmute::<
+                        pfn::VoidFunction,
+                        pfn::EnumeratePerformanceMetricsCounterPathsMETA,
+                    >(f)
+                }),
+            set_performance_metrics_state_meta: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrSetPerformanceMetricsStateMETA\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::SetPerformanceMetricsStateMETA>(f)),
+            get_performance_metrics_state_meta: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetPerformanceMetricsStateMETA\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetPerformanceMetricsStateMETA>(f)),
+            query_performance_metrics_counter_meta: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrQueryPerformanceMetricsCounterMETA\0",
+            )
+            .map(|f| {
+                mem::transmute::<pfn::VoidFunction, pfn::QueryPerformanceMetricsCounterMETA>(f)
+            }),
+            get_passthrough_preferences_meta: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetPassthroughPreferencesMETA\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetPassthroughPreferencesMETA>(f)),
+            apply_foveation_htc: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrApplyFoveationHTC\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::ApplyFoveationHTC>(f)),
+            create_space_from_coordinate_frame_uidml: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrCreateSpaceFromCoordinateFrameUIDML\0",
+            )
+            .map(|f| {
+                mem::transmute::<pfn::VoidFunction, pfn::CreateSpaceFromCoordinateFrameUIDML>(f)
+            }),
+            get_device_sample_rate_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetDeviceSampleRateFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetDeviceSampleRateFB>(f)),
+            set_tracking_optimization_settings_hint_qcom: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrSetTrackingOptimizationSettingsHintQCOM\0",
+            )
+            .map(|f| {
+                mem::transmute::<pfn::VoidFunction, pfn::SetTrackingOptimizationSettingsHintQCOM>(f)
+            }),
+            create_space_user_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrCreateSpaceUserFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::CreateSpaceUserFB>(f)),
+            get_space_user_id_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetSpaceUserIdFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetSpaceUserIdFB>(f)),
+            destroy_space_user_fb: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrDestroySpaceUserFB\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::DestroySpaceUserFB>(f)),
+            apply_force_feedback_curl_mndx: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrApplyForceFeedbackCurlMNDX\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::ApplyForceFeedbackCurlMNDX>(f)),
+            create_plane_detector_ext: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrCreatePlaneDetectorEXT\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::CreatePlaneDetectorEXT>(f)),
+            destroy_plane_detector_ext: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrDestroyPlaneDetectorEXT\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::DestroyPlaneDetectorEXT>(f)),
+            begin_plane_detection_ext: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrBeginPlaneDetectionEXT\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::BeginPlaneDetectionEXT>(f)),
+            get_plane_detection_state_ext: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetPlaneDetectionStateEXT\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetPlaneDetectionStateEXT>(f)),
+            get_plane_detections_ext: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetPlaneDetectionsEXT\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetPlaneDetectionsEXT>(f)),
+            get_plane_polygon_buffer_ext: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetPlanePolygonBufferEXT\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetPlanePolygonBufferEXT>(f)),
+            create_virtual_keyboard_meta: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrCreateVirtualKeyboardMETA\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::CreateVirtualKeyboardMETA>(f)),
+            destroy_virtual_keyboard_meta: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrDestroyVirtualKeyboardMETA\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::DestroyVirtualKeyboardMETA>(f)),
+            create_virtual_keyboard_space_meta: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrCreateVirtualKeyboardSpaceMETA\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::CreateVirtualKeyboardSpaceMETA>(f)),
+            suggest_virtual_keyboard_location_meta: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrSuggestVirtualKeyboardLocationMETA\0",
+            )
+            .map(|f| {
+                mem::transmute::<pfn::VoidFunction, pfn::SuggestVirtualKeyboardLocationMETA>(f)
+            }),
+            get_virtual_keyboard_scale_meta: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetVirtualKeyboardScaleMETA\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::GetVirtualKeyboardScaleMETA>(f)),
+            set_virtual_keyboard_model_visibility_meta: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrSetVirtualKeyboardModelVisibilityMETA\0",
+            )
+            .map(|f| {
+                mem::transmute::<pfn::VoidFunction, pfn::SetVirtualKeyboardModelVisibilityMETA>(f)
+            }),
+            get_virtual_keyboard_model_animation_states_meta:
+                super::load(
+                    get_instance_proc_addr,
+                    instance,
+                    b"xrGetVirtualKeyboardModelAnimationStatesMETA\0",
+                )
+                .map(|f| {
+                    mem::transmute::<
+                        pfn::VoidFunction,
+                        pfn::GetVirtualKeyboardModelAnimationStatesMETA,
+                    >(f)
+                }),
+            get_virtual_keyboard_dirty_textures_meta: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetVirtualKeyboardDirtyTexturesMETA\0",
+            )
+            .map(|f| {
+                mem::transmute::<pfn::VoidFunction, pfn::GetVirtualKeyboardDirtyTexturesMETA>(f)
+            }),
+            get_virtual_keyboard_texture_data_meta: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetVirtualKeyboardTextureDataMETA\0",
+            )
+            .map(|f| {
+                mem::transmute::<pfn::VoidFunction, pfn::GetVirtualKeyboardTextureDataMETA>(f)
+            }),
+            send_virtual_keyboard_input_meta: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrSendVirtualKeyboardInputMETA\0",
+            )
+            .map(|f| mem::transmute::<pfn::VoidFunction, pfn::SendVirtualKeyboardInputMETA>(f)),
+            change_virtual_keyboard_text_context_meta: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrChangeVirtualKeyboardTextContextMETA\0",
+            )
+            .map(|f| {
+                mem::transmute::<pfn::VoidFunction, pfn::ChangeVirtualKeyboardTextContextMETA>(f)
+            }),
+            get_vulkan_graphics_requirements2_khr: super::load(
+                get_instance_proc_addr,
+                instance,
+                b"xrGetVulkanGraphicsRequirements2KHR\0",
+            )
+            .map(|f| {
+                mem::transmute::<pfn::VoidFunction, pfn::GetVulkanGraphicsRequirements2KHR>(f)
+            }),
+        }
+    }
+}
+
+unsafe extern "system" fn enumerate_api_layer_properties(
+    property_capacity_input: u32,
+    property_count_output: *mut u32,
+    properties: *mut ApiLayerProperties,
+) -> Result {
+    super::call(
+        "xrEnumerateApiLayerProperties",
+        0,
+        |inputs| {
+            inputs.value(&property_capacity_input);
+        },
+        |next| match next.enumerate_api_layer_properties {
+            Some(f) => f(property_capacity_input, property_count_output, properties),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.chain_array(property_capacity_input, property_count_output, properties);
+        },
+    )
+}
+unsafe extern "system" fn enumerate_instance_extension_properties(
+    layer_name: *const c_char,
+    property_capacity_input: u32,
+    property_count_output: *mut u32,
+    properties: *mut ExtensionProperties,
+) -> Result {
+    super::call(
+        "xrEnumerateInstanceExtensionProperties",
+        0,
+        |inputs| {
+            inputs.string(layer_name);
+            inputs.value(&property_capacity_input);
+        },
+        |next| match next.enumerate_instance_extension_properties {
+            Some(f) => f(
+                layer_name,
+                property_capacity_input,
+                property_count_output,
+                properties,
+            ),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.chain_array(property_capacity_input, property_count_output, properties);
+        },
+    )
+}
+unsafe extern "system" fn create_instance(
+    create_info: *const InstanceCreateInfo,
+    instance: *mut Instance,
+) -> Result {
+    super::call(
+        "xrCreateInstance",
+        0,
+        |inputs| {
+            inputs.chain(create_info);
+        },
+        |next| match next.create_instance {
+            Some(f) => f(create_info, instance),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.pointer(instance);
+        },
+    )
+}
+unsafe extern "system" fn destroy_instance(instance: Instance) -> Result {
+    super::call(
+        "xrDestroyInstance",
+        instance.into_raw(),
+        |inputs| {
+            inputs.value(&instance);
+        },
+        |next| match next.destroy_instance {
+            Some(f) => f(instance),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |_| {},
+    )
+}
+unsafe extern "system" fn result_to_string(
+    instance: Instance,
+    value: Result,
+    buffer: *mut c_char,
+) -> Result {
+    super::call(
+        "xrResultToString",
+        instance.into_raw(),
+        |inputs| {
+            inputs.value(&instance);
+            inputs.value(&value);
+        },
+        |next| match next.result_to_string {
+            Some(f) => f(instance, value, buffer),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.fixed(buffer, MAX_RESULT_STRING_SIZE);
+        },
+    )
+}
+unsafe extern "system" fn structure_type_to_string(
+    instance: Instance,
+    value: StructureType,
+    buffer: *mut c_char,
+) -> Result {
+    super::call(
+        "xrStructureTypeToString",
+        instance.into_raw(),
+        |inputs| {
+            inputs.value(&instance);
+            inputs.value(&value);
+        },
+        |next| match next.structure_type_to_string {
+            Some(f) => f(instance, value, buffer),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.fixed(buffer, MAX_STRUCTURE_NAME_SIZE);
+        },
+    )
+}
+unsafe extern "system" fn get_instance_properties(
+    instance: Instance,
+    instance_properties: *mut InstanceProperties,
+) -> Result {
+    super::call(
+        "xrGetInstanceProperties",
+        instance.into_raw(),
+        |inputs| {
+            inputs.value(&instance);
+        },
+        |next| match next.get_instance_properties {
+            Some(f) => f(instance, instance_properties),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.chain(instance_properties);
+        },
+    )
+}
+unsafe extern "system" fn get_system(
+    instance: Instance,
+    get_info: *const SystemGetInfo,
+    system_id: *mut SystemId,
+) -> Result {
+    super::call(
+        "xrGetSystem",
+        instance.into_raw(),
+        |inputs| {
+            inputs.value(&instance);
+            inputs.chain(get_info);
+        },
+        |next| match next.get_system {
+            Some(f) => f(instance, get_info, system_id),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.pointer(system_id);
+        },
+    )
+}
+unsafe extern "system" fn get_system_properties(
+    instance: Instance,
+    system_id: SystemId,
+    properties: *mut SystemProperties,
+) -> Result {
+    super::call(
+        "xrGetSystemProperties",
+        instance.into_raw(),
+        |inputs| {
+            inputs.value(&instance);
+            inputs.value(&system_id);
+        },
+        |next| match next.get_system_properties {
+            Some(f) => f(instance, system_id, properties),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.chain(properties);
+        },
+    )
+}
+unsafe extern "system" fn create_session(
+    instance: Instance,
+    create_info: *const SessionCreateInfo,
+    session: *mut Session,
+) -> Result {
+    super::call(
+        "xrCreateSession",
+        instance.into_raw(),
+        |inputs| {
+            inputs.value(&instance);
+            inputs.chain(create_info);
+        },
+        |next| match next.create_session {
+            Some(f) => f(instance, create_info, session),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.pointer(session);
+        },
+    )
+}
+unsafe extern "system" fn destroy_session(session: Session) -> Result {
+    super::call(
+        "xrDestroySession",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+        },
+        |next| match next.destroy_session {
+            Some(f) => f(session),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |_| {},
+    )
+}
+unsafe extern "system" fn destroy_space(space: Space) -> Result {
+    super::call(
+        "xrDestroySpace",
+        space.into_raw(),
+        |inputs| {
+            inputs.value(&space);
+        },
+        |next| match next.destroy_space {
+            Some(f) => f(space),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |_| {},
+    )
+}
+unsafe extern "system" fn enumerate_swapchain_formats(
+    session: Session,
+    format_capacity_input: u32,
+    format_count_output: *mut u32,
+    formats: *mut i64,
+) -> Result {
+    super::call(
+        "xrEnumerateSwapchainFormats",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.value(&format_capacity_input);
+        },
+        |next| match next.enumerate_swapchain_formats {
+            Some(f) => f(session, format_capacity_input, format_count_output, formats),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.array(format_capacity_input, format_count_output, formats);
+        },
+    )
+}
+unsafe extern "system" fn create_swapchain(
+    session: Session,
+    create_info: *const SwapchainCreateInfo,
+    swapchain: *mut Swapchain,
+) -> Result {
+    super::call(
+        "xrCreateSwapchain",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.chain(create_info);
+        },
+        |next| match next.create_swapchain {
+            Some(f) => f(session, create_info, swapchain),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.pointer(swapchain);
+        },
+    )
+}
+unsafe extern "system" fn destroy_swapchain(swapchain: Swapchain) -> Result {
+    super::call(
+        "xrDestroySwapchain",
+        swapchain.into_raw(),
+        |inputs| {
+            inputs.value(&swapchain);
+        },
+        |next| match next.destroy_swapchain {
+            Some(f) => f(swapchain),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |_| {},
+    )
+}
+unsafe extern "system" fn enumerate_swapchain_images(
+    swapchain: Swapchain,
+    image_capacity_input: u32,
+    image_count_output: *mut u32,
+    images: *mut SwapchainImageBaseHeader,
+) -> Result {
+    super::call(
+        "xrEnumerateSwapchainImages",
+        swapchain.into_raw(),
+        |inputs| {
+            inputs.value(&swapchain);
+            inputs.value(&image_capacity_input);
+        },
+        |next| match next.enumerate_swapchain_images {
+            Some(f) => f(swapchain, image_capacity_input, image_count_output, images),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.chain_array(image_capacity_input, image_count_output, images);
+        },
+    )
+}
+unsafe extern "system" fn acquire_swapchain_image(
+    swapchain: Swapchain,
+    acquire_info: *const SwapchainImageAcquireInfo,
+    index: *mut u32,
+) -> Result {
+    super::call(
+        "xrAcquireSwapchainImage",
+        swapchain.into_raw(),
+        |inputs| {
+            inputs.value(&swapchain);
+            inputs.chain(acquire_info);
+        },
+        |next| match next.acquire_swapchain_image {
+            Some(f) => f(swapchain, acquire_info, index),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.pointer(index);
+        },
+    )
+}
+unsafe extern "system" fn wait_swapchain_image(
+    swapchain: Swapchain,
+    wait_info: *const SwapchainImageWaitInfo,
+) -> Result {
+    super::call(
+        "xrWaitSwapchainImage",
+        swapchain.into_raw(),
+        |inputs| {
+            inputs.value(&swapchain);
+            inputs.chain(wait_info);
+        },
+        |next| match next.wait_swapchain_image {
+            Some(f) => f(swapchain, wait_info),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |_| {},
+    )
+}
+unsafe extern "system" fn release_swapchain_image(
+    swapchain: Swapchain,
+    release_info: *const SwapchainImageReleaseInfo,
+) -> Result {
+    super::call(
+        "xrReleaseSwapchainImage",
+        swapchain.into_raw(),
+        |inputs| {
+            inputs.value(&swapchain);
+            inputs.chain(release_info);
+        },
+        |next| match next.release_swapchain_image {
+            Some(f) => f(swapchain, release_info),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |_| {},
+    )
+}
+unsafe extern "system" fn begin_session(
+    session: Session,
+    begin_info: *const SessionBeginInfo,
+) -> Result {
+    super::call(
+        "xrBeginSession",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.chain(begin_info);
+        },
+        |next| match next.begin_session {
+            Some(f) => f(session, begin_info),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |_| {},
+    )
+}
+unsafe extern "system" fn end_session(session: Session) -> Result {
+    super::call(
+        "xrEndSession",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+        },
+        |next| match next.end_session {
+            Some(f) => f(session),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |_| {},
+    )
+}
+unsafe extern "system" fn request_exit_session(session: Session) -> Result {
+    super::call(
+        "xrRequestExitSession",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+        },
+        |next| match next.request_exit_session {
+            Some(f) => f(session),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |_| {},
+    )
+}
+unsafe extern "system" fn enumerate_reference_spaces(
+    session: Session,
+    space_capacity_input: u32,
+    space_count_output: *mut u32,
+    spaces: *mut ReferenceSpaceType,
+) -> Result {
+    super::call(
+        "xrEnumerateReferenceSpaces",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.value(&space_capacity_input);
+        },
+        |next| match next.enumerate_reference_spaces {
+            Some(f) => f(session, space_capacity_input, space_count_output, spaces),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.array(space_capacity_input, space_count_output, spaces);
+        },
+    )
+}
+unsafe extern "system" fn create_reference_space(
+    session: Session,
+    create_info: *const ReferenceSpaceCreateInfo,
+    space: *mut Space,
+) -> Result {
+    super::call(
+        "xrCreateReferenceSpace",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.chain(create_info);
+        },
+        |next| match next.create_reference_space {
+            Some(f) => f(session, create_info, space),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.pointer(space);
+        },
+    )
+}
+unsafe extern "system" fn create_action_space(
+    session: Session,
+    create_info: *const ActionSpaceCreateInfo,
+    space: *mut Space,
+) -> Result {
+    super::call(
+        "xrCreateActionSpace",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.chain(create_info);
+        },
+        |next| match next.create_action_space {
+            Some(f) => f(session, create_info, space),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.pointer(space);
+        },
+    )
+}
+unsafe extern "system" fn locate_space(
+    space: Space,
+    base_space: Space,
+    time: Time,
+    location: *mut SpaceLocation,
+) -> Result {
+    super::call(
+        "xrLocateSpace",
+        space.into_raw(),
+        |inputs| {
+            inputs.value(&space);
+            inputs.value(&base_space);
+            inputs.value(&time);
+        },
+        |next| match next.locate_space {
+            Some(f) => f(space, base_space, time, location),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.chain(location);
+        },
+    )
+}
+unsafe extern "system" fn enumerate_view_configurations(
+    instance: Instance,
+    system_id: SystemId,
+    view_configuration_type_capacity_input: u32,
+    view_configuration_type_count_output: *mut u32,
+    view_configuration_types: *mut ViewConfigurationType,
+) -> Result {
+    super::call(
+        "xrEnumerateViewConfigurations",
+        instance.into_raw(),
+        |inputs| {
+            inputs.value(&instance);
+            inputs.value(&system_id);
+            inputs.value(&view_configuration_type_capacity_input);
+        },
+        |next| match next.enumerate_view_configurations {
+            Some(f) => f(
+                instance,
+                system_id,
+                view_configuration_type_capacity_input,
+                view_configuration_type_count_output,
+                view_configuration_types,
+            ),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.array(
+                view_configuration_type_capacity_input,
+                view_configuration_type_count_output,
+                view_configuration_types,
+            );
+        },
+    )
+}
+unsafe extern "system" fn enumerate_environment_blend_modes(
+    instance: Instance,
+    system_id: SystemId,
+    view_configuration_type: ViewConfigurationType,
+    environment_blend_mode_capacity_input: u32,
+    environment_blend_mode_count_output: *mut u32,
+    environment_blend_modes: *mut EnvironmentBlendMode,
+) -> Result {
+    super::call(
+        "xrEnumerateEnvironmentBlendModes",
+        instance.into_raw(),
+        |inputs| {
+            inputs.value(&instance);
+            inputs.value(&system_id);
+            inputs.value(&view_configuration_type);
+            inputs.value(&environment_blend_mode_capacity_input);
+        },
+        |next| match next.enumerate_environment_blend_modes {
+            Some(f) => f(
+                instance,
+                system_id,
+                view_configuration_type,
+                environment_blend_mode_capacity_input,
+                environment_blend_mode_count_output,
+                environment_blend_modes,
+            ),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.array(
+                environment_blend_mode_capacity_input,
+                environment_blend_mode_count_output,
+                environment_blend_modes,
+            );
+        },
+    )
+}
+unsafe extern "system" fn get_view_configuration_properties(
+    instance: Instance,
+    system_id: SystemId,
+    view_configuration_type: ViewConfigurationType,
+    configuration_properties: *mut ViewConfigurationProperties,
+) -> Result {
+    super::call(
+        "xrGetViewConfigurationProperties",
+        instance.into_raw(),
+        |inputs| {
+            inputs.value(&instance);
+            inputs.value(&system_id);
+            inputs.value(&view_configuration_type);
+        },
+        |next| match next.get_view_configuration_properties {
+            Some(f) => f(
+                instance,
+                system_id,
+                view_configuration_type,
+                configuration_properties,
+            ),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.chain(configuration_properties);
+        },
+    )
+}
+unsafe extern "system" fn enumerate_view_configuration_views(
+    instance: Instance,
+    system_id: SystemId,
+    view_configuration_type: ViewConfigurationType,
+    view_capacity_input: u32,
+    view_count_output: *mut u32,
+    views: *mut ViewConfigurationView,
+) -> Result {
+    super::call(
+        "xrEnumerateViewConfigurationViews",
+        instance.into_raw(),
+        |inputs| {
+            inputs.value(&instance);
+            inputs.value(&system_id);
+            inputs.value(&view_configuration_type);
+            inputs.value(&view_capacity_input);
+        },
+        |next| match next.enumerate_view_configuration_views {
+            Some(f) => f(
+                instance,
+                system_id,
+                view_configuration_type,
+                view_capacity_input,
+                view_count_output,
+                views,
+            ),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.chain_array(view_capacity_input, view_count_output, views);
+        },
+    )
+}
+unsafe extern "system" fn begin_frame(
+    session: Session,
+    frame_begin_info: *const FrameBeginInfo,
+) -> Result {
+    super::call(
+        "xrBeginFrame",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.chain(frame_begin_info);
+        },
+        |next| match next.begin_frame {
+            Some(f) => f(session, frame_begin_info),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |_| {},
+    )
+}
+unsafe extern "system" fn locate_views(
+    session: Session,
+    view_locate_info: *const ViewLocateInfo,
+    view_state: *mut ViewState,
+    view_capacity_input: u32,
+    view_count_output: *mut u32,
+    views: *mut View,
+) -> Result {
+    super::call(
+        "xrLocateViews",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.chain(view_locate_info);
+            inputs.value(&view_capacity_input);
+        },
+        |next| match next.locate_views {
+            Some(f) => f(
+                session,
+                view_locate_info,
+                view_state,
+                view_capacity_input,
+                view_count_output,
+                views,
+            ),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.chain(view_state);
+            outputs.chain_array(view_capacity_input, view_count_output, views);
+        },
+    )
+}
+unsafe extern "system" fn end_frame(
+    session: Session,
+    frame_end_info: *const FrameEndInfo,
+) -> Result {
+    super::call(
+        "xrEndFrame",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.chain(frame_end_info);
+        },
+        |next| match next.end_frame {
+            Some(f) => f(session, frame_end_info),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |_| {},
+    )
+}
+unsafe extern "system" fn wait_frame(
+    session: Session,
+    frame_wait_info: *const FrameWaitInfo,
+    frame_state: *mut FrameState,
+) -> Result {
+    super::call(
+        "xrWaitFrame",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.chain(frame_wait_info);
+        },
+        |next| match next.wait_frame {
+            Some(f) => f(session, frame_wait_info, frame_state),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.chain(frame_state);
+        },
+    )
+}
+unsafe extern "system" fn apply_haptic_feedback(
+    session: Session,
+    haptic_action_info: *const HapticActionInfo,
+    haptic_feedback: *const HapticBaseHeader,
+) -> Result {
+    super::call(
+        "xrApplyHapticFeedback",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.chain(haptic_action_info);
+            inputs.chain(haptic_feedback);
+        },
+        |next| match next.apply_haptic_feedback {
+            Some(f) => f(session, haptic_action_info, haptic_feedback),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |_| {},
+    )
+}
+unsafe extern "system" fn stop_haptic_feedback(
+    session: Session,
+    haptic_action_info: *const HapticActionInfo,
+) -> Result {
+    super::call(
+        "xrStopHapticFeedback",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.chain(haptic_action_info);
+        },
+        |next| match next.stop_haptic_feedback {
+            Some(f) => f(session, haptic_action_info),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |_| {},
+    )
+}
+unsafe extern "system" fn poll_event(
+    instance: Instance,
+    event_data: *mut EventDataBuffer,
+) -> Result {
+    super::call(
+        "xrPollEvent",
+        instance.into_raw(),
+        |inputs| {
+            inputs.value(&instance);
+        },
+        |next| match next.poll_event {
+            Some(f) => f(instance, event_data),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.chain(event_data);
+        },
+    )
+}
+unsafe extern "system" fn string_to_path(
+    instance: Instance,
+    path_string: *const c_char,
+    path: *mut Path,
+) -> Result {
+    super::call(
+        "xrStringToPath",
+        instance.into_raw(),
+        |inputs| {
+            inputs.value(&instance);
+            inputs.string(path_string);
+        },
+        |next| match next.string_to_path {
+            Some(f) => f(instance, path_string, path),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.pointer(path);
+        },
+    )
+}
+unsafe extern "system" fn path_to_string(
+    instance: Instance,
+    path: Path,
+    buffer_capacity_input: u32,
+    buffer_count_output: *mut u32,
+    buffer: *mut c_char,
+) -> Result {
+    super::call(
+        "xrPathToString",
+        instance.into_raw(),
+        |inputs| {
+            inputs.value(&instance);
+            inputs.value(&path);
+            inputs.value(&buffer_capacity_input);
+        },
+        |next| match next.path_to_string {
+            Some(f) => f(
+                instance,
+                path,
+                buffer_capacity_input,
+                buffer_count_output,
+                buffer,
+            ),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.array(buffer_capacity_input, buffer_count_output, buffer);
+        },
+    )
+}
+unsafe extern "system" fn get_reference_space_bounds_rect(
+    session: Session,
+    reference_space_type: ReferenceSpaceType,
+    bounds: *mut Extent2Df,
+) -> Result {
+    super::call(
+        "xrGetReferenceSpaceBoundsRect",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.value(&reference_space_type);
+        },
+        |next| match next.get_reference_space_bounds_rect {
+            Some(f) => f(session, reference_space_type, bounds),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.pointer(bounds);
+        },
+    )
+}
+#[cfg(target_os = "android")]
+unsafe extern "system" fn set_android_application_thread_khr(
+    session: Session,
+    thread_type: AndroidThreadTypeKHR,
+    thread_id: u32,
+) -> Result {
+    super::call(
+        "xrSetAndroidApplicationThreadKHR",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.value(&thread_type);
+            inputs.value(&thread_id);
+        },
+        |next| match next.set_android_application_thread_khr {
+            Some(f) => f(session, thread_type, thread_id),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |_| {},
+    )
+}
+#[cfg(target_os = "android")]
+unsafe extern "system" fn create_swapchain_android_surface_khr(
+    session: Session,
+    info: *const SwapchainCreateInfo,
+    swapchain: *mut Swapchain,
+    surface: *mut jobject,
+) -> Result {
+    super::call(
+        "xrCreateSwapchainAndroidSurfaceKHR",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.chain(info);
+        },
+        |next| match next.create_swapchain_android_surface_khr {
+            Some(f) => f(session, info, swapchain, surface),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.pointer(swapchain);
+            outputs.pointer(surface);
+        },
+    )
+}
+unsafe extern "system" fn get_action_state_boolean(
+    session: Session,
+    get_info: *const ActionStateGetInfo,
+    state: *mut ActionStateBoolean,
+) -> Result {
+    super::call(
+        "xrGetActionStateBoolean",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.chain(get_info);
+        },
+        |next| match next.get_action_state_boolean {
+            Some(f) => f(session, get_info, state),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.chain(state);
+        },
+    )
+}
+unsafe extern "system" fn get_action_state_float(
+    session: Session,
+    get_info: *const ActionStateGetInfo,
+    state: *mut ActionStateFloat,
+) -> Result {
+    super::call(
+        "xrGetActionStateFloat",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.chain(get_info);
+        },
+        |next| match next.get_action_state_float {
+            Some(f) => f(session, get_info, state),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.chain(state);
+        },
+    )
+}
+unsafe extern "system" fn get_action_state_vector2f(
+    session: Session,
+    get_info: *const ActionStateGetInfo,
+    state: *mut ActionStateVector2f,
+) -> Result {
+    super::call(
+        "xrGetActionStateVector2f",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.chain(get_info);
+        },
+        |next| match next.get_action_state_vector2f {
+            Some(f) => f(session, get_info, state),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.chain(state);
+        },
+    )
+}
+unsafe extern "system" fn get_action_state_pose(
+    session: Session,
+    get_info: *const ActionStateGetInfo,
+    state: *mut ActionStatePose,
+) -> Result {
+    super::call(
+        "xrGetActionStatePose",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.chain(get_info);
+        },
+        |next| match next.get_action_state_pose {
+            Some(f) => f(session, get_info, state),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.chain(state);
+        },
+    )
+}
+unsafe extern "system" fn create_action_set(
+    instance: Instance,
+    create_info: *const ActionSetCreateInfo,
+    action_set: *mut ActionSet,
+) -> Result {
+    super::call(
+        "xrCreateActionSet",
+        instance.into_raw(),
+        |inputs| {
+            inputs.value(&instance);
+            inputs.chain(create_info);
+        },
+        |next| match next.create_action_set {
+            Some(f) => f(instance, create_info, action_set),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.pointer(action_set);
+        },
+    )
+}
+unsafe extern "system" fn destroy_action_set(action_set: ActionSet) -> Result {
+    super::call(
+        "xrDestroyActionSet",
+        action_set.into_raw(),
+        |inputs| {
+            inputs.value(&action_set);
+        },
+        |next| match next.destroy_action_set {
+            Some(f) => f(action_set),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |_| {},
+    )
+}
+unsafe extern "system" fn create_action(
+    action_set: ActionSet,
+    create_info: *const ActionCreateInfo,
+    action: *mut Action,
+) -> Result {
+    super::call(
+        "xrCreateAction",
+        action_set.into_raw(),
+        |inputs| {
+            inputs.value(&action_set);
+            inputs.chain(create_info);
+        },
+        |next| match next.create_action {
+            Some(f) => f(action_set, create_info, action),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.pointer(action);
+        },
+    )
+}
+unsafe extern "system" fn destroy_action(action: Action) -> Result {
+    super::call(
+        "xrDestroyAction",
+        action.into_raw(),
+        |inputs| {
+            inputs.value(&action);
+        },
+        |next| match next.destroy_action {
+            Some(f) => f(action),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |_| {},
+    )
+}
+unsafe extern "system" fn suggest_interaction_profile_bindings(
+    instance: Instance,
+    suggested_bindings: *const InteractionProfileSuggestedBinding,
+) -> Result {
+    super::call(
+        "xrSuggestInteractionProfileBindings",
+        instance.into_raw(),
+        |inputs| {
+            inputs.value(&instance);
+            inputs.chain(suggested_bindings);
+        },
+        |next| match next.suggest_interaction_profile_bindings {
+            Some(f) => f(instance, suggested_bindings),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |_| {},
+    )
+}
+unsafe extern "system" fn attach_session_action_sets(
+    session: Session,
+    attach_info: *const SessionActionSetsAttachInfo,
+) -> Result {
+    super::call(
+        "xrAttachSessionActionSets",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.chain(attach_info);
+        },
+        |next| match next.attach_session_action_sets {
+            Some(f) => f(session, attach_info),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |_| {},
+    )
+}
+unsafe extern "system" fn get_current_interaction_profile(
+    session: Session,
+    top_level_user_path: Path,
+    interaction_profile: *mut InteractionProfileState,
+) -> Result {
+    super::call(
+        "xrGetCurrentInteractionProfile",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.value(&top_level_user_path);
+        },
+        |next| match next.get_current_interaction_profile {
+            Some(f) => f(session, top_level_user_path, interaction_profile),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.chain(interaction_profile);
+        },
+    )
+}
+unsafe extern "system" fn sync_actions(
+    session: Session,
+    sync_info: *const ActionsSyncInfo,
+) -> Result {
+    super::call(
+        "xrSyncActions",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.chain(sync_info);
+        },
+        |next| match next.sync_actions {
+            Some(f) => f(session, sync_info),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |_| {},
+    )
+}
+unsafe extern "system" fn enumerate_bound_sources_for_action(
+    session: Session,
+    enumerate_info: *const BoundSourcesForActionEnumerateInfo,
+    source_capacity_input: u32,
+    source_count_output: *mut u32,
+    sources: *mut Path,
+) -> Result {
+    super::call(
+        "xrEnumerateBoundSourcesForAction",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.chain(enumerate_info);
+            inputs.value(&source_capacity_input);
+        },
+        |next| match next.enumerate_bound_sources_for_action {
+            Some(f) => f(
+                session,
+                enumerate_info,
+                source_capacity_input,
+                source_count_output,
+                sources,
+            ),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.array(source_capacity_input, source_count_output, sources);
+        },
+    )
+}
+unsafe extern "system" fn get_input_source_localized_name(
+    session: Session,
+    get_info: *const InputSourceLocalizedNameGetInfo,
+    buffer_capacity_input: u32,
+    buffer_count_output: *mut u32,
+    buffer: *mut c_char,
+) -> Result {
+    super::call(
+        "xrGetInputSourceLocalizedName",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.chain(get_info);
+            inputs.value(&buffer_capacity_input);
+        },
+        |next| match next.get_input_source_localized_name {
+            Some(f) => f(
+                session,
+                get_info,
+                buffer_capacity_input,
+                buffer_count_output,
+                buffer,
+            ),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.array(buffer_capacity_input, buffer_count_output, buffer);
+        },
+    )
+}
+unsafe extern "system" fn locate_spaces(
+    session: Session,
+    locate_info: *const SpacesLocateInfo,
+    space_locations: *mut SpaceLocations,
+) -> Result {
+    super::call(
+        "xrLocateSpaces",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.chain(locate_info);
+        },
+        |next| match next.locate_spaces {
+            Some(f) => f(session, locate_info, space_locations),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.chain(space_locations);
+        },
+    )
+}
+unsafe extern "system" fn get_vulkan_instance_extensions_khr(
+    instance: Instance,
+    system_id: SystemId,
+    buffer_capacity_input: u32,
+    buffer_count_output: *mut u32,
+    buffer: *mut c_char,
+) -> Result {
+    super::call(
+        "xrGetVulkanInstanceExtensionsKHR",
+        instance.into_raw(),
+        |inputs| {
+            inputs.value(&instance);
+            inputs.value(&system_id);
+            inputs.value(&buffer_capacity_input);
+        },
+        |next| match next.get_vulkan_instance_extensions_khr {
+            Some(f) => f(
+                instance,
+                system_id,
+                buffer_capacity_input,
+                buffer_count_output,
+                buffer,
+            ),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.array(buffer_capacity_input, buffer_count_output, buffer);
+        },
+    )
+}
+unsafe extern "system" fn get_vulkan_device_extensions_khr(
+    instance: Instance,
+    system_id: SystemId,
+    buffer_capacity_input: u32,
+    buffer_count_output: *mut u32,
+    buffer: *mut c_char,
+) -> Result {
+    super::call(
+        "xrGetVulkanDeviceExtensionsKHR",
+        instance.into_raw(),
+        |inputs| {
+            inputs.value(&instance);
+            inputs.value(&system_id);
+            inputs.value(&buffer_capacity_input);
+        },
+        |next| match next.get_vulkan_device_extensions_khr {
+            Some(f) => f(
+                instance,
+                system_id,
+                buffer_capacity_input,
+                buffer_count_output,
+                buffer,
+            ),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.array(buffer_capacity_input, buffer_count_output, buffer);
+        },
+    )
+}
+unsafe extern "system" fn get_vulkan_graphics_device_khr(
+    instance: Instance,
+    system_id: SystemId,
+    vk_instance: VkInstance,
+    vk_physical_device: *mut VkPhysicalDevice,
+) -> Result {
+    super::call(
+        "xrGetVulkanGraphicsDeviceKHR",
+        instance.into_raw(),
+        |inputs| {
+            inputs.value(&instance);
+            inputs.value(&system_id);
+            inputs.value(&vk_instance);
+        },
+        |next| match next.get_vulkan_graphics_device_khr {
+            Some(f) => f(instance, system_id, vk_instance, vk_physical_device),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.pointer(vk_physical_device);
+        },
+    )
+}
+unsafe extern "system" fn get_open_gl_graphics_requirements_khr(
+    instance: Instance,
+    system_id: SystemId,
+    graphics_requirements: *mut GraphicsRequirementsOpenGLKHR,
+) -> Result {
+    super::call(
+        "xrGetOpenGLGraphicsRequirementsKHR",
+        instance.into_raw(),
+        |inputs| {
+            inputs.value(&instance);
+            inputs.value(&system_id);
+        },
+        |next| match next.get_open_gl_graphics_requirements_khr {
+            Some(f) => f(instance, system_id, graphics_requirements),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.chain(graphics_requirements);
+        },
+    )
+}
+unsafe extern "system" fn get_open_gles_graphics_requirements_khr(
+    instance: Instance,
+    system_id: SystemId,
+    graphics_requirements: *mut GraphicsRequirementsOpenGLESKHR,
+) -> Result {
+    super::call(
+        "xrGetOpenGLESGraphicsRequirementsKHR",
+        instance.into_raw(),
+        |inputs| {
+            inputs.value(&instance);
+            inputs.value(&system_id);
+        },
+        |next| match next.get_open_gles_graphics_requirements_khr {
+            Some(f) => f(instance, system_id, graphics_requirements),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.chain(graphics_requirements);
+        },
+    )
+}
+unsafe extern "system" fn get_vulkan_graphics_requirements_khr(
+    instance: Instance,
+    system_id: SystemId,
+    graphics_requirements: *mut GraphicsRequirementsVulkanKHR,
+) -> Result {
+    super::call(
+        "xrGetVulkanGraphicsRequirementsKHR",
+        instance.into_raw(),
+        |inputs| {
+            inputs.value(&instance);
+            inputs.value(&system_id);
+        },
+        |next| match next.get_vulkan_graphics_requirements_khr {
+            Some(f) => f(instance, system_id, graphics_requirements),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.chain(graphics_requirements);
+        },
+    )
+}
+#[cfg(windows)]
+unsafe extern "system" fn get_d3d11_graphics_requirements_khr(
+    instance: Instance,
+    system_id: SystemId,
+    graphics_requirements: *mut GraphicsRequirementsD3D11KHR,
+) -> Result {
+    super::call(
+        "xrGetD3D11GraphicsRequirementsKHR",
+        instance.into_raw(),
+        |inputs| {
+            inputs.value(&instance);
+            inputs.value(&system_id);
+        },
+        |next| match next.get_d3d11_graphics_requirements_khr {
+            Some(f) => f(instance, system_id, graphics_requirements),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.chain(graphics_requirements);
+        },
+    )
+}
+#[cfg(windows)]
+unsafe extern "system" fn get_d3d12_graphics_requirements_khr(
+    instance: Instance,
+    system_id: SystemId,
+    graphics_requirements: *mut GraphicsRequirementsD3D12KHR,
+) -> Result {
+    super::call(
+        "xrGetD3D12GraphicsRequirementsKHR",
+        instance.into_raw(),
+        |inputs| {
+            inputs.value(&instance);
+            inputs.value(&system_id);
+        },
+        |next| match next.get_d3d12_graphics_requirements_khr {
+            Some(f) => f(instance, system_id, graphics_requirements),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.chain(graphics_requirements);
+        },
+    )
+}
+unsafe extern "system" fn perf_settings_set_performance_level_ext(
+    session: Session,
+    domain: PerfSettingsDomainEXT,
+    level: PerfSettingsLevelEXT,
+) -> Result {
+    super::call(
+        "xrPerfSettingsSetPerformanceLevelEXT",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.value(&domain);
+            inputs.value(&level);
+        },
+        |next| match next.perf_settings_set_performance_level_ext {
+            Some(f) => f(session, domain, level),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |_| {},
+    )
+}
+unsafe extern "system" fn thermal_get_temperature_trend_ext(
+    session: Session,
+    domain: PerfSettingsDomainEXT,
+    notification_level: *mut PerfSettingsNotificationLevelEXT,
+    temp_headroom: *mut f32,
+    temp_slope: *mut f32,
+) -> Result {
+    super::call(
+        "xrThermalGetTemperatureTrendEXT",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.value(&domain);
+        },
+        |next| match next.thermal_get_temperature_trend_ext {
+            Some(f) => f(
+                session,
+                domain,
+                notification_level,
+                temp_headroom,
+                temp_slope,
+            ),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.pointer(notification_level);
+            outputs.pointer(temp_headroom);
+            outputs.pointer(temp_slope);
+        },
+    )
+}
+unsafe extern "system" fn set_debug_utils_object_name_ext(
+    instance: Instance,
+    name_info: *const DebugUtilsObjectNameInfoEXT,
+) -> Result {
+    super::call(
+        "xrSetDebugUtilsObjectNameEXT",
+        instance.into_raw(),
+        |inputs| {
+            inputs.value(&instance);
+            inputs.chain(name_info);
+        },
+        |next| match next.set_debug_utils_object_name_ext {
+            Some(f) => f(instance, name_info),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |_| {},
+    )
+}
+unsafe extern "system" fn create_debug_utils_messenger_ext(
+    instance: Instance,
+    create_info: *const DebugUtilsMessengerCreateInfoEXT,
+    messenger: *mut DebugUtilsMessengerEXT,
+) -> Result {
+    super::call(
+        "xrCreateDebugUtilsMessengerEXT",
+        instance.into_raw(),
+        |inputs| {
+            inputs.value(&instance);
+            inputs.chain(create_info);
+        },
+        |next| match next.create_debug_utils_messenger_ext {
+            Some(f) => f(instance, create_info, messenger),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.pointer(messenger);
+        },
+    )
+}
+unsafe extern "system" fn destroy_debug_utils_messenger_ext(
+    messenger: DebugUtilsMessengerEXT,
+) -> Result {
+    super::call(
+        "xrDestroyDebugUtilsMessengerEXT",
+        messenger.into_raw(),
+        |inputs| {
+            inputs.value(&messenger);
+        },
+        |next| match next.destroy_debug_utils_messenger_ext {
+            Some(f) => f(messenger),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |_| {},
+    )
+}
+unsafe extern "system" fn submit_debug_utils_message_ext(
+    instance: Instance,
+    message_severity: DebugUtilsMessageSeverityFlagsEXT,
+    message_types: DebugUtilsMessageTypeFlagsEXT,
+    callback_data: *const DebugUtilsMessengerCallbackDataEXT,
+) -> Result {
+    super::call(
+        "xrSubmitDebugUtilsMessageEXT",
+        instance.into_raw(),
+        |inputs| {
+            inputs.value(&instance);
+            inputs.value(&message_severity);
+            inputs.value(&message_types);
+            inputs.chain(callback_data);
+        },
+        |next| match next.submit_debug_utils_message_ext {
+            Some(f) => f(instance, message_severity, message_types, callback_data),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |_| {},
+    )
+}
+unsafe extern "system" fn session_begin_debug_utils_label_region_ext(
+    session: Session,
+    label_info: *const DebugUtilsLabelEXT,
+) -> Result {
+    super::call(
+        "xrSessionBeginDebugUtilsLabelRegionEXT",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.chain(label_info);
+        },
+        |next| match next.session_begin_debug_utils_label_region_ext {
+            Some(f) => f(session, label_info),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |_| {},
+    )
+}
+unsafe extern "system" fn session_end_debug_utils_label_region_ext(session: Session) -> Result {
+    super::call(
+        "xrSessionEndDebugUtilsLabelRegionEXT",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+        },
+        |next| match next.session_end_debug_utils_label_region_ext {
+            Some(f) => f(session),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |_| {},
+    )
+}
+unsafe extern "system" fn session_insert_debug_utils_label_ext(
+    session: Session,
+    label_info: *const DebugUtilsLabelEXT,
+) -> Result {
+    super::call(
+        "xrSessionInsertDebugUtilsLabelEXT",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.chain(label_info);
+        },
+        |next| match next.session_insert_debug_utils_label_ext {
+            Some(f) => f(session, label_info),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |_| {},
+    )
+}
+#[cfg(windows)]
+unsafe extern "system" fn convert_time_to_win32_performance_counter_khr(
+    instance: Instance,
+    time: Time,
+    performance_counter: *mut LARGE_INTEGER,
+) -> Result {
+    super::call(
+        "xrConvertTimeToWin32PerformanceCounterKHR",
+        instance.into_raw(),
+        |inputs| {
+            inputs.value(&instance);
+            inputs.value(&time);
+        },
+        |next| match next.convert_time_to_win32_performance_counter_khr {
+            Some(f) => f(instance, time, performance_counter),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.pointer(performance_counter);
+        },
+    )
+}
+#[cfg(windows)]
+unsafe extern "system" fn convert_win32_performance_counter_to_time_khr(
+    instance: Instance,
+    performance_counter: *const LARGE_INTEGER,
+    time: *mut Time,
+) -> Result {
+    super::call(
+        "xrConvertWin32PerformanceCounterToTimeKHR",
+        instance.into_raw(),
+        |inputs| {
+            inputs.value(&instance);
+            inputs.pointer(performance_counter);
+        },
+        |next| match next.convert_win32_performance_counter_to_time_khr {
+            Some(f) => f(instance, performance_counter, time),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.pointer(time);
+        },
+    )
+}
+unsafe extern "system" fn create_vulkan_instance_khr(
+    instance: Instance,
+    create_info: *const VulkanInstanceCreateInfoKHR,
+    vulkan_instance: *mut VkInstance,
+    vulkan_result: *mut VkResult,
+) -> Result {
+    super::call(
+        "xrCreateVulkanInstanceKHR",
+        instance.into_raw(),
+        |inputs| {
+            inputs.value(&instance);
+            inputs.chain(create_info);
+        },
+        |next| match next.create_vulkan_instance_khr {
+            Some(f) => f(instance, create_info, vulkan_instance, vulkan_result),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.pointer(vulkan_instance);
+            outputs.pointer(vulkan_result);
+        },
+    )
+}
+unsafe extern "system" fn create_vulkan_device_khr(
+    instance: Instance,
+    create_info: *const VulkanDeviceCreateInfoKHR,
+    vulkan_device: *mut VkDevice,
+    vulkan_result: *mut VkResult,
+) -> Result {
+    super::call(
+        "xrCreateVulkanDeviceKHR",
+        instance.into_raw(),
+        |inputs| {
+            inputs.value(&instance);
+            inputs.chain(create_info);
+        },
+        |next| match next.create_vulkan_device_khr {
+            Some(f) => f(instance, create_info, vulkan_device, vulkan_result),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.pointer(vulkan_device);
+            outputs.pointer(vulkan_result);
+        },
+    )
+}
+unsafe extern "system" fn get_vulkan_graphics_device2_khr(
+    instance: Instance,
+    get_info: *const VulkanGraphicsDeviceGetInfoKHR,
+    vulkan_physical_device: *mut VkPhysicalDevice,
+) -> Result {
+    super::call(
+        "xrGetVulkanGraphicsDevice2KHR",
+        instance.into_raw(),
+        |inputs| {
+            inputs.value(&instance);
+            inputs.chain(get_info);
+        },
+        |next| match next.get_vulkan_graphics_device2_khr {
+            Some(f) => f(instance, get_info, vulkan_physical_device),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.pointer(vulkan_physical_device);
+        },
+    )
+}
+unsafe extern "system" fn convert_time_to_timespec_time_khr(
+    instance: Instance,
+    time: Time,
+    timespec_time: *mut timespec,
+) -> Result {
+    super::call(
+        "xrConvertTimeToTimespecTimeKHR",
+        instance.into_raw(),
+        |inputs| {
+            inputs.value(&instance);
+            inputs.value(&time);
+        },
+        |next| match next.convert_time_to_timespec_time_khr {
+            Some(f) => f(instance, time, timespec_time),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.pointer(timespec_time);
+        },
+    )
+}
+unsafe extern "system" fn convert_timespec_time_to_time_khr(
+    instance: Instance,
+    timespec_time: *const timespec,
+    time: *mut Time,
+) -> Result {
+    super::call(
+        "xrConvertTimespecTimeToTimeKHR",
+        instance.into_raw(),
+        |inputs| {
+            inputs.value(&instance);
+            inputs.pointer(timespec_time);
+        },
+        |next| match next.convert_timespec_time_to_time_khr {
+            Some(f) => f(instance, timespec_time, time),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.pointer(time);
+        },
+    )
+}
+unsafe extern "system" fn get_visibility_mask_khr(
+    session: Session,
+    view_configuration_type: ViewConfigurationType,
+    view_index: u32,
+    visibility_mask_type: VisibilityMaskTypeKHR,
+    visibility_mask: *mut VisibilityMaskKHR,
+) -> Result {
+    super::call(
+        "xrGetVisibilityMaskKHR",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.value(&view_configuration_type);
+            inputs.value(&view_index);
+            inputs.value(&visibility_mask_type);
+        },
+        |next| match next.get_visibility_mask_khr {
+            Some(f) => f(
+                session,
+                view_configuration_type,
+                view_index,
+                visibility_mask_type,
+                visibility_mask,
+            ),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.chain(visibility_mask);
+        },
+    )
+}
+unsafe extern "system" fn create_spatial_anchor_msft(
+    session: Session,
+    create_info: *const SpatialAnchorCreateInfoMSFT,
+    anchor: *mut SpatialAnchorMSFT,
+) -> Result {
+    super::call(
+        "xrCreateSpatialAnchorMSFT",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.chain(create_info);
+        },
+        |next| match next.create_spatial_anchor_msft {
+            Some(f) => f(session, create_info, anchor),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.pointer(anchor);
+        },
+    )
+}
+unsafe extern "system" fn create_spatial_anchor_space_msft(
+    session: Session,
+    create_info: *const SpatialAnchorSpaceCreateInfoMSFT,
+    space: *mut Space,
+) -> Result {
+    super::call(
+        "xrCreateSpatialAnchorSpaceMSFT",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.chain(create_info);
+        },
+        |next| match next.create_spatial_anchor_space_msft {
+            Some(f) => f(session, create_info, space),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.pointer(space);
+        },
+    )
+}
+unsafe extern "system" fn destroy_spatial_anchor_msft(anchor: SpatialAnchorMSFT) -> Result {
+    super::call(
+        "xrDestroySpatialAnchorMSFT",
+        anchor.into_raw(),
+        |inputs| {
+            inputs.value(&anchor);
+        },
+        |next| match next.destroy_spatial_anchor_msft {
+            Some(f) => f(anchor),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |_| {},
+    )
+}
+unsafe extern "system" fn set_input_device_active_ext(
+    session: Session,
+    interaction_profile: Path,
+    top_level_path: Path,
+    is_active: Bool32,
+) -> Result {
+    super::call(
+        "xrSetInputDeviceActiveEXT",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.value(&interaction_profile);
+            inputs.value(&top_level_path);
+            inputs.value(&is_active);
+        },
+        |next| match next.set_input_device_active_ext {
+            Some(f) => f(session, interaction_profile, top_level_path, is_active),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |_| {},
+    )
+}
+unsafe extern "system" fn set_input_device_state_bool_ext(
+    session: Session,
+    top_level_path: Path,
+    input_source_path: Path,
+    state: Bool32,
+) -> Result {
+    super::call(
+        "xrSetInputDeviceStateBoolEXT",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.value(&top_level_path);
+            inputs.value(&input_source_path);
+            inputs.value(&state);
+        },
+        |next| match next.set_input_device_state_bool_ext {
+            Some(f) => f(session, top_level_path, input_source_path, state),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |_| {},
+    )
+}
+unsafe extern "system" fn set_input_device_state_float_ext(
+    session: Session,
+    top_level_path: Path,
+    input_source_path: Path,
+    state: f32,
+) -> Result {
+    super::call(
+        "xrSetInputDeviceStateFloatEXT",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.value(&top_level_path);
+            inputs.value(&input_source_path);
+            inputs.value(&state);
+        },
+        |next| match next.set_input_device_state_float_ext {
+            Some(f) => f(session, top_level_path, input_source_path, state),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |_| {},
+    )
+}
+unsafe extern "system" fn set_input_device_state_vector2f_ext(
+    session: Session,
+    top_level_path: Path,
+    input_source_path: Path,
+    state: Vector2f,
+) -> Result {
+    super::call(
+        "xrSetInputDeviceStateVector2fEXT",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.value(&top_level_path);
+            inputs.value(&input_source_path);
+            inputs.value(&state);
+        },
+        |next| match next.set_input_device_state_vector2f_ext {
+            Some(f) => f(session, top_level_path, input_source_path, state),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |_| {},
+    )
+}
+unsafe extern "system" fn set_input_device_location_ext(
+    session: Session,
+    top_level_path: Path,
+    input_source_path: Path,
+    space: Space,
+    pose: Posef,
+) -> Result {
+    super::call(
+        "xrSetInputDeviceLocationEXT",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.value(&top_level_path);
+            inputs.value(&input_source_path);
+            inputs.value(&space);
+            inputs.value(&pose);
+        },
+        |next| match next.set_input_device_location_ext {
+            Some(f) => f(session, top_level_path, input_source_path, space, pose),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |_| {},
+    )
+}
+unsafe extern "system" fn initialize_loader_khr(
+    loader_init_info: *const LoaderInitInfoBaseHeaderKHR,
+) -> Result {
+    super::call(
+        "xrInitializeLoaderKHR",
+        0,
+        |inputs| {
+            inputs.chain(loader_init_info);
+        },
+        |next| match next.initialize_loader_khr {
+            Some(f) => f(loader_init_info),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |_| {},
+    )
+}
+unsafe extern "system" fn create_spatial_graph_node_space_msft(
+    session: Session,
+    create_info: *const SpatialGraphNodeSpaceCreateInfoMSFT,
+    space: *mut Space,
+) -> Result {
+    super::call(
+        "xrCreateSpatialGraphNodeSpaceMSFT",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.chain(create_info);
+        },
+        |next| match next.create_spatial_graph_node_space_msft {
+            Some(f) => f(session, create_info, space),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.pointer(space);
+        },
+    )
+}
+unsafe extern "system" fn try_create_spatial_graph_static_node_binding_msft(
+    session: Session,
+    create_info: *const SpatialGraphStaticNodeBindingCreateInfoMSFT,
+    node_binding: *mut SpatialGraphNodeBindingMSFT,
+) -> Result {
+    super::call(
+        "xrTryCreateSpatialGraphStaticNodeBindingMSFT",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.chain(create_info);
+        },
+        |next| match next.try_create_spatial_graph_static_node_binding_msft {
+            Some(f) => f(session, create_info, node_binding),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.pointer(node_binding);
+        },
+    )
+}
+unsafe extern "system" fn destroy_spatial_graph_node_binding_msft(
+    node_binding: SpatialGraphNodeBindingMSFT,
+) -> Result {
+    super::call(
+        "xrDestroySpatialGraphNodeBindingMSFT",
+        node_binding.into_raw(),
+        |inputs| {
+            inputs.value(&node_binding);
+        },
+        |next| match next.destroy_spatial_graph_node_binding_msft {
+            Some(f) => f(node_binding),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |_| {},
+    )
+}
+unsafe extern "system" fn get_spatial_graph_node_binding_properties_msft(
+    node_binding: SpatialGraphNodeBindingMSFT,
+    get_info: *const SpatialGraphNodeBindingPropertiesGetInfoMSFT,
+    properties: *mut SpatialGraphNodeBindingPropertiesMSFT,
+) -> Result {
+    super::call(
+        "xrGetSpatialGraphNodeBindingPropertiesMSFT",
+        node_binding.into_raw(),
+        |inputs| {
+            inputs.value(&node_binding);
+            inputs.chain(get_info);
+        },
+        |next| match next.get_spatial_graph_node_binding_properties_msft {
+            Some(f) => f(node_binding, get_info, properties),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.chain(properties);
+        },
+    )
+}
+unsafe extern "system" fn create_hand_tracker_ext(
+    session: Session,
+    create_info: *const HandTrackerCreateInfoEXT,
+    hand_tracker: *mut HandTrackerEXT,
+) -> Result {
+    super::call(
+        "xrCreateHandTrackerEXT",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.chain(create_info);
+        },
+        |next| match next.create_hand_tracker_ext {
+            Some(f) => f(session, create_info, hand_tracker),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.pointer(hand_tracker);
+        },
+    )
+}
+unsafe extern "system" fn destroy_hand_tracker_ext(hand_tracker: HandTrackerEXT) -> Result {
+    super::call(
+        "xrDestroyHandTrackerEXT",
+        hand_tracker.into_raw(),
+        |inputs| {
+            inputs.value(&hand_tracker);
+        },
+        |next| match next.destroy_hand_tracker_ext {
+            Some(f) => f(hand_tracker),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |_| {},
+    )
+}
+unsafe extern "system" fn locate_hand_joints_ext(
+    hand_tracker: HandTrackerEXT,
+    locate_info: *const HandJointsLocateInfoEXT,
+    locations: *mut HandJointLocationsEXT,
+) -> Result {
+    super::call(
+        "xrLocateHandJointsEXT",
+        hand_tracker.into_raw(),
+        |inputs| {
+            inputs.value(&hand_tracker);
+            inputs.chain(locate_info);
+        },
+        |next| match next.locate_hand_joints_ext {
+            Some(f) => f(hand_tracker, locate_info, locations),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.chain(locations);
+        },
+    )
+}
+unsafe extern "system" fn create_face_tracker_fb(
+    session: Session,
+    create_info: *const FaceTrackerCreateInfoFB,
+    face_tracker: *mut FaceTrackerFB,
+) -> Result {
+    super::call(
+        "xrCreateFaceTrackerFB",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.chain(create_info);
+        },
+        |next| match next.create_face_tracker_fb {
+            Some(f) => f(session, create_info, face_tracker),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.pointer(face_tracker);
+        },
+    )
+}
+unsafe extern "system" fn destroy_face_tracker_fb(face_tracker: FaceTrackerFB) -> Result {
+    super::call(
+        "xrDestroyFaceTrackerFB",
+        face_tracker.into_raw(),
+        |inputs| {
+            inputs.value(&face_tracker);
+        },
+        |next| match next.destroy_face_tracker_fb {
+            Some(f) => f(face_tracker),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |_| {},
+    )
+}
+unsafe extern "system" fn get_face_expression_weights_fb(
+    face_tracker: FaceTrackerFB,
+    expression_info: *const FaceExpressionInfoFB,
+    expression_weights: *mut FaceExpressionWeightsFB,
+) -> Result {
+    super::call(
+        "xrGetFaceExpressionWeightsFB",
+        face_tracker.into_raw(),
+        |inputs| {
+            inputs.value(&face_tracker);
+            inputs.chain(expression_info);
+        },
+        |next| match next.get_face_expression_weights_fb {
+            Some(f) => f(face_tracker, expression_info, expression_weights),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.chain(expression_weights);
+        },
+    )
+}
+unsafe extern "system" fn create_body_tracker_fb(
+    session: Session,
+    create_info: *const BodyTrackerCreateInfoFB,
+    body_tracker: *mut BodyTrackerFB,
+) -> Result {
+    super::call(
+        "xrCreateBodyTrackerFB",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.chain(create_info);
+        },
+        |next| match next.create_body_tracker_fb {
+            Some(f) => f(session, create_info, body_tracker),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.pointer(body_tracker);
+        },
+    )
+}
+unsafe extern "system" fn destroy_body_tracker_fb(body_tracker: BodyTrackerFB) -> Result {
+    super::call(
+        "xrDestroyBodyTrackerFB",
+        body_tracker.into_raw(),
+        |inputs| {
+            inputs.value(&body_tracker);
+        },
+        |next| match next.destroy_body_tracker_fb {
+            Some(f) => f(body_tracker),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |_| {},
+    )
+}
+unsafe extern "system" fn locate_body_joints_fb(
+    body_tracker: BodyTrackerFB,
+    locate_info: *const BodyJointsLocateInfoFB,
+    locations: *mut BodyJointLocationsFB,
+) -> Result {
+    super::call(
+        "xrLocateBodyJointsFB",
+        body_tracker.into_raw(),
+        |inputs| {
+            inputs.value(&body_tracker);
+            inputs.chain(locate_info);
+        },
+        |next| match next.locate_body_joints_fb {
+            Some(f) => f(body_tracker, locate_info, locations),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.chain(locations);
+        },
+    )
+}
+unsafe extern "system" fn get_body_skeleton_fb(
+    body_tracker: BodyTrackerFB,
+    skeleton: *mut BodySkeletonFB,
+) -> Result {
+    super::call(
+        "xrGetBodySkeletonFB",
+        body_tracker.into_raw(),
+        |inputs| {
+            inputs.value(&body_tracker);
+        },
+        |next| match next.get_body_skeleton_fb {
+            Some(f) => f(body_tracker, skeleton),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.chain(skeleton);
+        },
+    )
+}
+unsafe extern "system" fn create_eye_tracker_fb(
+    session: Session,
+    create_info: *const EyeTrackerCreateInfoFB,
+    eye_tracker: *mut EyeTrackerFB,
+) -> Result {
+    super::call(
+        "xrCreateEyeTrackerFB",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.chain(create_info);
+        },
+        |next| match next.create_eye_tracker_fb {
+            Some(f) => f(session, create_info, eye_tracker),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.pointer(eye_tracker);
+        },
+    )
+}
+unsafe extern "system" fn destroy_eye_tracker_fb(eye_tracker: EyeTrackerFB) -> Result {
+    super::call(
+        "xrDestroyEyeTrackerFB",
+        eye_tracker.into_raw(),
+        |inputs| {
+            inputs.value(&eye_tracker);
+        },
+        |next| match next.destroy_eye_tracker_fb {
+            Some(f) => f(eye_tracker),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |_| {},
+    )
+}
+unsafe extern "system" fn get_eye_gazes_fb(
+    eye_tracker: EyeTrackerFB,
+    gaze_info: *const EyeGazesInfoFB,
+    eye_gazes: *mut EyeGazesFB,
+) -> Result {
+    super::call(
+        "xrGetEyeGazesFB",
+        eye_tracker.into_raw(),
+        |inputs| {
+            inputs.value(&eye_tracker);
+            inputs.chain(gaze_info);
+        },
+        |next| match next.get_eye_gazes_fb {
+            Some(f) => f(eye_tracker, gaze_info, eye_gazes),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.chain(eye_gazes);
+        },
+    )
+}
+unsafe extern "system" fn create_hand_mesh_space_msft(
+    hand_tracker: HandTrackerEXT,
+    create_info: *const HandMeshSpaceCreateInfoMSFT,
+    space: *mut Space,
+) -> Result {
+    super::call(
+        "xrCreateHandMeshSpaceMSFT",
+        hand_tracker.into_raw(),
+        |inputs| {
+            inputs.value(&hand_tracker);
+            inputs.chain(create_info);
+        },
+        |next| match next.create_hand_mesh_space_msft {
+            Some(f) => f(hand_tracker, create_info, space),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.pointer(space);
+        },
+    )
+}
+unsafe extern "system" fn update_hand_mesh_msft(
+    hand_tracker: HandTrackerEXT,
+    update_info: *const HandMeshUpdateInfoMSFT,
+    hand_mesh: *mut HandMeshMSFT,
+) -> Result {
+    super::call(
+        "xrUpdateHandMeshMSFT",
+        hand_tracker.into_raw(),
+        |inputs| {
+            inputs.value(&hand_tracker);
+            inputs.chain(update_info);
+        },
+        |next| match next.update_hand_mesh_msft {
+            Some(f) => f(hand_tracker, update_info, hand_mesh),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.chain(hand_mesh);
+        },
+    )
+}
+unsafe extern "system" fn get_controller_model_key_msft(
+    session: Session,
+    top_level_user_path: Path,
+    controller_model_key_state: *mut ControllerModelKeyStateMSFT,
+) -> Result {
+    super::call(
+        "xrGetControllerModelKeyMSFT",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.value(&top_level_user_path);
+        },
+        |next| match next.get_controller_model_key_msft {
+            Some(f) => f(session, top_level_user_path, controller_model_key_state),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.chain(controller_model_key_state);
+        },
+    )
+}
+unsafe extern "system" fn load_controller_model_msft(
+    session: Session,
+    model_key: ControllerModelKeyMSFT,
+    buffer_capacity_input: u32,
+    buffer_count_output: *mut u32,
+    buffer: *mut u8,
+) -> Result {
+    super::call(
+        "xrLoadControllerModelMSFT",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.value(&model_key);
+            inputs.value(&buffer_capacity_input);
+        },
+        |next| match next.load_controller_model_msft {
+            Some(f) => f(
+                session,
+                model_key,
+                buffer_capacity_input,
+                buffer_count_output,
+                buffer,
+            ),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.array(buffer_capacity_input, buffer_count_output, buffer);
+        },
+    )
+}
+unsafe extern "system" fn get_controller_model_properties_msft(
+    session: Session,
+    model_key: ControllerModelKeyMSFT,
+    properties: *mut ControllerModelPropertiesMSFT,
+) -> Result {
+    super::call(
+        "xrGetControllerModelPropertiesMSFT",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.value(&model_key);
+        },
+        |next| match next.get_controller_model_properties_msft {
+            Some(f) => f(session, model_key, properties),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.chain(properties);
+        },
+    )
+}
+unsafe extern "system" fn get_controller_model_state_msft(
+    session: Session,
+    model_key: ControllerModelKeyMSFT,
+    state: *mut ControllerModelStateMSFT,
+) -> Result {
+    super::call(
+        "xrGetControllerModelStateMSFT",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.value(&model_key);
+        },
+        |next| match next.get_controller_model_state_msft {
+            Some(f) => f(session, model_key, state),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.chain(state);
+        },
+    )
+}
+unsafe extern "system" fn enumerate_display_refresh_rates_fb(
+    session: Session,
+    display_refresh_rate_capacity_input: u32,
+    display_refresh_rate_count_output: *mut u32,
+    display_refresh_rates: *mut f32,
+) -> Result {
+    super::call(
+        "xrEnumerateDisplayRefreshRatesFB",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.value(&display_refresh_rate_capacity_input);
+        },
+        |next| match next.enumerate_display_refresh_rates_fb {
+            Some(f) => f(
+                session,
+                display_refresh_rate_capacity_input,
+                display_refresh_rate_count_output,
+                display_refresh_rates,
+            ),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.array(
+                display_refresh_rate_capacity_input,
+                display_refresh_rate_count_output,
+                display_refresh_rates,
+            );
+        },
+    )
+}
+unsafe extern "system" fn get_display_refresh_rate_fb(
+    session: Session,
+    display_refresh_rate: *mut f32,
+) -> Result {
+    super::call(
+        "xrGetDisplayRefreshRateFB",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+        },
+        |next| match next.get_display_refresh_rate_fb {
+            Some(f) => f(session, display_refresh_rate),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.pointer(display_refresh_rate);
+        },
+    )
+}
+unsafe extern "system" fn request_display_refresh_rate_fb(
+    session: Session,
+    display_refresh_rate: f32,
+) -> Result {
+    super::call(
+        "xrRequestDisplayRefreshRateFB",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.value(&display_refresh_rate);
+        },
+        |next| match next.request_display_refresh_rate_fb {
+            Some(f) => f(session, display_refresh_rate),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |_| {},
+    )
+}
+#[cfg(windows)]
+unsafe extern "system" fn create_spatial_anchor_from_perception_anchor_msft(
+    session: Session,
+    perception_anchor: *mut IUnknown,
+    anchor: *mut SpatialAnchorMSFT,
+) -> Result {
+    super::call(
+        "xrCreateSpatialAnchorFromPerceptionAnchorMSFT",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.value(&perception_anchor);
+        },
+        |next| match next.create_spatial_anchor_from_perception_anchor_msft {
+            Some(f) => f(session, perception_anchor, anchor),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.pointer(anchor);
+        },
+    )
+}
+#[cfg(windows)]
+unsafe extern "system" fn try_get_perception_anchor_from_spatial_anchor_msft(
+    session: Session,
+    anchor: SpatialAnchorMSFT,
+    perception_anchor: *mut *mut IUnknown,
+) -> Result {
+    super::call(
+        "xrTryGetPerceptionAnchorFromSpatialAnchorMSFT",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.value(&anchor);
+        },
+        |next| match next.try_get_perception_anchor_from_spatial_anchor_msft {
+            Some(f) => f(session, anchor, perception_anchor),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.pointer(perception_anchor);
+        },
+    )
+}
+unsafe extern "system" fn update_swapchain_fb(
+    swapchain: Swapchain,
+    state: *const SwapchainStateBaseHeaderFB,
+) -> Result {
+    super::call(
+        "xrUpdateSwapchainFB",
+        swapchain.into_raw(),
+        |inputs| {
+            inputs.value(&swapchain);
+            inputs.chain(state);
+        },
+        |next| match next.update_swapchain_fb {
+            Some(f) => f(swapchain, state),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |_| {},
+    )
+}
+unsafe extern "system" fn get_swapchain_state_fb(
+    swapchain: Swapchain,
+    state: *mut SwapchainStateBaseHeaderFB,
+) -> Result {
+    super::call(
+        "xrGetSwapchainStateFB",
+        swapchain.into_raw(),
+        |inputs| {
+            inputs.value(&swapchain);
+        },
+        |next| match next.get_swapchain_state_fb {
+            Some(f) => f(swapchain, state),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.chain(state);
+        },
+    )
+}
+unsafe extern "system" fn enumerate_color_spaces_fb(
+    session: Session,
+    color_space_capacity_input: u32,
+    color_space_count_output: *mut u32,
+    color_spaces: *mut ColorSpaceFB,
+) -> Result {
+    super::call(
+        "xrEnumerateColorSpacesFB",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.value(&color_space_capacity_input);
+        },
+        |next| match next.enumerate_color_spaces_fb {
+            Some(f) => f(
+                session,
+                color_space_capacity_input,
+                color_space_count_output,
+                color_spaces,
+            ),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.array(
+                color_space_capacity_input,
+                color_space_count_output,
+                color_spaces,
+            );
+        },
+    )
+}
+unsafe extern "system" fn set_color_space_fb(session: Session, colorspace: ColorSpaceFB) -> Result {
+    super::call(
+        "xrSetColorSpaceFB",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.value(&colorspace);
+        },
+        |next| match next.set_color_space_fb {
+            Some(f) => f(session, colorspace),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |_| {},
+    )
+}
+unsafe extern "system" fn create_foveation_profile_fb(
+    session: Session,
+    create_info: *const FoveationProfileCreateInfoFB,
+    profile: *mut FoveationProfileFB,
+) -> Result {
+    super::call(
+        "xrCreateFoveationProfileFB",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.chain(create_info);
+        },
+        |next| match next.create_foveation_profile_fb {
+            Some(f) => f(session, create_info, profile),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.pointer(profile);
+        },
+    )
+}
+unsafe extern "system" fn destroy_foveation_profile_fb(profile: FoveationProfileFB) -> Result {
+    super::call(
+        "xrDestroyFoveationProfileFB",
+        profile.into_raw(),
+        |inputs| {
+            inputs.value(&profile);
+        },
+        |next| match next.destroy_foveation_profile_fb {
+            Some(f) => f(profile),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |_| {},
+    )
+}
+unsafe extern "system" fn get_foveation_eye_tracked_state_meta(
+    session: Session,
+    foveation_state: *mut FoveationEyeTrackedStateMETA,
+) -> Result {
+    super::call(
+        "xrGetFoveationEyeTrackedStateMETA",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+        },
+        |next| match next.get_foveation_eye_tracked_state_meta {
+            Some(f) => f(session, foveation_state),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.chain(foveation_state);
+        },
+    )
+}
+unsafe extern "system" fn get_hand_mesh_fb(
+    hand_tracker: HandTrackerEXT,
+    mesh: *mut HandTrackingMeshFB,
+) -> Result {
+    super::call(
+        "xrGetHandMeshFB",
+        hand_tracker.into_raw(),
+        |inputs| {
+            inputs.value(&hand_tracker);
+        },
+        |next| match next.get_hand_mesh_fb {
+            Some(f) => f(hand_tracker, mesh),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.chain(mesh);
+        },
+    )
+}
+unsafe extern "system" fn enumerate_render_model_paths_fb(
+    session: Session,
+    path_capacity_input: u32,
+    path_count_output: *mut u32,
+    paths: *mut RenderModelPathInfoFB,
+) -> Result {
+    super::call(
+        "xrEnumerateRenderModelPathsFB",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.value(&path_capacity_input);
+        },
+        |next| match next.enumerate_render_model_paths_fb {
+            Some(f) => f(session, path_capacity_input, path_count_output, paths),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.chain_array(path_capacity_input, path_count_output, paths);
+        },
+    )
+}
+unsafe extern "system" fn get_render_model_properties_fb(
+    session: Session,
+    path: Path,
+    properties: *mut RenderModelPropertiesFB,
+) -> Result {
+    super::call(
+        "xrGetRenderModelPropertiesFB",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.value(&path);
+        },
+        |next| match next.get_render_model_properties_fb {
+            Some(f) => f(session, path, properties),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.chain(properties);
+        },
+    )
+}
+unsafe extern "system" fn load_render_model_fb(
+    session: Session,
+    info: *const RenderModelLoadInfoFB,
+    buffer: *mut RenderModelBufferFB,
+) -> Result {
+    super::call(
+        "xrLoadRenderModelFB",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.chain(info);
+        },
+        |next| match next.load_render_model_fb {
+            Some(f) => f(session, info, buffer),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.chain(buffer);
+        },
+    )
+}
+unsafe extern "system" fn query_system_tracked_keyboard_fb(
+    session: Session,
+    query_info: *const KeyboardTrackingQueryFB,
+    keyboard: *mut KeyboardTrackingDescriptionFB,
+) -> Result {
+    super::call(
+        "xrQuerySystemTrackedKeyboardFB",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.chain(query_info);
+        },
+        |next| match next.query_system_tracked_keyboard_fb {
+            Some(f) => f(session, query_info, keyboard),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.pointer(keyboard);
+        },
+    )
+}
+unsafe extern "system" fn create_keyboard_space_fb(
+    session: Session,
+    create_info: *const KeyboardSpaceCreateInfoFB,
+    keyboard_space: *mut Space,
+) -> Result {
+    super::call(
+        "xrCreateKeyboardSpaceFB",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.chain(create_info);
+        },
+        |next| match next.create_keyboard_space_fb {
+            Some(f) => f(session, create_info, keyboard_space),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.pointer(keyboard_space);
+        },
+    )
+}
+unsafe extern "system" fn set_environment_depth_estimation_varjo(
+    session: Session,
+    enabled: Bool32,
+) -> Result {
+    super::call(
+        "xrSetEnvironmentDepthEstimationVARJO",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.value(&enabled);
+        },
+        |next| match next.set_environment_depth_estimation_varjo {
+            Some(f) => f(session, enabled),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |_| {},
+    )
+}
+unsafe extern "system" fn enumerate_reprojection_modes_msft(
+    instance: Instance,
+    system_id: SystemId,
+    view_configuration_type: ViewConfigurationType,
+    mode_capacity_input: u32,
+    mode_count_output: *mut u32,
+    modes: *mut ReprojectionModeMSFT,
+) -> Result {
+    super::call(
+        "xrEnumerateReprojectionModesMSFT",
+        instance.into_raw(),
+        |inputs| {
+            inputs.value(&instance);
+            inputs.value(&system_id);
+            inputs.value(&view_configuration_type);
+            inputs.value(&mode_capacity_input);
+        },
+        |next| match next.enumerate_reprojection_modes_msft {
+            Some(f) => f(
+                instance,
+                system_id,
+                view_configuration_type,
+                mode_capacity_input,
+                mode_count_output,
+                modes,
+            ),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.array(mode_capacity_input, mode_count_output, modes);
+        },
+    )
+}
+unsafe extern "system" fn get_audio_output_device_guid_oculus(
+    instance: Instance,
+    buffer: *mut wchar_t,
+) -> Result {
+    super::call(
+        "xrGetAudioOutputDeviceGuidOculus",
+        instance.into_raw(),
+        |inputs| {
+            inputs.value(&instance);
+        },
+        |next| match next.get_audio_output_device_guid_oculus {
+            Some(f) => f(instance, buffer),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.fixed(buffer, MAX_AUDIO_DEVICE_STR_SIZE_OCULUS);
+        },
+    )
+}
+unsafe extern "system" fn get_audio_input_device_guid_oculus(
+    instance: Instance,
+    buffer: *mut wchar_t,
+) -> Result {
+    super::call(
+        "xrGetAudioInputDeviceGuidOculus",
+        instance.into_raw(),
+        |inputs| {
+            inputs.value(&instance);
+        },
+        |next| match next.get_audio_input_device_guid_oculus {
+            Some(f) => f(instance, buffer),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.fixed(buffer, MAX_AUDIO_DEVICE_STR_SIZE_OCULUS);
+        },
+    )
+}
+unsafe extern "system" fn create_spatial_anchor_fb(
+    session: Session,
+    info: *const SpatialAnchorCreateInfoFB,
+    request_id: *mut AsyncRequestIdFB,
+) -> Result {
+    super::call(
+        "xrCreateSpatialAnchorFB",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.chain(info);
+        },
+        |next| match next.create_spatial_anchor_fb {
+            Some(f) => f(session, info, request_id),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.pointer(request_id);
+        },
+    )
+}
+unsafe extern "system" fn get_space_uuid_fb(space: Space, uuid: *mut UuidEXT) -> Result {
+    super::call(
+        "xrGetSpaceUuidFB",
+        space.into_raw(),
+        |inputs| {
+            inputs.value(&space);
+        },
+        |next| match next.get_space_uuid_fb {
+            Some(f) => f(space, uuid),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.pointer(uuid);
+        },
+    )
+}
+unsafe extern "system" fn enumerate_space_supported_components_fb(
+    space: Space,
+    component_type_capacity_input: u32,
+    component_type_count_output: *mut u32,
+    component_types: *mut SpaceComponentTypeFB,
+) -> Result {
+    super::call(
+        "xrEnumerateSpaceSupportedComponentsFB",
+        space.into_raw(),
+        |inputs| {
+            inputs.value(&space);
+            inputs.value(&component_type_capacity_input);
+        },
+        |next| match next.enumerate_space_supported_components_fb {
+            Some(f) => f(
+                space,
+                component_type_capacity_input,
+                component_type_count_output,
+                component_types,
+            ),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.array(
+                component_type_capacity_input,
+                component_type_count_output,
+                component_types,
+            );
+        },
+    )
+}
+unsafe extern "system" fn set_space_component_status_fb(
+    space: Space,
+    info: *const SpaceComponentStatusSetInfoFB,
+    request_id: *mut AsyncRequestIdFB,
+) -> Result {
+    super::call(
+        "xrSetSpaceComponentStatusFB",
+        space.into_raw(),
+        |inputs| {
+            inputs.value(&space);
+            inputs.chain(info);
+        },
+        |next| match next.set_space_component_status_fb {
+            Some(f) => f(space, info, request_id),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.pointer(request_id);
+        },
+    )
+}
+unsafe extern "system" fn get_space_component_status_fb(
+    space: Space,
+    component_type: SpaceComponentTypeFB,
+    status: *mut SpaceComponentStatusFB,
+) -> Result {
+    super::call(
+        "xrGetSpaceComponentStatusFB",
+        space.into_raw(),
+        |inputs| {
+            inputs.value(&space);
+            inputs.value(&component_type);
+        },
+        |next| match next.get_space_component_status_fb {
+            Some(f) => f(space, component_type, status),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.chain(status);
+        },
+    )
+}
+unsafe extern "system" fn create_triangle_mesh_fb(
+    session: Session,
+    create_info: *const TriangleMeshCreateInfoFB,
+    out_triangle_mesh: *mut TriangleMeshFB,
+) -> Result {
+    super::call(
+        "xrCreateTriangleMeshFB",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.chain(create_info);
+        },
+        |next| match next.create_triangle_mesh_fb {
+            Some(f) => f(session, create_info, out_triangle_mesh),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.pointer(out_triangle_mesh);
+        },
+    )
+}
+unsafe extern "system" fn destroy_triangle_mesh_fb(mesh: TriangleMeshFB) -> Result {
+    super::call(
+        "xrDestroyTriangleMeshFB",
+        mesh.into_raw(),
+        |inputs| {
+            inputs.value(&mesh);
+        },
+        |next| match next.destroy_triangle_mesh_fb {
+            Some(f) => f(mesh),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |_| {},
+    )
+}
+unsafe extern "system" fn triangle_mesh_get_vertex_buffer_fb(
+    mesh: TriangleMeshFB,
+    out_vertex_buffer: *mut *mut Vector3f,
+) -> Result {
+    super::call(
+        "xrTriangleMeshGetVertexBufferFB",
+        mesh.into_raw(),
+        |inputs| {
+            inputs.value(&mesh);
+        },
+        |next| match next.triangle_mesh_get_vertex_buffer_fb {
+            Some(f) => f(mesh, out_vertex_buffer),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.pointer(out_vertex_buffer);
+        },
+    )
+}
+unsafe extern "system" fn triangle_mesh_get_index_buffer_fb(
+    mesh: TriangleMeshFB,
+    out_index_buffer: *mut *mut u32,
+) -> Result {
+    super::call(
+        "xrTriangleMeshGetIndexBufferFB",
+        mesh.into_raw(),
+        |inputs| {
+            inputs.value(&mesh);
+        },
+        |next| match next.triangle_mesh_get_index_buffer_fb {
+            Some(f) => f(mesh, out_index_buffer),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.pointer(out_index_buffer);
+        },
+    )
+}
+unsafe extern "system" fn triangle_mesh_begin_update_fb(mesh: TriangleMeshFB) -> Result {
+    super::call(
+        "xrTriangleMeshBeginUpdateFB",
+        mesh.into_raw(),
+        |inputs| {
+            inputs.value(&mesh);
+        },
+        |next| match next.triangle_mesh_begin_update_fb {
+            Some(f) => f(mesh),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |_| {},
+    )
+}
+unsafe extern "system" fn triangle_mesh_end_update_fb(
+    mesh: TriangleMeshFB,
+    vertex_count: u32,
+    triangle_count: u32,
+) -> Result {
+    super::call(
+        "xrTriangleMeshEndUpdateFB",
+        mesh.into_raw(),
+        |inputs| {
+            inputs.value(&mesh);
+            inputs.value(&vertex_count);
+            inputs.value(&triangle_count);
+        },
+        |next| match next.triangle_mesh_end_update_fb {
+            Some(f) => f(mesh, vertex_count, triangle_count),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |_| {},
+    )
+}
+unsafe extern "system" fn triangle_mesh_begin_vertex_buffer_update_fb(
+    mesh: TriangleMeshFB,
+    out_vertex_count: *mut u32,
+) -> Result {
+    super::call(
+        "xrTriangleMeshBeginVertexBufferUpdateFB",
+        mesh.into_raw(),
+        |inputs| {
+            inputs.value(&mesh);
+        },
+        |next| match next.triangle_mesh_begin_vertex_buffer_update_fb {
+            Some(f) => f(mesh, out_vertex_count),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.pointer(out_vertex_count);
+        },
+    )
+}
+unsafe extern "system" fn triangle_mesh_end_vertex_buffer_update_fb(
+    mesh: TriangleMeshFB,
+) -> Result {
+    super::call(
+        "xrTriangleMeshEndVertexBufferUpdateFB",
+        mesh.into_raw(),
+        |inputs| {
+            inputs.value(&mesh);
+        },
+        |next| match next.triangle_mesh_end_vertex_buffer_update_fb {
+            Some(f) => f(mesh),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |_| {},
+    )
+}
+unsafe extern "system" fn create_passthrough_fb(
+    session: Session,
+    create_info: *const PassthroughCreateInfoFB,
+    out_passthrough: *mut PassthroughFB,
+) -> Result {
+    super::call(
+        "xrCreatePassthroughFB",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.chain(create_info);
+        },
+        |next| match next.create_passthrough_fb {
+            Some(f) => f(session, create_info, out_passthrough),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.pointer(out_passthrough);
+        },
+    )
+}
+unsafe extern "system" fn destroy_passthrough_fb(passthrough: PassthroughFB) -> Result {
+    super::call(
+        "xrDestroyPassthroughFB",
+        passthrough.into_raw(),
+        |inputs| {
+            inputs.value(&passthrough);
+        },
+        |next| match next.destroy_passthrough_fb {
+            Some(f) => f(passthrough),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |_| {},
+    )
+}
+unsafe extern "system" fn passthrough_start_fb(passthrough: PassthroughFB) -> Result {
+    super::call(
+        "xrPassthroughStartFB",
+        passthrough.into_raw(),
+        |inputs| {
+            inputs.value(&passthrough);
+        },
+        |next| match next.passthrough_start_fb {
+            Some(f) => f(passthrough),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |_| {},
+    )
+}
+unsafe extern "system" fn passthrough_pause_fb(passthrough: PassthroughFB) -> Result {
+    super::call(
+        "xrPassthroughPauseFB",
+        passthrough.into_raw(),
+        |inputs| {
+            inputs.value(&passthrough);
+        },
+        |next| match next.passthrough_pause_fb {
+            Some(f) => f(passthrough),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |_| {},
+    )
+}
+unsafe extern "system" fn create_passthrough_layer_fb(
+    session: Session,
+    create_info: *const PassthroughLayerCreateInfoFB,
+    out_layer: *mut PassthroughLayerFB,
+) -> Result {
+    super::call(
+        "xrCreatePassthroughLayerFB",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.chain(create_info);
+        },
+        |next| match next.create_passthrough_layer_fb {
+            Some(f) => f(session, create_info, out_layer),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.pointer(out_layer);
+        },
+    )
+}
+unsafe extern "system" fn destroy_passthrough_layer_fb(layer: PassthroughLayerFB) -> Result {
+    super::call(
+        "xrDestroyPassthroughLayerFB",
+        layer.into_raw(),
+        |inputs| {
+            inputs.value(&layer);
+        },
+        |next| match next.destroy_passthrough_layer_fb {
+            Some(f) => f(layer),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |_| {},
+    )
+}
+unsafe extern "system" fn passthrough_layer_pause_fb(layer: PassthroughLayerFB) -> Result {
+    super::call(
+        "xrPassthroughLayerPauseFB",
+        layer.into_raw(),
+        |inputs| {
+            inputs.value(&layer);
+        },
+        |next| match next.passthrough_layer_pause_fb {
+            Some(f) => f(layer),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |_| {},
+    )
+}
+unsafe extern "system" fn passthrough_layer_resume_fb(layer: PassthroughLayerFB) -> Result {
+    super::call(
+        "xrPassthroughLayerResumeFB",
+        layer.into_raw(),
+        |inputs| {
+            inputs.value(&layer);
+        },
+        |next| match next.passthrough_layer_resume_fb {
+            Some(f) => f(layer),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |_| {},
+    )
+}
+unsafe extern "system" fn passthrough_layer_set_style_fb(
+    layer: PassthroughLayerFB,
+    style: *const PassthroughStyleFB,
+) -> Result {
+    super::call(
+        "xrPassthroughLayerSetStyleFB",
+        layer.into_raw(),
+        |inputs| {
+            inputs.value(&layer);
+            inputs.chain(style);
+        },
+        |next| match next.passthrough_layer_set_style_fb {
+            Some(f) => f(layer, style),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |_| {},
+    )
+}
+unsafe extern "system" fn create_geometry_instance_fb(
+    session: Session,
+    create_info: *const GeometryInstanceCreateInfoFB,
+    out_geometry_instance: *mut GeometryInstanceFB,
+) -> Result {
+    super::call(
+        "xrCreateGeometryInstanceFB",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.chain(create_info);
+        },
+        |next| match next.create_geometry_instance_fb {
+            Some(f) => f(session, create_info, out_geometry_instance),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.pointer(out_geometry_instance);
+        },
+    )
+}
+unsafe extern "system" fn destroy_geometry_instance_fb(instance: GeometryInstanceFB) -> Result {
+    super::call(
+        "xrDestroyGeometryInstanceFB",
+        instance.into_raw(),
+        |inputs| {
+            inputs.value(&instance);
+        },
+        |next| match next.destroy_geometry_instance_fb {
+            Some(f) => f(instance),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |_| {},
+    )
+}
+unsafe extern "system" fn geometry_instance_set_transform_fb(
+    instance: GeometryInstanceFB,
+    transformation: *const GeometryInstanceTransformFB,
+) -> Result {
+    super::call(
+        "xrGeometryInstanceSetTransformFB",
+        instance.into_raw(),
+        |inputs| {
+            inputs.value(&instance);
+            inputs.chain(transformation);
+        },
+        |next| match next.geometry_instance_set_transform_fb {
+            Some(f) => f(instance, transformation),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |_| {},
+    )
+}
+unsafe extern "system" fn query_spaces_fb(
+    session: Session,
+    info: *const SpaceQueryInfoBaseHeaderFB,
+    request_id: *mut AsyncRequestIdFB,
+) -> Result {
+    super::call(
+        "xrQuerySpacesFB",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.chain(info);
+        },
+        |next| match next.query_spaces_fb {
+            Some(f) => f(session, info, request_id),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.pointer(request_id);
+        },
+    )
+}
+unsafe extern "system" fn retrieve_space_query_results_fb(
+    session: Session,
+    request_id: AsyncRequestIdFB,
+    results: *mut SpaceQueryResultsFB,
+) -> Result {
+    super::call(
+        "xrRetrieveSpaceQueryResultsFB",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.value(&request_id);
+        },
+        |next| match next.retrieve_space_query_results_fb {
+            Some(f) => f(session, request_id, results),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.chain(results);
+        },
+    )
+}
+unsafe extern "system" fn save_space_fb(
+    session: Session,
+    info: *const SpaceSaveInfoFB,
+    request_id: *mut AsyncRequestIdFB,
+) -> Result {
+    super::call(
+        "xrSaveSpaceFB",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.chain(info);
+        },
+        |next| match next.save_space_fb {
+            Some(f) => f(session, info, request_id),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.pointer(request_id);
+        },
+    )
+}
+unsafe extern "system" fn erase_space_fb(
+    session: Session,
+    info: *const SpaceEraseInfoFB,
+    request_id: *mut AsyncRequestIdFB,
+) -> Result {
+    super::call(
+        "xrEraseSpaceFB",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.chain(info);
+        },
+        |next| match next.erase_space_fb {
+            Some(f) => f(session, info, request_id),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.pointer(request_id);
+        },
+    )
+}
+unsafe extern "system" fn save_space_list_fb(
+    session: Session,
+    info: *const SpaceListSaveInfoFB,
+    request_id: *mut AsyncRequestIdFB,
+) -> Result {
+    super::call(
+        "xrSaveSpaceListFB",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.chain(info);
+        },
+        |next| match next.save_space_list_fb {
+            Some(f) => f(session, info, request_id),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.pointer(request_id);
+        },
+    )
+}
+unsafe extern "system" fn share_spaces_fb(
+    session: Session,
+    info: *const SpaceShareInfoFB,
+    request_id: *mut AsyncRequestIdFB,
+) -> Result {
+    super::call(
+        "xrShareSpacesFB",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.chain(info);
+        },
+        |next| match next.share_spaces_fb {
+            Some(f) => f(session, info, request_id),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.pointer(request_id);
+        },
+    )
+}
+unsafe extern "system" fn get_space_container_fb(
+    session: Session,
+    space: Space,
+    space_container_output: *mut SpaceContainerFB,
+) -> Result {
+    super::call(
+        "xrGetSpaceContainerFB",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.value(&space);
+        },
+        |next| match next.get_space_container_fb {
+            Some(f) => f(session, space, space_container_output),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.chain(space_container_output);
+        },
+    )
+}
+unsafe extern "system" fn get_space_bounding_box2_dfb(
+    session: Session,
+    space: Space,
+    bounding_box2_d_output: *mut Rect2Df,
+) -> Result {
+    super::call(
+        "xrGetSpaceBoundingBox2DFB",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.value(&space);
+        },
+        |next| match next.get_space_bounding_box2_dfb {
+            Some(f) => f(session, space, bounding_box2_d_output),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.pointer(bounding_box2_d_output);
+        },
+    )
+}
+unsafe extern "system" fn get_space_bounding_box3_dfb(
+    session: Session,
+    space: Space,
+    bounding_box3_d_output: *mut Rect3DfFB,
+) -> Result {
+    super::call(
+        "xrGetSpaceBoundingBox3DFB",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.value(&space);
+        },
+        |next| match next.get_space_bounding_box3_dfb {
+            Some(f) => f(session, space, bounding_box3_d_output),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.pointer(bounding_box3_d_output);
+        },
+    )
+}
+unsafe extern "system" fn get_space_semantic_labels_fb(
+    session: Session,
+    space: Space,
+    semantic_labels_output: *mut SemanticLabelsFB,
+) -> Result {
+    super::call(
+        "xrGetSpaceSemanticLabelsFB",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.value(&space);
+        },
+        |next| match next.get_space_semantic_labels_fb {
+            Some(f) => f(session, space, semantic_labels_output),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.chain(semantic_labels_output);
+        },
+    )
+}
+unsafe extern "system" fn get_space_boundary2_dfb(
+    session: Session,
+    space: Space,
+    boundary2_d_output: *mut Boundary2DFB,
+) -> Result {
+    super::call(
+        "xrGetSpaceBoundary2DFB",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.value(&space);
+        },
+        |next| match next.get_space_boundary2_dfb {
+            Some(f) => f(session, space, boundary2_d_output),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.chain(boundary2_d_output);
+        },
+    )
+}
+unsafe extern "system" fn get_space_room_layout_fb(
+    session: Session,
+    space: Space,
+    room_layout_output: *mut RoomLayoutFB,
+) -> Result {
+    super::call(
+        "xrGetSpaceRoomLayoutFB",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.value(&space);
+        },
+        |next| match next.get_space_room_layout_fb {
+            Some(f) => f(session, space, room_layout_output),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.chain(room_layout_output);
+        },
+    )
+}
+unsafe extern "system" fn request_scene_capture_fb(
+    session: Session,
+    info: *const SceneCaptureRequestInfoFB,
+    request_id: *mut AsyncRequestIdFB,
+) -> Result {
+    super::call(
+        "xrRequestSceneCaptureFB",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.chain(info);
+        },
+        |next| match next.request_scene_capture_fb {
+            Some(f) => f(session, info, request_id),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.pointer(request_id);
+        },
+    )
+}
+unsafe extern "system" fn passthrough_layer_set_keyboard_hands_intensity_fb(
+    layer: PassthroughLayerFB,
+    intensity: *const PassthroughKeyboardHandsIntensityFB,
+) -> Result {
+    super::call(
+        "xrPassthroughLayerSetKeyboardHandsIntensityFB",
+        layer.into_raw(),
+        |inputs| {
+            inputs.value(&layer);
+            inputs.chain(intensity);
+        },
+        |next| match next.passthrough_layer_set_keyboard_hands_intensity_fb {
+            Some(f) => f(layer, intensity),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |_| {},
+    )
+}
+unsafe extern "system" fn create_spatial_anchor_store_connection_msft(
+    session: Session,
+    spatial_anchor_store: *mut SpatialAnchorStoreConnectionMSFT,
+) -> Result {
+    super::call(
+        "xrCreateSpatialAnchorStoreConnectionMSFT",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+        },
+        |next| match next.create_spatial_anchor_store_connection_msft {
+            Some(f) => f(session, spatial_anchor_store),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.pointer(spatial_anchor_store);
+        },
+    )
+}
+unsafe extern "system" fn destroy_spatial_anchor_store_connection_msft(
+    spatial_anchor_store: SpatialAnchorStoreConnectionMSFT,
+) -> Result {
+    super::call(
+        "xrDestroySpatialAnchorStoreConnectionMSFT",
+        spatial_anchor_store.into_raw(),
+        |inputs| {
+            inputs.value(&spatial_anchor_store);
+        },
+        |next| match next.destroy_spatial_anchor_store_connection_msft {
+            Some(f) => f(spatial_anchor_store),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |_| {},
+    )
+}
+unsafe extern "system" fn persist_spatial_anchor_msft(
+    spatial_anchor_store: SpatialAnchorStoreConnectionMSFT,
+    spatial_anchor_persistence_info: *const SpatialAnchorPersistenceInfoMSFT,
+) -> Result {
+    super::call(
+        "xrPersistSpatialAnchorMSFT",
+        spatial_anchor_store.into_raw(),
+        |inputs| {
+            inputs.value(&spatial_anchor_store);
+            inputs.chain(spatial_anchor_persistence_info);
+        },
+        |next| match next.persist_spatial_anchor_msft {
+            Some(f) => f(spatial_anchor_store, spatial_anchor_persistence_info),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |_| {},
+    )
+}
+unsafe extern "system" fn enumerate_persisted_spatial_anchor_names_msft(
+    spatial_anchor_store: SpatialAnchorStoreConnectionMSFT,
+    spatial_anchor_names_capacity_input: u32,
+    spatial_anchor_names_count_output: *mut u32,
+    persisted_anchor_names: *mut SpatialAnchorPersistenceNameMSFT,
+) -> Result {
+    super::call(
+        "xrEnumeratePersistedSpatialAnchorNamesMSFT",
+        spatial_anchor_store.into_raw(),
+        |inputs| {
+            inputs.value(&spatial_anchor_store);
+            inputs.value(&spatial_anchor_names_capacity_input);
+        },
+        |next| match next.enumerate_persisted_spatial_anchor_names_msft {
+            Some(f) => f(
+                spatial_anchor_store,
+                spatial_anchor_names_capacity_input,
+                spatial_anchor_names_count_output,
+                persisted_anchor_names,
+            ),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.array(
+                spatial_anchor_names_capacity_input,
+                spatial_anchor_names_count_output,
+                persisted_anchor_names,
+            );
+        },
+    )
+}
+unsafe extern "system" fn create_spatial_anchor_from_persisted_name_msft(
+    session: Session,
+    spatial_anchor_create_info: *const SpatialAnchorFromPersistedAnchorCreateInfoMSFT,
+    spatial_anchor: *mut SpatialAnchorMSFT,
+) -> Result {
+    super::call(
+        "xrCreateSpatialAnchorFromPersistedNameMSFT",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.chain(spatial_anchor_create_info);
+        },
+        |next| match next.create_spatial_anchor_from_persisted_name_msft {
+            Some(f) => f(session, spatial_anchor_create_info, spatial_anchor),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.pointer(spatial_anchor);
+        },
+    )
+}
+unsafe extern "system" fn unpersist_spatial_anchor_msft(
+    spatial_anchor_store: SpatialAnchorStoreConnectionMSFT,
+    spatial_anchor_persistence_name: *const SpatialAnchorPersistenceNameMSFT,
+) -> Result {
+    super::call(
+        "xrUnpersistSpatialAnchorMSFT",
+        spatial_anchor_store.into_raw(),
+        |inputs| {
+            inputs.value(&spatial_anchor_store);
+            inputs.pointer(spatial_anchor_persistence_name);
+        },
+        |next| match next.unpersist_spatial_anchor_msft {
+            Some(f) => f(spatial_anchor_store, spatial_anchor_persistence_name),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |_| {},
+    )
+}
+unsafe extern "system" fn clear_spatial_anchor_store_msft(
+    spatial_anchor_store: SpatialAnchorStoreConnectionMSFT,
+) -> Result {
+    super::call(
+        "xrClearSpatialAnchorStoreMSFT",
+        spatial_anchor_store.into_raw(),
+        |inputs| {
+            inputs.value(&spatial_anchor_store);
+        },
+        |next| match next.clear_spatial_anchor_store_msft {
+            Some(f) => f(spatial_anchor_store),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |_| {},
+    )
+}
+unsafe extern "system" fn create_facial_tracker_htc(
+    session: Session,
+    create_info: *const FacialTrackerCreateInfoHTC,
+    facial_tracker: *mut FacialTrackerHTC,
+) -> Result {
+    super::call(
+        "xrCreateFacialTrackerHTC",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.chain(create_info);
+        },
+        |next| match next.create_facial_tracker_htc {
+            Some(f) => f(session, create_info, facial_tracker),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.pointer(facial_tracker);
+        },
+    )
+}
+unsafe extern "system" fn destroy_facial_tracker_htc(facial_tracker: FacialTrackerHTC) -> Result {
+    super::call(
+        "xrDestroyFacialTrackerHTC",
+        facial_tracker.into_raw(),
+        |inputs| {
+            inputs.value(&facial_tracker);
+        },
+        |next| match next.destroy_facial_tracker_htc {
+            Some(f) => f(facial_tracker),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |_| {},
+    )
+}
+unsafe extern "system" fn get_facial_expressions_htc(
+    facial_tracker: FacialTrackerHTC,
+    facial_expressions: *mut FacialExpressionsHTC,
+) -> Result {
+    super::call(
+        "xrGetFacialExpressionsHTC",
+        facial_tracker.into_raw(),
+        |inputs| {
+            inputs.value(&facial_tracker);
+        },
+        |next| match next.get_facial_expressions_htc {
+            Some(f) => f(facial_tracker, facial_expressions),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.chain(facial_expressions);
+        },
+    )
+}
+unsafe extern "system" fn create_passthrough_htc(
+    session: Session,
+    create_info: *const PassthroughCreateInfoHTC,
+    passthrough: *mut PassthroughHTC,
+) -> Result {
+    super::call(
+        "xrCreatePassthroughHTC",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.chain(create_info);
+        },
+        |next| match next.create_passthrough_htc {
+            Some(f) => f(session, create_info, passthrough),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.pointer(passthrough);
+        },
+    )
+}
+unsafe extern "system" fn destroy_passthrough_htc(passthrough: PassthroughHTC) -> Result {
+    super::call(
+        "xrDestroyPassthroughHTC",
+        passthrough.into_raw(),
+        |inputs| {
+            inputs.value(&passthrough);
+        },
+        |next| match next.destroy_passthrough_htc {
+            Some(f) => f(passthrough),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |_| {},
+    )
+}
+unsafe extern "system" fn enumerate_vive_tracker_paths_htcx(
+    instance: Instance,
+    path_capacity_input: u32,
+    path_count_output: *mut u32,
+    paths: *mut ViveTrackerPathsHTCX,
+) -> Result {
+    super::call(
+        "xrEnumerateViveTrackerPathsHTCX",
+        instance.into_raw(),
+        |inputs| {
+            inputs.value(&instance);
+            inputs.value(&path_capacity_input);
+        },
+        |next| match next.enumerate_vive_tracker_paths_htcx {
+            Some(f) => f(instance, path_capacity_input, path_count_output, paths),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.chain_array(path_capacity_input, path_count_output, paths);
+        },
+    )
+}
+unsafe extern "system" fn set_marker_tracking_varjo(session: Session, enabled: Bool32) -> Result {
+    super::call(
+        "xrSetMarkerTrackingVARJO",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.value(&enabled);
+        },
+        |next| match next.set_marker_tracking_varjo {
+            Some(f) => f(session, enabled),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |_| {},
+    )
+}
+unsafe extern "system" fn set_marker_tracking_timeout_varjo(
+    session: Session,
+    marker_id: u64,
+    timeout: Duration,
+) -> Result {
+    super::call(
+        "xrSetMarkerTrackingTimeoutVARJO",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.value(&marker_id);
+            inputs.value(&timeout);
+        },
+        |next| match next.set_marker_tracking_timeout_varjo {
+            Some(f) => f(session, marker_id, timeout),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |_| {},
+    )
+}
+unsafe extern "system" fn set_marker_tracking_prediction_varjo(
+    session: Session,
+    marker_id: u64,
+    enabled: Bool32,
+) -> Result {
+    super::call(
+        "xrSetMarkerTrackingPredictionVARJO",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.value(&marker_id);
+            inputs.value(&enabled);
+        },
+        |next| match next.set_marker_tracking_prediction_varjo {
+            Some(f) => f(session, marker_id, enabled),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |_| {},
+    )
+}
+unsafe extern "system" fn get_marker_size_varjo(
+    session: Session,
+    marker_id: u64,
+    size: *mut Extent2Df,
+) -> Result {
+    super::call(
+        "xrGetMarkerSizeVARJO",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.value(&marker_id);
+        },
+        |next| match next.get_marker_size_varjo {
+            Some(f) => f(session, marker_id, size),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.pointer(size);
+        },
+    )
+}
+unsafe extern "system" fn create_marker_space_varjo(
+    session: Session,
+    create_info: *const MarkerSpaceCreateInfoVARJO,
+    space: *mut Space,
+) -> Result {
+    super::call(
+        "xrCreateMarkerSpaceVARJO",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.chain(create_info);
+        },
+        |next| match next.create_marker_space_varjo {
+            Some(f) => f(session, create_info, space),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.pointer(space);
+        },
+    )
+}
+unsafe extern "system" fn set_digital_lens_control_almalence(
+    session: Session,
+    digital_lens_control: *const DigitalLensControlALMALENCE,
+) -> Result {
+    super::call(
+        "xrSetDigitalLensControlALMALENCE",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.chain(digital_lens_control);
+        },
+        |next| match next.set_digital_lens_control_almalence {
+            Some(f) => f(session, digital_lens_control),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |_| {},
+    )
+}
+unsafe extern "system" fn set_view_offset_varjo(session: Session, offset: f32) -> Result {
+    super::call(
+        "xrSetViewOffsetVARJO",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.value(&offset);
+        },
+        |next| match next.set_view_offset_varjo {
+            Some(f) => f(session, offset),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |_| {},
+    )
+}
+unsafe extern "system" fn enumerate_external_cameras_oculus(
+    session: Session,
+    camera_capacity_input: u32,
+    camera_count_output: *mut u32,
+    cameras: *mut ExternalCameraOCULUS,
+) -> Result {
+    super::call(
+        "xrEnumerateExternalCamerasOCULUS",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.value(&camera_capacity_input);
+        },
+        |next| match next.enumerate_external_cameras_oculus {
+            Some(f) => f(session, camera_capacity_input, camera_count_output, cameras),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.chain_array(camera_capacity_input, camera_count_output, cameras);
+        },
+    )
+}
+unsafe extern "system" fn create_passthrough_color_lut_meta(
+    passthrough: PassthroughFB,
+    create_info: *const PassthroughColorLutCreateInfoMETA,
+    color_lut: *mut PassthroughColorLutMETA,
+) -> Result {
+    super::call(
+        "xrCreatePassthroughColorLutMETA",
+        passthrough.into_raw(),
+        |inputs| {
+            inputs.value(&passthrough);
+            inputs.chain(create_info);
+        },
+        |next| match next.create_passthrough_color_lut_meta {
+            Some(f) => f(passthrough, create_info, color_lut),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.pointer(color_lut);
+        },
+    )
+}
+unsafe extern "system" fn destroy_passthrough_color_lut_meta(
+    color_lut: PassthroughColorLutMETA,
+) -> Result {
+    super::call(
+        "xrDestroyPassthroughColorLutMETA",
+        color_lut.into_raw(),
+        |inputs| {
+            inputs.value(&color_lut);
+        },
+        |next| match next.destroy_passthrough_color_lut_meta {
+            Some(f) => f(color_lut),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |_| {},
+    )
+}
+unsafe extern "system" fn update_passthrough_color_lut_meta(
+    color_lut: PassthroughColorLutMETA,
+    update_info: *const PassthroughColorLutUpdateInfoMETA,
+) -> Result {
+    super::call(
+        "xrUpdatePassthroughColorLutMETA",
+        color_lut.into_raw(),
+        |inputs| {
+            inputs.value(&color_lut);
+            inputs.chain(update_info);
+        },
+        |next| match next.update_passthrough_color_lut_meta {
+            Some(f) => f(color_lut, update_info),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |_| {},
+    )
+}
+unsafe extern "system" fn enumerate_performance_metrics_counter_paths_meta(
+    instance: Instance,
+    counter_path_capacity_input: u32,
+    counter_path_count_output: *mut u32,
+    counter_paths: *mut Path,
+) -> Result {
+    super::call(
+        "xrEnumeratePerformanceMetricsCounterPathsMETA",
+        instance.into_raw(),
+        |inputs| {
+            inputs.value(&instance);
+            inputs.value(&counter_path_capacity_input);
+        },
+        |next| match next.enumerate_performance_metrics_counter_paths_meta {
+            Some(f) => f(
+                instance,
+                counter_path_capacity_input,
+                counter_path_count_output,
+                counter_paths,
+            ),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.array(
+                counter_path_capacity_input,
+                counter_path_count_output,
+                counter_paths,
+            );
+        },
+    )
+}
+unsafe extern "system" fn set_performance_metrics_state_meta(
+    session: Session,
+    state: *const PerformanceMetricsStateMETA,
+) -> Result {
+    super::call(
+        "xrSetPerformanceMetricsStateMETA",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.chain(state);
+        },
+        |next| match next.set_performance_metrics_state_meta {
+            Some(f) => f(session, state),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |_| {},
+    )
+}
+unsafe extern "system" fn get_performance_metrics_state_meta(
+    session: Session,
+    state: *mut PerformanceMetricsStateMETA,
+) -> Result {
+    super::call(
+        "xrGetPerformanceMetricsStateMETA",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+        },
+        |next| match next.get_performance_metrics_state_meta {
+            Some(f) => f(session, state),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.chain(state);
+        },
+    )
+}
+unsafe extern "system" fn query_performance_metrics_counter_meta(
+    session: Session,
+    counter_path: Path,
+    counter: *mut PerformanceMetricsCounterMETA,
+) -> Result {
+    super::call(
+        "xrQueryPerformanceMetricsCounterMETA",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.value(&counter_path);
+        },
+        |next| match next.query_performance_metrics_counter_meta {
+            Some(f) => f(session, counter_path, counter),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.chain(counter);
+        },
+    )
+}
+unsafe extern "system" fn get_passthrough_preferences_meta(
+    session: Session,
+    preferences: *mut PassthroughPreferencesMETA,
+) -> Result {
+    super::call(
+        "xrGetPassthroughPreferencesMETA",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+        },
+        |next| match next.get_passthrough_preferences_meta {
+            Some(f) => f(session, preferences),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.chain(preferences);
+        },
+    )
+}
+unsafe extern "system" fn apply_foveation_htc(
+    session: Session,
+    apply_info: *const FoveationApplyInfoHTC,
+) -> Result {
+    super::call(
+        "xrApplyFoveationHTC",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.chain(apply_info);
+        },
+        |next| match next.apply_foveation_htc {
+            Some(f) => f(session, apply_info),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |_| {},
+    )
+}
+unsafe extern "system" fn create_space_from_coordinate_frame_uidml(
+    session: Session,
+    create_info: *const CoordinateSpaceCreateInfoML,
+    space: *mut Space,
+) -> Result {
+    super::call(
+        "xrCreateSpaceFromCoordinateFrameUIDML",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.chain(create_info);
+        },
+        |next| match next.create_space_from_coordinate_frame_uidml {
+            Some(f) => f(session, create_info, space),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.pointer(space);
+        },
+    )
+}
+unsafe extern "system" fn get_device_sample_rate_fb(
+    session: Session,
+    haptic_action_info: *const HapticActionInfo,
+    device_sample_rate: *mut DevicePcmSampleRateStateFB,
+) -> Result {
+    super::call(
+        "xrGetDeviceSampleRateFB",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.chain(haptic_action_info);
+        },
+        |next| match next.get_device_sample_rate_fb {
+            Some(f) => f(session, haptic_action_info, device_sample_rate),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.chain(device_sample_rate);
+        },
+    )
+}
+unsafe extern "system" fn set_tracking_optimization_settings_hint_qcom(
+    session: Session,
+    domain: TrackingOptimizationSettingsDomainQCOM,
+    hint: TrackingOptimizationSettingsHintQCOM,
+) -> Result {
+    super::call(
+        "xrSetTrackingOptimizationSettingsHintQCOM",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.value(&domain);
+            inputs.value(&hint);
+        },
+        |next| match next.set_tracking_optimization_settings_hint_qcom {
+            Some(f) => f(session, domain, hint),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |_| {},
+    )
+}
+unsafe extern "system" fn create_space_user_fb(
+    session: Session,
+    info: *const SpaceUserCreateInfoFB,
+    user: *mut SpaceUserFB,
+) -> Result {
+    super::call(
+        "xrCreateSpaceUserFB",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.chain(info);
+        },
+        |next| match next.create_space_user_fb {
+            Some(f) => f(session, info, user),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.pointer(user);
+        },
+    )
+}
+unsafe extern "system" fn get_space_user_id_fb(
+    user: SpaceUserFB,
+    user_id: *mut SpaceUserIdFB,
+) -> Result {
+    super::call(
+        "xrGetSpaceUserIdFB",
+        user.into_raw(),
+        |inputs| {
+            inputs.value(&user);
+        },
+        |next| match next.get_space_user_id_fb {
+            Some(f) => f(user, user_id),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.pointer(user_id);
+        },
+    )
+}
+unsafe extern "system" fn destroy_space_user_fb(user: SpaceUserFB) -> Result {
+    super::call(
+        "xrDestroySpaceUserFB",
+        user.into_raw(),
+        |inputs| {
+            inputs.value(&user);
+        },
+        |next| match next.destroy_space_user_fb {
+            Some(f) => f(user),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |_| {},
+    )
+}
+unsafe extern "system" fn apply_force_feedback_curl_mndx(
+    hand_tracker: HandTrackerEXT,
+    locations: *const ForceFeedbackCurlApplyLocationsMNDX,
+) -> Result {
+    super::call(
+        "xrApplyForceFeedbackCurlMNDX",
+        hand_tracker.into_raw(),
+        |inputs| {
+            inputs.value(&hand_tracker);
+            inputs.chain(locations);
+        },
+        |next| match next.apply_force_feedback_curl_mndx {
+            Some(f) => f(hand_tracker, locations),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |_| {},
+    )
+}
+unsafe extern "system" fn create_plane_detector_ext(
+    session: Session,
+    create_info: *const PlaneDetectorCreateInfoEXT,
+    plane_detector: *mut PlaneDetectorEXT,
+) -> Result {
+    super::call(
+        "xrCreatePlaneDetectorEXT",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.chain(create_info);
+        },
+        |next| match next.create_plane_detector_ext {
+            Some(f) => f(session, create_info, plane_detector),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.pointer(plane_detector);
+        },
+    )
+}
+unsafe extern "system" fn destroy_plane_detector_ext(plane_detector: PlaneDetectorEXT) -> Result {
+    super::call(
+        "xrDestroyPlaneDetectorEXT",
+        plane_detector.into_raw(),
+        |inputs| {
+            inputs.value(&plane_detector);
+        },
+        |next| match next.destroy_plane_detector_ext {
+            Some(f) => f(plane_detector),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |_| {},
+    )
+}
+unsafe extern "system" fn begin_plane_detection_ext(
+    plane_detector: PlaneDetectorEXT,
+    begin_info: *const PlaneDetectorBeginInfoEXT,
+) -> Result {
+    super::call(
+        "xrBeginPlaneDetectionEXT",
+        plane_detector.into_raw(),
+        |inputs| {
+            inputs.value(&plane_detector);
+            inputs.chain(begin_info);
+        },
+        |next| match next.begin_plane_detection_ext {
+            Some(f) => f(plane_detector, begin_info),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |_| {},
+    )
+}
+unsafe extern "system" fn get_plane_detection_state_ext(
+    plane_detector: PlaneDetectorEXT,
+    state: *mut PlaneDetectionStateEXT,
+) -> Result {
+    super::call(
+        "xrGetPlaneDetectionStateEXT",
+        plane_detector.into_raw(),
+        |inputs| {
+            inputs.value(&plane_detector);
+        },
+        |next| match next.get_plane_detection_state_ext {
+            Some(f) => f(plane_detector, state),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.pointer(state);
+        },
+    )
+}
+unsafe extern "system" fn get_plane_detections_ext(
+    plane_detector: PlaneDetectorEXT,
+    info: *const PlaneDetectorGetInfoEXT,
+    locations: *mut PlaneDetectorLocationsEXT,
+) -> Result {
+    super::call(
+        "xrGetPlaneDetectionsEXT",
+        plane_detector.into_raw(),
+        |inputs| {
+            inputs.value(&plane_detector);
+            inputs.chain(info);
+        },
+        |next| match next.get_plane_detections_ext {
+            Some(f) => f(plane_detector, info, locations),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.chain(locations);
+        },
+    )
+}
+unsafe extern "system" fn get_plane_polygon_buffer_ext(
+    plane_detector: PlaneDetectorEXT,
+    plane_id: u64,
+    polygon_buffer_index: u32,
+    polygon_buffer: *mut PlaneDetectorPolygonBufferEXT,
+) -> Result {
+    super::call(
+        "xrGetPlanePolygonBufferEXT",
+        plane_detector.into_raw(),
+        |inputs| {
+            inputs.value(&plane_detector);
+            inputs.value(&plane_id);
+            inputs.value(&polygon_buffer_index);
+        },
+        |next| match next.get_plane_polygon_buffer_ext {
+            Some(f) => f(
+                plane_detector,
+                plane_id,
+                polygon_buffer_index,
+                polygon_buffer,
+            ),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.chain(polygon_buffer);
+        },
+    )
+}
+unsafe extern "system" fn create_virtual_keyboard_meta(
+    session: Session,
+    create_info: *const VirtualKeyboardCreateInfoMETA,
+    keyboard: *mut VirtualKeyboardMETA,
+) -> Result {
+    super::call(
+        "xrCreateVirtualKeyboardMETA",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.chain(create_info);
+        },
+        |next| match next.create_virtual_keyboard_meta {
+            Some(f) => f(session, create_info, keyboard),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.pointer(keyboard);
+        },
+    )
+}
+unsafe extern "system" fn destroy_virtual_keyboard_meta(keyboard: VirtualKeyboardMETA) -> Result {
+    super::call(
+        "xrDestroyVirtualKeyboardMETA",
+        keyboard.into_raw(),
+        |inputs| {
+            inputs.value(&keyboard);
+        },
+        |next| match next.destroy_virtual_keyboard_meta {
+            Some(f) => f(keyboard),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |_| {},
+    )
+}
+unsafe extern "system" fn create_virtual_keyboard_space_meta(
+    session: Session,
+    keyboard: VirtualKeyboardMETA,
+    create_info: *const VirtualKeyboardSpaceCreateInfoMETA,
+    keyboard_space: *mut Space,
+) -> Result {
+    super::call(
+        "xrCreateVirtualKeyboardSpaceMETA",
+        session.into_raw(),
+        |inputs| {
+            inputs.value(&session);
+            inputs.value(&keyboard);
+            inputs.chain(create_info);
+        },
+        |next| match next.create_virtual_keyboard_space_meta {
+            Some(f) => f(session, keyboard, create_info, keyboard_space),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.pointer(keyboard_space);
+        },
+    )
+}
+unsafe extern "system" fn suggest_virtual_keyboard_location_meta(
+    keyboard: VirtualKeyboardMETA,
+    location_info: *const VirtualKeyboardLocationInfoMETA,
+) -> Result {
+    super::call(
+        "xrSuggestVirtualKeyboardLocationMETA",
+        keyboard.into_raw(),
+        |inputs| {
+            inputs.value(&keyboard);
+            inputs.chain(location_info);
+        },
+        |next| match next.suggest_virtual_keyboard_location_meta {
+            Some(f) => f(keyboard, location_info),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |_| {},
+    )
+}
+unsafe extern "system" fn get_virtual_keyboard_scale_meta(
+    keyboard: VirtualKeyboardMETA,
+    scale: *mut f32,
+) -> Result {
+    super::call(
+        "xrGetVirtualKeyboardScaleMETA",
+        keyboard.into_raw(),
+        |inputs| {
+            inputs.value(&keyboard);
+        },
+        |next| match next.get_virtual_keyboard_scale_meta {
+            Some(f) => f(keyboard, scale),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.pointer(scale);
+        },
+    )
+}
+unsafe extern "system" fn set_virtual_keyboard_model_visibility_meta(
+    keyboard: VirtualKeyboardMETA,
+    model_visibility: *const VirtualKeyboardModelVisibilitySetInfoMETA,
+) -> Result {
+    super::call(
+        "xrSetVirtualKeyboardModelVisibilityMETA",
+        keyboard.into_raw(),
+        |inputs| {
+            inputs.value(&keyboard);
+            inputs.chain(model_visibility);
+        },
+        |next| match next.set_virtual_keyboard_model_visibility_meta {
+            Some(f) => f(keyboard, model_visibility),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |_| {},
+    )
+}
+unsafe extern "system" fn get_virtual_keyboard_model_animation_states_meta(
+    keyboard: VirtualKeyboardMETA,
+    animation_states: *mut VirtualKeyboardModelAnimationStatesMETA,
+) -> Result {
+    super::call(
+        "xrGetVirtualKeyboardModelAnimationStatesMETA",
+        keyboard.into_raw(),
+        |inputs| {
+            inputs.value(&keyboard);
+        },
+        |next| match next.get_virtual_keyboard_model_animation_states_meta {
+            Some(f) => f(keyboard, animation_states),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.chain(animation_states);
+        },
+    )
+}
+unsafe extern "system" fn get_virtual_keyboard_dirty_textures_meta(
+    keyboard: VirtualKeyboardMETA,
+    texture_id_capacity_input: u32,
+    texture_id_count_output: *mut u32,
+    texture_ids: *mut u64,
+) -> Result {
+    super::call(
+        "xrGetVirtualKeyboardDirtyTexturesMETA",
+        keyboard.into_raw(),
+        |inputs| {
+            inputs.value(&keyboard);
+            inputs.value(&texture_id_capacity_input);
+        },
+        |next| match next.get_virtual_keyboard_dirty_textures_meta {
+            Some(f) => f(
+                keyboard,
+                texture_id_capacity_input,
+                texture_id_count_output,
+                texture_ids,
+            ),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.array(
+                texture_id_capacity_input,
+                texture_id_count_output,
+                texture_ids,
+            );
+        },
+    )
+}
+unsafe extern "system" fn get_virtual_keyboard_texture_data_meta(
+    keyboard: VirtualKeyboardMETA,
+    texture_id: u64,
+    texture_data: *mut VirtualKeyboardTextureDataMETA,
+) -> Result {
+    super::call(
+        "xrGetVirtualKeyboardTextureDataMETA",
+        keyboard.into_raw(),
+        |inputs| {
+            inputs.value(&keyboard);
+            inputs.value(&texture_id);
+        },
+        |next| match next.get_virtual_keyboard_texture_data_meta {
+            Some(f) => f(keyboard, texture_id, texture_data),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.chain(texture_data);
+        },
+    )
+}
+unsafe extern "system" fn send_virtual_keyboard_input_meta(
+    keyboard: VirtualKeyboardMETA,
+    info: *const VirtualKeyboardInputInfoMETA,
+    interactor_root_pose: *mut Posef,
+) -> Result {
+    super::call(
+        "xrSendVirtualKeyboardInputMETA",
+        keyboard.into_raw(),
+        |inputs| {
+            inputs.value(&keyboard);
+            inputs.chain(info);
+        },
+        |next| match next.send_virtual_keyboard_input_meta {
+            Some(f) => f(keyboard, info, interactor_root_pose),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.pointer(interactor_root_pose);
+        },
+    )
+}
+unsafe extern "system" fn change_virtual_keyboard_text_context_meta(
+    keyboard: VirtualKeyboardMETA,
+    change_info: *const VirtualKeyboardTextContextChangeInfoMETA,
+) -> Result {
+    super::call(
+        "xrChangeVirtualKeyboardTextContextMETA",
+        keyboard.into_raw(),
+        |inputs| {
+            inputs.value(&keyboard);
+            inputs.chain(change_info);
+        },
+        |next| match next.change_virtual_keyboard_text_context_meta {
+            Some(f) => f(keyboard, change_info),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |_| {},
+    )
+}
+unsafe extern "system" fn get_vulkan_graphics_requirements2_khr(
+    instance: Instance,
+    system_id: SystemId,
+    graphics_requirements: *mut GraphicsRequirementsVulkanKHR,
+) -> Result {
+    super::call(
+        "xrGetVulkanGraphicsRequirements2KHR",
+        instance.into_raw(),
+        |inputs| {
+            inputs.value(&instance);
+            inputs.value(&system_id);
+        },
+        |next| match next.get_vulkan_graphics_requirements2_khr {
+            Some(f) => f(instance, system_id, graphics_requirements),
+            None => Result::ERROR_FUNCTION_UNSUPPORTED,
+        },
+        |outputs| {
+            outputs.chain(graphics_requirements);
+        },
+    )
+}
+
+#[doc = r" Look up the entry point for the command named `name`"]
+pub(super) fn thunk(name: &[u8]) -> Option<pfn::VoidFunction> {
+    unsafe {
+        Some(match name {
+            b"xrEnumerateApiLayerProperties" => mem::transmute::<
+                pfn::EnumerateApiLayerProperties,
+                pfn::VoidFunction,
+            >(enumerate_api_layer_properties),
+            b"xrEnumerateInstanceExtensionProperties" => {
+                mem::transmute::<pfn::EnumerateInstanceExtensionProperties, pfn::VoidFunction>(
+                    enumerate_instance_extension_properties,
+                )
+            }
+            b"xrCreateInstance" => {
+                mem::transmute::<pfn::CreateInstance, pfn::VoidFunction>(create_instance)
+            }
+            b"xrDestroyInstance" => {
+                mem::transmute::<pfn::DestroyInstance, pfn::VoidFunction>(destroy_instance)
+            }
+            b"xrResultToString" => {
+                mem::transmute::<pfn::ResultToString, pfn::VoidFunction>(result_to_string)
+            }
+            b"xrStructureTypeToString" => mem::transmute::<
+                pfn::StructureTypeToString,
+                pfn::VoidFunction,
+            >(structure_type_to_string),
+            b"xrGetInstanceProperties" => mem::transmute::<
+                pfn::GetInstanceProperties,
+                pfn::VoidFunction,
+            >(get_instance_properties),
+            b"xrGetSystem" => mem::transmute::<pfn::GetSystem, pfn::VoidFunction>(get_system),
+            b"xrGetSystemProperties" => {
+                mem::transmute::<pfn::GetSystemProperties, pfn::VoidFunction>(get_system_properties)
+            }
+            b"xrCreateSession" => {
+                mem::transmute::<pfn::CreateSession, pfn::VoidFunction>(create_session)
+            }
+            b"xrDestroySession" => {
+                mem::transmute::<pfn::DestroySession, pfn::VoidFunction>(destroy_session)
+            }
+            b"xrDestroySpace" => {
+                mem::transmute::<pfn::DestroySpace, pfn::VoidFunction>(destroy_space)
+            }
+            b"xrEnumerateSwapchainFormats" => mem::transmute::<
+                pfn::EnumerateSwapchainFormats,
+                pfn::VoidFunction,
+            >(enumerate_swapchain_formats),
+            b"xrCreateSwapchain" => {
+                mem::transmute::<pfn::CreateSwapchain, pfn::VoidFunction>(create_swapchain)
+            }
+            b"xrDestroySwapchain" => {
+                mem::transmute::<pfn::DestroySwapchain, pfn::VoidFunction>(destroy_swapchain)
+            }
+            b"xrEnumerateSwapchainImages" => mem::transmute::<
+                pfn::EnumerateSwapchainImages,
+                pfn::VoidFunction,
+            >(enumerate_swapchain_images),
+            b"xrAcquireSwapchainImage" => mem::transmute::<
+                pfn::AcquireSwapchainImage,
+                pfn::VoidFunction,
+            >(acquire_swapchain_image),
+            b"xrWaitSwapchainImage" => {
+                mem::transmute::<pfn::WaitSwapchainImage, pfn::VoidFunction>(wait_swapchain_image)
+            }
+            b"xrReleaseSwapchainImage" => mem::transmute::<
+                pfn::ReleaseSwapchainImage,
+                pfn::VoidFunction,
+            >(release_swapchain_image),
+            b"xrBeginSession" => {
+                mem::transmute::<pfn::BeginSession, pfn::VoidFunction>(begin_session)
+            }
+            b"xrEndSession" => mem::transmute::<pfn::EndSession, pfn::VoidFunction>(end_session),
+            b"xrRequestExitSession" => {
+                mem::transmute::<pfn::RequestExitSession, pfn::VoidFunction>(request_exit_session)
+            }
+            b"xrEnumerateReferenceSpaces" => mem::transmute::<
+                pfn::EnumerateReferenceSpaces,
+                pfn::VoidFunction,
+            >(enumerate_reference_spaces),
+            b"xrCreateReferenceSpace" => mem::transmute::<
+                pfn::CreateReferenceSpace,
+                pfn::VoidFunction,
+            >(create_reference_space),
+            b"xrCreateActionSpace" => {
+                mem::transmute::<pfn::CreateActionSpace, pfn::VoidFunction>(create_action_space)
+            }
+            b"xrLocateSpace" => mem::transmute::<pfn::LocateSpace, pfn::VoidFunction>(locate_space),
+            b"xrEnumerateViewConfigurations" => mem::transmute::<
+                pfn::EnumerateViewConfigurations,
+                pfn::VoidFunction,
+            >(enumerate_view_configurations),
+            b"xrEnumerateEnvironmentBlendModes" => mem::transmute::<
+                pfn::EnumerateEnvironmentBlendModes,
+                pfn::VoidFunction,
+            >(enumerate_environment_blend_modes),
+            b"xrGetViewConfigurationProperties" => mem::transmute::<
+                pfn::GetViewConfigurationProperties,
+                pfn::VoidFunction,
+            >(get_view_configuration_properties),
+            b"xrEnumerateViewConfigurationViews" => {
+                mem::transmute::<pfn::EnumerateViewConfigurationViews, pfn::VoidFunction>(
+                    enumerate_view_configuration_views,
+                )
+            }
+            b"xrBeginFrame" => mem::transmute::<pfn::BeginFrame, pfn::VoidFunction>(begin_frame),
+            b"xrLocateViews" => mem::transmute::<pfn::LocateViews, pfn::VoidFunction>(locate_views),
+            b"xrEndFrame" => mem::transmute::<pfn::EndFrame, pfn::VoidFunction>(end_frame),
+            b"xrWaitFrame" => mem::transmute::<pfn::WaitFrame, pfn::VoidFunction>(wait_frame),
+            b"xrApplyHapticFeedback" => {
+                mem::transmute::<pfn::ApplyHapticFeedback, pfn::VoidFunction>(apply_haptic_feedback)
+            }
+            b"xrStopHapticFeedback" => {
+                mem::transmute::<pfn::StopHapticFeedback, pfn::VoidFunction>(stop_haptic_feedback)
+            }
+            b"xrPollEvent" => mem::transmute::<pfn::PollEvent, pfn::VoidFunction>(poll_event),
+            b"xrStringToPath" => {
+                mem::transmute::<pfn::StringToPath, pfn::VoidFunction>(string_to_path)
+            }
+            b"xrPathToString" => {
+                mem::transmute::<pfn::PathToString, pfn::VoidFunction>(path_to_string)
+            }
+            b"xrGetReferenceSpaceBoundsRect" => mem::transmute::<
+                pfn::GetReferenceSpaceBoundsRect,
+                pfn::VoidFunction,
+            >(get_reference_space_bounds_rect),
+            #[cfg(target_os = "android")]
+            b"xrSetAndroidApplicationThreadKHR" => mem::transmute::<
+                pfn::SetAndroidApplicationThreadKHR,
+                pfn::VoidFunction,
+            >(set_android_application_thread_khr),
+            #[cfg(target_os = "android")]
+            b"xrCreateSwapchainAndroidSurfaceKHR" => {
+                mem::transmute::<pfn::CreateSwapchainAndroidSurfaceKHR, pfn::VoidFunction>(
+                    create_swapchain_android_surface_khr,
+                )
+            }
+            b"xrGetActionStateBoolean" => mem::transmute::<
+                pfn::GetActionStateBoolean,
+                pfn::VoidFunction,
+            >(get_action_state_boolean),
+            b"xrGetActionStateFloat" => {
+                mem::transmute::<pfn::GetActionStateFloat, pfn::VoidFunction>(
+                    get_action_state_float,
+                )
+            }
+            b"xrGetActionStateVector2f" => mem::transmute::<
+                pfn::GetActionStateVector2f,
+                pfn::VoidFunction,
+            >(get_action_state_vector2f),
+            b"xrGetActionStatePose" => {
+                mem::transmute::<pfn::GetActionStatePose, pfn::VoidFunction>(get_action_state_pose)
+            }
+            b"xrCreateActionSet" => {
+                mem::transmute::<pfn::CreateActionSet, pfn::VoidFunction>(create_action_set)
+            }
+            b"xrDestroyActionSet" => {
+                mem::transmute::<pfn::DestroyActionSet, pfn::VoidFunction>(destroy_action_set)
+            }
+            b"xrCreateAction" => {
+                mem::transmute::<pfn::CreateAction, pfn::VoidFunction>(create_action)
+            }
+            b"xrDestroyAction" => {
+                mem::transmute::<pfn::DestroyAction, pfn::VoidFunction>(destroy_action)
+            }
+            b"xrSuggestInteractionProfileBindings" => {
+                mem::transmute::<pfn::SuggestInteractionProfileBindings, pfn::VoidFunction>(
+                    suggest_interaction_profile_bindings,
+                )
+            }
+            b"xrAttachSessionActionSets" => mem::transmute::<
+                pfn::AttachSessionActionSets,
+                pfn::VoidFunction,
+            >(attach_session_action_sets),
+            b"xrGetCurrentInteractionProfile" => mem::transmute::<
+                pfn::GetCurrentInteractionProfile,
+                pfn::VoidFunction,
+            >(get_current_interaction_profile),
+            b"xrSyncActions" => mem::transmute::<pfn::SyncActions, pfn::VoidFunction>(sync_actions),
+            b"xrEnumerateBoundSourcesForAction" => mem::transmute::<
+                pfn::EnumerateBoundSourcesForAction,
+                pfn::VoidFunction,
+            >(enumerate_bound_sources_for_action),
+            b"xrGetInputSourceLocalizedName" => mem::transmute::<
+                pfn::GetInputSourceLocalizedName,
+                pfn::VoidFunction,
+            >(get_input_source_localized_name),
+            b"xrLocateSpaces" | b"xrLocateSpacesKHR" => {
+                mem::transmute::<pfn::LocateSpaces, pfn::VoidFunction>(locate_spaces)
+            }
+            b"xrGetVulkanInstanceExtensionsKHR" => mem::transmute::<
+                pfn::GetVulkanInstanceExtensionsKHR,
+                pfn::VoidFunction,
+            >(get_vulkan_instance_extensions_khr),
+            b"xrGetVulkanDeviceExtensionsKHR" => mem::transmute::<
+                pfn::GetVulkanDeviceExtensionsKHR,
+                pfn::VoidFunction,
+            >(get_vulkan_device_extensions_khr),
+            b"xrGetVulkanGraphicsDeviceKHR" => mem::transmute::<
+                pfn::GetVulkanGraphicsDeviceKHR,
+                pfn::VoidFunction,
+            >(get_vulkan_graphics_device_khr),
+            b"xrGetOpenGLGraphicsRequirementsKHR" => {
+                mem::transmute::<pfn::GetOpenGLGraphicsRequirementsKHR, pfn::VoidFunction>(
+                    get_open_gl_graphics_requirements_khr,
+                )
+            }
+            b"xrGetOpenGLESGraphicsRequirementsKHR" => {
+                mem::transmute::<pfn::GetOpenGLESGraphicsRequirementsKHR, pfn::VoidFunction>(
+                    get_open_gles_graphics_requirements_khr,
+                )
+            }
+            b"xrGetVulkanGraphicsRequirementsKHR" => {
+                mem::transmute::<pfn::GetVulkanGraphicsRequirementsKHR, pfn::VoidFunction>(
+                    get_vulkan_graphics_requirements_khr,
+                )
+            }
+            #[cfg(windows)]
+            b"xrGetD3D11GraphicsRequirementsKHR" => {
+                mem::transmute::<pfn::GetD3D11GraphicsRequirementsKHR, pfn::VoidFunction>(
+                    get_d3d11_graphics_requirements_khr,
+                )
+            }
+            #[cfg(windows)]
+            b"xrGetD3D12GraphicsRequirementsKHR" => {
+                mem::transmute::<pfn::GetD3D12GraphicsRequirementsKHR, pfn::VoidFunction>(
+                    get_d3d12_graphics_requirements_khr,
+                )
+            }
+            b"xrPerfSettingsSetPerformanceLevelEXT" => {
+                mem::transmute::<pfn::PerfSettingsSetPerformanceLevelEXT, pfn::VoidFunction>(
+                    perf_settings_set_performance_level_ext,
+                )
+            }
+            b"xrThermalGetTemperatureTrendEXT" => mem::transmute::<
+                pfn::ThermalGetTemperatureTrendEXT,
+                pfn::VoidFunction,
+            >(thermal_get_temperature_trend_ext),
+            b"xrSetDebugUtilsObjectNameEXT" => mem::transmute::<
+                pfn::SetDebugUtilsObjectNameEXT,
+                pfn::VoidFunction,
+            >(set_debug_utils_object_name_ext),
+            b"xrCreateDebugUtilsMessengerEXT" => mem::transmute::<
+                pfn::CreateDebugUtilsMessengerEXT,
+                pfn::VoidFunction,
+            >(create_debug_utils_messenger_ext),
+            b"xrDestroyDebugUtilsMessengerEXT" => mem::transmute::<
+                pfn::DestroyDebugUtilsMessengerEXT,
+                pfn::VoidFunction,
+            >(destroy_debug_utils_messenger_ext),
+            b"xrSubmitDebugUtilsMessageEXT" => mem::transmute::<
+                pfn::SubmitDebugUtilsMessageEXT,
+                pfn::VoidFunction,
+            >(submit_debug_utils_message_ext),
+            b"xrSessionBeginDebugUtilsLabelRegionEXT" => {
+                mem::transmute::<pfn::SessionBeginDebugUtilsLabelRegionEXT, pfn::VoidFunction>(
+                    session_begin_debug_utils_label_region_ext,
+                )
+            }
+            b"xrSessionEndDebugUtilsLabelRegionEXT" => {
+                mem::transmute::<pfn::SessionEndDebugUtilsLabelRegionEXT, pfn::VoidFunction>(
+                    session_end_debug_utils_label_region_ext,
+                )
+            }
+            b"xrSessionInsertDebugUtilsLabelEXT" => {
+                mem::transmute::<pfn::SessionInsertDebugUtilsLabelEXT, pfn::VoidFunction>(
+                    session_insert_debug_utils_label_ext,
+                )
+            }
+            #[cfg(windows)]
+            b"xrConvertTimeToWin32PerformanceCounterKHR" => {
+                mem::transmute::<pfn::ConvertTimeToWin32PerformanceCounterKHR, pfn::VoidFunction>(
+                    convert_time_to_win32_performance_counter_khr,
+                )
+            }
+            #[cfg(windows)]
+            b"xrConvertWin32PerformanceCounterToTimeKHR" => {
+                mem::transmute::<pfn::ConvertWin32PerformanceCounterToTimeKHR, pfn::VoidFunction>(
+                    convert_win32_performance_counter_to_time_khr,
+                )
+            }
+            b"xrCreateVulkanInstanceKHR" => mem::transmute::<
+                pfn::CreateVulkanInstanceKHR,
+                pfn::VoidFunction,
+            >(create_vulkan_instance_khr),
+            b"xrCreateVulkanDeviceKHR" => mem::transmute::<
+                pfn::CreateVulkanDeviceKHR,
+                pfn::VoidFunction,
+            >(create_vulkan_device_khr),
+            b"xrGetVulkanGraphicsDevice2KHR" => mem::transmute::<
+                pfn::GetVulkanGraphicsDevice2KHR,
+                pfn::VoidFunction,
+            >(get_vulkan_graphics_device2_khr),
+            b"xrConvertTimeToTimespecTimeKHR" => mem::transmute::<
+                pfn::ConvertTimeToTimespecTimeKHR,
+                pfn::VoidFunction,
+            >(convert_time_to_timespec_time_khr),
+            b"xrConvertTimespecTimeToTimeKHR" => mem::transmute::<
+                pfn::ConvertTimespecTimeToTimeKHR,
+                pfn::VoidFunction,
+            >(convert_timespec_time_to_time_khr),
+            b"xrGetVisibilityMaskKHR" => mem::transmute::<
+                pfn::GetVisibilityMaskKHR,
+                pfn::VoidFunction,
+            >(get_visibility_mask_khr),
+            b"xrCreateSpatialAnchorMSFT" => mem::transmute::<
+                pfn::CreateSpatialAnchorMSFT,
+                pfn::VoidFunction,
+            >(create_spatial_anchor_msft),
+            b"xrCreateSpatialAnchorSpaceMSFT" => mem::transmute::<
+                pfn::CreateSpatialAnchorSpaceMSFT,
+                pfn::VoidFunction,
+            >(create_spatial_anchor_space_msft),
+            b"xrDestroySpatialAnchorMSFT" => mem::transmute::<
+                pfn::DestroySpatialAnchorMSFT,
+                pfn::VoidFunction,
+            >(destroy_spatial_anchor_msft),
+            b"xrSetInputDeviceActiveEXT" => mem::transmute::<
+                pfn::SetInputDeviceActiveEXT,
+                pfn::VoidFunction,
+            >(set_input_device_active_ext),
+            b"xrSetInputDeviceStateBoolEXT" => mem::transmute::<
+                pfn::SetInputDeviceStateBoolEXT,
+                pfn::VoidFunction,
+            >(set_input_device_state_bool_ext),
+            b"xrSetInputDeviceStateFloatEXT" => mem::transmute::<
+                pfn::SetInputDeviceStateFloatEXT,
+                pfn::VoidFunction,
+            >(set_input_device_state_float_ext),
+            b"xrSetInputDeviceStateVector2fEXT" => {
+                mem::transmute::<pfn::SetInputDeviceStateVector2fEXT, pfn::VoidFunction>(
+                    set_input_device_state_vector2f_ext,
+                )
+            }
+            b"xrSetInputDeviceLocationEXT" => mem::transmute::<
+                pfn::SetInputDeviceLocationEXT,
+                pfn::VoidFunction,
+            >(set_input_device_location_ext),
+            b"xrInitializeLoaderKHR" => {
+                mem::transmute::<pfn::InitializeLoaderKHR, pfn::VoidFunction>(initialize_loader_khr)
+            }
+            b"xrCreateSpatialGraphNodeSpaceMSFT" => {
+                mem::transmute::<pfn::CreateSpatialGraphNodeSpaceMSFT, pfn::VoidFunction>(
+                    create_spatial_graph_node_space_msft,
+                )
+            }
+            b"xrTryCreateSpatialGraphStaticNodeBindingMSFT" => {
+                mem::transmute::<pfn::TryCreateSpatialGraphStaticNodeBindingMSFT, pfn::VoidFunction>(
+                    try_create_spatial_graph_static_node_binding_msft,
+                )
+            }
+            b"xrDestroySpatialGraphNodeBindingMSFT" => {
+                mem::transmute::<pfn::DestroySpatialGraphNodeBindingMSFT, pfn::VoidFunction>(
+                    destroy_spatial_graph_node_binding_msft,
+                )
+            }
+            b"xrGetSpatialGraphNodeBindingPropertiesMSFT" => {
+                mem::transmute::<pfn::GetSpatialGraphNodeBindingPropertiesMSFT, pfn::VoidFunction>(
+                    get_spatial_graph_node_binding_properties_msft,
+                )
+            }
+            b"xrCreateHandTrackerEXT" => mem::transmute::<
+                pfn::CreateHandTrackerEXT,
+                pfn::VoidFunction,
+            >(create_hand_tracker_ext),
+            b"xrDestroyHandTrackerEXT" => mem::transmute::<
+                pfn::DestroyHandTrackerEXT,
+                pfn::VoidFunction,
+            >(destroy_hand_tracker_ext),
+            b"xrLocateHandJointsEXT" => {
+                mem::transmute::<pfn::LocateHandJointsEXT, pfn::VoidFunction>(
+                    locate_hand_joints_ext,
+                )
+            }
+            b"xrCreateFaceTrackerFB" => {
+                mem::transmute::<pfn::CreateFaceTrackerFB, pfn::VoidFunction>(
+                    create_face_tracker_fb,
+                )
+            }
+            b"xrDestroyFaceTrackerFB" => mem::transmute::<
+                pfn::DestroyFaceTrackerFB,
+                pfn::VoidFunction,
+            >(destroy_face_tracker_fb),
+            b"xrGetFaceExpressionWeightsFB" => mem::transmute::<
+                pfn::GetFaceExpressionWeightsFB,
+                pfn::VoidFunction,
+            >(get_face_expression_weights_fb),
+            b"xrCreateBodyTrackerFB" => {
+                mem::transmute::<pfn::CreateBodyTrackerFB, pfn::VoidFunction>(
+                    create_body_tracker_fb,
+                )
+            }
+            b"xrDestroyBodyTrackerFB" => mem::transmute::<
+                pfn::DestroyBodyTrackerFB,
+                pfn::VoidFunction,
+            >(destroy_body_tracker_fb),
+            b"xrLocateBodyJointsFB" => {
+                mem::transmute::<pfn::LocateBodyJointsFB, pfn::VoidFunction>(locate_body_joints_fb)
+            }
+            b"xrGetBodySkeletonFB" => {
+                mem::transmute::<pfn::GetBodySkeletonFB, pfn::VoidFunction>(get_body_skeleton_fb)
+            }
+            b"xrCreateEyeTrackerFB" => {
+                mem::transmute::<pfn::CreateEyeTrackerFB, pfn::VoidFunction>(create_eye_tracker_fb)
+            }
+            b"xrDestroyEyeTrackerFB" => {
+                mem::transmute::<pfn::DestroyEyeTrackerFB, pfn::VoidFunction>(
+                    destroy_eye_tracker_fb,
+                )
+            }
+            b"xrGetEyeGazesFB" => {
+                mem::transmute::<pfn::GetEyeGazesFB, pfn::VoidFunction>(get_eye_gazes_fb)
+            }
+            b"xrCreateHandMeshSpaceMSFT" => mem::transmute::<
+                pfn::CreateHandMeshSpaceMSFT,
+                pfn::VoidFunction,
+            >(create_hand_mesh_space_msft),
+            b"xrUpdateHandMeshMSFT" => {
+                mem::transmute::<pfn::UpdateHandMeshMSFT, pfn::VoidFunction>(update_hand_mesh_msft)
+            }
+            b"xrGetControllerModelKeyMSFT" => mem::transmute::<
+                pfn::GetControllerModelKeyMSFT,
+                pfn::VoidFunction,
+            >(get_controller_model_key_msft),
+            b"xrLoadControllerModelMSFT" => mem::transmute::<
+                pfn::LoadControllerModelMSFT,
+                pfn::VoidFunction,
+            >(load_controller_model_msft),
+            b"xrGetControllerModelPropertiesMSFT" => {
+                mem::transmute::<pfn::GetControllerModelPropertiesMSFT, pfn::VoidFunction>(
+                    get_controller_model_properties_msft,
+                )
+            }
+            b"xrGetControllerModelStateMSFT" => mem::transmute::<
+                pfn::GetControllerModelStateMSFT,
+                pfn::VoidFunction,
+            >(get_controller_model_state_msft),
+            b"xrEnumerateDisplayRefreshRatesFB" => mem::transmute::<
+                pfn::EnumerateDisplayRefreshRatesFB,
+                pfn::VoidFunction,
+            >(enumerate_display_refresh_rates_fb),
+            b"xrGetDisplayRefreshRateFB" => mem::transmute::<
+                pfn::GetDisplayRefreshRateFB,
+                pfn::VoidFunction,
+            >(get_display_refresh_rate_fb),
+            b"xrRequestDisplayRefreshRateFB" => mem::transmute::<
+                pfn::RequestDisplayRefreshRateFB,
+                pfn::VoidFunction,
+            >(request_display_refresh_rate_fb),
+            #[cfg(windows)]
+            b"xrCreateSpatialAnchorFromPerceptionAnchorMSFT" => {
+                mem::transmute::<pfn::CreateSpatialAnchorFromPerceptionAnchorMSFT, pfn::VoidFunction>(
+                    create_spatial_anchor_from_perception_anchor_msft,
+                )
+            }
+            #[cfg(windows)]
+            b"xrTryGetPerceptionAnchorFromSpatialAnchorMSFT" => {
+                mem::transmute::<pfn::TryGetPerceptionAnchorFromSpatialAnchorMSFT, pfn::VoidFunction>(
+                    try_get_perception_anchor_from_spatial_anchor_msft,
+                )
+            }
+            b"xrUpdateSwapchainFB" => {
+                mem::transmute::<pfn::UpdateSwapchainFB, pfn::VoidFunction>(update_swapchain_fb)
+            }
+            b"xrGetSwapchainStateFB" => {
+                mem::transmute::<pfn::GetSwapchainStateFB, pfn::VoidFunction>(
+                    get_swapchain_state_fb,
+                )
+            }
+            b"xrEnumerateColorSpacesFB" => mem::transmute::<
+                pfn::EnumerateColorSpacesFB,
+                pfn::VoidFunction,
+            >(enumerate_color_spaces_fb),
+            b"xrSetColorSpaceFB" => {
+                mem::transmute::<pfn::SetColorSpaceFB, pfn::VoidFunction>(set_color_space_fb)
+            }
+            b"xrCreateFoveationProfileFB" => mem::transmute::<
+                pfn::CreateFoveationProfileFB,
+                pfn::VoidFunction,
+            >(create_foveation_profile_fb),
+            b"xrDestroyFoveationProfileFB" => mem::transmute::<
+                pfn::DestroyFoveationProfileFB,
+                pfn::VoidFunction,
+            >(destroy_foveation_profile_fb),
+            b"xrGetFoveationEyeTrackedStateMETA" => {
+                mem::transmute::<pfn::GetFoveationEyeTrackedStateMETA, pfn::VoidFunction>(
+                    get_foveation_eye_tracked_state_meta,
+                )
+            }
+            b"xrGetHandMeshFB" => {
+                mem::transmute::<pfn::GetHandMeshFB, pfn::VoidFunction>(get_hand_mesh_fb)
+            }
+            b"xrEnumerateRenderModelPathsFB" => mem::transmute::<
+                pfn::EnumerateRenderModelPathsFB,
+                pfn::VoidFunction,
+            >(enumerate_render_model_paths_fb),
+            b"xrGetRenderModelPropertiesFB" => mem::transmute::<
+                pfn::GetRenderModelPropertiesFB,
+                pfn::VoidFunction,
+            >(get_render_model_properties_fb),
+            b"xrLoadRenderModelFB" => {
+                mem::transmute::<pfn::LoadRenderModelFB, pfn::VoidFunction>(load_render_model_fb)
+            }
+            b"xrQuerySystemTrackedKeyboardFB" => mem::transmute::<
+                pfn::QuerySystemTrackedKeyboardFB,
+                pfn::VoidFunction,
+            >(query_system_tracked_keyboard_fb),
+            b"xrCreateKeyboardSpaceFB" => mem::transmute::<
+                pfn::CreateKeyboardSpaceFB,
+                pfn::VoidFunction,
+            >(create_keyboard_space_fb),
+            b"xrSetEnvironmentDepthEstimationVARJO" => {
+                mem::transmute::<pfn::SetEnvironmentDepthEstimationVARJO, pfn::VoidFunction>(
+                    set_environment_depth_estimation_varjo,
+                )
+            }
+            b"xrEnumerateReprojectionModesMSFT" => mem::transmute::<
+                pfn::EnumerateReprojectionModesMSFT,
+                pfn::VoidFunction,
+            >(enumerate_reprojection_modes_msft),
+            b"xrGetAudioOutputDeviceGuidOculus" => {
+                mem::transmute::<pfn::GetAudioOutputDeviceGuidOculus, pfn::VoidFunction>(
+                    get_audio_output_device_guid_oculus,
+                )
+            }
+            b"xrGetAudioInputDeviceGuidOculus" => mem::transmute::<
+                pfn::GetAudioInputDeviceGuidOculus,
+                pfn::VoidFunction,
+            >(get_audio_input_device_guid_oculus),
+            b"xrCreateSpatialAnchorFB" => mem::transmute::<
+                pfn::CreateSpatialAnchorFB,
+                pfn::VoidFunction,
+            >(create_spatial_anchor_fb),
+            b"xrGetSpaceUuidFB" => {
+                mem::transmute::<pfn::GetSpaceUuidFB, pfn::VoidFunction>(get_space_uuid_fb)
+            }
+            b"xrEnumerateSpaceSupportedComponentsFB" => {
+                mem::transmute::<pfn::EnumerateSpaceSupportedComponentsFB, pfn::VoidFunction>(
+                    enumerate_space_supported_components_fb,
+                )
+            }
+            b"xrSetSpaceComponentStatusFB" => mem::transmute::<
+                pfn::SetSpaceComponentStatusFB,
+                pfn::VoidFunction,
+            >(set_space_component_status_fb),
+            b"xrGetSpaceComponentStatusFB" => mem::transmute::<
+                pfn::GetSpaceComponentStatusFB,
+                pfn::VoidFunction,
+            >(get_space_component_status_fb),
+            b"xrCreateTriangleMeshFB" => mem::transmute::<
+                pfn::CreateTriangleMeshFB,
+                pfn::VoidFunction,
+            >(create_triangle_mesh_fb),
+            b"xrDestroyTriangleMeshFB" => mem::transmute::<
+                pfn::DestroyTriangleMeshFB,
+                pfn::VoidFunction,
+            >(destroy_triangle_mesh_fb),
+            b"xrTriangleMeshGetVertexBufferFB" => mem::transmute::<
+                pfn::TriangleMeshGetVertexBufferFB,
+                pfn::VoidFunction,
+            >(triangle_mesh_get_vertex_buffer_fb),
+            b"xrTriangleMeshGetIndexBufferFB" => mem::transmute::<
+                pfn::TriangleMeshGetIndexBufferFB,
+                pfn::VoidFunction,
+            >(triangle_mesh_get_index_buffer_fb),
+            b"xrTriangleMeshBeginUpdateFB" => mem::transmute::<
+                pfn::TriangleMeshBeginUpdateFB,
+                pfn::VoidFunction,
+            >(triangle_mesh_begin_update_fb),
+            b"xrTriangleMeshEndUpdateFB" => mem::transmute::<
+                pfn::TriangleMeshEndUpdateFB,
+                pfn::VoidFunction,
+            >(triangle_mesh_end_update_fb),
+            b"xrTriangleMeshBeginVertexBufferUpdateFB" => {
+                mem::transmute::<pfn::TriangleMeshBeginVertexBufferUpdateFB, pfn::VoidFunction>(
+                    triangle_mesh_begin_vertex_buffer_update_fb,
+                )
+            }
+            b"xrTriangleMeshEndVertexBufferUpdateFB" => {
+                mem::transmute::<pfn::TriangleMeshEndVertexBufferUpdateFB, pfn::VoidFunction>(
+                    triangle_mesh_end_vertex_buffer_update_fb,
+                )
+            }
+            b"xrCreatePassthroughFB" => {
+                mem::transmute::<pfn::CreatePassthroughFB, pfn::VoidFunction>(create_passthrough_fb)
+            }
+            b"xrDestroyPassthroughFB" => mem::transmute::<
+                pfn::DestroyPassthroughFB,
+                pfn::VoidFunction,
+            >(destroy_passthrough_fb),
+            b"xrPassthroughStartFB" => {
+                mem::transmute::<pfn::PassthroughStartFB, pfn::VoidFunction>(passthrough_start_fb)
+            }
+            b"xrPassthroughPauseFB" => {
+                mem::transmute::<pfn::PassthroughPauseFB, pfn::VoidFunction>(passthrough_pause_fb)
+            }
+            b"xrCreatePassthroughLayerFB" => mem::transmute::<
+                pfn::CreatePassthroughLayerFB,
+                pfn::VoidFunction,
+            >(create_passthrough_layer_fb),
+            b"xrDestroyPassthroughLayerFB" => mem::transmute::<
+                pfn::DestroyPassthroughLayerFB,
+                pfn::VoidFunction,
+            >(destroy_passthrough_layer_fb),
+            b"xrPassthroughLayerPauseFB" => mem::transmute::<
+                pfn::PassthroughLayerPauseFB,
+                pfn::VoidFunction,
+            >(passthrough_layer_pause_fb),
+            b"xrPassthroughLayerResumeFB" => mem::transmute::<
+                pfn::PassthroughLayerResumeFB,
+                pfn::VoidFunction,
+            >(passthrough_layer_resume_fb),
+            b"xrPassthroughLayerSetStyleFB" => mem::transmute::<
+                pfn::PassthroughLayerSetStyleFB,
+                pfn::VoidFunction,
+            >(passthrough_layer_set_style_fb),
+            b"xrCreateGeometryInstanceFB" => mem::transmute::<
+                pfn::CreateGeometryInstanceFB,
+                pfn::VoidFunction,
+            >(create_geometry_instance_fb),
+            b"xrDestroyGeometryInstanceFB" => mem::transmute::<
+                pfn::DestroyGeometryInstanceFB,
+                pfn::VoidFunction,
+            >(destroy_geometry_instance_fb),
+            b"xrGeometryInstanceSetTransformFB" => mem::transmute::<
+                pfn::GeometryInstanceSetTransformFB,
+                pfn::VoidFunction,
+            >(geometry_instance_set_transform_fb),
+            b"xrQuerySpacesFB" => {
+                mem::transmute::<pfn::QuerySpacesFB, pfn::VoidFunction>(query_spaces_fb)
+            }
+            b"xrRetrieveSpaceQueryResultsFB" => mem::transmute::<
+                pfn::RetrieveSpaceQueryResultsFB,
+                pfn::VoidFunction,
+            >(retrieve_space_query_results_fb),
+            b"xrSaveSpaceFB" => {
+                mem::transmute::<pfn::SaveSpaceFB, pfn::VoidFunction>(save_space_fb)
+            }
+            b"xrEraseSpaceFB" => {
+                mem::transmute::<pfn::EraseSpaceFB, pfn::VoidFunction>(erase_space_fb)
+            }
+            b"xrSaveSpaceListFB" => {
+                mem::transmute::<pfn::SaveSpaceListFB, pfn::VoidFunction>(save_space_list_fb)
+            }
+            b"xrShareSpacesFB" => {
+                mem::transmute::<pfn::ShareSpacesFB, pfn::VoidFunction>(share_spaces_fb)
+            }
+            b"xrGetSpaceContainerFB" => {
+                mem::transmute::<pfn::GetSpaceContainerFB, pfn::VoidFunction>(
+                    get_space_container_fb,
+                )
+            }
+            b"xrGetSpaceBoundingBox2DFB" => mem::transmute::<
+                pfn::GetSpaceBoundingBox2DFB,
+                pfn::VoidFunction,
+            >(get_space_bounding_box2_dfb),
+            b"xrGetSpaceBoundingBox3DFB" => mem::transmute::<
+                pfn::GetSpaceBoundingBox3DFB,
+                pfn::VoidFunction,
+            >(get_space_bounding_box3_dfb),
+            b"xrGetSpaceSemanticLabelsFB" => mem::transmute::<
+                pfn::GetSpaceSemanticLabelsFB,
+                pfn::VoidFunction,
+            >(get_space_semantic_labels_fb),
+            b"xrGetSpaceBoundary2DFB" => mem::transmute::<
+                pfn::GetSpaceBoundary2DFB,
+                pfn::VoidFunction,
+            >(get_space_boundary2_dfb),
+            b"xrGetSpaceRoomLayoutFB" => mem::transmute::<
+                pfn::GetSpaceRoomLayoutFB,
+                pfn::VoidFunction,
+            >(get_space_room_layout_fb),
+            b"xrRequestSceneCaptureFB" => mem::transmute::<
+                pfn::RequestSceneCaptureFB,
+                pfn::VoidFunction,
+            >(request_scene_capture_fb),
+            b"xrPassthroughLayerSetKeyboardHandsIntensityFB" => {
+                mem::transmute::<pfn::PassthroughLayerSetKeyboardHandsIntensityFB, pfn::VoidFunction>(
+                    passthrough_layer_set_keyboard_hands_intensity_fb,
+                )
+            }
+            b"xrCreateSpatialAnchorStoreConnectionMSFT" => {
+                mem::transmute::<pfn::CreateSpatialAnchorStoreConnectionMSFT, pfn::VoidFunction>(
+                    create_spatial_anchor_store_connection_msft,
+                )
+            }
+            b"xrDestroySpatialAnchorStoreConnectionMSFT" => {
+                mem::transmute::<pfn::DestroySpatialAnchorStoreConnectionMSFT, pfn::VoidFunction>(
+                    destroy_spatial_anchor_store_connection_msft,
+                )
+            }
+            b"xrPersistSpatialAnchorMSFT" => mem::transmute::<
+                pfn::PersistSpatialAnchorMSFT,
+                pfn::VoidFunction,
+            >(persist_spatial_anchor_msft),
+            b"xrEnumeratePersistedSpatialAnchorNamesMSFT" => {
+                mem::transmute::<pfn::EnumeratePersistedSpatialAnchorNamesMSFT, pfn::VoidFunction>(
+                    enumerate_persisted_spatial_anchor_names_msft,
+                )
+            }
+            b"xrCreateSpatialAnchorFromPersistedNameMSFT" => {
+                mem::transmute::<pfn::CreateSpatialAnchorFromPersistedNameMSFT, pfn::VoidFunction>(
+                    create_spatial_anchor_from_persisted_name_msft,
+                )
+            }
+            b"xrUnpersistSpatialAnchorMSFT" => mem::transmute::<
+                pfn::UnpersistSpatialAnchorMSFT,
+                pfn::VoidFunction,
+            >(unpersist_spatial_anchor_msft),
+            b"xrClearSpatialAnchorStoreMSFT" => mem::transmute::<
+                pfn::ClearSpatialAnchorStoreMSFT,
+                pfn::VoidFunction,
+            >(clear_spatial_anchor_store_msft),
+            b"xrCreateFacialTrackerHTC" => mem::transmute::<
+                pfn::CreateFacialTrackerHTC,
+                pfn::VoidFunction,
+            >(create_facial_tracker_htc),
+            b"xrDestroyFacialTrackerHTC" => mem::transmute::<
+                pfn::DestroyFacialTrackerHTC,
+                pfn::VoidFunction,
+            >(destroy_facial_tracker_htc),
+            b"xrGetFacialExpressionsHTC" => mem::transmute::<
+                pfn::GetFacialExpressionsHTC,
+                pfn::VoidFunction,
+            >(get_facial_expressions_htc),
+            b"xrCreatePassthroughHTC" => mem::transmute::<
+                pfn::CreatePassthroughHTC,
+                pfn::VoidFunction,
+            >(create_passthrough_htc),
+            b"xrDestroyPassthroughHTC" => mem::transmute::<
+                pfn::DestroyPassthroughHTC,
+                pfn::VoidFunction,
+            >(destroy_passthrough_htc),
+            b"xrEnumerateViveTrackerPathsHTCX" => mem::transmute::<
+                pfn::EnumerateViveTrackerPathsHTCX,
+                pfn::VoidFunction,
+            >(enumerate_vive_tracker_paths_htcx),
+            b"xrSetMarkerTrackingVARJO" => mem::transmute::<
+                pfn::SetMarkerTrackingVARJO,
+                pfn::VoidFunction,
+            >(set_marker_tracking_varjo),
+            b"xrSetMarkerTrackingTimeoutVARJO" => mem::transmute::<
+                pfn::SetMarkerTrackingTimeoutVARJO,
+                pfn::VoidFunction,
+            >(set_marker_tracking_timeout_varjo),
+            b"xrSetMarkerTrackingPredictionVARJO" => {
+                mem::transmute::<pfn::SetMarkerTrackingPredictionVARJO, pfn::VoidFunction>(
+                    set_marker_tracking_prediction_varjo,
+                )
+            }
+            b"xrGetMarkerSizeVARJO" => {
+                mem::transmute::<pfn::GetMarkerSizeVARJO, pfn::VoidFunction>(get_marker_size_varjo)
+            }
+            b"xrCreateMarkerSpaceVARJO" => mem::transmute::<
+                pfn::CreateMarkerSpaceVARJO,
+                pfn::VoidFunction,
+            >(create_marker_space_varjo),
+            b"xrSetDigitalLensControlALMALENCE" => mem::transmute::<
+                pfn::SetDigitalLensControlALMALENCE,
+                pfn::VoidFunction,
+            >(set_digital_lens_control_almalence),
+            b"xrSetViewOffsetVARJO" => {
+                mem::transmute::<pfn::SetViewOffsetVARJO, pfn::VoidFunction>(set_view_offset_varjo)
+            }
+            b"xrEnumerateExternalCamerasOCULUS" => mem::transmute::<
+                pfn::EnumerateExternalCamerasOCULUS,
+                pfn::VoidFunction,
+            >(enumerate_external_cameras_oculus),
+            b"xrCreatePassthroughColorLutMETA" => mem::transmute::<
+                pfn::CreatePassthroughColorLutMETA,
+                pfn::VoidFunction,
+            >(create_passthrough_color_lut_meta),
+            b"xrDestroyPassthroughColorLutMETA" => mem::transmute::<
+                pfn::DestroyPassthroughColorLutMETA,
+                pfn::VoidFunction,
+            >(destroy_passthrough_color_lut_meta),
+            b"xrUpdatePassthroughColorLutMETA" => mem::transmute::<
+                pfn::UpdatePassthroughColorLutMETA,
+                pfn::VoidFunction,
+            >(update_passthrough_color_lut_meta),
+            b"xrEnumeratePerformanceMetricsCounterPathsMETA" => {
+                mem::transmute::<pfn::EnumeratePerformanceMetricsCounterPathsMETA, pfn::VoidFunction>(
+                    enumerate_performance_metrics_counter_paths_meta,
+                )
+            }
+            b"xrSetPerformanceMetricsStateMETA" => mem::transmute::<
+                pfn::SetPerformanceMetricsStateMETA,
+                pfn::VoidFunction,
+            >(set_performance_metrics_state_meta),
+            b"xrGetPerformanceMetricsStateMETA" => mem::transmute::<
+                pfn::GetPerformanceMetricsStateMETA,
+                pfn::VoidFunction,
+            >(get_performance_metrics_state_meta),
+            b"xrQueryPerformanceMetricsCounterMETA" => {
+                mem::transmute::<pfn::QueryPerformanceMetricsCounterMETA, pfn::VoidFunction>(
+                    query_performance_metrics_counter_meta,
+                )
+            }
+            b"xrGetPassthroughPreferencesMETA" => mem::transmute::<
+                pfn::GetPassthroughPreferencesMETA,
+                pfn::VoidFunction,
+            >(get_passthrough_preferences_meta),
+            b"xrApplyFoveationHTC" => {
+                mem::transmute::<pfn::ApplyFoveationHTC, pfn::VoidFunction>(apply_foveation_htc)
+            }
+            b"xrCreateSpaceFromCoordinateFrameUIDML" => {
+                mem::transmute::<pfn::CreateSpaceFromCoordinateFrameUIDML, pfn::VoidFunction>(
+                    create_space_from_coordinate_frame_uidml,
+                )
+            }
+            b"xrGetDeviceSampleRateFB" => mem::transmute::<
+                pfn::GetDeviceSampleRateFB,
+                pfn::VoidFunction,
+            >(get_device_sample_rate_fb),
+            b"xrSetTrackingOptimizationSettingsHintQCOM" => {
+                mem::transmute::<pfn::SetTrackingOptimizationSettingsHintQCOM, pfn::VoidFunction>(
+                    set_tracking_optimization_settings_hint_qcom,
+                )
+            }
+            b"xrCreateSpaceUserFB" => {
+                mem::transmute::<pfn::CreateSpaceUserFB, pfn::VoidFunction>(create_space_user_fb)
+            }
+            b"xrGetSpaceUserIdFB" => {
+                mem::transmute::<pfn::GetSpaceUserIdFB, pfn::VoidFunction>(get_space_user_id_fb)
+            }
+            b"xrDestroySpaceUserFB" => {
+                mem::transmute::<pfn::DestroySpaceUserFB, pfn::VoidFunction>(destroy_space_user_fb)
+            }
+            b"xrApplyForceFeedbackCurlMNDX" => mem::transmute::<
+                pfn::ApplyForceFeedbackCurlMNDX,
+                pfn::VoidFunction,
+            >(apply_force_feedback_curl_mndx),
+            b"xrCreatePlaneDetectorEXT" => mem::transmute::<
+                pfn::CreatePlaneDetectorEXT,
+                pfn::VoidFunction,
+            >(create_plane_detector_ext),
+            b"xrDestroyPlaneDetectorEXT" => mem::transmute::<
+                pfn::DestroyPlaneDetectorEXT,
+                pfn::VoidFunction,
+            >(destroy_plane_detector_ext),
+            b"xrBeginPlaneDetectionEXT" => mem::transmute::<
+                pfn::BeginPlaneDetectionEXT,
+                pfn::VoidFunction,
+            >(begin_plane_detection_ext),
+            b"xrGetPlaneDetectionStateEXT" => mem::transmute::<
+                pfn::GetPlaneDetectionStateEXT,
+                pfn::VoidFunction,
+            >(get_plane_detection_state_ext),
+            b"xrGetPlaneDetectionsEXT" => mem::transmute::<
+                pfn::GetPlaneDetectionsEXT,
+                pfn::VoidFunction,
+            >(get_plane_detections_ext),
+            b"xrGetPlanePolygonBufferEXT" => mem::transmute::<
+                pfn::GetPlanePolygonBufferEXT,
+                pfn::VoidFunction,
+            >(get_plane_polygon_buffer_ext),
+            b"xrCreateVirtualKeyboardMETA" => mem::transmute::<
+                pfn::CreateVirtualKeyboardMETA,
+                pfn::VoidFunction,
+            >(create_virtual_keyboard_meta),
+            b"xrDestroyVirtualKeyboardMETA" => mem::transmute::<
+                pfn::DestroyVirtualKeyboardMETA,
+                pfn::VoidFunction,
+            >(destroy_virtual_keyboard_meta),
+            b"xrCreateVirtualKeyboardSpaceMETA" => mem::transmute::<
+                pfn::CreateVirtualKeyboardSpaceMETA,
+                pfn::VoidFunction,
+            >(create_virtual_keyboard_space_meta),
+            b"xrSuggestVirtualKeyboardLocationMETA" => {
+                mem::transmute::<pfn::SuggestVirtualKeyboardLocationMETA, pfn::VoidFunction>(
+                    suggest_virtual_keyboard_location_meta,
+                )
+            }
+            b"xrGetVirtualKeyboardScaleMETA" => mem::transmute::<
+                pfn::GetVirtualKeyboardScaleMETA,
+                pfn::VoidFunction,
+            >(get_virtual_keyboard_scale_meta),
+            b"xrSetVirtualKeyboardModelVisibilityMETA" => {
+                mem::transmute::<pfn::SetVirtualKeyboardModelVisibilityMETA, pfn::VoidFunction>(
+                    set_virtual_keyboard_model_visibility_meta,
+                )
+            }
+            b"xrGetVirtualKeyboardModelAnimationStatesMETA" => {
+                mem::transmute::<pfn::GetVirtualKeyboardModelAnimationStatesMETA, pfn::VoidFunction>(
+                    get_virtual_keyboard_model_animation_states_meta,
+                )
+            }
+            b"xrGetVirtualKeyboardDirtyTexturesMETA" => {
+                mem::transmute::<pfn::GetVirtualKeyboardDirtyTexturesMETA, pfn::VoidFunction>(
+                    get_virtual_keyboard_dirty_textures_meta,
+                )
+            }
+            b"xrGetVirtualKeyboardTextureDataMETA" => {
+                mem::transmute::<pfn::GetVirtualKeyboardTextureDataMETA, pfn::VoidFunction>(
+                    get_virtual_keyboard_texture_data_meta,
+                )
+            }
+            b"xrSendVirtualKeyboardInputMETA" => mem::transmute::<
+                pfn::SendVirtualKeyboardInputMETA,
+                pfn::VoidFunction,
+            >(send_virtual_keyboard_input_meta),
+            b"xrChangeVirtualKeyboardTextContextMETA" => {
+                mem::transmute::<pfn::ChangeVirtualKeyboardTextContextMETA, pfn::VoidFunction>(
+                    change_virtual_keyboard_text_context_meta,
+                )
+            }
+            b"xrGetVulkanGraphicsRequirements2KHR" => {
+                mem::transmute::<pfn::GetVulkanGraphicsRequirements2KHR, pfn::VoidFunction>(
+                    get_vulkan_graphics_requirements2_khr,
+                )
+            }
+            _ => return None,
+        })
+    }
+}
+
+#[doc = r" Size of the structure identified by `ty`"]
+pub(super) fn struct_size(ty: StructureType) -> Option<usize> {
+    Some(match ty {
+        StructureType::API_LAYER_PROPERTIES => mem::size_of::<ApiLayerProperties>(),
+        StructureType::EXTENSION_PROPERTIES => mem::size_of::<ExtensionProperties>(),
+        StructureType::INSTANCE_CREATE_INFO => mem::size_of::<InstanceCreateInfo>(),
+        StructureType::INSTANCE_PROPERTIES => mem::size_of::<InstanceProperties>(),
+        StructureType::SYSTEM_GET_INFO => mem::size_of::<SystemGetInfo>(),
+        StructureType::SYSTEM_PROPERTIES => mem::size_of::<SystemProperties>(),
+        #[cfg(windows)]
+        StructureType::GRAPHICS_BINDING_OPENGL_WIN32_KHR => {
+            mem::size_of::<GraphicsBindingOpenGLWin32KHR>()
+        }
+        StructureType::GRAPHICS_BINDING_OPENGL_XLIB_KHR => {
+            mem::size_of::<GraphicsBindingOpenGLXlibKHR>()
+        }
+        StructureType::GRAPHICS_BINDING_OPENGL_XCB_KHR => {
+            mem::size_of::<GraphicsBindingOpenGLXcbKHR>()
+        }
+        StructureType::GRAPHICS_BINDING_OPENGL_WAYLAND_KHR => {
+            mem::size_of::<GraphicsBindingOpenGLWaylandKHR>()
+        }
+        #[cfg(windows)]
+        StructureType::GRAPHICS_BINDING_D3D11_KHR => mem::size_of::<GraphicsBindingD3D11KHR>(),
+        #[cfg(windows)]
+        StructureType::GRAPHICS_BINDING_D3D12_KHR => mem::size_of::<GraphicsBindingD3D12KHR>(),
+        #[cfg(target_os = "android")]
+        StructureType::GRAPHICS_BINDING_OPENGL_ES_ANDROID_KHR => {
+            mem::size_of::<GraphicsBindingOpenGLESAndroidKHR>()
+        }
+        StructureType::GRAPHICS_BINDING_VULKAN_KHR => mem::size_of::<GraphicsBindingVulkanKHR>(),
+        StructureType::SESSION_CREATE_INFO => mem::size_of::<SessionCreateInfo>(),
+        StructureType::SESSION_BEGIN_INFO => mem::size_of::<SessionBeginInfo>(),
+        StructureType::SWAPCHAIN_CREATE_INFO => mem::size_of::<SwapchainCreateInfo>(),
+        StructureType::SWAPCHAIN_IMAGE_OPENGL_KHR => mem::size_of::<SwapchainImageOpenGLKHR>(),
+        StructureType::SWAPCHAIN_IMAGE_OPENGL_ES_KHR => mem::size_of::<SwapchainImageOpenGLESKHR>(),
+        StructureType::SWAPCHAIN_IMAGE_VULKAN_KHR => mem::size_of::<SwapchainImageVulkanKHR>(),
+        #[cfg(windows)]
+        StructureType::SWAPCHAIN_IMAGE_D3D11_KHR => mem::size_of::<SwapchainImageD3D11KHR>(),
+        #[cfg(windows)]
+        StructureType::SWAPCHAIN_IMAGE_D3D12_KHR => mem::size_of::<SwapchainImageD3D12KHR>(),
+        StructureType::SWAPCHAIN_IMAGE_ACQUIRE_INFO => mem::size_of::<SwapchainImageAcquireInfo>(),
+        StructureType::SWAPCHAIN_IMAGE_WAIT_INFO => mem::size_of::<SwapchainImageWaitInfo>(),
+        StructureType::SWAPCHAIN_IMAGE_RELEASE_INFO => mem::size_of::<SwapchainImageReleaseInfo>(),
+        StructureType::REFERENCE_SPACE_CREATE_INFO => mem::size_of::<ReferenceSpaceCreateInfo>(),
+        StructureType::ACTION_SPACE_CREATE_INFO => mem::size_of::<ActionSpaceCreateInfo>(),
+        StructureType::SPACE_LOCATION => mem::size_of::<SpaceLocation>(),
+        StructureType::SPACE_VELOCITY => mem::size_of::<SpaceVelocity>(),
+        StructureType::SPACES_LOCATE_INFO => mem::size_of::<SpacesLocateInfo>(),
+        StructureType::SPACE_LOCATIONS => mem::size_of::<SpaceLocations>(),
+        StructureType::SPACE_VELOCITIES => mem::size_of::<SpaceVelocities>(),
+        StructureType::VIEW => mem::size_of::<View>(),
+        StructureType::VIEW_LOCATE_INFO => mem::size_of::<ViewLocateInfo>(),
+        StructureType::VIEW_STATE => mem::size_of::<ViewState>(),
+        StructureType::VIEW_CONFIGURATION_VIEW => mem::size_of::<ViewConfigurationView>(),
+        StructureType::COMPOSITION_LAYER_PROJECTION_VIEW => {
+            mem::size_of::<CompositionLayerProjectionView>()
+        }
+        StructureType::COMPOSITION_LAYER_PROJECTION => mem::size_of::<CompositionLayerProjection>(),
+        StructureType::COMPOSITION_LAYER_QUAD => mem::size_of::<CompositionLayerQuad>(),
+        StructureType::COMPOSITION_LAYER_CYLINDER_KHR => {
+            mem::size_of::<CompositionLayerCylinderKHR>()
+        }
+        StructureType::COMPOSITION_LAYER_CUBE_KHR => mem::size_of::<CompositionLayerCubeKHR>(),
+        StructureType::COMPOSITION_LAYER_EQUIRECT_KHR => {
+            mem::size_of::<CompositionLayerEquirectKHR>()
+        }
+        StructureType::COMPOSITION_LAYER_DEPTH_INFO_KHR => {
+            mem::size_of::<CompositionLayerDepthInfoKHR>()
+        }
+        StructureType::FRAME_BEGIN_INFO => mem::size_of::<FrameBeginInfo>(),
+        StructureType::FRAME_END_INFO => mem::size_of::<FrameEndInfo>(),
+        StructureType::FRAME_WAIT_INFO => mem::size_of::<FrameWaitInfo>(),
+        StructureType::FRAME_STATE => mem::size_of::<FrameState>(),
+        StructureType::HAPTIC_VIBRATION => mem::size_of::<HapticVibration>(),
+        StructureType::EVENT_DATA_BUFFER => mem::size_of::<EventDataBuffer>(),
+        StructureType::EVENT_DATA_EVENTS_LOST => mem::size_of::<EventDataEventsLost>(),
+        StructureType::EVENT_DATA_INSTANCE_LOSS_PENDING => {
+            mem::size_of::<EventDataInstanceLossPending>()
+        }
+        StructureType::EVENT_DATA_SESSION_STATE_CHANGED => {
+            mem::size_of::<EventDataSessionStateChanged>()
+        }
+        StructureType::EVENT_DATA_REFERENCE_SPACE_CHANGE_PENDING => {
+            mem::size_of::<EventDataReferenceSpaceChangePending>()
+        }
+        StructureType::EVENT_DATA_PERF_SETTINGS_EXT => mem::size_of::<EventDataPerfSettingsEXT>(),
+        StructureType::EVENT_DATA_VISIBILITY_MASK_CHANGED_KHR => {
+            mem::size_of::<EventDataVisibilityMaskChangedKHR>()
+        }
+        StructureType::VIEW_CONFIGURATION_PROPERTIES => {
+            mem::size_of::<ViewConfigurationProperties>()
+        }
+        StructureType::ACTION_STATE_BOOLEAN => mem::size_of::<ActionStateBoolean>(),
+        StructureType::ACTION_STATE_FLOAT => mem::size_of::<ActionStateFloat>(),
+        StructureType::ACTION_STATE_VECTOR2F => mem::size_of::<ActionStateVector2f>(),
+        StructureType::ACTION_STATE_POSE => mem::size_of::<ActionStatePose>(),
+        StructureType::ACTION_STATE_GET_INFO => mem::size_of::<ActionStateGetInfo>(),
+        StructureType::HAPTIC_ACTION_INFO => mem::size_of::<HapticActionInfo>(),
+        StructureType::ACTION_SET_CREATE_INFO => mem::size_of::<ActionSetCreateInfo>(),
+        StructureType::INTERACTION_PROFILE_SUGGESTED_BINDING => {
+            mem::size_of::<InteractionProfileSuggestedBinding>()
+        }
+        StructureType::SESSION_ACTION_SETS_ATTACH_INFO => {
+            mem::size_of::<SessionActionSetsAttachInfo>()
+        }
+        StructureType::ACTIONS_SYNC_INFO => mem::size_of::<ActionsSyncInfo>(),
+        StructureType::BOUND_SOURCES_FOR_ACTION_ENUMERATE_INFO => {
+            mem::size_of::<BoundSourcesForActionEnumerateInfo>()
+        }
+        StructureType::INPUT_SOURCE_LOCALIZED_NAME_GET_INFO => {
+            mem::size_of::<InputSourceLocalizedNameGetInfo>()
+        }
+        StructureType::EVENT_DATA_INTERACTION_PROFILE_CHANGED => {
+            mem::size_of::<EventDataInteractionProfileChanged>()
+        }
+        StructureType::INTERACTION_PROFILE_STATE => mem::size_of::<InteractionProfileState>(),
+        StructureType::ACTION_CREATE_INFO => mem::size_of::<ActionCreateInfo>(),
+        #[cfg(target_os = "android")]
+        StructureType::INSTANCE_CREATE_INFO_ANDROID_KHR => {
+            mem::size_of::<InstanceCreateInfoAndroidKHR>()
+        }
+        StructureType::VULKAN_SWAPCHAIN_FORMAT_LIST_CREATE_INFO_KHR => {
+            mem::size_of::<VulkanSwapchainFormatListCreateInfoKHR>()
+        }
+        StructureType::DEBUG_UTILS_OBJECT_NAME_INFO_EXT => {
+            mem::size_of::<DebugUtilsObjectNameInfoEXT>()
+        }
+        StructureType::DEBUG_UTILS_LABEL_EXT => mem::size_of::<DebugUtilsLabelEXT>(),
+        StructureType::DEBUG_UTILS_MESSENGER_CALLBACK_DATA_EXT => {
+            mem::size_of::<DebugUtilsMessengerCallbackDataEXT>()
+        }
+        StructureType::DEBUG_UTILS_MESSENGER_CREATE_INFO_EXT => {
+            mem::size_of::<DebugUtilsMessengerCreateInfoEXT>()
+        }
+        StructureType::VISIBILITY_MASK_KHR => mem::size_of::<VisibilityMaskKHR>(),
+        StructureType::GRAPHICS_REQUIREMENTS_OPENGL_KHR => {
+            mem::size_of::<GraphicsRequirementsOpenGLKHR>()
+        }
+        StructureType::GRAPHICS_REQUIREMENTS_OPENGL_ES_KHR => {
+            mem::size_of::<GraphicsRequirementsOpenGLESKHR>()
+        }
+        StructureType::GRAPHICS_REQUIREMENTS_VULKAN_KHR => {
+            mem::size_of::<GraphicsRequirementsVulkanKHR>()
+        }
+        #[cfg(windows)]
+        StructureType::GRAPHICS_REQUIREMENTS_D3D11_KHR => {
+            mem::size_of::<GraphicsRequirementsD3D11KHR>()
+        }
+        #[cfg(windows)]
+        StructureType::GRAPHICS_REQUIREMENTS_D3D12_KHR => {
+            mem::size_of::<GraphicsRequirementsD3D12KHR>()
+        }
+        StructureType::VULKAN_INSTANCE_CREATE_INFO_KHR => {
+            mem::size_of::<VulkanInstanceCreateInfoKHR>()
+        }
+        StructureType::VULKAN_DEVICE_CREATE_INFO_KHR => mem::size_of::<VulkanDeviceCreateInfoKHR>(),
+        StructureType::VULKAN_GRAPHICS_DEVICE_GET_INFO_KHR => {
+            mem::size_of::<VulkanGraphicsDeviceGetInfoKHR>()
+        }
+        StructureType::VULKAN_SWAPCHAIN_CREATE_INFO_META => {
+            mem::size_of::<VulkanSwapchainCreateInfoMETA>()
+        }
+        StructureType::SESSION_CREATE_INFO_OVERLAY_EXTX => {
+            mem::size_of::<SessionCreateInfoOverlayEXTX>()
+        }
+        StructureType::EVENT_DATA_MAIN_SESSION_VISIBILITY_CHANGED_EXTX => {
+            mem::size_of::<EventDataMainSessionVisibilityChangedEXTX>()
+        }
+        StructureType::EVENT_DATA_DISPLAY_REFRESH_RATE_CHANGED_FB => {
+            mem::size_of::<EventDataDisplayRefreshRateChangedFB>()
+        }
+        StructureType::VIEW_CONFIGURATION_DEPTH_RANGE_EXT => {
+            mem::size_of::<ViewConfigurationDepthRangeEXT>()
+        }
+        StructureType::VIEW_CONFIGURATION_VIEW_FOV_EPIC => {
+            mem::size_of::<ViewConfigurationViewFovEPIC>()
+        }
+        StructureType::INTERACTION_PROFILE_DPAD_BINDING_EXT => {
+            mem::size_of::<InteractionProfileDpadBindingEXT>()
+        }
+        StructureType::INTERACTION_PROFILE_ANALOG_THRESHOLD_VALVE => {
+            mem::size_of::<InteractionProfileAnalogThresholdVALVE>()
+        }
+        StructureType::BINDING_MODIFICATIONS_KHR => mem::size_of::<BindingModificationsKHR>(),
+        StructureType::SYSTEM_EYE_GAZE_INTERACTION_PROPERTIES_EXT => {
+            mem::size_of::<SystemEyeGazeInteractionPropertiesEXT>()
+        }
+        StructureType::EYE_GAZE_SAMPLE_TIME_EXT => mem::size_of::<EyeGazeSampleTimeEXT>(),
+        StructureType::SPATIAL_ANCHOR_CREATE_INFO_MSFT => {
+            mem::size_of::<SpatialAnchorCreateInfoMSFT>()
+        }
+        StructureType::SPATIAL_ANCHOR_SPACE_CREATE_INFO_MSFT => {
+            mem::size_of::<SpatialAnchorSpaceCreateInfoMSFT>()
+        }
+        StructureType::COMPOSITION_LAYER_IMAGE_LAYOUT_FB => {
+            mem::size_of::<CompositionLayerImageLayoutFB>()
+        }
+        StructureType::COMPOSITION_LAYER_ALPHA_BLEND_FB => {
+            mem::size_of::<CompositionLayerAlphaBlendFB>()
+        }
+        StructureType::GRAPHICS_BINDING_EGL_MNDX => mem::size_of::<GraphicsBindingEGLMNDX>(),
+        StructureType::SPATIAL_GRAPH_NODE_SPACE_CREATE_INFO_MSFT => {
+            mem::size_of::<SpatialGraphNodeSpaceCreateInfoMSFT>()
+        }
+        StructureType::SPATIAL_GRAPH_STATIC_NODE_BINDING_CREATE_INFO_MSFT => {
+            mem::size_of::<SpatialGraphStaticNodeBindingCreateInfoMSFT>()
+        }
+        StructureType::SPATIAL_GRAPH_NODE_BINDING_PROPERTIES_GET_INFO_MSFT => {
+            mem::size_of::<SpatialGraphNodeBindingPropertiesGetInfoMSFT>()
+        }
+        StructureType::SPATIAL_GRAPH_NODE_BINDING_PROPERTIES_MSFT => {
+            mem::size_of::<SpatialGraphNodeBindingPropertiesMSFT>()
+        }
+        StructureType::SYSTEM_HAND_TRACKING_PROPERTIES_EXT => {
+            mem::size_of::<SystemHandTrackingPropertiesEXT>()
+        }
+        StructureType::HAND_TRACKER_CREATE_INFO_EXT => mem::size_of::<HandTrackerCreateInfoEXT>(),
+        StructureType::HAND_JOINTS_LOCATE_INFO_EXT => mem::size_of::<HandJointsLocateInfoEXT>(),
+        StructureType::HAND_JOINT_LOCATIONS_EXT => mem::size_of::<HandJointLocationsEXT>(),
+        StructureType::HAND_JOINT_VELOCITIES_EXT => mem::size_of::<HandJointVelocitiesEXT>(),
+        StructureType::SYSTEM_FACE_TRACKING_PROPERTIES_FB => {
+            mem::size_of::<SystemFaceTrackingPropertiesFB>()
+        }
+        StructureType::FACE_TRACKER_CREATE_INFO_FB => mem::size_of::<FaceTrackerCreateInfoFB>(),
+        StructureType::FACE_EXPRESSION_INFO_FB => mem::size_of::<FaceExpressionInfoFB>(),
+        StructureType::FACE_EXPRESSION_WEIGHTS_FB => mem::size_of::<FaceExpressionWeightsFB>(),
+        StructureType::SYSTEM_BODY_TRACKING_PROPERTIES_FB => {
+            mem::size_of::<SystemBodyTrackingPropertiesFB>()
+        }
+        StructureType::BODY_TRACKER_CREATE_INFO_FB => mem::size_of::<BodyTrackerCreateInfoFB>(),
+        StructureType::BODY_SKELETON_FB => mem::size_of::<BodySkeletonFB>(),
+        StructureType::BODY_JOINTS_LOCATE_INFO_FB => mem::size_of::<BodyJointsLocateInfoFB>(),
+        StructureType::BODY_JOINT_LOCATIONS_FB => mem::size_of::<BodyJointLocationsFB>(),
+        StructureType::SYSTEM_EYE_TRACKING_PROPERTIES_FB => {
+            mem::size_of::<SystemEyeTrackingPropertiesFB>()
+        }
+        StructureType::EYE_TRACKER_CREATE_INFO_FB => mem::size_of::<EyeTrackerCreateInfoFB>(),
+        StructureType::EYE_GAZES_INFO_FB => mem::size_of::<EyeGazesInfoFB>(),
+        StructureType::EYE_GAZES_FB => mem::size_of::<EyeGazesFB>(),
+        StructureType::HAND_JOINTS_MOTION_RANGE_INFO_EXT => {
+            mem::size_of::<HandJointsMotionRangeInfoEXT>()
+        }
+        StructureType::HAND_TRACKING_DATA_SOURCE_INFO_EXT => {
+            mem::size_of::<HandTrackingDataSourceInfoEXT>()
+        }
+        StructureType::HAND_TRACKING_DATA_SOURCE_STATE_EXT => {
+            mem::size_of::<HandTrackingDataSourceStateEXT>()
+        }
+        StructureType::HAND_MESH_SPACE_CREATE_INFO_MSFT => {
+            mem::size_of::<HandMeshSpaceCreateInfoMSFT>()
+        }
+        StructureType::HAND_MESH_UPDATE_INFO_MSFT => mem::size_of::<HandMeshUpdateInfoMSFT>(),
+        StructureType::HAND_MESH_MSFT => mem::size_of::<HandMeshMSFT>(),
+        StructureType::SYSTEM_HAND_TRACKING_MESH_PROPERTIES_MSFT => {
+            mem::size_of::<SystemHandTrackingMeshPropertiesMSFT>()
+        }
+        StructureType::HAND_POSE_TYPE_INFO_MSFT => mem::size_of::<HandPoseTypeInfoMSFT>(),
+        StructureType::SECONDARY_VIEW_CONFIGURATION_SESSION_BEGIN_INFO_MSFT => {
+            mem::size_of::<SecondaryViewConfigurationSessionBeginInfoMSFT>()
+        }
+        StructureType::SECONDARY_VIEW_CONFIGURATION_STATE_MSFT => {
+            mem::size_of::<SecondaryViewConfigurationStateMSFT>()
+        }
+        StructureType::SECONDARY_VIEW_CONFIGURATION_FRAME_STATE_MSFT => {
+            mem::size_of::<SecondaryViewConfigurationFrameStateMSFT>()
+        }
+        StructureType::SECONDARY_VIEW_CONFIGURATION_FRAME_END_INFO_MSFT => {
+            mem::size_of::<SecondaryViewConfigurationFrameEndInfoMSFT>()
+        }
+        StructureType::SECONDARY_VIEW_CONFIGURATION_LAYER_INFO_MSFT => {
+            mem::size_of::<SecondaryViewConfigurationLayerInfoMSFT>()
+        }
+        StructureType::SECONDARY_VIEW_CONFIGURATION_SWAPCHAIN_CREATE_INFO_MSFT => {
+            mem::size_of::<SecondaryViewConfigurationSwapchainCreateInfoMSFT>()
+        }
+        #[cfg(windows)]
+        StructureType::HOLOGRAPHIC_WINDOW_ATTACHMENT_MSFT => {
+            mem::size_of::<HolographicWindowAttachmentMSFT>()
+        }
+        #[cfg(target_os = "android")]
+        StructureType::ANDROID_SURFACE_SWAPCHAIN_CREATE_INFO_FB => {
+            mem::size_of::<AndroidSurfaceSwapchainCreateInfoFB>()
+        }
+        #[cfg(target_os = "android")]
+        StructureType::SWAPCHAIN_STATE_ANDROID_SURFACE_DIMENSIONS_FB => {
+            mem::size_of::<SwapchainStateAndroidSurfaceDimensionsFB>()
+        }
+        StructureType::SWAPCHAIN_STATE_SAMPLER_OPENGL_ES_FB => {
+            mem::size_of::<SwapchainStateSamplerOpenGLESFB>()
+        }
+        StructureType::SWAPCHAIN_STATE_SAMPLER_VULKAN_FB => {
+            mem::size_of::<SwapchainStateSamplerVulkanFB>()
+        }
+        StructureType::COMPOSITION_LAYER_SECURE_CONTENT_FB => {
+            mem::size_of::<CompositionLayerSecureContentFB>()
+        }
+        #[cfg(target_os = "android")]
+        StructureType::LOADER_INIT_INFO_ANDROID_KHR => mem::size_of::<LoaderInitInfoAndroidKHR>(),
+        StructureType::COMPOSITION_LAYER_EQUIRECT2_KHR => {
+            mem::size_of::<CompositionLayerEquirect2KHR>()
+        }
+        StructureType::COMPOSITION_LAYER_COLOR_SCALE_BIAS_KHR => {
+            mem::size_of::<CompositionLayerColorScaleBiasKHR>()
+        }
+        StructureType::CONTROLLER_MODEL_KEY_STATE_MSFT => {
+            mem::size_of::<ControllerModelKeyStateMSFT>()
+        }
+        StructureType::CONTROLLER_MODEL_NODE_PROPERTIES_MSFT => {
+            mem::size_of::<ControllerModelNodePropertiesMSFT>()
+        }
+        StructureType::CONTROLLER_MODEL_PROPERTIES_MSFT => {
+            mem::size_of::<ControllerModelPropertiesMSFT>()
+        }
+        StructureType::CONTROLLER_MODEL_NODE_STATE_MSFT => {
+            mem::size_of::<ControllerModelNodeStateMSFT>()
+        }
+        StructureType::CONTROLLER_MODEL_STATE_MSFT => mem::size_of::<ControllerModelStateMSFT>(),
+        StructureType::SYSTEM_COLOR_SPACE_PROPERTIES_FB => {
+            mem::size_of::<SystemColorSpacePropertiesFB>()
+        }
+        StructureType::SYSTEM_SPATIAL_ENTITY_PROPERTIES_FB => {
+            mem::size_of::<SystemSpatialEntityPropertiesFB>()
+        }
+        StructureType::SPATIAL_ANCHOR_CREATE_INFO_FB => mem::size_of::<SpatialAnchorCreateInfoFB>(),
+        StructureType::SPACE_COMPONENT_STATUS_SET_INFO_FB => {
+            mem::size_of::<SpaceComponentStatusSetInfoFB>()
+        }
+        StructureType::SPACE_COMPONENT_STATUS_FB => mem::size_of::<SpaceComponentStatusFB>(),
+        StructureType::EVENT_DATA_SPATIAL_ANCHOR_CREATE_COMPLETE_FB => {
+            mem::size_of::<EventDataSpatialAnchorCreateCompleteFB>()
+        }
+        StructureType::EVENT_DATA_SPACE_SET_STATUS_COMPLETE_FB => {
+            mem::size_of::<EventDataSpaceSetStatusCompleteFB>()
+        }
+        StructureType::FOVEATION_PROFILE_CREATE_INFO_FB => {
+            mem::size_of::<FoveationProfileCreateInfoFB>()
+        }
+        StructureType::SWAPCHAIN_CREATE_INFO_FOVEATION_FB => {
+            mem::size_of::<SwapchainCreateInfoFoveationFB>()
+        }
+        StructureType::SWAPCHAIN_STATE_FOVEATION_FB => mem::size_of::<SwapchainStateFoveationFB>(),
+        StructureType::SWAPCHAIN_IMAGE_FOVEATION_VULKAN_FB => {
+            mem::size_of::<SwapchainImageFoveationVulkanFB>()
+        }
+        StructureType::FOVEATION_LEVEL_PROFILE_CREATE_INFO_FB => {
+            mem::size_of::<FoveationLevelProfileCreateInfoFB>()
+        }
+        StructureType::FOVEATION_EYE_TRACKED_PROFILE_CREATE_INFO_META => {
+            mem::size_of::<FoveationEyeTrackedProfileCreateInfoMETA>()
+        }
+        StructureType::FOVEATION_EYE_TRACKED_STATE_META => {
+            mem::size_of::<FoveationEyeTrackedStateMETA>()
+        }
+        StructureType::SYSTEM_FOVEATION_EYE_TRACKED_PROPERTIES_META => {
+            mem::size_of::<SystemFoveationEyeTrackedPropertiesMETA>()
+        }
+        StructureType::HAND_TRACKING_MESH_FB => mem::size_of::<HandTrackingMeshFB>(),
+        StructureType::HAND_TRACKING_SCALE_FB => mem::size_of::<HandTrackingScaleFB>(),
+        StructureType::HAND_TRACKING_AIM_STATE_FB => mem::size_of::<HandTrackingAimStateFB>(),
+        StructureType::HAND_TRACKING_CAPSULES_STATE_FB => {
+            mem::size_of::<HandTrackingCapsulesStateFB>()
+        }
+        StructureType::RENDER_MODEL_PATH_INFO_FB => mem::size_of::<RenderModelPathInfoFB>(),
+        StructureType::RENDER_MODEL_PROPERTIES_FB => mem::size_of::<RenderModelPropertiesFB>(),
+        StructureType::RENDER_MODEL_BUFFER_FB => mem::size_of::<RenderModelBufferFB>(),
+        StructureType::RENDER_MODEL_LOAD_INFO_FB => mem::size_of::<RenderModelLoadInfoFB>(),
+        StructureType::SYSTEM_RENDER_MODEL_PROPERTIES_FB => {
+            mem::size_of::<SystemRenderModelPropertiesFB>()
+        }
+        StructureType::RENDER_MODEL_CAPABILITIES_REQUEST_FB => {
+            mem::size_of::<RenderModelCapabilitiesRequestFB>()
+        }
+        StructureType::SPACE_QUERY_INFO_FB => mem::size_of::<SpaceQueryInfoFB>(),
+        StructureType::SPACE_STORAGE_LOCATION_FILTER_INFO_FB => {
+            mem::size_of::<SpaceStorageLocationFilterInfoFB>()
+        }
+        StructureType::SPACE_UUID_FILTER_INFO_FB => mem::size_of::<SpaceUuidFilterInfoFB>(),
+        StructureType::SPACE_COMPONENT_FILTER_INFO_FB => {
+            mem::size_of::<SpaceComponentFilterInfoFB>()
+        }
+        StructureType::SPACE_QUERY_RESULTS_FB => mem::size_of::<SpaceQueryResultsFB>(),
+        StructureType::EVENT_DATA_SPACE_QUERY_RESULTS_AVAILABLE_FB => {
+            mem::size_of::<EventDataSpaceQueryResultsAvailableFB>()
+        }
+        StructureType::EVENT_DATA_SPACE_QUERY_COMPLETE_FB => {
+            mem::size_of::<EventDataSpaceQueryCompleteFB>()
+        }
+        StructureType::SPACE_SAVE_INFO_FB => mem::size_of::<SpaceSaveInfoFB>(),
+        StructureType::SPACE_ERASE_INFO_FB => mem::size_of::<SpaceEraseInfoFB>(),
+        StructureType::EVENT_DATA_SPACE_SAVE_COMPLETE_FB => {
+            mem::size_of::<EventDataSpaceSaveCompleteFB>()
+        }
+        StructureType::EVENT_DATA_SPACE_ERASE_COMPLETE_FB => {
+            mem::size_of::<EventDataSpaceEraseCompleteFB>()
+        }
+        StructureType::SPACE_SHARE_INFO_FB => mem::size_of::<SpaceShareInfoFB>(),
+        StructureType::EVENT_DATA_SPACE_SHARE_COMPLETE_FB => {
+            mem::size_of::<EventDataSpaceShareCompleteFB>()
+        }
+        StructureType::SPACE_LIST_SAVE_INFO_FB => mem::size_of::<SpaceListSaveInfoFB>(),
+        StructureType::EVENT_DATA_SPACE_LIST_SAVE_COMPLETE_FB => {
+            mem::size_of::<EventDataSpaceListSaveCompleteFB>()
+        }
+        StructureType::SPACE_CONTAINER_FB => mem::size_of::<SpaceContainerFB>(),
+        StructureType::SEMANTIC_LABELS_FB => mem::size_of::<SemanticLabelsFB>(),
+        StructureType::ROOM_LAYOUT_FB => mem::size_of::<RoomLayoutFB>(),
+        StructureType::BOUNDARY_2D_FB => mem::size_of::<Boundary2DFB>(),
+        StructureType::SEMANTIC_LABELS_SUPPORT_INFO_FB => {
+            mem::size_of::<SemanticLabelsSupportInfoFB>()
+        }
+        StructureType::SCENE_CAPTURE_REQUEST_INFO_FB => mem::size_of::<SceneCaptureRequestInfoFB>(),
+        StructureType::EVENT_DATA_SCENE_CAPTURE_COMPLETE_FB => {
+            mem::size_of::<EventDataSceneCaptureCompleteFB>()
+        }
+        StructureType::SYSTEM_KEYBOARD_TRACKING_PROPERTIES_FB => {
+            mem::size_of::<SystemKeyboardTrackingPropertiesFB>()
+        }
+        StructureType::KEYBOARD_SPACE_CREATE_INFO_FB => mem::size_of::<KeyboardSpaceCreateInfoFB>(),
+        StructureType::KEYBOARD_TRACKING_QUERY_FB => mem::size_of::<KeyboardTrackingQueryFB>(),
+        StructureType::COMPOSITION_LAYER_DEPTH_TEST_VARJO => {
+            mem::size_of::<CompositionLayerDepthTestVARJO>()
+        }
+        StructureType::VIEW_LOCATE_FOVEATED_RENDERING_VARJO => {
+            mem::size_of::<ViewLocateFoveatedRenderingVARJO>()
+        }
+        StructureType::FOVEATED_VIEW_CONFIGURATION_VIEW_VARJO => {
+            mem::size_of::<FoveatedViewConfigurationViewVARJO>()
+        }
+        StructureType::SYSTEM_FOVEATED_RENDERING_PROPERTIES_VARJO => {
+            mem::size_of::<SystemFoveatedRenderingPropertiesVARJO>()
+        }
+        StructureType::COMPOSITION_LAYER_REPROJECTION_INFO_MSFT => {
+            mem::size_of::<CompositionLayerReprojectionInfoMSFT>()
+        }
+        StructureType::COMPOSITION_LAYER_REPROJECTION_PLANE_OVERRIDE_MSFT => {
+            mem::size_of::<CompositionLayerReprojectionPlaneOverrideMSFT>()
+        }
+        StructureType::TRIANGLE_MESH_CREATE_INFO_FB => mem::size_of::<TriangleMeshCreateInfoFB>(),
+        StructureType::SYSTEM_PASSTHROUGH_PROPERTIES_FB => {
+            mem::size_of::<SystemPassthroughPropertiesFB>()
+        }
+        StructureType::SYSTEM_PASSTHROUGH_PROPERTIES2_FB => {
+            mem::size_of::<SystemPassthroughProperties2FB>()
+        }
+        StructureType::PASSTHROUGH_CREATE_INFO_FB => mem::size_of::<PassthroughCreateInfoFB>(),
+        StructureType::PASSTHROUGH_LAYER_CREATE_INFO_FB => {
+            mem::size_of::<PassthroughLayerCreateInfoFB>()
+        }
+        StructureType::COMPOSITION_LAYER_PASSTHROUGH_FB => {
+            mem::size_of::<CompositionLayerPassthroughFB>()
+        }
+        StructureType::GEOMETRY_INSTANCE_CREATE_INFO_FB => {
+            mem::size_of::<GeometryInstanceCreateInfoFB>()
+        }
+        StructureType::GEOMETRY_INSTANCE_TRANSFORM_FB => {
+            mem::size_of::<GeometryInstanceTransformFB>()
+        }
+        StructureType::PASSTHROUGH_STYLE_FB => mem::size_of::<PassthroughStyleFB>(),
+        StructureType::PASSTHROUGH_COLOR_MAP_MONO_TO_RGBA_FB => {
+            mem::size_of::<PassthroughColorMapMonoToRgbaFB>()
+        }
+        StructureType::PASSTHROUGH_COLOR_MAP_MONO_TO_MONO_FB => {
+            mem::size_of::<PassthroughColorMapMonoToMonoFB>()
+        }
+        StructureType::PASSTHROUGH_BRIGHTNESS_CONTRAST_SATURATION_FB => {
+            mem::size_of::<PassthroughBrightnessContrastSaturationFB>()
+        }
+        StructureType::EVENT_DATA_PASSTHROUGH_STATE_CHANGED_FB => {
+            mem::size_of::<EventDataPassthroughStateChangedFB>()
+        }
+        StructureType::PASSTHROUGH_KEYBOARD_HANDS_INTENSITY_FB => {
+            mem::size_of::<PassthroughKeyboardHandsIntensityFB>()
+        }
+        StructureType::LOCAL_DIMMING_FRAME_END_INFO_META => {
+            mem::size_of::<LocalDimmingFrameEndInfoMETA>()
+        }
+        StructureType::SPATIAL_ANCHOR_PERSISTENCE_INFO_MSFT => {
+            mem::size_of::<SpatialAnchorPersistenceInfoMSFT>()
+        }
+        StructureType::SPATIAL_ANCHOR_FROM_PERSISTED_ANCHOR_CREATE_INFO_MSFT => {
+            mem::size_of::<SpatialAnchorFromPersistedAnchorCreateInfoMSFT>()
+        }
+        StructureType::FACIAL_TRACKER_CREATE_INFO_HTC => {
+            mem::size_of::<FacialTrackerCreateInfoHTC>()
+        }
+        StructureType::SYSTEM_FACIAL_TRACKING_PROPERTIES_HTC => {
+            mem::size_of::<SystemFacialTrackingPropertiesHTC>()
+        }
+        StructureType::FACIAL_EXPRESSIONS_HTC => mem::size_of::<FacialExpressionsHTC>(),
+        StructureType::PASSTHROUGH_CREATE_INFO_HTC => mem::size_of::<PassthroughCreateInfoHTC>(),
+        StructureType::PASSTHROUGH_COLOR_HTC => mem::size_of::<PassthroughColorHTC>(),
+        StructureType::PASSTHROUGH_MESH_TRANSFORM_INFO_HTC => {
+            mem::size_of::<PassthroughMeshTransformInfoHTC>()
+        }
+        StructureType::COMPOSITION_LAYER_PASSTHROUGH_HTC => {
+            mem::size_of::<CompositionLayerPassthroughHTC>()
+        }
+        StructureType::VIVE_TRACKER_PATHS_HTCX => mem::size_of::<ViveTrackerPathsHTCX>(),
+        StructureType::EVENT_DATA_VIVE_TRACKER_CONNECTED_HTCX => {
+            mem::size_of::<EventDataViveTrackerConnectedHTCX>()
+        }
+        StructureType::COMPOSITION_LAYER_SPACE_WARP_INFO_FB => {
+            mem::size_of::<CompositionLayerSpaceWarpInfoFB>()
+        }
+        StructureType::SYSTEM_SPACE_WARP_PROPERTIES_FB => {
+            mem::size_of::<SystemSpaceWarpPropertiesFB>()
+        }
+        StructureType::SYSTEM_MARKER_TRACKING_PROPERTIES_VARJO => {
+            mem::size_of::<SystemMarkerTrackingPropertiesVARJO>()
+        }
+        StructureType::EVENT_DATA_MARKER_TRACKING_UPDATE_VARJO => {
+            mem::size_of::<EventDataMarkerTrackingUpdateVARJO>()
+        }
+        StructureType::MARKER_SPACE_CREATE_INFO_VARJO => {
+            mem::size_of::<MarkerSpaceCreateInfoVARJO>()
+        }
+        StructureType::GLOBAL_DIMMER_FRAME_END_INFO_ML => {
+            mem::size_of::<GlobalDimmerFrameEndInfoML>()
+        }
+        StructureType::DIGITAL_LENS_CONTROL_ALMALENCE => {
+            mem::size_of::<DigitalLensControlALMALENCE>()
+        }
+        StructureType::COMPOSITION_LAYER_SETTINGS_FB => {
+            mem::size_of::<CompositionLayerSettingsFB>()
+        }
+        StructureType::EXTERNAL_CAMERA_OCULUS => mem::size_of::<ExternalCameraOCULUS>(),
+        StructureType::PERFORMANCE_METRICS_STATE_META => {
+            mem::size_of::<PerformanceMetricsStateMETA>()
+        }
+        StructureType::PERFORMANCE_METRICS_COUNTER_META => {
+            mem::size_of::<PerformanceMetricsCounterMETA>()
+        }
+        StructureType::PASSTHROUGH_PREFERENCES_META => mem::size_of::<PassthroughPreferencesMETA>(),
+        StructureType::SYSTEM_HEADSET_ID_PROPERTIES_META => {
+            mem::size_of::<SystemHeadsetIdPropertiesMETA>()
+        }
+        StructureType::PASSTHROUGH_COLOR_LUT_CREATE_INFO_META => {
+            mem::size_of::<PassthroughColorLutCreateInfoMETA>()
+        }
+        StructureType::PASSTHROUGH_COLOR_LUT_UPDATE_INFO_META => {
+            mem::size_of::<PassthroughColorLutUpdateInfoMETA>()
+        }
+        StructureType::PASSTHROUGH_COLOR_MAP_LUT_META => {
+            mem::size_of::<PassthroughColorMapLutMETA>()
+        }
+        StructureType::PASSTHROUGH_COLOR_MAP_INTERPOLATED_LUT_META => {
+            mem::size_of::<PassthroughColorMapInterpolatedLutMETA>()
+        }
+        StructureType::SYSTEM_PASSTHROUGH_COLOR_LUT_PROPERTIES_META => {
+            mem::size_of::<SystemPassthroughColorLutPropertiesMETA>()
+        }
+        StructureType::FOVEATION_APPLY_INFO_HTC => mem::size_of::<FoveationApplyInfoHTC>(),
+        StructureType::FOVEATION_DYNAMIC_MODE_INFO_HTC => {
+            mem::size_of::<FoveationDynamicModeInfoHTC>()
+        }
+        StructureType::FOVEATION_CUSTOM_MODE_INFO_HTC => {
+            mem::size_of::<FoveationCustomModeInfoHTC>()
+        }
+        StructureType::ACTIVE_ACTION_SET_PRIORITIES_EXT => {
+            mem::size_of::<ActiveActionSetPrioritiesEXT>()
+        }
+        StructureType::COMPOSITION_LAYER_DEPTH_TEST_FB => {
+            mem::size_of::<CompositionLayerDepthTestFB>()
+        }
+        StructureType::COORDINATE_SPACE_CREATE_INFO_ML => {
+            mem::size_of::<CoordinateSpaceCreateInfoML>()
+        }
+        StructureType::FRAME_END_INFO_ML => mem::size_of::<FrameEndInfoML>(),
+        StructureType::HAPTIC_AMPLITUDE_ENVELOPE_VIBRATION_FB => {
+            mem::size_of::<HapticAmplitudeEnvelopeVibrationFB>()
+        }
+        StructureType::HAPTIC_PCM_VIBRATION_FB => mem::size_of::<HapticPcmVibrationFB>(),
+        StructureType::DEVICE_PCM_SAMPLE_RATE_STATE_FB => {
+            mem::size_of::<DevicePcmSampleRateStateFB>()
+        }
+        StructureType::SPACE_USER_CREATE_INFO_FB => mem::size_of::<SpaceUserCreateInfoFB>(),
+        StructureType::SYSTEM_FORCE_FEEDBACK_CURL_PROPERTIES_MNDX => {
+            mem::size_of::<SystemForceFeedbackCurlPropertiesMNDX>()
+        }
+        StructureType::FORCE_FEEDBACK_CURL_APPLY_LOCATIONS_MNDX => {
+            mem::size_of::<ForceFeedbackCurlApplyLocationsMNDX>()
+        }
+        StructureType::SYSTEM_PLANE_DETECTION_PROPERTIES_EXT => {
+            mem::size_of::<SystemPlaneDetectionPropertiesEXT>()
+        }
+        StructureType::PLANE_DETECTOR_CREATE_INFO_EXT => {
+            mem::size_of::<PlaneDetectorCreateInfoEXT>()
+        }
+        StructureType::PLANE_DETECTOR_BEGIN_INFO_EXT => mem::size_of::<PlaneDetectorBeginInfoEXT>(),
+        StructureType::PLANE_DETECTOR_GET_INFO_EXT => mem::size_of::<PlaneDetectorGetInfoEXT>(),
+        StructureType::PLANE_DETECTOR_LOCATIONS_EXT => mem::size_of::<PlaneDetectorLocationsEXT>(),
+        StructureType::PLANE_DETECTOR_LOCATION_EXT => mem::size_of::<PlaneDetectorLocationEXT>(),
+        StructureType::PLANE_DETECTOR_POLYGON_BUFFER_EXT => {
+            mem::size_of::<PlaneDetectorPolygonBufferEXT>()
+        }
+        StructureType::SYSTEM_VIRTUAL_KEYBOARD_PROPERTIES_META => {
+            mem::size_of::<SystemVirtualKeyboardPropertiesMETA>()
+        }
+        StructureType::VIRTUAL_KEYBOARD_CREATE_INFO_META => {
+            mem::size_of::<VirtualKeyboardCreateInfoMETA>()
+        }
+        StructureType::VIRTUAL_KEYBOARD_SPACE_CREATE_INFO_META => {
+            mem::size_of::<VirtualKeyboardSpaceCreateInfoMETA>()
+        }
+        StructureType::VIRTUAL_KEYBOARD_LOCATION_INFO_META => {
+            mem::size_of::<VirtualKeyboardLocationInfoMETA>()
+        }
+        StructureType::VIRTUAL_KEYBOARD_MODEL_VISIBILITY_SET_INFO_META => {
+            mem::size_of::<VirtualKeyboardModelVisibilitySetInfoMETA>()
+        }
+        StructureType::VIRTUAL_KEYBOARD_ANIMATION_STATE_META => {
+            mem::size_of::<VirtualKeyboardAnimationStateMETA>()
+        }
+        StructureType::VIRTUAL_KEYBOARD_MODEL_ANIMATION_STATES_META => {
+            mem::size_of::<VirtualKeyboardModelAnimationStatesMETA>()
+        }
+        StructureType::VIRTUAL_KEYBOARD_TEXTURE_DATA_META => {
+            mem::size_of::<VirtualKeyboardTextureDataMETA>()
+        }
+        StructureType::VIRTUAL_KEYBOARD_INPUT_INFO_META => {
+            mem::size_of::<VirtualKeyboardInputInfoMETA>()
+        }
+        StructureType::VIRTUAL_KEYBOARD_TEXT_CONTEXT_CHANGE_INFO_META => {
+            mem::size_of::<VirtualKeyboardTextContextChangeInfoMETA>()
+        }
+        StructureType::EVENT_DATA_VIRTUAL_KEYBOARD_COMMIT_TEXT_META => {
+            mem::size_of::<EventDataVirtualKeyboardCommitTextMETA>()
+        }
+        StructureType::EVENT_DATA_VIRTUAL_KEYBOARD_BACKSPACE_META => {
+            mem::size_of::<EventDataVirtualKeyboardBackspaceMETA>()
+        }
+        StructureType::EVENT_DATA_VIRTUAL_KEYBOARD_ENTER_META => {
+            mem::size_of::<EventDataVirtualKeyboardEnterMETA>()
+        }
+        StructureType::EVENT_DATA_VIRTUAL_KEYBOARD_SHOWN_META => {
+            mem::size_of::<EventDataVirtualKeyboardShownMETA>()
+        }
+        StructureType::EVENT_DATA_VIRTUAL_KEYBOARD_HIDDEN_META => {
+            mem::size_of::<EventDataVirtualKeyboardHiddenMETA>()
+        }
+        _ => return None,
+    })
+}
@@ -0,0 +1,725 @@
+//! Recording and replay of OpenXR calls
+//!
+//! A [`Recorder`] wraps an [`Entry`], logging every call made through it and the instances created
+//! from it to a compact binary [`Trace`]. A [`Replay`] serves a trace back through a new `Entry`,
+//! without a runtime, so the application code that produced it can be rerun offline, e.g. under a
+//! debugger on a machine without the user's headset.
+//!
+//! For each call, a trace holds the command, the handle it was made on, the arguments with the
+//! structures they point to (including `next` chains), the result, and everything the runtime
+//! wrote back: output structures and their `next` chains, handles, scalars, and the contents of
+//! two-call arrays. Pointers inside structures, other than `next`, are recorded by address only,
+//! so data a runtime returns through them (e.g. the joint locations of `xrLocateHandJointsEXT`)
+//! isn't replayed. Neither are objects of graphics APIs.
+//!
+//! Replay answers each call with the first unreplayed record of the same command on the same
+//! handle, so calls made from several threads needn't interleave as they did while recording. A
+//! call without a match fails with `ERROR_RUNTIME_FAILURE` and is reported by
+//! [`Replay::unmatched`].
+//!
+//! Structures are stored as laid out in memory, so traces can only be replayed on the platform
+//! they were recorded on. Only one trace can be recorded or replayed at a time in a process;
+//! constructing a `Recorder` or `Replay` waits until any other has been dropped.
+//!
+//! Available if the `trace` feature is enabled.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use openxr as xr;
+//! # use std::{fs::File, io::BufWriter};
+//!
+//! let entry = unsafe { xr::Entry::load() }.unwrap();
+//! let out = BufWriter::new(File::create("app.xrtrace").unwrap());
+//! let recorder = xr::trace::Recorder::new(&entry, out);
+//! let instance = recorder
+//!     .entry()
+//!     .create_instance(&xr::ApplicationInfo::default(), &xr::ExtensionSet::default(), &[])
+//!     .unwrap();
+//! // ...
+//! # drop(instance);
+//! recorder.flush().unwrap();
+//! drop(recorder);
+//!
+//! // Later, elsewhere
+//! let trace = xr::trace::Trace::read(File::open("app.xrtrace").unwrap()).unwrap();
+//! let replay = xr::trace::Replay::new(trace);
+//! let instance = replay
+//!     .entry()
+//!     .create_instance(&xr::ApplicationInfo::default(), &xr::ExtensionSet::default(), &[])
+//!     .unwrap();
+//! ```
+
+use std::collections::{HashMap, VecDeque};
+use std::convert::TryInto;
+use std::ffi::CStr;
+use std::io::{self, Read, Write};
+use std::os::raw::c_char;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{Arc, Condvar, Mutex, MutexGuard, RwLock};
+use std::{mem, ptr, slice};
+
+use sys::pfn;
+
+use crate::*;
+
+mod generated;
+use generated::{struct_size, Next};
+
+/// Identifies the trace format, including its version
+const MAGIC: &[u8; 8] = b"XRTRACE1";
+
+/// A recorded sequence of OpenXR calls
+#[derive(Debug, Clone, Default)]
+pub struct Trace {
+    /// Calls in the order they returned
+    pub calls: Vec<Call>,
+}
+
+impl Trace {
+    /// Parse a trace as written by a [`Recorder`] or [`Trace::write`]
+    pub fn read(mut reader: impl Read) -> io::Result<Self> {
+        let mut data = Vec::new();
+        reader.read_to_end(&mut data)?;
+        let mut input = &data[..];
+        if take(&mut input, MAGIC.len())? != MAGIC {
+            return Err(invalid_data("not an OpenXR trace"));
+        }
+        let mut calls = Vec::new();
+        while !input.is_empty() {
+            calls.push(Call::decode(&mut input)?);
+        }
+        Ok(Self { calls })
+    }
+
+    pub fn write(&self, mut writer: impl Write) -> io::Result<()> {
+        writer.write_all(MAGIC)?;
+        let mut out = Vec::new();
+        for call in &self.calls {
+            out.clear();
+            call.encode(&mut out);
+            writer.write_all(&out)?;
+        }
+        Ok(())
+    }
+}
+
+/// A single recorded call
+#[derive(Debug, Clone)]
+pub struct Call {
+    /// Name of the command, e.g. `xrCreateSession`
+    pub command: String,
+    /// Raw value of the handle the call was made on, or 0 for commands not made on a handle
+    pub handle: u64,
+    pub result: sys::Result,
+    /// The arguments as passed by the application, in order
+    pub inputs: Vec<Arg>,
+    /// Memory written by the runtime, in argument order; empty if the call had no effect
+    pub outputs: Vec<Arg>,
+}
+
+impl Call {
+    fn encode(&self, out: &mut Vec<u8>) {
+        put_bytes(out, self.command.as_bytes());
+        out.extend_from_slice(&self.handle.to_le_bytes());
+        out.extend_from_slice(&self.result.into_raw().to_le_bytes());
+        for args in [&self.inputs, &self.outputs].iter() {
+            put_len(out, args.len());
+            for arg in args.iter() {
+                put_bytes(out, &arg.data);
+                put_len(out, arg.next.len());
+                for (ty, data) in &arg.next {
+                    out.extend_from_slice(&ty.into_raw().to_le_bytes());
+                    put_bytes(out, data);
+                }
+            }
+        }
+    }
+
+    fn decode(input: &mut &[u8]) -> io::Result<Self> {
+        let command = String::from_utf8(take_bytes(input)?.into())
+            .map_err(|_| invalid_data("command name is not UTF-8"))?;
+        let handle = u64::from_le_bytes(take_array(input)?);
+        let result = sys::Result::from_raw(i32::from_le_bytes(take_array(input)?));
+        let mut args = || -> io::Result<Vec<Arg>> {
+            (0..take_len(input)?)
+                .map(|_| {
+                    let data = take_bytes(input)?.into();
+                    let next = (0..take_len(input)?)
+                        .map(|_| {
+                            let ty = sys::StructureType::from_raw(i32::from_le_bytes(take_array(
+                                input,
+                            )?));
+                            Ok((ty, take_bytes(input)?.into()))
+                        })
+                        .collect::<io::Result<_>>()?;
+                    Ok(Arg { data, next })
+                })
+                .collect()
+        };
+        let inputs = args()?;
+        let outputs = args()?;
+        Ok(Self {
+            command,
+            handle,
+            result,
+            inputs,
+            outputs,
+        })
+    }
+}
+
+/// The memory behind an argument
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Arg {
+    /// The value, or the memory it points to
+    ///
+    /// Arrays written through two-call idioms are prefixed by the element count as a `u32`.
+    pub data: Vec<u8>,
+    /// Structures reached through the `next` chain of a structure in `data`
+    pub next: Vec<(sys::StructureType, Vec<u8>)>,
+}
+
+fn put_len(out: &mut Vec<u8>, len: usize) {
+    out.extend_from_slice(&(len as u32).to_le_bytes());
+}
+
+fn put_bytes(out: &mut Vec<u8>, bytes: &[u8]) {
+    put_len(out, bytes.len());
+    out.extend_from_slice(bytes);
+}
+
+fn take<'a>(input: &mut &'a [u8], len: usize) -> io::Result<&'a [u8]> {
+    if input.len() < len {
+        return Err(invalid_data("truncated trace"));
+    }
+    let (x, rest) = input.split_at(len);
+    *input = rest;
+    Ok(x)
+}
+
+fn take_array<const N: usize>(input: &mut &[u8]) -> io::Result<[u8; N]> {
+    Ok(take(input, N)?.try_into().unwrap())
+}
+
+fn take_len(input: &mut &[u8]) -> io::Result<usize> {
+    Ok(u32::from_le_bytes(take_array(input)?) as usize)
+}
+
+fn take_bytes<'a>(input: &mut &'a [u8]) -> io::Result<&'a [u8]> {
+    let len = take_len(input)?;
+    take(input, len)
+}
+
+fn invalid_data(msg: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+/// Records calls made through an [`Entry`] and the objects created from it
+///
+/// Records are written to the output as their calls return, and the output is flushed when the
+/// `Recorder` is dropped. Calls made through [`Recorder::entry`] afterwards fail.
+pub struct Recorder {
+    inner: Arc<Recording>,
+}
+
+impl Recorder {
+    /// Begin recording calls made through [`Recorder::entry`], which are forwarded to `entry`
+    ///
+    /// Blocks while another trace is being recorded or replayed.
+    pub fn new(entry: &Entry, out: impl Write + Send + 'static) -> Self {
+        let get_instance_proc_addr = entry.fp().get_instance_proc_addr;
+        let inner = Arc::new(Recording {
+            entry: entry.clone(),
+            next: RwLock::new((
+                sys::Instance::NULL,
+                Arc::new(unsafe { Next::load(get_instance_proc_addr, sys::Instance::NULL) }),
+            )),
+            out: Mutex::new(Output {
+                writer: Box::new(out),
+                error: None,
+            }),
+        });
+        inner.write(MAGIC);
+        activate(Active::Record(inner.clone()));
+        Self { inner }
+    }
+
+    /// An `Entry` whose calls are recorded
+    pub fn entry(&self) -> Entry {
+        unsafe {
+            Entry::from_get_instance_proc_addr(get_instance_proc_addr)
+                .expect("traced entry exposes all global commands")
+        }
+    }
+
+    /// Flush the output, reporting the first error encountered while writing it, if any
+    pub fn flush(&self) -> io::Result<()> {
+        let mut out = lock(&self.inner.out);
+        if let Some(e) = out.error.take() {
+            return Err(e);
+        }
+        out.writer.flush()
+    }
+}
+
+impl Drop for Recorder {
+    fn drop(&mut self) {
+        deactivate();
+        let _ = lock(&self.inner.out).writer.flush();
+    }
+}
+
+struct Recording {
+    /// Keeps the traced implementation loaded
+    entry: Entry,
+    /// Commands of the most recently seen instance
+    next: RwLock<(sys::Instance, Arc<Next>)>,
+    out: Mutex<Output>,
+}
+
+struct Output {
+    writer: Box<dyn Write + Send>,
+    error: Option<io::Error>,
+}
+
+impl Recording {
+    fn call(
+        &self,
+        command: &str,
+        handle: u64,
+        inputs: impl FnOnce(&mut Inputs),
+        f: impl FnOnce(&Next) -> sys::Result,
+        outputs: impl FnOnce(&mut Outputs),
+    ) -> sys::Result {
+        let mut call = Call {
+            command: command.into(),
+            handle,
+            result: sys::Result::SUCCESS,
+            inputs: Vec::new(),
+            outputs: Vec::new(),
+        };
+        inputs(&mut Inputs(&mut call.inputs));
+        let next = self
+            .next
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .1
+            .clone();
+        call.result = f(&next);
+        // Output memory is left untouched, and possibly uninitialized, by failures and by these
+        if call.result.into_raw() >= 0
+            && call.result != sys::Result::EVENT_UNAVAILABLE
+            && call.result != sys::Result::TIMEOUT_EXPIRED
+        {
+            outputs(&mut Outputs::Record(&mut call.outputs));
+        }
+        let mut data = Vec::new();
+        call.encode(&mut data);
+        self.write(&data);
+        call.result
+    }
+
+    fn write(&self, data: &[u8]) {
+        let mut out = lock(&self.out);
+        if out.error.is_some() {
+            return;
+        }
+        if let Err(e) = out.writer.write_all(data) {
+            out.error = Some(e);
+        }
+    }
+
+    /// Make sure the commands of `instance` are the ones called
+    unsafe fn track(&self, instance: sys::Instance) {
+        if instance == sys::Instance::NULL
+            || self.next.read().unwrap_or_else(|e| e.into_inner()).0 == instance
+        {
+            return;
+        }
+        let next = Next::load(self.entry.fp().get_instance_proc_addr, instance);
+        *self.next.write().unwrap_or_else(|e| e.into_inner()) = (instance, Arc::new(next));
+    }
+}
+
+/// Serves the results of a [`Trace`] to calls made through an [`Entry`]
+pub struct Replay {
+    inner: Arc<Replaying>,
+}
+
+impl Replay {
+    /// Begin replaying `trace` to calls made through [`Replay::entry`]
+    ///
+    /// Blocks while another trace is being recorded or replayed.
+    pub fn new(trace: Trace) -> Self {
+        let mut pending = HashMap::<u64, HashMap<String, VecDeque<Call>>>::new();
+        for call in trace.calls {
+            pending
+                .entry(call.handle)
+                .or_default()
+                .entry(call.command.clone())
+                .or_default()
+                .push_back(call);
+        }
+        let inner = Arc::new(Replaying {
+            pending: Mutex::new(pending),
+            unmatched: Mutex::new(Vec::new()),
+        });
+        activate(Active::Replay(inner.clone()));
+        Self { inner }
+    }
+
+    /// An `Entry` whose calls are answered from the trace
+    pub fn entry(&self) -> Entry {
+        unsafe {
+            Entry::from_get_instance_proc_addr(get_instance_proc_addr)
+                .expect("traced entry exposes all global commands")
+        }
+    }
+
+    /// Calls that had no counterpart in the trace, as command names and handles
+    pub fn unmatched(&self) -> Vec<(String, u64)> {
+        lock(&self.inner.unmatched).clone()
+    }
+
+    /// Number of recorded calls that haven't been replayed yet
+    pub fn remaining(&self) -> usize {
+        lock(&self.inner.pending)
+            .values()
+            .flat_map(|x| x.values())
+            .map(|x| x.len())
+            .sum()
+    }
+}
+
+impl Drop for Replay {
+    fn drop(&mut self) {
+        deactivate();
+    }
+}
+
+struct Replaying {
+    /// Unreplayed calls by handle and command, in order
+    pending: Mutex<HashMap<u64, HashMap<String, VecDeque<Call>>>>,
+    unmatched: Mutex<Vec<(String, u64)>>,
+}
+
+impl Replaying {
+    fn call(&self, command: &str, handle: u64, outputs: impl FnOnce(&mut Outputs)) -> sys::Result {
+        let call = lock(&self.pending)
+            .get_mut(&handle)
+            .and_then(|x| x.get_mut(command))
+            .and_then(|x| x.pop_front());
+        let call = match call {
+            Some(x) => x,
+            None => {
+                lock(&self.unmatched).push((command.into(), handle));
+                return sys::Result::ERROR_RUNTIME_FAILURE;
+            }
+        };
+        if !call.outputs.is_empty() {
+            outputs(&mut Outputs::Replay(call.outputs.iter()));
+        }
+        call.result
+    }
+}
+
+#[derive(Clone)]
+enum Active {
+    Record(Arc<Recording>),
+    Replay(Arc<Replaying>),
+}
+
+static ACTIVE: Mutex<Option<Active>> = Mutex::new(None);
+static IDLE: Condvar = Condvar::new();
+
+fn lock<T>(x: &Mutex<T>) -> MutexGuard<'_, T> {
+    x.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+fn activate(trace: Active) {
+    let mut active = lock(&ACTIVE);
+    while active.is_some() {
+        active = IDLE.wait(active).unwrap_or_else(|e| e.into_inner());
+    }
+    *active = Some(trace);
+}
+
+fn deactivate() {
+    *lock(&ACTIVE) = None;
+    IDLE.notify_one();
+}
+
+fn active() -> Option<Active> {
+    lock(&ACTIVE).clone()
+}
+
+/// Record or replay a call
+///
+/// `inputs` and `outputs` describe the command's arguments, and `f` performs it.
+unsafe fn call(
+    command: &str,
+    handle: u64,
+    inputs: impl FnOnce(&mut Inputs),
+    f: impl FnOnce(&Next) -> sys::Result,
+    outputs: impl FnOnce(&mut Outputs),
+) -> sys::Result {
+    let active = match active() {
+        Some(x) => x,
+        None => return sys::Result::ERROR_RUNTIME_FAILURE,
+    };
+    // Unwinding into the application is undefined behavior
+    panic::catch_unwind(AssertUnwindSafe(|| match active {
+        Active::Record(ref x) => x.call(command, handle, inputs, f, outputs),
+        Active::Replay(ref x) => x.call(command, handle, outputs),
+    }))
+    .unwrap_or(sys::Result::ERROR_RUNTIME_FAILURE)
+}
+
+unsafe fn load(
+    get_instance_proc_addr: pfn::GetInstanceProcAddr,
+    instance: sys::Instance,
+    name: &[u8],
+) -> Option<pfn::VoidFunction> {
+    let mut f = None;
+    let result = get_instance_proc_addr(instance, name.as_ptr() as *const c_char, &mut f);
+    if result.into_raw() < 0 {
+        return None;
+    }
+    f
+}
+
+unsafe extern "system" fn get_instance_proc_addr(
+    instance: sys::Instance,
+    name: *const c_char,
+    function: *mut Option<pfn::VoidFunction>,
+) -> sys::Result {
+    let active = match active() {
+        Some(x) => x,
+        None => return sys::Result::ERROR_RUNTIME_FAILURE,
+    };
+    let name_bytes = CStr::from_ptr(name).to_bytes();
+    if name_bytes == b"xrGetInstanceProcAddr" {
+        *function = Some(
+            mem::transmute::<pfn::GetInstanceProcAddr, pfn::VoidFunction>(get_instance_proc_addr),
+        );
+        return sys::Result::SUCCESS;
+    }
+    match active {
+        Active::Record(ref recording) => {
+            // Only commands the traced implementation supports are exposed
+            let result = (recording.entry.fp().get_instance_proc_addr)(instance, name, function);
+            if result.into_raw() < 0 {
+                return result;
+            }
+            recording.track(instance);
+            if let Some(f) = generated::thunk(name_bytes) {
+                *function = Some(f);
+            }
+            result
+        }
+        Active::Replay(_) => {
+            *function = generated::thunk(name_bytes);
+            if (*function).is_none() {
+                return sys::Result::ERROR_FUNCTION_UNSUPPORTED;
+            }
+            sys::Result::SUCCESS
+        }
+    }
+}
+
+/// Collects the arguments of a call being recorded
+struct Inputs<'a>(&'a mut Vec<Arg>);
+
+impl Inputs<'_> {
+    fn value<T: Copy>(&mut self, x: &T) {
+        self.0.push(Arg {
+            data: unsafe { bytes(x as *const T as *const u8, mem::size_of::<T>()) },
+            next: Vec::new(),
+        });
+    }
+
+    unsafe fn pointer<T>(&mut self, p: *const T) {
+        self.0.push(Arg {
+            data: bytes(p as *const u8, mem::size_of::<T>()),
+            next: Vec::new(),
+        });
+    }
+
+    unsafe fn string(&mut self, p: *const c_char) {
+        let data = if p.is_null() {
+            Vec::new()
+        } else {
+            CStr::from_ptr(p).to_bytes().into()
+        };
+        self.0.push(Arg {
+            data,
+            next: Vec::new(),
+        });
+    }
+
+    /// Record a structure that begins with `type` and `next`, along with its `next` chain
+    unsafe fn chain<T>(&mut self, p: *const T) {
+        self.0.push(chain_arg(
+            p as *const sys::BaseInStructure,
+            mem::size_of::<T>(),
+        ));
+    }
+}
+
+/// Records or replays the memory a call writes to
+enum Outputs<'a> {
+    Record(&'a mut Vec<Arg>),
+    Replay(slice::Iter<'a, Arg>),
+}
+
+impl Outputs<'_> {
+    unsafe fn pointer<T>(&mut self, p: *mut T) {
+        self.fixed(p, 1);
+    }
+
+    unsafe fn fixed<T>(&mut self, p: *mut T, count: usize) {
+        let len = count * mem::size_of::<T>();
+        match *self {
+            Outputs::Record(ref mut args) => args.push(Arg {
+                data: bytes(p as *const u8, len),
+                next: Vec::new(),
+            }),
+            Outputs::Replay(ref mut args) => {
+                let arg = match args.next() {
+                    Some(x) => x,
+                    None => return,
+                };
+                if !p.is_null() {
+                    let len = arg.data.len().min(len);
+                    ptr::copy_nonoverlapping(arg.data.as_ptr(), p as *mut u8, len);
+                }
+            }
+        }
+    }
+
+    /// Handle a structure that begins with `type` and `next`, along with its `next` chain
+    unsafe fn chain<T>(&mut self, p: *mut T) {
+        let p = p as *mut sys::BaseOutStructure;
+        match *self {
+            Outputs::Record(ref mut args) => args.push(chain_arg(p as _, mem::size_of::<T>())),
+            Outputs::Replay(ref mut args) => {
+                let arg = match args.next() {
+                    Some(x) => x,
+                    None => return,
+                };
+                if p.is_null() {
+                    return;
+                }
+                restore(p, &arg.data);
+                let mut next = (*p).next;
+                while !next.is_null() {
+                    if let Some((_, data)) = arg.next.iter().find(|x| x.0 == (*next).ty) {
+                        restore(next, data);
+                    }
+                    next = (*next).next;
+                }
+            }
+        }
+    }
+
+    /// Handle an array filled in through the two-call idiom
+    unsafe fn array<T>(&mut self, capacity: u32, count: *mut u32, p: *mut T) {
+        self.array_with(
+            capacity,
+            count,
+            p as *mut u8,
+            mem::size_of::<T>(),
+            |dst, src| ptr::copy_nonoverlapping(src.as_ptr(), dst, src.len()),
+        );
+    }
+
+    /// Handle an array of structures that begin with `type` and `next`, filled in through the
+    /// two-call idiom
+    unsafe fn chain_array<T>(&mut self, capacity: u32, count: *mut u32, p: *mut T) {
+        // Arrays of base structures hold elements of the type given by the first
+        let stride = if p.is_null() || capacity == 0 {
+            0
+        } else {
+            struct_size((*(p as *const sys::BaseOutStructure)).ty).unwrap_or_else(mem::size_of::<T>)
+        };
+        self.array_with(capacity, count, p as *mut u8, stride, |dst, src| {
+            restore(dst as *mut sys::BaseOutStructure, src)
+        });
+    }
+
+    unsafe fn array_with(
+        &mut self,
+        capacity: u32,
+        count: *mut u32,
+        p: *mut u8,
+        stride: usize,
+        write: impl Fn(*mut u8, &[u8]),
+    ) {
+        match *self {
+            Outputs::Record(ref mut args) => {
+                let n = if count.is_null() { 0 } else { *count };
+                let mut data = n.to_ne_bytes().to_vec();
+                if !p.is_null() && capacity != 0 {
+                    data.extend_from_slice(&bytes(p, n.min(capacity) as usize * stride));
+                }
+                args.push(Arg {
+                    data,
+                    next: Vec::new(),
+                });
+            }
+            Outputs::Replay(ref mut args) => {
+                let arg = match args.next() {
+                    Some(x) if x.data.len() >= 4 => x,
+                    _ => return,
+                };
+                let (n, elements) = arg.data.split_at(4);
+                if !count.is_null() {
+                    *count = u32::from_ne_bytes(n.try_into().unwrap());
+                }
+                if p.is_null() || stride == 0 {
+                    return;
+                }
+                for (i, element) in elements.chunks(stride).take(capacity as usize).enumerate() {
+                    write(p.add(i * stride), element);
+                }
+            }
+        }
+    }
+}
+
+unsafe fn bytes(p: *const u8, len: usize) -> Vec<u8> {
+    if p.is_null() {
+        return Vec::new();
+    }
+    slice::from_raw_parts(p, len).into()
+}
+
+/// Capture a structure and its `next` chain, sized according to their types
+unsafe fn chain_arg(p: *const sys::BaseInStructure, fallback_size: usize) -> Arg {
+    if p.is_null() {
+        return Arg::default();
+    }
+    let data = bytes(
+        p as *const u8,
+        struct_size((*p).ty).unwrap_or(fallback_size),
+    );
+    let mut next = Vec::new();
+    let mut x = (*p).next;
+    while !x.is_null() {
+        // Unknown extension structures are recorded by type alone
+        let size = struct_size((*x).ty).unwrap_or(0);
+        next.push(((*x).ty, bytes(x as *const u8, size)));
+        x = (*x).next;
+    }
+    Arg { data, next }
+}
+
+/// Overwrite the structure at `dst` with recorded `data`, keeping its `next` chain intact
+///
+/// The type is overwritten too, since some outputs, like events, are polymorphic.
+unsafe fn restore(dst: *mut sys::BaseOutStructure, data: &[u8]) {
+    let next = (*dst).next;
+    ptr::copy_nonoverlapping(data.as_ptr(), dst as *mut u8, data.len());
+    (*dst).next = next;
+}
@@ -0,0 +1,755 @@
+//! An API layer that reports incorrect use of OpenXR
+//!
+//! Runtimes are free to trust their callers, so mistakes like ending a frame that was never begun
+//! or rendering into a swapchain image that was never waited on tend to surface as opaque errors,
+//! visual corruption, or nothing at all until a different runtime is used. [`Validation`] watches
+//! the calls an application makes and reports such mistakes as [`Violation`]s naming the
+//! offending command, in the manner of the Vulkan validation layers. Calls are passed on unchanged.
+//!
+//! Checks cover:
+//! - the frame loop: `xrWaitFrame`, `xrBeginFrame` and `xrEndFrame` called out of order or on a
+//!   session that isn't running
+//! - swapchain images: acquire, wait and release called out of order, more images acquired than
+//!   exist, and swapchains submitted before any image was released
+//! - handle provenance: spaces and swapchains used with a session other than the one they were
+//!   created from, and handles used after being destroyed
+//! - structure types: the `ty` of structures passed to the commands above, and of composition
+//!   layers submitted to `xrEndFrame`
+//!
+//! Applications enable validation for their own instances with [`Validation::in_process`]. The
+//! layer can also be built into a standalone shared library for the loader to enable, e.g. with
+//! `openxr::export_layer!("XR_APILAYER_example_validation", |_| Ok(Validation::default()));`.
+//!
+//! Available if the `validation` feature is enabled.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use openxr as xr;
+//!
+//! let entry = unsafe { xr::Entry::load() }.unwrap();
+//! let validation = xr::validation::Validation::in_process(&entry, |violation| {
+//!     panic!("{}", violation);
+//! });
+//! let instance = validation
+//!     .entry()
+//!     .create_instance(&xr::ApplicationInfo::default(), &xr::ExtensionSet::default(), &[])
+//!     .unwrap();
+//! ```
+
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use sys::{Session, Space, StructureType, Swapchain};
+
+use crate::layer::{Dispatch, InProcess, Layer};
+use crate::*;
+
+/// A misuse of the API detected by [`Validation`]
+#[derive(Debug, Clone)]
+pub struct Violation {
+    /// The offending command, e.g. `xrEndFrame`
+    pub command: &'static str,
+    pub message: String,
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.command, self.message)
+    }
+}
+
+impl std::error::Error for Violation {}
+
+type Report = Arc<dyn Fn(&Violation) + Send + Sync>;
+
+/// An API layer that checks the calls made on an instance
+///
+/// The default reports violations on standard error.
+pub struct Validation {
+    report: Report,
+    state: Mutex<State>,
+}
+
+impl Validation {
+    /// Report violations by calling `report`
+    pub fn new(report: impl Fn(&Violation) + Send + Sync + 'static) -> Self {
+        Self::with_report(Arc::new(report))
+    }
+
+    fn with_report(report: Report) -> Self {
+        Self {
+            report,
+            state: Mutex::new(State::default()),
+        }
+    }
+
+    /// Validate all instances created through [`InProcess::entry`], reporting violations by calling
+    /// `report`
+    pub fn in_process(
+        entry: &Entry,
+        report: impl Fn(&Violation) + Send + Sync + 'static,
+    ) -> InProcess {
+        let report: Report = Arc::new(report);
+        InProcess::new(entry, move |_| Ok(Self::with_report(report.clone())))
+    }
+
+    fn lock(&self) -> MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn report(&self, command: &'static str, message: impl Into<String>) {
+        (self.report)(&Violation {
+            command,
+            message: message.into(),
+        });
+    }
+
+    /// Check that `p` points to a structure of type `expected`
+    unsafe fn check_ty<T>(
+        &self,
+        command: &'static str,
+        param: &str,
+        p: *const T,
+        expected: StructureType,
+    ) {
+        if p.is_null() {
+            self.report(command, format!("`{}` is null", param));
+            return;
+        }
+        let ty = (*(p as *const sys::BaseInStructure)).ty;
+        if ty != expected {
+            self.report(
+                command,
+                format!("`{}` has type {:?}, expected {:?}", param, ty, expected),
+            );
+        }
+    }
+
+    /// Like `check_ty`, for optional structures
+    unsafe fn check_optional_ty<T>(
+        &self,
+        command: &'static str,
+        param: &str,
+        p: *const T,
+        expected: StructureType,
+    ) {
+        if !p.is_null() {
+            self.check_ty(command, param, p, expected);
+        }
+    }
+
+    /// Report unless `space` is alive and belongs to `session`
+    fn check_space(&self, state: &State, command: &'static str, space: Space, session: Session) {
+        match state.spaces.get(&space) {
+            None => self.report(command, format!("{:?} is not a live space", space)),
+            Some(&owner) if owner != session => self.report(
+                command,
+                format!("{:?} belongs to {:?}, not {:?}", space, owner, session),
+            ),
+            Some(_) => {}
+        }
+    }
+
+    /// Report unless `swapchain` is alive, belongs to `session`, and has a released image
+    fn check_submitted_swapchain(&self, state: &State, swapchain: Swapchain, session: Session) {
+        const COMMAND: &str = "xrEndFrame";
+        match state.swapchains.get(&swapchain) {
+            None => self.report(COMMAND, format!("{:?} is not a live swapchain", swapchain)),
+            Some(x) if x.session != session => self.report(
+                COMMAND,
+                format!(
+                    "{:?} belongs to {:?}, not {:?}",
+                    swapchain, x.session, session
+                ),
+            ),
+            Some(x) if !x.released => self.report(
+                COMMAND,
+                format!("{:?} has no released image to display", swapchain),
+            ),
+            Some(_) => {}
+        }
+    }
+
+    unsafe fn check_layer(
+        &self,
+        state: &State,
+        session: Session,
+        layer: *const sys::CompositionLayerBaseHeader,
+    ) {
+        const COMMAND: &str = "xrEndFrame";
+        if layer.is_null() {
+            self.report(COMMAND, "a composition layer is null");
+            return;
+        }
+        self.check_space(state, COMMAND, (*layer).space, session);
+        match (*layer).ty {
+            StructureType::COMPOSITION_LAYER_PROJECTION => {
+                let layer = &*(layer as *const sys::CompositionLayerProjection);
+                if layer.view_count == 0 || layer.views.is_null() {
+                    self.report(COMMAND, "a projection layer has no views");
+                    return;
+                }
+                for i in 0..layer.view_count as usize {
+                    let view = &*layer.views.add(i);
+                    if view.ty != StructureType::COMPOSITION_LAYER_PROJECTION_VIEW {
+                        self.report(
+                            COMMAND,
+                            format!(
+                                "projection view {} has type {:?}, expected {:?}",
+                                i,
+                                view.ty,
+                                StructureType::COMPOSITION_LAYER_PROJECTION_VIEW
+                            ),
+                        );
+                    }
+                    self.check_submitted_swapchain(state, view.sub_image.swapchain, session);
+                }
+            }
+            StructureType::COMPOSITION_LAYER_QUAD => {
+                let layer = &*(layer as *const sys::CompositionLayerQuad);
+                self.check_submitted_swapchain(state, layer.sub_image.swapchain, session);
+            }
+            // Layers of extensions are only checked for their space
+            _ => {}
+        }
+    }
+}
+
+impl Default for Validation {
+    fn default() -> Self {
+        Self::new(|violation| eprintln!("OpenXR validation: {}", violation))
+    }
+}
+
+#[derive(Default)]
+struct State {
+    sessions: HashMap<Session, SessionState>,
+    /// Spaces by the session they were created from
+    spaces: HashMap<Space, Session>,
+    swapchains: HashMap<Swapchain, SwapchainState>,
+}
+
+impl State {
+    /// The state of `session`, reporting if it isn't alive
+    fn session(
+        &mut self,
+        validation: &Validation,
+        command: &'static str,
+        session: Session,
+    ) -> Option<&mut SessionState> {
+        let state = self.sessions.get_mut(&session);
+        if state.is_none() {
+            validation.report(command, format!("{:?} is not a live session", session));
+        }
+        state
+    }
+
+    /// The state of `swapchain`, reporting if it isn't alive
+    fn swapchain(
+        &mut self,
+        validation: &Validation,
+        command: &'static str,
+        swapchain: Swapchain,
+    ) -> Option<&mut SwapchainState> {
+        let state = self.swapchains.get_mut(&swapchain);
+        if state.is_none() {
+            validation.report(command, format!("{:?} is not a live swapchain", swapchain));
+        }
+        state
+    }
+}
+
+#[derive(Default)]
+struct SessionState {
+    running: bool,
+    /// Frames waited for but not yet begun
+    waited: u32,
+    /// Whether a frame has begun and not yet ended
+    in_frame: bool,
+}
+
+struct SwapchainState {
+    session: Session,
+    static_image: bool,
+    image_count: Option<u32>,
+    /// Acquired images, oldest first, and whether each has been waited on
+    acquired: VecDeque<bool>,
+    acquisitions: u64,
+    /// Whether any image has been released
+    released: bool,
+}
+
+/// Call the next link's implementation of a command
+macro_rules! forward {
+    ($next:ident . $command:ident ($($arg:expr),*)) => {
+        match $next.$command {
+            Some(f) => f($($arg),*),
+            None => sys::Result::ERROR_FUNCTION_UNSUPPORTED,
+        }
+    };
+}
+
+fn succeeded(result: sys::Result) -> bool {
+    result.into_raw() >= 0
+}
+
+impl Layer for Validation {
+    unsafe fn create_session(
+        &self,
+        next: &Dispatch,
+        instance: sys::Instance,
+        create_info: *const sys::SessionCreateInfo,
+        session: *mut Session,
+    ) -> sys::Result {
+        self.check_ty(
+            "xrCreateSession",
+            "createInfo",
+            create_info,
+            sys::SessionCreateInfo::TYPE,
+        );
+        let result = forward!(next.create_session(instance, create_info, session));
+        if succeeded(result) {
+            self.lock()
+                .sessions
+                .insert(*session, SessionState::default());
+        }
+        result
+    }
+
+    unsafe fn destroy_session(&self, next: &Dispatch, session: Session) -> sys::Result {
+        {
+            let mut state = self.lock();
+            if state.session(self, "xrDestroySession", session).is_some() {
+                // Destroying a session destroys its children
+                state.sessions.remove(&session);
+                state.spaces.retain(|_, x| *x != session);
+                state.swapchains.retain(|_, x| x.session != session);
+            }
+        }
+        forward!(next.destroy_session(session))
+    }
+
+    unsafe fn begin_session(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        begin_info: *const sys::SessionBeginInfo,
+    ) -> sys::Result {
+        self.check_ty(
+            "xrBeginSession",
+            "beginInfo",
+            begin_info,
+            sys::SessionBeginInfo::TYPE,
+        );
+        let result = forward!(next.begin_session(session, begin_info));
+        if succeeded(result) {
+            if let Some(x) = self.lock().sessions.get_mut(&session) {
+                x.running = true;
+            }
+        }
+        result
+    }
+
+    unsafe fn end_session(&self, next: &Dispatch, session: Session) -> sys::Result {
+        if let Some(x) = self.lock().session(self, "xrEndSession", session) {
+            if x.in_frame {
+                self.report("xrEndSession", "a frame has begun but not ended");
+            }
+        }
+        let result = forward!(next.end_session(session));
+        if succeeded(result) {
+            if let Some(x) = self.lock().sessions.get_mut(&session) {
+                *x = SessionState::default();
+            }
+        }
+        result
+    }
+
+    unsafe fn create_reference_space(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        create_info: *const sys::ReferenceSpaceCreateInfo,
+        space: *mut Space,
+    ) -> sys::Result {
+        const COMMAND: &str = "xrCreateReferenceSpace";
+        self.lock().session(self, COMMAND, session);
+        self.check_ty(
+            COMMAND,
+            "createInfo",
+            create_info,
+            sys::ReferenceSpaceCreateInfo::TYPE,
+        );
+        let result = forward!(next.create_reference_space(session, create_info, space));
+        if succeeded(result) {
+            self.lock().spaces.insert(*space, session);
+        }
+        result
+    }
+
+    unsafe fn create_action_space(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        create_info: *const sys::ActionSpaceCreateInfo,
+        space: *mut Space,
+    ) -> sys::Result {
+        const COMMAND: &str = "xrCreateActionSpace";
+        self.lock().session(self, COMMAND, session);
+        self.check_ty(
+            COMMAND,
+            "createInfo",
+            create_info,
+            sys::ActionSpaceCreateInfo::TYPE,
+        );
+        let result = forward!(next.create_action_space(session, create_info, space));
+        if succeeded(result) {
+            self.lock().spaces.insert(*space, session);
+        }
+        result
+    }
+
+    unsafe fn destroy_space(&self, next: &Dispatch, space: Space) -> sys::Result {
+        if self.lock().spaces.remove(&space).is_none() {
+            self.report("xrDestroySpace", format!("{:?} is not a live space", space));
+        }
+        forward!(next.destroy_space(space))
+    }
+
+    unsafe fn locate_space(
+        &self,
+        next: &Dispatch,
+        space: Space,
+        base_space: Space,
+        time: sys::Time,
+        location: *mut sys::SpaceLocation,
+    ) -> sys::Result {
+        const COMMAND: &str = "xrLocateSpace";
+        {
+            let state = self.lock();
+            match (state.spaces.get(&space), state.spaces.get(&base_space)) {
+                (Some(a), Some(b)) if a != b => self.report(
+                    COMMAND,
+                    format!(
+                        "{:?} belongs to {:?}, but base {:?} belongs to {:?}",
+                        space, a, base_space, b
+                    ),
+                ),
+                (None, _) => self.report(COMMAND, format!("{:?} is not a live space", space)),
+                (_, None) => self.report(COMMAND, format!("{:?} is not a live space", base_space)),
+                _ => {}
+            }
+        }
+        self.check_ty(COMMAND, "location", location, sys::SpaceLocation::TYPE);
+        forward!(next.locate_space(space, base_space, time, location))
+    }
+
+    unsafe fn locate_views(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        view_locate_info: *const sys::ViewLocateInfo,
+        view_state: *mut sys::ViewState,
+        view_capacity_input: u32,
+        view_count_output: *mut u32,
+        views: *mut sys::View,
+    ) -> sys::Result {
+        const COMMAND: &str = "xrLocateViews";
+        self.check_ty(
+            COMMAND,
+            "viewLocateInfo",
+            view_locate_info,
+            sys::ViewLocateInfo::TYPE,
+        );
+        self.check_ty(COMMAND, "viewState", view_state, sys::ViewState::TYPE);
+        {
+            let mut state = self.lock();
+            if state.session(self, COMMAND, session).is_some() && !view_locate_info.is_null() {
+                self.check_space(&state, COMMAND, (*view_locate_info).space, session);
+            }
+        }
+        if !views.is_null() {
+            for i in 0..view_capacity_input as usize {
+                let ty = (*views.add(i)).ty;
+                if ty != sys::View::TYPE {
+                    self.report(
+                        COMMAND,
+                        format!(
+                            "`views[{}]` has type {:?}, expected {:?}",
+                            i,
+                            ty,
+                            sys::View::TYPE
+                        ),
+                    );
+                }
+            }
+        }
+        forward!(next.locate_views(
+            session,
+            view_locate_info,
+            view_state,
+            view_capacity_input,
+            view_count_output,
+            views
+        ))
+    }
+
+    unsafe fn create_swapchain(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        create_info: *const sys::SwapchainCreateInfo,
+        swapchain: *mut Swapchain,
+    ) -> sys::Result {
+        const COMMAND: &str = "xrCreateSwapchain";
+        self.lock().session(self, COMMAND, session);
+        self.check_ty(
+            COMMAND,
+            "createInfo",
+            create_info,
+            sys::SwapchainCreateInfo::TYPE,
+        );
+        let result = forward!(next.create_swapchain(session, create_info, swapchain));
+        if succeeded(result) {
+            let static_image = (*create_info)
+                .create_flags
+                .contains(sys::SwapchainCreateFlags::STATIC_IMAGE);
+            self.lock().swapchains.insert(
+                *swapchain,
+                SwapchainState {
+                    session,
+                    static_image,
+                    image_count: None,
+                    acquired: VecDeque::new(),
+                    acquisitions: 0,
+                    released: false,
+                },
+            );
+        }
+        result
+    }
+
+    unsafe fn destroy_swapchain(&self, next: &Dispatch, swapchain: Swapchain) -> sys::Result {
+        if self.lock().swapchains.remove(&swapchain).is_none() {
+            self.report(
+                "xrDestroySwapchain",
+                format!("{:?} is not a live swapchain", swapchain),
+            );
+        }
+        forward!(next.destroy_swapchain(swapchain))
+    }
+
+    unsafe fn enumerate_swapchain_images(
+        &self,
+        next: &Dispatch,
+        swapchain: Swapchain,
+        image_capacity_input: u32,
+        image_count_output: *mut u32,
+        images: *mut sys::SwapchainImageBaseHeader,
+    ) -> sys::Result {
+        self.lock()
+            .swapchain(self, "xrEnumerateSwapchainImages", swapchain);
+        let result = forward!(next.enumerate_swapchain_images(
+            swapchain,
+            image_capacity_input,
+            image_count_output,
+            images
+        ));
+        if succeeded(result) {
+            if let Some(x) = self.lock().swapchains.get_mut(&swapchain) {
+                x.image_count = Some(*image_count_output);
+            }
+        }
+        result
+    }
+
+    unsafe fn acquire_swapchain_image(
+        &self,
+        next: &Dispatch,
+        swapchain: Swapchain,
+        acquire_info: *const sys::SwapchainImageAcquireInfo,
+        index: *mut u32,
+    ) -> sys::Result {
+        const COMMAND: &str = "xrAcquireSwapchainImage";
+        self.check_optional_ty(
+            COMMAND,
+            "acquireInfo",
+            acquire_info,
+            sys::SwapchainImageAcquireInfo::TYPE,
+        );
+        if let Some(x) = self.lock().swapchain(self, COMMAND, swapchain) {
+            if x.static_image && x.acquisitions != 0 {
+                self.report(
+                    COMMAND,
+                    "the image of a static swapchain was already acquired",
+                );
+            } else if x.image_count == Some(x.acquired.len() as u32) {
+                self.report(COMMAND, "every image is already acquired");
+            }
+        }
+        let result = forward!(next.acquire_swapchain_image(swapchain, acquire_info, index));
+        if succeeded(result) {
+            if let Some(x) = self.lock().swapchains.get_mut(&swapchain) {
+                x.acquired.push_back(false);
+                x.acquisitions += 1;
+            }
+        }
+        result
+    }
+
+    unsafe fn wait_swapchain_image(
+        &self,
+        next: &Dispatch,
+        swapchain: Swapchain,
+        wait_info: *const sys::SwapchainImageWaitInfo,
+    ) -> sys::Result {
+        const COMMAND: &str = "xrWaitSwapchainImage";
+        self.check_ty(
+            COMMAND,
+            "waitInfo",
+            wait_info,
+            sys::SwapchainImageWaitInfo::TYPE,
+        );
+        if let Some(x) = self.lock().swapchain(self, COMMAND, swapchain) {
+            if x.acquired.iter().all(|&waited| waited) {
+                self.report(COMMAND, "no acquired image remains to be waited on");
+            }
+        }
+        let result = forward!(next.wait_swapchain_image(swapchain, wait_info));
+        if result == sys::Result::SUCCESS {
+            if let Some(x) = self.lock().swapchains.get_mut(&swapchain) {
+                if let Some(waited) = x.acquired.iter_mut().find(|x| !**x) {
+                    *waited = true;
+                }
+            }
+        }
+        result
+    }
+
+    unsafe fn release_swapchain_image(
+        &self,
+        next: &Dispatch,
+        swapchain: Swapchain,
+        release_info: *const sys::SwapchainImageReleaseInfo,
+    ) -> sys::Result {
+        const COMMAND: &str = "xrReleaseSwapchainImage";
+        self.check_optional_ty(
+            COMMAND,
+            "releaseInfo",
+            release_info,
+            sys::SwapchainImageReleaseInfo::TYPE,
+        );
+        if let Some(x) = self.lock().swapchain(self, COMMAND, swapchain) {
+            match x.acquired.front() {
+                None => self.report(COMMAND, "no image is acquired"),
+                Some(false) => self.report(COMMAND, "the oldest acquired image wasn't waited on"),
+                Some(true) => {}
+            }
+        }
+        let result = forward!(next.release_swapchain_image(swapchain, release_info));
+        if succeeded(result) {
+            if let Some(x) = self.lock().swapchains.get_mut(&swapchain) {
+                x.acquired.pop_front();
+                x.released = true;
+            }
+        }
+        result
+    }
+
+    unsafe fn wait_frame(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        frame_wait_info: *const sys::FrameWaitInfo,
+        frame_state: *mut sys::FrameState,
+    ) -> sys::Result {
+        const COMMAND: &str = "xrWaitFrame";
+        self.check_optional_ty(
+            COMMAND,
+            "frameWaitInfo",
+            frame_wait_info,
+            sys::FrameWaitInfo::TYPE,
+        );
+        self.check_ty(COMMAND, "frameState", frame_state, sys::FrameState::TYPE);
+        if let Some(x) = self.lock().session(self, COMMAND, session) {
+            if !x.running {
+                self.report(COMMAND, "the session isn't running");
+            }
+        }
+        let result = forward!(next.wait_frame(session, frame_wait_info, frame_state));
+        if succeeded(result) {
+            if let Some(x) = self.lock().sessions.get_mut(&session) {
+                x.waited += 1;
+            }
+        }
+        result
+    }
+
+    unsafe fn begin_frame(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        frame_begin_info: *const sys::FrameBeginInfo,
+    ) -> sys::Result {
+        const COMMAND: &str = "xrBeginFrame";
+        self.check_optional_ty(
+            COMMAND,
+            "frameBeginInfo",
+            frame_begin_info,
+            sys::FrameBeginInfo::TYPE,
+        );
+        if let Some(x) = self.lock().session(self, COMMAND, session) {
+            if !x.running {
+                self.report(COMMAND, "the session isn't running");
+            } else if x.waited == 0 {
+                self.report(COMMAND, "no frame was waited for with xrWaitFrame");
+            }
+        }
+        let result = forward!(next.begin_frame(session, frame_begin_info));
+        if succeeded(result) {
+            if let Some(x) = self.lock().sessions.get_mut(&session) {
+                x.waited = x.waited.saturating_sub(1);
+                x.in_frame = true;
+            }
+        }
+        result
+    }
+
+    unsafe fn end_frame(
+        &self,
+        next: &Dispatch,
+        session: Session,
+        frame_end_info: *const sys::FrameEndInfo,
+    ) -> sys::Result {
+        const COMMAND: &str = "xrEndFrame";
+        self.check_ty(
+            COMMAND,
+            "frameEndInfo",
+            frame_end_info,
+            sys::FrameEndInfo::TYPE,
+        );
+        {
+            let mut state = self.lock();
+            if let Some(x) = state.session(self, COMMAND, session) {
+                if !x.in_frame {
+                    self.report(COMMAND, "no frame was begun with xrBeginFrame");
+                }
+            }
+            if !frame_end_info.is_null() {
+                let info = &*frame_end_info;
+                for i in 0..info.layer_count as usize {
+                    self.check_layer(&state, session, *info.layers.add(i));
+                }
+            }
+        }
+        let result = forward!(next.end_frame(session, frame_end_info));
+        if succeeded(result) {
+            if let Some(x) = self.lock().sessions.get_mut(&session) {
+                x.in_frame = false;
+            }
+        }
+        result
+    }
+}
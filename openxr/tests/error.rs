@@ -0,0 +1,134 @@
+//! Errors raised by the bindings themselves, rather than returned by an OpenXR command, must not
+//! name a command
+#![cfg(feature = "mock")]
+
+use openxr as xr;
+use xr::sys;
+
+fn instance(runtime: &xr::mock::MockRuntime, api_version: xr::Version) -> xr::Instance {
+    let app_info = xr::ApplicationInfo {
+        application_name: "test",
+        api_version: Some(api_version),
+        ..Default::default()
+    };
+    runtime
+        .entry()
+        .create_instance(&app_info, &xr::ExtensionSet::default(), &[])
+        .unwrap()
+}
+
+fn system(instance: &xr::Instance) -> xr::SystemId {
+    instance
+        .system(xr::FormFactor::HEAD_MOUNTED_DISPLAY)
+        .unwrap()
+}
+
+fn vulkan_info() -> xr::vulkan::SessionCreateInfo {
+    xr::vulkan::SessionCreateInfo {
+        instance: std::ptr::null(),
+        physical_device: std::ptr::null(),
+        device: std::ptr::null(),
+        queue_family_index: 0,
+        queue_index: 0,
+    }
+}
+
+fn swapchain_info<G: xr::Graphics>(format: G::Format) -> xr::SwapchainCreateInfo<G> {
+    xr::SwapchainCreateInfo {
+        create_flags: xr::SwapchainCreateFlags::EMPTY,
+        usage_flags: xr::SwapchainUsageFlags::COLOR_ATTACHMENT,
+        format,
+        sample_count: 1,
+        width: 64,
+        height: 64,
+        face_count: 1,
+        array_size: 1,
+        mip_count: 1,
+    }
+}
+
+fn vulkan_session(
+    runtime: &xr::mock::MockRuntime,
+    api_version: xr::Version,
+) -> xr::Session<xr::Vulkan> {
+    let instance = instance(runtime, api_version);
+    let system = system(&instance);
+    unsafe { instance.create_session::<xr::Vulkan>(system, &vulkan_info()) }
+        .unwrap()
+        .0
+}
+
+#[test]
+fn runtime_error_names_command() {
+    let runtime = xr::mock::MockRuntime::new();
+    let session = vulkan_session(&runtime, xr::CURRENT_API_VERSION);
+    let error = session
+        .create_swapchain(&swapchain_info::<xr::Vulkan>(0x7fff))
+        .err()
+        .unwrap();
+    assert_eq!(error, sys::Result::ERROR_SWAPCHAIN_FORMAT_UNSUPPORTED);
+    assert_eq!(error.function(), Some("xrCreateSwapchain"));
+}
+
+#[test]
+fn missing_extension() {
+    let runtime = xr::mock::MockRuntime::new();
+    let session = vulkan_session(&runtime, xr::CURRENT_API_VERSION);
+    let error = session
+        .create_vulkan_swapchain(
+            &swapchain_info(43),
+            &xr::vulkan::ImageCreateInfo {
+                view_formats: &[43, 37],
+                ..Default::default()
+            },
+        )
+        .err()
+        .unwrap();
+    assert_eq!(error, sys::Result::ERROR_EXTENSION_NOT_PRESENT);
+    assert_eq!(error.function(), None);
+}
+
+#[test]
+fn missing_locate_spaces() {
+    let runtime = xr::mock::MockRuntime::new();
+    let session = vulkan_session(&runtime, sys::API_VERSION_1_0);
+    let space = session
+        .create_reference_space(xr::ReferenceSpaceType::LOCAL, xr::Posef::IDENTITY)
+        .unwrap();
+    let error = session
+        .locate_spaces(&[&space], &space, runtime.time())
+        .err()
+        .unwrap();
+    assert_eq!(error, sys::Result::ERROR_FUNCTION_UNSUPPORTED);
+    assert_eq!(error.function(), None);
+}
+
+#[test]
+fn no_depth_format() {
+    let runtime = xr::mock::MockRuntime::new();
+    runtime.set_swapchain_formats(&[43]);
+    let session = vulkan_session(&runtime, xr::CURRENT_API_VERSION);
+    let error = session
+        .create_depth_swapchain(&swapchain_info(43), &xr::FormatPreferences::default())
+        .err()
+        .unwrap();
+    assert_eq!(error, sys::Result::ERROR_SWAPCHAIN_FORMAT_UNSUPPORTED);
+    assert_eq!(error.function(), None);
+}
+
+#[test]
+fn dynamic_session_without_backend() {
+    let runtime = xr::mock::MockRuntime::new();
+    let instance = instance(&runtime, xr::CURRENT_API_VERSION);
+    let system = system(&instance);
+    let (session, _, _) = unsafe {
+        let handle =
+            <xr::Vulkan as xr::Graphics>::create_session(&instance, system, &vulkan_info())
+                .unwrap();
+        xr::Session::<xr::Dynamic>::from_raw(instance.clone(), handle, Box::new(()))
+    };
+    let swapchain = session.create_swapchain(&swapchain_info(43)).unwrap();
+    let error = swapchain.enumerate_images().unwrap_err();
+    assert_eq!(error, sys::Result::ERROR_GRAPHICS_DEVICE_INVALID);
+    assert_eq!(error.function(), None);
+}
@@ -0,0 +1,487 @@
+//! Applications driven end to end against the mock runtime
+#![cfg(feature = "mock")]
+
+use openxr as xr;
+use xr::mock::{MockRuntime, SubmittedLayer};
+use xr::{sys, FrameLoopStatus, SessionState};
+
+const VIEW_TYPE: xr::ViewConfigurationType = xr::ViewConfigurationType::PRIMARY_STEREO;
+
+fn instance(runtime: &MockRuntime) -> xr::Instance {
+    let app_info = xr::ApplicationInfo {
+        application_name: "test",
+        ..Default::default()
+    };
+    runtime
+        .entry()
+        .create_instance(&app_info, &xr::ExtensionSet::default(), &[])
+        .unwrap()
+}
+
+fn session(
+    runtime: &MockRuntime,
+) -> (
+    xr::Session<xr::Vulkan>,
+    xr::FrameWaiter,
+    xr::FrameStream<xr::Vulkan>,
+) {
+    let instance = instance(runtime);
+    let system = instance
+        .system(xr::FormFactor::HEAD_MOUNTED_DISPLAY)
+        .unwrap();
+    let info = xr::vulkan::SessionCreateInfo {
+        instance: std::ptr::null(),
+        physical_device: std::ptr::null(),
+        device: std::ptr::null(),
+        queue_family_index: 0,
+        queue_index: 0,
+    };
+    unsafe { instance.create_session::<xr::Vulkan>(system, &info) }.unwrap()
+}
+
+fn swapchain(session: &xr::Session<xr::Vulkan>) -> xr::Swapchain<xr::Vulkan> {
+    session
+        .create_swapchain(&xr::SwapchainCreateInfo {
+            create_flags: xr::SwapchainCreateFlags::EMPTY,
+            usage_flags: xr::SwapchainUsageFlags::COLOR_ATTACHMENT,
+            format: 43,
+            sample_count: 1,
+            width: 64,
+            height: 64,
+            face_count: 1,
+            array_size: 2,
+            mip_count: 1,
+        })
+        .unwrap()
+}
+
+/// Poll until the driver reports something other than `status`
+fn poll_past(
+    driver: &mut xr::SessionDriver<xr::Vulkan>,
+    status: FrameLoopStatus,
+) -> FrameLoopStatus {
+    for _ in 0..8 {
+        let next = driver.poll().unwrap();
+        if next != status {
+            return next;
+        }
+    }
+    panic!("driver stuck at {:?}", status);
+}
+
+#[test]
+fn frame_loop() {
+    let runtime = MockRuntime::new();
+    let (session, mut frame_waiter, mut frame_stream) = session(&runtime);
+    let space = session
+        .create_reference_space(xr::ReferenceSpaceType::LOCAL, xr::Posef::IDENTITY)
+        .unwrap();
+    let mut swapchain = swapchain(&session);
+    let mut driver = xr::SessionDriver::new(session.clone(), VIEW_TYPE);
+    assert_eq!(
+        poll_past(&mut driver, FrameLoopStatus::Idle),
+        FrameLoopStatus::Running
+    );
+    assert_eq!(driver.state(), SessionState::FOCUSED);
+
+    let mut display_times = Vec::new();
+    for _ in 0..3 {
+        let frame = frame_waiter.wait().unwrap();
+        let frame = frame_stream.begin(frame).unwrap();
+        let display_time = frame.state().predicted_display_time;
+        display_times.push(display_time);
+        let (_, views) = session
+            .locate_views(VIEW_TYPE, display_time, &space)
+            .unwrap();
+        let image = swapchain.acquire_image().unwrap().release().unwrap();
+        let rect = xr::Rect2Di {
+            offset: xr::Offset2Di { x: 0, y: 0 },
+            extent: xr::Extent2Di {
+                width: 64,
+                height: 64,
+            },
+        };
+        let projection_views = views
+            .iter()
+            .enumerate()
+            .map(|(i, view)| {
+                xr::CompositionLayerProjectionView::new()
+                    .pose(view.pose)
+                    .fov(view.fov)
+                    .sub_image(
+                        image
+                            .sub_image()
+                            .image_rect(rect)
+                            .image_array_index(i as u32),
+                    )
+            })
+            .collect::<Vec<_>>();
+        let layer = xr::CompositionLayerProjection::new()
+            .space(&space)
+            .views(&projection_views);
+        frame_stream
+            .end(frame, xr::EnvironmentBlendMode::OPAQUE, &[&layer])
+            .unwrap();
+        assert_eq!(driver.poll().unwrap(), FrameLoopStatus::Running);
+    }
+
+    let frames = runtime.submitted_frames();
+    assert_eq!(frames.len(), 3);
+    assert!(display_times
+        .windows(2)
+        .all(|x| x[0].as_nanos() < x[1].as_nanos()));
+    for ((frame, &display_time), index) in frames.iter().zip(&display_times).zip(0..) {
+        assert_eq!(frame.session, session.as_raw());
+        assert_eq!(frame.display_time, display_time);
+        assert_eq!(frame.layers.len(), 1);
+        match &frame.layers[0] {
+            SubmittedLayer::Projection {
+                space: s, views, ..
+            } => {
+                assert_eq!(*s, space.as_raw());
+                assert_eq!(views.len(), 2);
+                for (i, view) in views.iter().enumerate() {
+                    assert_eq!(view.sub_image.swapchain, swapchain.as_raw());
+                    assert_eq!(view.sub_image.image_array_index, i as u32);
+                    assert_eq!(view.sub_image.image_index, index % 3);
+                }
+            }
+            layer => panic!("unexpected layer {:?}", layer),
+        }
+    }
+    assert_eq!(runtime.swapchains()[0].acquired_images, 0);
+
+    driver.request_exit().unwrap();
+    assert_eq!(
+        poll_past(&mut driver, FrameLoopStatus::Running),
+        FrameLoopStatus::Exit(xr::ExitReason::Exited)
+    );
+    assert_eq!(runtime.session_state(), Some(SessionState::EXITING));
+}
+
+#[test]
+fn empty_frames() {
+    let runtime = MockRuntime::new();
+    let (session, mut frame_waiter, mut frame_stream) = session(&runtime);
+    session.begin(VIEW_TYPE).unwrap();
+    for _ in 0..4 {
+        let frame = frame_waiter.wait().unwrap();
+        let frame = frame_stream.begin(frame).unwrap();
+        frame_stream
+            .end_empty(frame, xr::EnvironmentBlendMode::OPAQUE)
+            .unwrap();
+    }
+    let frames = runtime.take_submitted_frames();
+    assert_eq!(frames.len(), 4);
+    assert!(frames.iter().all(
+        |x| x.layers.is_empty() && x.environment_blend_mode == xr::EnvironmentBlendMode::OPAQUE
+    ));
+    assert!(runtime.submitted_frames().is_empty());
+}
+
+#[test]
+fn frame_timing() {
+    let runtime = MockRuntime::new();
+    let (session, mut frame_waiter, mut frame_stream) = session(&runtime);
+    session.begin(VIEW_TYPE).unwrap();
+    let timing = xr::FrameTimingRecorder::new(session.instance().clone(), 16);
+    for i in 0..4 {
+        let frame = timing.wait(&mut frame_waiter).unwrap();
+        let frame = timing.begin(&mut frame_stream, frame).unwrap();
+        if i % 2 == 0 {
+            timing
+                .end(
+                    &mut frame_stream,
+                    frame,
+                    xr::EnvironmentBlendMode::OPAQUE,
+                    &[],
+                )
+                .unwrap();
+        } else {
+            timing
+                .end_empty(&mut frame_stream, frame, xr::EnvironmentBlendMode::OPAQUE)
+                .unwrap();
+        }
+    }
+    assert_eq!(timing.stats().frames, 4);
+    let frames = timing.frames();
+    let submitted = runtime.submitted_frames();
+    assert_eq!(frames.len(), submitted.len());
+    for (frame, submitted) in frames.iter().zip(&submitted) {
+        assert_eq!(frame.predicted_display_time, submitted.display_time);
+    }
+}
+
+#[test]
+fn abandoned_image() {
+    let runtime = MockRuntime::new();
+    let (session, _, _) = session(&runtime);
+    let mut swapchain = swapchain(&session);
+    drop(swapchain.acquire_image().unwrap());
+    assert_eq!(runtime.swapchains()[0].acquired_images, 1);
+    let mut image = swapchain.acquire_image().unwrap();
+    assert_eq!(image.wait(xr::Duration::INFINITE).unwrap(), Some(1));
+    assert_eq!(runtime.swapchains()[0].acquired_images, 1);
+    drop(image);
+    assert_eq!(runtime.swapchains()[0].acquired_images, 0);
+}
+
+#[test]
+fn failed_release() {
+    let runtime = MockRuntime::new();
+    let (session, _, _) = session(&runtime);
+    let mut swapchain = swapchain(&session);
+    runtime.fail_next_call(
+        "xrReleaseSwapchainImage",
+        sys::Result::ERROR_RUNTIME_FAILURE,
+    );
+    let error = swapchain.acquire_image().unwrap().release().err().unwrap();
+    assert_eq!(error, sys::Result::ERROR_RUNTIME_FAILURE);
+    assert_eq!(error.function(), Some("xrReleaseSwapchainImage"));
+    // Dropping the guard after the failure didn't release it again
+    assert_eq!(runtime.call_count("xrReleaseSwapchainImage"), 1);
+    assert_eq!(runtime.swapchains()[0].acquired_images, 1);
+}
+
+#[test]
+fn scripted_state_changes() {
+    let runtime = MockRuntime::new();
+    runtime.set_auto_transitions(false);
+    let (session, _, _) = session(&runtime);
+    let mut driver = xr::SessionDriver::new(session, VIEW_TYPE);
+    assert_eq!(driver.poll().unwrap(), FrameLoopStatus::Idle);
+
+    runtime.set_session_state(SessionState::READY);
+    assert_eq!(driver.poll().unwrap(), FrameLoopStatus::Running);
+    assert_eq!(driver.state(), SessionState::READY);
+    runtime.set_session_state(SessionState::FOCUSED);
+    assert_eq!(driver.poll().unwrap(), FrameLoopStatus::Running);
+    assert!(driver.is_focused());
+
+    runtime.set_session_state(SessionState::LOSS_PENDING);
+    assert_eq!(
+        driver.poll().unwrap(),
+        FrameLoopStatus::Exit(xr::ExitReason::SessionLossPending)
+    );
+    assert!(!driver.is_running());
+}
+
+#[test]
+fn instance_loss() {
+    let runtime = MockRuntime::new();
+    let (session, _, _) = session(&runtime);
+    let mut driver = xr::SessionDriver::new(session, VIEW_TYPE);
+    poll_past(&mut driver, FrameLoopStatus::Idle);
+    let loss_time = runtime.time();
+    runtime.lose_instance(loss_time);
+    assert_eq!(
+        driver.poll().unwrap(),
+        FrameLoopStatus::Exit(xr::ExitReason::InstanceLossPending { loss_time })
+    );
+}
+
+#[test]
+fn lost_events() {
+    let runtime = MockRuntime::new();
+    runtime.set_auto_transitions(false);
+    runtime.set_event_queue_capacity(2);
+    let (session, _, _) = session(&runtime);
+    let mut driver = xr::SessionDriver::new(session, VIEW_TYPE);
+    for _ in 0..3 {
+        runtime.set_interaction_profile(
+            "/user/hand/left",
+            Some("/interaction_profiles/khr/simple_controller"),
+        );
+    }
+    runtime.set_session_state(SessionState::READY);
+    let mut profile_changes = 0;
+    let status = driver
+        .poll_with(|event| {
+            if let xr::Event::InteractionProfileChanged(_) = event {
+                profile_changes += 1;
+            }
+        })
+        .unwrap();
+    assert_eq!(status, FrameLoopStatus::Running);
+    assert_eq!(profile_changes, 1);
+    // The initial `IDLE` and two profile changes were dropped
+    assert_eq!(driver.lost_event_count(), 3);
+}
+
+#[test]
+fn actions() {
+    let runtime = MockRuntime::new();
+    let (session, _, _) = session(&runtime);
+    let instance = session.instance().clone();
+    let set = instance
+        .create_action_set("gameplay", "Gameplay", 0)
+        .unwrap();
+    let select = set.create_action::<bool>("select", "Select", &[]).unwrap();
+    let squeeze = set.create_action::<f32>("squeeze", "Squeeze", &[]).unwrap();
+    session.attach_action_sets(&[&set]).unwrap();
+    let mut driver = xr::SessionDriver::new(session.clone(), VIEW_TYPE);
+    poll_past(&mut driver, FrameLoopStatus::Idle);
+
+    runtime.set_boolean_action("select", None, true);
+    runtime.set_float_action("squeeze", None, 0.5);
+    session
+        .sync_actions(&[xr::ActiveActionSet::new(&set)])
+        .unwrap();
+    let state = select.state(&session, xr::Path::NULL).unwrap();
+    assert!(state.is_active && state.current_state && !state.changed_since_last_sync);
+    let state = squeeze.state(&session, xr::Path::NULL).unwrap();
+    assert!(state.is_active);
+    assert_eq!(state.current_state, 0.5);
+
+    runtime.set_boolean_action("select", None, false);
+    session
+        .sync_actions(&[xr::ActiveActionSet::new(&set)])
+        .unwrap();
+    let state = select.state(&session, xr::Path::NULL).unwrap();
+    assert!(!state.current_state && state.changed_since_last_sync);
+
+    runtime.clear_action("select", None);
+    session
+        .sync_actions(&[xr::ActiveActionSet::new(&set)])
+        .unwrap();
+    assert!(!select.state(&session, xr::Path::NULL).unwrap().is_active);
+}
+
+#[test]
+fn headless() {
+    let runtime = MockRuntime::new();
+    let instance = instance(&runtime);
+    let system = instance
+        .system(xr::FormFactor::HEAD_MOUNTED_DISPLAY)
+        .unwrap();
+    let error = unsafe {
+        instance.create_session::<xr::Headless>(system, &xr::headless::SessionCreateInfo)
+    }
+    .err()
+    .unwrap();
+    assert_eq!(error, sys::Result::ERROR_EXTENSION_NOT_PRESENT);
+    assert_eq!(error.function(), Some("xrCreateSession"));
+
+    let mut extensions = xr::ExtensionSet::default();
+    extensions.mnd_headless = true;
+    let instance = runtime
+        .entry()
+        .create_instance(
+            &xr::ApplicationInfo {
+                application_name: "test",
+                ..Default::default()
+            },
+            &extensions,
+            &[],
+        )
+        .unwrap();
+    let system = instance
+        .system(xr::FormFactor::HEAD_MOUNTED_DISPLAY)
+        .unwrap();
+    let (session, mut frame_waiter, mut frame_stream) = unsafe {
+        instance.create_session::<xr::Headless>(system, &xr::headless::SessionCreateInfo)
+    }
+    .unwrap();
+    session.begin(VIEW_TYPE).unwrap();
+    let frame = frame_waiter.wait().unwrap();
+    let frame = frame_stream.begin(frame).unwrap();
+    frame_stream
+        .end_empty(frame, xr::EnvironmentBlendMode::OPAQUE)
+        .unwrap();
+    assert_eq!(runtime.submitted_frames().len(), 1);
+}
+
+#[test]
+fn dynamic_sessions() {
+    let runtime = MockRuntime::new();
+    let instance = instance(&runtime);
+    let system = instance
+        .system(xr::FormFactor::HEAD_MOUNTED_DISPLAY)
+        .unwrap();
+    // Created on one thread and used on another
+    let session = std::thread::spawn({
+        let instance = instance.clone();
+        move || {
+            let info =
+                xr::dynamic::SessionCreateInfo::OpenGL(xr::opengl::SessionCreateInfo::Xlib {
+                    x_display: std::ptr::null_mut(),
+                    visualid: 0,
+                    glx_fb_config: std::ptr::null_mut(),
+                    glx_drawable: 0,
+                    glx_context: std::ptr::null_mut(),
+                });
+            unsafe { instance.create_session::<xr::Dynamic>(system, &info) }
+                .unwrap()
+                .0
+        }
+    })
+    .join()
+    .unwrap();
+    assert_eq!(session.backend(), Some(xr::dynamic::Backend::OpenGL));
+    let mut info = xr::SwapchainCreateInfo {
+        create_flags: xr::SwapchainCreateFlags::EMPTY,
+        usage_flags: xr::SwapchainUsageFlags::COLOR_ATTACHMENT,
+        format: session.enumerate_swapchain_formats().unwrap()[0],
+        sample_count: 1,
+        width: 64,
+        height: 64,
+        face_count: 1,
+        array_size: 1,
+        mip_count: 1,
+    };
+    let images = session
+        .create_swapchain(&info)
+        .unwrap()
+        .enumerate_images()
+        .unwrap();
+    assert_eq!(images.len(), 3);
+    assert!(images
+        .iter()
+        .all(|x| matches!(x, xr::dynamic::SwapchainImage::OpenGL(_))));
+
+    let (session, _, _) = unsafe {
+        let handle = <xr::Vulkan as xr::Graphics>::create_session(
+            &instance,
+            system,
+            &xr::vulkan::SessionCreateInfo {
+                instance: std::ptr::null(),
+                physical_device: std::ptr::null(),
+                device: std::ptr::null(),
+                queue_family_index: 0,
+                queue_index: 0,
+            },
+        )
+        .unwrap();
+        xr::Session::<xr::Dynamic>::from_raw_with_backend(
+            instance.clone(),
+            handle,
+            Box::new(()),
+            xr::dynamic::Backend::Vulkan,
+        )
+    };
+    assert_eq!(session.backend(), Some(xr::dynamic::Backend::Vulkan));
+    info.format = session.enumerate_swapchain_formats().unwrap()[0];
+    let images = session
+        .create_swapchain(&info)
+        .unwrap()
+        .enumerate_images()
+        .unwrap();
+    assert!(images
+        .iter()
+        .all(|x| matches!(x, xr::dynamic::SwapchainImage::Vulkan(_))));
+}
+
+#[test]
+fn unattached_action_sets() {
+    let runtime = MockRuntime::new();
+    let (session, _, _) = session(&runtime);
+    let set = session
+        .instance()
+        .create_action_set("gameplay", "Gameplay", 0)
+        .unwrap();
+    let error = session
+        .sync_actions(&[xr::ActiveActionSet::new(&set)])
+        .unwrap_err();
+    assert_eq!(error, sys::Result::ERROR_ACTIONSET_NOT_ATTACHED);
+    assert_eq!(error.function(), Some("xrSyncActions"));
+}
@@ -0,0 +1,67 @@
+//! The mock runtime's fixed table of entry points
+//!
+//! Kept in its own test binary since it fills every slot, which would starve concurrently running
+//! tests.
+#![cfg(feature = "mock")]
+
+use openxr as xr;
+use xr::mock::MockRuntime;
+
+const SLOT_COUNT: usize = 32;
+
+fn instance(runtime: &MockRuntime) -> xr::Instance {
+    let app_info = xr::ApplicationInfo {
+        application_name: "test",
+        ..Default::default()
+    };
+    runtime
+        .entry()
+        .create_instance(&app_info, &xr::ExtensionSet::default(), &[])
+        .unwrap()
+}
+
+fn fill(tag: &str) -> Vec<(MockRuntime, xr::Instance)> {
+    (0..SLOT_COUNT)
+        .map(|i| {
+            let runtime = MockRuntime::new();
+            runtime.set_runtime_name(&format!("{} {}", tag, i));
+            let instance = instance(&runtime);
+            (runtime, instance)
+        })
+        .collect()
+}
+
+fn check(runtimes: &[(MockRuntime, xr::Instance)], tag: &str) {
+    for (i, (_, instance)) in runtimes.iter().enumerate() {
+        let properties = instance.properties().unwrap();
+        assert_eq!(properties.runtime_name, format!("{} {}", tag, i));
+    }
+}
+
+#[test]
+fn slots() {
+    let mut runtimes = fill("first");
+    check(&runtimes, "first");
+    assert!(std::panic::catch_unwind(MockRuntime::new).is_err());
+
+    // Instances keep their runtime's slot occupied
+    let (runtime, first) = runtimes.remove(0);
+    drop(runtime);
+    assert!(std::panic::catch_unwind(MockRuntime::new).is_err());
+    drop(first);
+    let runtime = MockRuntime::new();
+    runtime.set_runtime_name("reused");
+    let instance = instance(&runtime);
+    assert_eq!(instance.properties().unwrap().runtime_name, "reused");
+    assert!(std::panic::catch_unwind(MockRuntime::new).is_err());
+    drop((runtime, instance));
+    drop(runtimes);
+
+    let runtimes = fill("second");
+    check(&runtimes, "second");
+    assert!(std::panic::catch_unwind(MockRuntime::new).is_err());
+    drop(runtimes);
+
+    let runtimes = fill("third");
+    check(&runtimes, "third");
+}
@@ -10,8 +10,8 @@ pub type VkInstance = *const c_void;
 pub type VkPhysicalDevice = *const c_void;
 pub type VkDevice = *const c_void;
 pub type VkImage = u64;
-pub type VkImageCreateFlags = u64;
-pub type VkImageUsageFlags = u64;
+pub type VkImageCreateFlags = u32;
+pub type VkImageUsageFlags = u32;
 pub type VkFormat = u32;
 pub type VkInstanceCreateInfo = c_void;
 pub type VkDeviceCreateInfo = c_void;